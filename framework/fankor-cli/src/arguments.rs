@@ -0,0 +1,123 @@
+use std::path::PathBuf;
+
+/// The command to execute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    GenerateTs,
+    GenerateIdl,
+    InspectAccounts,
+    InspectInstructions,
+    Diff(PathBuf, PathBuf),
+}
+
+/// The parsed command line arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arguments {
+    pub command: Command,
+    pub path: Option<PathBuf>,
+    pub out_dir: Option<PathBuf>,
+    pub format: bool,
+    pub skip_unchanged: bool,
+    pub tests: bool,
+    pub standalone: bool,
+}
+
+impl Arguments {
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses the arguments without the binary name.
+    pub fn parse<I: Iterator<Item = String>>(arguments: I) -> Result<Arguments, String> {
+        let mut positional = Vec::new();
+        let mut result = Arguments {
+            command: Command::Help,
+            path: None,
+            out_dir: None,
+            format: false,
+            skip_unchanged: false,
+            tests: false,
+            standalone: false,
+        };
+
+        let mut arguments = arguments.peekable();
+        while let Some(argument) = arguments.next() {
+            match argument.as_str() {
+                "-h" | "--help" => return Ok(result),
+                "--path" => {
+                    let value = arguments.next().ok_or("Missing value for '--path'")?;
+                    result.path = Some(PathBuf::from(value));
+                }
+                "--out" => {
+                    let value = arguments.next().ok_or("Missing value for '--out'")?;
+                    result.out_dir = Some(PathBuf::from(value));
+                }
+                "--format" => result.format = true,
+                "--skip-unchanged" => result.skip_unchanged = true,
+                "--tests" => result.tests = true,
+                "--standalone" => result.standalone = true,
+                v if v.starts_with('-') => return Err(format!("Unknown option '{}'", v)),
+                _ => positional.push(argument),
+            }
+        }
+
+        result.command = match positional
+            .iter()
+            .map(|v| v.as_str())
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            [] | ["help"] => Command::Help,
+            ["generate", "ts"] => Command::GenerateTs,
+            ["generate", "idl"] => Command::GenerateIdl,
+            ["inspect", "accounts"] => Command::InspectAccounts,
+            ["inspect", "instructions"] => Command::InspectInstructions,
+            ["diff", old, new] => Command::Diff(PathBuf::from(old), PathBuf::from(new)),
+            _ => return Err(format!("Unknown command '{}'", positional.join(" "))),
+        };
+
+        Ok(result)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(arguments: &[&str]) -> Result<Arguments, String> {
+        Arguments::parse(arguments.iter().map(|v| v.to_string()))
+    }
+
+    #[test]
+    fn test_parse() {
+        let arguments = parse(&["generate", "ts", "--out", "dist", "--format"]).unwrap();
+        assert_eq!(arguments.command, Command::GenerateTs);
+        assert_eq!(arguments.out_dir, Some(PathBuf::from("dist")));
+        assert!(arguments.format);
+        assert!(!arguments.skip_unchanged);
+        assert!(!arguments.tests);
+        assert!(!arguments.standalone);
+
+        let arguments = parse(&["generate", "ts", "--tests", "--standalone"]).unwrap();
+        assert!(arguments.tests);
+        assert!(arguments.standalone);
+
+        let arguments = parse(&["--path", "program", "inspect", "instructions"]).unwrap();
+        assert_eq!(arguments.command, Command::InspectInstructions);
+        assert_eq!(arguments.path, Some(PathBuf::from("program")));
+
+        let arguments = parse(&["diff", "old.json", "new.json"]).unwrap();
+        assert_eq!(
+            arguments.command,
+            Command::Diff(PathBuf::from("old.json"), PathBuf::from("new.json"))
+        );
+
+        assert_eq!(parse(&[]).unwrap().command, Command::Help);
+        assert!(parse(&["generate"]).is_err());
+        assert!(parse(&["generate", "ts", "--unknown"]).is_err());
+        assert!(parse(&["generate", "ts", "--out"]).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use std::path::Path;
+use std::process::exit;
+
+use fankor::ts_gen::{diff, Idl};
+
+use crate::arguments::{Arguments, Command};
+use crate::runner::{find_idl_file, run_registration, RunnerConfig};
+
+mod arguments;
+mod runner;
+
+const USAGE: &str = "Usage: fankor <command> [options]
+
+Commands:
+    generate ts             Generates the TypeScript client of the program.
+    generate idl            Generates the JSON IDL of the program.
+    inspect accounts        Lists the accounts of the program.
+    inspect instructions    Lists the instructions of the program.
+    diff <old> <new>        Compares two JSON IDL files failing on breaking changes.
+
+Options:
+    --path <dir>            The folder of the program crate. Defaults to the current one.
+    --out <dir>             The output folder. Defaults to '<path>/target/fnk_ts'.
+    --format                Formats the TypeScript file with prettier.
+    --skip-unchanged        Does not rewrite the files whose content does not change.
+    --tests                 Also generates round-trip tests of the TypeScript schemas.
+    --standalone            Also generates the codecs of the types without dependencies.
+    -h, --help              Prints this message.";
+
+fn main() {
+    let arguments = match Arguments::parse(std::env::args().skip(1)) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            exit(2);
+        }
+    };
+
+    if let Err(e) = execute(arguments) {
+        eprintln!("Error: {}", e);
+        exit(1);
+    }
+}
+
+fn execute(arguments: Arguments) -> Result<(), String> {
+    let program_path = match &arguments.path {
+        Some(v) => v.clone(),
+        None => std::env::current_dir().map_err(|e| e.to_string())?,
+    };
+
+    match &arguments.command {
+        Command::Help => {
+            println!("{}", USAGE);
+        }
+        Command::GenerateTs | Command::GenerateIdl => {
+            let out_dir = arguments
+                .out_dir
+                .clone()
+                .unwrap_or_else(|| program_path.join("target/fnk_ts"));
+            let idl = arguments.command == Command::GenerateIdl;
+
+            run_registration(&RunnerConfig {
+                program_path: &program_path,
+                out_dir: &out_dir,
+                format: arguments.format,
+                skip_unchanged: arguments.skip_unchanged,
+                idl,
+                tests: arguments.tests,
+                standalone: arguments.standalone,
+            })?;
+
+            println!("Files generated at '{}'", out_dir.display());
+        }
+        Command::InspectAccounts | Command::InspectInstructions => {
+            let out_dir = program_path.join("target/fnk_cli");
+            run_registration(&RunnerConfig {
+                program_path: &program_path,
+                out_dir: &out_dir,
+                format: false,
+                skip_unchanged: true,
+                idl: true,
+                tests: false,
+                standalone: false,
+            })?;
+
+            let idl_path = find_idl_file(&out_dir)?;
+            print_inspection(&arguments.command, &idl_path)?;
+        }
+        Command::Diff(old_path, new_path) => {
+            if print_diff(old_path, new_path)? {
+                return Err("The new IDL contains breaking changes".to_string());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn read_idl(idl_path: &Path) -> Result<Idl, String> {
+    let content = std::fs::read_to_string(idl_path)
+        .map_err(|e| format!("Cannot read '{}': {}", idl_path.display(), e))?;
+
+    Idl::from_json(&content).map_err(|e| format!("Cannot parse '{}': {}", idl_path.display(), e))
+}
+
+fn print_inspection(command: &Command, idl_path: &Path) -> Result<(), String> {
+    let idl = read_idl(idl_path)?;
+
+    match command {
+        Command::InspectAccounts => {
+            println!("Accounts of '{}' ({}):", idl.name, idl.accounts.len());
+
+            for account in &idl.accounts {
+                println!("    {}", account);
+            }
+        }
+        Command::InspectInstructions => {
+            println!(
+                "Instructions of '{}' ({}):",
+                idl.name,
+                idl.instructions.len()
+            );
+
+            for instruction in &idl.instructions {
+                println!("    {}: {}", instruction.name, instruction.accounts);
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
+
+/// Prints the changes between two IDL files returning whether there are
+/// breaking changes or not.
+fn print_diff(old_path: &Path, new_path: &Path) -> Result<bool, String> {
+    let old = read_idl(old_path)?;
+    let new = read_idl(new_path)?;
+    let result = diff(&old, &new);
+
+    if result.changes.is_empty() {
+        println!("No changes");
+        return Ok(false);
+    }
+
+    for change in &result.changes {
+        if change.is_breaking() {
+            println!("[BREAKING] {}", change);
+        } else {
+            println!("{}", change);
+        }
+    }
+
+    Ok(result.is_breaking())
+}
@@ -0,0 +1,107 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The config to run the registration of a program.
+pub struct RunnerConfig<'a> {
+    pub program_path: &'a Path,
+    pub out_dir: &'a Path,
+    pub format: bool,
+    pub skip_unchanged: bool,
+    pub idl: bool,
+    pub tests: bool,
+    pub standalone: bool,
+}
+
+/// Runs the `ts-gen` tests of the program, that register all its items and
+/// write the generated files.
+pub fn run_registration(config: &RunnerConfig) -> Result<(), String> {
+    let manifest_path = config.program_path.join("Cargo.toml");
+    if !manifest_path.is_file() {
+        return Err(format!(
+            "Cannot find the program manifest at '{}'",
+            manifest_path.display()
+        ));
+    }
+
+    // The tests are executed in the program folder, so relative paths would
+    // point to a different place.
+    fs::create_dir_all(config.out_dir)
+        .map_err(|e| format!("Cannot create '{}': {}", config.out_dir.display(), e))?;
+    let out_dir = config
+        .out_dir
+        .canonicalize()
+        .map_err(|e| format!("Cannot resolve '{}': {}", config.out_dir.display(), e))?;
+
+    let status = Command::new("cargo")
+        .arg("test")
+        .arg("--manifest-path")
+        .arg(&manifest_path)
+        .arg("--features")
+        .arg("ts-gen")
+        .arg("--")
+        .arg("__ts_gen_test__")
+        .env("FANKOR_TS_OUT_DIR", &out_dir)
+        .env("FANKOR_TS_FORMAT", bool_flag(config.format))
+        .env("FANKOR_TS_SKIP_UNCHANGED", bool_flag(config.skip_unchanged))
+        .env("FANKOR_TS_IDL", bool_flag(config.idl))
+        .env("FANKOR_TS_TESTS", bool_flag(config.tests))
+        .env("FANKOR_TS_STANDALONE", bool_flag(config.standalone))
+        .status()
+        .map_err(|e| format!("Cannot execute cargo: {}", e))?;
+
+    if !status.success() {
+        return Err(format!("The registration tests failed with {}", status));
+    }
+
+    Ok(())
+}
+
+/// Finds the JSON IDL file generated in `out_dir`, i.e. `<program>.json`
+/// next to the `<program>.ts` file, ignoring the other JSON outputs like
+/// `<program>.docs.json`.
+pub fn find_idl_file(out_dir: &Path) -> Result<PathBuf, String> {
+    let entries =
+        fs::read_dir(out_dir).map_err(|e| format!("Cannot read '{}': {}", out_dir.display(), e))?;
+
+    entries
+        .filter_map(|v| v.ok())
+        .map(|v| v.path())
+        .filter(|v| v.extension() == Some(OsStr::new("ts")))
+        .map(|v| v.with_extension("json"))
+        .find(|v| v.is_file())
+        .ok_or_else(|| format!("Cannot find the IDL file in '{}'", out_dir.display()))
+}
+
+fn bool_flag(value: bool) -> &'static str {
+    if value {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_idl_file() {
+        let out_dir = std::env::temp_dir().join("fankor_cli_test_find_idl_file");
+        fs::create_dir_all(&out_dir).unwrap();
+
+        for name in ["program.docs.json", "program.ts", "program.json"] {
+            fs::write(out_dir.join(name), "").unwrap();
+        }
+
+        let result = find_idl_file(&out_dir);
+        fs::remove_dir_all(&out_dir).unwrap();
+
+        assert_eq!(result.unwrap(), out_dir.join("program.json"));
+    }
+}
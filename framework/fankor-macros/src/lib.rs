@@ -25,6 +25,66 @@ pub fn setup(args: TokenStream) -> TokenStream {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// This macro declares the metadata of the program, which is stored in the binary
+/// and included into the generated IDL and TypeScript files:
+///
+/// ```none
+/// program_info! {
+///     version: "1.0.0",
+///     docs: "https://docs.example.com",
+///     repository: "https://github.com/example/program",
+///     deployments: {
+///         mainnet: "<address>",
+///         devnet: "<address>",
+///     },
+/// }
+/// ```
+///
+/// All arguments are optional. The version defaults to the one of the crate.
+#[proc_macro]
+pub fn program_info(args: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as macros::program_info::ProgramInfoArgs);
+
+    match macros::program_info::processor(args) {
+        Ok(v) => v,
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// This macro declares a namespace of PDAs of the program:
+///
+/// ```none
+/// namespace! {
+///     config => [b"config"],
+///     user(wallet: Pubkey) => [b"user", wallet],
+/// }
+/// ```
+///
+/// For every entry it generates a `<name>_seeds` function, which can be used
+/// in the `pda` attribute of the accounts, a `<name>_address` function and,
+/// when the entry has no arguments, a `<NAME>_SEEDS` constant. The TypeScript
+/// file includes a `find<Name>Pda` function for each entry.
+///
+/// Seeds can be byte strings, strings or arguments, which must be `Pubkey`,
+/// `String`, `str`, `Vec<u8>` or `[u8; N]`.
+#[proc_macro]
+pub fn namespace(args: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as macros::namespace::NamespaceArgs);
+
+    match macros::namespace::processor(args) {
+        Ok(v) => v,
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 /// This macro creates a pubkey from a string.
 #[proc_macro]
 pub fn const_pubkey(args: TokenStream) -> TokenStream {
@@ -71,7 +131,11 @@ pub fn deserialize(input: TokenStream) -> TokenStream {
 // ----------------------------------------------------------------------------
 
 /// Implements the ZeroCopyType and CopyType traits for the given struct.
-#[proc_macro_derive(FankorZeroCopy, attributes(fankor))]
+///
+/// The `#[zc_derive(Trait1, Trait2, ...)]` attribute forwards derives to the
+/// generated `Zc<Name>` projection. Struct projections are compared and hashed
+/// by the account and offset they point to.
+#[proc_macro_derive(FankorZeroCopy, attributes(fankor, zc_derive))]
 pub fn zero_copy(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as Item);
 
@@ -114,6 +178,28 @@ pub fn accounts(args: TokenStream, input: TokenStream) -> TokenStream {
 /// - `FankorDeserialize`
 /// - `FankorZeroCopy`
 /// - `TsGen`
+/// - `CanonicalSerialize` if the `canonical` argument is present.
+///
+/// The `#[zc_derive(...)]` and `#[filter_derive(...)]` attributes forward
+/// derives to the generated zero-copy projection and filter builder.
+///
+/// With the `pod` argument the struct is stored as its raw bytes, aligned to 8
+/// bytes after the discriminant, and implements `PodAccountType` instead of
+/// being serialized with Borsh. It requires the `pod` feature of fankor and
+/// the struct must derive `Clone`, `Copy`, `Pod` and `Zeroable` from bytemuck.
+/// The TypeScript client also gets a `<Name>View` class that reads and writes
+/// the fields in place.
+///
+/// With the `singleton` argument the account implements `SingletonAccountType`
+/// so that it can be loaded as a `Singleton`, stored in the PDA whose only seed
+/// is the type name. The TypeScript client gets its address as the
+/// `<NAME>_ADDRESS` constant.
+///
+/// Structs with named fields also get a `<Name>Builder`, created with
+/// `<Name>::builder()` or from an existing value, with a setter per field and
+/// a `try_<field>` one that accepts any value convertible into the field type,
+/// e.g. a `u16` for a `Bps` field. `build` fails if a field is unset or has
+/// been set with a value out of the bounds of its type.
 #[proc_macro_attribute]
 pub fn account(args: TokenStream, input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(args as FnkMetaArgumentList);
@@ -159,6 +245,31 @@ pub fn field_offsets(input: TokenStream) -> TokenStream {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Implements `Migratable` for an account and generates the `Migrate<Name>`
+/// instruction that migrates a batch of accounts from the previous version:
+/// ```none
+/// #[derive(Migratable)]
+/// #[migratable(from = AccountV1, with = migrate_account_v1)]
+/// pub struct AccountV2 { ... }
+///
+/// fn migrate_account_v1(old: &AccountV1) -> FankorResult<AccountV2> { ... }
+/// ```
+/// The instruction must be added to the program with
+/// `#[return_type = MigrationProgress]`.
+#[proc_macro_derive(Migratable, attributes(migratable))]
+pub fn migratable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Item);
+
+    match macros::migratable::processor(input) {
+        Ok(v) => v,
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 /// Generates a secondary enumeration that sets the discriminant of an enum.
 #[proc_macro_derive(EnumDiscriminants, attributes(discriminant))]
 pub fn enum_discriminants(input: TokenStream) -> TokenStream {
@@ -177,6 +288,27 @@ pub fn enum_discriminants(input: TokenStream) -> TokenStream {
 /// Derives the `Instruction` trait for the given struct/enum as well as:
 /// - `EnumDiscriminants` if it is an enum
 /// - `TsGen`
+///
+/// In programs that declare `strict_mode!()` every `UncheckedAccount` and `AccountInfo` field must
+/// justify why it is not checked with `#[account(unchecked_reason = "...")]`, otherwise the
+/// compilation fails. The reasons are included in the IDL.
+///
+/// `#[account(token_gate = (<mint>, <min_amount>))]` requires the account to be a signer that holds
+/// at least `min_amount` tokens of `mint` in its associated token account, which must be among
+/// the accounts of the instruction too. It requires the `token-program` feature.
+///
+/// `#[instruction(collect_fee = lamports, fee_base = <expr>)]` transfers the fee of the `FeeConfig`
+/// PDA of the program, applied to `fee_base`, from `fee_payer` to `fee_recipient` right after
+/// validating the accounts, so before the handler runs. It requires the fields `fee_config`,
+/// `fee_payer`, `fee_recipient` and `system_program`. With `collect_fee = token(<mint field>)`
+/// the fee is paid in tokens from `fee_source` and it requires `token_program` instead of
+/// `system_program`.
+///
+/// `#[instruction(idempotent_by = args.nonce, idempotency_scope = <field>)]` records the nonce
+/// in the `IdempotencyRecord` PDA of the account in `<field>`, which must sign, after validating
+/// the accounts, failing with `InstructionReplayed` if it is among its last
+/// `IDEMPOTENCY_RECORD_CAPACITY` nonces. It requires the field `idempotency_record`, and the PDA
+/// must be created beforehand with `create_idempotency_record`.
 #[proc_macro_attribute]
 pub fn instruction(args: TokenStream, input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(args as FnkMetaArgumentList);
@@ -228,6 +360,14 @@ pub fn error_code(args: TokenStream, input: TokenStream) -> TokenStream {
 ///
 /// If `RESULT` is different from `()` then the instruction will store the result in the intermediate buffer as
 /// the instruction result.
+///
+/// Instructions can also be grouped into enums defined with `#[instruction_group]` and included
+/// as a single variant, e.g. `Admin(AdminInstruction)`. The data of those instructions starts with
+/// the discriminant of the group followed by the discriminant of the instruction inside it.
+///
+/// `#[program(account_registry = ProgramAccount)]` adds the `__account_registry` instruction, which
+/// stores the name, discriminant and layout hash of every account of the `#[accounts]` enum in a PDA
+/// so indexers can detect layout changes.
 #[proc_macro_attribute]
 pub fn program(args: TokenStream, input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(args as FnkMetaArgumentList);
@@ -239,6 +379,30 @@ pub fn program(args: TokenStream, input: TokenStream) -> TokenStream {
     }
 }
 
+/// This macro process a definition of a group of instructions to be included into a program
+/// as a single variant. Its variants follow the same rules as the ones of `#[program]`.
+///
+/// The `middleware` argument is optional and sets a function that is called before
+/// every instruction of the group with the signature:
+///
+/// ```none
+/// fn middleware(context: &FankorContext, instruction: GroupDiscriminant) -> FankorResult<()>;
+/// ```
+///
+/// CPI and LPI methods are generated as `cpi_<instruction>` and `lpi_<instruction>` methods
+/// of the group, generic over the program that includes it. Because of that, the CPI methods
+/// fail if the program is not in the CPI allowlist declared with `cpi_allowlist!`.
+#[proc_macro_attribute]
+pub fn instruction_group(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as FnkMetaArgumentList);
+    let input = parse_macro_input!(input as Item);
+
+    match macros::program::group::processor(args, input) {
+        Ok(v) => v,
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
@@ -283,6 +447,13 @@ pub fn constant(args: TokenStream, input: TokenStream) -> TokenStream {
 
 /// This macro defines a constant in the program. This is used to map it to
 /// the TypeScript generated code.
+///
+/// The fields of structs accept the following arguments in `#[fankor(...)]`
+/// to filter what the TypeScript types expose, while keeping the field in the
+/// on-chain layout:
+/// - `ts_skip`: hides the field. It is read and discarded, and written as its
+///   default value, so its type must implement `Default`.
+/// - `ts_opaque`: exposes the field as its raw bytes, i.e. a `Uint8Array`.
 #[proc_macro_derive(TsGen, attributes(fankor))]
 pub fn ts_gen(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as Item);
@@ -1,4 +1,5 @@
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream};
+use quote::ToTokens;
 
 use crate::fnk_syn::FnkMetaArgumentList;
 use crate::Result;
@@ -6,6 +7,20 @@ use crate::Result;
 pub struct AccountArguments {
     /// The accounts type name.
     pub accounts_type_name: Ident,
+
+    /// The previous discriminants still accepted when reading the account.
+    pub legacy_discriminants: Option<TokenStream>,
+
+    /// Whether the account must implement `CanonicalSerialize` or not.
+    pub canonical: bool,
+
+    /// Whether the account is stored as a plain-old-data value instead of
+    /// being serialized with Borsh.
+    pub pod: bool,
+
+    /// Whether the account is the only one of its type, stored in the PDA
+    /// derived from its name.
+    pub singleton: bool,
 }
 
 impl AccountArguments {
@@ -17,6 +32,12 @@ impl AccountArguments {
 
         let result = AccountArguments {
             accounts_type_name: args.pop_ident("base", false)?.unwrap(),
+            legacy_discriminants: args
+                .pop("legacy_discriminants", Some(true), true)?
+                .map(|v| v.value.to_token_stream()),
+            canonical: args.pop_plain("canonical", true)?,
+            pod: args.pop_plain("pod", true)?,
+            singleton: args.pop_plain("singleton", true)?,
         };
 
         args.error_on_unknown()?;
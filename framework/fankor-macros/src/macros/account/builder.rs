@@ -0,0 +1,125 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Fields, Item};
+
+/// Generates a builder for struct accounts with named fields, i.e.
+/// `MyAccount::builder().field(value).build()`. The `try_<field>` setters
+/// accept any value convertible into the field type, e.g. `u16` for `Bps`,
+/// and make `build` fail if the value is out of the bounds of the type.
+pub fn builder(input: &Item) -> TokenStream {
+    let item = match input {
+        Item::Struct(item) => item,
+        _ => return quote! {},
+    };
+
+    let fields = match &item.fields {
+        Fields::Named(v) => &v.named,
+        _ => return quote! {},
+    };
+
+    let name = &item.ident;
+    let name_str = name.to_string();
+    let vis = &item.vis;
+    let generics = &item.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let builder_name = format_ident!("{}Builder", name);
+    let builder_doc = format!("Builder of [{}].", name);
+
+    let field_names = fields
+        .iter()
+        .map(|v| v.ident.as_ref().unwrap())
+        .collect::<Vec<_>>();
+    let field_types = fields.iter().map(|v| &v.ty).collect::<Vec<_>>();
+    let field_name_strs = field_names.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    let try_setters = field_names
+        .iter()
+        .map(|v| format_ident!("try_{}", v))
+        .collect::<Vec<_>>();
+
+    quote! {
+        #[doc = #builder_doc]
+        #[allow(dead_code)]
+        #vis struct #builder_name #generics #where_clause {
+            #(#field_names: Option<#field_types>,)*
+            __invalid_field: Option<&'static str>,
+        }
+
+        #[automatically_derived]
+        #[allow(dead_code)]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Creates a builder with all the fields unset.
+            pub fn builder() -> #builder_name #ty_generics {
+                #builder_name::new()
+            }
+        }
+
+        #[automatically_derived]
+        #[allow(dead_code)]
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+            pub fn new() -> Self {
+                Self {
+                    #(#field_names: None,)*
+                    __invalid_field: None,
+                }
+            }
+
+            #(
+                pub fn #field_names(mut self, value: #field_types) -> Self {
+                    self.#field_names = Some(value);
+                    self
+                }
+
+                pub fn #try_setters<V: TryInto<#field_types>>(mut self, value: V) -> Self {
+                    match value.try_into() {
+                        Ok(value) => self.#field_names = Some(value),
+                        Err(_) => {
+                            self.__invalid_field.get_or_insert(#field_name_strs);
+                        }
+                    }
+
+                    self
+                }
+            )*
+
+            /// Builds the account failing if any field is unset or has been
+            /// set with an invalid value.
+            pub fn build(self) -> ::fankor::errors::FankorResult<#name #ty_generics> {
+                if let Some(field) = self.__invalid_field {
+                    return Err(::fankor::errors::FankorErrorCode::AccountBuilderInvalidField {
+                        account: #name_str,
+                        field,
+                    }
+                    .into());
+                }
+
+                Ok(#name {
+                    #(
+                        #field_names: self.#field_names.ok_or(
+                            ::fankor::errors::FankorErrorCode::AccountBuilderMissingField {
+                                account: #name_str,
+                                field: #field_name_strs,
+                            },
+                        )?,
+                    )*
+                })
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics Default for #builder_name #ty_generics #where_clause {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics From<#name #ty_generics> for #builder_name #ty_generics #where_clause {
+            fn from(value: #name #ty_generics) -> Self {
+                Self {
+                    #(#field_names: Some(value.#field_names),)*
+                    __invalid_field: None,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Fields, Item, Path};
+
+use crate::macros::account::offsets::{offset_const_name, static_offsets};
+use crate::Result;
+
+pub fn client(input: &Item, derives: &[Path], data_offset: usize) -> Result<TokenStream> {
+    // Process input.
+    let (name, visibility, generics) = match &input {
+        Item::Struct(item) => (&item.ident, &item.vis, &item.generics),
+        Item::Enum(item) => (&item.ident, &item.vis, &item.generics),
+        _ => unreachable!(),
+    };
+
+    // Generic accounts cannot be filtered because their layout is unknown.
+    if !generics.params.is_empty() {
+        return Ok(quote! {});
+    }
+
+    let filter_name = format_ident!("{}Filter", name);
+    let derive_attr = if derives.is_empty() {
+        quote! {}
+    } else {
+        quote! { #[derive(#(#derives),*)] }
+    };
+
+    // Only the fields with a static offset, i.e. those not preceded by a
+    // variable-size field, can be filtered.
+    let mut field_methods = Vec::new();
+
+    if let Item::Struct(item) = input {
+        if let Fields::Named(fields) = &item.fields {
+            for (field_name, _) in static_offsets(input, data_offset) {
+                let field = fields
+                    .named
+                    .iter()
+                    .find(|v| v.ident.as_ref() == Some(field_name))
+                    .unwrap();
+                let method_name = format_ident!("field_{}", field_name);
+                let const_name = offset_const_name(field_name);
+                let ty = &field.ty;
+
+                field_methods.push(quote! {
+                    pub fn #method_name(self, value: &#ty) -> ::fankor::errors::FankorResult<Self> {
+                        Ok(#filter_name(self.0.field(#name::#const_name, value)?))
+                    }
+                });
+            }
+        }
+    }
+
+    let result = quote! {
+        #[cfg(feature = "client")]
+        #[automatically_derived]
+        #derive_attr
+        #visibility struct #filter_name(::fankor::client::AccountFilter<#name>);
+
+        #[cfg(feature = "client")]
+        #[automatically_derived]
+        impl #name {
+            /// Creates a builder of `getProgramAccounts` filters for this account.
+            pub fn filter() -> #filter_name {
+                #filter_name(::fankor::client::AccountFilter::new())
+            }
+        }
+
+        #[cfg(feature = "client")]
+        #[automatically_derived]
+        impl #filter_name {
+            #(#field_methods)*
+
+            pub fn memcmp(self, offset: usize, bytes: Vec<u8>) -> Self {
+                #filter_name(self.0.memcmp(offset, bytes))
+            }
+
+            pub fn data_size(self, size: u64) -> Self {
+                #filter_name(self.0.data_size(size))
+            }
+
+            pub fn build(self) -> Vec<::fankor::prelude::solana_client::rpc_filter::RpcFilterType> {
+                self.0.build()
+            }
+        }
+    };
+
+    Ok(result)
+}
@@ -0,0 +1,68 @@
+use quote::{quote, ToTokens};
+use syn::{Fields, Item};
+
+/// Hashes the serialized layout of an account: the names and types of its
+/// fields and, for enums, the names and discriminants of its variants. Docs
+/// and other attributes do not change the hash.
+pub fn layout_hash(input: &Item, pod: bool) -> u64 {
+    let mut layout = String::new();
+
+    if pod {
+        layout.push_str("pod;");
+    }
+
+    match input {
+        Item::Struct(item) => push_fields(&mut layout, &item.fields),
+        Item::Enum(item) => {
+            for variant in &item.variants {
+                layout.push_str(&variant.ident.to_string());
+
+                for attribute in &variant.attrs {
+                    if attribute.path().is_ident("discriminant") {
+                        layout.push_str(&attribute.to_token_stream().to_string());
+                    }
+                }
+
+                if let Some((_, discriminant)) = &variant.discriminant {
+                    layout.push('=');
+                    layout.push_str(&discriminant.to_token_stream().to_string());
+                }
+
+                push_fields(&mut layout, &variant.fields);
+                layout.push(';');
+            }
+        }
+        _ => {}
+    }
+
+    fnv1a(layout.as_bytes())
+}
+
+fn push_fields(layout: &mut String, fields: &Fields) {
+    layout.push('{');
+
+    for field in fields {
+        if field.attrs.iter().any(|v| v.path().is_ident("borsh_skip")) {
+            continue;
+        }
+
+        if let Some(name) = &field.ident {
+            layout.push_str(&name.to_string());
+            layout.push(':');
+        }
+
+        let ty = &field.ty;
+        layout.push_str(&quote!(#ty).to_string().replace(' ', ""));
+        layout.push(',');
+    }
+
+    layout.push('}');
+}
+
+/// 64-bit FNV-1a. Unlike the std hashers, it is stable across compiler
+/// versions so the hash only changes with the layout.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, v| {
+        (hash ^ *v as u64).wrapping_mul(0x100000001b3)
+    })
+}
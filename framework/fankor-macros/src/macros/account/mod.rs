@@ -1,19 +1,39 @@
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use syn::spanned::Spanned;
-use syn::{Error, Item};
+use syn::{parse_quote, Error, Fields, Item, LitByteStr};
 
 use crate::fnk_syn::FnkMetaArgumentList;
 use crate::macros::account::arguments::AccountArguments;
+use crate::macros::account::builder::builder;
+use crate::macros::account::client::client;
+use crate::macros::account::layout_hash::layout_hash;
+use crate::macros::account::offsets::{field_readers, offsets};
 use crate::macros::account::ts_gen::ts_gen;
+use crate::utils::parse_derive_attributes;
 use crate::Result;
 
 mod arguments;
+mod builder;
+mod client;
+mod layout_hash;
+mod offsets;
 mod ts_gen;
 
-pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::TokenStream> {
+pub fn processor(args: FnkMetaArgumentList, mut input: Item) -> Result<proc_macro::TokenStream> {
     // Process arguments.
     let arguments = AccountArguments::from(args)?;
 
+    // The filter derives are removed because they are not a helper attribute
+    // of any derive macro.
+    let mut filter_derives = Vec::new();
+    if let Item::Struct(syn::ItemStruct { attrs, .. }) | Item::Enum(syn::ItemEnum { attrs, .. }) =
+        &mut input
+    {
+        filter_derives = parse_derive_attributes(attrs, "filter_derive")?;
+        attrs.retain(|v| !v.path().is_ident("filter_derive"));
+    }
+
     // Process input.
     let (name, generics, item, is_enum) = match &input {
         Item::Struct(item) => (&item.ident, &item.generics, quote! { #item }, false),
@@ -26,11 +46,68 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         }
     };
 
+    if arguments.pod && (is_enum || !generics.params.is_empty()) {
+        return Err(Error::new(
+            input.span(),
+            "pod accounts must be structs without generics",
+        ));
+    }
+
+    if arguments.singleton && !generics.params.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "singleton accounts cannot have generics",
+        ));
+    }
+
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    let legacy_discriminants = arguments.legacy_discriminants.as_ref().map(|v| {
+        quote! {
+            fn legacy_discriminants() -> &'static [u8] {
+                &#v
+            }
+        }
+    });
+
     let accounts_name = &arguments.accounts_type_name;
     let account_discriminants_name = format_ident!("{}Discriminant", accounts_name);
-    let ts_gen = ts_gen(&input)?;
+    // Pod accounts keep their value aligned to 8 bytes after the discriminant.
+    let data_offset = if arguments.pod { 8 } else { 1 };
+    let ts_gen = ts_gen(
+        &input,
+        data_offset,
+        &account_discriminants_name,
+        arguments.pod,
+        arguments.singleton,
+    )?;
+    let client = client(&input, &filter_derives, data_offset)?;
+    let offsets = offsets(&input, data_offset);
+    // Pod accounts are already read without deserializing them.
+    let field_readers = if arguments.pod {
+        quote! {}
+    } else {
+        field_readers(&input, data_offset)
+    };
+    let builder = builder(&input);
+    let layout_hash = layout_hash(&input, arguments.pod);
+    let singleton = if arguments.singleton {
+        let seed = LitByteStr::new(name.to_string().as_bytes(), name.span());
+
+        quote! {
+            #[automatically_derived]
+            impl ::fankor::traits::SingletonAccountType for #name {
+                const SINGLETON_SEED: &'static [u8] = #seed;
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let canonical = if arguments.canonical {
+        canonical(&input)
+    } else {
+        quote! {}
+    };
 
     let enum_discriminant_attr = if is_enum {
         quote! {
@@ -42,10 +119,18 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         quote! {}
     };
 
+    let item = if arguments.pod {
+        pod(&input, item, &account_discriminants_name)
+    } else {
+        quote! {
+            #enum_discriminant_attr
+            #[derive(FankorSerialize, FankorDeserialize, FankorZeroCopy, TsGen)]
+            #[fankor(account = #account_discriminants_name)]
+            #item
+        }
+    };
+
     let result = quote! {
-        #enum_discriminant_attr
-        #[derive(FankorSerialize, FankorDeserialize, FankorZeroCopy, TsGen)]
-        #[fankor(account = #account_discriminants_name)]
         #item
 
         #[automatically_derived]
@@ -57,10 +142,106 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
              fn owner() -> &'static Pubkey {
                 &crate::ID
             }
+
+            #legacy_discriminants
+
+            fn layout_hash() -> u64 {
+                #layout_hash
+            }
         }
 
+        #offsets
+
+        #field_readers
+
+        #builder
+
+        #singleton
+
+        #canonical
+
+        #client
+
         #ts_gen
     };
 
     Ok(result.into())
 }
+
+/// Stores a pod account as its raw bytes instead of serializing it with Borsh.
+/// The struct must derive `Clone`, `Copy`, `Pod` and `Zeroable` from bytemuck.
+fn pod(input: &Item, item: TokenStream, account_discriminants_name: &Ident) -> TokenStream {
+    let name = match input {
+        Item::Struct(item) => &item.ident,
+        _ => unreachable!(),
+    };
+
+    let has_repr = match input {
+        Item::Struct(item) => item.attrs.iter().any(|v| v.path().is_ident("repr")),
+        _ => unreachable!(),
+    };
+    let repr_attr = if has_repr {
+        quote! {}
+    } else {
+        quote! { #[repr(C)] }
+    };
+
+    quote! {
+        #[derive(FankorZeroCopy, TsGen)]
+        #[fankor(account = #account_discriminants_name, pod)]
+        #repr_attr
+        #item
+
+        #[automatically_derived]
+        impl ::fankor::traits::PodAccountType for #name {}
+
+        #[automatically_derived]
+        impl ::fankor::prelude::BorshSerialize for #name {
+            fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+                ::fankor::traits::serialize_pod_account(self, writer)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::fankor::prelude::BorshDeserialize for #name {
+            fn deserialize(buf: &mut &[u8]) -> ::std::io::Result<Self> {
+                ::fankor::traits::deserialize_pod_account(buf)
+            }
+        }
+    }
+}
+
+/// Implements `CanonicalSerialize` requiring all serialized fields to be
+/// canonical too, so floats or unordered collections fail to compile.
+fn canonical(input: &Item) -> TokenStream {
+    let (name, generics, fields) = match input {
+        Item::Struct(item) => (&item.ident, &item.generics, vec![&item.fields]),
+        Item::Enum(item) => (
+            &item.ident,
+            &item.generics,
+            item.variants.iter().map(|v| &v.fields).collect(),
+        ),
+        _ => unreachable!(),
+    };
+
+    let mut generics = generics.clone();
+    let where_clause = generics.make_where_clause();
+
+    for field in fields.into_iter().flat_map(Fields::iter) {
+        if field.attrs.iter().any(|v| v.path().is_ident("borsh_skip")) {
+            continue;
+        }
+
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: ::fankor::traits::CanonicalSerialize });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::fankor::traits::CanonicalSerialize for #name #ty_generics #where_clause {}
+    }
+}
@@ -0,0 +1,229 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Expr, Fields, Ident, Item, Lit, Type};
+
+/// The fields of a struct account whose byte offset is statically known, i.e.
+/// all the previous fields are fixed-size types. The first field starts at
+/// `data_offset`, after the discriminant and the padding of pod accounts.
+pub fn static_offsets(input: &Item, data_offset: usize) -> Vec<(&Ident, usize)> {
+    let mut result = Vec::new();
+
+    let fields = match input {
+        Item::Struct(item) => match &item.fields {
+            Fields::Named(v) => &v.named,
+            _ => return result,
+        },
+        _ => return result,
+    };
+
+    let mut offset = data_offset;
+
+    for field in fields {
+        result.push((field.ident.as_ref().unwrap(), offset));
+
+        match static_size(&field.ty) {
+            Some(size) => offset += size,
+            None => break,
+        }
+    }
+
+    result
+}
+
+/// The byte layout of the fields of a struct account: name, offset, size and
+/// type. The offset is `None` after the first field whose size is not fixed,
+/// and the size is `None` for those fields.
+pub fn field_layouts(
+    input: &Item,
+    data_offset: usize,
+) -> Vec<(&Ident, Option<usize>, Option<usize>, String)> {
+    let mut result = Vec::new();
+
+    let fields = match input {
+        Item::Struct(item) => match &item.fields {
+            Fields::Named(v) => &v.named,
+            _ => return result,
+        },
+        _ => return result,
+    };
+
+    let mut offset = Some(data_offset);
+
+    for field in fields {
+        let ty = &field.ty;
+        let size = static_size(ty);
+        let type_name = quote!(#ty).to_string().replace(' ', "");
+
+        result.push((field.ident.as_ref().unwrap(), offset, size, type_name));
+
+        offset = match (offset, size) {
+            (Some(offset), Some(size)) => Some(offset + size),
+            _ => None,
+        };
+    }
+
+    result
+}
+
+/// Generates the `OFFSET_<FIELD>` constants of an account.
+pub fn offsets(input: &Item, data_offset: usize) -> TokenStream {
+    let (name, generics) = match input {
+        Item::Struct(item) => (&item.ident, &item.generics),
+        _ => return quote! {},
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let constants = static_offsets(input, data_offset).into_iter().map(|(field, offset)| {
+        let const_name = offset_const_name(field);
+        let doc = format!(
+            "The byte offset of the `{}` field in the account data.",
+            field
+        );
+
+        quote! {
+            #[doc = #doc]
+            pub const #const_name: usize = #offset;
+        }
+    });
+
+    let layouts = field_layouts(input, data_offset)
+        .into_iter()
+        .map(|(field, offset, size, type_name)| {
+            let field = field.to_string();
+            let field = field.trim_start_matches("r#");
+            let offset = option_to_tokens(offset);
+            let size = option_to_tokens(size);
+
+            quote! {
+                ::fankor::models::AccountFieldLayout {
+                    name: #field,
+                    type_name: #type_name,
+                    offset: #offset,
+                    size: #size,
+                }
+            }
+        });
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#constants)*
+
+            /// The byte layout of the fields of the account, excluding the
+            /// discriminant.
+            pub fn field_layout() -> &'static [::fankor::models::AccountFieldLayout] {
+                &[#(#layouts),*]
+            }
+        }
+    }
+}
+
+/// Generates the `read_field_<field>` methods of an account, that read a
+/// field with a static offset without deserializing the whole account.
+pub fn field_readers(input: &Item, data_offset: usize) -> TokenStream {
+    let (name, generics, fields) = match input {
+        Item::Struct(item) => match &item.fields {
+            Fields::Named(v) => (&item.ident, &item.generics, &v.named),
+            _ => return quote! {},
+        },
+        _ => return quote! {},
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let readers = static_offsets(input, data_offset)
+        .into_iter()
+        .zip(fields)
+        .map(|((field, _), v)| {
+            let ty = &v.ty;
+            let const_name = offset_const_name(field);
+            let field_str = field.to_string();
+            let field_str = field_str.trim_start_matches("r#");
+            let method_name = format_ident!("read_field_{}", field_str);
+            let doc = format!(
+                "Reads the `{}` field from `info` without deserializing the whole account.",
+                field_str
+            );
+
+            quote! {
+                #[doc = #doc]
+                pub fn #method_name(info: &::fankor::prelude::AccountInfo) -> ::fankor::errors::FankorResult<#ty>
+                where
+                    Self: ::fankor::traits::AccountType,
+                    #ty: ::fankor::prelude::BorshDeserialize,
+                {
+                    ::fankor::traits::read_account_field::<Self, #ty>(info, Self::#const_name)
+                }
+            }
+        });
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#readers)*
+        }
+    }
+}
+
+/// The name of the offset constant of a field.
+pub fn offset_const_name(field: &Ident) -> Ident {
+    let field = field.to_string();
+    let field = field.trim_start_matches("r#");
+
+    format_ident!("OFFSET_{}", field.to_uppercase())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn option_to_tokens(value: Option<usize>) -> TokenStream {
+    match value {
+        Some(v) => quote! { Some(#v) },
+        None => quote! { None },
+    }
+}
+
+/// The serialized size of a type if it is fixed-size and known by its name.
+fn static_size(ty: &Type) -> Option<usize> {
+    match ty {
+        Type::Path(v) => {
+            if v.qself.is_some() {
+                return None;
+            }
+
+            let last = v.path.segments.last()?;
+
+            if !last.arguments.is_empty() {
+                return None;
+            }
+
+            let size = match last.ident.to_string().as_str() {
+                "u8" | "i8" | "bool" => 1,
+                "u16" | "i16" => 2,
+                "u32" | "i32" | "f32" => 4,
+                "u64" | "i64" | "f64" => 8,
+                "u128" | "i128" => 16,
+                "Pubkey" => 32,
+                _ => return None,
+            };
+
+            Some(size)
+        }
+        Type::Array(v) => {
+            let len = match &v.len {
+                Expr::Lit(v) => match &v.lit {
+                    Lit::Int(v) => v.base10_parse::<usize>().ok()?,
+                    _ => return None,
+                },
+                _ => return None,
+            };
+
+            static_size(&v.elem)?.checked_mul(len)
+        }
+        Type::Tuple(v) => v.elems.iter().map(static_size).sum(),
+        Type::Paren(v) => static_size(&v.elem),
+        _ => None,
+    }
+}
@@ -1,10 +1,18 @@
-use proc_macro2::TokenStream;
+use convert_case::{Case, Converter};
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
-use syn::Item;
+use syn::{Error, Item};
 
+use crate::macros::account::offsets::{field_layouts, offset_const_name, static_offsets};
 use crate::Result;
 
-pub fn ts_gen(input: &Item) -> Result<TokenStream> {
+pub fn ts_gen(
+    input: &Item,
+    data_offset: usize,
+    account_discriminants: &Ident,
+    pod: bool,
+    singleton: bool,
+) -> Result<TokenStream> {
     // Process input.
     let name = match &input {
         Item::Struct(item) => &item.ident,
@@ -13,9 +21,17 @@ pub fn ts_gen(input: &Item) -> Result<TokenStream> {
     };
 
     let name_str = name.to_string();
+    let ts_offsets = static_offsets(input, data_offset)
+        .into_iter()
+        .map(|(field, offset)| {
+            format!("export const {} = {};", offset_const_name(field), offset)
+        })
+        .collect::<Vec<_>>();
 
     let type_extension = format!(
         "export namespace {} {{
+            {}
+
             export async function fetchAccountByAddress(
                 connection: solana.Connection,
                 address: solana.PublicKey
@@ -72,11 +88,46 @@ pub fn ts_gen(input: &Item) -> Result<TokenStream> {
             }}
         }}",
         name_str,
+        ts_offsets.join("\n"),
         name_str,
         name_str,
         name_str,
     );
 
+    let register_layout = match input {
+        Item::Struct(_) => quote! {
+            action_context.add_account_layout(#name_str, #name::field_layout()).unwrap();
+        },
+        _ => quote! {},
+    };
+
+    let register_view = if pod {
+        let view_name = format!("{}View", name_str);
+        let view = pod_view(input, &view_name, account_discriminants)?;
+
+        quote! {
+            action_context.add_account_type_extensions(#view_name, std::borrow::Cow::Borrowed(#view)).unwrap();
+        }
+    } else {
+        quote! {}
+    };
+
+    let register_singleton = if singleton {
+        let const_name = format!(
+            "{}_ADDRESS",
+            Converter::new()
+                .from_case(Case::Pascal)
+                .to_case(Case::UpperSnake)
+                .convert(&name_str)
+        );
+
+        quote! {
+            action_context.add_constant(#const_name, <#name as ::fankor::traits::SingletonAccountType>::singleton_address().0).unwrap();
+        }
+    } else {
+        quote! {}
+    };
+
     let test_name = format_ident!("__ts_gen_test__account_ext_{}", name_str);
     let test_name_str = test_name.to_string();
     let result = quote! {
@@ -91,6 +142,10 @@ pub fn ts_gen(input: &Item) -> Result<TokenStream> {
                  // Register action.
                 crate::__ts_gen_test__setup::BUILD_CONTEXT.register_action(#test_name_str, file!(), move |action_context| {
                     action_context.add_account_type_extensions(#test_name_str, std::borrow::Cow::Borrowed(#type_extension)).unwrap();
+                    action_context.add_program_account(#name_str, #account_discriminants::#name.code()).unwrap();
+                    #register_layout
+                    #register_view
+                    #register_singleton
                 })
             }
         }
@@ -98,3 +153,150 @@ pub fn ts_gen(input: &Item) -> Result<TokenStream> {
 
     Ok(result)
 }
+
+/// Generates the TypeScript class that reads and writes the fields of a pod
+/// account directly from its data, without deserializing it.
+fn pod_view(input: &Item, view_name: &str, discriminants: &Ident) -> Result<String> {
+    let case_converter = Converter::new().from_case(Case::Snake).to_case(Case::Camel);
+    let name = match input {
+        Item::Struct(item) => &item.ident,
+        _ => unreachable!(),
+    };
+
+    let mut account_size = 8;
+    let mut accessors = Vec::new();
+
+    for (field, offset, size, type_name) in field_layouts(input, 8) {
+        let (offset, size) = match (offset, size) {
+            (Some(offset), Some(size)) => (offset, size),
+            _ => {
+                return Err(Error::new(
+                    field.span(),
+                    "The fields of a pod account must be numbers, Pubkeys or arrays of them",
+                ));
+            }
+        };
+
+        account_size = offset + size;
+
+        let field_name = field.to_string();
+        let field_name = case_converter.convert(field_name.trim_start_matches("r#"));
+        let end = offset + size;
+        let type_name = type_name.rsplit("::").next().unwrap();
+
+        let accessor = match type_name {
+            "u8" | "i8" => {
+                let method = if type_name == "u8" { "Uint8" } else { "Int8" };
+
+                format!(
+                    "get {0}(): number {{
+                        return this.view.get{1}({2});
+                    }}
+
+                    set {0}(value: number) {{
+                        this.view.set{1}({2}, value);
+                    }}",
+                    field_name, method, offset
+                )
+            }
+            "u16" | "i16" | "u32" | "i32" | "f32" | "f64" => {
+                let method = match type_name {
+                    "u16" => "Uint16",
+                    "i16" => "Int16",
+                    "u32" => "Uint32",
+                    "i32" => "Int32",
+                    "f32" => "Float32",
+                    _ => "Float64",
+                };
+
+                format!(
+                    "get {0}(): number {{
+                        return this.view.get{1}({2}, true);
+                    }}
+
+                    set {0}(value: number) {{
+                        this.view.set{1}({2}, value, true);
+                    }}",
+                    field_name, method, offset
+                )
+            }
+            "u64" | "u128" => format!(
+                "get {0}(): BN {{
+                    return new BN(this.data.subarray({1}, {2}), 'le');
+                }}
+
+                set {0}(value: BN) {{
+                    this.data.set(value.toArrayLike(Buffer, 'le', {3}), {1});
+                }}",
+                field_name, offset, end, size
+            ),
+            "i64" | "i128" => format!(
+                "get {0}(): BN {{
+                    return new BN(this.data.subarray({1}, {2}), 'le').fromTwos({4});
+                }}
+
+                set {0}(value: BN) {{
+                    this.data.set(value.toTwos({4}).toArrayLike(Buffer, 'le', {3}), {1});
+                }}",
+                field_name,
+                offset,
+                end,
+                size,
+                size * 8
+            ),
+            "Pubkey" => format!(
+                "get {0}(): solana.PublicKey {{
+                    return new solana.PublicKey(this.data.subarray({1}, {2}));
+                }}
+
+                set {0}(value: solana.PublicKey) {{
+                    this.data.set(value.toBytes(), {1});
+                }}",
+                field_name, offset, end
+            ),
+            // Arrays are exposed as their raw bytes, which are shared with the
+            // account data.
+            _ => format!(
+                "get {0}(): Uint8Array {{
+                    return this.data.subarray({1}, {2});
+                }}",
+                field_name, offset, end
+            ),
+        };
+
+        accessors.push(accessor);
+    }
+
+    Ok(format!(
+        "export class {0} {{
+            static readonly SIZE = {1};
+
+            readonly data: Uint8Array;
+            readonly view: DataView;
+
+            // CONSTRUCTORS -----------------------------------------------------------
+
+            constructor(data: Uint8Array) {{
+                if (data.length < {0}.SIZE) {{
+                    throw new Error('Invalid size for {2}');
+                }}
+
+                if (data[0] !== {3}.{2}) {{
+                    throw new Error('Invalid discriminant');
+                }}
+
+                this.data = data;
+                this.view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+            }}
+
+            // GETTERS ----------------------------------------------------------------
+
+            {4}
+        }}",
+        view_name,
+        account_size,
+        name,
+        discriminants,
+        accessors.join("\n\n"),
+    ))
+}
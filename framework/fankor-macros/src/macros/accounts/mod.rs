@@ -30,6 +30,7 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
     let arguments = AccountsArguments::from(args)?;
 
     let name = enum_item.ident;
+    let name_str = name.to_string();
     let discriminant_name = format_ident!("{}Discriminant", name);
 
     assert!(
@@ -81,6 +82,7 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
     let mut deserialize_entries = Vec::with_capacity(variants.len());
     let mut discriminants_as_list = Vec::with_capacity(variants.len());
     let mut variant_consts = Vec::with_capacity(variants.len());
+    let mut registry_entries = Vec::with_capacity(variants.len());
     for variant in &variants {
         let AccountVariant {
             name: variant_name,
@@ -182,6 +184,15 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
             #discriminant_name::#variant_name.code()
         });
 
+        let variant_name_str = variant_name.to_string();
+        registry_entries.push(quote! {
+            ::fankor::account_registry::AccountRegistryEntry {
+                name: #variant_name_str.to_string(),
+                discriminant: #discriminant_name::#variant_name.code(),
+                layout_hash: <#variant_name as ::fankor::traits::AccountType>::layout_hash(),
+            }
+        });
+
         // Insert discriminant.
         used_discriminants.insert(const_name);
     }
@@ -261,7 +272,24 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
             })
             .collect::<Vec<_>>()
     } else {
-        Vec::new()
+        // The tombstone discriminant is reserved for accounts marked for close
+        // and the others for the PDAs managed by the framework.
+        let reserved_discriminants = [
+            quote! { ::fankor::models::TOMBSTONE_DISCRIMINANT },
+            quote! { ::fankor::fees::FEE_CONFIG_DISCRIMINANT },
+            quote! { ::fankor::idempotency::IDEMPOTENCY_RECORD_DISCRIMINANT },
+        ];
+
+        discriminants_as_list
+            .iter()
+            .flat_map(|v| {
+                reserved_discriminants.iter().map(move |reserved| {
+                    quote! {
+                        const_assert!(#v != #reserved);
+                    }
+                })
+            })
+            .collect::<Vec<_>>()
     };
 
     let result = quote! {
@@ -283,6 +311,14 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
             #(#as_ref_methods)*
 
             #(#as_mut_methods)*
+
+            /// The registry of the accounts of this set, stored on-chain by
+            /// the programs with `#[program(account_registry = ...)]`.
+            pub fn account_registry() -> ::fankor::account_registry::AccountRegistry {
+                ::fankor::account_registry::AccountRegistry {
+                    entries: vec![#(#registry_entries),*],
+                }
+            }
         }
 
         #(#from_methods)*
@@ -303,6 +339,24 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
             }
         }
 
+        #[automatically_derived]
+        #[cfg(feature = "client")]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Decodes the data of any account of this set dispatching on its discriminant.
+            pub fn decode(data: &[u8]) -> ::fankor::errors::FankorResult<Self> {
+                match data.first() {
+                    Some(discriminant) if <Self as ::fankor::traits::AccountType>::check_discriminant(*discriminant) => {
+                        let mut buf = data;
+                        Ok(<Self as ::fankor::prelude::BorshDeserialize>::deserialize(&mut buf)?)
+                    }
+                    _ => Err(::fankor::errors::FankorErrorCode::AccountDiscriminantMismatch {
+                        account: #name_str.to_string(),
+                    }
+                    .into()),
+                }
+            }
+        }
+
         #enum_discriminants
     };
 
@@ -54,7 +54,9 @@ pub fn enum_de(input: &ItemEnum, crate_name: Ident) -> syn::Result<TokenStream2>
         let message = format!("Invalid discriminant for enum variant {}", name);
         quote! {
             let discriminant:u8 = #crate_name::BorshDeserialize::deserialize(buf)?;
-            if discriminant != #account_discriminants::#name.code() {
+            if discriminant != #account_discriminants::#name.code()
+                && !<Self as ::fankor::traits::AccountType>::legacy_discriminants().contains(&discriminant)
+            {
                 return Err(
                     std::io::Error::new(std::io::ErrorKind::Other, #message)
                 );
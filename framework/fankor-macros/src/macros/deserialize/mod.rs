@@ -6,6 +6,8 @@ use crate::macros::deserialize::enums::enum_de;
 use crate::macros::deserialize::structs::struct_de;
 use crate::Result;
 
+pub use structs::parse_field_arguments;
+
 mod enums;
 mod structs;
 
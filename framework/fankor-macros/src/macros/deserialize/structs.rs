@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use syn::spanned::Spanned;
-use syn::{Error, Fields, Ident, ItemStruct};
+use syn::{Attribute, Error, Fields, Ident, ItemStruct};
 
 use crate::fnk_syn::FnkMetaArgumentList;
 use crate::macros::deserialize::enums::{contains_initialize_with, contains_skip};
@@ -41,7 +41,9 @@ pub fn struct_de(input: &ItemStruct, crate_name: Ident) -> syn::Result<TokenStre
         let message = format!("Invalid discriminant for enum variant {}", name);
         quote! {
             let discriminant:u8 = #crate_name::BorshDeserialize::deserialize(buf)?;
-            if discriminant != #account_discriminants::#name.code() {
+            if discriminant != #account_discriminants::#name.code()
+                && !<Self as ::fankor::traits::AccountType>::legacy_discriminants().contains(&discriminant)
+            {
                 return Err(
                     std::io::Error::new(std::io::ErrorKind::Other, #message)
                 );
@@ -56,13 +58,32 @@ pub fn struct_de(input: &ItemStruct, crate_name: Ident) -> syn::Result<TokenStre
     let return_value = match &input.fields {
         Fields::Named(fields) => {
             let mut body = TokenStream2::new();
+            let mut any_default = false;
             for field in &fields.named {
                 let field_name = field.ident.as_ref().unwrap();
+                let is_default = contains_default(&field.attrs)?;
+
+                if any_default && !is_default {
+                    return Err(Error::new(
+                        field.span(),
+                        "Only trailing fields can have the default attribute",
+                    ));
+                }
+
+                any_default |= is_default;
 
                 let delta = if contains_skip(&field.attrs) {
                     quote! {
                         #field_name: Default::default(),
                     }
+                } else if is_default {
+                    quote! {
+                        #field_name: if buf.is_empty() {
+                            Default::default()
+                        } else {
+                            #crate_name::BorshDeserialize::deserialize(buf)?
+                        },
+                    }
                 } else {
                     quote! {
                         #field_name: #crate_name::BorshDeserialize::deserialize(buf)?,
@@ -114,3 +135,49 @@ pub fn struct_de(input: &ItemStruct, crate_name: Ident) -> syn::Result<TokenStre
         }
     })
 }
+
+/// Checks whether the field has the `#[fankor(default)]` attribute, i.e. it
+/// takes its default value when the data ends before it.
+pub fn contains_default(attrs: &[Attribute]) -> syn::Result<bool> {
+    Ok(parse_field_arguments(attrs)?.default)
+}
+
+/// The arguments of the `#[fankor(...)]` attribute of a field.
+#[derive(Default)]
+pub struct FieldArguments {
+    /// The field can be omitted at the end of the data.
+    pub default: bool,
+
+    /// The field is hidden in the TypeScript types.
+    pub ts_skip: bool,
+
+    /// The field is exposed as raw bytes in the TypeScript types.
+    pub ts_opaque: bool,
+}
+
+/// Parses the arguments of all the `#[fankor(...)]` attributes of a field.
+pub fn parse_field_arguments(attrs: &[Attribute]) -> syn::Result<FieldArguments> {
+    let mut result = FieldArguments::default();
+
+    for attr in attrs {
+        if attr.path().is_ident("fankor") {
+            let mut args = attr.parse_args::<FnkMetaArgumentList>()?;
+            args.error_on_duplicated()?;
+
+            result.default |= args.pop_plain("default", true)?;
+            result.ts_skip |= args.pop_plain("ts_skip", true)?;
+            result.ts_opaque |= args.pop_plain("ts_opaque", true)?;
+
+            if result.ts_skip && result.ts_opaque {
+                return Err(Error::new(
+                    attr.span(),
+                    "The ts_skip and ts_opaque arguments are incompatible",
+                ));
+            }
+
+            args.error_on_unknown()?;
+        }
+    }
+
+    Ok(result)
+}
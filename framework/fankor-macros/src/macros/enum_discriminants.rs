@@ -21,6 +21,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
 
             let mut fields = Vec::new();
             let mut codes = Vec::new();
+            let mut from_codes = Vec::new();
             let mut discriminants = Vec::new();
             let mut variant_idx = 0u8;
             let mut used_discriminants = HashSet::new();
@@ -90,6 +91,10 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                     Self::#variant_ident => #variant_idx
                 ));
 
+                from_codes.push(quote!(
+                    #variant_idx => Some(Self::#variant_ident)
+                ));
+
                 variant_idx += 1;
                 is_last_deprecated = is_deprecated;
             }
@@ -111,6 +116,13 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                             #(#codes,)*
                         }
                     }
+
+                    pub const fn from_code(code: u8) -> Option<Self> {
+                        match code {
+                            #(#from_codes,)*
+                            _ => None,
+                        }
+                    }
                 }
 
                 #[automatically_derived]
@@ -207,6 +207,39 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         }
     };
 
+    let docs_test = if attributes.skip_ts_gen {
+        quote! {}
+    } else {
+        let docs_errors = variants.iter().map(|v| {
+            let variant_name = &v.name;
+            let variant_name_str = variant_name.to_string();
+            let message = v.message_template.clone().unwrap_or_default();
+
+            quote! {
+                action_context.add_program_error(#variant_name_str, #discriminant_name::#variant_name.code(), #message).unwrap();
+            }
+        });
+        let test_name = format_ident!("__ts_gen_test__error_docs_{}", name);
+        let test_name_str = test_name.to_string();
+
+        quote! {
+            #[cfg(feature = "ts-gen")]
+            #[automatically_derived]
+            #[allow(non_snake_case)]
+            mod #test_name {
+                use super::*;
+
+                #[test]
+                fn build() {
+                    // Register action.
+                    crate::__ts_gen_test__setup::BUILD_CONTEXT.register_action(#test_name_str, file!(), move |action_context| {
+                        #(#docs_errors)*
+                    })
+                }
+            }
+        }
+    };
+
     let result = quote! {
         #[derive(::std::fmt::Debug, ::std::clone::Clone)]
         #[repr(u32)]
@@ -276,6 +309,8 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
                 }
             }
         }
+
+        #docs_test
     };
 
     Ok(result.into())
@@ -9,6 +9,7 @@ use crate::Result;
 pub struct ErrorVariant {
     pub name: Ident,
     pub message: Option<TokenStream>,
+    pub message_template: Option<String>,
     pub attributes: Vec<Attribute>,
     pub fields: Fields,
     pub code: Option<u32>,
@@ -28,6 +29,7 @@ impl ErrorVariant {
         let mut error_variant = ErrorVariant {
             name: variant.ident,
             message: None,
+            message_template: None,
             attributes: variant.attrs,
             fields: variant.fields,
             code,
@@ -70,9 +72,9 @@ impl ErrorVariant {
                 // Check first argument is a literal string.
                 let expr_list: Punctuated<Expr, Token![,]> = parse_quote! { #args };
 
-                match expr_list.first() {
+                let template = match expr_list.first() {
                     Some(Expr::Lit(v)) => match &v.lit {
-                        Lit::Str(_) => {}
+                        Lit::Str(v) => v.value(),
                         v => {
                             return Err(Error::new(v.span(), "This must be a literal string"));
                         }
@@ -83,9 +85,10 @@ impl ErrorVariant {
                             "First attribute must be a literal string",
                         ));
                     }
-                }
+                };
 
                 self.message = Some(args.clone());
+                self.message_template = Some(template);
             } else if attribute_path.is_ident("deprecated") {
                 let attribute_span = attribute.span();
 
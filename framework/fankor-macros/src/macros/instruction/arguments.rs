@@ -1,13 +1,22 @@
-use proc_macro2::TokenStream;
-use quote::ToTokens;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{Error, Expr};
 
 use crate::fnk_syn::FnkMetaArgumentList;
+use crate::utils::unwrap_ident_from_expr;
 use crate::Result;
 
 pub struct InstructionArguments {
     pub initial_validation: Option<Validation>,
     pub final_validation: Option<Validation>,
     pub phantom: bool,
+    pub max_data_len: Option<usize>,
+    pub max_accounts: Option<usize>,
+    pub collect_fee: Option<FeeCollection>,
+    pub fee_base: Option<TokenStream>,
+    pub idempotent_by: Option<TokenStream>,
+    pub idempotency_scope: Option<Ident>,
 }
 
 pub enum Validation {
@@ -15,6 +24,13 @@ pub enum Validation {
     Explicit(TokenStream),
 }
 
+/// How the fee of `collect_fee` is paid.
+pub enum FeeCollection {
+    Lamports,
+    /// Tokens of the mint in the given field.
+    Token(Ident),
+}
+
 impl InstructionArguments {
     // CONSTRUCTORS -----------------------------------------------------------
 
@@ -22,6 +38,7 @@ impl InstructionArguments {
     pub fn from(mut args: FnkMetaArgumentList) -> Result<InstructionArguments> {
         args.error_on_duplicated()?;
 
+        let list_span = args.list_span;
         let result = InstructionArguments {
             initial_validation: {
                 match args.pop_element("initial_validation", true)? {
@@ -42,10 +59,88 @@ impl InstructionArguments {
                 }
             },
             phantom: args.pop_plain("phantom", true)?,
+            max_data_len: args.pop_number("max_data_len", true)?,
+            max_accounts: args.pop_number("max_accounts", true)?,
+            collect_fee: match args.pop("collect_fee", Some(true), true)? {
+                Some(v) => Some(parse_fee_collection(v.value.unwrap())?),
+                None => None,
+            },
+            fee_base: args
+                .pop("fee_base", Some(true), true)?
+                .map(|v| v.value.unwrap().to_token_stream()),
+            idempotent_by: match args.pop("idempotent_by", Some(true), true)? {
+                Some(v) => Some(parse_idempotent_by(v.value.unwrap())?),
+                None => None,
+            },
+            idempotency_scope: args.pop_ident("idempotency_scope", true)?,
         };
 
         args.error_on_unknown()?;
 
+        if result.collect_fee.is_some() != result.fee_base.is_some() {
+            return Err(Error::new(
+                list_span,
+                "The collect_fee and fee_base attributes must be used together",
+            ));
+        }
+
+        if result.idempotent_by.is_some() != result.idempotency_scope.is_some() {
+            return Err(Error::new(
+                list_span,
+                "The idempotent_by and idempotency_scope attributes must be used together",
+            ));
+        }
+
         Ok(result)
     }
 }
+
+/// Parses the nonce of `idempotent_by`, a path to a field of the instruction
+/// with or without the leading `self`, e.g. `args.nonce`, into an expression
+/// relative to `self`.
+fn parse_idempotent_by(value: Expr) -> Result<TokenStream> {
+    let mut base = &value;
+    while let Expr::Field(field) = base {
+        base = &field.base;
+    }
+
+    match base {
+        Expr::Path(path) if path.path.is_ident("self") => Ok(value.to_token_stream()),
+        Expr::Path(_) => Ok(quote! { self.#value }),
+        _ => Err(Error::new(
+            value.span(),
+            "The idempotent_by attribute must be a field of the instruction, e.g. args.nonce",
+        )),
+    }
+}
+
+fn parse_fee_collection(value: Expr) -> Result<FeeCollection> {
+    let span = value.span();
+    let error = || {
+        Error::new(
+            span,
+            "The collect_fee attribute must be: lamports or token(<mint field>)",
+        )
+    };
+
+    match value {
+        Expr::Path(_) => {
+            let ident = unwrap_ident_from_expr(value)?;
+            if ident != "lamports" {
+                return Err(error());
+            }
+
+            Ok(FeeCollection::Lamports)
+        }
+        Expr::Call(call) => {
+            let function = unwrap_ident_from_expr(*call.func)?;
+            if function != "token" || call.args.len() != 1 {
+                return Err(error());
+            }
+
+            let mint = unwrap_ident_from_expr(call.args.into_iter().next().unwrap())?;
+            Ok(FeeCollection::Token(mint))
+        }
+        _ => Err(error()),
+    }
+}
@@ -9,6 +9,28 @@ use crate::Result;
 pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_macro::TokenStream> {
     let arguments = InstructionArguments::from(args)?;
     let name = &item.ident;
+
+    if arguments.max_data_len.is_some() || arguments.max_accounts.is_some() {
+        return Err(syn::Error::new(
+            name.span(),
+            "The max_data_len and max_accounts attributes are only allowed in structs",
+        ));
+    }
+
+    if arguments.collect_fee.is_some() {
+        return Err(syn::Error::new(
+            name.span(),
+            "The collect_fee attribute is only allowed in structs",
+        ));
+    }
+
+    if arguments.idempotent_by.is_some() {
+        return Err(syn::Error::new(
+            name.span(),
+            "The idempotent_by attribute is only allowed in structs",
+        ));
+    }
+
     let name_str = name.to_string();
     let discriminant_name = format_ident!("{}Discriminant", name);
     let visibility = &item.vis;
@@ -22,6 +44,8 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
         .collect::<Result<Vec<Field>>>()?;
     check_fields(&mapped_fields)?;
 
+    let strict_mode_checks = mapped_fields.iter().map(|v| v.strict_mode_check(name));
+
     let mut final_enum_variants = Vec::with_capacity(mapped_fields.len());
     let mut try_from_method_deserialize = Vec::with_capacity(mapped_fields.len());
     let mut variant_consts = Vec::with_capacity(mapped_fields.len());
@@ -276,6 +300,8 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
             #(#final_enum_variants,)*
         }
 
+        #(#strict_mode_checks)*
+
         #[automatically_derived]
         impl #impl_generics ::fankor::traits::Instruction<'info> for #name #ty_generics #where_clause {
             type CPI = #cpi_name <'info>;
@@ -325,6 +351,12 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
 
                 #final_validation
 
+                // Custom validation if `Validate` is implemented.
+                {
+                    use ::fankor::traits::{ValidateImplemented, ValidateNotImplemented};
+                    (&::fankor::traits::ValidateWrapper(self)).__fankor_validate(context)?;
+                }
+
                 Ok(())
             }
         }
@@ -403,12 +435,15 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
     let mut metas_replacements = Vec::new();
     let mut ts_type_names = Vec::new();
     let mut metas_fields = Vec::new();
+    let mut decode_replacements = Vec::new();
+    let mut decode_fields = Vec::new();
     let ts_types = mapped_fields.iter().map(|v| {
         let variant_name = &v.name;
         let name = format!("{}_{}", name_str, v.name);
         let ty = &v.ty;
         let types_replacement_str = format!("_r_interface_types_{}_r_", name);
         let metas_replacement_str = format!("_r_interface_metas_{}_r_", name);
+        let decode_replacement_str = format!("_r_interface_decode_{}_r_", name);
 
         ts_type_names.push(name.clone());
 
@@ -420,10 +455,15 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
             metas_replacements.push(quote! {
                  .replace(#metas_replacement_str, &< #ty as TsInstructionGen>::get_external_account_metas(Cow::Owned(format!("{}.value", value)), false, false))
             });
+            decode_fields.push(format!("case {}.{}: _r_value_r_ = {{ type: '{}' }} as any; {} break;", discriminant_name, variant_name, v.name, decode_replacement_str));
+            decode_replacements.push(quote! {
+                 .replace(#decode_replacement_str, &< #ty as TsInstructionGen>::decode_external_account_metas(Cow::Owned(format!("{}.value", value))))
+            });
 
             format!("export interface {} {{ type: '{}', value: {} }}", name, v.name, types_replacement_str)
         } else {
             metas_fields.push(format!("case '{}': writer.writeByte({}.{}); break;", v.name, discriminant_name, variant_name));
+            decode_fields.push(format!("case {}.{}: _r_value_r_ = {{ type: '{}' }}; break;", discriminant_name, variant_name, v.name));
 
             format!("export interface {} {{ type: '{}' }}", name, v.name)
         }
@@ -442,8 +482,17 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
         metas_fields.join(""),
     );
 
+    let ts_decode = format!(
+        "switch (reader.readByte()) {{ {} default: throw new Error('Invalid account type'); }}",
+        decode_fields.join(""),
+    );
+
     let get_metas_of_replacement_str =
         format!("getMetasOf{}(_r_value_r_,accountMetas, writer);", name_str);
+    let decode_metas_of_replacement_str = format!(
+        "_r_value_r_ = decodeMetasOf{}(reader, accountMetas);",
+        name_str
+    );
     let test_name = format_ident!("__ts_gen_test__instruction_accounts_{}", name_str);
     let test_name_str = test_name.to_string();
     let result = quote! {
@@ -495,6 +544,14 @@ pub fn process_enum(args: FnkMetaArgumentList, item: ItemEnum) -> Result<proc_ma
                 ) -> Cow<'static, str> {
                     Cow::Owned(#get_metas_of_replacement_str.replace("_r_value_r_", &value))
                 }
+
+                fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+                    Cow::Owned(#ts_decode.replace("_r_value_r_", &value) #(#decode_replacements)*)
+                }
+
+                fn decode_external_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+                    Cow::Owned(#decode_metas_of_replacement_str.replace("_r_value_r_", &value))
+                }
             }
 
             #[test]
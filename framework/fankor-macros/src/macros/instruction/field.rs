@@ -4,12 +4,26 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    Attribute, Error, Expr, Fields, GenericArgument, PathArguments, Token, Type, Variant,
-    Visibility,
+    parenthesized, token, Attribute, Error, Expr, Fields, GenericArgument, PathArguments, Token,
+    Type, Variant, Visibility,
 };
 
+use crate::utils::unwrap_ident_from_expr;
 use crate::Result;
 
+/// The arguments allowed inside `each(...)`, i.e. those checked per element.
+const EACH_ARGUMENTS: &[&str] = &[
+    "owner",
+    "address",
+    "initialized",
+    "writable",
+    "executable",
+    "rent_exempt",
+    "signer",
+    "tombstoned",
+    "constraint",
+];
+
 pub struct Field {
     pub name: Ident,
     pub ty: Option<Type>,
@@ -21,13 +35,28 @@ pub struct Field {
     pub initialized: Option<TokenStream>,
     pub writable: Option<TokenStream>,
     pub executable: Option<TokenStream>,
+    pub program_data: Option<TokenStream>,
+    pub upgrade_authority: Option<TokenStream>,
+    pub rent_to: Option<Ident>,
     pub rent_exempt: Option<TokenStream>,
     pub signer: Option<TokenStream>,
+    pub tombstoned: Option<TokenStream>,
+    pub signers_at_least: Option<TokenStream>,
+    pub token_gate: Option<(TokenStream, TokenStream)>,
+    pub unique: bool,
+    pub min: Option<TokenStream>,
+    pub max: Option<TokenStream>,
+    pub max_mode: VecMaxMode,
+    pub count: VecCount,
     pub pda: Option<DataAndError>,
     pub pda_bytes: Option<DataAndError>,
     pub pda_program_id: Option<TokenStream>,
+    pub resolve: Option<TokenStream>,
+    pub resolve_with_client: Option<TokenStream>,
     pub constraints: Vec<DataAndError>,
+    pub validations: Vec<DataAndError>,
     pub data: Vec<Data>,
+    pub unchecked_reason: Option<TokenStream>,
     pub attrs: Vec<Attribute>,
 }
 
@@ -38,6 +67,28 @@ pub enum FieldKind {
     Rest,
 }
 
+/// How a `Vec` field with `max` handles the accounts beyond its maximum.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum VecMaxMode {
+    /// Fails with `VecAccountsTooLarge`.
+    Error,
+    /// Deserializes all the accounts but keeps only the first `max` ones.
+    Truncate,
+    /// Deserializes only the first `max` accounts and leaves the rest for the
+    /// `Rest` field that follows it.
+    Rest,
+}
+
+/// How the number of accounts of a `Vec` field is communicated.
+pub enum VecCount {
+    /// A length byte in the instruction data.
+    Length,
+    /// The accounts until the sentinel one, which is consumed too.
+    Sentinel(TokenStream),
+    /// All the remaining accounts, up to `max` if present.
+    Remaining,
+}
+
 pub struct DataAndError {
     pub data: TokenStream,
     pub error: Option<TokenStream>,
@@ -63,13 +114,28 @@ impl Field {
             initialized: None,
             writable: None,
             executable: None,
+            program_data: None,
+            upgrade_authority: None,
+            rent_to: None,
             rent_exempt: None,
             signer: None,
+            tombstoned: None,
+            signers_at_least: None,
+            token_gate: None,
+            unique: false,
+            min: None,
+            max: None,
+            max_mode: VecMaxMode::Error,
+            count: VecCount::Length,
             pda: None,
             pda_bytes: None,
             pda_program_id: None,
+            resolve: None,
+            resolve_with_client: None,
             constraints: Vec::new(),
+            validations: Vec::new(),
             data: Vec::new(),
+            unchecked_reason: None,
             attrs: Vec::new(),
         };
 
@@ -100,13 +166,28 @@ impl Field {
                     initialized: None,
                     writable: None,
                     executable: None,
+                    program_data: None,
+                    upgrade_authority: None,
+                    rent_to: None,
                     rent_exempt: None,
                     signer: None,
+                    tombstoned: None,
+                    signers_at_least: None,
+                    token_gate: None,
+                    unique: false,
+                    min: None,
+                    max: None,
+                    max_mode: VecMaxMode::Error,
+                    count: VecCount::Length,
                     pda: None,
                     pda_bytes: None,
                     pda_program_id: None,
+                    resolve: None,
+                    resolve_with_client: None,
                     constraints: Vec::new(),
+                    validations: Vec::new(),
                     data: Vec::new(),
+                    unchecked_reason: None,
                     attrs: Vec::new(),
                 };
 
@@ -125,13 +206,28 @@ impl Field {
                     initialized: None,
                     writable: None,
                     executable: None,
+                    program_data: None,
+                    upgrade_authority: None,
+                    rent_to: None,
                     rent_exempt: None,
                     signer: None,
+                    tombstoned: None,
+                    signers_at_least: None,
+                    token_gate: None,
+                    unique: false,
+                    min: None,
+                    max: None,
+                    max_mode: VecMaxMode::Error,
+                    count: VecCount::Length,
                     pda: None,
                     pda_bytes: None,
                     pda_program_id: None,
+                    resolve: None,
+                    resolve_with_client: None,
                     constraints: Vec::new(),
+                    validations: Vec::new(),
                     data: Vec::new(),
+                    unchecked_reason: None,
                     attrs: Vec::new(),
                 };
 
@@ -146,8 +242,34 @@ impl Field {
         }
     }
 
+    // METHODS ----------------------------------------------------------------
+
+    /// Generates a compile-time check that fails in programs with the strict
+    /// mode enabled if the field is unchecked and does not justify it with
+    /// `unchecked_reason`.
+    pub fn strict_mode_check(&self, container: &Ident) -> TokenStream {
+        let is_unchecked = self.ty.as_ref().is_some_and(is_unchecked_type);
+        if !is_unchecked || self.unchecked_reason.is_some() {
+            return quote! {};
+        }
+
+        let message = format!(
+            "Strict mode: {}.{} is an unchecked account, add #[account(unchecked_reason = \"...\")] to justify it",
+            container, self.name
+        );
+
+        quote! {
+            const _: () = assert!(!crate::__FANKOR_STRICT_MODE, #message);
+        }
+    }
+
     fn parse_attributes(&mut self, mut attrs: Vec<Attribute>, is_enum: bool) -> Result<()> {
         while let Some(attribute) = attrs.pop() {
+            if attribute.path().is_ident("argument") {
+                self.parse_argument_attribute(attribute, is_enum)?;
+                continue;
+            }
+
             if !attribute.path().is_ident("account") {
                 self.attrs.push(attribute);
                 continue;
@@ -164,8 +286,41 @@ impl Field {
                 }
             };
 
-            // Check each argument.
+            // Expand the per-element arguments.
+            let mut metas = Vec::with_capacity(args.list.len());
             for meta in args.list {
+                let each = match meta.each {
+                    Some(v) => v,
+                    None => {
+                        metas.push(meta);
+                        continue;
+                    }
+                };
+
+                if !matches!(self.kind, FieldKind::Vec(_)) {
+                    return Err(Error::new(
+                        meta.name.span(),
+                        "The each argument is only allowed in Vec fields",
+                    ));
+                }
+
+                for meta in each {
+                    if !EACH_ARGUMENTS.contains(&meta.name.to_string().as_str()) {
+                        return Err(Error::new(
+                            meta.name.span(),
+                            format!(
+                                "The each argument only accepts: {}",
+                                EACH_ARGUMENTS.join(", ")
+                            ),
+                        ));
+                    }
+
+                    metas.push(meta);
+                }
+            }
+
+            // Check each argument.
+            for meta in metas {
                 let name = meta.name;
                 if let Some(value) = meta.value {
                     match name.to_string().as_str() {
@@ -289,6 +444,78 @@ impl Field {
 
                             self.executable = Some(quote! {#value});
                         }
+                        "program_data" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The program_data argument is not allowed in enums",
+                                ));
+                            }
+
+                            if self.program_data.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The program_data argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The program_data argument cannot have an error field",
+                                ));
+                            }
+
+                            self.program_data = Some(quote! {#value});
+                        }
+                        "upgrade_authority" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The upgrade_authority argument is not allowed in enums",
+                                ));
+                            }
+
+                            if self.upgrade_authority.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The upgrade_authority argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The upgrade_authority argument cannot have an error field",
+                                ));
+                            }
+
+                            self.upgrade_authority = Some(quote! {#value});
+                        }
+                        "rent_to" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The rent_to argument is not allowed in enums",
+                                ));
+                            }
+
+                            if self.rent_to.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The rent_to argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The rent_to argument cannot have an error field",
+                                ));
+                            }
+
+                            self.rent_to = Some(unwrap_ident_from_expr(value)?);
+                        }
                         "rent_exempt" => {
                             if is_enum {
                                 return Err(Error::new(
@@ -337,6 +564,61 @@ impl Field {
 
                             self.signer = Some(quote! {#value});
                         }
+                        "tombstoned" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The tombstoned argument is not allowed in enums",
+                                ));
+                            }
+
+                            if self.tombstoned.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The tombstoned argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The tombstoned argument cannot have an error field",
+                                ));
+                            }
+
+                            self.tombstoned = Some(quote! {#value});
+                        }
+                        "signers_at_least" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The signers_at_least argument is not allowed in enums",
+                                ));
+                            }
+
+                            if !matches!(self.kind, FieldKind::Vec(_)) {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The signers_at_least argument is only allowed in Vec fields",
+                                ));
+                            }
+
+                            if self.signers_at_least.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The signers_at_least argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The signers_at_least argument cannot have an error field",
+                                ));
+                            }
+
+                            self.signers_at_least = Some(quote! {#value});
+                        }
                         "pda" => {
                             if is_enum {
                                 return Err(Error::new(
@@ -639,6 +921,221 @@ impl Field {
                                 value: quote! {#value},
                             });
                         }
+                        "min" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The min argument is not allowed in enums",
+                                ));
+                            }
+
+                            if !matches!(self.kind, FieldKind::Vec(_) | FieldKind::Rest) {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The min argument is only allowed in Vec and Rest fields",
+                                ));
+                            }
+
+                            if self.min.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The min argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The min argument cannot have an error field",
+                                ));
+                            }
+
+                            self.min = Some(quote! {#value});
+                        }
+                        "max" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The max argument is not allowed in enums",
+                                ));
+                            }
+
+                            if !matches!(self.kind, FieldKind::Vec(_)) {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The max argument is only allowed in Vec fields",
+                                ));
+                            }
+
+                            if self.max.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The max argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The max argument cannot have an error field",
+                                ));
+                            }
+
+                            self.max = Some(quote! {#value});
+                        }
+                        "max_mode" => {
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The max_mode argument cannot have an error field",
+                                ));
+                            }
+
+                            let mode = unwrap_ident_from_expr(value)?;
+                            self.max_mode = match mode.to_string().as_str() {
+                                "error" => VecMaxMode::Error,
+                                "truncate" => VecMaxMode::Truncate,
+                                "rest" => VecMaxMode::Rest,
+                                _ => {
+                                    return Err(Error::new(
+                                        mode.span(),
+                                        "The max_mode argument must be one of: error, truncate, rest",
+                                    ));
+                                }
+                            };
+                        }
+                        "token_gate" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The token_gate argument is not allowed in enums",
+                                ));
+                            }
+
+                            if self.token_gate.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The token_gate argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The token_gate argument cannot have an error field",
+                                ));
+                            }
+
+                            let (mint, min_amount) = match value {
+                                Expr::Tuple(v) if v.elems.len() == 2 => {
+                                    let mut elems = v.elems.into_iter();
+                                    (elems.next().unwrap(), elems.next().unwrap())
+                                }
+                                _ => {
+                                    return Err(Error::new(
+                                        name.span(),
+                                        "The token_gate argument must be a tuple: token_gate = (<mint>, <min_amount>)",
+                                    ));
+                                }
+                            };
+
+                            self.token_gate = Some((quote! {#mint}, quote! {#min_amount}));
+                        }
+                        "unchecked_reason" => {
+                            if !self.ty.as_ref().is_some_and(is_unchecked_type) {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The unchecked_reason argument is only allowed in UncheckedAccount and AccountInfo fields",
+                                ));
+                            }
+
+                            if self.unchecked_reason.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The unchecked_reason argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The unchecked_reason argument cannot have an error field",
+                                ));
+                            }
+
+                            self.unchecked_reason = Some(quote! {#value});
+                        }
+                        "unique" => {
+                            return Err(Error::new(
+                                name.span(),
+                                "The unique argument is only allowed without values, i.e. #[account(unique)]",
+                            ));
+                        }
+                        "sentinel" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The sentinel argument is not allowed in enums",
+                                ));
+                            }
+
+                            if !matches!(self.kind, FieldKind::Vec(_)) {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The sentinel argument is only allowed in Vec fields",
+                                ));
+                            }
+
+                            if !matches!(self.count, VecCount::Length) {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The sentinel/remaining arguments can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The sentinel argument cannot have an error field",
+                                ));
+                            }
+
+                            self.count = VecCount::Sentinel(quote! {#value});
+                        }
+                        "remaining" => {
+                            return Err(Error::new(
+                                name.span(),
+                                "The remaining argument is only allowed without values, i.e. #[account(remaining)]",
+                            ));
+                        }
+                        "resolve" | "resolve_with_client" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    format!("The {} argument is not allowed in enums", name),
+                                ));
+                            }
+
+                            if self.resolve.is_some() || self.resolve_with_client.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The resolve/resolve_with_client arguments can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    format!("The {} argument cannot have an error field", name),
+                                ));
+                            }
+
+                            if name == "resolve" {
+                                self.resolve = Some(quote! {#value});
+                            } else {
+                                self.resolve_with_client = Some(quote! {#value});
+                            }
+                        }
                         "validate" => {
                             return Err(Error::new(
                                 name.span(),
@@ -789,6 +1286,104 @@ impl Field {
 
                             self.signer = Some(quote! {true});
                         }
+                        "unique" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The unique argument is not allowed in enums",
+                                ));
+                            }
+
+                            if !matches!(self.kind, FieldKind::Vec(_)) {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The unique argument is only allowed in Vec fields",
+                                ));
+                            }
+
+                            if self.unique {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The unique argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The unique argument cannot have an error field",
+                                ));
+                            }
+
+                            self.unique = true;
+                        }
+                        "remaining" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The remaining argument is not allowed in enums",
+                                ));
+                            }
+
+                            if !matches!(self.kind, FieldKind::Vec(_)) {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The remaining argument is only allowed in Vec fields",
+                                ));
+                            }
+
+                            if !matches!(self.count, VecCount::Length) {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The sentinel/remaining arguments can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The remaining argument cannot have an error field",
+                                ));
+                            }
+
+                            self.count = VecCount::Remaining;
+                        }
+                        "sentinel" => {
+                            return Err(Error::new(
+                                name.span(),
+                                "The sentinel argument must use a value: sentinel = <expr>",
+                            ));
+                        }
+                        "tombstoned" => {
+                            if is_enum {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The tombstoned argument is not allowed in enums",
+                                ));
+                            }
+
+                            if self.tombstoned.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The tombstoned argument can only be defined once",
+                                ));
+                            }
+
+                            if meta.error.is_some() {
+                                return Err(Error::new(
+                                    name.span(),
+                                    "The tombstoned argument cannot have an error field",
+                                ));
+                            }
+
+                            self.tombstoned = Some(quote! {true});
+                        }
+                        "signers_at_least" => {
+                            return Err(Error::new(
+                                name.span(),
+                                "The signers_at_least argument must use a value: signers_at_least = <expr>",
+                            ));
+                        }
                         "min" => {
                             return Err(Error::new(
                                 name.span(),
@@ -807,6 +1402,24 @@ impl Field {
                                 "The size argument must use a value: size = <expr>",
                             ));
                         }
+                        "token_gate" => {
+                            return Err(Error::new(
+                                name.span(),
+                                "The token_gate argument must use a value: token_gate = (<mint>, <min_amount>)",
+                            ));
+                        }
+                        "unchecked_reason" => {
+                            return Err(Error::new(
+                                name.span(),
+                                "The unchecked_reason argument must use a value: unchecked_reason = \"<reason>\"",
+                            ));
+                        }
+                        "max_mode" => {
+                            return Err(Error::new(
+                                name.span(),
+                                "The max_mode argument must use a value: max_mode = <error|truncate|rest>",
+                            ));
+                        }
                         "pda" => {
                             return Err(Error::new(
                                 name.span(),
@@ -843,6 +1456,30 @@ impl Field {
                                 "The metadata_pda argument must use a value: pda_program_id = <expr>",
                             ));
                         }
+                        "resolve" | "resolve_with_client" => {
+                            return Err(Error::new(
+                                name.span(),
+                                format!("The {0} argument must use a value: {0} = <expr>", name),
+                            ));
+                        }
+                        "program_data" => {
+                            return Err(Error::new(
+                                name.span(),
+                                "The program_data argument must use a value: program_data = <expr>",
+                            ));
+                        }
+                        "upgrade_authority" => {
+                            return Err(Error::new(
+                                name.span(),
+                                "The upgrade_authority argument must use a value: upgrade_authority = <expr>",
+                            ));
+                        }
+                        "rent_to" => {
+                            return Err(Error::new(
+                                name.span(),
+                                "The rent_to argument must use a value: rent_to = <field>",
+                            ));
+                        }
                         "constraint" => {
                             return Err(Error::new(
                                 name.span(),
@@ -863,6 +1500,27 @@ impl Field {
             }
         }
 
+        if let (Some(v), true) = (&self.upgrade_authority, self.program_data.is_none()) {
+            return Err(Error::new(
+                v.span(),
+                "The upgrade_authority argument cannot be defined without the program_data argument",
+            ));
+        }
+
+        if self.max_mode != VecMaxMode::Error && self.max.is_none() {
+            return Err(Error::new(
+                self.name.span(),
+                "The max_mode argument cannot be defined without the max argument",
+            ));
+        }
+
+        if self.max_mode != VecMaxMode::Error && !matches!(self.count, VecCount::Length) {
+            return Err(Error::new(
+                self.name.span(),
+                "The max_mode argument cannot be defined with the sentinel or remaining arguments",
+            ));
+        }
+
         if let (Some(v), true) = (&self.pda_program_id, self.pda.is_none()) {
             return Err(Error::new(
                 v.span(),
@@ -872,6 +1530,50 @@ impl Field {
 
         Ok(())
     }
+
+    fn parse_argument_attribute(&mut self, attribute: Attribute, is_enum: bool) -> Result<()> {
+        let attribute_span = attribute.span();
+
+        if is_enum {
+            return Err(Error::new(
+                attribute_span,
+                "The argument attribute is not allowed in enums",
+            ));
+        }
+
+        let args = match attribute.parse_args::<CustomMetaListWithErrors>() {
+            Ok(v) => v,
+            Err(_) => {
+                return Err(Error::new(
+                    attribute_span,
+                    "The argument attribute expects arguments",
+                ));
+            }
+        };
+
+        for meta in args.list {
+            let name = meta.name;
+            match (name.to_string().as_str(), meta.value) {
+                ("validate", Some(value)) => {
+                    self.validations.push(DataAndError {
+                        data: quote! {#value},
+                        error: meta.error.map(|e| quote! {#e}),
+                    });
+                }
+                ("validate", None) => {
+                    return Err(Error::new(
+                        name.span(),
+                        "The validate argument must use a value: validate = <expr>",
+                    ));
+                }
+                _ => {
+                    return Err(Error::new(name.span(), "Unknown argument"));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -915,13 +1617,52 @@ fn discriminate_type(ty: &Type) -> FieldKind {
     FieldKind::Other
 }
 
+/// Whether `ty` is an account that is not checked at all, i.e. an
+/// `UncheckedAccount` or an `AccountInfo`, even inside an `Option`, `Vec` or
+/// `Box`.
+pub fn is_unchecked_type(ty: &Type) -> bool {
+    let last_arg = match ty {
+        Type::Path(v) => v.path.segments.last().unwrap(),
+        Type::Reference(v) => return is_unchecked_type(&v.elem),
+        _ => return false,
+    };
+
+    match last_arg.ident.to_string().as_str() {
+        "UncheckedAccount" | "AccountInfo" => true,
+        "Option" | "Vec" | "Box" => match &last_arg.arguments {
+            PathArguments::AngleBracketed(v) => v.args.iter().any(|v| match v {
+                GenericArgument::Type(v) => is_unchecked_type(v),
+                _ => false,
+            }),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
 pub fn check_fields(fields: &[Field]) -> Result<()> {
     let mut rest_field = false;
-    for field in fields {
+    for (i, field) in fields.iter().enumerate() {
+        if field.max_mode == VecMaxMode::Rest
+            && !matches!(fields.get(i + 1).map(|v| &v.kind), Some(FieldKind::Rest))
+        {
+            return Err(Error::new(
+                field.name.span(),
+                "The max_mode = rest argument requires the field to be followed by a Rest field",
+            ));
+        }
+
+        if matches!(field.count, VecCount::Remaining) && i + 1 != fields.len() {
+            return Err(Error::new(
+                field.name.span(),
+                "The remaining argument requires the field to be the last one",
+            ));
+        }
+
         match &field.kind {
             FieldKind::Other | FieldKind::Option(_) | FieldKind::Vec(_) => {
                 if rest_field {
@@ -965,6 +1706,7 @@ impl Parse for CustomMetaListWithErrors {
 
 pub struct CustomMetaWithError {
     pub name: Ident,
+    pub each: Option<Punctuated<CustomMetaWithError, Token![,]>>,
     pub sub_name: Option<(Token![:], Token![:], Ident)>,
     pub eq_token: Option<Token![=]>,
     pub value: Option<Expr>,
@@ -976,6 +1718,15 @@ impl Parse for CustomMetaWithError {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let name = input.parse::<Ident>()?;
 
+        let each = if name == "each" && input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+
+            Some(content.parse_terminated(CustomMetaWithError::parse, Token![,])?)
+        } else {
+            None
+        };
+
         let sub_name = if name == "data" {
             let token_colon1 = input.parse::<Token![:]>()?;
             let token_colon2 = input.parse::<Token![:]>()?;
@@ -1002,6 +1753,7 @@ impl Parse for CustomMetaWithError {
 
         Ok(Self {
             name,
+            each,
             sub_name,
             eq_token,
             value,
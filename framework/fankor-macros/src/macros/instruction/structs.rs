@@ -1,10 +1,11 @@
 use convert_case::{Case, Converter};
 use quote::{format_ident, quote};
-use syn::ItemStruct;
+use proc_macro2::TokenStream;
+use syn::{ItemStruct, Type};
 
 use crate::fnk_syn::FnkMetaArgumentList;
-use crate::macros::instruction::arguments::{InstructionArguments, Validation};
-use crate::macros::instruction::field::{check_fields, Field};
+use crate::macros::instruction::arguments::{FeeCollection, InstructionArguments, Validation};
+use crate::macros::instruction::field::{check_fields, Field, FieldKind, VecCount, VecMaxMode};
 use crate::Result;
 
 pub fn process_struct(
@@ -24,6 +25,8 @@ pub fn process_struct(
         .collect::<Result<Vec<Field>>>()?;
     check_fields(&mapped_fields)?;
 
+    let strict_mode_checks = mapped_fields.iter().map(|v| v.strict_mode_check(name));
+
     let final_fields = mapped_fields.iter().map(|v| {
         let name = &v.name;
         let ty = v.ty.as_ref().unwrap();
@@ -49,14 +52,186 @@ pub fn process_struct(
         (quote! {}, quote! {})
     };
 
+    let try_from_limits = {
+        let max_data_len = arguments.max_data_len.map(|max| {
+            quote! {
+                if buf.len() > #max {
+                    return Err(::fankor::errors::FankorErrorCode::InstructionDataTooLarge {
+                        max: #max,
+                        actual: buf.len(),
+                    }
+                    .into());
+                }
+            }
+        });
+        let max_accounts = arguments.max_accounts.map(|max| {
+            quote! {
+                if accounts.len() > #max {
+                    return Err(::fankor::errors::FankorErrorCode::InstructionTooManyAccounts {
+                        max: #max,
+                        actual: accounts.len(),
+                    }
+                    .into());
+                }
+            }
+        });
+
+        quote! {
+            #max_data_len
+            #max_accounts
+        }
+    };
+    let max_data_len = option_to_tokens(arguments.max_data_len);
+    let max_accounts = option_to_tokens(arguments.max_accounts);
+
     let try_from_fn_deserialize = mapped_fields.iter().enumerate().map(|(i, v)| {
+        let name = &v.name;
+        let name_str = name.to_string();
         let var_name = format_ident!("__v{}", i);
         let ty = v.ty.as_ref().unwrap();
 
-        quote! {
-            let #var_name = <#ty as ::fankor::traits::Instruction>::try_from(context, buf, accounts)?;
+        if !v.validations.is_empty() && !is_argument_type(ty) {
+            return Err(syn::Error::new(
+                name.span(),
+                "The argument attribute is only allowed in Argument fields",
+            ));
         }
-    });
+
+        // Argument validations are checked right after deserializing them.
+        let validations = v.validations.iter().map(|validation| {
+            let condition = &validation.data;
+            let error = match &validation.error {
+                Some(v) => v.clone(),
+                None => {
+                    quote! {
+                        FankorErrorCode::ArgumentValidationFailed {
+                            argument: #name_str,
+                            validation: stringify!(#condition),
+                        }
+                    }
+                }
+            };
+
+            quote! {{
+                let #name = &#var_name;
+                require!(#condition, #error);
+            }}
+        });
+
+        let deserialize = match (&v.kind, &v.max, &v.count) {
+            (FieldKind::Vec(inner), max, VecCount::Sentinel(sentinel)) => {
+                let max_check = max.as_ref().map(|max| quote! {
+                    if result.len() == #max {
+                        return Err(::fankor::errors::FankorErrorCode::VecAccountsTooLarge {
+                            field: #name_str,
+                            max: #max,
+                            actual: result.len() + 1,
+                        }
+                        .into());
+                    }
+                });
+
+                quote! {{
+                    let sentinel: &::fankor::prelude::Pubkey = #sentinel;
+                    let mut result = Vec::new();
+
+                    // The accounts are consumed until the sentinel one, which is skipped.
+                    loop {
+                        match accounts.first() {
+                            Some(info) if info.key == sentinel => {
+                                *accounts = &accounts[1..];
+                                break;
+                            }
+                            Some(_) => {
+                                #max_check
+                                result.push(<#inner as ::fankor::traits::Instruction>::try_from(context, buf, accounts)?);
+                            }
+                            None => {
+                                return Err(::fankor::errors::FankorErrorCode::NotEnoughAccountKeys.into());
+                            }
+                        }
+                    }
+
+                    result
+                }}
+            }
+            (FieldKind::Vec(inner), max, VecCount::Remaining) => {
+                let max = match max {
+                    Some(max) => quote! { #max },
+                    None => quote! { usize::MAX },
+                };
+
+                quote! {{
+                    let mut result = Vec::new();
+                    while !accounts.is_empty() && result.len() < #max {
+                        result.push(<#inner as ::fankor::traits::Instruction>::try_from(context, buf, accounts)?);
+                    }
+
+                    result
+                }}
+            }
+            (FieldKind::Vec(inner), Some(max), VecCount::Length) => match v.max_mode {
+                VecMaxMode::Error => quote! {{
+                    if let Some(size) = buf.first() {
+                        let size = *size as usize;
+                        if size > #max {
+                            return Err(::fankor::errors::FankorErrorCode::VecAccountsTooLarge {
+                                field: #name_str,
+                                max: #max,
+                                actual: size,
+                            }
+                            .into());
+                        }
+                    }
+
+                    <#ty as ::fankor::traits::Instruction>::try_from(context, buf, accounts)?
+                }},
+                VecMaxMode::Truncate => quote! {{
+                    let mut result = <#ty as ::fankor::traits::Instruction>::try_from(context, buf, accounts)?;
+                    result.truncate(#max);
+                    result
+                }},
+                VecMaxMode::Rest => quote! {{
+                    if buf.is_empty() {
+                        return Err(::fankor::errors::FankorErrorCode::NotEnoughDataToDeserializeInstruction.into());
+                    }
+
+                    // The accounts beyond the maximum are left for the rest field.
+                    let size = (buf[0] as usize).min(#max);
+                    *buf = &buf[1..];
+
+                    let mut result = Vec::with_capacity(size);
+                    for _ in 0..size {
+                        result.push(<#inner as ::fankor::traits::Instruction>::try_from(context, buf, accounts)?);
+                    }
+
+                    result
+                }},
+            },
+            _ => quote! {
+                <#ty as ::fankor::traits::Instruction>::try_from(context, buf, accounts)?
+            },
+        };
+
+        let min_check = v.min.as_ref().map(|min| {
+            quote! {
+                if #var_name.len() < #min {
+                    return Err(::fankor::errors::FankorErrorCode::AccountConstraintMinimumMismatch {
+                        actual: #var_name.len(),
+                        expected: #min,
+                        account: #name_str,
+                    }
+                    .into());
+                }
+            }
+        });
+
+        Ok(quote! {
+            let #var_name = #deserialize;
+            #min_check
+            #(#validations)*
+        })
+    }).collect::<Result<Vec<_>>>()?;
 
     let mut pda_methods = Vec::new();
     let validate_method_fields = mapped_fields.iter().map(|v| {
@@ -160,6 +335,20 @@ pub fn process_struct(
             }});
         }
 
+        if let Some(program_data) = &v.program_data {
+            account_info_conditions.push(quote! {{
+                let program_data = ::fankor::traits::SingleInstructionAccount::info(&#program_data);
+                ::fankor::prelude::verify_program_data(info, program_data)?;
+            }});
+
+            if let Some(upgrade_authority) = &v.upgrade_authority {
+                account_info_conditions.push(quote! {{
+                    let program_data = ::fankor::traits::SingleInstructionAccount::info(&#program_data);
+                    ::fankor::prelude::verify_upgrade_authority(program_data, #upgrade_authority)?;
+                }});
+            }
+        }
+
         if let Some(rent_exempt) = &v.rent_exempt {
             account_info_conditions.push(quote! {{
                 let rent_exempt = #rent_exempt;
@@ -201,8 +390,37 @@ pub fn process_struct(
             }});
         }
 
+        if let Some(tombstoned) = &v.tombstoned {
+            account_info_conditions.push(quote! {{
+                let tombstoned = #tombstoned;
+                let is_tombstoned = ::fankor::models::DeferredClose::is_tombstoned(info, context.program_id());
+
+                if tombstoned {
+                    if !is_tombstoned {
+                        return Err(::fankor::errors::FankorErrorCode::AccountConstraintNotTombstoned {
+                            account: #name_str,
+                        }.into());
+                    }
+                } else if is_tombstoned {
+                    return Err(::fankor::errors::FankorErrorCode::AccountConstraintTombstoned {
+                        account: #name_str,
+                    }.into());
+                }
+            }});
+        }
+
+        if let Some((mint, min_amount)) = &v.token_gate {
+            account_info_conditions.push(quote! {{
+                let mint: &::fankor::prelude::Pubkey = #mint;
+                let min_amount: u64 = #min_amount;
+
+                ::fankor::models::check_token_gate(context, info, mint, min_amount, #name_str)?;
+            }});
+        }
+
         if let Some(pda) = &v.pda {
             let pda_method_name = format_ident!("{}_pda_seeds", name);
+            let pda_components_method_name = format_ident!("{}_pda", name);
             let seeds = &pda.data;
 
             pda_methods.push(quote! {
@@ -218,6 +436,20 @@ pub fn process_struct(
 
                     Ok(seeds)
                 }
+
+                /// The seed components and bump of the PDA as they were evaluated during the validation.
+                pub fn #pda_components_method_name(&self, context: &FankorContext<'info>) -> FankorResult<::fankor::models::PdaSeeds> {
+                    let info = match self.#name.pda_info() {
+                        Some(v) => v,
+                        None => return Err(::fankor::errors::FankorErrorCode::MissingSeedsAccount.into()),
+                    };
+
+                    let seeds = context.get_pda_seeds_for_account(info).ok_or_else(|| ::fankor::errors::FankorErrorCode::MissingPdaSeeds {
+                        account: *info.key
+                    })?;
+
+                    Ok(seeds)
+                }
             });
 
             let program_id = v.pda_program_id.clone().unwrap_or_else(|| quote! { context.program_id() });
@@ -236,18 +468,13 @@ pub fn process_struct(
                 let seeds: &[&[u8]] = &#seeds;
                 let program_id = #program_id;
 
-                let seeds_length = seeds.iter().map(|v|v.len()).sum::<usize>();
-                let mut final_seeds = Vec::with_capacity(seeds_length + 1 /* bump */);
-                for seeds in seeds {
-                    final_seeds.extend_from_slice(*seeds);
-                }
-
-                context.check_canonical_pda_with_program(info, final_seeds, program_id)#error;
+                context.check_canonical_pda_with_components(info, seeds, program_id)#error;
             }});
         }
 
         if let Some(pda) = &v.pda_bytes {
             let pda_method_name = format_ident!("{}_pda_seeds", name);
+            let pda_components_method_name = format_ident!("{}_pda", name);
             let seeds = &pda.data;
 
             pda_methods.push(quote! {
@@ -263,6 +490,20 @@ pub fn process_struct(
 
                     Ok(seeds)
                 }
+
+                /// The seed components and bump of the PDA as they were evaluated during the validation.
+                pub fn #pda_components_method_name(&self, context: &FankorContext<'info>) -> FankorResult<::fankor::models::PdaSeeds> {
+                    let info = match self.#name.pda_info() {
+                        Some(v) => v,
+                        None => return Err(::fankor::errors::FankorErrorCode::MissingSeedsAccount.into()),
+                    };
+
+                    let seeds = context.get_pda_seeds_for_account(info).ok_or_else(|| ::fankor::errors::FankorErrorCode::MissingPdaSeeds {
+                        account: *info.key
+                    })?;
+
+                    Ok(seeds)
+                }
             });
 
             let program_id = v.pda_program_id.clone().unwrap_or_else(|| quote! { context.program_id() });
@@ -293,14 +534,17 @@ pub fn process_struct(
                     quote! {
                         FankorErrorCode::AccountConstraintFailed {
                             account: #name_str,
-                            constraint: stringify!(#condition),
+                            constraint: ::fankor::errors::constraint_message(stringify!(#condition)),
                         }
                     }
                 }
             };
 
             constraints_conditions.push(quote! {{
-                require!(#condition, #error);
+                if !(#condition) {
+                    ::fankor::errors::log_constraint_failure(#name_str, stringify!(#condition));
+                    return Err(#error.into());
+                }
             }});
         }
 
@@ -344,6 +588,105 @@ pub fn process_struct(
             }
         };
 
+        let result = if let Some(signers_at_least) = &v.signers_at_least {
+            quote! {
+                #result
+
+                {
+                    let candidates = self.#name.iter().map(::fankor::traits::SingleInstructionAccount::info).collect::<Vec<_>>();
+                    ::fankor::prelude::verify_threshold_signers(#signers_at_least, &candidates)?;
+                }
+            }
+        } else {
+            result
+        };
+
+        let result = if v.unique {
+            quote! {
+                #result
+
+                {
+                    let mut addresses = self.#name.iter().map(|v| ::fankor::traits::SingleInstructionAccount::info(v).key).collect::<Vec<_>>();
+                    addresses.sort_unstable();
+
+                    if let Some(v) = addresses.windows(2).find(|v| v[0] == v[1]) {
+                        return Err(::fankor::errors::FankorErrorCode::DuplicatedAccountInVec {
+                            account: #name_str,
+                            address: *v[0],
+                        }.into());
+                    }
+                }
+            }
+        } else {
+            result
+        };
+
+        let result = if let Some(rent_to) = &v.rent_to {
+            let destination = match mapped_fields.iter().find(|v| &v.name == rent_to) {
+                Some(v) => v,
+                None => {
+                    return Err(syn::Error::new(
+                        rent_to.span(),
+                        "The rent_to argument must reference another field of the struct",
+                    ));
+                }
+            };
+
+            if !matches!(v.kind, FieldKind::Other) {
+                return Err(syn::Error::new(
+                    rent_to.span(),
+                    "The rent_to argument is only allowed in single account fields",
+                ));
+            }
+
+            let close_method_name = format_ident!("close_{}", name);
+            let doc = format!(
+                "Closes `{}` at the end of the instruction sending its rent to `{}`.",
+                name, rent_to
+            );
+
+            pda_methods.push(quote! {
+                #[doc = #doc]
+                pub fn #close_method_name(&self) -> FankorResult<()> {
+                    self.#name.close_account_at_exit(::fankor::traits::SingleInstructionAccount::info(&self.#rent_to))
+                }
+            });
+
+            // Destinations with a configured address are treasuries, otherwise
+            // they must be system accounts.
+            let owner_check = if destination.address.is_some() {
+                quote! {}
+            } else {
+                quote! {
+                    if destination.owner != &::fankor::prelude::solana_program::system_program::ID {
+                        return Err(::fankor::errors::FankorErrorCode::InvalidRentDestination {
+                            account: #name_str,
+                            destination: *destination.key,
+                        }.into());
+                    }
+                }
+            };
+
+            quote! {
+                #result
+
+                {
+                    let destination = ::fankor::traits::SingleInstructionAccount::info(&self.#rent_to);
+
+                    if !destination.is_writable {
+                        return Err(::fankor::errors::FankorErrorCode::InvalidRentDestination {
+                            account: #name_str,
+                            destination: *destination.key,
+                        }.into());
+                    }
+
+                    #owner_check
+                }
+            }
+        } else {
+            result
+        };
+
         Ok(result)
     }).collect::<Result<Vec<_>>>()?;
 
@@ -356,18 +699,102 @@ pub fn process_struct(
         }
     });
 
+    // Account layout.
+    let account_layout = mapped_fields
+        .iter()
+        .filter(|v| !is_argument_type(v.ty.as_ref().unwrap()))
+        .map(|v| {
+            let name_str = v.name.to_string();
+            let ty = v.ty.as_ref().unwrap();
+            let type_str = quote!(#ty).to_string().replace(' ', "");
+            let writable = static_bool(&v.writable);
+            let signer = static_bool(&v.signer);
+            let min = v.min.clone().unwrap_or_else(|| quote! { 0 });
+            let (optional, min_count, max_count) = match &v.kind {
+                FieldKind::Other => (false, quote! { 1 }, quote! { Some(1) }),
+                FieldKind::Option(_) => (true, quote! { 0 }, quote! { Some(1) }),
+                FieldKind::Vec(_) => match (&v.max, v.max_mode) {
+                    (Some(max), VecMaxMode::Error) => (false, min, quote! { Some(#max) }),
+                    _ => (false, min, quote! { None }),
+                },
+                FieldKind::Rest => (false, min, quote! { None }),
+            };
+            let constraints = constraint_descriptions(v);
+            let pda = match &v.pda {
+                Some(pda) => {
+                    let seeds = pda.data.to_string();
+                    quote! { Some(#seeds) }
+                }
+                None => quote! { None },
+            };
+            let unchecked_reason = match &v.unchecked_reason {
+                Some(reason) => quote! { Some(#reason) },
+                None => quote! { None },
+            };
+
+            quote! {
+                ::fankor::models::AccountLayout {
+                    name: #name_str,
+                    type_name: #type_str,
+                    writable: #writable,
+                    signer: #signer,
+                    optional: #optional,
+                    min_count: #min_count,
+                    max_count: #max_count,
+                    constraints: &[#(#constraints),*],
+                    pda: #pda,
+                    unchecked_reason: #unchecked_reason,
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
     // CpiInstruction implementation
     let cpi_name = format_ident!("Cpi{}", name);
     let cpi_fields = mapped_fields.iter().map(|v| {
         let name = &v.name;
         let ty = v.ty.as_ref().unwrap();
 
+        // The sentinel account must be provided to be passed to the CPI.
+        if let VecCount::Sentinel(_) = &v.count {
+            return quote! {
+                pub #name:(<#ty as ::fankor::traits::Instruction<'info>>::CPI, AccountInfo<'info>)
+            };
+        }
+
         quote! {
             pub #name:<#ty as ::fankor::traits::Instruction<'info>>::CPI
         }
     });
     let cpi_fn_elements = mapped_fields.iter().map(|v| {
         let name = &v.name;
+        let (serialize, sentinel) = match &v.count {
+            VecCount::Length => (
+                quote! {
+                    ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas, infos)?;
+                },
+                quote! {},
+            ),
+            VecCount::Sentinel(_) => (
+                quote! {
+                    for v in &self.#name.0 {
+                        ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(v, writer, metas, infos)?;
+                    }
+                },
+                quote! {
+                    metas.push(AccountMeta::new_readonly(*self.#name.1.key, false));
+                    infos.push(self.#name.1.clone());
+                },
+            ),
+            VecCount::Remaining => (
+                quote! {
+                    for v in &self.#name {
+                        ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(v, writer, metas, infos)?;
+                    }
+                },
+                quote! {},
+            ),
+        };
 
         let mut any = false;
         let (writable_let, writable_for) = if let Some(writable) = &v.writable {
@@ -400,7 +827,7 @@ pub fn process_struct(
             quote! {
                 {
                     let from = metas.len();
-                    ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas, infos)?;
+                    #serialize
                     let to = metas.len();
                     #writable_let
                     #signer_let
@@ -409,11 +836,14 @@ pub fn process_struct(
                         #writable_for
                         #signer_for
                     }
+
+                    #sentinel
                 }
             }
         } else {
             quote! {
-               ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas, infos)?;
+                #serialize
+                #sentinel
             }
         }
     });
@@ -430,6 +860,33 @@ pub fn process_struct(
     });
     let lpi_fn_elements = mapped_fields.iter().map(|v| {
         let name = &v.name;
+        let (serialize, sentinel) = match &v.count {
+            VecCount::Length => (
+                quote! {
+                    ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas)?;
+                },
+                quote! {},
+            ),
+            VecCount::Sentinel(sentinel) => (
+                quote! {
+                    for v in &self.#name {
+                        ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(v, writer, metas)?;
+                    }
+                },
+                quote! {
+                    let sentinel: &::fankor::prelude::Pubkey = #sentinel;
+                    metas.push(::fankor::prelude::solana_program::instruction::AccountMeta::new_readonly(*sentinel, false));
+                },
+            ),
+            VecCount::Remaining => (
+                quote! {
+                    for v in &self.#name {
+                        ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(v, writer, metas)?;
+                    }
+                },
+                quote! {},
+            ),
+        };
 
         let mut any = false;
         let (writable_let, writable_for) = if let Some(writable) = &v.writable {
@@ -462,7 +919,7 @@ pub fn process_struct(
             quote! {
                 {
                     let from = metas.len();
-                    ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas)?;
+                    #serialize
                     let to = metas.len();
                     #writable_let
                     #signer_let
@@ -471,11 +928,14 @@ pub fn process_struct(
                         #writable_for
                         #signer_for
                     }
+
+                    #sentinel
                 }
             }
         } else {
             quote! {
-                ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(&self.#name, writer, metas)?;
+                #serialize
+                #sentinel
             }
         }
     });
@@ -499,6 +959,126 @@ pub fn process_struct(
         Validation::Explicit(v) => v,
     });
 
+    // Fee collection.
+    let (collect_fee_call, collect_fee_method) = match &arguments.collect_fee {
+        Some(collect_fee) => {
+            let fee_base = arguments.fee_base.as_ref().unwrap();
+            let (required_fields, collect) = match collect_fee {
+                FeeCollection::Lamports => (
+                    vec!["fee_config", "fee_payer", "fee_recipient", "system_program"],
+                    quote! {
+                        ::fankor::fees::collect_lamports_fee(
+                            &fee_config,
+                            ::fankor::traits::SingleInstructionAccount::info(&self.fee_payer),
+                            ::fankor::traits::SingleInstructionAccount::info(&self.fee_recipient),
+                            &self.system_program,
+                            #fee_base,
+                        )
+                    },
+                ),
+                FeeCollection::Token(mint) => (
+                    vec![
+                        "fee_config",
+                        "fee_payer",
+                        "fee_source",
+                        "fee_recipient",
+                        "token_program",
+                    ],
+                    quote! {
+                        ::fankor::fees::collect_token_fee(
+                            &fee_config,
+                            &self.fee_source,
+                            &self.fee_recipient,
+                            &self.#mint,
+                            ::fankor::traits::SingleInstructionAccount::info(&self.fee_payer),
+                            &self.token_program,
+                            #fee_base,
+                        )
+                    },
+                ),
+            };
+
+            let missing_fields = required_fields
+                .iter()
+                .filter(|v| !mapped_fields.iter().any(|f| f.name == v))
+                .copied()
+                .collect::<Vec<_>>();
+            if !missing_fields.is_empty() {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!(
+                        "The collect_fee attribute requires the fields: {}",
+                        missing_fields.join(", ")
+                    ),
+                ));
+            }
+
+            (
+                quote! {
+                    // Collect the fee before the handler.
+                    result.collect_fee(context)?;
+                },
+                quote! {
+                    /// Transfers the fee of the instruction to the recipient of
+                    /// the fee config. Returns the collected fee.
+                    fn collect_fee(
+                        &self,
+                        context: &'info FankorContext<'info>,
+                    ) -> ::fankor::errors::FankorResult<u64> {
+                        let fee_config = ::fankor::fees::FeeConfig::load(
+                            context,
+                            ::fankor::traits::SingleInstructionAccount::info(&self.fee_config),
+                        )?;
+
+                        #collect
+                    }
+                },
+            )
+        }
+        None => (quote! {}, quote! {}),
+    };
+
+    // Replay protection.
+    let (record_nonce_call, idempotency_nonce_method) = match (
+        &arguments.idempotent_by,
+        &arguments.idempotency_scope,
+    ) {
+        (Some(nonce), Some(scope)) => {
+            if !mapped_fields.iter().any(|f| f.name == "idempotency_record") {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "The idempotent_by attribute requires the field: idempotency_record",
+                ));
+            }
+
+            if !mapped_fields.iter().any(|f| &f.name == scope) {
+                return Err(syn::Error::new(
+                    scope.span(),
+                    format!("The idempotency_scope field does not exist: {}", scope),
+                ));
+            }
+
+            (
+                quote! {
+                    // Reject replays before the handler.
+                    ::fankor::idempotency::record_nonce(
+                        context,
+                        ::fankor::traits::SingleInstructionAccount::info(&result.idempotency_record),
+                        ::fankor::traits::SingleInstructionAccount::info(&result.#scope),
+                        result.idempotency_nonce(),
+                    )?;
+                },
+                quote! {
+                    /// The nonce that identifies this instruction to reject replays.
+                    pub fn idempotency_nonce(&self) -> u64 {
+                        ::core::convert::Into::into(#nonce)
+                    }
+                },
+            )
+        }
+        _ => (quote! {}, quote! {}),
+    };
+
     // Result
     let phantom_lifetime = if arguments.phantom && mapped_fields.is_empty() {
         quote! {}
@@ -506,6 +1086,101 @@ pub fn process_struct(
         quote! { <'info> }
     };
 
+    // LPI account resolution.
+    let is_resolved = |v: &Field| v.resolve.is_some() || v.resolve_with_client.is_some();
+    let lpi_resolver = if mapped_fields.iter().any(is_resolved) {
+        let unresolved_name = format_ident!("{}Unresolved", lpi_name);
+        let unresolved_fields = mapped_fields
+            .iter()
+            .filter(|v| !is_resolved(v))
+            .map(|v| {
+                let name = &v.name;
+                let ty = v.ty.as_ref().unwrap();
+
+                quote! {
+                    pub #name: <#ty as ::fankor::traits::Instruction<'info>>::LPI
+                }
+            })
+            .collect::<Vec<_>>();
+        let unresolved_bindings = mapped_fields
+            .iter()
+            .filter(|v| !is_resolved(v))
+            .map(|v| {
+                let name = &v.name;
+
+                quote! {
+                    let #name = self.#name;
+                }
+            })
+            .collect::<Vec<_>>();
+        let resolutions = mapped_fields
+            .iter()
+            .filter_map(|v| {
+                let name = &v.name;
+                let ty = v.ty.as_ref().unwrap();
+                let value = v.resolve.as_ref().or(v.resolve_with_client.as_ref())?;
+
+                Some(quote! {
+                    let #name: <#ty as ::fankor::traits::Instruction<'info>>::LPI = #value;
+                })
+            })
+            .collect::<Vec<_>>();
+        let field_names = mapped_fields.iter().map(|v| &v.name).collect::<Vec<_>>();
+        let unresolved_lifetime = if unresolved_fields.is_empty() {
+            quote! {}
+        } else {
+            quote! { <'info> }
+        };
+
+        let resolve_method = if mapped_fields.iter().any(|v| v.resolve_with_client.is_some()) {
+            quote! {}
+        } else {
+            quote! {
+                /// Derives the resolvable accounts from the provided ones.
+                pub fn resolve(self) -> ::fankor::errors::FankorResult<#lpi_name <'info>> {
+                    #(#unresolved_bindings)*
+                    #(#resolutions)*
+
+                    Ok(#lpi_name {
+                        #(#field_names,)*
+                    })
+                }
+            }
+        };
+
+        quote! {
+            /// The LPI instruction without the accounts that can be derived
+            /// from the others.
+            #[automatically_derived]
+            #visibility struct #unresolved_name #unresolved_lifetime {
+                #(#unresolved_fields),*
+            }
+
+            #[automatically_derived]
+            impl <'info> #unresolved_name #unresolved_lifetime {
+                #resolve_method
+
+                /// Derives the resolvable accounts from the provided ones,
+                /// fetching the on-chain state with `client` when required.
+                #[cfg(feature = "client")]
+                #[allow(unused_variables)]
+                pub async fn resolve_with_client(
+                    self,
+                    client: &::fankor::client::FankorClient,
+                ) -> ::fankor::client::FankorClientResult<#lpi_name <'info>> {
+                    #(#unresolved_bindings)*
+                    #(#resolutions)*
+
+                    Ok(#lpi_name {
+                        #(#field_names,)*
+                    })
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let result = quote! {
         #(#attributes)*
         #visibility struct #name #ty_generics #where_clause {
@@ -513,6 +1188,8 @@ pub fn process_struct(
             #phantom_field
         }
 
+        #(#strict_mode_checks)*
+
         #[automatically_derived]
         impl #impl_generics ::fankor::traits::Instruction<'info> for #name #ty_generics #where_clause {
             type CPI = #cpi_name #phantom_lifetime;
@@ -523,6 +1200,9 @@ pub fn process_struct(
                 buf: &mut &[u8],
                 accounts: &mut &'info [AccountInfo<'info>],
             ) -> ::fankor::errors::FankorResult<Self> {
+                // Check limits before deserializing anything.
+                #try_from_limits
+
                 #(#try_from_fn_deserialize)*
 
                 let result = Self {
@@ -533,12 +1213,22 @@ pub fn process_struct(
                 // Validate instruction.
                 result.validate(context)?;
 
+                #collect_fee_call
+
+                #record_nonce_call
+
                 Ok(result)
             }
         }
 
         #[automatically_derived]
         impl #impl_generics #name #ty_generics #where_clause {
+            /// The maximum length of the instruction data, if any.
+            pub const MAX_DATA_LEN: Option<usize> = #max_data_len;
+
+            /// The maximum number of accounts of the instruction, if any.
+            pub const MAX_ACCOUNTS: Option<usize> = #max_accounts;
+
             fn validate(
                 &self,
                 context: &'info FankorContext<'info>,
@@ -551,10 +1241,25 @@ pub fn process_struct(
 
                 #final_validation
 
+                // Custom validation if `Validate` is implemented.
+                {
+                    use ::fankor::traits::{ValidateImplemented, ValidateNotImplemented};
+                    (&::fankor::traits::ValidateWrapper(self)).__fankor_validate(context)?;
+                }
+
                 Ok(())
             }
 
+            #collect_fee_method
+
+            #idempotency_nonce_method
+
             #(#pda_methods)*
+
+            /// The static layout of the accounts of the instruction in order.
+            pub fn account_layout() -> &'static [::fankor::models::AccountLayout] {
+                &[#(#account_layout),*]
+            }
         }
 
         #[automatically_derived]
@@ -593,6 +1298,8 @@ pub fn process_struct(
                 Ok(())
             }
         }
+
+        #lpi_resolver
     };
 
     // Implement TypeScript generation.
@@ -600,12 +1307,15 @@ pub fn process_struct(
     let mut type_replacements = Vec::new();
     let mut metas_replacements = Vec::new();
     let mut metas_fields = Vec::new();
+    let mut decode_replacements = Vec::new();
+    let mut decode_fields = Vec::new();
     let case_converter = Converter::new().from_case(Case::Snake).to_case(Case::Camel);
     let ts_types = mapped_fields.iter().map(|v| {
         let ty = v.ty.as_ref().unwrap();
         let field_name = case_converter.convert(v.name.to_string());
         let types_replacement_str = format!("_r_interface_types_{}_r_", v.name);
         let metas_replacement_str = format!("_r_interface_metas_{}_r_", v.name);
+        let decode_replacement_str = format!("_r_interface_decode_{}_r_", v.name);
         let writable = v.writable.clone().unwrap_or(quote! { false });
         let signer = v.signer.clone().unwrap_or(quote! { false });
 
@@ -613,24 +1323,101 @@ pub fn process_struct(
              .replace(#types_replacement_str, &< #ty as TsInstructionGen>::generate_type(registered_types))
         });
         metas_fields.push(metas_replacement_str.clone());
+        decode_fields.push(decode_replacement_str.clone());
 
         let value_str = format!("{{}}.{}", field_name);
-        metas_replacements.push(quote! {
-             .replace(#metas_replacement_str, &< #ty as TsInstructionGen>::get_external_account_metas(Cow::Owned(format!(#value_str, value)), #signer, #writable))
-        });
+        match (&v.kind, &v.count) {
+            (FieldKind::Vec(inner), VecCount::Sentinel(_) | VecCount::Remaining) => {
+                // The accounts are not preceded by their length.
+                let sentinel = match &v.count {
+                    VecCount::Sentinel(sentinel) => quote! {
+                        format!(
+                            "accountMetas.push({{ pubkey: new solana.PublicKey('{}'), isSigner: false, isWritable: false }});",
+                            { let sentinel: &::fankor::prelude::Pubkey = #sentinel; sentinel }
+                        )
+                    },
+                    _ => quote! { String::new() },
+                };
+
+                // Sentinel lists end at the sentinel, which is consumed, and
+                // remaining lists at the end of the accounts.
+                let decode_condition = match &v.count {
+                    VecCount::Sentinel(sentinel) => quote! {
+                        format!(
+                            "!accountMetas.peek().equals(new solana.PublicKey('{}'))",
+                            { let sentinel: &::fankor::prelude::Pubkey = #sentinel; sentinel }
+                        )
+                    },
+                    _ => quote! { String::from("accountMetas.remainingLength > 0") },
+                };
+                let decode_sentinel = match &v.count {
+                    VecCount::Sentinel(_) => "accountMetas.next();",
+                    _ => "",
+                };
 
-        format!("{}: {}", field_name, types_replacement_str)
+                decode_replacements.push(quote! {
+                     .replace(#decode_replacement_str, &format!(
+                         "{} = []; while ({}) {{ let v: any; {} {}.push(v); }}{}",
+                         format!(#value_str, value),
+                         #decode_condition,
+                         < #inner as TsInstructionGen>::decode_external_account_metas(Cow::Borrowed("v")),
+                         format!(#value_str, value),
+                         #decode_sentinel
+                     ))
+                });
+
+                metas_replacements.push(quote! {
+                     .replace(#metas_replacement_str, &format!(
+                         "{}.forEach(v => {{ {} }});{}",
+                         format!(#value_str, value),
+                         < #inner as TsInstructionGen>::get_external_account_metas(Cow::Borrowed("v"), #signer, #writable),
+                         #sentinel
+                     ))
+                });
+            }
+            _ => {
+                metas_replacements.push(quote! {
+                     .replace(#metas_replacement_str, &< #ty as TsInstructionGen>::get_external_account_metas(Cow::Owned(format!(#value_str, value)), #signer, #writable))
+                });
+                decode_replacements.push(quote! {
+                     .replace(#decode_replacement_str, &< #ty as TsInstructionGen>::decode_external_account_metas(Cow::Owned(format!(#value_str, value))))
+                });
+            }
+        }
+
+        // Optional accounts can be omitted in the client.
+        if is_option_type(ty) {
+            format!("{}?: {}", field_name, types_replacement_str)
+        } else {
+            format!("{}: {}", field_name, types_replacement_str)
+        }
     }).collect::<Vec<_>>();
 
-    let ts_type = format!(
+    let mut ts_type = format!(
         "export interface {} {{ {} }};",
         name_str,
         ts_types.join(",")
     );
 
+    if arguments.max_data_len.is_some() || arguments.max_accounts.is_some() {
+        let ts_limit = |v: Option<usize>| v.map_or("undefined".to_string(), |v| v.to_string());
+
+        ts_type.push_str(&format!(
+            "export const {}Limits = {{ maxDataLen: {}, maxAccounts: {} }};",
+            name_str,
+            ts_limit(arguments.max_data_len),
+            ts_limit(arguments.max_accounts)
+        ));
+    }
+
     let ts_metas = metas_fields.join("");
+    let ts_decode = decode_fields.join("");
     let get_metas_of_replacement_str =
         format!("getMetasOf{}(_r_value_r_,accountMetas, writer);", name_str);
+    let decode_metas_of_replacement_str = format!(
+        "_r_value_r_ = decodeMetasOf{}(reader, accountMetas);",
+        name_str
+    );
     let test_name = format_ident!("__ts_gen_test__instruction_accounts_{}", name_str);
     let test_name_str = test_name.to_string();
     let result = quote! {
@@ -651,6 +1438,10 @@ pub fn process_struct(
                     Cow::Borrowed(#name_str)
                 }
 
+                fn account_layout() -> &'static [::fankor::models::AccountLayout] {
+                    #name::account_layout()
+                }
+
                 fn generate_type(registered_types: &mut TsTypesCache) -> Cow<'static, str> {
                     let name = Self::value_type();
 
@@ -682,6 +1473,14 @@ pub fn process_struct(
                 ) -> Cow<'static, str> {
                     Cow::Owned(#get_metas_of_replacement_str.replace("_r_value_r_", &value))
                 }
+
+                fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+                    Cow::Owned(#ts_decode #(#decode_replacements)*)
+                }
+
+                fn decode_external_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+                    Cow::Owned(#decode_metas_of_replacement_str.replace("_r_value_r_", &value))
+                }
             }
 
             #[test]
@@ -696,3 +1495,109 @@ pub fn process_struct(
 
     Ok(result.into())
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Whether the type is an instruction argument, i.e. it does not consume accounts.
+fn is_argument_type(ty: &Type) -> bool {
+    if let Type::Path(v) = ty {
+        let last_arg = v.path.segments.last().unwrap();
+        let ident = last_arg.ident.to_string();
+
+        return ident == "Argument" || ident == "RestArguments" || ident == "Batch";
+    }
+
+    false
+}
+
+/// Whether the type is an optional account, i.e. `Option<T>`.
+fn is_option_type(ty: &Type) -> bool {
+    if let Type::Path(v) = ty {
+        return v.path.segments.last().unwrap().ident == "Option";
+    }
+
+    false
+}
+
+/// Maps a boolean attribute to its value when it is a literal, otherwise `None`.
+fn static_bool(value: &Option<TokenStream>) -> TokenStream {
+    match value.as_ref().map(|v| v.to_string()) {
+        Some(v) if v == "true" => quote! { Some(true) },
+        Some(v) if v == "false" => quote! { Some(false) },
+        _ => quote! { None },
+    }
+}
+
+/// Describes the constraints of a field as written in its `#[account(...)]`
+/// attributes, excluding `writable` and `signer` that are part of the layout.
+fn constraint_descriptions(field: &Field) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut push = |name: &str, value: &Option<TokenStream>| {
+        if let Some(value) = value {
+            result.push(format!("{} = {}", name, value));
+        }
+    };
+
+    push("owner", &field.owner);
+    push("address", &field.address);
+    push("initialized", &field.initialized);
+    push("executable", &field.executable);
+    push("rent_exempt", &field.rent_exempt);
+    push("tombstoned", &field.tombstoned);
+    push("program_data", &field.program_data);
+    push("upgrade_authority", &field.upgrade_authority);
+    push("signers_at_least", &field.signers_at_least);
+
+    if let Some((mint, min_amount)) = &field.token_gate {
+        result.push(format!("token_gate = ({}, {})", mint, min_amount));
+    }
+
+    if let Some(rent_to) = &field.rent_to {
+        result.push(format!("rent_to = {}", rent_to));
+    }
+
+    if field.unique {
+        result.push("unique".to_string());
+    }
+
+    if let Some(min) = &field.min {
+        result.push(format!("min = {}", min));
+    }
+
+    if let Some(max) = &field.max {
+        match field.max_mode {
+            VecMaxMode::Error => result.push(format!("max = {}", max)),
+            VecMaxMode::Truncate => result.push(format!("max = {} (truncated)", max)),
+            VecMaxMode::Rest => result.push(format!("max = {} (extra accounts in rest)", max)),
+        }
+    }
+
+    match &field.count {
+        VecCount::Length => {}
+        VecCount::Sentinel(sentinel) => result.push(format!("sentinel = {}", sentinel)),
+        VecCount::Remaining => result.push("remaining".to_string()),
+    }
+
+    if let Some(pda_bytes) = &field.pda_bytes {
+        result.push(format!("pda_bytes = {}", pda_bytes.data));
+    }
+
+    if let Some(pda_program_id) = &field.pda_program_id {
+        result.push(format!("pda_program_id = {}", pda_program_id));
+    }
+
+    for constraint in &field.constraints {
+        result.push(format!("constraint = {}", constraint.data));
+    }
+
+    result
+}
+
+fn option_to_tokens(value: Option<usize>) -> TokenStream {
+    match value {
+        Some(v) => quote! { Some(#v) },
+        None => quote! { None },
+    }
+}
@@ -0,0 +1,97 @@
+use quote::{format_ident, quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{Error, Item};
+
+use crate::fnk_syn::FnkMetaArgumentList;
+use crate::Result;
+
+pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
+    let (name, visibility, generics, attrs) = match &input {
+        Item::Struct(item) => (&item.ident, &item.vis, &item.generics, &item.attrs),
+        Item::Enum(item) => (&item.ident, &item.vis, &item.generics, &item.attrs),
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "Migratable can only be derived for struct or enum declarations",
+            ));
+        }
+    };
+
+    if !generics.params.is_empty() {
+        return Err(Error::new(
+            generics.span(),
+            "Migratable cannot be derived for generic accounts",
+        ));
+    }
+
+    let attribute = attrs
+        .iter()
+        .find(|v| v.path().is_ident("migratable"))
+        .ok_or_else(|| {
+            Error::new(
+                name.span(),
+                "The correct pattern is #[migratable(from = <type>, with = <function>)]",
+            )
+        })?;
+
+    let mut args = attribute.parse_args::<FnkMetaArgumentList>()?;
+    args.error_on_duplicated()?;
+
+    let old_type = args
+        .pop("from", Some(true), false)?
+        .unwrap()
+        .value
+        .to_token_stream();
+    let migrate_fn = args
+        .pop("with", Some(true), false)?
+        .unwrap()
+        .value
+        .to_token_stream();
+
+    args.error_on_unknown()?;
+
+    let instruction_name = format_ident!("Migrate{}", name);
+    let doc = format!("Migrates `{}` accounts to `{}`.", old_type, name);
+
+    let result = quote! {
+        #[automatically_derived]
+        impl ::fankor::traits::Migratable for #name {
+            type Old = #old_type;
+
+            fn migrate(old: &Self::Old) -> ::fankor::errors::FankorResult<Self> {
+                #migrate_fn(old)
+            }
+        }
+
+        #[doc = #doc]
+        #[::fankor::prelude::instruction]
+        #visibility struct #instruction_name<'info> {
+            #[account(writable)]
+            #[account(signer)]
+            #[account(unchecked_reason = "Only pays the rent of the migrated accounts")]
+            pub payer: ::fankor::models::UncheckedAccount<'info>,
+
+            pub system_program: ::fankor::models::Program<'info, ::fankor::models::System>,
+
+            #[account(writable)]
+            pub accounts: ::fankor::models::Rest<'info>,
+        }
+
+        #[automatically_derived]
+        impl<'info> #instruction_name<'info> {
+            pub fn processor(
+                self,
+                _context: FankorContext<'info>,
+            ) -> ::fankor::errors::FankorResult<::fankor::models::MigrationProgress> {
+                ::fankor::models::migrate_accounts::<#name>(
+                    self.accounts.context(),
+                    self.accounts.accounts(),
+                    self.payer.info(),
+                    &self.system_program,
+                )
+            }
+        }
+    };
+
+    Ok(result.into())
+}
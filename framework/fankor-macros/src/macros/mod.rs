@@ -8,7 +8,10 @@ pub mod enum_discriminants;
 pub mod error;
 pub mod field_offset;
 pub mod instruction;
+pub mod migratable;
+pub mod namespace;
 pub mod program;
+pub mod program_info;
 pub mod serialize;
 pub mod setup;
 pub mod ts_gen;
@@ -0,0 +1,317 @@
+use convert_case::{Case, Converter};
+use proc_macro2::Ident;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{bracketed, parenthesized, Error, Expr, Lit, Token, Type};
+
+use crate::Result;
+
+/// The maximum number of seeds of a PDA.
+const MAX_SEEDS: usize = 16;
+
+/// The maximum length of a PDA seed.
+const MAX_SEED_LEN: usize = 32;
+
+pub struct NamespaceArgs {
+    entries: Vec<NamespaceEntry>,
+}
+
+impl Parse for NamespaceArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let list = Punctuated::<NamespaceEntry, Token![,]>::parse_terminated(input)?;
+
+        Ok(NamespaceArgs {
+            entries: list.into_iter().collect(),
+        })
+    }
+}
+
+struct NamespaceEntry {
+    name: Ident,
+    arguments: Vec<(Ident, Type)>,
+    seeds: Vec<Expr>,
+}
+
+impl Parse for NamespaceEntry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let mut arguments = Vec::new();
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            parenthesized!(content in input);
+
+            let list = Punctuated::<NamespaceArgument, Token![,]>::parse_terminated(&content)?;
+            arguments = list.into_iter().map(|v| (v.name, v.ty)).collect();
+        }
+
+        input.parse::<Token![=>]>()?;
+
+        let content;
+        bracketed!(content in input);
+
+        let list = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+
+        Ok(NamespaceEntry {
+            name,
+            arguments,
+            seeds: list.into_iter().collect(),
+        })
+    }
+}
+
+struct NamespaceArgument {
+    name: Ident,
+    ty: Type,
+}
+
+impl Parse for NamespaceArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+
+        Ok(NamespaceArgument { name, ty })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+pub fn processor(args: NamespaceArgs) -> Result<proc_macro::TokenStream> {
+    let case_converter = Converter::new()
+        .from_case(Case::Snake)
+        .to_case(Case::Pascal);
+
+    let mut names = Vec::<String>::new();
+    let mut items = Vec::new();
+    let mut ts_functions = Vec::new();
+
+    for entry in &args.entries {
+        let name = &entry.name;
+        let name_str = name.to_string();
+
+        if names.contains(&name_str) {
+            return Err(Error::new(name.span(), "Duplicated namespace entry"));
+        }
+
+        names.push(name_str.clone());
+
+        if entry.seeds.is_empty() || entry.seeds.len() > MAX_SEEDS {
+            return Err(Error::new(
+                name.span(),
+                format!("A PDA must have between 1 and {} seeds", MAX_SEEDS),
+            ));
+        }
+
+        // Arguments.
+        let mut rust_arguments = Vec::new();
+        let mut rust_address_arguments = Vec::new();
+        let mut rust_argument_names = Vec::new();
+        let mut ts_arguments = Vec::new();
+        let mut ts_converters = Vec::new();
+
+        for (argument, ty) in &entry.arguments {
+            let (ts_type, ts_converter) = ts_argument_type(ty)?;
+
+            rust_arguments.push(quote! { #argument: &'a #ty });
+            rust_address_arguments.push(quote! { #argument: &#ty });
+            rust_argument_names.push(argument);
+            ts_arguments.push(format!("{}: {}", argument, ts_type));
+            ts_converters.push((argument.to_string(), ts_converter));
+        }
+
+        // Seeds.
+        let mut rust_seeds = Vec::new();
+        let mut const_seeds = Some(Vec::new());
+        let mut ts_seeds = Vec::new();
+
+        for seed in &entry.seeds {
+            match seed {
+                Expr::Lit(v) => {
+                    let bytes = match &v.lit {
+                        Lit::ByteStr(v) => v.value(),
+                        Lit::Str(v) => v.value().into_bytes(),
+                        _ => {
+                            return Err(Error::new(
+                                seed.span(),
+                                "Namespace seeds must be byte strings, strings or arguments",
+                            ));
+                        }
+                    };
+
+                    if bytes.len() > MAX_SEED_LEN {
+                        return Err(Error::new(
+                            seed.span(),
+                            format!("A PDA seed cannot be longer than {} bytes", MAX_SEED_LEN),
+                        ));
+                    }
+
+                    let bytes_literal = syn::LitByteStr::new(&bytes, seed.span());
+                    rust_seeds.push(quote! { #bytes_literal });
+
+                    if let Some(const_seeds) = &mut const_seeds {
+                        const_seeds.push(quote! { #bytes_literal });
+                    }
+
+                    ts_seeds.push(format!(
+                        "new Uint8Array([{}])",
+                        bytes
+                            .iter()
+                            .map(|v| v.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
+                Expr::Path(v) if v.qself.is_none() && v.path.get_ident().is_some() => {
+                    let argument = v.path.get_ident().unwrap();
+                    let argument_str = argument.to_string();
+                    let converter = match ts_converters.iter().find(|(v, _)| v == &argument_str)
+                    {
+                        Some((_, v)) => v,
+                        None => {
+                            return Err(Error::new(
+                                argument.span(),
+                                format!("Unknown argument '{}'", argument),
+                            ));
+                        }
+                    };
+
+                    rust_seeds.push(quote! { ::std::convert::AsRef::<[u8]>::as_ref(#argument) });
+                    const_seeds = None;
+                    ts_seeds.push(converter.replace("{}", &argument_str));
+                }
+                _ => {
+                    return Err(Error::new(
+                        seed.span(),
+                        "Namespace seeds must be byte strings, strings or arguments",
+                    ));
+                }
+            }
+        }
+
+        // Rust items.
+        let seeds_count = rust_seeds.len();
+        let seeds_method_name = format_ident!("{}_seeds", name);
+        let address_method_name = format_ident!("{}_address", name);
+        let seeds_doc = format!(
+            " The seeds of the `{}` PDA, which can be used in the `pda` attribute of the accounts.",
+            name_str
+        );
+        let address_doc = format!(
+            " Finds the address and bump of the `{}` PDA in the current program.",
+            name_str
+        );
+
+        items.push(quote! {
+            #[doc = #seeds_doc]
+            #[allow(clippy::needless_lifetimes)]
+            pub fn #seeds_method_name<'a>(#(#rust_arguments),*) -> [&'a [u8]; #seeds_count] {
+                [#(#rust_seeds),*]
+            }
+
+            #[doc = #address_doc]
+            pub fn #address_method_name(#(#rust_address_arguments),*) -> (::fankor::prelude::Pubkey, u8) {
+                ::fankor::prelude::Pubkey::find_program_address(&#seeds_method_name(#(#rust_argument_names),*), &crate::ID)
+            }
+        });
+
+        if let Some(const_seeds) = const_seeds {
+            let const_name = format_ident!("{}_SEEDS", name_str.to_uppercase());
+            let const_doc = format!(" The seeds of the `{}` PDA.", name_str);
+
+            items.push(quote! {
+                #[doc = #const_doc]
+                pub const #const_name: [&[u8]; #seeds_count] = [#(#const_seeds),*];
+            });
+        }
+
+        // TypeScript function.
+        let ts_name = format!("find{}Pda", case_converter.convert(&name_str));
+        ts_arguments.push("programId: solana.PublicKey = ID".to_string());
+
+        ts_functions.push((
+            name_str,
+            format!(
+                "export function {}({}): [solana.PublicKey, number] {{
+                    return solana.PublicKey.findProgramAddressSync([{}], programId);
+                }}",
+                ts_name,
+                ts_arguments.join(", "),
+                ts_seeds.join(", "),
+            ),
+        ));
+    }
+
+    let ts_functions = ts_functions.iter().map(|(name, function)| {
+        quote! {
+            action_context.add_pda_function(#name, #function).unwrap();
+        }
+    });
+    let test_name = format_ident!("__ts_gen_test__namespace_{}", names.join("_"));
+    let test_name_str = test_name.to_string();
+
+    let result = quote! {
+        #(#items)*
+
+        #[cfg(feature = "ts-gen")]
+        #[automatically_derived]
+        #[allow(non_snake_case)]
+        mod #test_name {
+            #[test]
+            fn build() {
+                crate::__ts_gen_test__setup::BUILD_CONTEXT.register_action(#test_name_str, file!(), move |action_context| {
+                    #(#ts_functions)*
+                })
+            }
+        }
+    };
+
+    Ok(result.into())
+}
+
+/// Gets the TypeScript type of an argument and the expression that converts
+/// it into bytes, where `{}` is replaced by the argument.
+fn ts_argument_type(ty: &Type) -> Result<(&'static str, &'static str)> {
+    let error = || {
+        Error::new(
+            ty.span(),
+            "Namespace arguments must be Pubkey, String, str, Vec<u8> or [u8; N]",
+        )
+    };
+
+    match ty {
+        Type::Array(v) => match &*v.elem {
+            Type::Path(v) if v.path.is_ident("u8") => Ok(("Uint8Array", "{}")),
+            _ => Err(error()),
+        },
+        Type::Path(v) => {
+            let segment = v.path.segments.last().ok_or_else(error)?;
+
+            match segment.ident.to_string().as_str() {
+                "Pubkey" => Ok(("solana.PublicKey", "{}.toBytes()")),
+                "String" | "str" => Ok(("string", "new TextEncoder().encode({})")),
+                "Vec" => {
+                    let is_bytes = match &segment.arguments {
+                        syn::PathArguments::AngleBracketed(v) => {
+                            v.args.len() == 1
+                                && matches!(v.args.first(), Some(syn::GenericArgument::Type(Type::Path(v))) if v.path.is_ident("u8"))
+                        }
+                        _ => false,
+                    };
+
+                    if is_bytes {
+                        Ok(("Uint8Array", "{}"))
+                    } else {
+                        Err(error())
+                    }
+                }
+                _ => Err(error()),
+            }
+        }
+        _ => Err(error()),
+    }
+}
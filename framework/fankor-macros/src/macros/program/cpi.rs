@@ -5,9 +5,10 @@ use crate::macros::program::programs::Program;
 use crate::Result;
 
 pub fn build_cpi(program: &Program) -> Result<TokenStream> {
-    let methods = program.methods.iter().map(|v| {
+    let methods = program.methods.iter().filter(|v| v.group.is_none()).map(|v| {
         let program_name = &program.name;
         let method_name = &v.snake_name;
+        let method_with_pdas_name = format_ident!("{}_with_pdas", method_name);
         let type_name = &v.name;
         let discriminant_name = format_ident!("{}Discriminant", program_name);
 
@@ -39,6 +40,25 @@ pub fn build_cpi(program: &Program) -> Result<TokenStream> {
 
                 #result
             }
+
+            /// Same as the method without the suffix but it also signs with the seeds
+            /// of the PDAs of the calling program verified in `context`.
+            pub fn #method_with_pdas_name<'info>(context: &::fankor::models::FankorContext<'info>, _program: &::fankor::models::Program<super::#program_name>, accounts: <#type_name<'info> as ::fankor::traits::Instruction<'info>>::CPI, signer_seeds: &[&[&[u8]]]) -> ::fankor::errors::FankorResult<#result_param> {
+                let mut data = vec![#discriminant_name::#type_name.code()];
+                let mut metas = Vec::new();
+                let mut infos = Vec::new();
+                ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(&accounts, &mut data, &mut metas, &mut infos)?;
+
+                let instruction = ::fankor::prelude::solana_program::instruction::Instruction {
+                    program_id: *<super::#program_name as ::fankor::traits::ProgramType>::address(),
+                    accounts: metas,
+                    data
+                };
+
+                context.invoke_signed_with_pdas(&instruction, &infos, signer_seeds)?;
+
+                #result
+            }
         }
     });
 
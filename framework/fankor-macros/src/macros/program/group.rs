@@ -0,0 +1,245 @@
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{Error, Item};
+
+use crate::fnk_syn::FnkMetaArgumentList;
+use crate::macros::program::build_dispatch_method;
+use crate::macros::program::programs::InstructionGroup;
+use crate::Result;
+
+pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::TokenStream> {
+    // Process input.
+    let item = match input {
+        Item::Enum(v) => v,
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "instruction_group macro can only be applied to an enum declaration",
+            ));
+        }
+    };
+
+    let group = InstructionGroup::from(args, item)?;
+    let name = &group.name;
+    let discriminant_name = format_ident!("{}Discriminant", group.name);
+
+    let group_methods = group
+        .methods
+        .iter()
+        .map(|v| {
+            let variant_name = &v.name;
+            let attrs = &v.attrs;
+
+            quote! {
+                #(#attrs)*
+                #variant_name
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut discriminant_constants = Vec::new();
+    let dispatch_methods = group
+        .methods
+        .iter()
+        .map(|v| {
+            let variant_name = &v.name;
+
+            discriminant_constants.push(quote! {
+                const #variant_name: u8 = #discriminant_name::#variant_name.code();
+            });
+
+            build_dispatch_method(v)
+        })
+        .collect::<Vec<_>>();
+
+    let middleware_call = group.middleware.as_ref().map(|middleware| {
+        quote! {
+            #middleware(context, instruction)?;
+        }
+    });
+
+    let cpi_methods = group.methods.iter().map(|v| {
+        let method_name = format_ident!("cpi_{}", v.snake_name);
+        let method_with_pdas_name = format_ident!("cpi_{}_with_pdas", v.snake_name);
+        let type_name = &v.name;
+
+        let (result, result_param) = if let Some(result_type) = &v.return_type {
+            (
+                quote! {
+                    Ok(::fankor::models::CpiReturn::new())
+                },
+                quote! {
+                    ::fankor::models::CpiReturn<#result_type>
+                },
+            )
+        } else {
+            (quote! { Ok(()) }, quote! { () })
+        };
+
+        quote! {
+            #[cfg(not(feature = "library"))]
+            pub fn #method_name<'info, P: ::fankor::traits::ProgramType>(context: &::fankor::models::FankorContext<'info>, _program: &::fankor::models::Program<P>, accounts: <#type_name<'info> as ::fankor::traits::Instruction<'info>>::CPI, signer_seeds: &[&[&[u8]]]) -> ::fankor::errors::FankorResult<#result_param>
+            where
+                Self: ::fankor::traits::InstructionGroupOf<P>,
+            {
+                let mut data = vec![<Self as ::fankor::traits::InstructionGroupOf<P>>::DISCRIMINANT, #discriminant_name::#type_name.code()];
+                let mut metas = Vec::new();
+                let mut infos = Vec::new();
+                ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(&accounts, &mut data, &mut metas, &mut infos)?;
+
+                let instruction = ::fankor::prelude::solana_program::instruction::Instruction {
+                    program_id: *P::address(),
+                    accounts: metas,
+                    data
+                };
+
+                // The program is chosen by the caller so it must be in the CPI allowlist.
+                ::fankor::cpi::raw::invoke_signed(context, &instruction, &infos, signer_seeds)?;
+
+                #result
+            }
+
+            /// Same as the method without the suffix but it also signs with the seeds
+            /// of the PDAs of the calling program verified in `context`.
+            #[cfg(not(feature = "library"))]
+            pub fn #method_with_pdas_name<'info, P: ::fankor::traits::ProgramType>(context: &::fankor::models::FankorContext<'info>, _program: &::fankor::models::Program<P>, accounts: <#type_name<'info> as ::fankor::traits::Instruction<'info>>::CPI, signer_seeds: &[&[&[u8]]]) -> ::fankor::errors::FankorResult<#result_param>
+            where
+                Self: ::fankor::traits::InstructionGroupOf<P>,
+            {
+                let mut data = vec![<Self as ::fankor::traits::InstructionGroupOf<P>>::DISCRIMINANT, #discriminant_name::#type_name.code()];
+                let mut metas = Vec::new();
+                let mut infos = Vec::new();
+                ::fankor::traits::CpiInstruction::serialize_into_instruction_parts(&accounts, &mut data, &mut metas, &mut infos)?;
+
+                let instruction = ::fankor::prelude::solana_program::instruction::Instruction {
+                    program_id: *P::address(),
+                    accounts: metas,
+                    data
+                };
+
+                context.invoke_signed_with_pdas(&instruction, &infos, signer_seeds)?;
+
+                #result
+            }
+        }
+    });
+
+    let lpi_methods = group.methods.iter().map(|v| {
+        let method_name = format_ident!("lpi_{}", v.snake_name);
+        let type_name = &v.name;
+
+        quote! {
+            #[cfg(any(test, feature = "library"))]
+            pub fn #method_name<'info, P: ::fankor::traits::ProgramType>(accounts: <#type_name<'info> as ::fankor::traits::Instruction<'info>>::LPI) -> ::fankor::errors::FankorResult<::fankor::prelude::solana_program::instruction::Instruction>
+            where
+                Self: ::fankor::traits::InstructionGroupOf<P>,
+            {
+                let mut data = vec![<Self as ::fankor::traits::InstructionGroupOf<P>>::DISCRIMINANT, #discriminant_name::#type_name.code()];
+                let mut metas = Vec::new();
+                ::fankor::traits::LpiInstruction::serialize_into_instruction_parts(&accounts, &mut data, &mut metas)?;
+
+                Ok(::fankor::prelude::solana_program::instruction::Instruction {
+                    program_id: *P::address(),
+                    accounts: metas,
+                    data
+                })
+            }
+        }
+    });
+
+    let result = quote! {
+        #[automatically_derived]
+        #[allow(dead_code)]
+        #[derive(Debug, Copy, Clone, EnumDiscriminants)]
+        #[non_exhaustive]
+        #[repr(u8)]
+        pub enum #name {
+            #(#group_methods,)*
+        }
+
+        #[automatically_derived]
+        impl ::fankor::traits::InstructionGroup for #name {
+            #[allow(non_snake_case)]
+            #[allow(non_upper_case_globals)]
+            fn dispatch<'info>(
+                context: &'info ::fankor::prelude::FankorContext<'info>,
+                ix_data: &mut &[u8],
+                ix_accounts: &mut &'info [::fankor::prelude::AccountInfo<'info>],
+            ) -> ::fankor::errors::FankorResult<()> {
+                #[allow(dead_code)]
+                #[inline(never)]
+                fn parse_accounts<'info, T: ::fankor::traits::Instruction<'info>>(
+                    context: &'info ::fankor::prelude::FankorContext<'info>,
+                    ix_data: &mut &[u8],
+                    ix_accounts: &mut &'info [::fankor::prelude::AccountInfo<'info>],
+                ) -> ::fankor::errors::FankorResult<Box<T>> {
+                    Ok(Box::new(T::try_from(context, ix_data, ix_accounts)?))
+                }
+
+                if ix_data.is_empty() {
+                    return Err(::fankor::errors::FankorErrorCode::MissingInstructionDiscriminant.into());
+                }
+
+                // Process data.
+                let sighash = ix_data[0];
+                let mut ix_data = &ix_data[1..];
+                let mut ix_accounts = *ix_accounts;
+
+                #[allow(unused_variables)]
+                let instruction = match #discriminant_name::from_code(sighash) {
+                    Some(v) => v,
+                    None => return Err(::fankor::errors::FankorErrorCode::InstructionDiscriminantNotFound.into()),
+                };
+
+                #middleware_call
+
+                #(#discriminant_constants)*
+
+                match sighash {
+                    #(#dispatch_methods,)*
+                    _ => Err(::fankor::errors::FankorErrorCode::InstructionDiscriminantNotFound.into())
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #name {
+            #(#cpi_methods)*
+
+            #(#lpi_methods)*
+        }
+    };
+
+    // Implement TypeScript generation.
+    let method_registration = group
+        .methods
+        .iter()
+        .map(|v| {
+            let name = &v.name;
+            let name_str = name.to_string();
+            let discriminant_name_str = discriminant_name.to_string();
+
+            quote! {
+                action_context.add_program_group_method::<#name<'info>>(group_discriminant, #discriminant_name_str, #name_str, #discriminant_name::#name.code()).unwrap();
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let result = quote! {
+        #result
+
+        #[cfg(feature = "ts-gen")]
+        #[automatically_derived]
+        impl #name {
+            #[doc(hidden)]
+            pub fn __ts_gen_register_methods<'info>(
+                action_context: &mut ::fankor::ts_gen::DataContext,
+                group_discriminant: (&'static str, u8),
+            ) {
+                #(#method_registration)*
+            }
+        }
+    };
+
+    Ok(result.into())
+}
@@ -5,7 +5,7 @@ use crate::macros::program::programs::Program;
 use crate::Result;
 
 pub fn build_lpi(program: &Program) -> Result<TokenStream> {
-    let methods = program.methods.iter().map(|v| {
+    let methods = program.methods.iter().filter(|v| v.group.is_none()).map(|v| {
         let program_name = &program.name;
         let method_name = &v.snake_name;
         let type_name = &v.name;
@@ -1,3 +1,4 @@
+use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::spanned::Spanned;
 use syn::{Error, Item};
@@ -6,10 +7,11 @@ use cpi::build_cpi;
 use lpi::build_lpi;
 
 use crate::fnk_syn::FnkMetaArgumentList;
-use crate::macros::program::programs::Program;
+use crate::macros::program::programs::{Program, ProgramMethod};
 use crate::Result;
 
 mod cpi;
+pub mod group;
 mod lpi;
 mod programs;
 
@@ -39,64 +41,75 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         .map(|v| {
             let variant_name = &v.name;
             let attrs = &v.attrs;
+            let group = v.group.as_ref().map(|v| quote! { (#v) });
 
             quote! {
                 #(#attrs)*
-                #variant_name
+                #variant_name #group
             }
         })
         .collect::<Vec<_>>();
 
     let mut discriminant_constants = Vec::new();
-    let dispatch_methods = program.methods.iter().map(|v| {
-        let variant_name = &v.name;
-        let instruction_msg = format!("Instruction: {}", v.name);
+    let dispatch_methods = program
+        .methods
+        .iter()
+        .map(|v| {
+            let variant_name = &v.name;
 
-        discriminant_constants.push(quote! {
-            const #variant_name: u8 = #discriminant_name::#variant_name.code();
-        });
+            discriminant_constants.push(quote! {
+                const #variant_name: u8 = #discriminant_name::#variant_name.code();
+            });
 
-        let accounts = if v.boxed {
-            quote! {
-                let accounts = parse_accounts::<#variant_name<'info>>(
+            build_dispatch_method(v)
+        })
+        .collect::<Vec<_>>();
+
+    let group_impls = program
+        .methods
+        .iter()
+        .filter_map(|v| {
+            let variant_name = &v.name;
+            let group = v.group.as_ref()?;
+
+            Some(quote! {
+                #[automatically_derived]
+                impl ::fankor::traits::InstructionGroupOf<#name> for #group {
+                    const DISCRIMINANT: u8 = #discriminant_name::#variant_name.code();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let testable_dispatch_method = if program.testable {
+        quote! {
+            #[cfg(any(feature = "testable-program"))]
+            0 => {
+                ::fankor::prelude::msg!("Testable Instruction");
+                let accounts = parse_accounts::<::fankor::prelude::TestInstruction<'info>>(
                     context,
                     &mut ix_data,
                     &mut ix_accounts,
                 )?;
-            }
-        } else {
-            quote! {
-                let accounts = <#variant_name<'info> as fankor::traits::Instruction>::try_from(&context, &mut ix_data, &mut ix_accounts)?;
-            }
-        };
-
-        quote! {
-            #variant_name => {
-                ::fankor::prelude::msg!(#instruction_msg);
-                #accounts
 
                 if ix_accounts.len() != 0 {
                     return Err(::fankor::errors::FankorErrorCode::UnusedAccounts.into());
                 }
 
-                let result = accounts.processor(context.clone())?;
-
-                // Write return data.
-                if type_id_of(&result) != type_id_of(&()) {
-                    ::fankor::prelude::solana_program::program::set_return_data(&::fankor::prelude::BorshSerialize::try_to_vec(&result).unwrap());
-                }
+                accounts.processor(context.clone())?;
 
                 Ok(())
             }
         }
-    }).collect::<Vec<_>>();
+    } else {
+        quote! {}
+    };
 
-    let testable_dispatch_method = if program.testable {
+    let growable_dispatch_method = if let Some(growable) = &program.growable {
         quote! {
-            #[cfg(any(feature = "testable-program"))]
-            0 => {
-                ::fankor::prelude::msg!("Testable Instruction");
-                let accounts = parse_accounts::<::fankor::prelude::TestInstruction<'info>>(
+            ::fankor::prelude::GROW_INSTRUCTION_DISCRIMINANT => {
+                ::fankor::prelude::msg!("Grow Instruction");
+                let accounts = parse_accounts::<::fankor::prelude::GrowInstruction<'info, #growable>>(
                     context,
                     &mut ix_data,
                     &mut ix_accounts,
@@ -115,6 +128,68 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         quote! {}
     };
 
+    // The discriminants of the maintenance instructions are reserved.
+    let mut reserved_discriminants = Vec::new();
+
+    if program.growable.is_some() {
+        reserved_discriminants.push(quote! { ::fankor::prelude::GROW_INSTRUCTION_DISCRIMINANT });
+    }
+
+    if program.versioned {
+        reserved_discriminants.push(quote! { ::fankor::prelude::VERSION_INSTRUCTION_DISCRIMINANT });
+    }
+
+    if program.account_registry.is_some() {
+        reserved_discriminants
+            .push(quote! { ::fankor::prelude::ACCOUNT_REGISTRY_INSTRUCTION_DISCRIMINANT });
+    }
+
+    let reserved_discriminant_asserts = program
+        .methods
+        .iter()
+        .flat_map(|v| {
+            let discriminant_name = &discriminant_name;
+            let variant_name = &v.name;
+
+            reserved_discriminants.iter().map(move |reserved| {
+                quote! {
+                    const_assert!(#discriminant_name::#variant_name.code() != #reserved);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let versioned_dispatch_method = if program.versioned {
+        quote! {
+            ::fankor::prelude::VERSION_INSTRUCTION_DISCRIMINANT => {
+                ::fankor::prelude::msg!("Version Instruction");
+
+                if ix_accounts.len() != 0 {
+                    return Err(::fankor::errors::FankorErrorCode::UnusedAccounts.into());
+                }
+
+                ::fankor::prelude::process_version_instruction(env!("CARGO_PKG_VERSION"))
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let account_registry_dispatch_method = if let Some(accounts) = &program.account_registry {
+        quote! {
+            ::fankor::prelude::ACCOUNT_REGISTRY_INSTRUCTION_DISCRIMINANT => {
+                ::fankor::prelude::msg!("Account Registry Instruction");
+                ::fankor::prelude::process_account_registry_instruction(
+                    context,
+                    ix_accounts,
+                    &#accounts::account_registry(),
+                )
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let dispatch_default = if let Some(fallback_method_call) = &program.fallback_method_call {
         quote! {
             _ => {
@@ -128,6 +203,19 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         }
     };
 
+    let transfer_hook_dispatch = if let Some(transfer_hook_method_call) =
+        &program.transfer_hook_method_call
+    {
+        quote! {
+            if let Some(execute) = ::fankor::models::TransferHookExecute::try_from_instruction(accounts, data)? {
+                ::fankor::prelude::msg!("Instruction: TransferHookExecute");
+                return #transfer_hook_method_call;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let cpi_mod = build_cpi(&program)?;
     let lpi_mod = build_lpi(&program)?;
 
@@ -141,6 +229,10 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
             #(#program_methods,)*
         }
 
+        #(#group_impls)*
+
+        #(#reserved_discriminant_asserts)*
+
         #[automatically_derived]
         #[cfg(any(test, feature = "test-utils"))]
         impl #name {
@@ -148,27 +240,22 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
                 ::solana_program_test::ProgramTest::new(
                     #name_str,
                     crate::ID,
-                    Some(
-                        |first_instruction_account: usize,
-                            invoke_context: &mut ::solana_program_test::InvokeContext| {
-                            ::solana_program_test::builtin_process_instruction(
-                                |program_id: &::fankor::prelude::Pubkey,
-                                    accounts: &[::fankor::prelude::AccountInfo],
-                                    data: &[u8]| {
-                                    // Hacks to change the lifetime to 'info.
-                                    let program_id = unsafe {
-                                        std::mem::transmute::<&::fankor::prelude::Pubkey, &'info ::fankor::prelude::Pubkey>(program_id)
-                                    };
-                                    let accounts = unsafe {
-                                        std::mem::transmute::<&[::fankor::prelude::AccountInfo], &'info [::fankor::prelude::AccountInfo<'info>]>(accounts)
-                                    };
-
-                                    #program_entry_name(program_id, accounts, data)
-                                },
-                                first_instruction_account,
-                                invoke_context,
-                            )
-                        },
+                    // The processor macro adapts the entrypoint to the builtin
+                    // signature of each version of `solana-program-test`.
+                    ::solana_program_test::processor!(
+                        |program_id: &::fankor::prelude::Pubkey,
+                            accounts: &[::fankor::prelude::AccountInfo],
+                            data: &[u8]| {
+                            // Hacks to change the lifetime to 'info.
+                            let program_id = unsafe {
+                                std::mem::transmute::<&::fankor::prelude::Pubkey, &'info ::fankor::prelude::Pubkey>(program_id)
+                            };
+                            let accounts = unsafe {
+                                std::mem::transmute::<&[::fankor::prelude::AccountInfo], &'info [::fankor::prelude::AccountInfo<'info>]>(accounts)
+                            };
+
+                            #program_entry_name(program_id, accounts, data)
+                        }
                     )
                 )
             }
@@ -186,7 +273,7 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
         }
 
         #[automatically_derived]
-        #[cfg(not(feature = "no-entrypoint"))]
+        #[cfg(not(any(feature = "no-entrypoint", feature = "library")))]
         ::fankor::prelude::solana_program::entrypoint!(#program_entry_name);
 
         #[allow(non_snake_case)]
@@ -235,12 +322,19 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
                 std::mem::transmute::<&::fankor::models::FankorContext, &'info ::fankor::models::FankorContext>(&context)
             };
 
+            context.set_cpi_allowlist_unchecked(crate::__FANKOR_CPI_ALLOWLIST);
+
+            #transfer_hook_dispatch
+
             #(#discriminant_constants)*
 
             let mut ix_data = ix_data;
             let mut ix_accounts = accounts;
             match sighash {
                 #testable_dispatch_method
+                #growable_dispatch_method
+                #versioned_dispatch_method
+                #account_registry_dispatch_method
                 #(#dispatch_methods,)*
                 #dispatch_default
             }
@@ -273,12 +367,36 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
             let name_str = name.to_string();
             let discriminant_name_str = discriminant_name.to_string();
 
-            quote! {
-                action_context.add_program_method::<#name<'info>>(#discriminant_name_str, #name_str).unwrap();
+            if let Some(group) = &v.group {
+                let group_discriminant_str = format!("{}.{}", discriminant_name_str, name_str);
+
+                quote! {
+                    #group::__ts_gen_register_methods(action_context, (#group_discriminant_str, #discriminant_name::#name.code()));
+                }
+            } else {
+                quote! {
+                    action_context.add_program_method::<#name<'info>>(#discriminant_name_str, #name_str, #discriminant_name::#name.code()).unwrap();
+                }
             }
         })
         .collect::<Vec<_>>();
 
+    let version_registration = if program.versioned {
+        quote! {
+            action_context.add_version_instruction().unwrap();
+        }
+    } else {
+        quote! {}
+    };
+
+    let account_registry_registration = if program.account_registry.is_some() {
+        quote! {
+            action_context.add_account_registry_instruction().unwrap();
+        }
+    } else {
+        quote! {}
+    };
+
     let test_name = format_ident!("__ts_gen_test__program_{}", name_str);
     let test_name_str = test_name.to_string();
     let result = quote! {
@@ -299,6 +417,8 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
                 crate::__ts_gen_test__setup::BUILD_CONTEXT.register_action(#test_name_str, file!(), move |action_context| {
                     action_context.set_context_name(#name_str).unwrap();
                     action_context.add_constant("PROGRAM_NAME", #name_str).unwrap();
+                    #version_registration
+                    #account_registry_registration
                     #(#method_registration)*
                 })
             }
@@ -307,3 +427,62 @@ pub fn processor(args: FnkMetaArgumentList, input: Item) -> Result<proc_macro::T
 
     Ok(result.into())
 }
+
+/// Builds the match arm that dispatches the instruction of a program or group method.
+fn build_dispatch_method(method: &ProgramMethod) -> TokenStream {
+    let variant_name = &method.name;
+
+    if let Some(group) = &method.group {
+        let group_msg = format!("Instruction group: {}", variant_name);
+
+        return quote! {
+            #variant_name => {
+                ::fankor::prelude::msg!(#group_msg);
+                <#group as ::fankor::traits::InstructionGroup>::dispatch(context, &mut ix_data, &mut ix_accounts)
+            }
+        };
+    }
+
+    let instruction_msg = format!("Instruction: {}", variant_name);
+    let instruction_name = variant_name.to_string();
+
+    let accounts = if method.boxed {
+        quote! {
+            let accounts = parse_accounts::<#variant_name<'info>>(
+                context,
+                &mut ix_data,
+                &mut ix_accounts,
+            )?;
+        }
+    } else {
+        quote! {
+            let accounts = <#variant_name<'info> as fankor::traits::Instruction>::try_from(&context, &mut ix_data, &mut ix_accounts)?;
+        }
+    };
+
+    quote! {
+        #variant_name => {
+            ::fankor::prelude::msg!(#instruction_msg);
+            context.set_instruction_name_unchecked(#instruction_name);
+            context.log_account_load_stats();
+            #accounts
+
+            if ix_accounts.len() != 0 {
+                return Err(::fankor::errors::FankorErrorCode::UnusedAccounts.into());
+            }
+
+            let result = accounts.processor(context.clone())?;
+
+            // The accounts are dropped at this point so their exit actions
+            // are already applied.
+            context.log_lamport_report();
+
+            // Write return data.
+            if ::fankor::prelude::type_id_of(&result) != ::fankor::prelude::type_id_of(&()) {
+                ::fankor::prelude::solana_program::program::set_return_data(&::fankor::prelude::BorshSerialize::try_to_vec(&result).unwrap());
+            }
+
+            Ok(())
+        }
+    }
+}
@@ -2,7 +2,7 @@ use convert_case::{Boundary, Case, Converter};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 use syn::spanned::Spanned;
-use syn::{Attribute, Error, Expr, ItemEnum, Meta};
+use syn::{Attribute, Error, Expr, Fields, ItemEnum, Meta, Type};
 
 use crate::fnk_syn::FnkMetaArgumentList;
 use crate::Result;
@@ -12,8 +12,18 @@ pub struct Program {
     pub snake_name: Ident,
     pub methods: Vec<ProgramMethod>,
     pub fallback_method_call: Option<TokenStream>,
+    pub transfer_hook_method_call: Option<TokenStream>,
     pub testable: bool,
 
+    /// The account type, or accounts enum, whose accounts can be grown by the
+    /// grow instruction.
+    pub growable: Option<Ident>,
+    pub versioned: bool,
+
+    /// The accounts enum whose registry is stored by the `__account_registry`
+    /// instruction.
+    pub account_registry: Option<Ident>,
+
     /// List of attributes to apply to the enum.
     pub attrs: Vec<Attribute>,
 }
@@ -24,6 +34,15 @@ pub struct ProgramMethod {
     pub return_type: Option<TokenStream>,
     pub boxed: bool,
     pub attrs: Vec<Attribute>,
+
+    /// The type of the instruction group if the variant is `Name(Group)`.
+    pub group: Option<Type>,
+}
+
+pub struct InstructionGroup {
+    pub name: Ident,
+    pub methods: Vec<ProgramMethod>,
+    pub middleware: Option<TokenStream>,
 }
 
 impl Program {
@@ -37,8 +56,17 @@ impl Program {
             Some(v) => v.to_token_stream(),
             None => quote! { fallback(program_id, accounts, data) },
         });
+        let transfer_hook_method_call =
+            args.pop_element("transfer_hook", true)?
+                .map(|v| match v.value {
+                    Some(v) => v.to_token_stream(),
+                    None => quote! { transfer_hook_execute(context, execute) },
+                });
 
         let testable = args.pop_plain("testable", true)?;
+        let growable = args.pop_ident("growable", true)?;
+        let versioned = args.pop_plain("versioned", true)?;
+        let account_registry = args.pop_ident("account_registry", true)?;
 
         args.error_on_unknown()?;
 
@@ -67,7 +95,11 @@ impl Program {
             snake_name,
             methods: vec![],
             fallback_method_call,
+            transfer_hook_method_call,
             testable,
+            growable,
+            versioned,
+            account_registry,
             attrs: Vec::new(),
         };
 
@@ -77,68 +109,141 @@ impl Program {
     }
 
     fn parse_methods(&mut self, item: ItemEnum) -> Result<()> {
-        let case_converter = Converter::new()
-            .from_case(Case::Pascal)
-            .to_case(Case::Snake)
-            .remove_boundary(Boundary::LowerDigit)
-            .remove_boundary(Boundary::UpperDigit);
+        self.methods = parse_methods(item, true)?;
+
+        Ok(())
+    }
+}
+
+impl InstructionGroup {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new instance of the InstructionGroup struct from the given attributes.
+    pub fn from(mut args: FnkMetaArgumentList, item: ItemEnum) -> Result<InstructionGroup> {
+        args.error_on_duplicated()?;
+
+        let middleware = match args.pop_element("middleware", true)? {
+            Some(v) => match v.value {
+                Some(v) => Some(v.to_token_stream()),
+                None => {
+                    return Err(Error::new(
+                        v.name.span(),
+                        "The correct pattern is #[instruction_group(middleware = <path>)]",
+                    ));
+                }
+            },
+            None => None,
+        };
+
+        args.error_on_unknown()?;
+
+        if !item.generics.params.is_empty() || item.generics.where_clause.is_some() {
+            return Err(Error::new(
+                item.generics.span(),
+                "instruction_group macro does not support generics in the enum declarations",
+            ));
+        }
+
+        Ok(InstructionGroup {
+            name: item.ident.clone(),
+            methods: parse_methods(item, false)?,
+            middleware,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn parse_methods(item: ItemEnum, allow_groups: bool) -> Result<Vec<ProgramMethod>> {
+    let case_converter = Converter::new()
+        .from_case(Case::Pascal)
+        .to_case(Case::Snake)
+        .remove_boundary(Boundary::LowerDigit)
+        .remove_boundary(Boundary::UpperDigit);
+
+    let mut methods = Vec::new();
+
+    for variant in item.variants {
+        let method_name = variant.ident.clone();
+
+        let group = match variant.fields {
+            Fields::Unit => None,
+            Fields::Unnamed(v) if allow_groups && v.unnamed.len() == 1 => {
+                Some(v.unnamed.into_iter().next().unwrap().ty)
+            }
+            v => {
+                let message = if allow_groups {
+                    "Program variants must be unit or contain only an instruction group: Name(Group)"
+                } else {
+                    "Instruction group variants must be unit"
+                };
+
+                return Err(Error::new(v.span(), message));
+            }
+        };
 
-        for variant in item.variants {
-            let method_name = variant.ident.clone();
-
-            let mut return_type = None;
-            let mut boxed = false;
-            let mut attrs = Vec::new();
-
-            for attribute in variant.attrs {
-                let attribute_span = attribute.span();
-                let attribute_path = attribute.path();
-
-                if attribute_path.is_ident("return_type") {
-                    let result = if let Meta::NameValue(v) = attribute.meta {
-                        if let Expr::Path(p) = v.value {
-                            p.path.to_token_stream()
-                        } else {
-                            return Err(Error::new(
-                                attribute_span,
-                                "The correct pattern is #[return_type = <path>]",
-                            ));
-                        }
+        let mut return_type = None;
+        let mut boxed = false;
+        let mut attrs = Vec::new();
+
+        for attribute in variant.attrs {
+            let attribute_span = attribute.span();
+            let attribute_path = attribute.path();
+
+            if attribute_path.is_ident("return_type") {
+                let result = if let Meta::NameValue(v) = attribute.meta {
+                    if let Expr::Path(p) = v.value {
+                        p.path.to_token_stream()
                     } else {
                         return Err(Error::new(
                             attribute_span,
                             "The correct pattern is #[return_type = <path>]",
                         ));
-                    };
-
-                    return_type = Some(result);
-                } else if attribute_path.is_ident("boxed") {
-                    if attribute.meta.require_path_only().is_err() {
-                        return Err(Error::new(
-                            attribute_span,
-                            "The correct pattern is #[boxed]",
-                        ));
                     }
-
-                    boxed = true;
                 } else {
-                    attrs.push(attribute);
+                    return Err(Error::new(
+                        attribute_span,
+                        "The correct pattern is #[return_type = <path>]",
+                    ));
+                };
+
+                return_type = Some(result);
+            } else if attribute_path.is_ident("boxed") {
+                if attribute.meta.require_path_only().is_err() {
+                    return Err(Error::new(
+                        attribute_span,
+                        "The correct pattern is #[boxed]",
+                    ));
                 }
+
+                boxed = true;
+            } else {
+                attrs.push(attribute);
             }
+        }
 
-            self.methods.push(ProgramMethod {
-                snake_name: format_ident!(
-                    "{}",
-                    case_converter.convert(method_name.to_string()),
-                    span = method_name.span()
-                ),
-                name: method_name,
-                return_type,
-                boxed,
-                attrs,
-            });
+        if group.is_some() && (return_type.is_some() || boxed) {
+            return Err(Error::new(
+                method_name.span(),
+                "Instruction groups cannot define #[return_type] or #[boxed]",
+            ));
         }
 
-        Ok(())
+        methods.push(ProgramMethod {
+            snake_name: format_ident!(
+                "{}",
+                case_converter.convert(method_name.to_string()),
+                span = method_name.span()
+            ),
+            name: method_name,
+            return_type,
+            boxed,
+            attrs,
+            group,
+        });
     }
+
+    Ok(methods)
 }
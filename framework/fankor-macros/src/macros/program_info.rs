@@ -0,0 +1,195 @@
+use proc_macro2::Ident;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, Error, LitStr, Token};
+
+use crate::Result;
+
+pub struct ProgramInfoArgs {
+    version: Option<LitStr>,
+    docs: Option<LitStr>,
+    repository: Option<LitStr>,
+    deployments: Vec<(Ident, LitStr)>,
+}
+
+impl Parse for ProgramInfoArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut result = ProgramInfoArgs {
+            version: None,
+            docs: None,
+            repository: None,
+            deployments: Vec::new(),
+        };
+        let mut has_deployments = false;
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![:]>()?;
+
+            let field = match key.to_string().as_str() {
+                "version" => Some(&mut result.version),
+                "docs" => Some(&mut result.docs),
+                "repository" => Some(&mut result.repository),
+                "deployments" => {
+                    if has_deployments {
+                        return Err(Error::new(key.span(), "Duplicated argument"));
+                    }
+
+                    let content;
+                    braced!(content in input);
+
+                    let list = Punctuated::<Deployment, Token![,]>::parse_terminated(&content)?;
+                    result.deployments = list.into_iter().map(|v| (v.cluster, v.address)).collect();
+                    has_deployments = true;
+                    None
+                }
+                _ => {
+                    return Err(Error::new(
+                        key.span(),
+                        format!("Unknown argument '{}'", key),
+                    ));
+                }
+            };
+
+            if let Some(field) = field {
+                if field.is_some() {
+                    return Err(Error::new(key.span(), "Duplicated argument"));
+                }
+
+                *field = Some(input.parse()?);
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(result)
+    }
+}
+
+struct Deployment {
+    cluster: Ident,
+    address: LitStr,
+}
+
+impl Parse for Deployment {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let cluster = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let address = input.parse()?;
+
+        Ok(Deployment { cluster, address })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+pub fn processor(args: ProgramInfoArgs) -> Result<proc_macro::TokenStream> {
+    let version = match &args.version {
+        Some(version) => {
+            if !is_valid_version(&version.value()) {
+                return Err(Error::new(
+                    version.span(),
+                    "The version must follow the semver format: major.minor.patch",
+                ));
+            }
+
+            quote! { #version }
+        }
+        None => quote! { env!("CARGO_PKG_VERSION") },
+    };
+
+    let docs = args.docs.as_ref();
+    let docs_value = match docs {
+        Some(v) => quote! { Some(#v) },
+        None => quote! { None },
+    };
+
+    let repository = args.repository.as_ref();
+    let repository_value = match repository {
+        Some(v) => quote! { Some(#v) },
+        None => quote! { None },
+    };
+
+    let mut deployments = Vec::new();
+    let mut binary_deployments = Vec::new();
+    for (cluster, address) in &args.deployments {
+        let cluster_str = cluster.to_string();
+
+        if deployments.iter().any(|(v, _)| v == &cluster_str) {
+            return Err(Error::new(cluster.span(), "Duplicated cluster"));
+        }
+
+        binary_deployments.push(quote! {
+            "\ndeployment.", #cluster_str, "=", #address
+        });
+        deployments.push((
+            cluster_str.clone(),
+            quote! {
+                (#cluster_str, ::fankor::prelude::const_pubkey!(#address))
+            },
+        ));
+    }
+
+    let deployments = deployments.iter().map(|(_, v)| v);
+    let binary_docs = docs.map(|v| quote! { "\ndocs=", #v, });
+    let binary_repository = repository.map(|v| quote! { "\nrepository=", #v, });
+
+    let result = quote! {
+        /// The metadata of the program.
+        pub const PROGRAM_INFO: ::fankor::models::ProgramInfo = ::fankor::models::ProgramInfo {
+            version: #version,
+            docs: #docs_value,
+            repository: #repository_value,
+            deployments: &[#(#deployments),*],
+        };
+
+        /// The metadata of the program embedded in the binary so it can be read
+        /// from the deployed program data.
+        #[cfg(not(any(feature = "no-entrypoint", feature = "library")))]
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        #[used]
+        #[no_mangle]
+        pub static fankor_program_info: &str = concat!(
+            "=====BEGIN FANKOR PROGRAM INFO=====\nversion=",
+            #version,
+            #binary_docs
+            #binary_repository
+            #(#binary_deployments,)*
+            "\n=====END FANKOR PROGRAM INFO====="
+        );
+
+        #[cfg(feature = "ts-gen")]
+        #[automatically_derived]
+        #[allow(non_snake_case)]
+        mod __ts_gen_test__program_info {
+            use super::*;
+
+            #[test]
+            fn build() {
+                crate::__ts_gen_test__setup::BUILD_CONTEXT.register_action("__ts_gen_test__program_info", file!(), move |action_context| {
+                    action_context.set_program_info(PROGRAM_INFO).unwrap();
+                })
+            }
+        }
+    };
+
+    Ok(result.into())
+}
+
+fn is_valid_version(version: &str) -> bool {
+    let version = version.split(['-', '+']).next().unwrap_or_default();
+    let parts = version.split('.').collect::<Vec<_>>();
+
+    parts.len() == 3
+        && parts
+            .iter()
+            .all(|v| !v.is_empty() && v.chars().all(|c| c.is_ascii_digit()))
+}
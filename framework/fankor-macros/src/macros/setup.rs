@@ -9,6 +9,16 @@ pub fn processor(pubkey: LitStr) -> Result<proc_macro::TokenStream> {
         #[::fankor::prelude::constant]
         pub const ID: ::fankor::prelude::solana_program::pubkey::Pubkey = ::fankor::prelude::const_pubkey!(#pubkey);
 
+        // Default CPI allowlist, shadowed by `cpi_allowlist!`.
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use ::fankor::cpi::raw::default_allowlist::*;
+
+        // Default strict mode, shadowed by `strict_mode!`.
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use ::fankor::macros::default_strict_mode::*;
+
         #[cfg(feature = "ts-gen")]
         pub(crate) mod __ts_gen_test__setup {
             use ::fankor::prelude::ts_gen::BuildContext;
@@ -23,7 +33,8 @@ pub fn processor(pubkey: LitStr) -> Result<proc_macro::TokenStream> {
             // ----------------------------------------------------------------
             // ----------------------------------------------------------------
 
-            /// This is the main build function.
+            /// This is the main build function. The output can be configured
+            /// through environment variables, see `TsGenConfig::from_env`.
             #[test]
             fn build() {
                 BUILD_CONTEXT.build();
@@ -1,16 +1,18 @@
 use convert_case::{Case, Converter};
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use syn::spanned::Spanned;
-use syn::{Error, Fields, Item};
+use syn::{Error, Fields, Item, Type, Variant};
 
 use crate::fnk_syn::FnkMetaArgumentList;
+use crate::macros::deserialize::parse_field_arguments;
 use crate::Result;
 
 pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
     let case_converter = Converter::new().from_case(Case::Snake).to_case(Case::Camel);
 
     // Process input.
-    let (result, account_name) = match &input {
+    let (result, account_name, schema_samples) = match &input {
         Item::Struct(item) => {
             let name = &item.ident;
             let name_str = name.to_string();
@@ -19,6 +21,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
 
             // Check for fankor attribute.
             let mut account_discriminants = None;
+            let mut is_pod = false;
 
             for attr in &item.attrs {
                 if attr.path().is_ident("fankor") {
@@ -26,6 +29,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                         args.error_on_duplicated()?;
 
                         account_discriminants = args.pop_ident("account", true)?;
+                        is_pod = args.pop_plain("pod", true)?;
 
                         if args.pop_plain("accounts", true)? {
                             return Err(Error::new(
@@ -56,28 +60,100 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
             let mut ts_schema_fields = Vec::new();
             let mut equals_method_conditions = Vec::new();
             let mut clone_method_fields = Vec::new();
+            let mut codec_replacements = Vec::new();
+            let mut codec_fields = Vec::new();
 
             for field in &item.fields {
                 let field_name = field.ident.as_ref().unwrap();
                 let field_name_str = case_converter.convert(field_name.to_string());
                 let field_name = format_ident!("{}", field_name_str, span = field_name.span());
                 let field_ty = &field.ty;
+                let field_arguments = parse_field_arguments(&field.attrs)?;
+                let is_default = field_arguments.default;
+
+                // Hidden fields are still part of the layout, so their schema
+                // reads them and writes their default value instead.
+                let default_bytes = quote! {
+                    ::fankor::prelude::BorshSerialize::try_to_vec(&<#field_ty as Default>::default())
+                        .unwrap()
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                };
+                let field_schema = quote! { < #field_ty as TsTypeGen>::generate_schema(registered_schemas) };
+                let field_codec = quote! { < #field_ty as TsTypeGen>::generate_standalone_codec(registered_codecs) };
+                let (field_schema, field_codec) = if field_arguments.ts_skip {
+                    (
+                        quote! { format!("fnk.TSkip({}, new Uint8Array([{}]))", #field_schema, #default_bytes) },
+                        quote! { format!("fnkSkip({}, new Uint8Array([{}]))", #field_codec, #default_bytes) },
+                    )
+                } else if field_arguments.ts_opaque {
+                    (
+                        quote! { format!("fnk.TOpaque({})", #field_schema) },
+                        quote! { format!("fnkOpaque({})", #field_codec) },
+                    )
+                } else {
+                    (
+                        quote! { #field_schema.to_string() },
+                        quote! { #field_codec.to_string() },
+                    )
+                };
 
                 let schema_replacement_str = format!("_r_schema_{}_r_", field_name);
-                ts_schema_fields.push(format!("['{}', {}]", field_name, schema_replacement_str));
+                // Default fields are marked so that they can be omitted.
+                if is_default {
+                    ts_schema_fields.push(format!(
+                        "['{}', {}, true]",
+                        field_name, schema_replacement_str
+                    ));
+                } else {
+                    ts_schema_fields.push(format!("['{}', {}]", field_name, schema_replacement_str));
+                }
+                schema_replacements.push(quote! {
+                    .replace(#schema_replacement_str, &#field_schema)
+                });
+
+                let codec_replacement_str = format!("_r_codec_{}_r_", field_name);
+                codec_fields.push(format!("['{}', {}]", field_name, codec_replacement_str));
+                codec_replacements.push(quote! {
+                    .replace(#codec_replacement_str, &#field_codec)
+                });
+
+                if field_arguments.ts_skip {
+                    continue;
+                }
 
-                let ts_field_replacement_format_str = format!("public {}: {{}};", field_name);
+                // Opaque fields are exposed as their raw bytes.
+                let (field_type, unit_value) = if field_arguments.ts_opaque {
+                    (
+                        quote! { std::borrow::Cow::<'static, str>::Borrowed("Uint8Array") },
+                        quote! { Option::<std::borrow::Cow<'static, str>>::None },
+                    )
+                } else {
+                    (
+                        quote! { < #field_ty as TsTypeGen>::generate_type(registered_types) },
+                        quote! { < #field_ty as TsTypeGen>::unit_value() },
+                    )
+                };
+
+                // Default fields can be omitted because the program will use their default value.
+                let ts_field_replacement_format_str = if is_default {
+                    format!("public {}?: {{}};", field_name)
+                } else {
+                    format!("public {}: {{}};", field_name)
+                };
                 let ts_field_replacement_str = format!("_r_field_{}_r_", field_name);
                 ts_fields.push_str(&ts_field_replacement_str);
                 ts_replacements.push(quote! {
-                    .replace(#ts_field_replacement_str, &format!(#ts_field_replacement_format_str, < #field_ty as TsTypeGen>::generate_type(registered_types)))
+                    .replace(#ts_field_replacement_str, &format!(#ts_field_replacement_format_str, #field_type))
                 });
 
                 let ts_optional_field_str = format!(" _r_optional_field_{}_r_", field_name);
                 let ts_optional_field_replacement_str = format!("| '{}'", field_name);
                 ts_optional_field.push_str(&ts_optional_field_str);
                 ts_replacements.push(quote! {
-                    .replace(#ts_optional_field_str,  if < #field_ty as TsTypeGen>::unit_value().is_some() {
+                    .replace(#ts_optional_field_str,  if #is_default || #unit_value.is_some() {
                         #ts_optional_field_replacement_str
                     } else {
                         ""
@@ -91,17 +167,13 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                     format!("this.{} = data.{} ?? {{}};", field_name, field_name);
                 ts_constructor_fields.push_str(&ts_constructor_field_str);
                 ts_replacements.push(quote! {
-                    .replace(#ts_constructor_field_str,  &if let Some(unit_value) = < #field_ty as TsTypeGen>::unit_value() {
+                    .replace(#ts_constructor_field_str,  &if let Some(unit_value) = #unit_value {
                         format!(#ts_constructor_field_optional_replacement_str, unit_value)
                     } else {
                         #ts_constructor_field_replacement_str.to_string()
                     })
                 });
 
-                schema_replacements.push(quote! {
-                    .replace(#schema_replacement_str, &< #field_ty as TsTypeGen>::generate_schema(registered_schemas))
-                });
-
                 equals_method_conditions.push(format!(
                     "fnk.equals(this.{}, other.{})",
                     field_name_str, field_name_str
@@ -110,6 +182,11 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                 clone_method_fields.push(format!("{}: fnk.clone(this.{})", field_name, field_name));
             }
 
+            // All the fields can be hidden.
+            if equals_method_conditions.is_empty() {
+                equals_method_conditions.push("true".to_string());
+            }
+
             let ts_type = format!(
                 "export class {} {{
                     // FIELDS -----------------------------------------------------------------
@@ -165,7 +242,14 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                 schema_constant_name,
             );
 
-            let ts_schema = if let Some(account_discriminants) = account_discriminants {
+            let ts_schema = if let Some(account_discriminants) = &account_discriminants {
+                // Pod accounts are padded to keep their value aligned.
+                let (padding_schema, padding_value) = if is_pod {
+                    ("['padding', fnk.ByteArray(7)],", "padding: new Uint8Array(7),")
+                } else {
+                    ("", "")
+                };
+
                 format!(
                     "export class {} implements fnk.FnkBorshSchema<{}> {{
                         innerSchema = null as any as ReturnType<{}['initSchema']>;
@@ -176,6 +260,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                             const innerSchema = fnk.TStruct([
                                 ['discriminant', fnk.U8],
                                 {}
+                                {}
                             ] as const);
                             this.innerSchema = innerSchema;
                             return innerSchema;
@@ -186,6 +271,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                         serialize(writer: fnk.FnkBorshWriter, value: {}) {{
                             this.innerSchema.serialize(writer, {{
                                 discriminant: {}.{},
+                                {}
                                 ...value
                             }});
                         }}
@@ -201,10 +287,12 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                     schema_name,
                     name_str,
                     schema_name,
+                    padding_schema,
                     ts_schema_fields.join(","),
                     name_str,
                     account_discriminants,
                     name_str,
+                    padding_value,
                     account_discriminants,
                     name_str,
                     name_str,
@@ -242,6 +330,20 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                 )
             };
 
+            let struct_codec = format!("fnkStruct(() => [{}])", codec_fields.join(","));
+            let codec = if account_discriminants.is_some() {
+                codec_replacements.push(quote! {
+                    .replace("_r_codec_account_discriminant_r_", &<Self as ::fankor::traits::AccountType>::discriminant().to_string())
+                });
+
+                // Pod accounts are padded to keep their value aligned.
+                let padding = if is_pod { 7 } else { 0 };
+                format!("fnkAccount(_r_codec_account_discriminant_r_, {}, {})", padding, struct_codec)
+            } else {
+                struct_codec
+            };
+            let (codec_name, ts_codec) = standalone_codec(&name_str, &codec);
+
             let ts_schema_use_method_name = format!("use{}", schema_name);
             let ts_schema_use_method_call = format!("{}()", ts_schema_use_method_name);
             let ts_schema_constant = format!(
@@ -336,10 +438,34 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                         let ts_schema = #ts_schema_use_method .to_string();
                         registered_use_methods.insert(name.clone(), std::borrow::Cow::Owned(ts_schema));
                     }
+
+                    fn generate_standalone_codec(registered_codecs: &mut ::fankor::prelude::TsTypesCache) -> std::borrow::Cow<'static, str> {
+                        use ::fankor::prelude::TsTypeGen;
+                        let name = std::borrow::Cow::Borrowed(#codec_name);
+
+                        if registered_codecs.contains_key(&name) {
+                            return name;
+                        }
+
+                        // Prevents infinite recursion.
+                        registered_codecs.insert(name.clone(), std::borrow::Cow::Borrowed(""));
+
+                        let ts_codec = #ts_codec.to_string() #(#codec_replacements)*;
+                        *registered_codecs.get_mut(&name).unwrap() = std::borrow::Cow::Owned(ts_codec);
+
+                        name
+                    }
                 }
             };
 
-            (result, name)
+            let schema_samples = vec![quote! {
+                {
+                    use ::fankor::ts_gen::{SchemaDefaultImplemented, SchemaDefaultNotImplemented};
+                    (&::fankor::ts_gen::SchemaSampleWrapper::<#name>::new()).__fankor_schema_default()
+                }
+            }];
+
+            (result, name, schema_samples)
         }
         Item::Enum(item) => {
             let name = &item.ident;
@@ -399,8 +525,13 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
             let mut ts_schema_fields = Vec::new();
             let mut ts_interface_names = Vec::new();
             let mut ts_interfaces = Vec::new();
+            let mut codec_replacements = Vec::new();
+            let mut codec_variants = Vec::new();
+            let mut schema_samples = Vec::new();
 
             for variant in &item.variants {
+                schema_samples.push(variant_schema_sample(name, variant));
+
                 let variant_name = &variant.ident;
                 let variant_name_str = variant_name.to_string();
                 let variant_name =
@@ -417,6 +548,13 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                     .replace(#schema_discriminant_replacement_str, &#discriminant_name::#variant_name.code().to_string())
                 });
 
+                let codec_discriminant_replacement_str =
+                    format!("_r_codec_discriminant_{}_r_", variant_name);
+
+                codec_replacements.push(quote! {
+                    .replace(#codec_discriminant_replacement_str, &#discriminant_name::#variant_name.code().to_string())
+                });
+
                 match &variant.fields {
                     Fields::Named(v) => {
                         let fields = v.named.iter().map(|f| {
@@ -462,6 +600,26 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                             variant_name,
                             field_schemas.join(",")
                         ));
+
+                        let field_codecs = v.named.iter().map(|f| {
+                            let field_name = f.ident.as_ref().unwrap();
+                            let field_name = case_converter.convert(field_name.to_string());
+                            let replacement_str = format!("_r_codec_{}_{}_r_", interface_name, field_name);
+                            let ty = &f.ty;
+
+                            codec_replacements.push(quote! {
+                                .replace(#replacement_str, &< #ty as TsTypeGen>::generate_standalone_codec(registered_codecs))
+                            });
+
+                            format!("['{}', {}]", field_name, replacement_str)
+                        }).collect::<Vec<_>>();
+
+                        codec_variants.push(format!(
+                            "[{}, '{}', fnkStruct(() => [{}])]",
+                            codec_discriminant_replacement_str,
+                            variant_name,
+                            field_codecs.join(",")
+                        ));
                     }
                     Fields::Unnamed(v) => {
                         if v.unnamed.len() != 1 {
@@ -496,6 +654,17 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                             variant_name,
                             schema_replacement_str
                         ));
+
+                        let codec_replacement_str = format!("_r_codec_{}_r_", interface_name);
+
+                        codec_replacements.push(quote! {
+                            .replace(#codec_replacement_str, &< #field_ty as TsTypeGen>::generate_standalone_codec(registered_codecs))
+                        });
+
+                        codec_variants.push(format!(
+                            "[{}, '{}', {}]",
+                            codec_discriminant_replacement_str, variant_name, codec_replacement_str
+                        ));
                     }
                     Fields::Unit => {
                         ts_interfaces.push(format!(
@@ -507,6 +676,11 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                             "[{},'{}']",
                             schema_discriminant_replacement_str, variant_name
                         ));
+
+                        codec_variants.push(format!(
+                            "[{}, '{}', null]",
+                            codec_discriminant_replacement_str, variant_name
+                        ));
                     }
                 }
             }
@@ -563,7 +737,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                 ts_interfaces.join("\n")
             );
 
-            let ts_schema = if let Some(account_discriminants) = account_discriminants {
+            let ts_schema = if let Some(account_discriminants) = &account_discriminants {
                 format!(
                     "export class {} implements fnk.FnkBorshSchema<{}> {{
                         innerSchema = null as any as ReturnType<{}['initSchema']>;
@@ -639,6 +813,22 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                 )
             };
 
+            let enum_variants = codec_variants.join(",");
+            let codec = if account_discriminants.is_some() {
+                codec_replacements.push(quote! {
+                    .replace("_r_codec_account_discriminant_r_", &<Self as ::fankor::traits::AccountType>::discriminant().to_string())
+                });
+
+                format!(
+                    "fnkAccount(_r_codec_account_discriminant_r_, 0, fnkEnum(() => [{}]))",
+                    enum_variants
+                )
+            } else {
+                // The variants of the accounts enum contain their own discriminant.
+                format!("fnkEnum(() => [{}], {})", enum_variants, is_accounts)
+            };
+            let (codec_name, ts_codec) = standalone_codec(&name_str, &codec);
+
             let ts_schema_use_method_name = format!("use{}", schema_name);
             let ts_schema_use_method_call = format!("{}()", ts_schema_use_method_name);
             let ts_schema_constant = format!(
@@ -733,10 +923,27 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                         let ts_schema = #ts_schema_use_method .to_string();
                         registered_use_methods.insert(name.clone(), std::borrow::Cow::Owned(ts_schema));
                     }
+
+                    fn generate_standalone_codec(registered_codecs: &mut fankor::prelude::TsTypesCache) -> std::borrow::Cow<'static, str> {
+                        use fankor::prelude::TsTypeGen;
+                        let name = std::borrow::Cow::Borrowed(#codec_name);
+
+                        if registered_codecs.contains_key(&name) {
+                            return name;
+                        }
+
+                        // Prevents infinite recursion.
+                        registered_codecs.insert(name.clone(), std::borrow::Cow::Borrowed(""));
+
+                        let ts_codec = #ts_codec.to_string() #(#codec_replacements)*;
+                        *registered_codecs.get_mut(&name).unwrap() = std::borrow::Cow::Owned(ts_codec);
+
+                        name
+                    }
                 }
             };
 
-            (result, name)
+            (result, name, schema_samples)
         }
         _ => {
             return Err(Error::new(
@@ -763,6 +970,20 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                  // Register action.
                 crate::__ts_gen_test__setup::BUILD_CONTEXT.register_action(#test_name_str, file!(), move |action_context| {
                     action_context.add_account::<#account_name>().unwrap();
+
+                    // Register the samples to test the schema, one per enum variant, that
+                    // can be built from default values.
+                    use ::fankor::ts_gen::{SchemaSerializeImplemented, SchemaSerializeNotImplemented};
+                    let serializer = ::fankor::ts_gen::SchemaSampleWrapper::<#account_name>::new();
+                    let samples = vec![#(#schema_samples),*]
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|v| (&serializer).__fankor_schema_serialize(&v))
+                        .collect::<Vec<_>>();
+
+                    if !samples.is_empty() {
+                        action_context.add_schema_samples::<#account_name>(samples).unwrap();
+                    }
                 })
             }
         }
@@ -770,3 +991,65 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
 
     Ok(result.into())
 }
+
+/// Builds an expression that evaluates to a value of the `variant` of the enum
+/// `name` whose fields have their default values, or `None` if any of them does
+/// not implement `Default`.
+fn variant_schema_sample(name: &Ident, variant: &Variant) -> TokenStream {
+    let variant_name = &variant.ident;
+    let default_value = |ty: &Type| {
+        quote! {
+            (&::fankor::ts_gen::SchemaSampleWrapper::<#ty>::new()).__fankor_schema_default()?
+        }
+    };
+
+    let value = match &variant.fields {
+        Fields::Named(v) => {
+            let fields = v.named.iter().map(|f| {
+                let field_name = f.ident.as_ref().unwrap();
+                let value = default_value(&f.ty);
+                quote! { #field_name: #value }
+            });
+
+            quote! { #name::#variant_name { #(#fields),* } }
+        }
+        Fields::Unnamed(v) => {
+            let fields = v.unnamed.iter().map(|f| default_value(&f.ty));
+
+            quote! { #name::#variant_name(#(#fields),*) }
+        }
+        Fields::Unit => return quote! { Some(#name::#variant_name) },
+    };
+
+    quote! {
+        {
+            fn sample() -> Option<#name> {
+                use ::fankor::ts_gen::{SchemaDefaultImplemented, SchemaDefaultNotImplemented};
+                Some(#value)
+            }
+
+            sample()
+        }
+    }
+}
+
+/// Builds the declaration of the codec of a type in the standalone TypeScript
+/// file with its encode and decode functions, returning the name of the codec
+/// and the declaration.
+fn standalone_codec(name: &str, codec: &str) -> (String, String) {
+    let codec_name = format!("{}Codec", name);
+    let declaration = format!(
+        "export const {}: FnkCodec<any> = {};
+
+        export function encode{}(value: any): Uint8Array {{
+            return fnkEncode({}, value);
+        }}
+
+        export function decode{}(bytes: Uint8Array, offset?: number): any {{
+            return fnkDecode({}, bytes, offset);
+        }}",
+        codec_name, codec, name, codec_name, name, codec_name,
+    );
+
+    (codec_name, declaration)
+}
@@ -1,9 +1,10 @@
 use convert_case::{Case, Converter};
 use quote::{format_ident, quote};
 use syn::spanned::Spanned;
-use syn::{Error, Fields, Item};
+use syn::{parse_quote, Error, Fields, Generics, Item};
 
 use crate::fnk_syn::FnkMetaArgumentList;
+use crate::utils::parse_derive_attributes;
 use crate::Result;
 
 pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
@@ -12,7 +13,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
             let name = &item.ident;
             let visibility = &item.vis;
 
-            let (_, ty_generics, where_clause) = item.generics.split_for_impl();
+            let (_, ty_generics, _) = item.generics.split_for_impl();
 
             // Check for fankor attribute.
             let mut extra_offset = 0usize;
@@ -26,6 +27,11 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                             extra_offset = 1;
                         }
 
+                        // Pod accounts are padded to keep their value aligned.
+                        if args.pop_plain("pod", true)? {
+                            extra_offset = 8;
+                        }
+
                         if args.pop_plain("accounts", true)? {
                             return Err(Error::new(
                                 input.span(),
@@ -56,7 +62,15 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                 let field_type = &field.ty;
 
                 quote! {
-                    size += <#field_type>::min_byte_size();
+                    size += <#field_type as ::fankor::traits::CopyType>::min_byte_size();
+                }
+            });
+
+            let min_account_size_method = item.fields.iter().map(|field| {
+                let field_type = &field.ty;
+
+                quote! {
+                    size += <#field_type as ::fankor::traits::CopyType>::min_account_size();
                 }
             });
 
@@ -74,10 +88,51 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
             aux_zc_generics
                 .params
                 .insert(0, syn::parse_quote! { 'info });
+            add_copy_type_bounds(&mut aux_zc_generics);
 
             let (zc_impl_generics, zc_ty_generics, zc_where_clause) =
                 aux_zc_generics.split_for_impl();
 
+            // The projection only holds the account and the offset, so it is
+            // compared and hashed by the position it points to.
+            let mut zc_derives = Vec::new();
+            let mut zc_derive_impls = Vec::new();
+
+            for path in parse_derive_attributes(&item.attrs, "zc_derive")? {
+                let trait_name = path.segments.last().map(|v| v.ident.to_string());
+
+                match trait_name.as_deref() {
+                    Some("PartialEq") => zc_derive_impls.push(quote! {
+                        #[automatically_derived]
+                        impl #zc_impl_generics ::std::cmp::PartialEq for #zc_name #zc_ty_generics #zc_where_clause {
+                            fn eq(&self, other: &Self) -> bool {
+                                self.info.key == other.info.key && self.offset == other.offset
+                            }
+                        }
+                    }),
+                    Some("Eq") => zc_derive_impls.push(quote! {
+                        #[automatically_derived]
+                        impl #zc_impl_generics ::std::cmp::Eq for #zc_name #zc_ty_generics #zc_where_clause {}
+                    }),
+                    Some("Hash") => zc_derive_impls.push(quote! {
+                        #[automatically_derived]
+                        impl #zc_impl_generics ::std::hash::Hash for #zc_name #zc_ty_generics #zc_where_clause {
+                            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                                ::std::hash::Hash::hash(self.info.key, state);
+                                ::std::hash::Hash::hash(&self.offset, state);
+                            }
+                        }
+                    }),
+                    _ => zc_derives.push(path),
+                }
+            }
+
+            let zc_derive_attr = if zc_derives.is_empty() {
+                quote! {}
+            } else {
+                quote! { #[derive(#(#zc_derives),*)] }
+            };
+
             let case_converter = Converter::new()
                 .from_case(Case::Snake)
                 .to_case(Case::Pascal);
@@ -97,10 +152,11 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                 let res = quote! {
                     #visibility fn #field_name(&self) -> FankorResult<Zc<'info, #field_ty>> {
                         let offset = self.offset + #extra_offset; // Account discriminant
-                        let bytes = self.info.try_borrow_data()
-                            .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                                type_name: std::any::type_name::<Self>(),
-                            })?;
+                        let bytes = ::fankor::models::try_borrow_zc_data(
+                            self.info,
+                            std::any::type_name::<Self>(),
+                            stringify!(#field_name),
+                        )?;
                         let bytes = &bytes[offset..];
                         let mut size = 0;
 
@@ -117,7 +173,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                                 return Ok(Zc::new_unchecked(self.info, offset))
                             }
 
-                            let bytes = self.info.try_borrow_data().map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock { type_name: std::any::type_name::<Self>() })?;
+                            let bytes = ::fankor::models::try_borrow_zc_data(self.info, std::any::type_name::<Self>(), stringify!(#from_previous_method_name))?;
                             let mut processed = false;
 
                             #(#zc_from_previous_methods_lasts)*
@@ -147,7 +203,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
 
             quote! {
                 #[automatically_derived]
-                impl #zc_impl_generics CopyType<'info> for #name #ty_generics #where_clause {
+                impl #zc_impl_generics CopyType<'info> for #name #ty_generics #zc_where_clause {
                     type ZeroCopyType = #zc_name #zc_ty_generics;
 
                     fn byte_size(&self) -> usize {
@@ -161,6 +217,12 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                         #(#min_byte_size_method)*
                         size
                     }
+
+                    fn min_account_size() -> usize {
+                        let mut size = #extra_offset; // Account discriminant
+                        #(#min_account_size_method)*
+                        size
+                    }
                 }
 
                 #[allow(dead_code)]
@@ -168,12 +230,15 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
                 #visibility enum #fields_name { #(#zc_field_names),* }
 
-                // TODO process generics to add phantom data if needed
+                #zc_derive_attr
                 #visibility struct #zc_name #zc_ty_generics #zc_where_clause {
                     info: &'info AccountInfo<'info>,
                     offset: usize,
+                    _data: std::marker::PhantomData<#name #ty_generics>,
                 }
 
+                #(#zc_derive_impls)*
+
                 #[automatically_derived]
                 impl #zc_impl_generics ZeroCopyType<'info> for #zc_name #zc_ty_generics #zc_where_clause {
                     fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
@@ -181,6 +246,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                             #zc_name {
                                 info,
                                 offset,
+                                _data: std::marker::PhantomData,
                             },
                             None,
                         ))
@@ -204,12 +270,13 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
             let name = &item.ident;
             let discriminants_name = format_ident!("{}Discriminant", name);
             let visibility = &item.vis;
-            let (_, ty_generics, where_clause) = item.generics.split_for_impl();
+            let (_, ty_generics, _) = item.generics.split_for_impl();
 
             let mut aux_zc_generics = item.generics.clone();
             aux_zc_generics
                 .params
                 .insert(0, syn::parse_quote! { 'info });
+            add_copy_type_bounds(&mut aux_zc_generics);
 
             let (zc_impl_generics, zc_ty_generics, zc_where_clause) =
                 aux_zc_generics.split_for_impl();
@@ -257,6 +324,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
             }
 
             let mut min_byte_size_method = Vec::with_capacity(item.variants.len());
+            let mut min_account_size_method = Vec::with_capacity(item.variants.len());
             let mut are_empty_variants = false;
             let byte_size_method = item.variants.iter().map(|variant| {
                 let variant_name = &variant.ident;
@@ -290,14 +358,32 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                                 }
                             })
                             .collect::<Vec<_>>();
+                        let account_fields = named_fields
+                            .named
+                            .iter()
+                            .map(|field| {
+                                let field_type = &field.ty;
+
+                                quote! {
+                                    variant_size += <#field_type as ::fankor::traits::CopyType>::min_account_size();
+                                }
+                            })
+                            .collect::<Vec<_>>();
 
                         min_byte_size_method.push(quote! {
                             {
-                                let mut variant_size = 1;
+                                let mut variant_size = #initial_size;
                                 #(#min_fields)*
                                 size = size.min(variant_size);
                             }
                         });
+                        min_account_size_method.push(quote! {
+                            {
+                                let mut variant_size = #initial_size;
+                                #(#account_fields)*
+                                size = size.max(variant_size);
+                            }
+                        });
 
                         quote! {
                             #name::#variant_name { #(#field_names),* } => {
@@ -334,6 +420,17 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                                 }
                             })
                             .collect::<Vec<_>>();
+                        let account_fields = unnamed_fields
+                            .unnamed
+                            .iter()
+                            .map(|field| {
+                                let field_type = &field.ty;
+
+                                quote! {
+                                    variant_size += <#field_type as ::fankor::traits::CopyType>::min_account_size();
+                                }
+                            })
+                            .collect::<Vec<_>>();
 
                         min_byte_size_method.push(quote! {
                             {
@@ -342,6 +439,13 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                                 size = size.min(variant_size);
                             }
                         });
+                        min_account_size_method.push(quote! {
+                            {
+                                let mut variant_size = #initial_size;
+                                #(#account_fields)*
+                                size = size.max(variant_size);
+                            }
+                        });
 
                         quote! {
                             #name::#variant_name(#(#field_names),*) => {
@@ -360,6 +464,15 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
             }).collect::<Vec<_>>();
 
             let zc_name = format_ident!("Zc{}", name);
+
+            // The variants only contain projections, so all derives are forwarded.
+            let zc_derives = parse_derive_attributes(&item.attrs, "zc_derive")?;
+            let zc_derive_attr = if zc_derives.is_empty() {
+                quote! {}
+            } else {
+                quote! { #[derive(#(#zc_derives),*)] }
+            };
+
             let zc_name_variants = item.variants.iter().map(|variant| {
                 let variant_name = &variant.ident;
 
@@ -536,7 +649,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
 
                 quote! {
                     #[automatically_derived]
-                    impl #zc_impl_generics CopyType<'info> for #name #ty_generics #where_clause {
+                    impl #zc_impl_generics CopyType<'info> for #name #ty_generics #zc_where_clause {
                         type ZeroCopyType = #name #ty_generics;
 
                         fn min_byte_size() -> usize {
@@ -546,11 +659,9 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
 
                     #[automatically_derived]
                     #[allow(non_upper_case_globals)]
-                    impl #zc_impl_generics ZeroCopyType<'info> for #name #ty_generics #where_clause {
+                    impl #zc_impl_generics ZeroCopyType<'info> for #name #ty_generics #zc_where_clause {
                         fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
-                            let bytes = info
-                                .try_borrow_data()
-                                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock { type_name: std::any::type_name::<Self>() })?;
+                            let bytes = ::fankor::models::try_borrow_zc_data(info, std::any::type_name::<Self>(), "new")?;
                             let bytes = &bytes[offset..];
 
                             if bytes.is_empty() {
@@ -604,9 +715,18 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                     }
                 };
 
+                // The unit variants are already covered by the initial size.
+                let min_account_size_body = quote! {
+                    let mut size = #initial_size;
+
+                    #(#min_account_size_method)*
+
+                    size + #extra_offset // Account discriminant
+                };
+
                 quote! {
                     #[automatically_derived]
-                    impl #zc_impl_generics CopyType<'info> for #name #ty_generics #where_clause {
+                    impl #zc_impl_generics CopyType<'info> for #name #ty_generics #zc_where_clause {
                         type ZeroCopyType = #zc_name #zc_ty_generics;
 
                         fn byte_size(&self) -> usize {
@@ -622,8 +742,13 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                         fn min_byte_size() -> usize {
                             #min_byte_size_body
                         }
+
+                        fn min_account_size() -> usize {
+                            #min_account_size_body
+                        }
                     }
 
+                    #zc_derive_attr
                     #visibility enum #zc_name #zc_ty_generics #zc_where_clause {
                         #(#zc_name_variants),*
                     }
@@ -633,9 +758,7 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
                     impl #zc_impl_generics ZeroCopyType<'info> for #zc_name #zc_ty_generics #zc_where_clause {
                         fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
                             let __offset = offset;
-                            let bytes = info
-                                .try_borrow_data()
-                                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock { type_name: std::any::type_name::<Self>() })?;
+                            let bytes = ::fankor::models::try_borrow_zc_data(info, std::any::type_name::<Self>(), "new")?;
                             let bytes = &bytes[__offset..];
 
                             if bytes.is_empty() {
@@ -686,3 +809,18 @@ pub fn processor(input: Item) -> Result<proc_macro::TokenStream> {
 
     Ok(result.into())
 }
+
+/// Adds the `CopyType` bound to all type parameters of the generics.
+fn add_copy_type_bounds(generics: &mut Generics) {
+    let params = generics
+        .type_params()
+        .map(|v| v.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+
+    for param in params {
+        where_clause
+            .predicates
+            .push(parse_quote! { #param: ::fankor::traits::CopyType<'info> });
+    }
+}
@@ -1,6 +1,7 @@
 use proc_macro2::Ident;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Expr, Lit, LitInt};
+use syn::{Attribute, Expr, Lit, LitInt, Path, Token};
 
 use crate::Result;
 
@@ -57,3 +58,32 @@ pub fn unwrap_ident_from_expr(expr: Expr) -> Result<Ident> {
         _ => Err(syn::Error::new(expr.span(), "Expected identifier")),
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Gets the traits listed in all the `#[<name>(Trait1, Trait2, ...)]` attributes,
+/// which are forwarded to the derives of the generated types.
+pub fn parse_derive_attributes(attrs: &[Attribute], name: &str) -> Result<Vec<Path>> {
+    let mut result = Vec::new();
+
+    for attr in attrs {
+        if !attr.path().is_ident(name) {
+            continue;
+        }
+
+        let list = attr
+            .parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+            .map_err(|_| {
+                syn::Error::new(
+                    attr.span(),
+                    format!("The correct pattern is #[{}(<trait_list>)]", name),
+                )
+            })?;
+
+        result.extend(list);
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,216 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::AccountMeta;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use crate::cpi;
+use crate::cpi::system_program::CpiCreateAccount;
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::{FankorContext, Program, System};
+use crate::utils::realloc::realloc_account_to_size;
+
+/// The discriminant of the `__account_registry` instruction in the programs
+/// that enable it with `#[program(account_registry = <accounts enum>)]`.
+pub const ACCOUNT_REGISTRY_INSTRUCTION_DISCRIMINANT: u8 = u8::MAX - 2;
+
+/// The seed of the PDA that stores the [AccountRegistry] of a program.
+pub const ACCOUNT_REGISTRY_SEED: &[u8] = b"__account_registry";
+
+/// The account types of a program along with a hash of their layouts, so
+/// indexers can detect layout changes before decoding the accounts.
+#[derive(Debug, Default, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AccountRegistry {
+    pub entries: Vec<AccountRegistryEntry>,
+}
+
+/// An account type of an [AccountRegistry].
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AccountRegistryEntry {
+    /// The name of the account type.
+    pub name: String,
+
+    /// The discriminant of the account type.
+    pub discriminant: u8,
+
+    /// The hash of the names and types of the fields of the account type.
+    /// It changes whenever the serialized layout of the account changes.
+    pub layout_hash: u64,
+}
+
+impl AccountRegistry {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn get_by_name(&self, name: &str) -> Option<&AccountRegistryEntry> {
+        self.entries.iter().find(|v| v.name == name)
+    }
+
+    pub fn get_by_discriminant(&self, discriminant: u8) -> Option<&AccountRegistryEntry> {
+        self.entries.iter().find(|v| v.discriminant == discriminant)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// The entries that are not in `previous` or whose discriminant or layout
+    /// have changed since then.
+    pub fn changed_since(&self, previous: &AccountRegistry) -> Vec<&AccountRegistryEntry> {
+        self.entries
+            .iter()
+            .filter(|v| previous.get_by_name(&v.name) != Some(v))
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Gets the address of the account that stores the [AccountRegistry] of
+/// `program_id`.
+pub fn find_account_registry_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ACCOUNT_REGISTRY_SEED], program_id)
+}
+
+/// Processes the `__account_registry` instruction writing `registry` into its
+/// PDA, creating or resizing it if needed. The accounts are the registry PDA,
+/// the payer of the rent and the system program.
+pub fn process_account_registry_instruction<'info>(
+    context: &'info FankorContext<'info>,
+    accounts: &'info [AccountInfo<'info>],
+    registry: &AccountRegistry,
+) -> FankorResult<()> {
+    if accounts.len() < 3 {
+        return Err(FankorErrorCode::NotEnoughAccountKeys.into());
+    }
+
+    if accounts.len() > 3 {
+        return Err(FankorErrorCode::UnusedAccounts.into());
+    }
+
+    let registry_info = &accounts[0];
+    let payer = &accounts[1];
+    let system_program = Program::<System>::new(context, &accounts[2])?;
+
+    let (address, bump) = find_account_registry_address(context.program_id());
+    if registry_info.key != &address {
+        return Err(FankorErrorCode::InvalidPda {
+            expected: address,
+            actual: *registry_info.key,
+        }
+        .into());
+    }
+
+    if !registry_info.is_writable {
+        return Err(FankorErrorCode::AccountConstraintNotWritable {
+            account: "registry",
+        }
+        .into());
+    }
+
+    if !payer.is_writable {
+        return Err(FankorErrorCode::AccountConstraintNotWritable { account: "payer" }.into());
+    }
+
+    if !payer.is_signer {
+        return Err(FankorErrorCode::AccountConstraintNotSigner { account: "payer" }.into());
+    }
+
+    let data = registry.try_to_vec()?;
+
+    if registry_info.owner == system_program.address() {
+        let lamports = Rent::get()?.minimum_balance(data.len());
+
+        cpi::system_program::create_account(
+            &system_program,
+            CpiCreateAccount {
+                from: payer.clone(),
+                to: registry_info.clone(),
+            },
+            lamports,
+            data.len() as u64,
+            context.program_id(),
+            &[&[ACCOUNT_REGISTRY_SEED, &[bump]]],
+        )?;
+    } else if registry_info.owner != context.program_id() {
+        return Err(FankorErrorCode::AccountConstraintOwnerMismatch {
+            actual: *registry_info.owner,
+            expected: *context.program_id(),
+            account: "registry",
+        }
+        .into());
+    } else {
+        realloc_account_to_size(data.len(), false, registry_info, Some(payer), &system_program)?;
+    }
+
+    registry_info.try_borrow_mut_data()?.copy_from_slice(&data);
+
+    Ok(())
+}
+
+/// Creates a new `__account_registry` instruction paid by `payer`.
+pub fn create_account_registry_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+) -> solana_program::instruction::Instruction {
+    solana_program::instruction::Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(find_account_registry_address(program_id).0, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(solana_program::system_program::ID, false),
+        ],
+        data: vec![ACCOUNT_REGISTRY_INSTRUCTION_DISCRIMINANT],
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_changed_since() {
+        let entry = |name: &str, discriminant: u8, layout_hash: u64| AccountRegistryEntry {
+            name: name.to_string(),
+            discriminant,
+            layout_hash,
+        };
+
+        let previous = AccountRegistry {
+            entries: vec![entry("Config", 1, 10), entry("User", 2, 20)],
+        };
+        let current = AccountRegistry {
+            entries: vec![
+                entry("Config", 1, 10),
+                entry("User", 2, 21),
+                entry("Vault", 3, 30),
+            ],
+        };
+
+        let changed = current.changed_since(&previous);
+        assert_eq!(changed, vec![&current.entries[1], &current.entries[2]]);
+        assert_eq!(current.get_by_discriminant(3), Some(&current.entries[2]));
+        assert_eq!(current.get_by_name("Other"), None);
+
+        let bytes = current.try_to_vec().unwrap();
+        assert_eq!(AccountRegistry::try_from_slice(&bytes).unwrap(), current);
+    }
+
+    #[test]
+    fn test_create_account_registry_instruction() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let instruction = create_account_registry_instruction(&program_id, &payer);
+
+        assert_eq!(instruction.data, vec![ACCOUNT_REGISTRY_INSTRUCTION_DISCRIMINANT]);
+        assert_eq!(
+            instruction.accounts[0].pubkey,
+            find_account_registry_address(&program_id).0
+        );
+        assert!(instruction.accounts[1].is_signer);
+    }
+}
@@ -0,0 +1,304 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::{env, fs, io};
+
+use crate::ts_gen::BuildContext;
+
+/// The configuration of the TypeScript generation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TsGenConfig {
+    /// The folder where the TypeScript file is written.
+    pub out_dir: PathBuf,
+
+    /// Whether to format the generated file with `prettier` or not.
+    /// It requires `npx` to be available in the path.
+    pub format: bool,
+
+    /// Whether to keep the current file untouched when its content does not
+    /// change, so that its modification time is preserved.
+    pub skip_unchanged: bool,
+
+    /// Whether to also write the JSON IDL of the program next to the
+    /// TypeScript file or not.
+    pub idl: bool,
+
+    /// Whether to also write the JSON documentation data of the program, i.e.
+    /// its instructions with their accounts and constraints, and its errors.
+    pub docs: bool,
+
+    /// Whether to also write a Markdown file with the byte layout of the
+    /// accounts of the program.
+    pub layout: bool,
+
+    /// Whether to also write a `__tests__` file with round-trip tests of the
+    /// generated schemas or not.
+    pub tests: bool,
+
+    /// Whether to also write a TypeScript file with the codecs of the types
+    /// that does not depend on any package, for bundle-size-sensitive apps.
+    pub standalone: bool,
+}
+
+impl TsGenConfig {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates the default config overriding its values with the following
+    /// environment variables if they are defined:
+    /// - `FANKOR_TS_OUT_DIR`: the output folder.
+    /// - `FANKOR_TS_FORMAT`: `true` or `1` to format the file.
+    /// - `FANKOR_TS_SKIP_UNCHANGED`: `true` or `1` to skip unchanged files.
+    /// - `FANKOR_TS_IDL`: `true` or `1` to also write the JSON IDL.
+    /// - `FANKOR_TS_DOCS`: `true` or `1` to also write the JSON documentation data.
+    /// - `FANKOR_TS_LAYOUT`: `true` or `1` to also write the accounts layout.
+    /// - `FANKOR_TS_TESTS`: `true` or `1` to also write the schema tests.
+    /// - `FANKOR_TS_STANDALONE`: `true` or `1` to also write the standalone codecs.
+    pub fn from_env() -> TsGenConfig {
+        let mut config = Self::default();
+
+        if let Ok(v) = env::var("FANKOR_TS_OUT_DIR") {
+            config.out_dir = PathBuf::from(v);
+        }
+
+        if let Ok(v) = env::var("FANKOR_TS_FORMAT") {
+            config.format = parse_env_flag(&v);
+        }
+
+        if let Ok(v) = env::var("FANKOR_TS_SKIP_UNCHANGED") {
+            config.skip_unchanged = parse_env_flag(&v);
+        }
+
+        if let Ok(v) = env::var("FANKOR_TS_IDL") {
+            config.idl = parse_env_flag(&v);
+        }
+
+        if let Ok(v) = env::var("FANKOR_TS_DOCS") {
+            config.docs = parse_env_flag(&v);
+        }
+
+        if let Ok(v) = env::var("FANKOR_TS_LAYOUT") {
+            config.layout = parse_env_flag(&v);
+        }
+
+        if let Ok(v) = env::var("FANKOR_TS_TESTS") {
+            config.tests = parse_env_flag(&v);
+        }
+
+        if let Ok(v) = env::var("FANKOR_TS_STANDALONE") {
+            config.standalone = parse_env_flag(&v);
+        }
+
+        config
+    }
+}
+
+impl Default for TsGenConfig {
+    fn default() -> Self {
+        Self {
+            out_dir: PathBuf::from("target/fnk_ts"),
+            format: false,
+            skip_unchanged: false,
+            idl: false,
+            docs: false,
+            layout: false,
+            tests: false,
+            standalone: false,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The result of the TypeScript generation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TsGenOutput {
+    /// The path of the generated file.
+    pub file_path: PathBuf,
+
+    /// Whether the file has been written or not.
+    pub changed: bool,
+
+    /// The path of the JSON IDL file if it has been generated.
+    pub idl_file_path: Option<PathBuf>,
+
+    /// The path of the JSON documentation file if it has been generated.
+    pub docs_file_path: Option<PathBuf>,
+
+    /// The path of the accounts layout file if it has been generated.
+    pub layout_file_path: Option<PathBuf>,
+
+    /// The path of the schema tests file if it has been generated.
+    pub tests_file_path: Option<PathBuf>,
+
+    /// The path of the standalone codecs file if it has been generated.
+    pub standalone_file_path: Option<PathBuf>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Runs all the actions registered in `context` and writes the TypeScript file
+/// of the program following `config`.
+///
+/// This is what the `build` test generated by the `setup!` macro executes.
+pub fn generate(context: &BuildContext, config: &TsGenConfig) -> io::Result<TsGenOutput> {
+    let mut data_context = context.execute_all_actions();
+    let file_path = config
+        .out_dir
+        .join(format!("{}.ts", data_context.program_name));
+
+    let mut file_content = data_context.build_ts_file();
+    if config.format {
+        file_content = format_with_prettier(&file_path, &file_content)?;
+    }
+
+    fs::create_dir_all(&config.out_dir)?;
+    let changed = write_file(&file_path, &file_content, config.skip_unchanged)?;
+
+    let idl_file_path = if config.idl {
+        let idl_file_path = config
+            .out_dir
+            .join(format!("{}.json", data_context.program_name));
+        let idl_content = data_context.build_idl_file();
+        write_file(&idl_file_path, &idl_content, config.skip_unchanged)?;
+
+        Some(idl_file_path)
+    } else {
+        None
+    };
+
+    let docs_file_path = if config.docs {
+        let docs_file_path = config
+            .out_dir
+            .join(format!("{}.docs.json", data_context.program_name));
+        let docs_content = data_context.build_docs_file();
+        write_file(&docs_file_path, &docs_content, config.skip_unchanged)?;
+
+        Some(docs_file_path)
+    } else {
+        None
+    };
+
+    let layout_file_path = if config.layout {
+        let layout_file_path = config
+            .out_dir
+            .join(format!("{}.layout.md", data_context.program_name));
+        let layout_content = data_context.build_layout_file();
+        write_file(&layout_file_path, &layout_content, config.skip_unchanged)?;
+
+        Some(layout_file_path)
+    } else {
+        None
+    };
+
+    let tests_file_path = if config.tests {
+        let tests_dir = config.out_dir.join("__tests__");
+        let tests_file_path = tests_dir.join(format!("{}.spec.ts", data_context.program_name));
+
+        let mut tests_content = data_context.build_ts_tests_file();
+        if config.format {
+            tests_content = format_with_prettier(&tests_file_path, &tests_content)?;
+        }
+
+        fs::create_dir_all(&tests_dir)?;
+        write_file(&tests_file_path, &tests_content, config.skip_unchanged)?;
+
+        Some(tests_file_path)
+    } else {
+        None
+    };
+
+    let standalone_file_path = if config.standalone {
+        let standalone_file_path = config
+            .out_dir
+            .join(format!("{}.standalone.ts", data_context.program_name));
+
+        let mut standalone_content = data_context.build_standalone_ts_file();
+        if config.format {
+            standalone_content = format_with_prettier(&standalone_file_path, &standalone_content)?;
+        }
+
+        write_file(&standalone_file_path, &standalone_content, config.skip_unchanged)?;
+
+        Some(standalone_file_path)
+    } else {
+        None
+    };
+
+    Ok(TsGenOutput {
+        file_path,
+        changed,
+        idl_file_path,
+        docs_file_path,
+        layout_file_path,
+        tests_file_path,
+        standalone_file_path,
+    })
+}
+
+/// Writes `content` into `file_path` returning whether the file has been
+/// written or not.
+fn write_file(file_path: &Path, content: &str, skip_unchanged: bool) -> io::Result<bool> {
+    if skip_unchanged {
+        if let Ok(current_content) = fs::read_to_string(file_path) {
+            if current_content == content {
+                return Ok(false);
+            }
+        }
+    }
+
+    fs::write(file_path, content)?;
+
+    Ok(true)
+}
+
+fn format_with_prettier(file_path: &Path, content: &str) -> io::Result<String> {
+    let mut child = Command::new("npx")
+        .arg("prettier")
+        .arg("--stdin-filepath")
+        .arg(file_path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    child
+        .stdin
+        .take()
+        .expect("The stdin must be piped")
+        .write_all(content.as_bytes())?;
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("prettier failed with status {}", output.status),
+        ));
+    }
+
+    String::from_utf8(output.stdout).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn parse_env_flag(value: &str) -> bool {
+    matches!(value, "1" | "true")
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_env_flag() {
+        assert!(parse_env_flag("1"));
+        assert!(parse_env_flag("true"));
+        assert!(!parse_env_flag("0"));
+        assert!(!parse_env_flag("false"));
+        assert!(!parse_env_flag(""));
+    }
+}
@@ -0,0 +1,278 @@
+use std::mem::size_of;
+
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::message::Message;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::{self, ComputeBudgetInstruction};
+use solana_sdk::signature::Signature;
+
+use crate::client::prepend_compute_budget_instructions;
+
+/// The maximum compute unit limit of a transaction.
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// A list of instructions, usually built with the `lpi` module of one or more
+/// programs, composed into a single transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComposedInstructions {
+    /// The instructions of the transaction.
+    pub instructions: Vec<Instruction>,
+
+    /// The different accounts of the transaction, including the programs,
+    /// with the merged roles of all the instructions.
+    pub accounts: Vec<AccountMeta>,
+
+    /// The signers required by more than one instruction along with the
+    /// number of instructions that require them.
+    pub duplicated_signers: Vec<(Pubkey, usize)>,
+
+    /// The number of account references removed by the deduplication, i.e.
+    /// the ones that appear in more than one place.
+    pub deduplicated_accounts: usize,
+}
+
+impl ComposedInstructions {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Analyzes the accounts of `instructions`. If `merge_compute_budget` is
+    /// set, all the compute budget instructions are replaced by a single one
+    /// of each kind at the start of the transaction: the compute unit limits
+    /// are added up and the maximum compute unit price and heap frame are kept.
+    pub fn new(instructions: &[Instruction], merge_compute_budget: bool) -> ComposedInstructions {
+        let instructions = if merge_compute_budget {
+            merge_compute_budget_instructions(instructions)
+        } else {
+            instructions.to_vec()
+        };
+
+        let mut accounts = Vec::<AccountMeta>::new();
+        let mut signers = Vec::<(Pubkey, usize)>::new();
+        let mut references = 0;
+
+        for instruction in &instructions {
+            references += 1 + instruction.accounts.len();
+            merge_account_meta(
+                &mut accounts,
+                &AccountMeta::new_readonly(instruction.program_id, false),
+            );
+
+            let mut instruction_signers = Vec::new();
+            for meta in &instruction.accounts {
+                merge_account_meta(&mut accounts, meta);
+
+                if meta.is_signer && !instruction_signers.contains(&meta.pubkey) {
+                    instruction_signers.push(meta.pubkey);
+                }
+            }
+
+            for signer in instruction_signers {
+                match signers.iter_mut().find(|(key, _)| key == &signer) {
+                    Some((_, count)) => *count += 1,
+                    None => signers.push((signer, 1)),
+                }
+            }
+        }
+
+        signers.retain(|(_, count)| *count > 1);
+
+        ComposedInstructions {
+            deduplicated_accounts: references - accounts.len(),
+            instructions,
+            accounts,
+            duplicated_signers: signers,
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The size of the serialized transaction paid by `payer`, which must be
+    /// below `PACKET_DATA_SIZE` (1232 bytes) to be sent.
+    pub fn transaction_size(&self, payer: &Pubkey) -> usize {
+        let message = Message::new(&self.instructions, Some(payer));
+        let signatures = message.header.num_required_signatures as usize;
+
+        short_vec_length_size(signatures)
+            + signatures * size_of::<Signature>()
+            + message.serialize().len()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Adds `meta` to `accounts` or upgrades the roles of its previous entry.
+fn merge_account_meta(accounts: &mut Vec<AccountMeta>, meta: &AccountMeta) {
+    match accounts.iter_mut().find(|v| v.pubkey == meta.pubkey) {
+        Some(account) => {
+            account.is_signer |= meta.is_signer;
+            account.is_writable |= meta.is_writable;
+        }
+        None => accounts.push(meta.clone()),
+    }
+}
+
+/// Replaces the compute budget instructions by a single one of each kind.
+/// Unknown compute budget instructions are kept untouched.
+fn merge_compute_budget_instructions(instructions: &[Instruction]) -> Vec<Instruction> {
+    let mut compute_unit_limit = None::<u32>;
+    let mut compute_unit_price = 0;
+    let mut heap_frame = None::<u32>;
+    let mut others = Vec::with_capacity(instructions.len());
+
+    for instruction in instructions {
+        if instruction.program_id != compute_budget::id() {
+            others.push(instruction.clone());
+            continue;
+        }
+
+        match decode_compute_budget_instruction(&instruction.data) {
+            Some(ComputeBudgetInstruction::SetComputeUnitLimit(v)) => {
+                compute_unit_limit = Some(
+                    compute_unit_limit
+                        .unwrap_or(0)
+                        .saturating_add(v)
+                        .min(MAX_COMPUTE_UNIT_LIMIT),
+                );
+            }
+            Some(ComputeBudgetInstruction::SetComputeUnitPrice(v)) => {
+                compute_unit_price = compute_unit_price.max(v);
+            }
+            Some(ComputeBudgetInstruction::RequestHeapFrame(v)) => {
+                heap_frame = Some(heap_frame.unwrap_or(0).max(v));
+            }
+            _ => others.push(instruction.clone()),
+        }
+    }
+
+    let mut result = Vec::with_capacity(others.len() + 3);
+
+    if let Some(heap_frame) = heap_frame {
+        result.push(ComputeBudgetInstruction::request_heap_frame(heap_frame));
+    }
+
+    result.extend(prepend_compute_budget_instructions(
+        &others,
+        compute_unit_price,
+        compute_unit_limit,
+    ));
+    result
+}
+
+/// Decodes the compute budget instructions that are merged. They are decoded
+/// by hand because each version of `solana-sdk` uses a different version of
+/// Borsh for them.
+fn decode_compute_budget_instruction(data: &[u8]) -> Option<ComputeBudgetInstruction> {
+    let (discriminant, value) = data.split_first()?;
+
+    match *discriminant {
+        1 => Some(ComputeBudgetInstruction::RequestHeapFrame(u32::from_le_bytes(
+            value.try_into().ok()?,
+        ))),
+        2 => Some(ComputeBudgetInstruction::SetComputeUnitLimit(u32::from_le_bytes(
+            value.try_into().ok()?,
+        ))),
+        3 => Some(ComputeBudgetInstruction::SetComputeUnitPrice(u64::from_le_bytes(
+            value.try_into().ok()?,
+        ))),
+        _ => None,
+    }
+}
+
+/// The size of the compact length prefix of a list with `length` elements.
+fn short_vec_length_size(mut length: usize) -> usize {
+    let mut size = 1;
+
+    while length >= 0x80 {
+        length >>= 7;
+        size += 1;
+    }
+
+    size
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_compose_instructions() {
+        let program_id = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let state = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+
+        let first = Instruction::new_with_bytes(
+            program_id,
+            &[0],
+            vec![
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new_readonly(state, false),
+            ],
+        );
+        let second = Instruction::new_with_bytes(
+            program_id,
+            &[1],
+            vec![
+                AccountMeta::new(authority, true),
+                AccountMeta::new(state, false),
+                AccountMeta::new_readonly(vault, false),
+            ],
+        );
+        let instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(100_000),
+            ComputeBudgetInstruction::set_compute_unit_price(5),
+            first.clone(),
+            ComputeBudgetInstruction::set_compute_unit_limit(50_000),
+            ComputeBudgetInstruction::set_compute_unit_price(10),
+            second.clone(),
+        ];
+
+        let composed = ComposedInstructions::new(&instructions, true);
+        assert_eq!(
+            composed.instructions,
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(150_000),
+                ComputeBudgetInstruction::set_compute_unit_price(10),
+                first,
+                second,
+            ]
+        );
+        assert_eq!(composed.duplicated_signers, vec![(authority, 2)]);
+        assert!(composed
+            .accounts
+            .contains(&AccountMeta::new(authority, true)));
+        assert!(composed.accounts.contains(&AccountMeta::new(state, false)));
+
+        // compute budget (x2), program (x2), authority (x2), state (x2), vault.
+        assert_eq!(composed.accounts.len(), 5);
+        assert_eq!(composed.deduplicated_accounts, 4);
+
+        let unmerged = ComposedInstructions::new(&instructions, false);
+        assert_eq!(unmerged.instructions, instructions);
+        assert_eq!(unmerged.deduplicated_accounts, 6);
+    }
+
+    #[test]
+    fn test_transaction_size() {
+        let composed = ComposedInstructions::new(
+            &[Instruction::new_with_bytes(
+                Pubkey::new_unique(),
+                &[1, 2, 3],
+                vec![AccountMeta::new(Pubkey::new_unique(), false)],
+            )],
+            false,
+        );
+
+        // signatures: 1 + 64
+        // message: header 3 + keys 1 + 3 * 32 + blockhash 32 + instructions 1
+        // instruction: program 1 + accounts 1 + 1 + data 1 + 3
+        assert_eq!(composed.transaction_size(&Pubkey::new_unique()), 205);
+        assert_eq!(short_vec_length_size(0x7f), 1);
+        assert_eq!(short_vec_length_size(0x80), 2);
+    }
+}
@@ -0,0 +1,142 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+use solana_client::client_error::{ClientError, ClientErrorKind};
+use solana_client::nonblocking::pubsub_client::PubsubClientError;
+use solana_client::rpc_request::{RpcError, RpcResponseErrorData};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+
+use crate::errors::Error;
+use crate::rpc_errors::RpcFankorError;
+
+pub type FankorClientResult<T> = Result<T, FankorClientError>;
+
+/// Errors that can be returned by the [FankorClient](crate::client::FankorClient).
+#[derive(Debug)]
+pub enum FankorClientError {
+    /// The RPC request failed.
+    Rpc(Box<ClientError>),
+
+    /// The websocket subscription failed.
+    Pubsub(Box<PubsubClientError>),
+
+    /// The program failed with a Fankor error decoded from the logs.
+    Program(RpcFankorError<'static>),
+
+    /// The account does not exist.
+    AccountNotFound { address: Pubkey },
+
+    /// The account is not owned by the expected program.
+    AccountOwnedByWrongProgram {
+        address: Pubkey,
+        expected: Pubkey,
+        actual: Pubkey,
+    },
+
+    /// The account data cannot be deserialized into the expected type.
+    AccountDidNotDeserialize { address: Pubkey, error: Error },
+
+    /// The address returned by the node is not a valid public key.
+    InvalidAddress { address: String },
+
+    /// The simulation of a transaction failed without a Fankor error.
+    SimulationFailed {
+        error: TransactionError,
+        logs: Vec<String>,
+    },
+
+    /// The return data or the events of a simulation cannot be decoded.
+    SimulationDidNotDecode,
+
+    /// The keypair cannot be loaded.
+    InvalidKeypair { reason: String },
+
+    /// A framework error, e.g. while resolving the accounts of an instruction.
+    Framework(Error),
+}
+
+impl FankorClientError {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The code of the error thrown by the program, if any.
+    pub fn program_error_code(&self) -> Option<u32> {
+        match self {
+            FankorClientError::Program(e) => Some(e.code),
+            _ => None,
+        }
+    }
+}
+
+impl Display for FankorClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FankorClientError::Rpc(e) => Display::fmt(e, f),
+            FankorClientError::Pubsub(e) => Display::fmt(e, f),
+            FankorClientError::Program(e) => Display::fmt(e, f),
+            FankorClientError::AccountNotFound { address } => {
+                write!(f, "Account {} not found", address)
+            }
+            FankorClientError::AccountOwnedByWrongProgram {
+                address,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "The account {} was expected to be owned by {} but it is owned by {}",
+                address, expected, actual
+            ),
+            FankorClientError::AccountDidNotDeserialize { address, error } => {
+                write!(f, "Cannot deserialize the account {}: {}", address, error)
+            }
+            FankorClientError::InvalidAddress { address } => {
+                write!(f, "The address {} is not a valid public key", address)
+            }
+            FankorClientError::SimulationFailed { error, .. } => {
+                write!(f, "The simulation failed: {}", error)
+            }
+            FankorClientError::SimulationDidNotDecode => {
+                write!(f, "Cannot decode the result of the simulation")
+            }
+            FankorClientError::InvalidKeypair { reason } => {
+                write!(f, "Cannot load the keypair: {}", reason)
+            }
+            FankorClientError::Framework(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+impl std::error::Error for FankorClientError {}
+
+impl From<ClientError> for FankorClientError {
+    fn from(e: ClientError) -> Self {
+        // Try to decode the Fankor error from the simulation logs.
+        if let ClientErrorKind::RpcError(RpcError::RpcResponseError {
+            data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+            ..
+        }) = e.kind()
+        {
+            if let Some(error) = result.logs.as_deref().and_then(RpcFankorError::from_logs) {
+                return FankorClientError::Program(RpcFankorError::new(
+                    error.code,
+                    Cow::Owned(error.name.into_owned()),
+                    Cow::Owned(error.message.into_owned()),
+                ));
+            }
+        }
+
+        FankorClientError::Rpc(Box::new(e))
+    }
+}
+
+impl From<PubsubClientError> for FankorClientError {
+    fn from(e: PubsubClientError) -> Self {
+        FankorClientError::Pubsub(Box::new(e))
+    }
+}
+
+impl From<Error> for FankorClientError {
+    fn from(e: Error) -> Self {
+        FankorClientError::Framework(e)
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use borsh::BorshSerialize;
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+
+use crate::errors::FankorResult;
+use crate::traits::AccountType;
+
+/// Builder of the filters used in `getProgramAccounts` requests to fetch
+/// only the accounts of type `T`.
+pub struct AccountFilter<T: AccountType> {
+    filters: Vec<RpcFilterType>,
+    _data: PhantomData<T>,
+}
+
+impl<T: AccountType> AccountFilter<T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new filter that matches the discriminant of `T`.
+    pub fn new() -> Self {
+        AccountFilter {
+            filters: vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                0,
+                vec![T::discriminant()],
+            ))],
+            _data: PhantomData,
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Adds a filter that matches `bytes` at `offset`.
+    pub fn memcmp(mut self, offset: usize, bytes: Vec<u8>) -> Self {
+        self.filters
+            .push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes)));
+        self
+    }
+
+    /// Adds a filter that matches the serialized `value` at `offset`.
+    pub fn field<V: BorshSerialize>(self, offset: usize, value: &V) -> FankorResult<Self> {
+        let bytes = value.try_to_vec()?;
+        Ok(self.memcmp(offset, bytes))
+    }
+
+    /// Adds a filter that matches the exact size of the account data.
+    pub fn data_size(mut self, size: u64) -> Self {
+        self.filters.push(RpcFilterType::DataSize(size));
+        self
+    }
+
+    /// Returns the list of filters.
+    pub fn build(self) -> Vec<RpcFilterType> {
+        self.filters
+    }
+}
+
+impl<T: AccountType> Default for AccountFilter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: AccountType> Clone for AccountFilter<T> {
+    fn clone(&self) -> Self {
+        AccountFilter {
+            filters: self.filters.clone(),
+            _data: PhantomData,
+        }
+    }
+}
+
+impl<T: AccountType> PartialEq for AccountFilter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.filters == other.filters
+    }
+}
+
+impl<T: AccountType> Eq for AccountFilter<T> {}
+
+impl<T: AccountType> Debug for AccountFilter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccountFilter")
+            .field("filters", &self.filters)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    #[derive(BorshSerialize, BorshDeserialize)]
+    struct TestAccount;
+
+    impl AccountType for TestAccount {
+        fn discriminant() -> u8 {
+            7
+        }
+
+        fn owner() -> &'static Pubkey {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn test_build() {
+        let filters = AccountFilter::<TestAccount>::new()
+            .field(1, &5u32)
+            .unwrap()
+            .data_size(10)
+            .build();
+
+        assert_eq!(filters.len(), 3);
+
+        match &filters[0] {
+            RpcFilterType::Memcmp(v) => {
+                assert_eq!(v.offset, 0);
+                assert_eq!(*v.bytes().unwrap(), vec![7u8]);
+            }
+            _ => panic!("Incorrect filter"),
+        }
+
+        match &filters[1] {
+            RpcFilterType::Memcmp(v) => {
+                assert_eq!(v.offset, 1);
+                assert_eq!(*v.bytes().unwrap(), vec![5u8, 0, 0, 0]);
+            }
+            _ => panic!("Incorrect filter"),
+        }
+
+        assert_eq!(filters[2], RpcFilterType::DataSize(10));
+    }
+}
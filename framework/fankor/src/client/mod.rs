@@ -0,0 +1,19 @@
+pub use composition::*;
+pub use errors::*;
+pub use filters::*;
+pub use priority_fees::*;
+pub use pubsub::*;
+pub use rpc::*;
+pub use signers::*;
+pub use simulation::*;
+pub use transactions::*;
+
+mod composition;
+mod errors;
+mod filters;
+mod priority_fees;
+mod pubsub;
+mod rpc;
+mod signers;
+mod simulation;
+mod transactions;
@@ -0,0 +1,122 @@
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::client::errors::FankorClientResult;
+
+/// The maximum number of accounts accepted by `getRecentPrioritizationFees`.
+const MAX_PRIORITIZATION_FEE_ACCOUNTS: usize = 128;
+
+/// The percentile of the recent fees used as the suggested price.
+const DEFAULT_PRIORITY_FEE_PERCENTILE: u8 = 75;
+
+/// Suggests a compute-unit price, in micro-lamports, for a transaction that
+/// write-locks the writable `accounts`, e.g. the ones of an instruction built
+/// with the `lpi` module of a program.
+///
+/// It is the 75th percentile of the fees paid in the recent slots to lock
+/// those accounts, or 0 if none of them has been recently contended.
+pub async fn estimate_priority_fee(
+    rpc: &RpcClient,
+    accounts: &[AccountMeta],
+) -> FankorClientResult<u64> {
+    let mut addresses = Vec::<Pubkey>::new();
+
+    for account in accounts.iter().filter(|v| v.is_writable) {
+        if !addresses.contains(&account.pubkey) {
+            addresses.push(account.pubkey);
+        }
+    }
+
+    addresses.truncate(MAX_PRIORITIZATION_FEE_ACCOUNTS);
+
+    let fees = rpc
+        .get_recent_prioritization_fees(&addresses)
+        .await?
+        .into_iter()
+        .map(|v| v.prioritization_fee)
+        .collect::<Vec<_>>();
+
+    Ok(fee_percentile(fees, DEFAULT_PRIORITY_FEE_PERCENTILE))
+}
+
+/// Prepends the compute budget instructions that set the `compute_unit_price`
+/// and, optionally, the `compute_unit_limit` of the transaction.
+pub fn prepend_compute_budget_instructions(
+    instructions: &[Instruction],
+    compute_unit_price: u64,
+    compute_unit_limit: Option<u32>,
+) -> Vec<Instruction> {
+    let mut result = Vec::with_capacity(instructions.len() + 2);
+
+    if let Some(compute_unit_limit) = compute_unit_limit {
+        result.push(ComputeBudgetInstruction::set_compute_unit_limit(
+            compute_unit_limit,
+        ));
+    }
+
+    if compute_unit_price > 0 {
+        result.push(ComputeBudgetInstruction::set_compute_unit_price(
+            compute_unit_price,
+        ));
+    }
+
+    result.extend_from_slice(instructions);
+    result
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Gets the `percentile` of `fees` rounding up to the next sample.
+fn fee_percentile(mut fees: Vec<u64>, percentile: u8) -> u64 {
+    if fees.is_empty() {
+        return 0;
+    }
+
+    fees.sort_unstable();
+
+    let percentile = percentile.min(100) as usize;
+    let index = ((fees.len() * percentile + 99) / 100).saturating_sub(1);
+
+    fees[index]
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fee_percentile() {
+        assert_eq!(fee_percentile(vec![], 75), 0);
+        assert_eq!(fee_percentile(vec![10], 75), 10);
+        assert_eq!(fee_percentile(vec![40, 10, 30, 20], 75), 30);
+        assert_eq!(fee_percentile(vec![40, 10, 30, 20], 50), 20);
+        assert_eq!(fee_percentile(vec![40, 10, 30, 20], 100), 40);
+        assert_eq!(fee_percentile(vec![40, 10, 30, 20], 0), 10);
+    }
+
+    #[test]
+    fn test_prepend_compute_budget_instructions() {
+        let instruction = Instruction::new_with_bytes(Pubkey::new_unique(), &[1], vec![]);
+
+        let result = prepend_compute_budget_instructions(&[instruction.clone()], 0, None);
+        assert_eq!(result, vec![instruction.clone()]);
+
+        let result = prepend_compute_budget_instructions(&[instruction.clone()], 5, Some(200_000));
+        assert_eq!(
+            result,
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                ComputeBudgetInstruction::set_compute_unit_price(5),
+                instruction,
+            ]
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use borsh::BorshDeserialize;
+use futures::future;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use solana_account_decoder::{UiAccount, UiAccountEncoding};
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::RpcFilterType;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+
+use crate::client::errors::{FankorClientError, FankorClientResult};
+use crate::client::rpc::decode_account;
+use crate::traits::AccountType;
+
+/// A stream of decoded account values together with the function to
+/// unsubscribe from it.
+pub type FankorSubscription<'a, T> = (
+    BoxStream<'a, FankorClientResult<T>>,
+    Box<dyn FnOnce() -> futures::future::BoxFuture<'static, ()> + Send>,
+);
+
+/// A thin wrapper over the nonblocking [PubsubClient] that decodes the
+/// notifications into Fankor types.
+pub struct FankorPubsubClient {
+    pubsub: PubsubClient,
+}
+
+impl FankorPubsubClient {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(pubsub: PubsubClient) -> Self {
+        Self { pubsub }
+    }
+
+    pub async fn new_with_url(url: &str) -> FankorClientResult<Self> {
+        Ok(Self::new(PubsubClient::new(url).await?))
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn pubsub(&self) -> &PubsubClient {
+        &self.pubsub
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Subscribes to the changes of the account at `address`.
+    pub async fn subscribe_account<T: AccountType + BorshDeserialize + Send + 'static>(
+        &self,
+        address: &Pubkey,
+    ) -> FankorClientResult<FankorSubscription<'_, T>> {
+        let address = *address;
+        let (stream, unsubscribe) = self
+            .pubsub
+            .account_subscribe(
+                &address,
+                Some(RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                }),
+            )
+            .await?;
+
+        let stream = stream
+            .map(move |response| decode_ui_account(&address, &response.value))
+            .boxed();
+
+        Ok((stream, unsubscribe))
+    }
+
+    /// Subscribes to the changes of all accounts of type `T` that match `filters`.
+    /// The filters can be built with the [AccountFilter](crate::client::AccountFilter).
+    pub async fn subscribe_program_accounts<
+        T: AccountType + BorshDeserialize + Send + 'static,
+    >(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> FankorClientResult<FankorSubscription<'_, (Pubkey, T)>> {
+        let (stream, unsubscribe) = self
+            .pubsub
+            .program_subscribe(
+                T::owner(),
+                Some(RpcProgramAccountsConfig {
+                    filters: Some(filters),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+
+        // Accounts of other types owned by the same program are skipped.
+        let stream = stream
+            .filter_map(|response| {
+                future::ready(
+                    decode_keyed_ui_account(&response.value.pubkey, &response.value.account)
+                        .transpose(),
+                )
+            })
+            .boxed();
+
+        Ok((stream, unsubscribe))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn decode_raw_ui_account(address: &Pubkey, account: &UiAccount) -> FankorClientResult<Account> {
+    account
+        .decode::<Account>()
+        .ok_or(FankorClientError::AccountDidNotDeserialize {
+            address: *address,
+            error: ProgramError::InvalidAccountData.into(),
+        })
+}
+
+fn decode_ui_account<T: AccountType + BorshDeserialize>(
+    address: &Pubkey,
+    account: &UiAccount,
+) -> FankorClientResult<T> {
+    let account = decode_raw_ui_account(address, account)?;
+
+    decode_account(address, &account.owner, &account.data)
+}
+
+/// Decodes a program account notification, returning `None` if the account
+/// does not have the discriminant of `T`.
+fn decode_keyed_ui_account<T: AccountType + BorshDeserialize>(
+    pubkey: &str,
+    account: &UiAccount,
+) -> FankorClientResult<Option<(Pubkey, T)>> {
+    let address: Pubkey = pubkey
+        .parse()
+        .map_err(|_| FankorClientError::InvalidAddress {
+            address: pubkey.to_string(),
+        })?;
+    let account = decode_raw_ui_account(&address, account)?;
+
+    if !account
+        .data
+        .first()
+        .map_or(false, |v| T::check_discriminant(*v))
+    {
+        return Ok(None);
+    }
+
+    let data = decode_account(&address, &account.owner, &account.data)?;
+
+    Ok(Some((address, data)))
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use borsh::BorshSerialize;
+    use solana_account_decoder::UiAccountData;
+
+    use super::*;
+
+    const OWNER: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[derive(Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+    struct TestAccount {
+        value: u64,
+    }
+
+    impl AccountType for TestAccount {
+        fn discriminant() -> u8 {
+            1
+        }
+
+        fn owner() -> &'static Pubkey {
+            &OWNER
+        }
+    }
+
+    fn encode_account(address: &Pubkey, owner: Pubkey, data: Vec<u8>) -> UiAccount {
+        let account = Account {
+            lamports: 1,
+            data,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        UiAccount::encode(address, &account, UiAccountEncoding::Base64, None, None)
+    }
+
+    #[test]
+    fn test_decode_ui_account() {
+        let address = Pubkey::new_unique();
+        let data = TestAccount { value: 5 }.try_to_vec().unwrap();
+
+        let account = encode_account(&address, OWNER, data.clone());
+        let decoded = decode_ui_account::<TestAccount>(&address, &account).unwrap();
+        assert_eq!(decoded, TestAccount { value: 5 });
+
+        let account = encode_account(&address, Pubkey::new_unique(), data);
+        assert!(matches!(
+            decode_ui_account::<TestAccount>(&address, &account),
+            Err(FankorClientError::AccountOwnedByWrongProgram { .. })
+        ));
+
+        // The data is not valid base58.
+        let mut account = encode_account(&address, OWNER, vec![]);
+        account.data = UiAccountData::LegacyBinary("0".to_string());
+        assert!(matches!(
+            decode_ui_account::<TestAccount>(&address, &account),
+            Err(FankorClientError::AccountDidNotDeserialize { .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_keyed_ui_account() {
+        // The first byte of the value matches the discriminant.
+        let address = Pubkey::new_unique();
+        let data = TestAccount { value: 0x0701 }.try_to_vec().unwrap();
+        let account = encode_account(&address, OWNER, data);
+
+        let (decoded_address, decoded) =
+            decode_keyed_ui_account::<TestAccount>(&address.to_string(), &account)
+                .unwrap()
+                .unwrap();
+        assert_eq!(decoded_address, address);
+        assert_eq!(decoded, TestAccount { value: 0x0701 });
+
+        assert!(matches!(
+            decode_keyed_ui_account::<TestAccount>("invalid", &account),
+            Err(FankorClientError::InvalidAddress { .. })
+        ));
+
+        // Other discriminant.
+        let data = TestAccount { value: 7 }.try_to_vec().unwrap();
+        let account = encode_account(&address, OWNER, data);
+        assert!(
+            decode_keyed_ui_account::<TestAccount>(&address.to_string(), &account)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
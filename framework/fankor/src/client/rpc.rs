@@ -0,0 +1,260 @@
+use borsh::BorshDeserialize;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::RpcFilterType;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::Transaction;
+
+use crate::client::errors::{FankorClientError, FankorClientResult};
+use crate::client::priority_fees::{estimate_priority_fee, prepend_compute_budget_instructions};
+use crate::client::signers::FankorSigner;
+use crate::errors::FankorResult;
+use crate::traits::{AccountType, LpiInstruction};
+
+/// A thin wrapper over the nonblocking [RpcClient] that works with the
+/// Fankor types.
+pub struct FankorClient {
+    rpc: RpcClient,
+}
+
+impl FankorClient {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(rpc: RpcClient) -> Self {
+        Self { rpc }
+    }
+
+    pub fn new_with_url(url: String) -> Self {
+        Self::new(RpcClient::new(url))
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Fetches and deserializes the account at `address`.
+    pub async fn fetch_account<T: AccountType + BorshDeserialize>(
+        &self,
+        address: &Pubkey,
+    ) -> FankorClientResult<T> {
+        let account = self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())
+            .await?
+            .value
+            .ok_or(FankorClientError::AccountNotFound { address: *address })?;
+
+        decode_account(address, &account.owner, &account.data)
+    }
+
+    /// Fetches and deserializes all the accounts of type `T` that match `filters`.
+    /// The filters can be built with the [AccountFilter](crate::client::AccountFilter).
+    pub async fn fetch_all_accounts<T: AccountType + BorshDeserialize>(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> FankorClientResult<Vec<(Pubkey, T)>> {
+        let accounts = self
+            .rpc
+            .get_program_accounts_with_config(
+                T::owner(),
+                RpcProgramAccountsConfig {
+                    filters: Some(filters),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        commitment: Some(self.rpc.commitment()),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        accounts
+            .into_iter()
+            .filter(|(_, account)| {
+                account
+                    .data
+                    .first()
+                    .map_or(false, |v| T::check_discriminant(*v))
+            })
+            .map(|(address, account)| {
+                let data = decode_account(&address, &account.owner, &account.data)?;
+                Ok((address, data))
+            })
+            .collect()
+    }
+
+    /// Sends a transaction with `instructions` signed by `signers`, where the first
+    /// signer is the fee payer. Program failures are decoded into
+    /// [FankorClientError::Program].
+    pub async fn send_instructions(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&dyn FankorSigner],
+    ) -> FankorClientResult<Signature> {
+        let signers = signers.iter().map(|v| v.as_signer()).collect::<Vec<_>>();
+        let payer = signers.first().map(|v| v.pubkey());
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            payer.as_ref(),
+            &signers,
+            blockhash,
+        );
+
+        Ok(self.rpc.send_and_confirm_transaction(&transaction).await?)
+    }
+
+    /// Sends a transaction with a single `instruction`, usually built with the
+    /// `lpi` module of a program.
+    pub async fn send_instruction(
+        &self,
+        instruction: Instruction,
+        signers: &[&dyn FankorSigner],
+    ) -> FankorClientResult<Signature> {
+        self.send_instructions(&[instruction], signers).await
+    }
+
+    /// Builds an instruction from its LPI `accounts` with `lpi`, one of the
+    /// methods of the `lpi` module of a program, and sends it in a transaction.
+    ///
+    /// ```ignore
+    /// client.send_lpi(lpi::init_global_config, LpiInitGlobalConfig { .. }, &[&payer]).await?;
+    /// ```
+    pub async fn send_lpi<L: LpiInstruction>(
+        &self,
+        lpi: impl FnOnce(L) -> FankorResult<Instruction>,
+        accounts: L,
+        signers: &[&dyn FankorSigner],
+    ) -> FankorClientResult<Signature> {
+        let instruction = lpi(accounts)?;
+        self.send_instruction(instruction, signers).await
+    }
+
+    /// Same as [FankorClient::send_instructions] but prepending the compute
+    /// budget instructions with the price suggested by [estimate_priority_fee]
+    /// for the writable accounts of `instructions`.
+    pub async fn send_instructions_with_priority_fee(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&dyn FankorSigner],
+        compute_unit_limit: Option<u32>,
+    ) -> FankorClientResult<Signature> {
+        let accounts = instructions
+            .iter()
+            .flat_map(|v| v.accounts.iter().cloned())
+            .collect::<Vec<_>>();
+        let compute_unit_price = estimate_priority_fee(&self.rpc, &accounts).await?;
+        let instructions = prepend_compute_budget_instructions(
+            instructions,
+            compute_unit_price,
+            compute_unit_limit,
+        );
+
+        self.send_instructions(&instructions, signers).await
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+pub(crate) fn decode_account<T: AccountType + BorshDeserialize>(
+    address: &Pubkey,
+    owner: &Pubkey,
+    data: &[u8],
+) -> FankorClientResult<T> {
+    if owner != T::owner() {
+        return Err(FankorClientError::AccountOwnedByWrongProgram {
+            address: *address,
+            expected: *T::owner(),
+            actual: *owner,
+        });
+    }
+
+    let mut data = data;
+    T::deserialize(&mut data).map_err(|e| FankorClientError::AccountDidNotDeserialize {
+        address: *address,
+        error: e.into(),
+    })
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use borsh::BorshSerialize;
+
+    use crate::errors::FankorErrorCode;
+
+    use super::*;
+
+    const OWNER: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[derive(Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+    struct TestAccount {
+        value: u64,
+    }
+
+    impl AccountType for TestAccount {
+        fn discriminant() -> u8 {
+            1
+        }
+
+        fn owner() -> &'static Pubkey {
+            &OWNER
+        }
+    }
+
+    #[test]
+    fn test_decode_account() {
+        let address = Pubkey::new_unique();
+        let data = TestAccount { value: 5 }.try_to_vec().unwrap();
+
+        let account = decode_account::<TestAccount>(&address, &OWNER, &data).unwrap();
+        assert_eq!(account, TestAccount { value: 5 });
+
+        let other_owner = Pubkey::new_unique();
+        match decode_account::<TestAccount>(&address, &other_owner, &data) {
+            Err(FankorClientError::AccountOwnedByWrongProgram {
+                address: error_address,
+                expected,
+                actual,
+            }) => {
+                assert_eq!(error_address, address);
+                assert_eq!(expected, OWNER);
+                assert_eq!(actual, other_owner);
+            }
+            _ => panic!("Expected AccountOwnedByWrongProgram"),
+        }
+
+        match decode_account::<TestAccount>(&address, &OWNER, &data[..4]) {
+            Err(FankorClientError::AccountDidNotDeserialize {
+                address: error_address,
+                ..
+            }) => assert_eq!(error_address, address),
+            _ => panic!("Expected AccountDidNotDeserialize"),
+        }
+    }
+
+    #[test]
+    fn test_send_lpi_build_error() {
+        let client = FankorClient::new(RpcClient::new_mock("succeeds".to_string()));
+        let lpi = |_: Pubkey| -> FankorResult<Instruction> {
+            Err(FankorErrorCode::MissingInstructionDiscriminant.into())
+        };
+
+        // The instruction fails to build before any request is sent.
+        let result = futures::executor::block_on(client.send_lpi(lpi, Pubkey::new_unique(), &[]));
+        assert!(matches!(result, Err(FankorClientError::Framework(_))));
+    }
+}
@@ -0,0 +1,153 @@
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+use solana_sdk::derivation_path::DerivationPath;
+use solana_sdk::signature::{read_keypair, read_keypair_file, Keypair};
+use solana_sdk::signer::keypair::{
+    generate_seed_from_seed_phrase_and_passphrase, keypair_from_seed_and_derivation_path,
+};
+use solana_sdk::signer::Signer;
+
+use crate::client::errors::{FankorClientError, FankorClientResult};
+
+/// A signer accepted by the [FankorClient](crate::client::FankorClient) to
+/// sign transactions. It is implemented for all the thread-safe [Signer]s,
+/// like the [Keypair]s returned by the loaders of this module.
+pub trait FankorSigner: Signer + Send + Sync {
+    fn as_signer(&self) -> &dyn Signer;
+}
+
+impl<T: Signer + Send + Sync> FankorSigner for T {
+    fn as_signer(&self) -> &dyn Signer {
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The path of the keypair used by default by the Solana CLI, i.e.
+/// `~/.config/solana/id.json`.
+pub fn default_keypair_path() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    let mut path = PathBuf::from(home);
+    path.extend([".config", "solana", "id.json"]);
+
+    Some(path)
+}
+
+/// Loads a keypair from a file in the JSON format of the Solana CLI, i.e. an
+/// array with the 64 bytes of the keypair.
+pub fn keypair_from_file<P: AsRef<Path>>(path: P) -> FankorClientResult<Keypair> {
+    read_keypair_file(path.as_ref()).map_err(|e| FankorClientError::InvalidKeypair {
+        reason: format!("{}: {}", path.as_ref().display(), e),
+    })
+}
+
+/// Loads a keypair from the JSON format of the Solana CLI.
+pub fn keypair_from_json(json: &str) -> FankorClientResult<Keypair> {
+    read_keypair(&mut Cursor::new(json)).map_err(|e| FankorClientError::InvalidKeypair {
+        reason: e.to_string(),
+    })
+}
+
+/// Loads a keypair from its 64 bytes encoded in base58, like the ones
+/// exported by most wallets.
+pub fn keypair_from_base58(value: &str) -> FankorClientResult<Keypair> {
+    let bytes =
+        bs58::decode(value.trim())
+            .into_vec()
+            .map_err(|e| FankorClientError::InvalidKeypair {
+                reason: e.to_string(),
+            })?;
+
+    Keypair::from_bytes(&bytes).map_err(|e| FankorClientError::InvalidKeypair {
+        reason: e.to_string(),
+    })
+}
+
+/// Recovers a keypair from a seed phrase and an optional passphrase.
+///
+/// Without `derivation_path` the result is the same as `solana-keygen recover`.
+/// Wallets usually derive their accounts from paths like `m/44'/501'/0'/0'`.
+pub fn keypair_from_seed_phrase(
+    seed_phrase: &str,
+    passphrase: &str,
+    derivation_path: Option<&str>,
+) -> FankorClientResult<Keypair> {
+    let derivation_path = derivation_path
+        .map(|v| {
+            DerivationPath::from_absolute_path_str(v).map_err(|e| {
+                FankorClientError::InvalidKeypair {
+                    reason: e.to_string(),
+                }
+            })
+        })
+        .transpose()?;
+
+    let seed_phrase = seed_phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+    let seed = generate_seed_from_seed_phrase_and_passphrase(&seed_phrase, passphrase);
+
+    keypair_from_seed_and_derivation_path(&seed, derivation_path).map_err(|e| {
+        FankorClientError::InvalidKeypair {
+            reason: e.to_string(),
+        }
+    })
+}
+
+/// Loads a keypair from the environment variable `name`, which can contain
+/// the keypair in the JSON format of the Solana CLI, the keypair encoded in
+/// base58 or the path to a keypair file.
+pub fn keypair_from_env(name: &str) -> FankorClientResult<Keypair> {
+    let value = std::env::var(name).map_err(|e| FankorClientError::InvalidKeypair {
+        reason: format!("{}: {}", name, e),
+    })?;
+    let value = value.trim();
+
+    if value.starts_with('[') {
+        return keypair_from_json(value);
+    }
+
+    if Path::new(value).is_file() {
+        return keypair_from_file(value);
+    }
+
+    keypair_from_base58(value)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_keypair_from_json_and_base58() {
+        let keypair = Keypair::new();
+        let json = format!("{:?}", keypair.to_bytes().to_vec());
+
+        let loaded = keypair_from_json(&json).unwrap();
+        assert_eq!(loaded.pubkey(), keypair.pubkey());
+
+        let loaded = keypair_from_base58(&keypair.to_base58_string()).unwrap();
+        assert_eq!(loaded.pubkey(), keypair.pubkey());
+
+        assert!(keypair_from_json("[1, 2, 3]").is_err());
+        assert!(keypair_from_base58("invalid").is_err());
+    }
+
+    #[test]
+    fn test_keypair_from_seed_phrase() {
+        let seed_phrase = "pistol arm sugar dial tired bamboo brick donkey sand vocal soap ahead";
+        let keypair = keypair_from_seed_phrase(seed_phrase, "", None).unwrap();
+        let keypair2 = keypair_from_seed_phrase(&format!("  {}\n", seed_phrase), "", None).unwrap();
+        let derived = keypair_from_seed_phrase(seed_phrase, "", Some("m/44'/501'/0'/0'")).unwrap();
+
+        assert_eq!(keypair.pubkey(), keypair2.pubkey());
+        assert_ne!(keypair.pubkey(), derived.pubkey());
+        assert!(keypair_from_seed_phrase(seed_phrase, "", Some("invalid")).is_err());
+    }
+}
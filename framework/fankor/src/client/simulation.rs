@@ -0,0 +1,202 @@
+use borsh::BorshDeserialize;
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::transaction::Transaction;
+
+use crate::client::errors::{FankorClientError, FankorClientResult};
+use crate::client::rpc::FankorClient;
+use crate::errors::FankorResult;
+use crate::rpc_errors::RpcFankorError;
+
+/// The decoded result of simulating an instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulationResult<R> {
+    /// The value returned by the instruction, if any.
+    pub return_data: Option<R>,
+
+    /// The data logged by the program with `sol_log_data`, excluding the one
+    /// of other programs invoked through CPIs. Each entry contains all the
+    /// slices of a single log concatenated.
+    pub events: Vec<Vec<u8>>,
+
+    /// The compute units consumed by the whole transaction.
+    pub compute_units_consumed: Option<u64>,
+
+    /// The raw logs of the simulation.
+    pub logs: Vec<String>,
+}
+
+impl<R> SimulationResult<R> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Deserializes the events into `E`.
+    pub fn decode_events<E: BorshDeserialize>(&self) -> FankorResult<Vec<E>> {
+        self.events
+            .iter()
+            .map(|v| Ok(E::try_from_slice(v)?))
+            .collect()
+    }
+}
+
+impl FankorClient {
+    // METHODS ----------------------------------------------------------------
+
+    /// Simulates a transaction with `instruction`, usually built with the `lpi`
+    /// module of a program, paid by `payer` and decodes its return data, events
+    /// and consumed compute units.
+    ///
+    /// The signatures are not verified and the blockhash is replaced, so the
+    /// transaction does not need to be signed. Program failures are decoded
+    /// into [FankorClientError::Program].
+    pub async fn simulate<R: BorshDeserialize>(
+        &self,
+        instruction: Instruction,
+        payer: &Pubkey,
+    ) -> FankorClientResult<SimulationResult<R>> {
+        let program_id = instruction.program_id;
+        let transaction = Transaction::new_with_payer(&[instruction], Some(payer));
+        let result = self
+            .rpc()
+            .simulate_transaction_with_config(
+                &transaction,
+                RpcSimulateTransactionConfig {
+                    sig_verify: false,
+                    replace_recent_blockhash: true,
+                    commitment: Some(self.rpc().commitment()),
+                    ..Default::default()
+                },
+            )
+            .await?
+            .value;
+
+        let logs = result.logs.unwrap_or_default();
+
+        if let Some(error) = result.err {
+            if let Some(error) = RpcFankorError::from_logs(&logs) {
+                return Err(FankorClientError::Program(RpcFankorError::new(
+                    error.code,
+                    error.name.into_owned().into(),
+                    error.message.into_owned().into(),
+                )));
+            }
+
+            return Err(FankorClientError::SimulationFailed { error, logs });
+        }
+
+        let return_data = match result.return_data {
+            Some(return_data) if return_data.program_id == program_id.to_string() => {
+                let data = base64::decode(&return_data.data.0)
+                    .map_err(|_| FankorClientError::SimulationDidNotDecode)?;
+
+                Some(
+                    R::try_from_slice(&data)
+                        .map_err(|_| FankorClientError::SimulationDidNotDecode)?,
+                )
+            }
+            _ => None,
+        };
+
+        Ok(SimulationResult {
+            return_data,
+            events: decode_log_data(&logs, &program_id)
+                .ok_or(FankorClientError::SimulationDidNotDecode)?,
+            compute_units_consumed: result.units_consumed,
+            logs,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Gets the data logged by `program_id` following the invocation stack in
+/// `logs`, so the data logged by the programs it invokes are ignored. Returns
+/// `None` if any data is not valid base64.
+pub(crate) fn decode_log_data(logs: &[String], program_id: &Pubkey) -> Option<Vec<Vec<u8>>> {
+    let program_id = program_id.to_string();
+    let mut stack = Vec::<&str>::new();
+    let mut result = Vec::new();
+
+    for log in logs {
+        if let Some(data) = log.strip_prefix("Program data: ") {
+            if stack.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+
+            let mut event = Vec::new();
+            for slice in data.split(' ') {
+                event.extend(base64::decode(slice).ok()?);
+            }
+
+            result.push(event);
+            continue;
+        }
+
+        let log = match log.strip_prefix("Program ") {
+            Some(v) => v,
+            None => continue,
+        };
+
+        let (program, rest) = match log.split_once(' ') {
+            Some(v) => v,
+            None => continue,
+        };
+
+        if rest.starts_with("invoke [") {
+            stack.push(program);
+        } else if rest == "success" || rest.starts_with("failed") {
+            stack.pop();
+        }
+    }
+
+    Some(result)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode_log_data() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+        let logs = [
+            format!("Program {} invoke [1]", program_id),
+            "Program log: Instruction: Test".to_string(),
+            format!(
+                "Program data: {} {}",
+                base64::encode([1, 2]),
+                base64::encode([3])
+            ),
+            format!("Program {} invoke [2]", other_program_id),
+            format!("Program data: {}", base64::encode([4])),
+            format!("Program {} success", other_program_id),
+            format!("Program data: {}", base64::encode([5])),
+            format!(
+                "Program {} consumed 1000 of 200000 compute units",
+                program_id
+            ),
+            format!("Program {} success", program_id),
+        ];
+
+        let events = decode_log_data(&logs, &program_id).unwrap();
+        assert_eq!(events, vec![vec![1, 2, 3], vec![5]]);
+
+        let events = decode_log_data(&logs, &other_program_id).unwrap();
+        assert_eq!(events, vec![vec![4]]);
+
+        let result = SimulationResult::<()> {
+            return_data: None,
+            events,
+            compute_units_consumed: Some(1000),
+            logs: vec![],
+        };
+        assert_eq!(result.decode_events::<u8>().unwrap(), vec![4]);
+    }
+}
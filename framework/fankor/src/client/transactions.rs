@@ -0,0 +1,293 @@
+use borsh::BorshDeserialize;
+use solana_program::instruction::AccountMeta;
+use solana_program::pubkey::Pubkey;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, UiInstruction, UiLoadedAddresses,
+};
+
+use crate::client::simulation::decode_log_data;
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::traits::ProgramType;
+
+/// An instruction of a specific program executed inside a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedInstruction {
+    /// The index of the top-level instruction that contains this one.
+    pub index: usize,
+
+    /// Whether the instruction was executed inside a CPI or not.
+    pub inner: bool,
+
+    /// The accounts of the instruction with their resolved roles.
+    pub accounts: Vec<AccountMeta>,
+
+    /// The data of the instruction.
+    pub data: Vec<u8>,
+}
+
+impl DecodedInstruction {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The discriminant of the instruction, i.e. the first byte of its data.
+    pub fn discriminant(&self) -> Option<u8> {
+        self.data.first().copied()
+    }
+
+    /// The data of the instruction after the discriminant.
+    pub fn arguments(&self) -> &[u8] {
+        self.data.get(1..).unwrap_or(&[])
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Deserializes the arguments of the instruction into `A`, failing if
+    /// any byte is left.
+    pub fn decode_arguments<A: BorshDeserialize>(&self) -> FankorResult<A> {
+        Ok(A::try_from_slice(self.arguments())?)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Decodes all the instructions of the program `P` executed inside a transaction,
+/// including the inner ones, in execution order.
+///
+/// The transaction must be requested with a binary encoding (base58 or base64)
+/// for the inner instructions to be compiled ones.
+pub fn decode_program_instructions<P: ProgramType>(
+    transaction: &EncodedConfirmedTransactionWithStatusMeta,
+) -> FankorResult<Vec<DecodedInstruction>> {
+    decode_instructions(transaction, P::address())
+}
+
+/// Decodes all the instructions of `program_id` executed inside a transaction,
+/// including the inner ones, in execution order.
+///
+/// The transaction must be requested with a binary encoding (base58 or base64)
+/// for the inner instructions to be compiled ones.
+pub fn decode_instructions(
+    transaction: &EncodedConfirmedTransactionWithStatusMeta,
+    program_id: &Pubkey,
+) -> FankorResult<Vec<DecodedInstruction>> {
+    let transaction_with_meta = &transaction.transaction;
+    let versioned_transaction = transaction_with_meta
+        .transaction
+        .decode()
+        .ok_or(FankorErrorCode::TransactionDidNotDecode)?;
+    let message = &versioned_transaction.message;
+
+    // Resolve the keys and roles of all accounts including the lookup table ones.
+    let static_keys = message.static_account_keys();
+    let mut keys = static_keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| AccountMeta {
+            pubkey: *key,
+            is_signer: message.is_signer(i),
+            is_writable: message.is_maybe_writable(i),
+        })
+        .collect::<Vec<_>>();
+
+    let meta = transaction_with_meta.meta.as_ref();
+    if let Some(OptionSerializer::Some(UiLoadedAddresses { writable, readonly })) =
+        meta.map(|v| &v.loaded_addresses)
+    {
+        for (addresses, is_writable) in [(writable, true), (readonly, false)] {
+            for address in addresses {
+                let pubkey: Pubkey = address
+                    .parse()
+                    .map_err(|_| FankorErrorCode::TransactionDidNotDecode)?;
+
+                keys.push(AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable,
+                });
+            }
+        }
+    }
+
+    let get_meta = |index: u8| {
+        keys.get(index as usize)
+            .cloned()
+            .ok_or(FankorErrorCode::TransactionDidNotDecode)
+    };
+
+    // Collect the inner instructions of each top-level instruction.
+    let inner_instructions = match meta.map(|v| &v.inner_instructions) {
+        Some(OptionSerializer::Some(v)) => v.as_slice(),
+        _ => &[],
+    };
+
+    let mut result = Vec::new();
+    for (index, instruction) in message.instructions().iter().enumerate() {
+        if get_meta(instruction.program_id_index)?.pubkey == *program_id {
+            result.push(DecodedInstruction {
+                index,
+                inner: false,
+                accounts: instruction
+                    .accounts
+                    .iter()
+                    .map(|v| get_meta(*v))
+                    .collect::<Result<Vec<_>, _>>()?,
+                data: instruction.data.clone(),
+            });
+        }
+
+        for inner_instruction in inner_instructions
+            .iter()
+            .filter(|v| v.index as usize == index)
+        {
+            for instruction in &inner_instruction.instructions {
+                let instruction = match instruction {
+                    UiInstruction::Compiled(v) => v,
+                    UiInstruction::Parsed(_) => {
+                        return Err(FankorErrorCode::TransactionDidNotDecode.into());
+                    }
+                };
+
+                if get_meta(instruction.program_id_index)?.pubkey != *program_id {
+                    continue;
+                }
+
+                // Inner instructions do not include the roles of the accounts,
+                // so they are taken from the transaction ones.
+                result.push(DecodedInstruction {
+                    index,
+                    inner: true,
+                    accounts: instruction
+                        .accounts
+                        .iter()
+                        .map(|v| get_meta(*v))
+                        .collect::<Result<Vec<_>, _>>()?,
+                    data: bs58::decode(&instruction.data)
+                        .into_vec()
+                        .map_err(|_| FankorErrorCode::TransactionDidNotDecode)?,
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Decodes the events emitted by the program `P` inside a transaction, i.e.
+/// the data it logged with `sol_log_data`, in emission order.
+pub fn decode_program_events<P: ProgramType, E: BorshDeserialize>(
+    transaction: &EncodedConfirmedTransactionWithStatusMeta,
+) -> FankorResult<Vec<E>> {
+    decode_events(transaction, P::address())
+}
+
+/// Decodes the events emitted by `program_id` inside a transaction, i.e.
+/// the data it logged with `sol_log_data`, in emission order. The events of
+/// the programs it invokes are ignored.
+pub fn decode_events<E: BorshDeserialize>(
+    transaction: &EncodedConfirmedTransactionWithStatusMeta,
+    program_id: &Pubkey,
+) -> FankorResult<Vec<E>> {
+    let logs = match transaction.transaction.meta.as_ref().map(|v| &v.log_messages) {
+        Some(OptionSerializer::Some(v)) => v.as_slice(),
+        _ => &[],
+    };
+
+    decode_log_data(logs, program_id)
+        .ok_or(FankorErrorCode::TransactionDidNotDecode)?
+        .iter()
+        .map(|v| Ok(E::try_from_slice(v)?))
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::instruction::Instruction;
+    use solana_sdk::message::Message;
+    use solana_sdk::transaction::Transaction;
+    use solana_transaction_status::{
+        Encodable, EncodedTransactionWithStatusMeta, TransactionStatusMeta,
+        UiTransactionEncoding, UiTransactionStatusMeta,
+    };
+
+    use super::*;
+
+    fn encode_transaction(
+        instructions: &[Instruction],
+        logs: Vec<String>,
+    ) -> EncodedConfirmedTransactionWithStatusMeta {
+        let payer = Pubkey::new_unique();
+        let transaction = Transaction::new_unsigned(Message::new(instructions, Some(&payer)));
+        let meta = TransactionStatusMeta {
+            log_messages: Some(logs),
+            ..Default::default()
+        };
+
+        EncodedConfirmedTransactionWithStatusMeta {
+            slot: 0,
+            transaction: EncodedTransactionWithStatusMeta {
+                transaction: transaction.encode(UiTransactionEncoding::Base58),
+                meta: Some(UiTransactionStatusMeta::from(meta)),
+                version: None,
+            },
+            block_time: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_instructions() {
+        let program_id = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let instructions = [
+            Instruction {
+                program_id,
+                accounts: vec![AccountMeta::new(account, false)],
+                data: vec![3, 7, 0, 0, 0],
+            },
+            Instruction {
+                program_id: Pubkey::new_unique(),
+                accounts: vec![],
+                data: vec![1],
+            },
+        ];
+        let transaction = encode_transaction(&instructions, vec![]);
+
+        let decoded = decode_instructions(&transaction, &program_id).unwrap();
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].index, 0);
+        assert!(!decoded[0].inner);
+        assert_eq!(decoded[0].accounts, vec![AccountMeta::new(account, false)]);
+        assert_eq!(decoded[0].discriminant(), Some(3));
+        assert_eq!(decoded[0].decode_arguments::<u32>().unwrap(), 7);
+        assert!(decoded[0].decode_arguments::<u16>().is_err());
+    }
+
+    #[test]
+    fn test_decode_events() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+        let logs = vec![
+            format!("Program {} invoke [1]", program_id),
+            format!("Program data: {}", base64::encode(5u16.to_le_bytes())),
+            format!("Program {} invoke [2]", other_program_id),
+            format!("Program data: {}", base64::encode(6u16.to_le_bytes())),
+            format!("Program {} success", other_program_id),
+            format!("Program {} success", program_id),
+        ];
+        let transaction = encode_transaction(&[], logs);
+
+        let events = decode_events::<u16>(&transaction, &program_id).unwrap();
+        assert_eq!(events, vec![5]);
+
+        let events = decode_events::<u16>(&transaction, &other_program_id).unwrap();
+        assert_eq!(events, vec![6]);
+
+        // The events are not of the given type.
+        assert!(decode_events::<u32>(&transaction, &program_id).is_err());
+    }
+}
@@ -0,0 +1,39 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::{AccountMeta, Instruction};
+
+use crate::cpi::account_compression::instruction_data;
+use crate::errors::Error;
+use crate::models::{AccountCompression, Program};
+use crate::prelude::FankorResult;
+
+const DISCRIMINATOR: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+
+pub struct CpiAppend<'info> {
+    pub merkle_tree: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub noop: AccountInfo<'info>,
+}
+
+pub fn append(
+    program: &Program<AccountCompression>,
+    accounts: CpiAppend,
+    leaf: [u8; 32],
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = Instruction {
+        program_id: *program.address(),
+        accounts: vec![
+            AccountMeta::new(*accounts.merkle_tree.key, false),
+            AccountMeta::new_readonly(*accounts.authority.key, true),
+            AccountMeta::new_readonly(*accounts.noop.key, false),
+        ],
+        data: instruction_data(DISCRIMINATOR, &[&leaf]),
+    };
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &[accounts.merkle_tree, accounts.authority, accounts.noop],
+        signer_seeds,
+    )
+    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+}
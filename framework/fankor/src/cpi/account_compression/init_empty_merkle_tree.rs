@@ -0,0 +1,46 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::{AccountMeta, Instruction};
+
+use crate::cpi::account_compression::instruction_data;
+use crate::errors::Error;
+use crate::models::{AccountCompression, Program};
+use crate::prelude::FankorResult;
+
+const DISCRIMINATOR: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+
+pub struct CpiInitEmptyMerkleTree<'info> {
+    pub merkle_tree: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub noop: AccountInfo<'info>,
+}
+
+/// Initializes a tree account previously allocated with
+/// [ConcurrentMerkleTreeAccount::space](crate::models::ConcurrentMerkleTreeAccount::space)
+/// bytes and owned by the account compression program.
+pub fn init_empty_merkle_tree(
+    program: &Program<AccountCompression>,
+    accounts: CpiInitEmptyMerkleTree,
+    max_depth: u32,
+    max_buffer_size: u32,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = Instruction {
+        program_id: *program.address(),
+        accounts: vec![
+            AccountMeta::new(*accounts.merkle_tree.key, false),
+            AccountMeta::new_readonly(*accounts.authority.key, true),
+            AccountMeta::new_readonly(*accounts.noop.key, false),
+        ],
+        data: instruction_data(
+            DISCRIMINATOR,
+            &[&max_depth.to_le_bytes(), &max_buffer_size.to_le_bytes()],
+        ),
+    };
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &[accounts.merkle_tree, accounts.authority, accounts.noop],
+        signer_seeds,
+    )
+    .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+}
@@ -0,0 +1,22 @@
+pub use append::*;
+pub use init_empty_merkle_tree::*;
+pub use replace_leaf::*;
+pub use verify_leaf::*;
+
+mod append;
+mod init_empty_merkle_tree;
+mod replace_leaf;
+mod verify_leaf;
+
+/// Builds the data of an instruction of the account compression program that,
+/// as an Anchor program, starts with the 8-byte discriminator of its name.
+fn instruction_data(discriminator: [u8; 8], args: &[&[u8]]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(8 + args.iter().map(|v| v.len()).sum::<usize>());
+    data.extend_from_slice(&discriminator);
+
+    for arg in args {
+        data.extend_from_slice(arg);
+    }
+
+    data
+}
@@ -0,0 +1,58 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::{AccountMeta, Instruction};
+
+use crate::cpi::account_compression::instruction_data;
+use crate::errors::Error;
+use crate::models::{AccountCompression, Program};
+use crate::prelude::FankorResult;
+
+const DISCRIMINATOR: [u8; 8] = [204, 165, 76, 100, 73, 147, 0, 128];
+
+pub struct CpiReplaceLeaf<'info> {
+    pub merkle_tree: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub noop: AccountInfo<'info>,
+
+    /// The nodes of the proof from the leaf to the root, excluding the ones
+    /// stored in the canopy of the tree.
+    pub proof: Vec<AccountInfo<'info>>,
+}
+
+pub fn replace_leaf(
+    program: &Program<AccountCompression>,
+    accounts: CpiReplaceLeaf,
+    root: [u8; 32],
+    previous_leaf: [u8; 32],
+    new_leaf: [u8; 32],
+    index: u32,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let mut metas = Vec::with_capacity(3 + accounts.proof.len());
+    metas.push(AccountMeta::new(*accounts.merkle_tree.key, false));
+    metas.push(AccountMeta::new_readonly(*accounts.authority.key, true));
+    metas.push(AccountMeta::new_readonly(*accounts.noop.key, false));
+    metas.extend(
+        accounts
+            .proof
+            .iter()
+            .map(|v| AccountMeta::new_readonly(*v.key, false)),
+    );
+
+    let ix = Instruction {
+        program_id: *program.address(),
+        accounts: metas,
+        data: instruction_data(
+            DISCRIMINATOR,
+            &[&root, &previous_leaf, &new_leaf, &index.to_le_bytes()],
+        ),
+    };
+
+    let mut infos = Vec::with_capacity(3 + accounts.proof.len());
+    infos.push(accounts.merkle_tree);
+    infos.push(accounts.authority);
+    infos.push(accounts.noop);
+    infos.extend(accounts.proof);
+
+    solana_program::program::invoke_signed(&ix, &infos, signer_seeds)
+        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+}
@@ -0,0 +1,49 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::{AccountMeta, Instruction};
+
+use crate::cpi::account_compression::instruction_data;
+use crate::errors::Error;
+use crate::models::{AccountCompression, Program};
+use crate::prelude::FankorResult;
+
+const DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+pub struct CpiVerifyLeaf<'info> {
+    pub merkle_tree: AccountInfo<'info>,
+
+    /// The nodes of the proof from the leaf to the root, excluding the ones
+    /// stored in the canopy of the tree.
+    pub proof: Vec<AccountInfo<'info>>,
+}
+
+/// Fails if `leaf` is not at `index` in the tree with the given `root`.
+pub fn verify_leaf(
+    program: &Program<AccountCompression>,
+    accounts: CpiVerifyLeaf,
+    root: [u8; 32],
+    leaf: [u8; 32],
+    index: u32,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let mut metas = Vec::with_capacity(1 + accounts.proof.len());
+    metas.push(AccountMeta::new_readonly(*accounts.merkle_tree.key, false));
+    metas.extend(
+        accounts
+            .proof
+            .iter()
+            .map(|v| AccountMeta::new_readonly(*v.key, false)),
+    );
+
+    let ix = Instruction {
+        program_id: *program.address(),
+        accounts: metas,
+        data: instruction_data(DISCRIMINATOR, &[&root, &leaf, &index.to_le_bytes()]),
+    };
+
+    let mut infos = Vec::with_capacity(1 + accounts.proof.len());
+    infos.push(accounts.merkle_tree);
+    infos.extend(accounts.proof);
+
+    solana_program::program::invoke_signed(&ix, &infos, signer_seeds)
+        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+}
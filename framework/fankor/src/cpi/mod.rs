@@ -1,8 +1,11 @@
+#[cfg(feature = "account-compression-program")]
+pub mod account_compression;
 #[cfg(feature = "token-program")]
 pub mod associated_token;
 mod macros;
 #[cfg(feature = "metadata-program")]
 pub mod metadata;
+pub mod raw;
 pub mod system_program;
 #[cfg(feature = "token-program")]
 pub mod token;
@@ -0,0 +1,42 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::Instruction;
+
+use crate::errors::FankorResult;
+use crate::models::FankorContext;
+
+/// Invokes an arbitrary `instruction`, failing if its program is not in the
+/// CPI allowlist declared with [cpi_allowlist](crate::cpi_allowlist).
+///
+/// Use it instead of `solana_program::program::invoke` when the program to
+/// invoke is chosen dynamically, e.g. taken from the accounts.
+pub fn invoke<'info>(
+    context: &FankorContext<'info>,
+    instruction: &Instruction,
+    infos: &[AccountInfo<'info>],
+) -> FankorResult<()> {
+    invoke_signed(context, instruction, infos, &[])
+}
+
+/// Same as [invoke] but signing with `signer_seeds`.
+pub fn invoke_signed<'info>(
+    context: &FankorContext<'info>,
+    instruction: &Instruction,
+    infos: &[AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    context.check_cpi_program(&instruction.program_id)?;
+
+    solana_program::program::invoke_signed(instruction, infos, signer_seeds)?;
+
+    Ok(())
+}
+
+/// The CPI allowlist used by programs that do not declare one, which allows
+/// all programs. It is imported by the `setup!` macro and shadowed by the
+/// [cpi_allowlist](crate::cpi_allowlist) macro.
+#[doc(hidden)]
+pub mod default_allowlist {
+    use solana_program::pubkey::Pubkey;
+
+    pub const __FANKOR_CPI_ALLOWLIST: Option<&[Pubkey]> = None;
+}
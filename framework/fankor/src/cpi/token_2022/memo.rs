@@ -0,0 +1,30 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::{AccountMeta, Instruction};
+
+use crate::errors::Error;
+use crate::models::{Memo, Program};
+use crate::prelude::FankorResult;
+
+pub struct CpiMemo<'info> {
+    pub signers: Vec<AccountInfo<'info>>,
+}
+
+pub fn memo(
+    program: &Program<Memo>,
+    accounts: CpiMemo,
+    memo: &str,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let ix = Instruction {
+        program_id: *program.address(),
+        accounts: accounts
+            .signers
+            .iter()
+            .map(|v| AccountMeta::new_readonly(*v.key, true))
+            .collect(),
+        data: memo.as_bytes().to_vec(),
+    };
+
+    solana_program::program::invoke_signed(&ix, &accounts.signers, signer_seeds)
+        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+}
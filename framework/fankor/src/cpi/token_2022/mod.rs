@@ -7,6 +7,7 @@ pub use freeze_account::*;
 pub use initialize_account3::*;
 pub use initialize_mint2::*;
 pub use initialize_multisig2::*;
+pub use memo::*;
 pub use mint_to::*;
 pub use mint_to_checked::*;
 pub use revoke::*;
@@ -14,6 +15,8 @@ pub use set_authority::*;
 pub use sync_native::*;
 pub use thaw_account::*;
 pub use transfer_checked::*;
+pub use transfer_checked_with_memo::*;
+pub use transfer_checked_with_hook::*;
 
 mod approve;
 mod approve_checked;
@@ -24,6 +27,7 @@ mod freeze_account;
 mod initialize_account3;
 mod initialize_mint2;
 mod initialize_multisig2;
+mod memo;
 mod mint_to;
 mod mint_to_checked;
 mod revoke;
@@ -31,3 +35,5 @@ mod set_authority;
 mod sync_native;
 mod thaw_account;
 mod transfer_checked;
+mod transfer_checked_with_memo;
+mod transfer_checked_with_hook;
@@ -0,0 +1,93 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::AccountMeta;
+
+use crate::errors::{Error, FankorErrorCode};
+use crate::models::{
+    resolve_extra_account_metas, unpack_extra_account_metas, Program, Token2022,
+    TransferHookExecute,
+};
+use crate::prelude::FankorResult;
+
+pub struct CpiTransferCheckedWithHook<'info> {
+    pub from: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub hook_program: AccountInfo<'info>,
+    pub extra_account_metas: AccountInfo<'info>,
+
+    /// The accounts the extra account metas resolve to, in any order.
+    pub extra_accounts: Vec<AccountInfo<'info>>,
+}
+
+/// Same as [transfer_checked](super::transfer_checked) but for mints with a
+/// transfer hook. The extra accounts required by the hook are resolved from
+/// `extra_account_metas` and appended to the instruction along with the hook
+/// program and the meta list itself.
+pub fn transfer_checked_with_hook(
+    program: &Program<Token2022>,
+    accounts: CpiTransferCheckedWithHook,
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        program.address(),
+        accounts.from.key,
+        accounts.mint.key,
+        accounts.to.key,
+        accounts.authority.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+
+    let metas = {
+        let data = accounts.extra_account_metas.try_borrow_data()?;
+        unpack_extra_account_metas(&mut &data[..])?
+    };
+    let execute_accounts = [
+        AccountMeta::new_readonly(*accounts.from.key, false),
+        AccountMeta::new_readonly(*accounts.mint.key, false),
+        AccountMeta::new_readonly(*accounts.to.key, false),
+        AccountMeta::new_readonly(*accounts.authority.key, false),
+        AccountMeta::new_readonly(*accounts.extra_account_metas.key, false),
+    ];
+    let resolved = resolve_extra_account_metas(
+        &metas,
+        &TransferHookExecute::instruction_data(amount),
+        accounts.hook_program.key,
+        &execute_accounts,
+    )?;
+
+    let mut infos = Vec::with_capacity(6 + resolved.len());
+    infos.push(accounts.from);
+    infos.push(accounts.mint);
+    infos.push(accounts.to);
+    infos.push(accounts.authority);
+
+    for meta in resolved {
+        let info = accounts
+            .extra_accounts
+            .iter()
+            .find(|v| v.key == &meta.pubkey)
+            .ok_or(FankorErrorCode::TransferHookMissingExtraAccount {
+                address: meta.pubkey,
+            })?;
+
+        ix.accounts.push(meta);
+        infos.push(info.clone());
+    }
+
+    ix.accounts
+        .push(AccountMeta::new_readonly(*accounts.hook_program.key, false));
+    ix.accounts.push(AccountMeta::new_readonly(
+        *accounts.extra_account_metas.key,
+        false,
+    ));
+    infos.push(accounts.hook_program);
+    infos.push(accounts.extra_account_metas);
+
+    solana_program::program::invoke_signed(&ix, &infos, signer_seeds)
+        .map_or_else(|e| Err(Error::ProgramError(e)), |_| Ok(()))
+}
@@ -0,0 +1,49 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::cpi::token_2022::{memo, transfer_checked, CpiMemo, CpiTransferChecked};
+use crate::models::{Memo, Program, Token2022};
+use crate::prelude::FankorResult;
+
+pub struct CpiTransferCheckedWithMemo<'info> {
+    pub from: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+/// Same as [transfer_checked](super::transfer_checked) but preceded by a memo
+/// signed by the authority, as required when the destination has the
+/// memo-transfer extension enabled, which can be checked with
+/// [TokenAccount2022::memo_required](crate::models::TokenAccount2022::memo_required).
+pub fn transfer_checked_with_memo(
+    program: &Program<Token2022>,
+    memo_program: &Program<Memo>,
+    accounts: CpiTransferCheckedWithMemo,
+    amount: u64,
+    decimals: u8,
+    memo_text: &str,
+    signer_seeds: &[&[&[u8]]],
+) -> FankorResult<()> {
+    // The memo must be the instruction right before the transfer.
+    memo(
+        memo_program,
+        CpiMemo {
+            signers: vec![accounts.authority.clone()],
+        },
+        memo_text,
+        signer_seeds,
+    )?;
+
+    transfer_checked(
+        program,
+        CpiTransferChecked {
+            from: accounts.from,
+            to: accounts.to,
+            mint: accounts.mint,
+            authority: accounts.authority,
+        },
+        amount,
+        decimals,
+        signer_seeds,
+    )
+}
@@ -19,6 +19,11 @@ pub const ERROR_CODE_OFFSET: u32 = 6000;
 /// - 1500..1999 - Accounts
 /// - 2000..2499 - CPI
 /// - 2500..2999 - ZeroCopy
+/// - 3000..3499 - Client
+/// - 3500..3999 - Oracles
+/// - 4000..4499 - Staking
+/// - 4500..4999 - Fees
+/// - 5000..5499 - Idempotency
 ///
 /// The starting point for user-defined errors is defined
 /// by the [ERROR_CODE_OFFSET](crate::error::ERROR_CODE_OFFSET).
@@ -75,6 +80,61 @@ pub enum FankorErrorCode {
     #[msg("The seeds of the PDA ({}) are missing", account)]
     MissingPdaSeeds { account: Pubkey },
 
+    /// An arithmetic operation overflowed or divided by zero
+    #[msg("The arithmetic operation '{}' overflowed or divided by zero", operation)]
+    ArithmeticOverflow { operation: &'static str },
+
+    /// A value is out of its valid range
+    #[msg("The value {} of '{}' is out of range [0, {}]", value, type_name, max)]
+    ValueOutOfRange {
+        type_name: &'static str,
+        value: u64,
+        max: u64,
+    },
+
+    /// The current timestamp is not after the required one
+    #[msg("The current timestamp ({}) must be after {}", now, timestamp)]
+    TimestampNotReached { timestamp: i64, now: i64 },
+
+    /// The slot is no longer in the SlotHashes sysvar
+    #[msg("The slot {} is no longer in the SlotHashes sysvar", slot)]
+    SlotHashNotFound { slot: u64 },
+
+    /// The hash of the slot does not match the one in the SlotHashes sysvar
+    #[msg("The hash of the slot {} does not match the one in the SlotHashes sysvar", slot)]
+    SlotHashMismatch { slot: u64 },
+
+    /// The validation of an instruction argument failed
+    #[msg("The validation '{}' of the argument '{}' failed", validation, argument)]
+    ArgumentValidationFailed {
+        argument: &'static str,
+        validation: &'static str,
+    },
+
+    /// The instruction data exceeds the maximum length of the instruction
+    #[msg(
+    "The instruction data length ({}) exceeds the maximum of the instruction ({})",
+    actual,
+    max
+    )]
+    InstructionDataTooLarge { max: usize, actual: usize },
+
+    /// The instruction receives more accounts than its maximum
+    #[msg(
+    "The instruction receives more accounts ({}) than its maximum ({})",
+    actual,
+    max
+    )]
+    InstructionTooManyAccounts { max: usize, actual: usize },
+
+    /// The batch contains more operations than its maximum
+    #[msg(
+    "The batch contains more operations ({}) than its maximum ({})",
+    actual,
+    max
+    )]
+    BatchTooLarge { max: usize, actual: usize },
+
     // ------------------------------------------------------------------------
     // Accounts ---------------------------------------------------------------
     // ------------------------------------------------------------------------
@@ -295,6 +355,190 @@ pub enum FankorErrorCode {
     #[msg("The account must be the default one")]
     AccountNotDefault,
 
+    /// The account does not contain the header of a growable account.
+    #[msg("The account {} does not contain the header of a growable account", address)]
+    GrowableAccountWithoutHeader { address: Pubkey },
+
+    /// The account is marked for close.
+    #[msg("The account {} is marked for close", address)]
+    AccountTombstoned { address: Pubkey },
+
+    /// The cooldown of the account marked for close has not finished yet.
+    #[msg(
+    "The account {} cannot be closed until the timestamp {}",
+    address,
+    unlock_timestamp
+    )]
+    AccountTombstoneLocked {
+        address: Pubkey,
+        unlock_timestamp: i64,
+    },
+
+    /// The account must be marked for close
+    #[msg("The account '{}' must be marked for close", account)]
+    AccountConstraintNotTombstoned { account: &'static str },
+
+    /// The account must not be marked for close
+    #[msg("The account '{}' must not be marked for close", account)]
+    AccountConstraintTombstoned { account: &'static str },
+
+    /// Not enough accounts have signed the transaction
+    #[msg(
+    "At least {} accounts must sign the transaction but only {} have signed",
+    required,
+    actual
+    )]
+    NotEnoughSigners { required: u8, actual: usize },
+
+    /// The account was modified after a checkpoint of it was taken
+    #[msg("The account {} was modified after a checkpoint of it was taken", address)]
+    AccountModifiedAfterCheckpoint { address: Pubkey },
+
+    /// The account is duplicated in a list that requires unique accounts
+    #[msg("The account {} is duplicated in '{}'", address, account)]
+    DuplicatedAccountInVec {
+        account: &'static str,
+        address: Pubkey,
+    },
+
+    /// The ProgramData account does not belong to the program
+    #[msg(
+    "The account {} is not the ProgramData account of the program {}",
+    program_data,
+    program
+    )]
+    InvalidProgramDataAccount { program: Pubkey, program_data: Pubkey },
+
+    /// The upgrade authority of the program does not match the expected one
+    #[msg(
+    "The upgrade authority stored in {} does not match the expected one",
+    program_data
+    )]
+    UpgradeAuthorityMismatch { program_data: Pubkey },
+
+    /// The destination of the rent of a closed account is not valid
+    #[msg(
+    "The rent destination of {} must be a writable system account or the configured treasury: {}",
+    account,
+    destination
+    )]
+    InvalidRentDestination { account: &'static str, destination: Pubkey },
+
+    /// The account referenced by a FnkAccountRef is not in the instruction
+    #[msg("The referenced {} account {} is not in the instruction", account_type, address)]
+    ReferencedAccountNotFound {
+        address: Pubkey,
+        account_type: &'static str,
+    },
+
+    /// A Vec account field contains more accounts than its maximum
+    #[msg(
+    "The field {} contains more accounts ({}) than its maximum ({})",
+    field,
+    actual,
+    max
+    )]
+    VecAccountsTooLarge {
+        field: &'static str,
+        max: usize,
+        actual: usize,
+    },
+
+    /// The account does not have enough lamports to transfer
+    #[msg(
+    "The account {} does not have enough lamports ({}) to transfer {}",
+    address,
+    balance,
+    amount
+    )]
+    InsufficientLamports {
+        address: Pubkey,
+        balance: u64,
+        amount: u64,
+    },
+
+    /// The account of an AccountMetaEntry is not in the instruction
+    #[msg("The account {} of the meta entry is not in the instruction", address)]
+    AccountMetaEntryNotFound { address: Pubkey },
+
+    /// The account of an AccountMetaEntry lacks the signer or writable privilege of the entry
+    #[msg(
+    "The account {} lacks the signer or writable privilege of its meta entry",
+    address
+    )]
+    AccountMetaEntryMissingPrivileges { address: Pubkey },
+
+    /// A field of an account builder has not been set
+    #[msg("The field '{}' of the {} builder has not been set", field, account)]
+    AccountBuilderMissingField {
+        account: &'static str,
+        field: &'static str,
+    },
+
+    /// A field of an account builder has been set with a value out of its bounds
+    #[msg(
+    "The field '{}' of the {} builder has been set with an invalid value",
+    field,
+    account
+    )]
+    AccountBuilderInvalidField {
+        account: &'static str,
+        field: &'static str,
+    },
+
+    /// The rest accounts cannot be split in chunks of the requested size
+    #[msg(
+    "The {} rest accounts cannot be split in chunks of {}",
+    actual,
+    chunk_size
+    )]
+    RestAccountsNotDivisible { chunk_size: usize, actual: usize },
+
+    /// The token account of a token gate is not among the accounts of the instruction
+    #[msg(
+    "The token account {} required by the token gate of '{}' is missing",
+    token_account,
+    account
+    )]
+    TokenGateAccountMissing {
+        account: &'static str,
+        token_account: Pubkey,
+    },
+
+    /// The holder of a token gate does not hold enough tokens
+    #[msg(
+    "The account '{}' must hold at least {} tokens to pass the token gate but it holds {}",
+    account,
+    min_amount,
+    amount
+    )]
+    TokenGateNotMet {
+        account: &'static str,
+        amount: u64,
+        min_amount: u64,
+    },
+
+    /// The funders of the rent of an account do not have enough lamports
+    #[msg(
+    "The funders of the account {} can only cover {} of the {} lamports of its rent",
+    address,
+    funded,
+    required
+    )]
+    InsufficientRentFunding {
+        address: Pubkey,
+        required: u64,
+        funded: u64,
+    },
+
+    /// The index of a PDA collection item has not been assigned yet
+    #[msg(
+    "The item {} of the collection does not exist, the next index is {}",
+    index,
+    next_index
+    )]
+    CollectionIndexOutOfBounds { index: u64, next_index: u64 },
+
     // ------------------------------------------------------------------------
     // CPI --------------------------------------------------------------------
     // ------------------------------------------------------------------------
@@ -318,6 +562,18 @@ pub enum FankorErrorCode {
     )]
     TooManyAccounts { size: usize },
 
+    /// An extra account meta of a transfer hook is invalid
+    #[msg("An extra account meta of a transfer hook is invalid")]
+    TransferHookInvalidExtraAccountMeta,
+
+    /// An extra account required by a transfer hook is missing
+    #[msg("The extra account {} required by the transfer hook is missing", address)]
+    TransferHookMissingExtraAccount { address: Pubkey },
+
+    /// The program is not in the CPI allowlist of the current program
+    #[msg("The program {} is not in the CPI allowlist", program)]
+    CpiProgramNotAllowed { program: Pubkey },
+
     // ------------------------------------------------------------------------
     // Zero Copy --------------------------------------------------------------
     // ------------------------------------------------------------------------
@@ -352,6 +608,111 @@ pub enum FankorErrorCode {
     /// Cannot move the specified bytes.
     #[msg("Cannot move the specified bytes")]
     ZeroCopyInvalidMove,
+
+    /// The data of the account is already borrowed in an incompatible way,
+    /// e.g. modifying a zero copy value while iterating over it.
+    #[msg(
+    "Cannot execute '{}' on the zero copy type '{}' because its data is already borrowed",
+    operation,
+    type_name
+    )]
+    AlreadyBorrowed {
+        type_name: &'static str,
+        operation: &'static str,
+    },
+
+    /// Two planned zero copy writes modify the same bytes.
+    #[msg("Two planned zero copy writes overlap at offset {}", offset)]
+    ZeroCopyOverlappingWrites { offset: usize },
+
+    /// A zero copy write was planned for a different account than the planner's one.
+    #[msg(
+    "Cannot plan a zero copy write of account {} in the planner of another account",
+    address
+    )]
+    ZeroCopyWritePlannerAccountMismatch { address: Pubkey },
+
+    /// The size of an element does not match the fixed size of the slots of the
+    /// zero copy type.
+    #[msg(
+    "The element of the zero copy type '{}' must have a size of {} bytes but it is {}",
+    type_name,
+    expected,
+    actual
+    )]
+    ZeroCopyInvalidElementSize {
+        type_name: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+
+    // ------------------------------------------------------------------------
+    // Client -----------------------------------------------------------------
+    // ------------------------------------------------------------------------
+    /// The transaction cannot be decoded
+    #[msg("The transaction cannot be decoded")]
+    #[discriminant = 3000]
+    TransactionDidNotDecode,
+
+    // ------------------------------------------------------------------------
+    // Oracles ----------------------------------------------------------------
+    // ------------------------------------------------------------------------
+    /// The oracle price is not available, e.g. the market is halted
+    #[msg("The oracle price is not available")]
+    #[discriminant = 3500]
+    OraclePriceUnavailable,
+
+    /// The oracle price is older than the maximum age
+    #[msg("The oracle price is {} seconds old but the maximum age is {}", age, max_age)]
+    OraclePriceStale { age: u64, max_age: u64 },
+
+    /// The confidence interval of the oracle price is too wide
+    #[msg(
+    "The confidence interval of the oracle price is {} but the maximum is {}",
+    confidence,
+    max_confidence
+    )]
+    OraclePriceConfidenceTooWide { confidence: u64, max_confidence: u64 },
+
+    // ------------------------------------------------------------------------
+    // Staking ----------------------------------------------------------------
+    // ------------------------------------------------------------------------
+    /// The balances of the stake pool are not updated for the current epoch
+    #[msg(
+    "The stake pool was last updated in epoch {} but the current epoch is {}",
+    last_update_epoch,
+    epoch
+    )]
+    #[discriminant = 4000]
+    StakePoolNotUpdated { last_update_epoch: u64, epoch: u64 },
+
+    /// The last vote of the vote account is older than the maximum age
+    #[msg(
+    "The last vote of the vote account is {} slots old but the maximum age is {}",
+    age,
+    max_age
+    )]
+    VoteAccountStale { age: u64, max_age: u64 },
+
+    // ------------------------------------------------------------------------
+    // Fees -------------------------------------------------------------------
+    // ------------------------------------------------------------------------
+    /// The recipient of the fee is not the one of the fee config
+    #[msg("The fee recipient must be {} but it is {}", expected, actual)]
+    #[discriminant = 4500]
+    FeeRecipientMismatch { expected: Pubkey, actual: Pubkey },
+
+    /// The token accounts of the fee do not belong to the fee mint
+    #[msg("The fee must be paid in mint {} but it is {}", expected, actual)]
+    FeeMintMismatch { expected: Pubkey, actual: Pubkey },
+
+    // ------------------------------------------------------------------------
+    // Idempotency ------------------------------------------------------------
+    // ------------------------------------------------------------------------
+    /// The nonce of an idempotent instruction has already been used
+    #[msg("The instruction with nonce {} has already been processed", nonce)]
+    #[discriminant = 5000]
+    InstructionReplayed { nonce: u64 },
 }
 
 // ----------------------------------------------------------------------------
@@ -460,6 +821,37 @@ impl Eq for FankorError {}
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Logs the account and the expression of a failed `constraint = ...` so that
+/// custom errors can be traced back to their check. It is a no-op with the
+/// `strip-constraint-messages` feature.
+#[inline(always)]
+#[allow(unused_variables)]
+pub fn log_constraint_failure(account: &str, constraint: &str) {
+    #[cfg(not(feature = "strip-constraint-messages"))]
+    msg!(
+        "Constraint failed. Account: {}. Constraint: {}.",
+        account,
+        constraint
+    );
+}
+
+/// The expression of a constraint to include in
+/// [AccountConstraintFailed](FankorErrorCode::AccountConstraintFailed), which is
+/// empty with the `strip-constraint-messages` feature.
+#[inline(always)]
+#[allow(unused_variables)]
+pub const fn constraint_message(constraint: &'static str) -> &'static str {
+    #[cfg(not(feature = "strip-constraint-messages"))]
+    return constraint;
+
+    #[cfg(feature = "strip-constraint-messages")]
+    return "";
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 impl From<Error> for ProgramError {
     fn from(e: Error) -> ProgramError {
         match e {
@@ -0,0 +1,301 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+
+use crate::cpi;
+use crate::cpi::system_program::CpiTransfer;
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::Bps;
+use crate::models::{FankorContext, Program, System};
+#[cfg(feature = "token-program")]
+use crate::models::{Account, Mint, Token, TokenAccount};
+use crate::utils::rent::create_rent_exempt_account;
+
+/// The seed of the PDA that stores the [FeeConfig] of a program.
+pub const FEE_CONFIG_SEED: &[u8] = b"__fee_config";
+
+/// The discriminant written before the [FeeConfig] in its PDA. It is
+/// reserved, so no account type can use it and the config cannot be read as
+/// another account of the program.
+pub const FEE_CONFIG_DISCRIMINANT: u8 = u8::MAX - 1;
+
+/// The fee charged by the instructions declared with
+/// `#[instruction(collect_fee = ...)]`, stored in a PDA of the program.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FeeConfig {
+    /// The portion of the base amount of the instruction charged as fee.
+    pub bps: Bps,
+
+    /// The owner of the accounts that receive the fees, i.e. the wallet for
+    /// lamport fees and the owner of the token account for token fees.
+    pub recipient: Pubkey,
+}
+
+impl FeeConfig {
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Reads the fee config of the current program from `info`, failing if
+    /// it is not the fee config PDA.
+    pub fn load<'info>(
+        context: &FankorContext<'info>,
+        info: &AccountInfo<'info>,
+    ) -> FankorResult<FeeConfig> {
+        let (address, _) = find_fee_config_address(context.program_id());
+        if info.key != &address {
+            return Err(FankorErrorCode::InvalidPda {
+                expected: address,
+                actual: *info.key,
+            }
+            .into());
+        }
+
+        if info.owner != context.program_id() {
+            return Err(FankorErrorCode::AccountOwnedByWrongProgram {
+                address: *info.key,
+                expected: *context.program_id(),
+                actual: *info.owner,
+            }
+            .into());
+        }
+
+        let data = info.try_borrow_data()?;
+        if data.first() != Some(&FEE_CONFIG_DISCRIMINANT) {
+            return Err(FankorErrorCode::AccountDiscriminantMismatch {
+                account: "FeeConfig".to_string(),
+            }
+            .into());
+        }
+
+        let mut data: &[u8] = &data[1..];
+        Ok(FeeConfig::deserialize(&mut data)?)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Computes the fee of `amount` rounding up so it is never undercharged.
+    pub fn compute_fee(&self, amount: u64) -> u64 {
+        self.bps.apply_to_ceil(amount)
+    }
+
+    /// Fails if `recipient` is not the recipient of the fees.
+    pub fn check_recipient(&self, recipient: &Pubkey) -> FankorResult<()> {
+        if recipient != &self.recipient {
+            return Err(FankorErrorCode::FeeRecipientMismatch {
+                expected: self.recipient,
+                actual: *recipient,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Gets the address of the account that stores the [FeeConfig] of
+/// `program_id`.
+pub fn find_fee_config_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_CONFIG_SEED], program_id)
+}
+
+/// Writes `config` into the fee config PDA of the current program, creating
+/// it if needed. Checking who is allowed to change the fees is up to the
+/// instruction that calls it.
+pub fn set_fee_config<'info>(
+    context: &FankorContext<'info>,
+    fee_config: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<System>,
+    config: &FeeConfig,
+) -> FankorResult<()> {
+    let (address, bump) = find_fee_config_address(context.program_id());
+    if fee_config.key != &address {
+        return Err(FankorErrorCode::InvalidPda {
+            expected: address,
+            actual: *fee_config.key,
+        }
+        .into());
+    }
+
+    if !fee_config.is_writable {
+        return Err(FankorErrorCode::AccountConstraintNotWritable {
+            account: "fee_config",
+        }
+        .into());
+    }
+
+    let mut data = vec![FEE_CONFIG_DISCRIMINANT];
+    config.serialize(&mut data)?;
+
+    // The PDA can hold lamports before being created.
+    if fee_config.owner == system_program.address() {
+        create_rent_exempt_account(
+            data.len(),
+            context.program_id(),
+            &[&[FEE_CONFIG_SEED, &[bump]]],
+            payer,
+            fee_config,
+            system_program,
+        )?;
+    } else if fee_config.owner != context.program_id() {
+        return Err(FankorErrorCode::AccountConstraintOwnerMismatch {
+            actual: *fee_config.owner,
+            expected: *context.program_id(),
+            account: "fee_config",
+        }
+        .into());
+    } else if fee_config.try_borrow_data()?.first() != Some(&FEE_CONFIG_DISCRIMINANT) {
+        return Err(FankorErrorCode::AccountDiscriminantMismatch {
+            account: "FeeConfig".to_string(),
+        }
+        .into());
+    }
+
+    fee_config.try_borrow_mut_data()?.copy_from_slice(&data);
+
+    Ok(())
+}
+
+/// Transfers the fee of `amount` in lamports from `payer` to `recipient`,
+/// which must be the recipient of `config`. Returns the collected fee.
+pub fn collect_lamports_fee<'info>(
+    config: &FeeConfig,
+    payer: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    system_program: &Program<System>,
+    amount: u64,
+) -> FankorResult<u64> {
+    config.check_recipient(recipient.key)?;
+
+    let fee = config.compute_fee(amount);
+    if fee == 0 {
+        return Ok(0);
+    }
+
+    cpi::system_program::transfer(
+        system_program,
+        CpiTransfer {
+            from: payer.clone(),
+            to: recipient.clone(),
+        },
+        fee,
+        &[],
+    )?;
+
+    Ok(fee)
+}
+
+/// Transfers the fee of `amount` in `mint` tokens from `source` to
+/// `recipient`, which must be owned by the recipient of `config`. Returns the
+/// collected fee.
+#[cfg(feature = "token-program")]
+pub fn collect_token_fee<'info>(
+    config: &FeeConfig,
+    source: &Account<'info, TokenAccount>,
+    recipient: &Account<'info, TokenAccount>,
+    mint: &Account<'info, Mint>,
+    authority: &AccountInfo<'info>,
+    token_program: &Program<Token>,
+    amount: u64,
+) -> FankorResult<u64> {
+    config.check_recipient(&recipient.data().owner)?;
+
+    for token_account in [source, recipient] {
+        if &token_account.data().mint != mint.address() {
+            return Err(FankorErrorCode::FeeMintMismatch {
+                expected: *mint.address(),
+                actual: token_account.data().mint,
+            }
+            .into());
+        }
+    }
+
+    let fee = config.compute_fee(amount);
+    if fee == 0 {
+        return Ok(0);
+    }
+
+    cpi::token::transfer_checked(
+        token_program,
+        cpi::token::CpiTransferChecked {
+            from: source.info().clone(),
+            to: recipient.info().clone(),
+            mint: mint.info().clone(),
+            authority: authority.clone(),
+        },
+        fee,
+        mint.data().decimals,
+        &[],
+    )?;
+
+    Ok(fee)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[test]
+    fn test_compute_fee() {
+        let config = FeeConfig {
+            bps: Bps::new(30).unwrap(),
+            recipient: Pubkey::new_unique(),
+        };
+
+        assert_eq!(config.compute_fee(0), 0);
+        assert_eq!(config.compute_fee(10_000), 30);
+        assert_eq!(config.compute_fee(10_001), 31);
+        assert_eq!(config.compute_fee(1), 1);
+
+        assert!(config.check_recipient(&config.recipient).is_ok());
+        assert!(config.check_recipient(&Pubkey::default()).is_err());
+
+        let bytes = config.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), 34);
+        assert_eq!(FeeConfig::try_from_slice(&bytes).unwrap(), config);
+    }
+
+    #[test]
+    fn test_load() {
+        let config = FeeConfig {
+            bps: Bps::new(30).unwrap(),
+            recipient: Pubkey::new_unique(),
+        };
+        let (address, _) = find_fee_config_address(&PROGRAM_ID);
+
+        let mut data = vec![FEE_CONFIG_DISCRIMINANT];
+        config.serialize(&mut data).unwrap();
+
+        let lamports = Box::leak(Box::new(1));
+        let data = Box::leak(data.into_boxed_slice());
+        let mut info = create_account_info_for_tests(lamports, data);
+        info.key = Box::leak(Box::new(address));
+        info.owner = &PROGRAM_ID;
+
+        let infos = Box::leak(Box::new([info]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(&PROGRAM_ID, infos)));
+
+        assert_eq!(FeeConfig::load(context, &infos[0]).unwrap(), config);
+
+        // Data without the discriminant is not a fee config.
+        infos[0].try_borrow_mut_data().unwrap()[0] = 30;
+        assert_eq!(
+            FeeConfig::load(context, &infos[0]),
+            Err(FankorErrorCode::AccountDiscriminantMismatch {
+                account: "FeeConfig".to_string(),
+            }
+            .into())
+        );
+    }
+}
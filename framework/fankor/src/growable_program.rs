@@ -0,0 +1,186 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::AccountMeta;
+use solana_program::msg;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::{FankorContext, GrowableAccount, Program, System, UncheckedAccount};
+use crate::traits::{
+    AccountInfoVerification, AccountType, CpiInstruction, Instruction, LpiInstruction,
+};
+
+/// The discriminant of the [GrowInstruction] in the programs that enable it
+/// with `#[program(growable = <type>)]`. It cannot be used by any other
+/// instruction of those programs.
+pub const GROW_INSTRUCTION_DISCRIMINANT: u8 = u8::MAX;
+
+/// Maintenance instruction that grows a [GrowableAccount] of type `T` one step
+/// towards its target size. It must be called repeatedly until the account is
+/// fully grown.
+pub struct GrowInstruction<'info, T: AccountType> {
+    /// The account to grow.
+    pub account: GrowableAccount<'info, T>,
+
+    /// The account that pays the rent of the new space.
+    pub payer: UncheckedAccount<'info>,
+
+    /// System program to call their instructions.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info, T: AccountType> GrowInstruction<'info, T> {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn processor(self, _context: FankorContext<'info>) -> FankorResult<()> {
+        let fully_grown = self
+            .account
+            .grow_step(self.payer.info(), &self.system_program)?;
+
+        msg!(
+            "Grow Instruction: {}/{} bytes",
+            self.account.current_size(),
+            self.account.target_size()
+        );
+
+        if fully_grown {
+            msg!("Grow Instruction: fully grown");
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info, T: AccountType> Instruction<'info> for GrowInstruction<'info, T> {
+    type CPI = CpiGrowInstruction;
+    type LPI = LpiGrowInstruction<'info>;
+
+    fn try_from(
+        context: &'info FankorContext<'info>,
+        buf: &mut &[u8],
+        accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        let account =
+            <GrowableAccount<'info, T> as Instruction>::try_from(context, buf, accounts)?;
+        let payer = <UncheckedAccount<'info> as Instruction>::try_from(context, buf, accounts)?;
+        let system_program =
+            <Program<'info, System> as Instruction>::try_from(context, buf, accounts)?;
+
+        let result = Self {
+            account,
+            payer,
+            system_program,
+        };
+
+        result.validate(context)?;
+
+        Ok(result)
+    }
+}
+
+impl<'info, T: AccountType> GrowInstruction<'info, T> {
+    fn validate(&self, _context: &'info FankorContext<'info>) -> FankorResult<()> {
+        let mut verification_config = AccountInfoVerification::default();
+        let mut closure = |info: &AccountInfo<'info>| {
+            if !info.is_writable {
+                return Err(
+                    FankorErrorCode::AccountConstraintNotWritable { account: "account" }.into(),
+                );
+            }
+
+            Ok(())
+        };
+        verification_config.account_info = Some(&mut closure);
+        self.account
+            .verify_account_infos(&mut verification_config)?;
+
+        let mut verification_config = AccountInfoVerification::default();
+        let mut closure = |info: &AccountInfo<'info>| {
+            if !info.is_writable {
+                return Err(
+                    FankorErrorCode::AccountConstraintNotWritable { account: "payer" }.into(),
+                );
+            }
+
+            if !info.is_signer {
+                return Err(
+                    FankorErrorCode::AccountConstraintNotSigner { account: "payer" }.into(),
+                );
+            }
+
+            Ok(())
+        };
+        verification_config.account_info = Some(&mut closure);
+        self.payer.verify_account_infos(&mut verification_config)?;
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+pub struct CpiGrowInstruction {}
+
+impl<'info> CpiInstruction<'info> for CpiGrowInstruction {
+    fn serialize_into_instruction_parts<W: std::io::Write>(
+        &self,
+        _writer: &mut W,
+        _metas: &mut Vec<AccountMeta>,
+        _infos: &mut Vec<AccountInfo<'info>>,
+    ) -> FankorResult<()> {
+        unreachable!("CpiGrowInstruction should never be used")
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+pub struct LpiGrowInstruction<'info> {
+    /// The address of the [GrowableAccount].
+    pub account: Pubkey,
+    pub payer: <UncheckedAccount<'info> as Instruction<'info>>::LPI,
+    pub system_program: <Program<'info, System> as Instruction<'info>>::LPI,
+}
+
+impl<'info> LpiInstruction for LpiGrowInstruction<'info> {
+    fn serialize_into_instruction_parts<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        metas: &mut Vec<AccountMeta>,
+    ) -> FankorResult<()> {
+        LpiInstruction::serialize_into_instruction_parts(&self.account, writer, metas)?;
+        let mut meta = metas.last_mut().unwrap();
+        meta.is_writable = true;
+
+        LpiInstruction::serialize_into_instruction_parts(&self.payer, writer, metas)?;
+        meta = metas.last_mut().unwrap();
+        meta.is_writable = true;
+        meta.is_signer = true;
+
+        LpiInstruction::serialize_into_instruction_parts(&self.system_program, writer, metas)?;
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Creates a new grow instruction.
+pub fn create_grow_instruction<'info>(
+    accounts: LpiGrowInstruction<'info>,
+    program_id: &Pubkey,
+) -> FankorResult<solana_program::instruction::Instruction> {
+    let mut data = vec![GROW_INSTRUCTION_DISCRIMINANT];
+    let mut metas = Vec::new();
+    LpiInstruction::serialize_into_instruction_parts(&accounts, &mut data, &mut metas)?;
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: *program_id,
+        accounts: metas,
+        data,
+    })
+}
@@ -0,0 +1,264 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::FnkRingBuffer;
+use crate::models::{FankorContext, Program, System};
+use crate::utils::rent::create_rent_exempt_account;
+
+/// The seed of the PDAs that store the [IdempotencyRecord]s of a program,
+/// followed by the address of their authority.
+pub const IDEMPOTENCY_RECORD_SEED: &[u8] = b"__idempotency";
+
+/// The discriminant written before the [IdempotencyRecord] in its PDA. It is
+/// reserved, so no account type can use it and the record cannot be read as
+/// another account of the program.
+pub const IDEMPOTENCY_RECORD_DISCRIMINANT: u8 = u8::MAX - 2;
+
+/// The number of recent nonces kept in each [IdempotencyRecord], i.e. the
+/// replay window of an authority. A nonce is forgotten once the authority
+/// records as many newer nonces, so it must not be reused until then or
+/// before its transaction expires, whichever comes last.
+pub const IDEMPOTENCY_RECORD_CAPACITY: usize = 64;
+
+/// The recent nonces of the instructions declared with
+/// `#[instruction(idempotent_by = ...)]` signed by an authority, stored in a
+/// PDA of the program per authority so that authorities can neither evict the
+/// nonces of others nor contend for the same account.
+#[derive(Debug, Default, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct IdempotencyRecord {
+    pub nonces: FnkRingBuffer<u64, IDEMPOTENCY_RECORD_CAPACITY>,
+}
+
+impl IdempotencyRecord {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Whether `nonce` is among the recent nonces or not.
+    pub fn contains(&self, nonce: u64) -> bool {
+        self.nonces.iter().any(|(_, v)| *v == nonce)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Records `nonce` forgetting the oldest one if the record is full. Fails
+    /// if it is among the recent nonces.
+    pub fn record(&mut self, nonce: u64) -> FankorResult<()> {
+        if self.contains(nonce) {
+            return Err(FankorErrorCode::InstructionReplayed { nonce }.into());
+        }
+
+        self.nonces.push_overwrite(nonce);
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Gets the address of the account that stores the [IdempotencyRecord] of
+/// `authority` in `program_id`.
+pub fn find_idempotency_record_address(program_id: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[IDEMPOTENCY_RECORD_SEED, authority.as_ref()], program_id)
+}
+
+/// Creates the idempotency record PDA of `authority` in the current program.
+/// It must exist before `authority` calls any idempotent instruction.
+pub fn create_idempotency_record<'info>(
+    context: &FankorContext<'info>,
+    record: &AccountInfo<'info>,
+    authority: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<System>,
+) -> FankorResult<()> {
+    let (address, bump) = find_idempotency_record_address(context.program_id(), authority);
+    if record.key != &address {
+        return Err(FankorErrorCode::InvalidPda {
+            expected: address,
+            actual: *record.key,
+        }
+        .into());
+    }
+
+    if record.owner != system_program.address() {
+        return Err(FankorErrorCode::AccountAlreadyInitialized {
+            address: *record.key,
+        }
+        .into());
+    }
+
+    // The PDA can hold lamports before being created.
+    let mut data = vec![IDEMPOTENCY_RECORD_DISCRIMINANT];
+    IdempotencyRecord::default().serialize(&mut data)?;
+    create_rent_exempt_account(
+        data.len(),
+        context.program_id(),
+        &[&[IDEMPOTENCY_RECORD_SEED, authority.as_ref(), &[bump]]],
+        payer,
+        record,
+        system_program,
+    )?;
+
+    record.try_borrow_mut_data()?.copy_from_slice(&data);
+
+    Ok(())
+}
+
+/// Records `nonce` in the idempotency record of `authority` in the current
+/// program failing if it has been used recently. `authority` must sign.
+pub fn record_nonce<'info>(
+    context: &FankorContext<'info>,
+    record: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    nonce: u64,
+) -> FankorResult<()> {
+    if !authority.is_signer {
+        return Err(FankorErrorCode::AccountConstraintNotSigner {
+            account: "idempotency_scope",
+        }
+        .into());
+    }
+
+    let (address, _) = find_idempotency_record_address(context.program_id(), authority.key);
+    if record.key != &address {
+        return Err(FankorErrorCode::InvalidPda {
+            expected: address,
+            actual: *record.key,
+        }
+        .into());
+    }
+
+    if record.owner != context.program_id() {
+        return Err(FankorErrorCode::AccountOwnedByWrongProgram {
+            address: *record.key,
+            expected: *context.program_id(),
+            actual: *record.owner,
+        }
+        .into());
+    }
+
+    if !record.is_writable {
+        return Err(FankorErrorCode::AccountConstraintNotWritable {
+            account: "idempotency_record",
+        }
+        .into());
+    }
+
+    let mut data = record.try_borrow_mut_data()?;
+    if data.first() != Some(&IDEMPOTENCY_RECORD_DISCRIMINANT) {
+        return Err(FankorErrorCode::AccountDiscriminantMismatch {
+            account: "IdempotencyRecord".to_string(),
+        }
+        .into());
+    }
+
+    let mut buf: &[u8] = &data[1..];
+    let mut idempotency_record = IdempotencyRecord::deserialize(&mut buf)?;
+    idempotency_record.record(nonce)?;
+
+    let mut writer: &mut [u8] = &mut data[1..];
+    idempotency_record.serialize(&mut writer)?;
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[test]
+    fn test_record() {
+        let mut record = IdempotencyRecord::default();
+
+        assert!(record.record(0).is_ok());
+        assert!(record.record(7).is_ok());
+        assert!(record.record(7).is_err());
+        assert!(record.contains(0));
+
+        for nonce in 100..(100 + IDEMPOTENCY_RECORD_CAPACITY as u64) {
+            assert!(record.record(nonce).is_ok());
+        }
+
+        // The oldest nonces are forgotten.
+        assert!(!record.contains(0));
+        assert!(!record.contains(7));
+        assert!(record.record(7).is_ok());
+
+        let bytes = record.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), 8 + 8 * IDEMPOTENCY_RECORD_CAPACITY);
+        assert_eq!(IdempotencyRecord::try_from_slice(&bytes).unwrap(), record);
+    }
+
+    #[test]
+    fn test_record_address() {
+        let program_id = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let (address, bump) = find_idempotency_record_address(&program_id, &authority);
+
+        assert_eq!(
+            Pubkey::create_program_address(
+                &[IDEMPOTENCY_RECORD_SEED, authority.as_ref(), &[bump]],
+                &program_id
+            )
+            .unwrap(),
+            address
+        );
+
+        // Each authority has its own record.
+        let other = find_idempotency_record_address(&program_id, &Pubkey::new_unique());
+        assert_ne!(other.0, address);
+    }
+
+    #[test]
+    fn test_record_nonce() {
+        let authority_key = Pubkey::new_unique();
+        let (address, _) = find_idempotency_record_address(&PROGRAM_ID, &authority_key);
+
+        let mut data = vec![IDEMPOTENCY_RECORD_DISCRIMINANT];
+        IdempotencyRecord::default().serialize(&mut data).unwrap();
+
+        let lamports = Box::leak(Box::new(1));
+        let data = Box::leak(data.into_boxed_slice());
+        let mut record = create_account_info_for_tests(lamports, data);
+        record.key = Box::leak(Box::new(address));
+        record.owner = &PROGRAM_ID;
+        record.is_writable = true;
+
+        let authority_lamports = Box::leak(Box::new(0));
+        let authority_data = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let mut authority = create_account_info_for_tests(authority_lamports, authority_data);
+        authority.key = Box::leak(Box::new(authority_key));
+        authority.is_signer = true;
+
+        let infos = Box::leak(Box::new([record, authority]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(&PROGRAM_ID, infos)));
+
+        record_nonce(context, &infos[0], &infos[1], 5).unwrap();
+        assert!(record_nonce(context, &infos[0], &infos[1], 5).is_err());
+
+        let data = infos[0].try_borrow_data().unwrap();
+        assert_eq!(data[0], IDEMPOTENCY_RECORD_DISCRIMINANT);
+        assert!(IdempotencyRecord::try_from_slice(&data[1..]).unwrap().contains(5));
+        drop(data);
+
+        // Data without the discriminant is not a record.
+        infos[0].try_borrow_mut_data().unwrap()[0] = 1;
+        assert_eq!(
+            record_nonce(context, &infos[0], &infos[1], 6),
+            Err(FankorErrorCode::AccountDiscriminantMismatch {
+                account: "IdempotencyRecord".to_string(),
+            }
+            .into())
+        );
+    }
+}
@@ -1,7 +1,17 @@
+pub mod account_registry;
+#[cfg(feature = "ts-gen")]
+pub mod build;
+#[cfg(feature = "client")]
+pub mod client;
 pub mod cpi;
 pub mod errors;
+pub mod fees;
+pub mod growable_program;
+pub mod idempotency;
+pub mod logs;
 pub mod macros;
 pub mod models;
+pub mod pda_collections;
 pub mod prelude;
 pub mod rpc_errors;
 #[cfg(feature = "testable-program")]
@@ -12,3 +22,4 @@ pub mod traits;
 #[cfg(feature = "ts-gen")]
 pub mod ts_gen;
 mod utils;
+pub mod versioned_program;
@@ -0,0 +1,173 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The tag that starts the structured logs emitted by [fnk_log](crate::fnk_log).
+pub const FNK_LOG_PREFIX: &str = "FnkLog";
+
+/// The most verbose level that is logged by [fnk_log](crate::fnk_log). The
+/// logs above it are stripped at compile time, which is selected with the
+/// `max-log-level-*` features.
+pub const MAX_LOG_LEVEL: LogLevel = max_log_level();
+
+/// The level of a structured log.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Off => "off",
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+impl Display for LogLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LogLevel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(LogLevel::Off),
+            "error" => Ok(LogLevel::Error),
+            "warn" => Ok(LogLevel::Warn),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            "trace" => Ok(LogLevel::Trace),
+            _ => Err(()),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A structured log emitted by [fnk_log](crate::fnk_log) and decoded from the
+/// logs of a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FnkLog<'a> {
+    pub level: LogLevel,
+    pub program: &'a str,
+    pub instruction: Option<&'a str>,
+    pub message: &'a str,
+}
+
+impl<'a> FnkLog<'a> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Decodes a structured log from a log line of a transaction.
+    pub fn from_log(log: &'a str) -> Option<Self> {
+        let log = log.strip_prefix("Program log: ")?;
+        let log = log.strip_prefix(FNK_LOG_PREFIX)?.strip_prefix(' ')?;
+
+        let (header, message) = log.split_once(": ")?;
+        let mut header = header.split(' ');
+        let level = header.next()?.parse().ok()?;
+        let program = header.next()?;
+        let instruction = header.next()?;
+
+        if header.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            level,
+            program,
+            instruction: if instruction == "-" {
+                None
+            } else {
+                Some(instruction)
+            },
+            message,
+        })
+    }
+
+    /// Decodes all the structured logs of a transaction whose level is at
+    /// most `max_level`.
+    pub fn from_logs(logs: &'a [String], max_level: LogLevel) -> Vec<Self> {
+        logs.iter()
+            .filter_map(|v| Self::from_log(v))
+            .filter(|v| v.level <= max_level)
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+const fn max_log_level() -> LogLevel {
+    if cfg!(feature = "max-log-level-off") {
+        LogLevel::Off
+    } else if cfg!(feature = "max-log-level-error") {
+        LogLevel::Error
+    } else if cfg!(feature = "max-log-level-warn") {
+        LogLevel::Warn
+    } else if cfg!(feature = "max-log-level-info") {
+        LogLevel::Info
+    } else if cfg!(feature = "max-log-level-debug") {
+        LogLevel::Debug
+    } else {
+        LogLevel::Trace
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_log() {
+        let log = FnkLog::from_log("Program log: FnkLog warn my_program Deposit: Low: 5").unwrap();
+        assert_eq!(log.level, LogLevel::Warn);
+        assert_eq!(log.program, "my_program");
+        assert_eq!(log.instruction, Some("Deposit"));
+        assert_eq!(log.message, "Low: 5");
+
+        let log = FnkLog::from_log("Program log: FnkLog info my_program -: Message").unwrap();
+        assert_eq!(log.instruction, None);
+
+        assert!(FnkLog::from_log("Program log: Message").is_none());
+        assert!(FnkLog::from_log("Program log: FnkLog unknown my_program -: Message").is_none());
+        assert!(FnkLog::from_log("Program log: FnkLog info my_program: Message").is_none());
+    }
+
+    #[test]
+    fn test_from_logs() {
+        let logs = [
+            "Program log: FnkLog error my_program -: A".to_string(),
+            "Program log: FnkLog debug my_program -: B".to_string(),
+            "Program log: other".to_string(),
+            "Program log: FnkLog info my_program -: C".to_string(),
+        ];
+
+        let messages = FnkLog::from_logs(&logs, LogLevel::Info)
+            .into_iter()
+            .map(|v| v.message)
+            .collect::<Vec<_>>();
+        assert_eq!(messages, vec!["A", "C"]);
+    }
+}
@@ -29,15 +29,260 @@ macro_rules! require_not {
     };
 }
 
-/// Empty macro to not include the content if the feature is enabled.
-#[cfg(feature = "no-entrypoint")]
+/// Logs the address, owner, lamports and data length of the accounts.
+///
+/// - `dump_accounts!(accounts => field, ...)` logs the specified fields of the
+///   instruction accounts, named after the fields.
+/// - `dump_accounts!(name = account, ...)` logs the specified accounts with their names.
+#[macro_export]
+macro_rules! dump_accounts {
+    ($($name:ident = $account:expr),+ $(,)?) => {
+        $(
+            let info = $crate::traits::SingleInstructionAccount::info(&$account);
+            $crate::prelude::msg!(
+                "{}: {} | owner: {} | lamports: {} | data: {} bytes | signer: {} | writable: {}",
+                stringify!($name),
+                info.key,
+                info.owner,
+                info.lamports(),
+                info.data_len(),
+                info.is_signer,
+                info.is_writable
+            );
+        )+
+    };
+    ($accounts:expr => $($field:ident),+ $(,)?) => {
+        $crate::dump_accounts!($($field = $accounts.$field),+)
+    };
+}
+
+/// Declares a program that is not built into the framework so that it can be
+/// used in `Program<T>` accounts, which verify its address.
+///
+/// ```ignore
+/// declare_external_program!(MyDex, "DEX1111111111111111111111111111111111111111");
+///
+/// #[instruction]
+/// pub struct Swap<'info> {
+///     pub dex: Program<'info, MyDex>,
+/// }
+/// ```
+#[macro_export]
+macro_rules! declare_external_program {
+    ($name:ident, $address:literal $(,)?) => {
+        #[derive(Debug, Copy, Clone)]
+        pub struct $name;
+
+        impl $name {
+            /// The address of the program.
+            pub const ID: $crate::prelude::Pubkey = $crate::prelude::const_pubkey!($address);
+        }
+
+        impl $crate::traits::ProgramType for $name {
+            fn name() -> &'static str {
+                stringify!($name)
+            }
+
+            fn address() -> &'static $crate::prelude::Pubkey {
+                &Self::ID
+            }
+        }
+    };
+}
+
+/// Declares the programs that can be invoked through generic CPIs, i.e.
+/// [cpi::raw](crate::cpi::raw) and
+/// [invoke_signed_with_pdas](crate::models::FankorContext::invoke_signed_with_pdas),
+/// which fail with `CpiProgramNotAllowed` for any other program. Without it
+/// all programs are allowed.
+///
+/// It must be declared in the root of the crate, next to `setup!`.
+///
+/// ```ignore
+/// cpi_allowlist!(spl_token::ID, MyDex::ID);
+/// ```
+#[macro_export]
+macro_rules! cpi_allowlist {
+    ($($program:expr),* $(,)?) => {
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        pub(crate) const __FANKOR_CPI_ALLOWLIST: Option<&[$crate::prelude::Pubkey]> =
+            Some(&[$($program),*]);
+    };
+}
+
+/// Enables the strict mode, which makes the `Instruction` derive fail for
+/// every `UncheckedAccount` or `AccountInfo` field that does not justify
+/// itself with `#[account(unchecked_reason = "...")]`. The reasons are
+/// included in the IDL.
+///
+/// It must be declared in the root of the crate, next to `setup!`.
+///
+/// ```ignore
+/// strict_mode!();
+/// ```
+#[macro_export]
+macro_rules! strict_mode {
+    () => {
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        pub(crate) const __FANKOR_STRICT_MODE: bool = true;
+    };
+}
+
+/// The strict mode used by programs that do not enable it. It is imported by
+/// the `setup!` macro and shadowed by the [strict_mode](crate::strict_mode)
+/// macro.
+#[doc(hidden)]
+pub mod default_strict_mode {
+    pub const __FANKOR_STRICT_MODE: bool = false;
+}
+
+/// Takes a checkpoint of the owner, lamports and data of the accounts so
+/// that [assert_unchanged] can later verify they were not modified, e.g. by
+/// an untrusted CPI.
+///
+/// ```ignore
+/// let checkpoint = checkpoint!(accounts.config, accounts.vault);
+/// cpi_untrusted_program(...)?;
+/// assert_unchanged!(checkpoint);
+/// ```
+#[cfg(feature = "debug")]
+#[macro_export]
+macro_rules! checkpoint {
+    ($($account:expr),+ $(,)?) => {
+        [$(
+            $crate::models::AccountCheckpoint::new(
+                $crate::traits::SingleInstructionAccount::info(&$account)
+            )?
+        ),+]
+    };
+}
+
+/// Returns with an error if any of the accounts of a [checkpoint] has been
+/// modified since it was taken.
+#[cfg(feature = "debug")]
+#[macro_export]
+macro_rules! assert_unchanged {
+    ($checkpoint:expr) => {
+        for checkpoint in $checkpoint.iter() {
+            checkpoint.verify()?;
+        }
+    };
+}
+
+/// Embeds the security.txt of the program in the binary.
+///
+/// It is excluded when the program crate is compiled with its `no-entrypoint`
+/// or `library` features, so that programs depending on it do not end up with
+/// two `security_txt` symbols.
 #[macro_export]
 macro_rules! security_txt {
-    ($($name:ident: $value:expr),*) => {};
+    ($($name:ident: $value:expr),*) => {
+        #[cfg(not(any(feature = "no-entrypoint", feature = "library")))]
+        $crate::prelude::solana_security_txt::security_txt! {
+            $($name: $value),*
+        }
+    };
+}
+
+/// Logs a structured line tagged with the level, the program and, when the
+/// context is given, the instruction, so that it can be filtered off-chain
+/// with [FnkLog](crate::logs::FnkLog). The levels are `error`, `warn`, `info`,
+/// `debug` and `trace`.
+///
+/// The logs more verbose than [MAX_LOG_LEVEL](crate::logs::MAX_LOG_LEVEL) are
+/// stripped at compile time, e.g. enable the `max-log-level-warn` feature in
+/// mainnet builds to keep only the errors and warnings.
+///
+/// ```ignore
+/// fnk_log!(info, "Deposited {} lamports", amount);
+/// fnk_log!(context, warn, "The vault is almost empty");
+/// ```
+#[macro_export]
+macro_rules! fnk_log {
+    (@level error) => { $crate::logs::LogLevel::Error };
+    (@level warn) => { $crate::logs::LogLevel::Warn };
+    (@level info) => { $crate::logs::LogLevel::Info };
+    (@level debug) => { $crate::logs::LogLevel::Debug };
+    (@level trace) => { $crate::logs::LogLevel::Trace };
+    (@log $instruction:expr, $level:ident, $($arg:tt)+) => {{
+        let level = $crate::fnk_log!(@level $level);
+        if level <= $crate::logs::MAX_LOG_LEVEL {
+            $crate::prelude::msg!(
+                "{} {} {} {}: {}",
+                $crate::logs::FNK_LOG_PREFIX,
+                level,
+                env!("CARGO_PKG_NAME"),
+                $instruction.unwrap_or("-"),
+                format_args!($($arg)+)
+            );
+        }
+    }};
+    ($level:ident, $fmt:literal $(, $arg:expr)* $(,)?) => {
+        $crate::fnk_log!(@log Option::<&str>::None, $level, $fmt $(, $arg)*)
+    };
+    ($context:expr, $level:ident, $fmt:literal $(, $arg:expr)* $(,)?) => {
+        $crate::fnk_log!(@log $context.instruction_name(), $level, $fmt $(, $arg)*)
+    };
+}
+
+/// Builds an [ErasedInstruction](crate::traits::ErasedInstruction) for the
+/// instruction struct `$name` that deserializes it and calls its `processor`
+/// like the dispatch generated by `#[program]`, so that it can be registered
+/// in an [InstructionRegistry](crate::models::InstructionRegistry).
+///
+/// ```ignore
+/// let registry = InstructionRegistry::new()
+///     .with(erased_instruction!(0, Deposit))
+///     .with(erased_instruction!(1, Withdraw));
+///
+/// registry.dispatch(context, data, accounts)?;
+/// ```
+#[cfg(feature = "dynamic-dispatch")]
+#[macro_export]
+macro_rules! erased_instruction {
+    ($discriminant:expr, $name:ident) => {{
+        fn process<'info>(
+            context: &'info $crate::models::FankorContext<'info>,
+            ix_data: &mut &[u8],
+            ix_accounts: &mut &'info [$crate::prelude::AccountInfo<'info>],
+        ) -> $crate::errors::FankorResult<()> {
+            let accounts =
+                <$name<'info> as $crate::traits::Instruction>::try_from(context, ix_data, ix_accounts)?;
+
+            if !ix_accounts.is_empty() {
+                return Err($crate::errors::FankorErrorCode::UnusedAccounts.into());
+            }
+
+            let result = accounts.processor(context.clone())?;
+
+            // Write return data.
+            if $crate::prelude::type_id_of(&result) != $crate::prelude::type_id_of(&()) {
+                $crate::prelude::solana_program::program::set_return_data(
+                    &$crate::prelude::BorshSerialize::try_to_vec(&result).unwrap(),
+                );
+            }
+
+            Ok(())
+        }
+
+        $crate::models::FnInstruction::new(stringify!($name), $discriminant, process)
+    }};
 }
 
+#[cfg(feature = "debug")]
+pub use assert_unchanged;
+#[cfg(feature = "debug")]
+pub use checkpoint;
+pub use cpi_allowlist;
+pub use declare_external_program;
+pub use dump_accounts;
+#[cfg(feature = "dynamic-dispatch")]
+pub use erased_instruction;
+pub use fnk_log;
 pub use panic_error;
 pub use require;
 pub use require_not;
-#[cfg(feature = "no-entrypoint")]
 pub use security_txt;
+pub use strict_mode;
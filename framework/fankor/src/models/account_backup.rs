@@ -0,0 +1,137 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::FankorResult;
+
+/// A copy of the data of an account taken before risky in-place mutations,
+/// e.g. multi-step zero-copy edits, to restore it if they fail midway.
+///
+/// The runtime already reverts the accounts when the instruction fails, so a
+/// backup is only useful when the error is handled and the instruction goes
+/// on. Only the data is backed up: lamports are not restored because they
+/// always move to or from other accounts.
+#[derive(Debug, Clone)]
+pub struct AccountBackup<'info> {
+    info: &'info AccountInfo<'info>,
+    data: Vec<u8>,
+}
+
+impl<'info> AccountBackup<'info> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Copies the current data of the account.
+    pub fn new(info: &'info AccountInfo<'info>) -> FankorResult<AccountBackup<'info>> {
+        Ok(AccountBackup {
+            info,
+            data: info.try_borrow_data()?.to_vec(),
+        })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Runs `f` restoring the data of all `infos` if it fails, so that its
+    /// mutations are applied either completely or not at all.
+    pub fn atomic<T, F>(infos: &[&'info AccountInfo<'info>], f: F) -> FankorResult<T>
+    where
+        F: FnOnce() -> FankorResult<T>,
+    {
+        let backups = infos
+            .iter()
+            .map(|info| AccountBackup::new(info))
+            .collect::<FankorResult<Vec<_>>>()?;
+
+        match f() {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                for backup in &backups {
+                    backup.restore()?;
+                }
+
+                Err(e)
+            }
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Writes back the data of the backup into the account, resizing it if
+    /// its length has changed in the meantime.
+    pub fn restore(&self) -> FankorResult<()> {
+        if self.info.data_len() != self.data.len() {
+            #[cfg(any(feature = "test-utils", test))]
+            if self.info.rent_epoch != crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+                self.info.realloc(self.data.len(), false)?;
+            }
+
+            #[cfg(not(any(feature = "test-utils", test)))]
+            {
+                self.info.realloc(self.data.len(), false)?;
+            }
+        }
+
+        let mut data = self.info.try_borrow_mut_data()?;
+        let length = self.data.len().min(data.len());
+        data[..length].copy_from_slice(&self.data[..length]);
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::errors::FankorErrorCode;
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_restore() {
+        let lamports = Box::leak(Box::new(10));
+        let vector = Box::leak(vec![1, 2, 3].into_boxed_slice());
+        let info: &AccountInfo =
+            Box::leak(Box::new(create_account_info_for_tests(lamports, vector)));
+
+        let backup = AccountBackup::new(info).unwrap();
+        info.try_borrow_mut_data().unwrap()[1] = 5;
+
+        backup.restore().unwrap();
+        assert_eq!(*info.try_borrow_data().unwrap(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_atomic() {
+        let lamports = Box::leak(Box::new(10));
+        let vector = Box::leak(vec![1, 2, 3].into_boxed_slice());
+        let info: &AccountInfo =
+            Box::leak(Box::new(create_account_info_for_tests(lamports, vector)));
+
+        // Failure.
+        let result = AccountBackup::atomic::<(), _>(&[info], || {
+            info.try_borrow_mut_data().unwrap()[0] = 7;
+            Err(FankorErrorCode::AccountNotDefault.into())
+        });
+        assert!(result.is_err());
+        assert_eq!(*info.try_borrow_data().unwrap(), [1, 2, 3]);
+
+        // Success.
+        let result = AccountBackup::atomic(&[info], || {
+            info.try_borrow_mut_data().unwrap()[0] = 7;
+            Ok(5)
+        });
+        assert_eq!(result.unwrap(), 5);
+        assert_eq!(*info.try_borrow_data().unwrap(), [7, 2, 3]);
+    }
+}
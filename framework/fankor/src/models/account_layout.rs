@@ -0,0 +1,70 @@
+/// The static layout of an account field of an instruction, as declared in
+/// its `#[instruction]` struct. Fields that are instruction accounts themselves
+/// are listed as a single entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountLayout {
+    /// The name of the field.
+    pub name: &'static str,
+
+    /// The type of the field as written in the source code.
+    pub type_name: &'static str,
+
+    /// Whether the account must be writable or not. `None` if it is not
+    /// constrained or it depends on runtime values.
+    pub writable: Option<bool>,
+
+    /// Whether the account must be a signer or not. `None` if it is not
+    /// constrained or it depends on runtime values.
+    pub signer: Option<bool>,
+
+    /// Whether the account can be omitted or not.
+    pub optional: bool,
+
+    /// The minimum number of entries of the field.
+    pub min_count: usize,
+
+    /// The maximum number of entries of the field. `None` if unbounded.
+    pub max_count: Option<usize>,
+
+    /// The constraints declared in the `#[account(...)]` attributes of the
+    /// field, e.g. `owner = &crate::ID`.
+    pub constraints: &'static [&'static str],
+
+    /// The seeds of the field if it is a PDA.
+    pub pda: Option<&'static str>,
+
+    /// Why the field is not checked, declared with
+    /// `#[account(unchecked_reason = "...")]` in unchecked accounts.
+    pub unchecked_reason: Option<&'static str>,
+}
+
+impl AccountLayout {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Whether the field can contain a variable number of entries or not.
+    pub fn is_variable(&self) -> bool {
+        self.max_count != Some(self.min_count)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The byte layout of a field of an account, as declared in its `#[account]`
+/// struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountFieldLayout {
+    /// The name of the field.
+    pub name: &'static str,
+
+    /// The type of the field as written in the source code.
+    pub type_name: &'static str,
+
+    /// The byte offset of the field in the account data. `None` if it
+    /// depends on the size of a previous field.
+    pub offset: Option<usize>,
+
+    /// The serialized size of the field. `None` if it is not fixed.
+    pub size: Option<usize>,
+}
@@ -0,0 +1,125 @@
+use solana_program::account_info::AccountInfo;
+
+/// The maximum number of accounts a transaction can lock.
+pub const MAX_TRANSACTION_ACCOUNTS: usize = 64;
+
+/// The maximum size of the data of all accounts loaded by a transaction.
+pub const MAX_LOADED_ACCOUNTS_DATA_SIZE: usize = 64 * 1024 * 1024;
+
+/// The usage of a limit, in percent, from which it is considered to be close
+/// to being reached.
+pub const ACCOUNT_LOAD_WARNING_PERCENT: usize = 80;
+
+/// The number of accounts and the size of their data loaded by an instruction.
+///
+/// The transaction limits also count the accounts of the other instructions
+/// and the programs, so these stats are a lower bound of the actual usage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AccountLoadStats {
+    /// The number of accounts passed to the instruction, including duplicates.
+    pub accounts: usize,
+
+    /// The number of different accounts passed to the instruction.
+    pub unique_accounts: usize,
+
+    /// The size of the data of the different accounts.
+    pub data_size: usize,
+}
+
+impl AccountLoadStats {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Computes the stats of `accounts`. Duplicated accounts are counted only
+    /// once in `unique_accounts` and `data_size`.
+    pub fn from_accounts(accounts: &[AccountInfo]) -> AccountLoadStats {
+        let mut stats = AccountLoadStats {
+            accounts: accounts.len(),
+            ..Default::default()
+        };
+
+        for (index, account) in accounts.iter().enumerate() {
+            if accounts[..index].iter().any(|v| v.key == account.key) {
+                continue;
+            }
+
+            stats.unique_accounts += 1;
+            stats.data_size += account.data_len();
+        }
+
+        stats
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Whether the number of different accounts is close to
+    /// [MAX_TRANSACTION_ACCOUNTS].
+    pub fn is_close_to_account_limit(&self) -> bool {
+        self.unique_accounts * 100 >= MAX_TRANSACTION_ACCOUNTS * ACCOUNT_LOAD_WARNING_PERCENT
+    }
+
+    /// Whether the size of the data is close to [MAX_LOADED_ACCOUNTS_DATA_SIZE].
+    pub fn is_close_to_data_limit(&self) -> bool {
+        self.data_size * 100 >= MAX_LOADED_ACCOUNTS_DATA_SIZE * ACCOUNT_LOAD_WARNING_PERCENT
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_from_accounts() {
+        let lamports_a = Box::leak(Box::new(0));
+        let data_a = Box::leak(vec![0u8; 10].into_boxed_slice());
+        let mut info_a = create_account_info_for_tests(lamports_a, data_a);
+        info_a.key = Box::leak(Box::new(Pubkey::new_unique()));
+
+        let lamports_b = Box::leak(Box::new(0));
+        let data_b = Box::leak(vec![0u8; 5].into_boxed_slice());
+        let mut info_b = create_account_info_for_tests(lamports_b, data_b);
+        info_b.key = Box::leak(Box::new(Pubkey::new_unique()));
+
+        let duplicated = info_a.clone();
+        let stats = AccountLoadStats::from_accounts(&[info_a, info_b, duplicated]);
+
+        assert_eq!(
+            stats,
+            AccountLoadStats {
+                accounts: 3,
+                unique_accounts: 2,
+                data_size: 15,
+            }
+        );
+        assert!(!stats.is_close_to_account_limit());
+        assert!(!stats.is_close_to_data_limit());
+    }
+
+    #[test]
+    fn test_limits() {
+        let stats = AccountLoadStats {
+            accounts: 60,
+            unique_accounts: 52,
+            data_size: 52 * 1024 * 1024,
+        };
+
+        assert!(stats.is_close_to_account_limit());
+        assert!(stats.is_close_to_data_limit());
+
+        let stats = AccountLoadStats {
+            accounts: 60,
+            unique_accounts: 51,
+            data_size: 51 * 1024 * 1024,
+        };
+
+        assert!(!stats.is_close_to_account_limit());
+        assert!(!stats.is_close_to_data_limit());
+    }
+}
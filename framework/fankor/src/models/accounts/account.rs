@@ -1,16 +1,20 @@
+use std::any::type_name;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 use std::io::Write;
 
 use solana_program::account_info::AccountInfo;
 use solana_program::clock::Epoch;
+use solana_program::hash::{hash, Hash};
 use solana_program::pubkey::Pubkey;
 use solana_program::rent::Rent;
 use solana_program::system_program;
 use solana_program::sysvar::Sysvar;
 
 use crate::errors::{Error, FankorErrorCode, FankorResult};
-use crate::models::{FankorContext, FankorContextExitAction, Program, System, ZcAccount};
+use crate::models::{
+    DeferredClose, FankorContext, FankorContextExitAction, Program, System, ZcAccount,
+};
 use crate::prelude::AccountInfoVerification;
 use crate::traits::{AccountType, CopyType, Instruction, PdaChecker, SingleInstructionAccount};
 use crate::utils::close::close_account;
@@ -141,8 +145,63 @@ impl<'info, T: AccountType> Account<'info, T> {
         self.info.owner == self.context.program_id()
     }
 
+    /// The discriminant stored in the account, that can be one of the
+    /// [legacy discriminants](AccountType::legacy_discriminants) of `T` if the
+    /// account has not been rewritten yet.
+    pub fn stored_discriminant(&self) -> FankorResult<u8> {
+        let data = self.info.try_borrow_data()?;
+
+        data.first().copied().ok_or_else(|| {
+            FankorErrorCode::AccountDiscriminantMismatch {
+                account: type_name::<T>().to_string(),
+            }
+            .into()
+        })
+    }
+
+    /// Whether the account is stored with a legacy discriminant of `T`.
+    pub fn has_legacy_discriminant(&self) -> FankorResult<bool> {
+        Ok(self.stored_discriminant()? != T::discriminant())
+    }
+
     // METHODS ----------------------------------------------------------------
 
+    /// The SHA-256 hash of the account data as it is in storage, i.e. without
+    /// the changes not yet saved.
+    pub fn data_hash(&self) -> FankorResult<Hash> {
+        let data = self.info.try_borrow_data()?;
+        Ok(hash(&data))
+    }
+
+    /// Rewrites the stored discriminant to the current one of `T` if the
+    /// account is stored with a legacy discriminant. Saving the account
+    /// rewrites it too.
+    pub fn rewrite_discriminant(&self) -> FankorResult<()> {
+        if !self.has_legacy_discriminant()? {
+            return Ok(());
+        }
+
+        if !self.is_owned_by_program() {
+            return Err(FankorErrorCode::AccountNotOwnedByProgram {
+                address: *self.address(),
+                action: "write",
+            }
+            .into());
+        }
+
+        if !self.is_writable() {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *self.address(),
+                action: "write",
+            }
+            .into());
+        }
+
+        self.info.try_borrow_mut_data()?[0] = T::discriminant();
+
+        Ok(())
+    }
+
     /// Reloads the account from storage. This is useful, for example, when
     /// observing side effects after CPI.
     pub fn reload(&mut self) -> FankorResult<()> {
@@ -200,6 +259,17 @@ impl<'info, T: AccountType> Account<'info, T> {
         Ok(())
     }
 
+    /// Marks the account for close replacing its data with a tombstone. The account
+    /// can only be closed with [`DeferredClose::finalize_close`] once `cooldown` seconds
+    /// have passed, and meanwhile it cannot be used as any account type.
+    pub fn mark_for_close(mut self, cooldown: i64) -> FankorResult<DeferredClose<'info>> {
+        let result = DeferredClose::mark(self.context, self.info, cooldown)?;
+
+        // Prevent account to execute the drop actions.
+        self.dropped = true;
+        Ok(result)
+    }
+
     /// Reallocates the account to the given `size`. If a `payer` is provided,
     /// fankor will add funds to the account to make it rent-exempt.
     ///
@@ -577,6 +647,10 @@ impl<'info, T: AccountType> Instruction<'info> for Account<'info, T> {
             .into());
         }
 
+        if DeferredClose::is_tombstoned(info, context.program_id()) {
+            return Err(FankorErrorCode::AccountTombstoned { address: *info.key }.into());
+        }
+
         let mut data: &[u8] = &info.try_borrow_data()?;
         let result = Account::new_unchecked(context, info, T::deserialize(&mut data)?);
 
@@ -601,6 +675,17 @@ impl<'info, T: AccountType> PdaChecker<'info> for Account<'info, T> {
     }
 }
 
+#[cfg(any(test, feature = "test-utils"))]
+impl<'info, T: AccountType + Debug> Account<'info, T> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Renders the field-level differences between a previous snapshot of the
+    /// data and the current one.
+    pub fn debug_diff_from(&self, snapshot: &T) -> String {
+        crate::tests::FankorDebug::debug_diff(snapshot, self.data())
+    }
+}
+
 impl<'info, T: AccountType> Debug for Account<'info, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("Account").field("info", &self.info).finish()
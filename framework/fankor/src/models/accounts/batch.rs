@@ -0,0 +1,288 @@
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::io::Write;
+use std::ops::Deref;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::AccountMeta;
+use solana_program::msg;
+
+use crate::errors::{Error, FankorErrorCode, FankorResult};
+use crate::models::FankorContext;
+use crate::prelude::{AccountInfoVerification, LpiInstruction};
+use crate::traits::{CpiInstruction, Instruction, PdaChecker};
+
+/// The default maximum number of operations of a [Batch].
+pub const DEFAULT_BATCH_MAX_OPERATIONS: usize = 16;
+
+/// An instruction argument that contains a list of at most `N` operations
+/// that are executed against the same accounts of the instruction.
+pub struct Batch<T, const N: usize = DEFAULT_BATCH_MAX_OPERATIONS>(Vec<T>);
+
+impl<T, const N: usize> Batch<T, N> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new batch with the given operations.
+    pub fn new(operations: Vec<T>) -> FankorResult<Batch<T, N>> {
+        check_batch_len::<N>(operations.len())?;
+
+        Ok(Self(operations))
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn operations(&self) -> &[T] {
+        &self.0
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Returns the operations.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Executes `f` for every operation in order stopping at the first error,
+    /// which is returned after logging the index of the failing operation.
+    pub fn execute<F>(&self, mut f: F) -> FankorResult<()>
+    where
+        F: FnMut(usize, &T) -> FankorResult<()>,
+    {
+        for (index, operation) in self.0.iter().enumerate() {
+            if let Err(e) = f(index, operation) {
+                msg!("Batch operation {} failed", index);
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Executes `f` for every operation in order without stopping at errors,
+    /// reporting which operations failed.
+    ///
+    /// Note that the changes made by a failed operation before failing are
+    /// not reverted.
+    pub fn execute_all<F>(&self, mut f: F) -> BatchReport
+    where
+        F: FnMut(usize, &T) -> FankorResult<()>,
+    {
+        let mut report = BatchReport::default();
+
+        for (index, operation) in self.0.iter().enumerate() {
+            match f(index, operation) {
+                Ok(()) => report.succeeded += 1,
+                Err(e) => {
+                    msg!("Batch operation {} failed", index);
+                    e.log();
+
+                    report.failed.push(BatchFailure {
+                        index: index as u32,
+                        error_code: error_code_of(&e),
+                    });
+                }
+            }
+        }
+
+        report
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for Batch<T, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> Deref for Batch<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'info, T: BorshSerialize + BorshDeserialize, const N: usize> Instruction<'info>
+    for Batch<T, N>
+{
+    type CPI = Batch<T, N>;
+    type LPI = Batch<T, N>;
+
+    fn verify_account_infos<'a>(
+        &self,
+        _config: &mut AccountInfoVerification<'a, 'info>,
+    ) -> FankorResult<()> {
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn try_from(
+        _context: &'info FankorContext<'info>,
+        buf: &mut &[u8],
+        _accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        // Check the length before deserializing any operation.
+        let len = u32::deserialize(buf)? as usize;
+        check_batch_len::<N>(len)?;
+
+        let mut operations = Vec::with_capacity(len);
+        for _ in 0..len {
+            operations.push(T::deserialize(buf)?);
+        }
+
+        Ok(Self(operations))
+    }
+}
+
+impl<'info, T: BorshSerialize, const N: usize> CpiInstruction<'info> for Batch<T, N> {
+    fn serialize_into_instruction_parts<W: Write>(
+        &self,
+        writer: &mut W,
+        _metas: &mut Vec<AccountMeta>,
+        _infos: &mut Vec<AccountInfo<'info>>,
+    ) -> FankorResult<()> {
+        BorshSerialize::serialize(&self.0, writer)?;
+
+        Ok(())
+    }
+}
+
+impl<T: BorshSerialize, const N: usize> LpiInstruction for Batch<T, N> {
+    fn serialize_into_instruction_parts<W: Write>(
+        &self,
+        writer: &mut W,
+        _metas: &mut Vec<AccountMeta>,
+    ) -> FankorResult<()> {
+        BorshSerialize::serialize(&self.0, writer)?;
+
+        Ok(())
+    }
+}
+
+impl<'info, T, const N: usize> PdaChecker<'info> for Batch<T, N> {
+    fn pda_info(&self) -> Option<&'info AccountInfo<'info>> {
+        None
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for Batch<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Batch")
+            .field("max", &N)
+            .field("operations", &self.0)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The result of [Batch::execute_all].
+#[derive(Debug, Default, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct BatchReport {
+    /// The number of operations that succeeded.
+    pub succeeded: u32,
+
+    /// The operations that failed.
+    pub failed: Vec<BatchFailure>,
+}
+
+impl BatchReport {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Whether all operations succeeded.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// An operation of a batch that failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct BatchFailure {
+    /// The position of the operation in the batch.
+    pub index: u32,
+
+    /// The code of the error.
+    pub error_code: u64,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn check_batch_len<const N: usize>(len: usize) -> FankorResult<()> {
+    if len > N {
+        return Err(FankorErrorCode::BatchTooLarge {
+            max: N,
+            actual: len,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+fn error_code_of(error: &Error) -> u64 {
+    match error {
+        Error::FankorError(e) => e.error_code_number as u64,
+        Error::ProgramError(e) => u64::from(e.clone()),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::program_error::ProgramError;
+
+    use super::*;
+
+    #[test]
+    fn test_batch_len() {
+        assert!(Batch::<u8, 2>::new(vec![1, 2]).is_ok());
+        assert!(Batch::<u8, 2>::new(vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_execute() {
+        let batch = Batch::<u8, 4>::new(vec![1, 2, 3, 4]).unwrap();
+        let mut executed = Vec::new();
+
+        let result = batch.execute(|_, v| {
+            if *v == 3 {
+                return Err(ProgramError::InvalidArgument.into());
+            }
+
+            executed.push(*v);
+            Ok(())
+        });
+
+        assert!(result.is_err());
+        assert_eq!(executed, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_execute_all() {
+        let batch = Batch::<u8, 4>::new(vec![1, 2, 3, 4]).unwrap();
+
+        let report = batch.execute_all(|_, v| {
+            if v % 2 == 0 {
+                return Err(FankorErrorCode::BatchTooLarge { max: 0, actual: 0 }.into());
+            }
+
+            Ok(())
+        });
+
+        assert!(!report.is_success());
+        assert_eq!(report.succeeded, 2);
+        assert_eq!(
+            report.failed.iter().map(|v| v.index).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(report.failed[0].error_code, 1017);
+    }
+}
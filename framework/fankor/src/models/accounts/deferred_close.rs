@@ -0,0 +1,326 @@
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::FankorContext;
+use crate::traits::{AccountInfoVerification, Instruction, PdaChecker, SingleInstructionAccount};
+use crate::utils::close::close_account;
+
+/// The discriminant written in the accounts marked for close. It is reserved,
+/// so no account type can use it.
+pub const TOMBSTONE_DISCRIMINANT: u8 = u8::MAX;
+
+/// The offset of the unlock timestamp in a tombstone, i.e. just after the discriminant.
+const UNLOCK_TIMESTAMP_OFFSET: usize = 1;
+
+/// The size of a tombstone: the discriminant followed by the unlock timestamp.
+pub const TOMBSTONE_SIZE: usize = UNLOCK_TIMESTAMP_OFFSET + 8;
+
+/// Wrapper for `AccountInfo` of a program account that has been marked for close
+/// with [`Account::mark_for_close`](crate::models::Account::mark_for_close).
+///
+/// The data of the account is replaced by a tombstone that contains the timestamp
+/// from which the account can be closed. Until then, the account cannot be
+/// deserialized as any account type, which prevents it from being revived
+/// in the meantime.
+pub struct DeferredClose<'info> {
+    context: &'info FankorContext<'info>,
+    info: &'info AccountInfo<'info>,
+}
+
+impl<'info> DeferredClose<'info> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new account with the given data.
+    pub fn new(
+        context: &'info FankorContext<'info>,
+        info: &'info AccountInfo<'info>,
+    ) -> FankorResult<DeferredClose<'info>> {
+        if info.owner != context.program_id() {
+            return Err(FankorErrorCode::AccountOwnedByWrongProgram {
+                address: *info.key,
+                expected: *context.program_id(),
+                actual: *info.owner,
+            }
+            .into());
+        }
+
+        if !Self::is_tombstoned(info, context.program_id()) {
+            return Err(FankorErrorCode::AccountDiscriminantMismatch {
+                account: "DeferredClose".to_string(),
+            }
+            .into());
+        }
+
+        Ok(DeferredClose { context, info })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn address(&self) -> &'info Pubkey {
+        self.info.key
+    }
+
+    pub fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    pub fn context(&self) -> &'info FankorContext<'info> {
+        self.context
+    }
+
+    /// The timestamp from which the account can be closed.
+    pub fn unlock_timestamp(&self) -> i64 {
+        let data = self.info.data.borrow();
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&data[UNLOCK_TIMESTAMP_OFFSET..TOMBSTONE_SIZE]);
+
+        i64::from_le_bytes(bytes)
+    }
+
+    /// Whether the cooldown has finished or not.
+    pub fn is_unlocked(&self) -> FankorResult<bool> {
+        let clock = self.context.clock()?;
+        Ok(clock.unix_timestamp >= self.unlock_timestamp())
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Closes the account and sends the lamports to the `destination_account`.
+    /// Fails if the cooldown has not finished yet.
+    pub fn finalize_close(self, destination_account: &AccountInfo<'info>) -> FankorResult<()> {
+        if !self.is_unlocked()? {
+            return Err(FankorErrorCode::AccountTombstoneLocked {
+                address: *self.info.key,
+                unlock_timestamp: self.unlock_timestamp(),
+            }
+            .into());
+        }
+
+        close_account(self.info, self.context, destination_account)
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Closes `info` sending its lamports to `treasury` if it is a tombstone
+    /// that unlocked at or before `cutoff`, zeroing its data first. Returns
+    /// the reclaimed lamports or `None` if the account was skipped.
+    pub fn sweep(
+        context: &'info FankorContext<'info>,
+        info: &'info AccountInfo<'info>,
+        treasury: &AccountInfo<'info>,
+        cutoff: i64,
+    ) -> FankorResult<Option<u64>> {
+        if info.key == treasury.key || !Self::is_tombstoned(info, context.program_id()) {
+            return Ok(None);
+        }
+
+        let account = DeferredClose::new(context, info)?;
+        let unlock_timestamp = account.unlock_timestamp();
+        if unlock_timestamp > cutoff {
+            return Ok(None);
+        }
+
+        if !account.is_unlocked()? {
+            return Err(FankorErrorCode::AccountTombstoneLocked {
+                address: *info.key,
+                unlock_timestamp,
+            }
+            .into());
+        }
+
+        if !info.is_writable {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *info.key,
+                action: "sweep",
+            }
+            .into());
+        }
+
+        let lamports = info.lamports();
+        info.try_borrow_mut_data()?.fill(0);
+        close_account(info, context, treasury)?;
+
+        Ok(Some(lamports))
+    }
+
+    /// Whether the account is owned by `program_id` and marked for close or not.
+    pub fn is_tombstoned(info: &AccountInfo, program_id: &Pubkey) -> bool {
+        if info.owner != program_id {
+            return false;
+        }
+
+        match info.try_borrow_data() {
+            Ok(data) => data.len() >= TOMBSTONE_SIZE && data[0] == TOMBSTONE_DISCRIMINANT,
+            Err(_) => false,
+        }
+    }
+
+    /// Replaces the data of the account with a tombstone that unlocks after `cooldown` seconds.
+    pub(crate) fn mark(
+        context: &'info FankorContext<'info>,
+        info: &'info AccountInfo<'info>,
+        cooldown: i64,
+    ) -> FankorResult<DeferredClose<'info>> {
+        if info.owner != context.program_id() {
+            return Err(FankorErrorCode::AccountNotOwnedByProgram {
+                address: *info.key,
+                action: "mark for close",
+            }
+            .into());
+        }
+
+        if !info.is_writable {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *info.key,
+                action: "mark for close",
+            }
+            .into());
+        }
+
+        let unlock_timestamp = context.clock()?.unix_timestamp.saturating_add(cooldown);
+
+        #[cfg(any(feature = "test-utils", test))]
+        if info.rent_epoch != crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+            info.realloc(TOMBSTONE_SIZE, false)?;
+        }
+
+        #[cfg(not(any(feature = "test-utils", test)))]
+        {
+            info.realloc(TOMBSTONE_SIZE, false)?;
+        }
+
+        let mut data = info.try_borrow_mut_data()?;
+        data.fill(0);
+        data[0] = TOMBSTONE_DISCRIMINANT;
+        data[UNLOCK_TIMESTAMP_OFFSET..TOMBSTONE_SIZE]
+            .copy_from_slice(&unlock_timestamp.to_le_bytes());
+
+        context.remove_exit_action(info);
+
+        Ok(DeferredClose { context, info })
+    }
+}
+
+impl<'info> Instruction<'info> for DeferredClose<'info> {
+    type CPI = AccountInfo<'info>;
+    type LPI = Pubkey;
+
+    fn verify_account_infos<'a>(
+        &self,
+        config: &mut AccountInfoVerification<'a, 'info>,
+    ) -> FankorResult<()> {
+        config.verify(self.info)
+    }
+
+    #[inline(never)]
+    fn try_from(
+        context: &'info FankorContext<'info>,
+        _buf: &mut &[u8],
+        accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        if accounts.is_empty() {
+            return Err(FankorErrorCode::NotEnoughAccountKeys.into());
+        }
+
+        let info = &accounts[0];
+        let result = DeferredClose::new(context, info)?;
+
+        *accounts = &accounts[1..];
+        Ok(result)
+    }
+}
+
+impl<'info> SingleInstructionAccount<'info> for DeferredClose<'info> {
+    fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    fn context(&self) -> &'info FankorContext<'info> {
+        self.context
+    }
+}
+
+impl<'info> PdaChecker<'info> for DeferredClose<'info> {
+    fn pda_info(&self) -> Option<&'info AccountInfo<'info>> {
+        Some(self.info)
+    }
+}
+
+impl<'info> Debug for DeferredClose<'info> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeferredClose")
+            .field("info", &self.info)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::clock::Clock;
+
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_is_tombstoned() {
+        let mut data = vec![0u8; 20];
+        data[0] = TOMBSTONE_DISCRIMINANT;
+        data[UNLOCK_TIMESTAMP_OFFSET..TOMBSTONE_SIZE].copy_from_slice(&50i64.to_le_bytes());
+
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(data.into_boxed_slice());
+        let info = create_account_info_for_tests(lamports, data);
+
+        let program_id = Box::leak(Box::new(Pubkey::default()));
+        let infos = Box::leak(Box::new([info]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(
+            program_id, infos,
+        )));
+
+        assert!(DeferredClose::is_tombstoned(&infos[0], program_id));
+        assert!(!DeferredClose::is_tombstoned(
+            &infos[0],
+            &Pubkey::new_unique()
+        ));
+
+        let account = DeferredClose::new(context, &infos[0]).unwrap();
+        assert_eq!(account.unlock_timestamp(), 50);
+
+        context.set_mocked_clock(Some(Clock {
+            unix_timestamp: 49,
+            ..Default::default()
+        }));
+        assert!(!account.is_unlocked().unwrap());
+
+        context.set_mocked_clock(Some(Clock {
+            unix_timestamp: 50,
+            ..Default::default()
+        }));
+        assert!(account.is_unlocked().unwrap());
+    }
+
+    #[test]
+    fn test_not_tombstoned() {
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(vec![1u8; 20].into_boxed_slice());
+        let info = create_account_info_for_tests(lamports, data);
+
+        let program_id = Box::leak(Box::new(Pubkey::default()));
+        let infos = Box::leak(Box::new([info]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(
+            program_id, infos,
+        )));
+
+        assert!(!DeferredClose::is_tombstoned(&infos[0], program_id));
+        assert!(DeferredClose::new(context, &infos[0]).is_err());
+    }
+}
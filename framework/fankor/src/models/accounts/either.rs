@@ -13,6 +13,12 @@ use crate::traits::{
     AccountInfoVerification, CpiInstruction, Instruction, SingleInstructionAccount,
 };
 
+/// The byte that precedes the data of the left side of an [Either].
+pub const EITHER_LEFT_SELECTOR: u8 = 0;
+
+/// The byte that precedes the data of the right side of an [Either].
+pub const EITHER_RIGHT_SELECTOR: u8 = 1;
+
 /// Deserialize `L` or `R` depending on a flag.
 pub enum Either<L, R> {
     Left(L),
@@ -103,8 +109,8 @@ impl<'info, L: Instruction<'info>, R: Instruction<'info>> Instruction<'info> for
         *buf = &buf[1..];
 
         let result = match condition {
-            0 => Either::Left(L::try_from(context, buf, accounts)?),
-            1 => Either::Right(R::try_from(context, buf, accounts)?),
+            EITHER_LEFT_SELECTOR => Either::Left(L::try_from(context, buf, accounts)?),
+            EITHER_RIGHT_SELECTOR => Either::Right(R::try_from(context, buf, accounts)?),
             _ => {
                 return Err(FankorErrorCode::InstructionDidNotDeserialize {
                     account: type_name::<Self>().to_string(),
@@ -165,11 +171,35 @@ impl<'info, L: Debug + Instruction<'info>, R: Debug + Instruction<'info>> Debug
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// The CPI of an [Either]. The selector byte of the side is written
+/// automatically before its data.
 pub enum CpiEither<L, R> {
     Left(L),
     Right(R),
 }
 
+impl<L, R> CpiEither<L, R> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn left(value: L) -> Self {
+        Self::Left(value)
+    }
+
+    pub fn right(value: R) -> Self {
+        Self::Right(value)
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn is_left(&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    pub fn is_right(&self) -> bool {
+        matches!(self, Self::Right(_))
+    }
+}
+
 impl<'info, L: CpiInstruction<'info>, R: CpiInstruction<'info>> CpiInstruction<'info>
     for CpiEither<L, R>
 {
@@ -181,11 +211,11 @@ impl<'info, L: CpiInstruction<'info>, R: CpiInstruction<'info>> CpiInstruction<'
     ) -> FankorResult<()> {
         match self {
             Self::Left(v) => {
-                writer.write_all(&[0])?;
+                writer.write_all(&[EITHER_LEFT_SELECTOR])?;
                 v.serialize_into_instruction_parts(writer, metas, infos)
             }
             Self::Right(v) => {
-                writer.write_all(&[1])?;
+                writer.write_all(&[EITHER_RIGHT_SELECTOR])?;
                 v.serialize_into_instruction_parts(writer, metas, infos)
             }
         }
@@ -196,11 +226,35 @@ impl<'info, L: CpiInstruction<'info>, R: CpiInstruction<'info>> CpiInstruction<'
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// The LPI of an [Either]. The selector byte of the side is written
+/// automatically before its data.
 pub enum LpiEither<L, R> {
     Left(L),
     Right(R),
 }
 
+impl<L, R> LpiEither<L, R> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn left(value: L) -> Self {
+        Self::Left(value)
+    }
+
+    pub fn right(value: R) -> Self {
+        Self::Right(value)
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn is_left(&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    pub fn is_right(&self) -> bool {
+        matches!(self, Self::Right(_))
+    }
+}
+
 impl<L: crate::traits::LpiInstruction, R: crate::traits::LpiInstruction>
     crate::traits::LpiInstruction for LpiEither<L, R>
 {
@@ -211,13 +265,44 @@ impl<L: crate::traits::LpiInstruction, R: crate::traits::LpiInstruction>
     ) -> FankorResult<()> {
         match self {
             Self::Left(v) => {
-                writer.write_all(&[0])?;
+                writer.write_all(&[EITHER_LEFT_SELECTOR])?;
                 v.serialize_into_instruction_parts(writer, metas)
             }
             Self::Right(v) => {
-                writer.write_all(&[1])?;
+                writer.write_all(&[EITHER_RIGHT_SELECTOR])?;
                 v.serialize_into_instruction_parts(writer, metas)
             }
         }
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::traits::LpiInstruction;
+
+    use super::*;
+
+    #[test]
+    fn test_lpi_serialize_into_instruction_parts() {
+        let left = Pubkey::new_unique();
+        let right = Pubkey::new_unique();
+
+        for (lpi, selector, key) in [
+            (LpiEither::left(left), EITHER_LEFT_SELECTOR, left),
+            (LpiEither::right(right), EITHER_RIGHT_SELECTOR, right),
+        ] {
+            let mut data = Vec::new();
+            let mut metas = Vec::new();
+            lpi.serialize_into_instruction_parts(&mut data, &mut metas).unwrap();
+
+            assert_eq!(data, vec![selector]);
+            assert_eq!(metas, vec![AccountMeta::new_readonly(key, false)]);
+        }
+    }
+}
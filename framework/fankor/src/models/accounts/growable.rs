@@ -0,0 +1,301 @@
+use std::any::type_name;
+use std::cmp::min;
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::{FankorContext, Program, System};
+use crate::traits::{
+    AccountInfoVerification, AccountType, Instruction, PdaChecker, SingleInstructionAccount,
+};
+use crate::utils::realloc::realloc_account_to_size;
+
+/// The offset of the target size in the header of a growable account,
+/// i.e. just after the discriminant.
+const TARGET_SIZE_OFFSET: usize = 1;
+
+/// The size of the header of a growable account: the discriminant followed
+/// by the target size.
+pub const GROWABLE_ACCOUNT_HEADER_SIZE: usize = TARGET_SIZE_OFFSET + 8;
+
+/// Wrapper for `AccountInfo` of a program account that can grow over several
+/// transactions up to a target size, as the runtime limits the growth of an account
+/// to [MAX_PERMITTED_DATA_INCREASE] bytes per instruction.
+///
+/// `T` is the growable account type, or an accounts enum with all of them.
+/// Its first field must be a `u64` with the target size so that it is placed
+/// just after the discriminant.
+pub struct GrowableAccount<'info, T: AccountType> {
+    context: &'info FankorContext<'info>,
+    info: &'info AccountInfo<'info>,
+    _data: PhantomData<T>,
+}
+
+impl<'info, T: AccountType> GrowableAccount<'info, T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new account checking its owner, header and discriminant.
+    pub fn new(
+        context: &'info FankorContext<'info>,
+        info: &'info AccountInfo<'info>,
+    ) -> FankorResult<GrowableAccount<'info, T>> {
+        if info.owner != T::owner() {
+            return Err(FankorErrorCode::AccountOwnedByWrongProgram {
+                address: *info.key,
+                expected: *T::owner(),
+                actual: *info.owner,
+            }
+            .into());
+        }
+
+        {
+            let data = info.try_borrow_data()?;
+
+            if data.len() < GROWABLE_ACCOUNT_HEADER_SIZE {
+                return Err(
+                    FankorErrorCode::GrowableAccountWithoutHeader { address: *info.key }.into(),
+                );
+            }
+
+            if !T::check_discriminant(data[0]) {
+                return Err(FankorErrorCode::AccountDiscriminantMismatch {
+                    account: format!("GrowableAccount<{}>", type_name::<T>()),
+                }
+                .into());
+            }
+        }
+
+        Ok(GrowableAccount {
+            context,
+            info,
+            _data: PhantomData,
+        })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn address(&self) -> &'info Pubkey {
+        self.info.key
+    }
+
+    pub fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    pub fn context(&self) -> &'info FankorContext<'info> {
+        self.context
+    }
+
+    /// The current size of the account.
+    pub fn current_size(&self) -> usize {
+        self.info.data_len()
+    }
+
+    /// The size the account must reach.
+    pub fn target_size(&self) -> usize {
+        let data = self.info.data.borrow();
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&data[TARGET_SIZE_OFFSET..GROWABLE_ACCOUNT_HEADER_SIZE]);
+
+        u64::from_le_bytes(bytes) as usize
+    }
+
+    /// Whether the account has reached its target size or not.
+    pub fn is_fully_grown(&self) -> bool {
+        self.current_size() >= self.target_size()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Sets the size the account must reach. It is not allowed to shrink the account.
+    pub fn set_target_size(&self, target_size: usize) -> FankorResult<()> {
+        if !self.info.is_writable {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *self.info.key,
+                action: "set target size",
+            }
+            .into());
+        }
+
+        let target_size = target_size.max(self.current_size());
+        let mut data = self.info.try_borrow_mut_data()?;
+        data[TARGET_SIZE_OFFSET..GROWABLE_ACCOUNT_HEADER_SIZE]
+            .copy_from_slice(&(target_size as u64).to_le_bytes());
+
+        Ok(())
+    }
+
+    /// Grows the account towards its target size as much as allowed in a single
+    /// instruction, using `payer` to keep it rent-exempt. The new bytes are zeroed.
+    ///
+    /// Returns whether the account has reached its target size or not.
+    pub fn grow_step(
+        &self,
+        payer: &AccountInfo<'info>,
+        system_program: &Program<System>,
+    ) -> FankorResult<bool> {
+        let current_size = self.current_size();
+        let target_size = self.target_size();
+
+        if current_size >= target_size {
+            return Ok(true);
+        }
+
+        let new_size = min(target_size, current_size + MAX_PERMITTED_DATA_INCREASE);
+        realloc_account_to_size(new_size, true, self.info, Some(payer), system_program)?;
+
+        Ok(new_size == target_size)
+    }
+}
+
+impl<'info, T: AccountType> Instruction<'info> for GrowableAccount<'info, T> {
+    type CPI = AccountInfo<'info>;
+    type LPI = Pubkey;
+
+    fn verify_account_infos<'a>(
+        &self,
+        config: &mut AccountInfoVerification<'a, 'info>,
+    ) -> FankorResult<()> {
+        config.verify(self.info)
+    }
+
+    #[inline(never)]
+    fn try_from(
+        context: &'info FankorContext<'info>,
+        _buf: &mut &[u8],
+        accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        if accounts.is_empty() {
+            return Err(FankorErrorCode::NotEnoughAccountKeys.into());
+        }
+
+        let info = &accounts[0];
+        let result = GrowableAccount::<T>::new(context, info)?;
+
+        *accounts = &accounts[1..];
+        Ok(result)
+    }
+}
+
+impl<'info, T: AccountType> SingleInstructionAccount<'info> for GrowableAccount<'info, T> {
+    fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    fn context(&self) -> &'info FankorContext<'info> {
+        self.context
+    }
+}
+
+impl<'info, T: AccountType> PdaChecker<'info> for GrowableAccount<'info, T> {
+    fn pda_info(&self) -> Option<&'info AccountInfo<'info>> {
+        Some(self.info)
+    }
+}
+
+impl<'info, T: AccountType> Debug for GrowableAccount<'info, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GrowableAccount")
+            .field("info", &self.info)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    // The owner of the accounts created for tests.
+    const OWNER: Pubkey = Pubkey::new_from_array([0; 32]);
+    const DISCRIMINANT: u8 = 3;
+
+    #[derive(BorshSerialize, BorshDeserialize)]
+    struct GrowableData {
+        target_size: u64,
+    }
+
+    impl AccountType for GrowableData {
+        fn discriminant() -> u8 {
+            DISCRIMINANT
+        }
+
+        fn owner() -> &'static Pubkey {
+            &OWNER
+        }
+    }
+
+    #[test]
+    fn test_target_size() {
+        let mut data = vec![0u8; 20];
+        data[0] = DISCRIMINANT;
+        data[TARGET_SIZE_OFFSET..GROWABLE_ACCOUNT_HEADER_SIZE]
+            .copy_from_slice(&30u64.to_le_bytes());
+
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(data.into_boxed_slice());
+        let mut info = create_account_info_for_tests(lamports, data);
+        info.is_writable = true;
+
+        let program_id = Box::leak(Box::new(Pubkey::default()));
+        let infos = Box::leak(Box::new([info]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(
+            program_id, infos,
+        )));
+
+        let account = GrowableAccount::<GrowableData>::new(context, &infos[0]).unwrap();
+        assert_eq!(account.current_size(), 20);
+        assert_eq!(account.target_size(), 30);
+        assert!(!account.is_fully_grown());
+
+        account.set_target_size(20).unwrap();
+        assert_eq!(account.target_size(), 20);
+        assert!(account.is_fully_grown());
+
+        // Cannot shrink.
+        account.set_target_size(5).unwrap();
+        assert_eq!(account.target_size(), 20);
+    }
+
+    #[test]
+    fn test_without_header() {
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(vec![0u8; 5].into_boxed_slice());
+        let info = create_account_info_for_tests(lamports, data);
+
+        let program_id = Box::leak(Box::new(Pubkey::default()));
+        let infos = Box::leak(Box::new([info]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(
+            program_id, infos,
+        )));
+
+        assert!(GrowableAccount::<GrowableData>::new(context, &infos[0]).is_err());
+    }
+
+    #[test]
+    fn test_wrong_discriminant() {
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(vec![DISCRIMINANT + 1; 20].into_boxed_slice());
+        let info = create_account_info_for_tests(lamports, data);
+
+        let program_id = Box::leak(Box::new(Pubkey::default()));
+        let infos = Box::leak(Box::new([info]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(
+            program_id, infos,
+        )));
+
+        assert!(GrowableAccount::<GrowableData>::new(context, &infos[0]).is_err());
+    }
+}
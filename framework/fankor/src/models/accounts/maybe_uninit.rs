@@ -5,16 +5,24 @@ use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 
 use crate::errors::FankorResult;
-use crate::models::{FankorContext, UninitializedAccount};
+use crate::models::{Account, FankorContext, Program, System, UninitializedAccount, ZcAccount};
 use crate::prelude::PdaChecker;
-use crate::traits::{AccountInfoVerification, Instruction, SingleInstructionAccount};
+use crate::traits::{AccountInfoVerification, AccountType, Instruction, SingleInstructionAccount};
 
 /// Tries to deserialize an actual account or its uninitialized counterpart.
+/// The uninitialized counterpart is untyped, the type of the account is only
+/// given when it is initialized.
 pub enum MaybeUninitialized<'info, T> {
     Init(T),
     Uninit(UninitializedAccount<'info>),
 }
 
+/// A program account of type `T` that can be uninitialized.
+pub type MaybeUninitializedAccount<'info, T> = MaybeUninitialized<'info, Account<'info, T>>;
+
+/// A zero-copy program account of type `T` that can be uninitialized.
+pub type MaybeUninitializedZcAccount<'info, T> = MaybeUninitialized<'info, ZcAccount<'info, T>>;
+
 impl<'info, T: SingleInstructionAccount<'info>> MaybeUninitialized<'info, T> {
     // GETTERS -----------------------------------------------------------------
 
@@ -71,6 +79,39 @@ impl<'info, T: SingleInstructionAccount<'info>> MaybeUninitialized<'info, T> {
     }
 }
 
+impl<'info, T: Default + AccountType> MaybeUninitialized<'info, Account<'info, T>> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Returns the account if it is initialized, otherwise initializes it
+    /// with the default value of `T` and the given `space` using `payer` as
+    /// the funding account.
+    pub fn init_if_needed(
+        self,
+        space: usize,
+        payer: &AccountInfo<'info>,
+        system_program: &Program<System>,
+    ) -> FankorResult<Account<'info, T>> {
+        match self {
+            Self::Init(v) => Ok(v),
+            Self::Uninit(v) => v.init::<T>(space, payer, system_program),
+        }
+    }
+
+    /// Same as [init_if_needed](Self::init_if_needed) but for PDA accounts.
+    pub fn init_pda_if_needed(
+        self,
+        space: usize,
+        seeds: &[&[u8]],
+        payer: &AccountInfo<'info>,
+        system_program: &Program<System>,
+    ) -> FankorResult<Account<'info, T>> {
+        match self {
+            Self::Init(v) => Ok(v),
+            Self::Uninit(v) => v.init_pda::<T>(space, seeds, payer, system_program),
+        }
+    }
+}
+
 impl<'info, T: Instruction<'info>> Instruction<'info> for MaybeUninitialized<'info, T> {
     type CPI = AccountInfo<'info>;
     type LPI = Pubkey;
@@ -140,12 +181,12 @@ impl<'info, T: Debug> Debug for MaybeUninitialized<'info, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::Init(v) => f
-                .debug_struct("MaybeUninitializedAccount")
+                .debug_struct("MaybeUninitialized")
                 .field("Init", &v)
                 .field("Uninit", &Option::<UninitializedAccount<'info>>::None)
                 .finish(),
             Self::Uninit(v) => f
-                .debug_struct("MaybeUninitializedAccount")
+                .debug_struct("MaybeUninitialized")
                 .field("Init", &Option::<T>::None)
                 .field("Uninit", &v)
                 .finish(),
@@ -1,12 +1,19 @@
 pub use account::*;
 pub use argument::*;
+pub use batch::*;
 pub use boxed::*;
+pub use deferred_close::*;
 pub use either::*;
+pub use growable::*;
 pub use maybe_uninit::*;
 pub use option::*;
+#[cfg(feature = "pod")]
+pub use pod::*;
+pub use prefunded::*;
 pub use program::*;
 pub use rest::*;
 pub use rest_arguments::*;
+pub use singleton::*;
 pub use single_either::*;
 pub use sysvar_account::*;
 pub use unchecked_account::*;
@@ -16,13 +23,20 @@ pub use zero_copy::*;
 
 mod account;
 mod argument;
+mod batch;
 mod boxed;
+mod deferred_close;
 mod either;
+mod growable;
 mod maybe_uninit;
 mod option;
+#[cfg(feature = "pod")]
+mod pod;
+mod prefunded;
 mod program;
 mod rest;
 mod rest_arguments;
+mod singleton;
 mod single_either;
 mod sysvar_account;
 mod unchecked_account;
@@ -0,0 +1,450 @@
+use std::any::type_name;
+use std::cell::{Ref, RefMut};
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Epoch;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_program;
+use solana_program::sysvar::Sysvar;
+
+use crate::errors::{Error, FankorErrorCode, FankorResult};
+use crate::models::{Account, FankorContext, FankorContextExitAction, Program, System};
+use crate::traits::{
+    AccountInfoVerification, Instruction, PdaChecker, PodAccountType, SingleInstructionAccount,
+    POD_ACCOUNT_DATA_OFFSET,
+};
+use crate::utils::close::close_account;
+use crate::utils::rent::make_rent_exempt;
+
+/// An initialized account of a [PodAccountType] whose value is cast in place
+/// from the data of the account, without deserializing nor serializing it.
+pub struct PodAccount<'info, T: PodAccountType> {
+    context: &'info FankorContext<'info>,
+    info: &'info AccountInfo<'info>,
+    _data: PhantomData<T>,
+    dropped: bool,
+}
+
+impl<'info, T: PodAccountType> PodAccount<'info, T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new account checking its owner, size and discriminant.
+    pub fn new(
+        context: &'info FankorContext<'info>,
+        info: &'info AccountInfo<'info>,
+    ) -> FankorResult<PodAccount<'info, T>> {
+        if info.owner == &system_program::ID && info.lamports() == 0 {
+            return Err(FankorErrorCode::AccountNotInitialized { address: *info.key }.into());
+        }
+
+        if info.owner != T::owner() {
+            return Err(FankorErrorCode::AccountOwnedByWrongProgram {
+                address: *info.key,
+                expected: *T::owner(),
+                actual: *info.owner,
+            }
+            .into());
+        }
+
+        // Check it is not closed.
+        if context.is_account_uninitialized(info) {
+            return Err(FankorErrorCode::NewFromClosedAccount { address: *info.key }.into());
+        }
+
+        // Check size and discriminant.
+        {
+            let data = info.try_borrow_data()?;
+
+            if data.len() < T::ACCOUNT_SIZE {
+                return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                    type_name: type_name::<T>(),
+                }
+                .into());
+            }
+
+            if !T::check_discriminant(data[0]) {
+                return Err(FankorErrorCode::AccountDiscriminantMismatch {
+                    account: format!("PodAccount<{}>", type_name::<T>()),
+                }
+                .into());
+            }
+        }
+
+        Ok(PodAccount::new_unchecked(context, info))
+    }
+
+    pub fn new_unchecked(
+        context: &'info FankorContext<'info>,
+        info: &'info AccountInfo<'info>,
+    ) -> PodAccount<'info, T> {
+        PodAccount {
+            context,
+            info,
+            _data: PhantomData,
+            dropped: false,
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn address(&self) -> &'info Pubkey {
+        self.info().key
+    }
+
+    pub fn owner(&self) -> &'info Pubkey {
+        self.info().owner
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.info().is_writable
+    }
+
+    pub fn is_signer(&self) -> bool {
+        self.info().is_signer
+    }
+
+    pub fn is_executable(&self) -> bool {
+        self.info().executable
+    }
+
+    pub fn balance(&self) -> u64 {
+        self.info().lamports()
+    }
+
+    pub fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    pub fn rent_epoch(&self) -> Epoch {
+        self.info.rent_epoch
+    }
+
+    pub fn context(&self) -> &'info FankorContext<'info> {
+        self.context
+    }
+
+    /// Borrows the value of the account.
+    pub fn data(&self) -> FankorResult<Ref<'info, T>> {
+        let data = self
+            .info
+            .data
+            .try_borrow()
+            .map_err(|_| FankorErrorCode::AlreadyBorrowed {
+                type_name: type_name::<T>(),
+                operation: "data",
+            })?;
+
+        Ref::filter_map(data, |v| {
+            bytemuck::try_from_bytes(&v[POD_ACCOUNT_DATA_OFFSET..T::ACCOUNT_SIZE]).ok()
+        })
+        .map_err(|_| {
+            FankorErrorCode::ZeroCopyCannotDeserialize {
+                type_name: type_name::<T>(),
+            }
+            .into()
+        })
+    }
+
+    /// Mutably borrows the value of the account. The changes are written
+    /// directly into the account data.
+    pub fn data_mut(&self) -> FankorResult<RefMut<'info, T>> {
+        if !self.is_owned_by_program() {
+            return Err(FankorErrorCode::AccountNotOwnedByProgram {
+                address: *self.address(),
+                action: "write",
+            }
+            .into());
+        }
+
+        if !self.is_writable() {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *self.address(),
+                action: "write",
+            }
+            .into());
+        }
+
+        if self.context.is_account_uninitialized(self.info) {
+            return Err(FankorErrorCode::AlreadyClosedAccount {
+                address: *self.address(),
+                action: "write",
+            }
+            .into());
+        }
+
+        let data = self
+            .info
+            .data
+            .try_borrow_mut()
+            .map_err(|_| FankorErrorCode::AlreadyBorrowed {
+                type_name: type_name::<T>(),
+                operation: "data_mut",
+            })?;
+
+        RefMut::filter_map(data, |v| {
+            bytemuck::try_from_bytes_mut(&mut v[POD_ACCOUNT_DATA_OFFSET..T::ACCOUNT_SIZE]).ok()
+        })
+        .map_err(|_| {
+            FankorErrorCode::ZeroCopyCannotDeserialize {
+                type_name: type_name::<T>(),
+            }
+            .into()
+        })
+    }
+
+    /// Whether the account has enough lamports to be rent-exempt or not.
+    pub fn is_rent_exempt(&self) -> bool {
+        let info = self.info();
+        let lamports = info.lamports();
+        let data_len = info.data_len();
+
+        let rent = Rent::get().expect("Cannot access Rent Sysvar");
+
+        rent.is_exempt(lamports, data_len)
+    }
+
+    /// The exit action of this account.
+    pub fn exit_action(&self) -> Option<FankorContextExitAction<'info>> {
+        self.context().get_exit_action(self.info)
+    }
+
+    /// Whether the account is owned by the current program.
+    pub fn is_owned_by_program(&self) -> bool {
+        self.info.owner == self.context.program_id()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Closes the account and sends the lamports to the `destination_account`.
+    pub fn close(mut self, destination_account: &AccountInfo<'info>) -> FankorResult<()> {
+        close_account(self.info, self.context(), destination_account)?;
+
+        // Prevent account to execute the drop actions.
+        self.dropped = true;
+        Ok(())
+    }
+
+    /// Makes the account rent-exempt by adding funds from `payer` if necessary.
+    pub fn make_rent_exempt(
+        &self,
+        payer: &'info AccountInfo<'info>,
+        system_program: &Program<System>,
+    ) -> FankorResult<()> {
+        self._make_rent_exempt(false, payer, system_program)
+    }
+
+    /// Makes the account rent-exempt by adding or removing funds from/to `payer`
+    /// if necessary.
+    pub fn make_exact_rent_exempt(
+        &self,
+        payer: &'info AccountInfo<'info>,
+        system_program: &Program<System>,
+    ) -> FankorResult<()> {
+        self._make_rent_exempt(true, payer, system_program)
+    }
+
+    fn _make_rent_exempt(
+        &self,
+        exact: bool,
+        payer: &'info AccountInfo<'info>,
+        system_program: &Program<System>,
+    ) -> FankorResult<()> {
+        if !self.is_owned_by_program() {
+            return Err(FankorErrorCode::AccountNotOwnedByProgram {
+                address: *self.address(),
+                action: "make rent-exempt",
+            }
+            .into());
+        }
+
+        if !self.is_writable() {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *self.address(),
+                action: "make rent-exempt",
+            }
+            .into());
+        }
+
+        if self.context.is_account_uninitialized(self.info) {
+            return Err(FankorErrorCode::AlreadyClosedAccount {
+                address: *self.address(),
+                action: "make rent-exempt",
+            }
+            .into());
+        }
+
+        make_rent_exempt(T::ACCOUNT_SIZE, exact, payer, self.info, system_program)
+    }
+
+    /// Copies the value and creates a new account.
+    pub fn into_account(mut self) -> FankorResult<Account<'info, T>> {
+        let data = *self.data()?;
+        let new_account = Account::new_unchecked(self.context, self.info, data);
+
+        // Prevent old account to execute the drop actions.
+        self.dropped = true;
+
+        Ok(new_account)
+    }
+
+    /// Invalidates the exit action for this account.
+    pub fn remove_exit_action(&self) {
+        self.context().remove_exit_action(self.info);
+    }
+
+    /// Closes the account at the end of the instruction sending the lamports to
+    /// the `destination_account` account.
+    ///
+    /// This replaces other exit actions associated with this account.
+    pub fn close_account_at_exit(
+        &self,
+        destination_account: &'info AccountInfo<'info>,
+    ) -> FankorResult<()> {
+        if !self.is_owned_by_program() {
+            return Err(FankorErrorCode::AccountNotOwnedByProgram {
+                address: *self.address(),
+                action: "close",
+            }
+            .into());
+        }
+
+        if !self.is_writable() {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *self.address(),
+                action: "close",
+            }
+            .into());
+        }
+
+        let context = self.context();
+        context.set_exit_action(
+            self.info,
+            FankorContextExitAction::Close {
+                destination_account,
+            },
+        );
+
+        Ok(())
+    }
+}
+
+impl<'info, T: PodAccountType> Instruction<'info> for PodAccount<'info, T> {
+    type CPI = AccountInfo<'info>;
+    type LPI = Pubkey;
+
+    fn verify_account_infos<'a>(
+        &self,
+        config: &mut AccountInfoVerification<'a, 'info>,
+    ) -> FankorResult<()> {
+        config.verify(self.info)
+    }
+
+    #[inline(never)]
+    fn try_from(
+        context: &'info FankorContext<'info>,
+        _buf: &mut &[u8],
+        accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        if accounts.is_empty() {
+            return Err(FankorErrorCode::NotEnoughAccountKeys.into());
+        }
+
+        let result = PodAccount::new(context, &accounts[0])?;
+
+        *accounts = &accounts[1..];
+        Ok(result)
+    }
+}
+
+impl<'info, T: PodAccountType> SingleInstructionAccount<'info> for PodAccount<'info, T> {
+    fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    fn context(&self) -> &'info FankorContext<'info> {
+        self.context
+    }
+}
+
+impl<'info, T: PodAccountType> PdaChecker<'info> for PodAccount<'info, T> {
+    fn pda_info(&self) -> Option<&'info AccountInfo<'info>> {
+        Some(self.info)
+    }
+}
+
+impl<'info, T: PodAccountType> Debug for PodAccount<'info, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PodAccount").field("info", &self.info).finish()
+    }
+}
+
+/// Execute the last actions over the account.
+impl<'info, T: PodAccountType> Drop for PodAccount<'info, T> {
+    fn drop(&mut self) {
+        // Ignore if not owned by program.
+        if !self.is_owned_by_program() {
+            return;
+        }
+
+        // Ignore already dropped accounts.
+        if self.dropped {
+            return;
+        }
+
+        if let Err(e) = drop_aux(self) {
+            crate::macros::panic_error!(e);
+        }
+    }
+}
+
+fn drop_aux<'info, T: PodAccountType>(account: &mut PodAccount<'info, T>) -> FankorResult<()> {
+    match account.context.get_exit_action(account.info) {
+        None => {}
+        Some(FankorContextExitAction::ProcessedByZeroCopy) => {}
+        Some(FankorContextExitAction::Processed) => {
+            return Err(FankorErrorCode::DuplicatedWritableAccounts {
+                address: *account.address(),
+            }
+            .into());
+        }
+        Some(FankorContextExitAction::Realloc {
+            exact,
+            payer,
+            system_program,
+            ..
+        }) => {
+            // Pod accounts have a fixed size so they are only made rent-exempt.
+            let payer = match payer {
+                Some(payer) => payer,
+                None => return Ok(()),
+            };
+
+            account._make_rent_exempt(
+                exact,
+                payer,
+                &Program::new(account.context(), system_program)?,
+            )?;
+
+            // Prevent executing this action twice.
+            account
+                .context
+                .set_exit_action(account.info, FankorContextExitAction::ProcessedByZeroCopy);
+        }
+        Some(FankorContextExitAction::Close {
+            destination_account,
+        }) => {
+            close_account(account.info(), account.context(), destination_account)?;
+
+            // Prevent executing this action twice.
+            account
+                .context
+                .set_exit_action(account.info, FankorContextExitAction::ProcessedByZeroCopy);
+        }
+    }
+
+    Ok(())
+}
@@ -1,13 +1,14 @@
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 use std::io::Write;
+use std::slice::ChunksExact;
 
 use solana_program::account_info::AccountInfo;
 use solana_program::instruction::AccountMeta;
 use solana_program::pubkey::Pubkey;
 
-use crate::errors::FankorResult;
-use crate::models::FankorContext;
+use crate::errors::{Error, FankorErrorCode, FankorResult};
+use crate::models::{DeferredClose, FankorContext};
 use crate::traits::{AccountInfoVerification, CpiInstruction, Instruction, LpiInstruction};
 
 /// A wrapper around a `Vec<AccountInfo>` that keeps the rest infos.
@@ -44,6 +45,79 @@ impl<'info> Rest<'info> {
     pub fn context(&self) -> &'info FankorContext<'info> {
         self.context
     }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Iterates over the accounts in groups of `chunk_size`, e.g. triplets of
+    /// `(mint, from, to)`, failing if the number of accounts is not a
+    /// multiple of it.
+    pub fn chunks(
+        &self,
+        chunk_size: usize,
+    ) -> FankorResult<ChunksExact<'info, AccountInfo<'info>>> {
+        if chunk_size == 0 || self.accounts.len() % chunk_size != 0 {
+            return Err(FankorErrorCode::RestAccountsNotDivisible {
+                chunk_size,
+                actual: self.accounts.len(),
+            }
+            .into());
+        }
+
+        Ok(self.accounts.chunks_exact(chunk_size))
+    }
+
+    /// Parses all the accounts as consecutive `T` accounts structs, failing
+    /// if any account is left. The structs cannot read instruction data.
+    pub fn parse_chunks<T: Instruction<'info>>(&self) -> FankorResult<Vec<T>> {
+        let mut accounts = self.accounts;
+        let mut result = Vec::new();
+
+        while !accounts.is_empty() {
+            let remaining = accounts.len();
+            result.push(T::try_from(self.context, &mut &[][..], &mut accounts)?);
+
+            if accounts.len() == remaining {
+                return Err(FankorErrorCode::RestAccountsNotDivisible {
+                    chunk_size: 0,
+                    actual: self.accounts.len(),
+                }
+                .into());
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Closes the accounts that are tombstones of this program unlocked at or
+    /// before `cutoff`, zeroing their data and sending their lamports to
+    /// `treasury`. A failure closing an account does not stop the sweep, it
+    /// is reported in its entry instead.
+    pub fn sweep_tombstones(
+        &self,
+        treasury: &AccountInfo<'info>,
+        cutoff: i64,
+    ) -> FankorResult<Vec<SweptAccount>> {
+        if !treasury.is_writable {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *treasury.key,
+                action: "sweep",
+            }
+            .into());
+        }
+
+        Ok(self
+            .accounts
+            .iter()
+            .map(|info| SweptAccount {
+                address: *info.key,
+                outcome: match DeferredClose::sweep(self.context, info, treasury, cutoff) {
+                    Ok(Some(lamports)) => SweepOutcome::Reclaimed(lamports),
+                    Ok(None) => SweepOutcome::Skipped,
+                    Err(e) => SweepOutcome::Failed(e),
+                },
+            })
+            .collect())
+    }
 }
 
 impl<'info> Instruction<'info> for Rest<'info> {
@@ -86,6 +160,29 @@ impl<'info> Debug for Rest<'info> {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// An account processed by [Rest::sweep_tombstones].
+#[derive(Debug, PartialEq, Eq)]
+pub struct SweptAccount {
+    pub address: Pubkey,
+    pub outcome: SweepOutcome,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SweepOutcome {
+    /// The account was closed and these lamports sent to the treasury.
+    Reclaimed(u64),
+
+    /// The account is not a tombstone or it unlocks after the cutoff.
+    Skipped,
+
+    /// The account is a tombstone but it could not be closed.
+    Failed(Error),
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 pub struct CpiRest<'info>(pub Vec<AccountInfo<'info>>);
 
 impl<'info> CpiRest<'info> {
@@ -138,3 +235,100 @@ impl LpiInstruction for LpiRest {
         Ok(())
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::clock::Clock;
+
+    use crate::models::{UncheckedAccount, TOMBSTONE_DISCRIMINANT, TOMBSTONE_SIZE};
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[test]
+    fn test_chunks() {
+        let infos: &'static [AccountInfo] = Box::leak(
+            (0..6)
+                .map(|_| {
+                    let lamports = Box::leak(Box::new(0));
+                    let data = Box::leak(vec![0u8; 0].into_boxed_slice());
+                    let mut info = create_account_info_for_tests(lamports, data);
+                    info.key = Box::leak(Box::new(Pubkey::new_unique()));
+                    info
+                })
+                .collect::<Vec<_>>()
+                .into_boxed_slice(),
+        );
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(&PROGRAM_ID, infos)));
+        let rest = Rest::new(context, infos).unwrap();
+
+        let chunks = rest.chunks(3).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1][0].key, infos[3].key);
+
+        assert!(rest.chunks(4).is_err());
+        assert!(rest.chunks(0).is_err());
+
+        let parsed = rest.parse_chunks::<UncheckedAccount>().unwrap();
+        assert_eq!(parsed.len(), 6);
+    }
+
+    #[test]
+    fn test_sweep_tombstones() {
+        let create_info = |data: Vec<u8>, is_writable: bool| {
+            let lamports = Box::leak(Box::new(100));
+            let data = Box::leak(data.into_boxed_slice());
+            let mut info = create_account_info_for_tests(lamports, data);
+            info.key = Box::leak(Box::new(Pubkey::new_unique()));
+            info.owner = Box::leak(Box::new(PROGRAM_ID));
+            info.is_writable = is_writable;
+            info
+        };
+        let tombstone = |unlock_timestamp: i64| {
+            let mut data = vec![0u8; TOMBSTONE_SIZE];
+            data[0] = TOMBSTONE_DISCRIMINANT;
+            data[1..].copy_from_slice(&unlock_timestamp.to_le_bytes());
+            data
+        };
+
+        let infos: &'static [AccountInfo] = Box::leak(Box::new([
+            create_info(tombstone(10), true),
+            create_info(tombstone(100), true),
+            create_info(vec![1u8; TOMBSTONE_SIZE], true),
+            create_info(tombstone(10), false),
+        ]));
+        let treasury = create_info(vec![], true);
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(&PROGRAM_ID, infos)));
+        context.set_mocked_clock(Some(Clock {
+            unix_timestamp: 60,
+            ..Default::default()
+        }));
+
+        let rest = Rest::new(context, infos).unwrap();
+        let outcomes = rest
+            .sweep_tombstones(&treasury, 50)
+            .unwrap()
+            .into_iter()
+            .map(|v| v.outcome)
+            .collect::<Vec<_>>();
+
+        assert_eq!(outcomes[0], SweepOutcome::Reclaimed(100));
+        assert_eq!(outcomes[1], SweepOutcome::Skipped);
+        assert_eq!(outcomes[2], SweepOutcome::Skipped);
+        assert!(matches!(outcomes[3], SweepOutcome::Failed(_)));
+
+        assert_eq!(treasury.lamports(), 200);
+        assert_eq!(infos[0].lamports(), 0);
+        assert!(infos[0].data.borrow().iter().all(|v| *v == 0));
+        assert_eq!(infos[1].lamports(), 100);
+
+        let readonly_treasury = create_info(vec![], false);
+        assert!(rest.sweep_tombstones(&readonly_treasury, 50).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::ops::{Deref, DerefMut};
+
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::FankorResult;
+use crate::models::{Account, FankorContext, Program, System};
+use crate::traits::{
+    AccountInfoVerification, Instruction, PdaChecker, SingleInstructionAccount,
+    SingletonAccountType,
+};
+
+/// An [Account] that is the only one of its type in the program, stored in
+/// the PDA derived from the type name, e.g. the global config.
+///
+/// The address is checked when it is loaded so the seeds do not need to be
+/// repeated in every instruction.
+pub struct Singleton<'info, T: SingletonAccountType> {
+    account: Account<'info, T>,
+}
+
+impl<'info, T: SingletonAccountType> Singleton<'info, T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Wraps `account` checking it is at the address of the singleton.
+    pub fn new(account: Account<'info, T>) -> FankorResult<Singleton<'info, T>> {
+        account.context().check_canonical_pda_with_components(
+            account.info(),
+            &[T::SINGLETON_SEED],
+            T::owner(),
+        )?;
+
+        Ok(Singleton { account })
+    }
+
+    /// Gets the singleton, creating and initializing it with the default value
+    /// of `T` and the given `space` using `payer` as the funding account if it
+    /// does not exist.
+    ///
+    /// The singleton must be among the accounts of the instruction.
+    pub fn get_or_init(
+        context: &'info FankorContext<'info>,
+        space: usize,
+        payer: &AccountInfo<'info>,
+        system_program: &Program<System>,
+    ) -> FankorResult<Singleton<'info, T>>
+    where
+        T: Default,
+    {
+        let account =
+            context.ensure_pda_account::<T>(&[T::SINGLETON_SEED], space, payer, system_program)?;
+
+        Ok(Singleton { account })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The address and bump of the singleton.
+    pub fn singleton_address() -> (Pubkey, u8) {
+        T::singleton_address()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn into_account(self) -> Account<'info, T> {
+        self.account
+    }
+}
+
+impl<'info, T: SingletonAccountType> Deref for Singleton<'info, T> {
+    type Target = Account<'info, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.account
+    }
+}
+
+impl<'info, T: SingletonAccountType> DerefMut for Singleton<'info, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.account
+    }
+}
+
+impl<'info, T: SingletonAccountType> Instruction<'info> for Singleton<'info, T> {
+    type CPI = AccountInfo<'info>;
+    type LPI = Pubkey;
+
+    fn verify_account_infos<'a>(
+        &self,
+        config: &mut AccountInfoVerification<'a, 'info>,
+    ) -> FankorResult<()> {
+        self.account.verify_account_infos(config)
+    }
+
+    #[inline(never)]
+    fn try_from(
+        context: &'info FankorContext<'info>,
+        buf: &mut &[u8],
+        accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<Self> {
+        let account = <Account<T> as Instruction>::try_from(context, buf, accounts)?;
+        Singleton::new(account)
+    }
+}
+
+impl<'info, T: SingletonAccountType> SingleInstructionAccount<'info> for Singleton<'info, T> {
+    fn info(&self) -> &'info AccountInfo<'info> {
+        self.account.info()
+    }
+
+    fn context(&self) -> &'info FankorContext<'info> {
+        self.account.context()
+    }
+}
+
+impl<'info, T: SingletonAccountType> PdaChecker<'info> for Singleton<'info, T> {
+    fn pda_info(&self) -> Option<&'info AccountInfo<'info>> {
+        self.account.pda_info()
+    }
+}
+
+impl<'info, T: SingletonAccountType> Debug for Singleton<'info, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Singleton")
+            .field("info", &self.account.info())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use crate::tests::create_account_info_for_tests;
+    use crate::traits::AccountType;
+
+    use super::*;
+
+    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+    struct Config {
+        value: u8,
+    }
+
+    impl AccountType for Config {
+        fn discriminant() -> u8 {
+            0
+        }
+
+        fn owner() -> &'static Pubkey {
+            &PROGRAM_ID
+        }
+    }
+
+    impl SingletonAccountType for Config {
+        const SINGLETON_SEED: &'static [u8] = b"Config";
+    }
+
+    #[test]
+    fn test_try_from() {
+        let (address, _) = Config::singleton_address();
+        assert_eq!(
+            address,
+            Pubkey::find_program_address(&[b"Config"], &PROGRAM_ID).0
+        );
+
+        let lamports = Box::leak(Box::new(1));
+        let data = Box::leak(vec![7u8].into_boxed_slice());
+        let mut info = create_account_info_for_tests(lamports, data);
+        info.key = Box::leak(Box::new(address));
+        info.owner = &PROGRAM_ID;
+
+        let lamports = Box::leak(Box::new(1));
+        let data = Box::leak(vec![7u8].into_boxed_slice());
+        let mut other = create_account_info_for_tests(lamports, data);
+        other.key = Box::leak(Box::new(Pubkey::new_unique()));
+        other.owner = &PROGRAM_ID;
+
+        let infos = Box::leak(Box::new([info, other]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(&PROGRAM_ID, infos)));
+
+        let mut accounts = &infos[..];
+        let singleton =
+            <Singleton<Config> as Instruction>::try_from(context, &mut &[][..], &mut accounts)
+                .unwrap();
+        assert_eq!(singleton.data().value, 7);
+        assert!(context.get_seeds_for_account(&infos[0]).is_some());
+
+        assert!(
+            <Singleton<Config> as Instruction>::try_from(context, &mut &[][..], &mut accounts)
+                .is_err()
+        );
+    }
+}
@@ -27,9 +27,9 @@ impl<'info, T: SysvarId> SysvarAccount<'info, T> {
         context: &'info FankorContext<'info>,
         info: &'info AccountInfo<'info>,
     ) -> FankorResult<SysvarAccount<'info, T>> {
-        if info.owner == &T::id() {
+        if !T::check_id(info.key) {
             return Err(FankorErrorCode::IncorrectSysvarAccount {
-                actual: *info.owner,
+                actual: *info.key,
                 expected: T::id(),
             }
             .into());
@@ -3,13 +3,14 @@ use std::fmt::{Debug, Formatter};
 
 use solana_program::account_info::AccountInfo;
 use solana_program::clock::Epoch;
+use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_program::rent::Rent;
 use solana_program::system_program;
 use solana_program::sysvar::Sysvar;
 
 use crate::cpi;
-use crate::cpi::system_program::CpiCreateAccount;
+use crate::cpi::system_program::{CpiAllocate, CpiAssign, CpiCreateAccount, CpiTransfer};
 use crate::errors::{FankorErrorCode, FankorResult};
 use crate::models::{Account, FankorContext, Program, System};
 use crate::traits::{
@@ -17,6 +18,64 @@ use crate::traits::{
     SingleInstructionAccount,
 };
 
+/// A source of lamports to pay the rent of an account initialized with
+/// [UninitializedAccount::init_funded].
+pub struct RentFunder<'a, 'info> {
+    pub info: &'a AccountInfo<'info>,
+
+    /// The seeds to sign the transfer when the funder is a PDA owned by the
+    /// system program. Empty otherwise.
+    pub seeds: &'a [&'a [u8]],
+
+    /// The maximum amount of lamports the funder contributes, if any.
+    pub max_lamports: Option<u64>,
+}
+
+impl<'a, 'info> RentFunder<'a, 'info> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(info: &'a AccountInfo<'info>) -> RentFunder<'a, 'info> {
+        RentFunder {
+            info,
+            seeds: &[],
+            max_lamports: None,
+        }
+    }
+
+    pub fn new_pda(info: &'a AccountInfo<'info>, seeds: &'a [&'a [u8]]) -> RentFunder<'a, 'info> {
+        RentFunder {
+            info,
+            seeds,
+            max_lamports: None,
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Limits the amount of lamports the funder contributes.
+    pub fn with_max_lamports(mut self, max_lamports: u64) -> RentFunder<'a, 'info> {
+        self.max_lamports = Some(max_lamports);
+        self
+    }
+
+    /// The lamports the funder can contribute while remaining rent-exempt.
+    fn available_lamports(&self, rent: &Rent) -> u64 {
+        let available = self
+            .info
+            .lamports()
+            .saturating_sub(rent.minimum_balance(self.info.data_len()));
+
+        match self.max_lamports {
+            Some(max_lamports) => available.min(max_lamports),
+            None => available,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 /// Wrapper for `AccountInfo` to explicitly define an uninitialized account.
 pub struct UninitializedAccount<'info> {
     context: &'info FankorContext<'info>,
@@ -136,18 +195,18 @@ impl<'info> UninitializedAccount<'info> {
     }
 
     /// Initializes the account transferring the necessary lamports to cover the rent
-    /// for the minimum space to contain the smallest value of `T`
+    /// for the minimum space to contain any value of `T` with empty dynamic parts
     /// using `payer` as the funding account.
     pub fn init_with_min_space<T: Default + AccountType + CopyType<'info>>(
         self,
         payer: &AccountInfo<'info>,
         system_program: &Program<System>,
     ) -> FankorResult<Account<'info, T>> {
-        self.init(T::min_byte_size(), payer, system_program)
+        self.init(T::min_account_size(), payer, system_program)
     }
 
     /// Initializes the PDA account transferring the necessary lamports to cover the rent
-    /// for the minimum space to contain the smallest value of `T`
+    /// for the minimum space to contain any value of `T` with empty dynamic parts
     /// using `payer` as the funding account.
     pub fn init_pda_with_min_space<T: Default + AccountType + CopyType<'info>>(
         self,
@@ -155,7 +214,7 @@ impl<'info> UninitializedAccount<'info> {
         payer: &AccountInfo<'info>,
         system_program: &Program<System>,
     ) -> FankorResult<Account<'info, T>> {
-        self.init_pda(T::min_byte_size(), seeds, payer, system_program)
+        self.init_pda(T::min_account_size(), seeds, payer, system_program)
     }
 
     /// Initializes the account transferring the necessary lamports to cover the rent
@@ -212,6 +271,234 @@ impl<'info> UninitializedAccount<'info> {
 
         Ok(Account::new_unchecked(self.context, self.info, value))
     }
+
+    /// Initializes the PDA account transferring the necessary lamports to cover the rent
+    /// for the given `space` from `vault`.
+    ///
+    /// Unlike [init_pda](Self::init_pda), the vault can be an account owned by the
+    /// program with data, because the account is created by `allocate` + `assign`
+    /// instead of `create_account`. `vault_seeds` are only required when the vault is
+    /// a PDA owned by the system program.
+    pub fn init_pda_from_vault<T: Default + AccountType>(
+        self,
+        space: usize,
+        seeds: &[&[u8]],
+        vault: &AccountInfo<'info>,
+        vault_seeds: &[&[u8]],
+        system_program: &Program<System>,
+    ) -> FankorResult<Account<'info, T>> {
+        self.create_from_vault(space, seeds, vault, vault_seeds, system_program)?;
+
+        Ok(Account::new_unchecked(
+            self.context,
+            self.info,
+            T::default(),
+        ))
+    }
+
+    /// Initializes the PDA account transferring the necessary lamports to cover the rent
+    /// for the required space to contain `value` from `vault`.
+    ///
+    /// See [init_pda_from_vault](Self::init_pda_from_vault).
+    pub fn init_pda_with_value_from_vault<T: AccountType + CopyType<'info>>(
+        self,
+        value: T,
+        seeds: &[&[u8]],
+        vault: &AccountInfo<'info>,
+        vault_seeds: &[&[u8]],
+        system_program: &Program<System>,
+    ) -> FankorResult<Account<'info, T>> {
+        let space = value.byte_size();
+        self.create_from_vault(space, seeds, vault, vault_seeds, system_program)?;
+
+        Ok(Account::new_unchecked(self.context, self.info, value))
+    }
+
+    /// Initializes the account transferring the necessary lamports to cover the rent
+    /// for the given `space` from `funders` in order, e.g. a protocol vault first and
+    /// then the user. Each funder contributes as much as it can while remaining
+    /// rent-exempt, up to its `max_lamports`.
+    ///
+    /// The account is created by `allocate` + `assign`, so `seeds` are required when
+    /// it is a PDA and must be empty when it is a signer.
+    pub fn init_funded<T: Default + AccountType>(
+        self,
+        space: usize,
+        seeds: &[&[u8]],
+        funders: &[RentFunder<'_, 'info>],
+        system_program: &Program<System>,
+    ) -> FankorResult<Account<'info, T>> {
+        self.create_funded(space, seeds, funders, system_program)?;
+
+        Ok(Account::new_unchecked(
+            self.context,
+            self.info,
+            T::default(),
+        ))
+    }
+
+    /// Initializes the account transferring the necessary lamports to cover the rent
+    /// for the required space to contain `value` from `funders` in order.
+    ///
+    /// See [init_funded](Self::init_funded).
+    pub fn init_funded_with_value<T: AccountType + CopyType<'info>>(
+        self,
+        value: T,
+        seeds: &[&[u8]],
+        funders: &[RentFunder<'_, 'info>],
+        system_program: &Program<System>,
+    ) -> FankorResult<Account<'info, T>> {
+        let space = value.byte_size();
+        self.create_funded(space, seeds, funders, system_program)?;
+
+        Ok(Account::new_unchecked(self.context, self.info, value))
+    }
+
+    fn create_from_vault(
+        &self,
+        space: usize,
+        seeds: &[&[u8]],
+        vault: &AccountInfo<'info>,
+        vault_seeds: &[&[u8]],
+        system_program: &Program<System>,
+    ) -> FankorResult<()> {
+        let rent = Rent::get()?;
+        let lamports = rent.minimum_balance(space);
+
+        // Fund the account first so that it is rent-exempt during the whole process.
+        self.fund_from(vault, vault_seeds, lamports, &rent, system_program)?;
+        self.allocate_and_assign(space, seeds, system_program)
+    }
+
+    fn create_funded(
+        &self,
+        space: usize,
+        seeds: &[&[u8]],
+        funders: &[RentFunder<'_, 'info>],
+        system_program: &Program<System>,
+    ) -> FankorResult<()> {
+        let rent = Rent::get()?;
+        let required = rent.minimum_balance(space);
+        let available = funders
+            .iter()
+            .map(|v| v.available_lamports(&rent))
+            .collect::<Vec<_>>();
+
+        let contributions = split_rent(required, &available).ok_or_else(|| {
+            FankorErrorCode::InsufficientRentFunding {
+                address: *self.info.key,
+                required,
+                funded: available.iter().fold(0u64, |acc, v| acc.saturating_add(*v)),
+            }
+        })?;
+
+        // Fund the account first so that it is rent-exempt during the whole process.
+        for (funder, lamports) in funders.iter().zip(contributions) {
+            if lamports > 0 {
+                self.fund_from(funder.info, funder.seeds, lamports, &rent, system_program)?;
+            }
+        }
+
+        self.allocate_and_assign(space, seeds, system_program)
+    }
+
+    fn fund_from(
+        &self,
+        funder: &AccountInfo<'info>,
+        funder_seeds: &[&[u8]],
+        lamports: u64,
+        rent: &Rent,
+        system_program: &Program<System>,
+    ) -> FankorResult<()> {
+        if !funder.is_writable {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *funder.key,
+                action: "fund account",
+            }
+            .into());
+        }
+
+        if funder.owner == self.context.program_id() {
+            let funder_lamports = funder
+                .lamports()
+                .checked_sub(lamports)
+                .ok_or(ProgramError::InsufficientFunds)?;
+            let info_lamports = self
+                .info
+                .lamports()
+                .checked_add(lamports)
+                .ok_or(FankorErrorCode::ArithmeticOverflow { operation: "add" })?;
+
+            // The vault must remain rent-exempt.
+            if funder_lamports < rent.minimum_balance(funder.data_len()) {
+                return Err(FankorErrorCode::AccountNotRentExempt {
+                    account: *funder.key,
+                }
+                .into());
+            }
+
+            **funder.lamports.borrow_mut() = funder_lamports;
+            **self.info.lamports.borrow_mut() = info_lamports;
+
+            Ok(())
+        } else {
+            cpi::system_program::transfer(
+                system_program,
+                CpiTransfer {
+                    from: funder.clone(),
+                    to: self.info.clone(),
+                },
+                lamports,
+                &[funder_seeds],
+            )
+        }
+    }
+
+    fn allocate_and_assign(
+        &self,
+        space: usize,
+        seeds: &[&[u8]],
+        system_program: &Program<System>,
+    ) -> FankorResult<()> {
+        cpi::system_program::allocate(
+            system_program,
+            CpiAllocate {
+                account_to_allocate: self.info.clone(),
+            },
+            space as u64,
+            &[seeds],
+        )?;
+
+        cpi::system_program::assign(
+            system_program,
+            CpiAssign {
+                account_to_assign: self.info.clone(),
+            },
+            self.context.program_id(),
+            &[seeds],
+        )
+    }
+}
+
+/// Splits `required` lamports among funders with the given `available` lamports,
+/// taking as much as possible from the first ones. Returns `None` if they cannot
+/// cover it.
+fn split_rent(required: u64, available: &[u64]) -> Option<Vec<u64>> {
+    let mut remaining = required;
+    let contributions = available
+        .iter()
+        .map(|available| {
+            let lamports = remaining.min(*available);
+            remaining -= lamports;
+            lamports
+        })
+        .collect();
+
+    if remaining > 0 {
+        return None;
+    }
+
+    Some(contributions)
 }
 
 impl<'info> Instruction<'info> for UninitializedAccount<'info> {
@@ -266,3 +553,86 @@ impl<'info> Debug for UninitializedAccount<'info> {
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[test]
+    fn test_fund_from_program_owned() {
+        let lamports = Box::leak(Box::new(u64::MAX));
+        let data = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let mut info = create_account_info_for_tests(lamports, data);
+        info.key = Box::leak(Box::new(Pubkey::new_unique()));
+        info.is_writable = true;
+
+        let rent = Rent::default();
+        let vault_minimum_balance = rent.minimum_balance(8);
+        let vault_lamports = Box::leak(Box::new(vault_minimum_balance + 100));
+        let vault_data = Box::leak(vec![0u8; 8].into_boxed_slice());
+        let mut vault = create_account_info_for_tests(vault_lamports, vault_data);
+        vault.key = Box::leak(Box::new(Pubkey::new_unique()));
+        vault.owner = &PROGRAM_ID;
+        vault.is_writable = true;
+
+        let system_lamports = Box::leak(Box::new(1));
+        let system_data = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let mut system = create_account_info_for_tests(system_lamports, system_data);
+        system.key = &system_program::ID;
+        system.executable = true;
+
+        let infos = Box::leak(Box::new([info, vault, system]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(&PROGRAM_ID, infos)));
+        let system_program = Program::<System>::new(context, &infos[2]).unwrap();
+        let account = UninitializedAccount {
+            context,
+            info: &infos[0],
+        };
+
+        // The lamports of the account overflow.
+        assert_eq!(
+            account.fund_from(&infos[1], &[], 10, &rent, &system_program),
+            Err(FankorErrorCode::ArithmeticOverflow { operation: "add" }.into())
+        );
+        assert_eq!(infos[1].lamports(), vault_minimum_balance + 100);
+
+        **infos[0].lamports.borrow_mut() = 0;
+        assert!(account.fund_from(&infos[1], &[], 10, &rent, &system_program).is_ok());
+        assert_eq!(infos[0].lamports(), 10);
+        assert_eq!(infos[1].lamports(), vault_minimum_balance + 90);
+
+        // The vault would not be rent-exempt.
+        assert_eq!(
+            account.fund_from(&infos[1], &[], 91, &rent, &system_program),
+            Err(FankorErrorCode::AccountNotRentExempt {
+                account: *infos[1].key
+            }
+            .into())
+        );
+
+        // The vault cannot cover the lamports.
+        assert_eq!(
+            account.fund_from(&infos[1], &[], u64::MAX, &rent, &system_program),
+            Err(ProgramError::InsufficientFunds.into())
+        );
+        assert_eq!(infos[1].lamports(), vault_minimum_balance + 90);
+    }
+
+    #[test]
+    fn test_split_rent() {
+        assert_eq!(split_rent(100, &[60, 80]), Some(vec![60, 40]));
+        assert_eq!(split_rent(100, &[150, 80]), Some(vec![100, 0]));
+        assert_eq!(split_rent(100, &[0, 100]), Some(vec![0, 100]));
+        assert_eq!(split_rent(0, &[10]), Some(vec![0]));
+        assert_eq!(split_rent(100, &[30, 30]), None);
+        assert_eq!(split_rent(100, &[]), None);
+    }
+}
@@ -0,0 +1,104 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::hash::{hash, Hash};
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+
+/// A snapshot of the owner, lamports and data of an account used to verify
+/// it has not been modified in between, e.g. by an untrusted CPI.
+///
+/// Use the [checkpoint](crate::macros::checkpoint) and
+/// [assert_unchanged](crate::macros::assert_unchanged) macros instead of
+/// creating it manually.
+#[derive(Debug, Clone)]
+pub struct AccountCheckpoint<'info> {
+    info: &'info AccountInfo<'info>,
+    owner: Pubkey,
+    lamports: u64,
+    data_hash: Hash,
+}
+
+impl<'info> AccountCheckpoint<'info> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Takes a checkpoint of the current state of the account.
+    pub fn new(info: &'info AccountInfo<'info>) -> FankorResult<AccountCheckpoint<'info>> {
+        Ok(AccountCheckpoint {
+            info,
+            owner: *info.owner,
+            lamports: info.lamports(),
+            data_hash: hash(&info.try_borrow_data()?),
+        })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    pub fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+
+    pub fn lamports(&self) -> u64 {
+        self.lamports
+    }
+
+    pub fn data_hash(&self) -> &Hash {
+        &self.data_hash
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Whether the account is in the same state as when the checkpoint was
+    /// taken.
+    pub fn is_unchanged(&self) -> FankorResult<bool> {
+        Ok(self.info.owner == &self.owner
+            && self.info.lamports() == self.lamports
+            && hash(&self.info.try_borrow_data()?) == self.data_hash)
+    }
+
+    /// Fails if the account has been modified since the checkpoint was taken.
+    pub fn verify(&self) -> FankorResult<()> {
+        if !self.is_unchanged()? {
+            return Err(FankorErrorCode::AccountModifiedAfterCheckpoint {
+                address: *self.info.key,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        let mut lamports = 10;
+        let mut vector = vec![1, 2, 3];
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+
+        let checkpoint = AccountCheckpoint::new(&info).unwrap();
+        assert_eq!(checkpoint.data_hash(), &hash(&[1, 2, 3]));
+        assert!(checkpoint.verify().is_ok());
+
+        info.try_borrow_mut_data().unwrap()[1] = 5;
+        assert!(checkpoint.verify().is_err());
+
+        info.try_borrow_mut_data().unwrap()[1] = 2;
+        assert!(checkpoint.verify().is_ok());
+
+        **info.try_borrow_mut_lamports().unwrap() = 11;
+        assert!(checkpoint.verify().is_err());
+    }
+}
@@ -3,10 +3,18 @@ use std::collections::BTreeMap;
 use std::rc::Rc;
 
 use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::instruction::AccountMeta;
+use solana_program::program::invoke_signed;
 use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::{
+    Account, AccountLoadStats, PdaSeeds, PreFundedUninitializedAccount, Program, System,
+};
 use crate::prelude::byte_seeds_to_slices;
+use crate::traits::{AccountType, Instruction};
 
 #[derive(Clone)]
 pub struct FankorContext<'info> {
@@ -16,6 +24,10 @@ pub struct FankorContext<'info> {
     /// The list of all accounts passed to the instruction.
     accounts: &'info [AccountInfo<'info>],
 
+    /// The lamports of each account when the instruction started.
+    #[cfg(feature = "debug")]
+    initial_lamports: Rc<Vec<u64>>,
+
     /// The reference to the mutable part of the context.
     inner: Rc<RefCell<FankorContextInnerMut<'info>>>,
 }
@@ -24,6 +36,16 @@ struct FankorContextInnerMut<'info> {
     // Data for each account.
     // The key is u8 because the maximum number of accounts per transaction is 256.
     account_data: BTreeMap<u8, FankorContextAccountData<'info>>,
+
+    // Name of the instruction being executed.
+    instruction_name: Option<&'static str>,
+
+    // Programs that can be invoked through generic CPIs, if restricted.
+    cpi_allowlist: Option<&'static [Pubkey]>,
+
+    // Clock returned instead of the sysvar one in tests.
+    #[cfg(any(test, feature = "test-utils"))]
+    mocked_clock: Option<Clock>,
 }
 
 struct FankorContextAccountData<'info> {
@@ -32,6 +54,9 @@ struct FankorContextAccountData<'info> {
 
     // Seeds used to derived the account.
     seeds: Option<Rc<Vec<u8>>>,
+
+    // Length of each seed component when they are known.
+    seed_lengths: Option<Rc<Vec<usize>>>,
 }
 
 /// The action to perform at the end of the instruction for a specific account.
@@ -75,8 +100,14 @@ impl<'info> FankorContext<'info> {
         Self {
             program_id,
             accounts,
+            #[cfg(feature = "debug")]
+            initial_lamports: Rc::new(accounts.iter().map(|v| v.lamports()).collect()),
             inner: Rc::new(RefCell::new(FankorContextInnerMut {
                 account_data: Default::default(),
+                instruction_name: None,
+                cpi_allowlist: None,
+                #[cfg(any(test, feature = "test-utils"))]
+                mocked_clock: None,
             })),
         }
     }
@@ -91,8 +122,157 @@ impl<'info> FankorContext<'info> {
         self.accounts
     }
 
+    /// The name of the instruction being executed, if it was dispatched by
+    /// the program.
+    pub fn instruction_name(&self) -> Option<&'static str> {
+        self.inner.borrow().instruction_name
+    }
+
+    /// The programs that can be invoked through generic CPIs, declared with
+    /// [cpi_allowlist](crate::cpi_allowlist). `None` means any program.
+    pub fn cpi_allowlist(&self) -> Option<&'static [Pubkey]> {
+        self.inner.borrow().cpi_allowlist
+    }
+
+    /// The current [Clock] sysvar, or the mocked one if it was set with
+    /// [set_mocked_clock](Self::set_mocked_clock).
+    pub fn clock(&self) -> FankorResult<Clock> {
+        #[cfg(any(test, feature = "test-utils"))]
+        if let Some(clock) = &self.inner.borrow().mocked_clock {
+            return Ok(clock.clone());
+        }
+
+        Ok(Clock::get()?)
+    }
+
+    /// The difference between the current lamports of `account` and the ones
+    /// it had when the instruction started, i.e. its net lamport flow.
+    #[cfg(feature = "debug")]
+    pub fn lamport_delta(&self, account: &AccountInfo<'info>) -> i128 {
+        let index = self.get_index_for_account(account) as usize;
+        account.lamports() as i128 - self.initial_lamports[index] as i128
+    }
+
+    /// The net lamport flow of every account whose lamports have changed since
+    /// the instruction started. Duplicated accounts are included only once.
+    #[cfg(feature = "debug")]
+    pub fn lamport_deltas(&self) -> Vec<(&'info Pubkey, i128)> {
+        let mut result = Vec::new();
+
+        for (index, account) in self.accounts.iter().enumerate() {
+            if self.get_index_for_account(account) as usize != index {
+                continue;
+            }
+
+            let delta = account.lamports() as i128 - self.initial_lamports[index] as i128;
+            if delta != 0 {
+                result.push((account.key, delta));
+            }
+        }
+
+        result
+    }
+
+    /// The number of accounts and the size of their data loaded by the
+    /// instruction.
+    pub fn account_load_stats(&self) -> AccountLoadStats {
+        AccountLoadStats::from_accounts(self.accounts)
+    }
+
     // METHODS ----------------------------------------------------------------
 
+    /// Logs the [account_load_stats](Self::account_load_stats) of the
+    /// instruction warning when they are close to the transaction limits.
+    ///
+    /// It is called by the program at the start of each instruction and only
+    /// logs when the `debug` feature is enabled.
+    pub fn log_account_load_stats(&self) {
+        #[cfg(feature = "debug")]
+        {
+            use crate::models::{MAX_LOADED_ACCOUNTS_DATA_SIZE, MAX_TRANSACTION_ACCOUNTS};
+
+            let stats = self.account_load_stats();
+            solana_program::msg!(
+                "Loaded accounts: {} ({} unique) of {}, data size: {} of {} bytes",
+                stats.accounts,
+                stats.unique_accounts,
+                MAX_TRANSACTION_ACCOUNTS,
+                stats.data_size,
+                MAX_LOADED_ACCOUNTS_DATA_SIZE
+            );
+
+            if stats.is_close_to_account_limit() {
+                solana_program::msg!(" Warning: close to the transaction account limit");
+            }
+
+            if stats.is_close_to_data_limit() {
+                solana_program::msg!(" Warning: close to the loaded accounts data size limit");
+            }
+        }
+    }
+
+    /// Logs the net lamport flow of each account and whether the instruction
+    /// is unbalanced, i.e. lamports have been created or destroyed, which the
+    /// runtime rejects with `UnbalancedInstruction`.
+    ///
+    /// It is called by the program at the end of each instruction and only
+    /// logs when the `debug` feature is enabled.
+    pub fn log_lamport_report(&self) {
+        #[cfg(feature = "debug")]
+        {
+            let deltas = self.lamport_deltas();
+            if deltas.is_empty() {
+                return;
+            }
+
+            let mut total = 0i128;
+            solana_program::msg!("Lamport report:");
+
+            for (address, delta) in deltas {
+                solana_program::msg!(" - {}: {:+}", address, delta);
+                total += delta;
+            }
+
+            if total != 0 {
+                solana_program::msg!(" Unbalanced instruction: net {:+} lamports", total);
+            }
+        }
+    }
+
+    /// Replaces the clock returned by [clock](Self::clock) so that
+    /// time-dependent logic can be tested deterministically. `None` restores
+    /// the sysvar one.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn set_mocked_clock(&self, clock: Option<Clock>) {
+        self.inner.borrow_mut().mocked_clock = clock;
+    }
+
+    /// Sets the programs that can be invoked through generic CPIs.
+    pub fn set_cpi_allowlist_unchecked(&self, allowlist: Option<&'static [Pubkey]>) {
+        self.inner.borrow_mut().cpi_allowlist = allowlist;
+    }
+
+    /// Fails if `program_id` cannot be invoked through generic CPIs because
+    /// it is not in the CPI allowlist.
+    pub fn check_cpi_program(&self, program_id: &Pubkey) -> FankorResult<()> {
+        match self.cpi_allowlist() {
+            Some(allowlist) if !allowlist.contains(program_id) => {
+                Err(FankorErrorCode::CpiProgramNotAllowed {
+                    program: *program_id,
+                }
+                .into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Sets the name of the instruction being executed.
+    ///
+    /// This method is intended to be used only by the framework.
+    pub fn set_instruction_name_unchecked(&self, name: &'static str) {
+        self.inner.borrow_mut().instruction_name = Some(name);
+    }
+
     /// Gets the corresponding account info for the given account key.
     pub fn get_account_from_address(&self, address: &Pubkey) -> Option<&AccountInfo<'info>> {
         self.accounts.iter().find(|account| account.key == address)
@@ -108,6 +288,28 @@ impl<'info> FankorContext<'info> {
             .and_then(|v| v.seeds.clone())
     }
 
+    /// Gets the corresponding seed components and bump for an account if it was
+    /// previously computed. If the components are unknown, the seeds are split
+    /// in components of the max seed length.
+    pub fn get_pda_seeds_for_account(&self, account: &AccountInfo<'info>) -> Option<PdaSeeds> {
+        let index = self.get_index_for_account(account);
+        let inner = self.inner.borrow();
+        let data = inner.account_data.get(&index)?;
+        let seeds = data.seeds.clone().filter(|v| !v.is_empty())?;
+        let lengths = data.seed_lengths.clone().unwrap_or_else(|| {
+            // Without the components, split the seeds in chunks of the max
+            // seed length like the CPIs do.
+            Rc::new(
+                byte_seeds_to_slices(&seeds[..seeds.len() - 1])
+                    .iter()
+                    .map(|v| v.len())
+                    .collect(),
+            )
+        });
+
+        Some(PdaSeeds::new_unchecked(seeds, lengths))
+    }
+
     pub(crate) fn get_index_for_account(&self, account: &AccountInfo<'info>) -> u8 {
         self.accounts
             .iter()
@@ -153,6 +355,7 @@ impl<'info> FankorContext<'info> {
                     FankorContextAccountData {
                         exit_action: Some(exit_action),
                         seeds: None,
+                        seed_lengths: None,
                     },
                 );
             }
@@ -181,13 +384,17 @@ impl<'info> FankorContext<'info> {
         let mut inner = (*self.inner).borrow_mut();
 
         match inner.account_data.get_mut(&index) {
-            Some(v) => v.seeds = Some(seeds),
+            Some(v) => {
+                v.seeds = Some(seeds);
+                v.seed_lengths = None;
+            }
             None => {
                 inner.account_data.insert(
                     index,
                     FankorContextAccountData {
                         exit_action: None,
                         seeds: Some(seeds),
+                        seed_lengths: None,
                     },
                 );
             }
@@ -246,13 +453,17 @@ impl<'info> FankorContext<'info> {
 
         let mut inner = (*self.inner).borrow_mut();
         match inner.account_data.get_mut(&index) {
-            Some(v) => v.seeds = Some(Rc::new(seeds)),
+            Some(v) => {
+                v.seeds = Some(Rc::new(seeds));
+                v.seed_lengths = None;
+            }
             None => {
                 inner.account_data.insert(
                     index,
                     FankorContextAccountData {
                         exit_action: None,
                         seeds: Some(Rc::new(seeds)),
+                        seed_lengths: None,
                     },
                 );
             }
@@ -260,4 +471,351 @@ impl<'info> FankorContext<'info> {
 
         Ok(())
     }
+
+    /// Checks whether the given account is a canonical PDA with the given seed
+    /// components and program_id. Unlike [check_canonical_pda_with_program](Self::check_canonical_pda_with_program),
+    /// it keeps the components so that they can be retrieved later with
+    /// [get_pda_seeds_for_account](Self::get_pda_seeds_for_account).
+    pub fn check_canonical_pda_with_components(
+        &self,
+        account: &AccountInfo<'info>,
+        components: &[&[u8]],
+        program_id: &Pubkey,
+    ) -> FankorResult<()> {
+        let seeds_length = components.iter().map(|v| v.len()).sum::<usize>();
+        let mut seeds = Vec::with_capacity(seeds_length + 1 /* bump */);
+        for component in components {
+            seeds.extend_from_slice(component);
+        }
+
+        self.check_canonical_pda_with_program(account, seeds, program_id)?;
+        self.set_seed_components_for_account(account, components);
+
+        Ok(())
+    }
+
+    /// Keeps the length of the seed components of an account whose seeds are
+    /// already in the context.
+    fn set_seed_components_for_account(
+        &self,
+        account: &AccountInfo<'info>,
+        components: &[&[u8]],
+    ) {
+        let index = self.get_index_for_account(account);
+        let mut inner = (*self.inner).borrow_mut();
+        if let Some(v) = inner.account_data.get_mut(&index) {
+            v.seed_lengths = Some(Rc::new(components.iter().map(|v| v.len()).collect()));
+        }
+    }
+
+    /// Gets the signer seeds of the accounts of `metas` that must sign and are
+    /// PDAs of the current program verified during the validation, skipping
+    /// those that are already signers of the transaction.
+    pub fn get_pda_signer_seeds(&self, metas: &[AccountMeta]) -> Vec<Vec<Vec<u8>>> {
+        let mut result = Vec::new();
+        let mut signers = Vec::new();
+
+        for meta in metas {
+            if !meta.is_signer || signers.contains(&meta.pubkey) {
+                continue;
+            }
+
+            let info = match self.get_account_from_address(&meta.pubkey) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            if info.is_signer {
+                continue;
+            }
+
+            let seeds = match self.get_pda_seeds_for_account(info) {
+                Some(v) => v,
+                None => continue,
+            };
+            let signer_seeds = seeds.signer_seeds();
+
+            // The seeds can belong to a PDA of another program.
+            match Pubkey::create_program_address(&signer_seeds, self.program_id) {
+                Ok(address) if address == meta.pubkey => {}
+                _ => continue,
+            }
+
+            signers.push(meta.pubkey);
+            result.push(signer_seeds.into_iter().map(|v| v.to_vec()).collect());
+        }
+
+        result
+    }
+
+    /// Invokes `instruction` signing with `signer_seeds` and the seeds of the
+    /// PDAs of the current program that must sign it, so that nested CPI
+    /// structures do not need to thread them manually.
+    ///
+    /// Fails if the program is not in the CPI allowlist.
+    pub fn invoke_signed_with_pdas(
+        &self,
+        instruction: &solana_program::instruction::Instruction,
+        infos: &[AccountInfo<'info>],
+        signer_seeds: &[&[&[u8]]],
+    ) -> FankorResult<()> {
+        self.check_cpi_program(&instruction.program_id)?;
+
+        let pda_seeds = self.get_pda_signer_seeds(&instruction.accounts);
+        let pda_seeds = pda_seeds
+            .iter()
+            .map(|v| v.iter().map(|v| v.as_slice()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let mut all_seeds = signer_seeds.to_vec();
+        all_seeds.extend(pda_seeds.iter().map(|v| v.as_slice()));
+
+        invoke_signed(instruction, infos, &all_seeds)?;
+
+        Ok(())
+    }
+
+    /// Gets the PDA account of type `T` derived from `seeds`, without the bump,
+    /// creating and initializing it with the default value of `T` and the given
+    /// `space` using `payer` as the funding account if it does not exist.
+    ///
+    /// The PDA must be among the accounts of the instruction and, if it
+    /// exists, it is checked like any other `Account<T>`. It is created even
+    /// if it already holds lamports, e.g. because anyone transferred them to
+    /// its address, so that it cannot be blocked: only the missing lamports to
+    /// cover the rent are transferred from `payer`.
+    pub fn ensure_pda_account<T: Default + AccountType>(
+        &'info self,
+        seeds: &[&[u8]],
+        space: usize,
+        payer: &AccountInfo<'info>,
+        system_program: &Program<System>,
+    ) -> FankorResult<Account<'info, T>> {
+        let (address, bump) = Pubkey::find_program_address(seeds, self.program_id);
+        let info = self.get_account_from_address(&address).ok_or_else(|| {
+            FankorErrorCode::ReferencedAccountNotFound {
+                address,
+                account_type: std::any::type_name::<T>(),
+            }
+        })?;
+
+        // The address is already derived from the seeds, so they are saved
+        // without checking them again.
+        let mut pda_seeds = seeds.concat();
+        pda_seeds.push(bump);
+        self.set_seeds_for_account_unchecked(info, Rc::new(pda_seeds));
+        self.set_seed_components_for_account(info, seeds);
+
+        if info.owner != &solana_program::system_program::ID || !info.data_is_empty() {
+            let mut accounts = std::slice::from_ref(info);
+            return <Account<T> as Instruction>::try_from(self, &mut &[][..], &mut accounts);
+        }
+
+        let bump = [bump];
+        let mut signer_seeds = seeds.to_vec();
+        signer_seeds.push(&bump);
+
+        PreFundedUninitializedAccount::new(self, info)?.init_pda::<T>(
+            space,
+            &signer_seeds,
+            payer,
+            system_program,
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+    struct Config {
+        value: u8,
+    }
+
+    impl AccountType for Config {
+        fn discriminant() -> u8 {
+            0
+        }
+
+        fn owner() -> &'static Pubkey {
+            &PROGRAM_ID
+        }
+    }
+
+    #[test]
+    fn test_get_pda_signer_seeds() {
+        let program_id = Box::leak(Box::new(Pubkey::new_unique()));
+        let (pda, bump) = Pubkey::find_program_address(&[b"vault".as_ref()], program_id);
+
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let mut info = create_account_info_for_tests(lamports, data);
+        info.key = Box::leak(Box::new(pda));
+
+        let infos = Box::leak(Box::new([info]));
+        let context = FankorContext::new_unchecked(program_id, infos);
+
+        // Not verified yet.
+        assert!(context
+            .get_pda_signer_seeds(&[AccountMeta::new(pda, true)])
+            .is_empty());
+
+        context
+            .check_canonical_pda_with_components(&infos[0], &[b"vault".as_ref()], program_id)
+            .unwrap();
+
+        assert_eq!(
+            context.get_pda_signer_seeds(&[
+                AccountMeta::new(pda, true),
+                AccountMeta::new_readonly(pda, true)
+            ]),
+            vec![vec![b"vault".to_vec(), vec![bump]]]
+        );
+        assert!(context
+            .get_pda_signer_seeds(&[AccountMeta::new(pda, false)])
+            .is_empty());
+
+        // Empty seeds are skipped.
+        context.set_seeds_for_account_unchecked(&infos[0], Rc::new(vec![]));
+        assert!(context
+            .get_pda_signer_seeds(&[AccountMeta::new(pda, true)])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_get_pda_seeds_for_account_without_components() {
+        let program_id = Box::leak(Box::new(Pubkey::new_unique()));
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let infos = Box::leak(Box::new([create_account_info_for_tests(lamports, data)]));
+        let context = FankorContext::new_unchecked(program_id, infos);
+
+        let mut seeds = vec![7; 40];
+        seeds.push(255);
+        context.set_seeds_for_account_unchecked(&infos[0], Rc::new(seeds));
+
+        let seeds = context.get_pda_seeds_for_account(&infos[0]).unwrap();
+        assert_eq!(seeds.components(), vec![&[7; 32][..], &[7; 8][..]]);
+        assert_eq!(seeds.bump(), 255);
+    }
+
+    #[test]
+    #[cfg(feature = "debug")]
+    fn test_lamport_delta() {
+        let lamports_a = Box::leak(Box::new(100));
+        let data_a = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let mut info_a = create_account_info_for_tests(lamports_a, data_a);
+        info_a.key = Box::leak(Box::new(Pubkey::new_unique()));
+
+        let lamports_b = Box::leak(Box::new(50));
+        let data_b = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let mut info_b = create_account_info_for_tests(lamports_b, data_b);
+        info_b.key = Box::leak(Box::new(Pubkey::new_unique()));
+
+        let infos = Box::leak(Box::new([info_a, info_b]));
+        let context = FankorContext::new_unchecked(&PROGRAM_ID, infos);
+
+        assert_eq!(context.lamport_delta(&infos[0]), 0);
+        assert!(context.lamport_deltas().is_empty());
+
+        **infos[0].lamports.borrow_mut() -= 30;
+        **infos[1].lamports.borrow_mut() += 30;
+
+        assert_eq!(context.lamport_delta(&infos[0]), -30);
+        assert_eq!(context.lamport_delta(&infos[1]), 30);
+        assert_eq!(
+            context.lamport_deltas(),
+            vec![(infos[0].key, -30), (infos[1].key, 30)]
+        );
+    }
+
+    #[test]
+    fn test_account_load_stats() {
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(vec![0u8; 8].into_boxed_slice());
+        let mut info = create_account_info_for_tests(lamports, data);
+        info.key = Box::leak(Box::new(Pubkey::new_unique()));
+
+        let duplicated = info.clone();
+        let infos = Box::leak(Box::new([info, duplicated]));
+        let context = FankorContext::new_unchecked(&PROGRAM_ID, infos);
+
+        assert_eq!(
+            context.account_load_stats(),
+            AccountLoadStats {
+                accounts: 2,
+                unique_accounts: 1,
+                data_size: 8,
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_cpi_program() {
+        let allowed = Pubkey::new_unique();
+        let allowlist = Box::leak(Box::new([allowed]));
+        let context = FankorContext::new_unchecked(&PROGRAM_ID, &[]);
+
+        assert!(context.check_cpi_program(&Pubkey::new_unique()).is_ok());
+
+        context.set_cpi_allowlist_unchecked(Some(allowlist));
+        assert!(context.check_cpi_program(&allowed).is_ok());
+        assert_eq!(
+            context.check_cpi_program(&PROGRAM_ID),
+            Err(FankorErrorCode::CpiProgramNotAllowed {
+                program: PROGRAM_ID
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn test_ensure_pda_account_existing() {
+        let (pda, bump) = Pubkey::find_program_address(&[b"config".as_ref()], &PROGRAM_ID);
+
+        let lamports = Box::leak(Box::new(1));
+        let data = Box::leak(vec![7u8].into_boxed_slice());
+        let mut info = create_account_info_for_tests(lamports, data);
+        info.key = Box::leak(Box::new(pda));
+        info.owner = &PROGRAM_ID;
+
+        let payer_lamports = Box::leak(Box::new(0));
+        let payer_data = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let payer = create_account_info_for_tests(payer_lamports, payer_data);
+
+        let system_lamports = Box::leak(Box::new(1));
+        let system_data = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let mut system = create_account_info_for_tests(system_lamports, system_data);
+        system.key = &solana_program::system_program::ID;
+        system.executable = true;
+
+        let infos = Box::leak(Box::new([info, payer, system]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(&PROGRAM_ID, infos)));
+        let system_program = Program::<System>::new(context, &infos[2]).unwrap();
+
+        // The PDA is not among the accounts.
+        assert!(context
+            .ensure_pda_account::<Config>(&[b"other"], 1, &infos[1], &system_program)
+            .is_err());
+
+        let account = context
+            .ensure_pda_account::<Config>(&[b"config"], 1, &infos[1], &system_program)
+            .unwrap();
+        assert_eq!(account.data().value, 7);
+
+        let seeds = context.get_pda_seeds_for_account(&infos[0]).unwrap();
+        assert_eq!(seeds.components(), vec![b"config".as_ref()]);
+        assert_eq!(seeds.bump(), bump);
+    }
 }
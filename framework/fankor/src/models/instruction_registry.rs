@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::FankorContext;
+use crate::traits::ErasedInstruction;
+
+/// The signature of the functions that process an instruction.
+pub type InstructionHandlerFn = for<'info> fn(
+    &'info FankorContext<'info>,
+    &mut &[u8],
+    &mut &'info [AccountInfo<'info>],
+) -> FankorResult<()>;
+
+/// An [ErasedInstruction] that delegates to a function.
+#[derive(Clone, Copy)]
+pub struct FnInstruction {
+    name: &'static str,
+    discriminant: u8,
+    handler: InstructionHandlerFn,
+}
+
+impl FnInstruction {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(name: &'static str, discriminant: u8, handler: InstructionHandlerFn) -> Self {
+        Self {
+            name,
+            discriminant,
+            handler,
+        }
+    }
+}
+
+impl ErasedInstruction for FnInstruction {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn discriminant(&self) -> u8 {
+        self.discriminant
+    }
+
+    fn process<'info>(
+        &self,
+        context: &'info FankorContext<'info>,
+        ix_data: &mut &[u8],
+        ix_accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<()> {
+        (self.handler)(context, ix_data, ix_accounts)
+    }
+}
+
+impl Debug for FnInstruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnInstruction")
+            .field("name", &self.name)
+            .field("discriminant", &self.discriminant)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A set of instruction handlers keyed by their discriminant that is built at
+/// runtime, as an alternative to the dispatch generated by `#[program]` for
+/// plugin-style programs and test harnesses.
+#[derive(Default)]
+pub struct InstructionRegistry {
+    handlers: BTreeMap<u8, Box<dyn ErasedInstruction>>,
+}
+
+impl InstructionRegistry {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    /// Gets the handler of the instruction with `discriminant`.
+    pub fn get(&self, discriminant: u8) -> Option<&dyn ErasedInstruction> {
+        self.handlers.get(&discriminant).map(|v| v.as_ref())
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Registers `handler` under its discriminant, returning the handler it
+    /// replaces, if any.
+    pub fn register<T: ErasedInstruction + 'static>(
+        &mut self,
+        handler: T,
+    ) -> Option<Box<dyn ErasedInstruction>> {
+        self.handlers
+            .insert(handler.discriminant(), Box::new(handler))
+    }
+
+    /// Same as [register](Self::register) but consuming the registry.
+    pub fn with<T: ErasedInstruction + 'static>(mut self, handler: T) -> Self {
+        self.register(handler);
+        self
+    }
+
+    /// Removes the handler of the instruction with `discriminant`.
+    pub fn unregister(&mut self, discriminant: u8) -> Option<Box<dyn ErasedInstruction>> {
+        self.handlers.remove(&discriminant)
+    }
+
+    /// Executes the instruction selected by the first byte of `data`.
+    pub fn dispatch<'info>(
+        &self,
+        context: &'info FankorContext<'info>,
+        data: &[u8],
+        accounts: &'info [AccountInfo<'info>],
+    ) -> FankorResult<()> {
+        let (discriminant, mut ix_data) = match data.split_first() {
+            Some((discriminant, ix_data)) => (*discriminant, ix_data),
+            None => return Err(FankorErrorCode::MissingInstructionDiscriminant.into()),
+        };
+
+        let handler = self
+            .get(discriminant)
+            .ok_or(FankorErrorCode::InstructionDiscriminantNotFound)?;
+
+        let mut ix_accounts = accounts;
+        handler.process(context, &mut ix_data, &mut ix_accounts)
+    }
+}
+
+impl Debug for InstructionRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.handlers.iter().map(|(k, v)| (k, v.name())))
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::program_error::ProgramError;
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    fn ok<'info>(
+        _context: &'info FankorContext<'info>,
+        ix_data: &mut &[u8],
+        _ix_accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<()> {
+        if *ix_data != &[1u8, 2][..] {
+            return Err(ProgramError::InvalidInstructionData.into());
+        }
+
+        Ok(())
+    }
+
+    fn fail<'info>(
+        _context: &'info FankorContext<'info>,
+        _ix_data: &mut &[u8],
+        _ix_accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<()> {
+        Err(ProgramError::InvalidInstructionData.into())
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let program_id = Box::leak(Box::new(Pubkey::new_unique()));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(program_id, &[])));
+
+        let mut registry = InstructionRegistry::new()
+            .with(FnInstruction::new("Ok", 0, ok))
+            .with(FnInstruction::new("Fail", 1, fail));
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.get(1).unwrap().name(), "Fail");
+
+        assert!(registry.dispatch(context, &[0, 1, 2], &[]).is_ok());
+        assert!(registry.dispatch(context, &[1], &[]).is_err());
+        assert!(registry.dispatch(context, &[2], &[]).is_err());
+        assert!(registry.dispatch(context, &[], &[]).is_err());
+
+        // Replace a handler.
+        let previous = registry.register(FnInstruction::new("Ok2", 1, ok));
+        assert_eq!(previous.unwrap().name(), "Fail");
+        assert!(registry.dispatch(context, &[1, 1, 2], &[]).is_ok());
+    }
+}
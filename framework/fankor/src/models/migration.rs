@@ -0,0 +1,89 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+use solana_program::msg;
+
+use crate::errors::FankorResult;
+use crate::models::{Account, FankorContext, Program, System};
+use crate::traits::{Instruction, Migratable};
+
+/// The result of a batched migration.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MigrationProgress {
+    /// The number of accounts migrated.
+    pub migrated: u32,
+
+    /// The number of accounts skipped because they were already migrated.
+    pub skipped: u32,
+}
+
+impl MigrationProgress {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The number of accounts processed.
+    pub fn total(&self) -> u32 {
+        self.migrated + self.skipped
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Migrates `info` from `T::Old` to `T`, reallocating it and rewriting its
+/// discriminant. The rent difference is paid by `payer`.
+///
+/// Returns `false` without modifying the account if it is already a `T`.
+pub fn migrate_account<'info, T: Migratable>(
+    context: &'info FankorContext<'info>,
+    info: &'info AccountInfo<'info>,
+    payer: &'info AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> FankorResult<bool> {
+    if is_migrated::<T>(&info.try_borrow_data()?) {
+        return Ok(false);
+    }
+
+    let mut accounts = std::slice::from_ref(info);
+    let old = <Account<T::Old> as Instruction>::try_from(context, &mut &[][..], &mut accounts)?;
+    let new_value = T::migrate(old.data())?;
+
+    old.transmute(new_value, false, payer, system_program)?;
+
+    Ok(true)
+}
+
+/// Migrates all `accounts` from `T::Old` to `T`, skipping those already
+/// migrated so that a migration can be split into several transactions.
+pub fn migrate_accounts<'info, T: Migratable>(
+    context: &'info FankorContext<'info>,
+    accounts: &'info [AccountInfo<'info>],
+    payer: &'info AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> FankorResult<MigrationProgress> {
+    let mut progress = MigrationProgress::default();
+
+    for info in accounts {
+        if migrate_account::<T>(context, info, payer, system_program)? {
+            progress.migrated += 1;
+        } else {
+            progress.skipped += 1;
+        }
+    }
+
+    msg!(
+        "Migration: {} migrated, {} skipped",
+        progress.migrated,
+        progress.skipped
+    );
+
+    Ok(progress)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Whether the account data already starts with the discriminant of `T`.
+fn is_migrated<T: Migratable>(data: &[u8]) -> bool {
+    data.first().map_or(false, |v| T::check_discriminant(*v))
+}
@@ -1,12 +1,34 @@
+pub use account_backup::*;
+pub use account_load_stats::*;
+pub use account_layout::*;
 pub use accounts::*;
+#[cfg(feature = "debug")]
+pub use checkpoint::*;
 pub use context::*;
 pub use cpi_return::*;
+#[cfg(feature = "dynamic-dispatch")]
+pub use instruction_registry::*;
+pub use migration::*;
+pub use pda_seeds::*;
+pub use program_info::*;
 pub use programs::*;
+pub use sysvars::*;
 pub use zc_types::*;
 
+mod account_backup;
+mod account_load_stats;
+mod account_layout;
 mod accounts;
+#[cfg(feature = "debug")]
+mod checkpoint;
 mod context;
 mod cpi_return;
+#[cfg(feature = "dynamic-dispatch")]
+mod instruction_registry;
+mod migration;
+mod pda_seeds;
+mod program_info;
 mod programs;
+mod sysvars;
 pub mod types;
 mod zc_types;
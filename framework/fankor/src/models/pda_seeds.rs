@@ -0,0 +1,88 @@
+use std::rc::Rc;
+
+/// The seeds and bump of a verified PDA as they were evaluated during the
+/// validation of the instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdaSeeds {
+    /// The concatenation of all the seed components followed by the bump.
+    seeds: Rc<Vec<u8>>,
+
+    /// The length of each seed component.
+    lengths: Rc<Vec<usize>>,
+}
+
+impl PdaSeeds {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new instance from the concatenated seeds, including the bump
+    /// as the last byte, and the length of each component.
+    ///
+    /// # Safety
+    /// The sum of `lengths` must be equal to the length of `seeds` minus one.
+    pub fn new_unchecked(seeds: Rc<Vec<u8>>, lengths: Rc<Vec<usize>>) -> PdaSeeds {
+        Self { seeds, lengths }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The concatenation of all the seed components followed by the bump.
+    pub fn seeds(&self) -> &Rc<Vec<u8>> {
+        &self.seeds
+    }
+
+    /// The bump of the PDA.
+    pub fn bump(&self) -> u8 {
+        *self.seeds.last().expect("The seeds must contain the bump")
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// The evaluated seed components, without the bump.
+    pub fn components(&self) -> Vec<&[u8]> {
+        let mut result = Vec::with_capacity(self.lengths.len());
+        let mut seeds = &self.seeds[..self.seeds.len() - 1];
+
+        for length in self.lengths.iter() {
+            let (component, rest) = seeds.split_at(*length);
+            result.push(component);
+            seeds = rest;
+        }
+
+        result
+    }
+
+    /// The seed components followed by the bump, ready to be used as the
+    /// signer seeds of a CPI.
+    pub fn signer_seeds(&self) -> Vec<&[u8]> {
+        let mut result = self.components();
+        result.push(&self.seeds[self.seeds.len() - 1..]);
+        result
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_components() {
+        let seeds = PdaSeeds::new_unchecked(
+            Rc::new(vec![1, 2, 3, 4, 5, 6, 255]),
+            Rc::new(vec![2, 0, 4]),
+        );
+
+        assert_eq!(seeds.bump(), 255);
+        assert_eq!(
+            seeds.components(),
+            vec![&[1, 2][..], &[][..], &[3, 4, 5, 6][..]]
+        );
+        assert_eq!(
+            seeds.signer_seeds(),
+            vec![&[1, 2][..], &[][..], &[3, 4, 5, 6][..], &[255][..]]
+        );
+    }
+}
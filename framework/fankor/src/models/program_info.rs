@@ -0,0 +1,117 @@
+use solana_program::pubkey::Pubkey;
+
+/// The metadata of a program declared with `program_info!`. It is stored in the
+/// binary and included into the generated IDL and TypeScript files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgramInfo {
+    /// The semantic version of the program.
+    pub version: &'static str,
+
+    /// The URL of the documentation.
+    pub docs: Option<&'static str>,
+
+    /// The URL of the source code repository.
+    pub repository: Option<&'static str>,
+
+    /// The address of the program in each cluster it is deployed to.
+    pub deployments: &'static [(&'static str, Pubkey)],
+}
+
+impl ProgramInfo {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The address of the program in `cluster`, if it is deployed there.
+    pub fn deployment(&self, cluster: &str) -> Option<&'static Pubkey> {
+        self.deployments
+            .iter()
+            .find(|(name, _)| *name == cluster)
+            .map(|(_, address)| address)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Whether a client built for `version` can talk to this program following
+    /// semver rules, i.e. same major version (or minor for `0.x` versions) and
+    /// not newer than the program's one.
+    pub fn is_compatible_with(&self, version: &str) -> bool {
+        let (actual, required) = match (parse_version(self.version), parse_version(version)) {
+            (Some(actual), Some(required)) => (actual, required),
+            _ => return false,
+        };
+
+        let same_series = if actual.0 == 0 {
+            actual.0 == required.0 && actual.1 == required.1
+        } else {
+            actual.0 == required.0
+        };
+
+        same_series && required <= actual
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Parses the `major.minor.patch` part of a semantic version, ignoring the
+/// pre-release and build metadata.
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.split(['-', '+']).next()?;
+    let mut parts = version.split('.').map(|v| v.parse::<u64>().ok());
+
+    let result = (parts.next()??, parts.next()??, parts.next()??);
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(result)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DEPLOYMENTS: &[(&str, Pubkey)] = &[("devnet", Pubkey::new_from_array([1; 32]))];
+
+    fn info(version: &'static str) -> ProgramInfo {
+        ProgramInfo {
+            version,
+            docs: None,
+            repository: None,
+            deployments: DEPLOYMENTS,
+        }
+    }
+
+    #[test]
+    fn test_deployment() {
+        let info = info("1.0.0");
+
+        assert_eq!(
+            info.deployment("devnet"),
+            Some(&Pubkey::new_from_array([1; 32]))
+        );
+        assert_eq!(info.deployment("mainnet"), None);
+    }
+
+    #[test]
+    fn test_is_compatible_with() {
+        let info = info("1.4.2");
+        assert!(info.is_compatible_with("1.4.2"));
+        assert!(info.is_compatible_with("1.0.0"));
+        assert!(info.is_compatible_with("1.4.0-beta.1"));
+        assert!(!info.is_compatible_with("1.5.0"));
+        assert!(!info.is_compatible_with("2.0.0"));
+        assert!(!info.is_compatible_with("0.9.0"));
+        assert!(!info.is_compatible_with("1.4"));
+
+        let info = self::info("0.3.1");
+        assert!(info.is_compatible_with("0.3.0"));
+        assert!(!info.is_compatible_with("0.2.0"));
+        assert!(!info.is_compatible_with("0.4.0"));
+    }
+}
@@ -0,0 +1,261 @@
+use std::io::{ErrorKind, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::traits::{AccountType, ProgramType};
+
+/// The address of the SPL account compression program.
+pub const ACCOUNT_COMPRESSION_ID: Pubkey =
+    solana_program::pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+/// The address of the SPL noop program used by the account compression
+/// program to log the changes of the trees.
+pub const NOOP_ID: Pubkey = solana_program::pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// The size of the header of a concurrent merkle tree account.
+pub const CONCURRENT_MERKLE_TREE_HEADER_SIZE: usize = 56;
+
+const CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE: u8 = 1;
+const CONCURRENT_MERKLE_TREE_HEADER_V1: u8 = 0;
+
+/// The size of the `sequence_number`, `active_index` and `buffer_size` fields.
+const TREE_FIELDS_SIZE: usize = 3 * 8;
+
+#[derive(Debug, Copy, Clone)]
+pub struct AccountCompression;
+
+impl ProgramType for AccountCompression {
+    fn name() -> &'static str {
+        "AccountCompression"
+    }
+
+    fn address() -> &'static Pubkey {
+        &ACCOUNT_COMPRESSION_ID
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Noop;
+
+impl ProgramType for Noop {
+    fn name() -> &'static str {
+        "Noop"
+    }
+
+    fn address() -> &'static Pubkey {
+        &NOOP_ID
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ACCOUNTS -------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A concurrent merkle tree account of the SPL account compression program.
+/// Only the header and the current state of the tree are read, the change
+/// logs, the rightmost proof and the canopy are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ConcurrentMerkleTreeAccount {
+    /// The maximum number of concurrent changes that can be applied to the
+    /// tree within the same slot.
+    pub max_buffer_size: u32,
+
+    /// The depth of the tree, i.e. it can hold up to `2^max_depth` leaves.
+    pub max_depth: u32,
+
+    /// The authority allowed to modify the tree.
+    pub authority: Pubkey,
+
+    /// The slot in which the tree was created.
+    pub creation_slot: u64,
+
+    /// The number of changes applied to the tree.
+    pub sequence_number: u64,
+
+    /// The index of the latest change log.
+    pub active_index: u64,
+
+    /// The number of valid change logs.
+    pub buffer_size: u64,
+
+    /// The current root of the tree.
+    pub root: [u8; 32],
+}
+
+impl ConcurrentMerkleTreeAccount {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The number of leaves the tree can hold.
+    pub fn capacity(&self) -> u64 {
+        1u64.checked_shl(self.max_depth).unwrap_or(u64::MAX)
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// The size of a tree account with the given parameters, i.e. the space
+    /// to allocate before calling
+    /// [init_empty_merkle_tree](crate::cpi::account_compression::init_empty_merkle_tree).
+    pub fn space(max_depth: u32, max_buffer_size: u32, canopy_depth: u32) -> usize {
+        let canopy_size = ((1usize << (canopy_depth + 1)) - 2) * 32;
+
+        Self::tree_size(max_depth, max_buffer_size) + canopy_size
+    }
+
+    /// The size of the header and the tree without the canopy.
+    fn tree_size(max_depth: u32, max_buffer_size: u32) -> usize {
+        let max_depth = max_depth as usize;
+        let max_buffer_size = max_buffer_size as usize;
+        let change_log_size = 32 + 32 * max_depth + 8;
+        let rightmost_proof_size = 32 * max_depth + 32 + 8;
+
+        CONCURRENT_MERKLE_TREE_HEADER_SIZE
+            + TREE_FIELDS_SIZE
+            + max_buffer_size * change_log_size
+            + rightmost_proof_size
+    }
+}
+
+impl AccountType for ConcurrentMerkleTreeAccount {
+    fn discriminant() -> u8 {
+        0
+    }
+
+    fn owner() -> &'static Pubkey {
+        &ACCOUNT_COMPRESSION_ID
+    }
+}
+
+#[cfg(any(feature = "test-utils", test))]
+impl BorshSerialize for ConcurrentMerkleTreeAccount {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut buf = vec![0u8; Self::tree_size(self.max_depth, self.max_buffer_size)];
+        buf[0] = CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE;
+        buf[1] = CONCURRENT_MERKLE_TREE_HEADER_V1;
+        buf[2..6].copy_from_slice(&self.max_buffer_size.to_le_bytes());
+        buf[6..10].copy_from_slice(&self.max_depth.to_le_bytes());
+        buf[10..42].copy_from_slice(self.authority.as_ref());
+        buf[42..50].copy_from_slice(&self.creation_slot.to_le_bytes());
+
+        let offset = CONCURRENT_MERKLE_TREE_HEADER_SIZE;
+        buf[offset..offset + 8].copy_from_slice(&self.sequence_number.to_le_bytes());
+        buf[offset + 8..offset + 16].copy_from_slice(&self.active_index.to_le_bytes());
+        buf[offset + 16..offset + 24].copy_from_slice(&self.buffer_size.to_le_bytes());
+
+        let change_log_size = 32 + 32 * self.max_depth as usize + 8;
+        let root_offset = offset + TREE_FIELDS_SIZE + self.active_index as usize * change_log_size;
+        buf[root_offset..root_offset + 32].copy_from_slice(&self.root);
+
+        writer.write_all(&buf)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(not(any(feature = "test-utils", test)))]
+impl BorshSerialize for ConcurrentMerkleTreeAccount {
+    fn serialize<W: Write>(&self, _writer: &mut W) -> std::io::Result<()> {
+        unreachable!("Cannot write accounts that does not belong to the current program")
+    }
+}
+
+impl BorshDeserialize for ConcurrentMerkleTreeAccount {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if buf.len() < CONCURRENT_MERKLE_TREE_HEADER_SIZE + TREE_FIELDS_SIZE
+            || buf[0] != CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE
+            || buf[1] != CONCURRENT_MERKLE_TREE_HEADER_V1
+        {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Invalid concurrent merkle tree account",
+            ));
+        }
+
+        let data = *buf;
+        let read_u32 =
+            |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        let read_u64 =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        let max_buffer_size = read_u32(2);
+        let max_depth = read_u32(6);
+        let offset = CONCURRENT_MERKLE_TREE_HEADER_SIZE;
+        let active_index = read_u64(offset + 8);
+
+        if max_depth > 32
+            || active_index >= max_buffer_size as u64
+            || data.len() < Self::tree_size(max_depth, max_buffer_size)
+        {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Invalid concurrent merkle tree account",
+            ));
+        }
+
+        let change_log_size = 32 + 32 * max_depth as usize + 8;
+        let root_offset = offset + TREE_FIELDS_SIZE + active_index as usize * change_log_size;
+
+        let result = ConcurrentMerkleTreeAccount {
+            max_buffer_size,
+            max_depth,
+            authority: Pubkey::new_from_array(data[10..42].try_into().unwrap()),
+            creation_slot: read_u64(42),
+            sequence_number: read_u64(offset),
+            active_index,
+            buffer_size: read_u64(offset + 16),
+            root: data[root_offset..root_offset + 32].try_into().unwrap(),
+        };
+
+        // The change logs, the rightmost proof and the canopy are ignored.
+        *buf = &buf[buf.len()..];
+
+        Ok(result)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_space() {
+        assert_eq!(ConcurrentMerkleTreeAccount::space(3, 8, 0), 1_304);
+        assert_eq!(ConcurrentMerkleTreeAccount::space(14, 64, 0), 31_800);
+        assert_eq!(ConcurrentMerkleTreeAccount::space(14, 64, 2), 31_992);
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let account = ConcurrentMerkleTreeAccount {
+            max_buffer_size: 8,
+            max_depth: 3,
+            authority: Pubkey::new_unique(),
+            creation_slot: 10,
+            sequence_number: 5,
+            active_index: 5,
+            buffer_size: 6,
+            root: [7; 32],
+        };
+        let mut bytes = account.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), ConcurrentMerkleTreeAccount::space(3, 8, 0));
+
+        // Canopy.
+        bytes.extend_from_slice(&[1; 64]);
+
+        let mut buf = bytes.as_slice();
+        let deserialized = ConcurrentMerkleTreeAccount::deserialize(&mut buf).unwrap();
+
+        assert_eq!(deserialized, account);
+        assert_eq!(deserialized.capacity(), 8);
+        assert!(buf.is_empty());
+
+        assert!(ConcurrentMerkleTreeAccount::deserialize(&mut &bytes[..1000]).is_err());
+
+        bytes[0] = 0;
+        assert!(ConcurrentMerkleTreeAccount::deserialize(&mut bytes.as_slice()).is_err());
+    }
+}
@@ -1,13 +1,29 @@
+#[cfg(feature = "account-compression-program")]
+pub use account_compression::*;
 #[cfg(any(feature = "spl-associated-token-account"))]
 pub use associated_token::*;
 #[cfg(feature = "metadata-program")]
 pub use metadata::*;
+#[cfg(any(feature = "pyth-oracle", feature = "switchboard-oracle"))]
+pub use oracles::*;
+#[cfg(feature = "pyth-oracle")]
+pub use pyth::*;
+#[cfg(feature = "switchboard-oracle")]
+pub use switchboard::*;
+#[cfg(feature = "stake-pool-program")]
+pub use stake_pool::*;
 pub use system_program::*;
 #[cfg(feature = "token-program")]
 pub use token::*;
 #[cfg(feature = "token-program-2022")]
 pub use token_2022::*;
+#[cfg(feature = "token-program-2022")]
+pub use transfer_hook::*;
+#[cfg(feature = "vote-program")]
+pub use vote::*;
 
+#[cfg(feature = "account-compression-program")]
+mod account_compression;
 mod system_program;
 #[cfg(feature = "token-program")]
 mod token;
@@ -21,3 +37,17 @@ mod associated_token;
 
 #[cfg(feature = "token-program-2022")]
 mod token_2022;
+#[cfg(feature = "token-program-2022")]
+mod transfer_hook;
+
+#[cfg(any(feature = "pyth-oracle", feature = "switchboard-oracle"))]
+mod oracles;
+#[cfg(feature = "pyth-oracle")]
+mod pyth;
+#[cfg(feature = "switchboard-oracle")]
+mod switchboard;
+
+#[cfg(feature = "stake-pool-program")]
+mod stake_pool;
+#[cfg(feature = "vote-program")]
+mod vote;
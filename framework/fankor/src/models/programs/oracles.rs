@@ -0,0 +1,130 @@
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::Bps;
+
+/// A price read from an oracle account after checking it is available and
+/// not older than the maximum age requested by the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OraclePrice {
+    /// The price in units of `10^exponent`.
+    pub price: i64,
+
+    /// The confidence interval around the price in units of `10^exponent`.
+    pub confidence: u64,
+
+    /// The exponent of the price and the confidence.
+    pub exponent: i32,
+
+    /// The unix timestamp at which the price was published.
+    pub publish_time: i64,
+}
+
+impl OraclePrice {
+    // METHODS ----------------------------------------------------------------
+
+    /// Fails if the confidence interval is wider than `max_confidence` of the
+    /// price, e.g. `Bps::new(100)` rejects prices whose confidence is greater
+    /// than 1% of their value.
+    pub fn check_confidence(self, max_confidence: Bps) -> FankorResult<OraclePrice> {
+        let max_confidence = max_confidence.apply_to(self.price.unsigned_abs());
+
+        if self.confidence > max_confidence {
+            return Err(FankorErrorCode::OraclePriceConfidenceTooWide {
+                confidence: self.confidence,
+                max_confidence,
+            }
+            .into());
+        }
+
+        Ok(self)
+    }
+
+    /// Converts the price to units of `10^exponent`, rounding down when the
+    /// precision decreases.
+    pub fn price_with_exponent(&self, exponent: i32) -> FankorResult<i64> {
+        scale_value(self.price as i128, self.exponent, exponent)
+            .and_then(|v| i64::try_from(v).ok())
+            .ok_or_else(|| {
+                FankorErrorCode::ArithmeticOverflow {
+                    operation: "OraclePrice::price_with_exponent",
+                }
+                .into()
+            })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Fails if a price published at `publish_time` is older than `max_age` seconds
+/// at `now`.
+pub(crate) fn check_oracle_price_age(
+    publish_time: i64,
+    now: i64,
+    max_age: u64,
+) -> FankorResult<()> {
+    let age = now.saturating_sub(publish_time).max(0) as u64;
+
+    if age > max_age {
+        return Err(FankorErrorCode::OraclePriceStale { age, max_age }.into());
+    }
+
+    Ok(())
+}
+
+/// Converts `value` from units of `10^from` to units of `10^to`.
+pub(crate) fn scale_value(value: i128, from: i32, to: i32) -> Option<i128> {
+    let diff = from.checked_sub(to)?;
+    let factor = 10i128.checked_pow(diff.unsigned_abs())?;
+
+    if diff >= 0 {
+        value.checked_mul(factor)
+    } else {
+        Some(value / factor)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_check_confidence() {
+        let price = OraclePrice {
+            price: 20_000,
+            confidence: 200,
+            exponent: -2,
+            publish_time: 0,
+        };
+
+        assert!(price.check_confidence(Bps::new(100).unwrap()).is_ok());
+        assert!(price.check_confidence(Bps::new(99).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_price_with_exponent() {
+        let price = OraclePrice {
+            price: 123_456,
+            confidence: 0,
+            exponent: -3,
+            publish_time: 0,
+        };
+
+        assert_eq!(price.price_with_exponent(-3).unwrap(), 123_456);
+        assert_eq!(price.price_with_exponent(-6).unwrap(), 123_456_000);
+        assert_eq!(price.price_with_exponent(-1).unwrap(), 1_234);
+        assert_eq!(price.price_with_exponent(0).unwrap(), 123);
+        assert!(price.price_with_exponent(-30).is_err());
+    }
+
+    #[test]
+    fn test_check_oracle_price_age() {
+        assert!(check_oracle_price_age(100, 160, 60).is_ok());
+        assert!(check_oracle_price_age(100, 161, 60).is_err());
+        assert!(check_oracle_price_age(200, 100, 0).is_ok());
+    }
+}
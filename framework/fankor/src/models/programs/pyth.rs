@@ -0,0 +1,295 @@
+use std::io::{ErrorKind, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::clock::Clock;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::programs::oracles::{check_oracle_price_age, OraclePrice};
+use crate::traits::{AccountType, ProgramType};
+
+/// The address of the Pyth oracle program in mainnet.
+pub const PYTH_MAINNET_ID: Pubkey =
+    solana_program::pubkey!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+
+/// The address of the Pyth oracle program in devnet.
+pub const PYTH_DEVNET_ID: Pubkey =
+    solana_program::pubkey!("gSbePebfvPy7tRqimPoVecS2UsBUyhLb9zhs1GeL9sj");
+
+const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+const PYTH_VERSION: u32 = 2;
+const PYTH_PRICE_ACCOUNT_TYPE: u32 = 3;
+const PYTH_PRICE_ACCOUNT_HEADER_SIZE: usize = 240;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Pyth;
+
+impl ProgramType for Pyth {
+    fn name() -> &'static str {
+        "Pyth"
+    }
+
+    /// The mainnet address unless the `oracles-devnet` feature is enabled.
+    fn address() -> &'static Pubkey {
+        if cfg!(feature = "oracles-devnet") {
+            &PYTH_DEVNET_ID
+        } else {
+            &PYTH_MAINNET_ID
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ACCOUNTS -------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The status of a Pyth price.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum PythPriceStatus {
+    #[default]
+    Unknown = 0,
+    Trading = 1,
+    Halted = 2,
+    Auction = 3,
+    Ignored = 4,
+}
+
+impl PythPriceStatus {
+    fn from_u32(value: u32) -> PythPriceStatus {
+        match value {
+            1 => PythPriceStatus::Trading,
+            2 => PythPriceStatus::Halted,
+            3 => PythPriceStatus::Auction,
+            4 => PythPriceStatus::Ignored,
+            _ => PythPriceStatus::Unknown,
+        }
+    }
+}
+
+/// A Pyth price account (v2). Only the aggregated values are read, the
+/// components of each publisher are ignored.
+///
+/// The magic number, the version and the account type are checked while
+/// deserializing, and the owner of the account when it is loaded into an
+/// [Account](crate::models::Account).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PythPriceAccount {
+    /// The exponent of all prices and confidences.
+    pub exponent: i32,
+
+    /// The unix timestamp of the aggregated price.
+    pub timestamp: i64,
+
+    /// The exponentially-weighted moving average price.
+    pub ema_price: i64,
+
+    /// The exponentially-weighted moving average confidence.
+    pub ema_confidence: u64,
+
+    /// The product account this price belongs to.
+    pub product: Pubkey,
+
+    /// The aggregated price.
+    pub price: i64,
+
+    /// The confidence interval of the aggregated price.
+    pub confidence: u64,
+
+    /// The status of the aggregated price.
+    pub status: PythPriceStatus,
+
+    /// The slot in which the aggregated price was published.
+    pub publish_slot: u64,
+}
+
+impl PythPriceAccount {
+    // METHODS ----------------------------------------------------------------
+
+    /// Gets the aggregated price failing if it is not being traded or if it
+    /// is older than `max_age` seconds.
+    pub fn get_price(&self, max_age: u64) -> FankorResult<OraclePrice> {
+        let clock = Clock::get()?;
+        self.get_price_at(clock.unix_timestamp, max_age)
+    }
+
+    /// Same as [PythPriceAccount::get_price] but using `now` as the current
+    /// unix timestamp.
+    pub fn get_price_at(&self, now: i64, max_age: u64) -> FankorResult<OraclePrice> {
+        if self.status != PythPriceStatus::Trading {
+            return Err(FankorErrorCode::OraclePriceUnavailable.into());
+        }
+
+        check_oracle_price_age(self.timestamp, now, max_age)?;
+
+        Ok(OraclePrice {
+            price: self.price,
+            confidence: self.confidence,
+            exponent: self.exponent,
+            publish_time: self.timestamp,
+        })
+    }
+
+    /// Gets the exponentially-weighted moving average price failing if it is
+    /// older than `max_age` seconds.
+    pub fn get_ema_price(&self, max_age: u64) -> FankorResult<OraclePrice> {
+        let clock = Clock::get()?;
+        self.get_ema_price_at(clock.unix_timestamp, max_age)
+    }
+
+    /// Same as [PythPriceAccount::get_ema_price] but using `now` as the current
+    /// unix timestamp.
+    pub fn get_ema_price_at(&self, now: i64, max_age: u64) -> FankorResult<OraclePrice> {
+        check_oracle_price_age(self.timestamp, now, max_age)?;
+
+        Ok(OraclePrice {
+            price: self.ema_price,
+            confidence: self.ema_confidence,
+            exponent: self.exponent,
+            publish_time: self.timestamp,
+        })
+    }
+}
+
+impl AccountType for PythPriceAccount {
+    fn discriminant() -> u8 {
+        0
+    }
+
+    fn owner() -> &'static Pubkey {
+        Pyth::address()
+    }
+}
+
+#[cfg(any(feature = "test-utils", test))]
+impl BorshSerialize for PythPriceAccount {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut buf = [0u8; PYTH_PRICE_ACCOUNT_HEADER_SIZE];
+        buf[0..4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+        buf[4..8].copy_from_slice(&PYTH_VERSION.to_le_bytes());
+        buf[8..12].copy_from_slice(&PYTH_PRICE_ACCOUNT_TYPE.to_le_bytes());
+        buf[12..16].copy_from_slice(&(PYTH_PRICE_ACCOUNT_HEADER_SIZE as u32).to_le_bytes());
+        buf[20..24].copy_from_slice(&self.exponent.to_le_bytes());
+        buf[48..56].copy_from_slice(&self.ema_price.to_le_bytes());
+        buf[72..80].copy_from_slice(&self.ema_confidence.to_le_bytes());
+        buf[96..104].copy_from_slice(&self.timestamp.to_le_bytes());
+        buf[112..144].copy_from_slice(self.product.as_ref());
+        buf[208..216].copy_from_slice(&self.price.to_le_bytes());
+        buf[216..224].copy_from_slice(&self.confidence.to_le_bytes());
+        buf[224..228].copy_from_slice(&(self.status as u32).to_le_bytes());
+        buf[232..240].copy_from_slice(&self.publish_slot.to_le_bytes());
+
+        writer.write_all(&buf)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(not(any(feature = "test-utils", test)))]
+impl BorshSerialize for PythPriceAccount {
+    fn serialize<W: Write>(&self, _writer: &mut W) -> std::io::Result<()> {
+        unreachable!("Cannot write accounts that does not belong to the current program")
+    }
+}
+
+impl BorshDeserialize for PythPriceAccount {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if buf.len() < PYTH_PRICE_ACCOUNT_HEADER_SIZE {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Pyth price account too small",
+            ));
+        }
+
+        let data = &buf[..PYTH_PRICE_ACCOUNT_HEADER_SIZE];
+        let read_u32 =
+            |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        let read_u64 =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        if read_u32(0) != PYTH_MAGIC
+            || read_u32(4) != PYTH_VERSION
+            || read_u32(8) != PYTH_PRICE_ACCOUNT_TYPE
+        {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Invalid Pyth price account",
+            ));
+        }
+
+        let result = PythPriceAccount {
+            exponent: read_u32(20) as i32,
+            ema_price: read_u64(48) as i64,
+            ema_confidence: read_u64(72),
+            timestamp: read_u64(96) as i64,
+            product: Pubkey::new_from_array(data[112..144].try_into().unwrap()),
+            price: read_u64(208) as i64,
+            confidence: read_u64(216),
+            status: PythPriceStatus::from_u32(read_u32(224)),
+            publish_slot: read_u64(232),
+        };
+
+        // The components of the publishers are ignored.
+        *buf = &buf[buf.len()..];
+
+        Ok(result)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn price_account() -> PythPriceAccount {
+        PythPriceAccount {
+            exponent: -8,
+            timestamp: 1_000,
+            ema_price: 2_000_000_000,
+            ema_confidence: 2_000_000,
+            product: Pubkey::new_unique(),
+            price: 2_100_000_000,
+            confidence: 1_000_000,
+            status: PythPriceStatus::Trading,
+            publish_slot: 50,
+        }
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let account = price_account();
+        let mut bytes = account.try_to_vec().unwrap();
+        bytes.extend_from_slice(&[1; 96]);
+
+        let mut buf = bytes.as_slice();
+        let deserialized = PythPriceAccount::deserialize(&mut buf).unwrap();
+
+        assert_eq!(deserialized, account);
+        assert!(buf.is_empty());
+
+        bytes[0] = 0;
+        assert!(PythPriceAccount::deserialize(&mut bytes.as_slice()).is_err());
+        assert!(PythPriceAccount::deserialize(&mut &bytes[..100]).is_err());
+    }
+
+    #[test]
+    fn test_get_price() {
+        let mut account = price_account();
+
+        let price = account.get_price_at(1_030, 30).unwrap();
+        assert_eq!(price.price, 2_100_000_000);
+        assert_eq!(price.confidence, 1_000_000);
+        assert_eq!(price.exponent, -8);
+
+        let price = account.get_ema_price_at(1_030, 30).unwrap();
+        assert_eq!(price.price, 2_000_000_000);
+
+        assert!(account.get_price_at(1_031, 30).is_err());
+
+        account.status = PythPriceStatus::Halted;
+        assert!(account.get_price_at(1_000, 30).is_err());
+    }
+}
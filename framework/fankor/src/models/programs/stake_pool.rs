@@ -0,0 +1,487 @@
+use std::io::{ErrorKind, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::clock::Clock;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::traits::{AccountType, ProgramType};
+
+/// The address of the SPL stake pool program.
+pub const STAKE_POOL_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+const STAKE_POOL_ACCOUNT_TYPE: u8 = 1;
+const VALIDATOR_LIST_ACCOUNT_TYPE: u8 = 2;
+
+#[derive(Debug, Copy, Clone)]
+pub struct StakePoolProgram;
+
+impl ProgramType for StakePoolProgram {
+    fn name() -> &'static str {
+        "StakePoolProgram"
+    }
+
+    fn address() -> &'static Pubkey {
+        &STAKE_POOL_PROGRAM_ID
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ACCOUNTS -------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The state of an SPL stake pool. Only the fields up to the epoch fee are
+/// read because they have the same layout in all versions of the program.
+///
+/// The account type is checked while deserializing, and the owner of the
+/// account when it is loaded into an [Account](crate::models::Account).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StakePoolAccount {
+    pub manager: Pubkey,
+    pub staker: Pubkey,
+    pub stake_deposit_authority: Pubkey,
+    pub stake_withdraw_bump_seed: u8,
+    pub validator_list: Pubkey,
+    pub reserve_stake: Pubkey,
+    pub pool_mint: Pubkey,
+    pub manager_fee_account: Pubkey,
+    pub token_program_id: Pubkey,
+
+    /// The lamports managed by the pool as of the last update.
+    pub total_lamports: u64,
+
+    /// The supply of pool tokens as of the last update.
+    pub pool_token_supply: u64,
+
+    /// The epoch in which the balances were last updated.
+    pub last_update_epoch: u64,
+
+    pub lockup_unix_timestamp: i64,
+    pub lockup_epoch: u64,
+    pub lockup_custodian: Pubkey,
+
+    /// The fee taken from the rewards of each epoch as a fraction.
+    pub epoch_fee_denominator: u64,
+    pub epoch_fee_numerator: u64,
+}
+
+impl StakePoolAccount {
+    // METHODS ----------------------------------------------------------------
+
+    /// Fails if the balances of the pool were not updated in the current
+    /// epoch, i.e. the exchange rate may be outdated.
+    pub fn check_updated(&self) -> FankorResult<()> {
+        let clock = Clock::get()?;
+        self.check_updated_at(clock.epoch)
+    }
+
+    /// Same as [StakePoolAccount::check_updated] but using `epoch` as the
+    /// current epoch.
+    pub fn check_updated_at(&self, epoch: u64) -> FankorResult<()> {
+        if self.last_update_epoch < epoch {
+            return Err(FankorErrorCode::StakePoolNotUpdated {
+                last_update_epoch: self.last_update_epoch,
+                epoch,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Converts an amount of pool tokens to lamports at the exchange rate of
+    /// the last update, rounding down. Returns `None` if it overflows.
+    pub fn pool_tokens_to_lamports(&self, pool_tokens: u64) -> Option<u64> {
+        if self.pool_token_supply == 0 {
+            return Some(pool_tokens);
+        }
+
+        let result = (pool_tokens as u128)
+            .checked_mul(self.total_lamports as u128)?
+            .checked_div(self.pool_token_supply as u128)?;
+
+        u64::try_from(result).ok()
+    }
+
+    /// Converts an amount of lamports to pool tokens at the exchange rate of
+    /// the last update, rounding down. Returns `None` if it overflows.
+    pub fn lamports_to_pool_tokens(&self, lamports: u64) -> Option<u64> {
+        if self.total_lamports == 0 || self.pool_token_supply == 0 {
+            return Some(lamports);
+        }
+
+        let result = (lamports as u128)
+            .checked_mul(self.pool_token_supply as u128)?
+            .checked_div(self.total_lamports as u128)?;
+
+        u64::try_from(result).ok()
+    }
+}
+
+impl AccountType for StakePoolAccount {
+    fn discriminant() -> u8 {
+        0
+    }
+
+    fn owner() -> &'static Pubkey {
+        StakePoolProgram::address()
+    }
+}
+
+#[cfg(any(feature = "test-utils", test))]
+impl BorshSerialize for StakePoolAccount {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        STAKE_POOL_ACCOUNT_TYPE.serialize(writer)?;
+        self.manager.serialize(writer)?;
+        self.staker.serialize(writer)?;
+        self.stake_deposit_authority.serialize(writer)?;
+        self.stake_withdraw_bump_seed.serialize(writer)?;
+        self.validator_list.serialize(writer)?;
+        self.reserve_stake.serialize(writer)?;
+        self.pool_mint.serialize(writer)?;
+        self.manager_fee_account.serialize(writer)?;
+        self.token_program_id.serialize(writer)?;
+        self.total_lamports.serialize(writer)?;
+        self.pool_token_supply.serialize(writer)?;
+        self.last_update_epoch.serialize(writer)?;
+        self.lockup_unix_timestamp.serialize(writer)?;
+        self.lockup_epoch.serialize(writer)?;
+        self.lockup_custodian.serialize(writer)?;
+        self.epoch_fee_denominator.serialize(writer)?;
+        self.epoch_fee_numerator.serialize(writer)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(not(any(feature = "test-utils", test)))]
+impl BorshSerialize for StakePoolAccount {
+    fn serialize<W: Write>(&self, _writer: &mut W) -> std::io::Result<()> {
+        unreachable!("Cannot write accounts that does not belong to the current program")
+    }
+}
+
+impl BorshDeserialize for StakePoolAccount {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let mut data = *buf;
+
+        if u8::deserialize(&mut data)? != STAKE_POOL_ACCOUNT_TYPE {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Invalid stake pool account",
+            ));
+        }
+
+        let result = StakePoolAccount {
+            manager: Pubkey::deserialize(&mut data)?,
+            staker: Pubkey::deserialize(&mut data)?,
+            stake_deposit_authority: Pubkey::deserialize(&mut data)?,
+            stake_withdraw_bump_seed: u8::deserialize(&mut data)?,
+            validator_list: Pubkey::deserialize(&mut data)?,
+            reserve_stake: Pubkey::deserialize(&mut data)?,
+            pool_mint: Pubkey::deserialize(&mut data)?,
+            manager_fee_account: Pubkey::deserialize(&mut data)?,
+            token_program_id: Pubkey::deserialize(&mut data)?,
+            total_lamports: u64::deserialize(&mut data)?,
+            pool_token_supply: u64::deserialize(&mut data)?,
+            last_update_epoch: u64::deserialize(&mut data)?,
+            lockup_unix_timestamp: i64::deserialize(&mut data)?,
+            lockup_epoch: u64::deserialize(&mut data)?,
+            lockup_custodian: Pubkey::deserialize(&mut data)?,
+            epoch_fee_denominator: u64::deserialize(&mut data)?,
+            epoch_fee_numerator: u64::deserialize(&mut data)?,
+        };
+
+        // The rest of the fields are ignored.
+        *buf = &buf[buf.len()..];
+
+        Ok(result)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The status of a validator in a stake pool.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum StakePoolValidatorStatus {
+    #[default]
+    Active,
+    DeactivatingTransient,
+    ReadyForRemoval,
+    DeactivatingValidator,
+    DeactivatingAll,
+}
+
+impl StakePoolValidatorStatus {
+    fn from_u8(value: u8) -> Option<StakePoolValidatorStatus> {
+        match value {
+            0 => Some(StakePoolValidatorStatus::Active),
+            1 => Some(StakePoolValidatorStatus::DeactivatingTransient),
+            2 => Some(StakePoolValidatorStatus::ReadyForRemoval),
+            3 => Some(StakePoolValidatorStatus::DeactivatingValidator),
+            4 => Some(StakePoolValidatorStatus::DeactivatingAll),
+            _ => None,
+        }
+    }
+}
+
+/// The info of a validator in the validator list of a stake pool.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct StakePoolValidator {
+    /// The lamports in the active stake account.
+    pub active_stake_lamports: u64,
+
+    /// The lamports in the transient stake account.
+    pub transient_stake_lamports: u64,
+
+    /// The epoch in which the balances were last updated.
+    pub last_update_epoch: u64,
+
+    pub transient_seed_suffix: u64,
+    pub validator_seed_suffix: u32,
+    pub status: StakePoolValidatorStatus,
+    pub vote_account_address: Pubkey,
+}
+
+impl StakePoolValidator {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The lamports delegated to the validator.
+    pub fn stake_lamports(&self) -> u64 {
+        self.active_stake_lamports
+            .saturating_add(self.transient_stake_lamports)
+    }
+}
+
+/// The validator list of an SPL stake pool.
+///
+/// The account type is checked while deserializing, and the owner of the
+/// account when it is loaded into an [Account](crate::models::Account).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StakePoolValidatorList {
+    pub max_validators: u32,
+    pub validators: Vec<StakePoolValidator>,
+}
+
+impl StakePoolValidatorList {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Finds the validator with the given vote account.
+    pub fn find(&self, vote_account_address: &Pubkey) -> Option<&StakePoolValidator> {
+        self.validators
+            .iter()
+            .find(|v| &v.vote_account_address == vote_account_address)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Fails if any validator was not updated in the current epoch.
+    pub fn check_updated(&self) -> FankorResult<()> {
+        let clock = Clock::get()?;
+        self.check_updated_at(clock.epoch)
+    }
+
+    /// Same as [StakePoolValidatorList::check_updated] but using `epoch` as
+    /// the current epoch.
+    pub fn check_updated_at(&self, epoch: u64) -> FankorResult<()> {
+        for validator in &self.validators {
+            if validator.last_update_epoch < epoch {
+                return Err(FankorErrorCode::StakePoolNotUpdated {
+                    last_update_epoch: validator.last_update_epoch,
+                    epoch,
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl AccountType for StakePoolValidatorList {
+    fn discriminant() -> u8 {
+        0
+    }
+
+    fn owner() -> &'static Pubkey {
+        StakePoolProgram::address()
+    }
+}
+
+#[cfg(any(feature = "test-utils", test))]
+impl BorshSerialize for StakePoolValidatorList {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        VALIDATOR_LIST_ACCOUNT_TYPE.serialize(writer)?;
+        self.max_validators.serialize(writer)?;
+        (self.validators.len() as u32).serialize(writer)?;
+
+        for validator in &self.validators {
+            validator.active_stake_lamports.serialize(writer)?;
+            validator.transient_stake_lamports.serialize(writer)?;
+            validator.last_update_epoch.serialize(writer)?;
+            validator.transient_seed_suffix.serialize(writer)?;
+            0u32.serialize(writer)?;
+            validator.validator_seed_suffix.serialize(writer)?;
+            (validator.status as u8).serialize(writer)?;
+            validator.vote_account_address.serialize(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(not(any(feature = "test-utils", test)))]
+impl BorshSerialize for StakePoolValidatorList {
+    fn serialize<W: Write>(&self, _writer: &mut W) -> std::io::Result<()> {
+        unreachable!("Cannot write accounts that does not belong to the current program")
+    }
+}
+
+impl BorshDeserialize for StakePoolValidatorList {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let mut data = *buf;
+
+        if u8::deserialize(&mut data)? != VALIDATOR_LIST_ACCOUNT_TYPE {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Invalid stake pool validator list account",
+            ));
+        }
+
+        let max_validators = u32::deserialize(&mut data)?;
+        let len = u32::deserialize(&mut data)?;
+
+        if len > max_validators {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Invalid stake pool validator list account",
+            ));
+        }
+
+        let mut validators = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            let active_stake_lamports = u64::deserialize(&mut data)?;
+            let transient_stake_lamports = u64::deserialize(&mut data)?;
+            let last_update_epoch = u64::deserialize(&mut data)?;
+            let transient_seed_suffix = u64::deserialize(&mut data)?;
+            let _unused = u32::deserialize(&mut data)?;
+            let validator_seed_suffix = u32::deserialize(&mut data)?;
+            let status = StakePoolValidatorStatus::from_u8(u8::deserialize(&mut data)?)
+                .ok_or_else(|| {
+                    std::io::Error::new(ErrorKind::InvalidData, "Invalid stake pool validator")
+                })?;
+            let vote_account_address = Pubkey::deserialize(&mut data)?;
+
+            validators.push(StakePoolValidator {
+                active_stake_lamports,
+                transient_stake_lamports,
+                last_update_epoch,
+                transient_seed_suffix,
+                validator_seed_suffix,
+                status,
+                vote_account_address,
+            });
+        }
+
+        // The rest of the account is reserved for new validators.
+        *buf = &buf[buf.len()..];
+
+        Ok(StakePoolValidatorList {
+            max_validators,
+            validators,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stake_pool_serialize_deserialize() {
+        let account = StakePoolAccount {
+            manager: Pubkey::new_unique(),
+            pool_mint: Pubkey::new_unique(),
+            total_lamports: 1_100,
+            pool_token_supply: 1_000,
+            last_update_epoch: 10,
+            epoch_fee_denominator: 100,
+            epoch_fee_numerator: 5,
+            ..Default::default()
+        };
+        let mut bytes = account.try_to_vec().unwrap();
+        bytes.extend_from_slice(&[1; 64]);
+
+        let mut buf = bytes.as_slice();
+        let deserialized = StakePoolAccount::deserialize(&mut buf).unwrap();
+
+        assert_eq!(deserialized, account);
+        assert!(buf.is_empty());
+
+        bytes[0] = VALIDATOR_LIST_ACCOUNT_TYPE;
+        assert!(StakePoolAccount::deserialize(&mut bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_stake_pool_exchange_rate() {
+        let account = StakePoolAccount {
+            total_lamports: 1_100,
+            pool_token_supply: 1_000,
+            last_update_epoch: 10,
+            ..Default::default()
+        };
+
+        assert_eq!(account.pool_tokens_to_lamports(100), Some(110));
+        assert_eq!(account.lamports_to_pool_tokens(110), Some(100));
+        assert!(account.check_updated_at(10).is_ok());
+        assert!(account.check_updated_at(11).is_err());
+    }
+
+    #[test]
+    fn test_validator_list_serialize_deserialize() {
+        let vote_account_address = Pubkey::new_unique();
+        let account = StakePoolValidatorList {
+            max_validators: 4,
+            validators: vec![
+                StakePoolValidator {
+                    active_stake_lamports: 100,
+                    transient_stake_lamports: 20,
+                    last_update_epoch: 10,
+                    vote_account_address,
+                    ..Default::default()
+                },
+                StakePoolValidator {
+                    last_update_epoch: 9,
+                    status: StakePoolValidatorStatus::ReadyForRemoval,
+                    vote_account_address: Pubkey::new_unique(),
+                    ..Default::default()
+                },
+            ],
+        };
+        let mut bytes = account.try_to_vec().unwrap();
+        bytes.extend_from_slice(&[0; 73 * 2]);
+
+        let mut buf = bytes.as_slice();
+        let deserialized = StakePoolValidatorList::deserialize(&mut buf).unwrap();
+
+        assert_eq!(deserialized, account);
+        assert!(buf.is_empty());
+        assert_eq!(
+            deserialized.find(&vote_account_address).unwrap().stake_lamports(),
+            120
+        );
+        assert!(deserialized.check_updated_at(9).is_ok());
+        assert!(deserialized.check_updated_at(10).is_err());
+
+        bytes[0] = STAKE_POOL_ACCOUNT_TYPE;
+        assert!(StakePoolValidatorList::deserialize(&mut bytes.as_slice()).is_err());
+    }
+}
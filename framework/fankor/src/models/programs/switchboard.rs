@@ -0,0 +1,248 @@
+use std::io::{ErrorKind, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::clock::Clock;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::programs::oracles::{check_oracle_price_age, scale_value, OraclePrice};
+use crate::traits::{AccountType, ProgramType};
+
+/// The address of the Switchboard V2 oracle program in mainnet.
+pub const SWITCHBOARD_MAINNET_ID: Pubkey =
+    solana_program::pubkey!("SW1TCH7qEPTdLsDHRgPuMQjbQxKdH2aBStViMFnt64f");
+
+/// The address of the Switchboard V2 oracle program in devnet.
+pub const SWITCHBOARD_DEVNET_ID: Pubkey =
+    solana_program::pubkey!("2TfB33aLaneQb5TNVwyDz3jSZXS6jdW2ARw1Dgf84XCG");
+
+const SWITCHBOARD_AGGREGATOR_DISCRIMINATOR: [u8; 8] = [217, 230, 65, 101, 201, 162, 27, 125];
+const SWITCHBOARD_AGGREGATOR_HEADER_SIZE: usize = 406;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Switchboard;
+
+impl ProgramType for Switchboard {
+    fn name() -> &'static str {
+        "Switchboard"
+    }
+
+    /// The mainnet address unless the `oracles-devnet` feature is enabled.
+    fn address() -> &'static Pubkey {
+        if cfg!(feature = "oracles-devnet") {
+            &SWITCHBOARD_DEVNET_ID
+        } else {
+            &SWITCHBOARD_MAINNET_ID
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ACCOUNTS -------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A Switchboard V2 aggregator account. Only the result of the latest
+/// confirmed round is read.
+///
+/// The discriminator is checked while deserializing, and the owner of the
+/// account when it is loaded into an [Account](crate::models::Account).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SwitchboardAggregator {
+    /// The minimum number of oracle responses required to accept a round.
+    pub min_oracle_results: u32,
+
+    /// The number of successful oracle responses of the latest round.
+    pub num_success: u32,
+
+    /// The slot in which the latest round was opened.
+    pub round_open_slot: u64,
+
+    /// The unix timestamp at which the latest round was opened.
+    pub round_open_timestamp: i64,
+
+    /// The mantissa of the result.
+    pub result_mantissa: i128,
+
+    /// The number of decimals of the result.
+    pub result_scale: u32,
+
+    /// The mantissa of the standard deviation of the oracle responses.
+    pub std_deviation_mantissa: i128,
+
+    /// The number of decimals of the standard deviation.
+    pub std_deviation_scale: u32,
+}
+
+impl SwitchboardAggregator {
+    // METHODS ----------------------------------------------------------------
+
+    /// Gets the result of the latest round failing if it did not get enough
+    /// oracle responses or if it is older than `max_age` seconds.
+    ///
+    /// The standard deviation is used as the confidence interval.
+    pub fn get_price(&self, max_age: u64) -> FankorResult<OraclePrice> {
+        let clock = Clock::get()?;
+        self.get_price_at(clock.unix_timestamp, max_age)
+    }
+
+    /// Same as [SwitchboardAggregator::get_price] but using `now` as the
+    /// current unix timestamp.
+    pub fn get_price_at(&self, now: i64, max_age: u64) -> FankorResult<OraclePrice> {
+        if self.num_success == 0 || self.num_success < self.min_oracle_results {
+            return Err(FankorErrorCode::OraclePriceUnavailable.into());
+        }
+
+        check_oracle_price_age(self.round_open_timestamp, now, max_age)?;
+
+        let exponent = -(self.result_scale as i32);
+        let price = i64::try_from(self.result_mantissa).map_err(|_| {
+            FankorErrorCode::ArithmeticOverflow {
+                operation: "SwitchboardAggregator::get_price",
+            }
+        })?;
+        let confidence = scale_value(
+            self.std_deviation_mantissa,
+            -(self.std_deviation_scale as i32),
+            exponent,
+        )
+        .and_then(|v| u64::try_from(v.unsigned_abs()).ok())
+        .ok_or(FankorErrorCode::ArithmeticOverflow {
+            operation: "SwitchboardAggregator::get_price",
+        })?;
+
+        Ok(OraclePrice {
+            price,
+            confidence,
+            exponent,
+            publish_time: self.round_open_timestamp,
+        })
+    }
+}
+
+impl AccountType for SwitchboardAggregator {
+    fn discriminant() -> u8 {
+        0
+    }
+
+    fn owner() -> &'static Pubkey {
+        Switchboard::address()
+    }
+}
+
+#[cfg(any(feature = "test-utils", test))]
+impl BorshSerialize for SwitchboardAggregator {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut buf = [0u8; SWITCHBOARD_AGGREGATOR_HEADER_SIZE];
+        buf[0..8].copy_from_slice(&SWITCHBOARD_AGGREGATOR_DISCRIMINATOR);
+        buf[236..240].copy_from_slice(&self.min_oracle_results.to_le_bytes());
+        buf[341..345].copy_from_slice(&self.num_success.to_le_bytes());
+        buf[350..358].copy_from_slice(&self.round_open_slot.to_le_bytes());
+        buf[358..366].copy_from_slice(&self.round_open_timestamp.to_le_bytes());
+        buf[366..382].copy_from_slice(&self.result_mantissa.to_le_bytes());
+        buf[382..386].copy_from_slice(&self.result_scale.to_le_bytes());
+        buf[386..402].copy_from_slice(&self.std_deviation_mantissa.to_le_bytes());
+        buf[402..406].copy_from_slice(&self.std_deviation_scale.to_le_bytes());
+
+        writer.write_all(&buf)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(not(any(feature = "test-utils", test)))]
+impl BorshSerialize for SwitchboardAggregator {
+    fn serialize<W: Write>(&self, _writer: &mut W) -> std::io::Result<()> {
+        unreachable!("Cannot write accounts that does not belong to the current program")
+    }
+}
+
+impl BorshDeserialize for SwitchboardAggregator {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if buf.len() < SWITCHBOARD_AGGREGATOR_HEADER_SIZE
+            || buf[0..8] != SWITCHBOARD_AGGREGATOR_DISCRIMINATOR
+        {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Invalid Switchboard aggregator account",
+            ));
+        }
+
+        let data = &buf[..SWITCHBOARD_AGGREGATOR_HEADER_SIZE];
+        let read_u32 =
+            |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        let read_u64 =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        let read_i128 =
+            |offset: usize| i128::from_le_bytes(data[offset..offset + 16].try_into().unwrap());
+
+        let result = SwitchboardAggregator {
+            min_oracle_results: read_u32(236),
+            num_success: read_u32(341),
+            round_open_slot: read_u64(350),
+            round_open_timestamp: read_u64(358) as i64,
+            result_mantissa: read_i128(366),
+            result_scale: read_u32(382),
+            std_deviation_mantissa: read_i128(386),
+            std_deviation_scale: read_u32(402),
+        };
+
+        // The rest of the aggregator is ignored.
+        *buf = &buf[buf.len()..];
+
+        Ok(result)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn aggregator() -> SwitchboardAggregator {
+        SwitchboardAggregator {
+            min_oracle_results: 2,
+            num_success: 3,
+            round_open_slot: 50,
+            round_open_timestamp: 1_000,
+            result_mantissa: 21_000_000,
+            result_scale: 6,
+            std_deviation_mantissa: 15,
+            std_deviation_scale: 3,
+        }
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let account = aggregator();
+        let mut bytes = account.try_to_vec().unwrap();
+        bytes.extend_from_slice(&[1; 200]);
+
+        let mut buf = bytes.as_slice();
+        let deserialized = SwitchboardAggregator::deserialize(&mut buf).unwrap();
+
+        assert_eq!(deserialized, account);
+        assert!(buf.is_empty());
+
+        bytes[0] = 0;
+        assert!(SwitchboardAggregator::deserialize(&mut bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_get_price() {
+        let mut account = aggregator();
+
+        let price = account.get_price_at(1_060, 60).unwrap();
+        assert_eq!(price.price, 21_000_000);
+        assert_eq!(price.confidence, 15_000);
+        assert_eq!(price.exponent, -6);
+
+        assert!(account.get_price_at(1_061, 60).is_err());
+
+        account.num_success = 1;
+        assert!(account.get_price_at(1_000, 60).is_err());
+    }
+}
@@ -12,9 +12,11 @@ use crate::cpi;
 use crate::cpi::associated_token::CpiCreateAssociatedTokenAccount;
 use crate::cpi::system_program::CpiCreateAccount;
 use crate::cpi::token::{CpiInitializeAccount3, CpiInitializeMint2, CpiInitializeMultisig2};
-use crate::errors::FankorResult;
+use crate::errors::{FankorErrorCode, FankorResult};
 use crate::models::programs::macros::impl_account;
-use crate::models::{Account, AssociatedToken, Program, System, UninitializedAccount};
+use crate::models::{
+    Account, AssociatedToken, FankorContext, Program, System, UninitializedAccount,
+};
 use crate::traits::ProgramType;
 
 #[derive(Debug, Copy, Clone)]
@@ -367,3 +369,57 @@ impl TokenMultisig {
         )
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Fails if `holder` is not a signer or if its associated token account of
+/// `mint` holds less than `min_amount` tokens. The associated token account
+/// must be among the accounts of the instruction.
+///
+/// This is the check of `#[account(token_gate = (mint, min_amount))]`.
+pub fn check_token_gate<'info>(
+    context: &FankorContext<'info>,
+    holder: &AccountInfo<'info>,
+    mint: &Pubkey,
+    min_amount: u64,
+    account: &'static str,
+) -> FankorResult<()> {
+    if !holder.is_signer {
+        return Err(FankorErrorCode::AccountConstraintNotSigner { account }.into());
+    }
+
+    let address = spl_associated_token_account::get_associated_token_address(holder.key, mint);
+    let token_account = match context.get_account_from_address(&address) {
+        Some(v) => v,
+        None => {
+            return Err(FankorErrorCode::TokenGateAccountMissing {
+                account,
+                token_account: address,
+            }
+            .into());
+        }
+    };
+
+    if token_account.owner != &spl_token::ID {
+        return Err(FankorErrorCode::AccountOwnedByWrongProgram {
+            address,
+            expected: spl_token::ID,
+            actual: *token_account.owner,
+        }
+        .into());
+    }
+
+    let amount = spl_token::state::Account::unpack(&token_account.try_borrow_data()?)?.amount;
+    if amount < min_amount {
+        return Err(FankorErrorCode::TokenGateNotMet {
+            account,
+            amount,
+            min_amount,
+        }
+        .into());
+    }
+
+    Ok(())
+}
@@ -8,6 +8,9 @@ use solana_program::pubkey::Pubkey;
 use solana_program::rent::Rent;
 use solana_program::sysvar::Sysvar;
 
+use spl_token_2022::extension::memo_transfer::MemoTransfer;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+
 use crate::cpi;
 use crate::cpi::associated_token::CpiCreateAssociatedTokenAccount;
 use crate::cpi::system_program::CpiCreateAccount;
@@ -30,6 +33,22 @@ impl ProgramType for Token2022 {
     }
 }
 
+/// The address of the SPL Memo program (v2).
+pub const MEMO_ID: Pubkey = solana_program::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+#[derive(Debug, Copy, Clone)]
+pub struct Memo;
+
+impl ProgramType for Memo {
+    fn name() -> &'static str {
+        "Memo"
+    }
+
+    fn address() -> &'static Pubkey {
+        &MEMO_ID
+    }
+}
+
 // ----------------------------------------------------------------------------
 // ACCOUNTS -------------------------------------------------------------------
 // ----------------------------------------------------------------------------
@@ -157,6 +176,17 @@ impl Mint2022 {
 impl TokenAccount2022 {
     // STATIC METHODS ---------------------------------------------------------
 
+    /// Whether the token account `info` has the memo-transfer extension
+    /// enabled, i.e. incoming transfers must be preceded by a memo.
+    pub fn memo_required(info: &AccountInfo) -> FankorResult<bool> {
+        let data = info.try_borrow_data()?;
+        let state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+
+        Ok(state
+            .get_extension::<MemoTransfer>()
+            .map_or(false, |v| bool::from(&v.require_incoming_transfer_memos)))
+    }
+
     /// Initializes a TokenAccount.
     pub fn init<'info>(
         account_to_init: UninitializedAccount<'info>,
@@ -0,0 +1,524 @@
+use std::io::{ErrorKind, Write};
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::AccountMeta;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::traits::{AccountType, ProgramType};
+
+/// The discriminator of the `Execute` instruction of the transfer hook
+/// interface, i.e. the first 8 bytes of
+/// `sha256("spl-transfer-hook-interface:execute")`.
+pub const TRANSFER_HOOK_EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
+
+/// The seed of the PDA that stores the [ExtraAccountMetaList] of a mint.
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// The size of the discriminator, length and count fields of an
+/// [ExtraAccountMetaList].
+const EXTRA_ACCOUNT_META_LIST_HEADER_SIZE: usize = 8 + size_of::<u32>() + size_of::<u32>();
+
+/// The size of the [ExtraAccountMeta] in its serialized form.
+const EXTRA_ACCOUNT_META_SIZE: usize = 1 + 32 + 1 + 1;
+
+/// The number of accounts of the `Execute` instruction before the extra ones:
+/// source, mint, destination, authority and the extra account meta list.
+const EXECUTE_ACCOUNT_COUNT: usize = 5;
+
+/// The seeds of an [ExtraAccountMeta] whose address is a PDA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtraAccountMetaSeed {
+    /// A fixed value.
+    Literal { bytes: Vec<u8> },
+
+    /// A slice of the data of the `Execute` instruction, including its
+    /// discriminator, e.g. `{ index: 8, length: 8 }` is the amount.
+    InstructionData { index: u8, length: u8 },
+
+    /// The address of an account of the `Execute` instruction, including the
+    /// already resolved extra accounts.
+    AccountKey { index: u8 },
+}
+
+/// An extra account required by a transfer hook program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ExtraAccountMeta {
+    /// Whether `address_config` is the address itself (0) or the seeds of a
+    /// PDA of the hook program (1).
+    pub discriminator: u8,
+    pub address_config: [u8; 32],
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl ExtraAccountMeta {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a meta for a fixed address.
+    pub fn new_with_pubkey(address: &Pubkey, is_signer: bool, is_writable: bool) -> Self {
+        Self {
+            discriminator: 0,
+            address_config: address.to_bytes(),
+            is_signer,
+            is_writable,
+        }
+    }
+
+    /// Creates a meta for a PDA of the hook program. The seeds must fit in
+    /// 32 bytes once packed.
+    pub fn new_with_seeds(
+        seeds: &[ExtraAccountMetaSeed],
+        is_signer: bool,
+        is_writable: bool,
+    ) -> FankorResult<Self> {
+        let mut address_config = [0u8; 32];
+        let mut offset = 0;
+
+        for seed in seeds {
+            let packed = match seed {
+                ExtraAccountMetaSeed::Literal { bytes } => {
+                    let length = u8::try_from(bytes.len())
+                        .map_err(|_| FankorErrorCode::TransferHookInvalidExtraAccountMeta)?;
+                    let mut packed = vec![1, length];
+                    packed.extend_from_slice(bytes);
+                    packed
+                }
+                ExtraAccountMetaSeed::InstructionData { index, length } => {
+                    vec![2, *index, *length]
+                }
+                ExtraAccountMetaSeed::AccountKey { index } => vec![3, *index],
+            };
+
+            let end = offset + packed.len();
+            if end > address_config.len() {
+                return Err(FankorErrorCode::TransferHookInvalidExtraAccountMeta.into());
+            }
+
+            address_config[offset..end].copy_from_slice(&packed);
+            offset = end;
+        }
+
+        Ok(Self {
+            discriminator: 1,
+            address_config,
+            is_signer,
+            is_writable,
+        })
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Unpacks the seeds of a PDA meta.
+    pub fn seeds(&self) -> FankorResult<Vec<ExtraAccountMetaSeed>> {
+        let config = &self.address_config;
+        let mut seeds = Vec::new();
+        let mut offset = 0;
+
+        while offset < config.len() {
+            let seed = match config[offset] {
+                0 => break,
+                1 => {
+                    let length = *config
+                        .get(offset + 1)
+                        .ok_or(FankorErrorCode::TransferHookInvalidExtraAccountMeta)?
+                        as usize;
+                    let bytes = config
+                        .get(offset + 2..offset + 2 + length)
+                        .ok_or(FankorErrorCode::TransferHookInvalidExtraAccountMeta)?;
+                    offset += 2 + length;
+
+                    ExtraAccountMetaSeed::Literal {
+                        bytes: bytes.to_vec(),
+                    }
+                }
+                2 => {
+                    let bytes = config
+                        .get(offset + 1..offset + 3)
+                        .ok_or(FankorErrorCode::TransferHookInvalidExtraAccountMeta)?;
+                    offset += 3;
+
+                    ExtraAccountMetaSeed::InstructionData {
+                        index: bytes[0],
+                        length: bytes[1],
+                    }
+                }
+                3 => {
+                    let index = *config
+                        .get(offset + 1)
+                        .ok_or(FankorErrorCode::TransferHookInvalidExtraAccountMeta)?;
+                    offset += 2;
+
+                    ExtraAccountMetaSeed::AccountKey { index }
+                }
+                _ => return Err(FankorErrorCode::TransferHookInvalidExtraAccountMeta.into()),
+            };
+
+            seeds.push(seed);
+        }
+
+        Ok(seeds)
+    }
+
+    /// Computes the account of this meta given the data of the `Execute`
+    /// instruction and its accounts, including the already resolved extra
+    /// accounts.
+    pub fn resolve(
+        &self,
+        instruction_data: &[u8],
+        hook_program_id: &Pubkey,
+        accounts: &[AccountMeta],
+    ) -> FankorResult<AccountMeta> {
+        let address = match self.discriminator {
+            0 => Pubkey::new_from_array(self.address_config),
+            1 => {
+                let seeds = self.seeds()?;
+                let mut seed_bytes = Vec::with_capacity(seeds.len());
+
+                for seed in &seeds {
+                    let bytes = match seed {
+                        ExtraAccountMetaSeed::Literal { bytes } => bytes.as_slice(),
+                        ExtraAccountMetaSeed::InstructionData { index, length } => {
+                            let start = *index as usize;
+                            instruction_data
+                                .get(start..start + *length as usize)
+                                .ok_or(FankorErrorCode::TransferHookInvalidExtraAccountMeta)?
+                        }
+                        ExtraAccountMetaSeed::AccountKey { index } => accounts
+                            .get(*index as usize)
+                            .ok_or(FankorErrorCode::TransferHookInvalidExtraAccountMeta)?
+                            .pubkey
+                            .as_ref(),
+                    };
+
+                    seed_bytes.push(bytes);
+                }
+
+                Pubkey::find_program_address(&seed_bytes, hook_program_id).0
+            }
+            _ => return Err(FankorErrorCode::TransferHookInvalidExtraAccountMeta.into()),
+        };
+
+        Ok(AccountMeta {
+            pubkey: address,
+            is_signer: self.is_signer,
+            is_writable: self.is_writable,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ACCOUNTS -------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The list of extra accounts required by the `Execute` instruction of the
+/// transfer hook program `P`, stored in the PDA
+/// [`["extra-account-metas", mint]`](ExtraAccountMetaList::get_pda_seeds).
+///
+/// It is serialized in the TLV format of the transfer hook interface so it is
+/// readable by any client of Token-2022.
+#[derive(Debug, Clone)]
+pub struct ExtraAccountMetaList<P: ProgramType> {
+    metas: Vec<ExtraAccountMeta>,
+    _program: PhantomData<P>,
+}
+
+impl<P: ProgramType> ExtraAccountMetaList<P> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(metas: Vec<ExtraAccountMeta>) -> Self {
+        Self {
+            metas,
+            _program: PhantomData,
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn metas(&self) -> &[ExtraAccountMeta] {
+        &self.metas
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Resolves the extra accounts of an `Execute` instruction whose first
+    /// accounts are `execute_accounts`: source, mint, destination, authority
+    /// and this list.
+    pub fn resolve(
+        &self,
+        instruction_data: &[u8],
+        execute_accounts: &[AccountMeta],
+    ) -> FankorResult<Vec<AccountMeta>> {
+        resolve_extra_account_metas(
+            &self.metas,
+            instruction_data,
+            P::address(),
+            execute_accounts,
+        )
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// The size of a list with `count` metas.
+    pub fn space(count: usize) -> usize {
+        EXTRA_ACCOUNT_META_LIST_HEADER_SIZE + count * EXTRA_ACCOUNT_META_SIZE
+    }
+
+    pub fn get_pda_seeds(mint: &Pubkey) -> [&[u8]; 2] {
+        [EXTRA_ACCOUNT_METAS_SEED, mint.as_ref()]
+    }
+
+    pub fn find_address(mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::get_pda_seeds(mint), P::address())
+    }
+}
+
+impl<P: ProgramType> PartialEq for ExtraAccountMetaList<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.metas == other.metas
+    }
+}
+
+impl<P: ProgramType> AccountType for ExtraAccountMetaList<P> {
+    fn discriminant() -> u8 {
+        0
+    }
+
+    fn owner() -> &'static Pubkey {
+        P::address()
+    }
+}
+
+impl<P: ProgramType> BorshSerialize for ExtraAccountMetaList<P> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let count = u32::try_from(self.metas.len())
+            .map_err(|e| std::io::Error::new(ErrorKind::InvalidInput, e))?;
+        let length = size_of::<u32>() + self.metas.len() * EXTRA_ACCOUNT_META_SIZE;
+
+        writer.write_all(&TRANSFER_HOOK_EXECUTE_DISCRIMINATOR)?;
+        (length as u32).serialize(writer)?;
+        count.serialize(writer)?;
+
+        for meta in &self.metas {
+            meta.serialize(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<P: ProgramType> BorshDeserialize for ExtraAccountMetaList<P> {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        Ok(Self::new(unpack_extra_account_metas(buf)?))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// INSTRUCTIONS ---------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The accounts and arguments of the `Execute` instruction of the transfer
+/// hook interface, dispatched by programs declared with
+/// `#[program(transfer_hook)]`.
+pub struct TransferHookExecute<'info> {
+    pub source: &'info AccountInfo<'info>,
+    pub mint: &'info AccountInfo<'info>,
+    pub destination: &'info AccountInfo<'info>,
+    pub authority: &'info AccountInfo<'info>,
+    pub extra_account_metas: &'info AccountInfo<'info>,
+    pub extra_accounts: &'info [AccountInfo<'info>],
+    pub amount: u64,
+}
+
+impl<'info> TransferHookExecute<'info> {
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses an `Execute` instruction. Returns `None` if `data` does not
+    /// start with [TRANSFER_HOOK_EXECUTE_DISCRIMINATOR].
+    pub fn try_from_instruction(
+        accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> FankorResult<Option<Self>> {
+        if !data.starts_with(&TRANSFER_HOOK_EXECUTE_DISCRIMINATOR) {
+            return Ok(None);
+        }
+
+        let mut args = &data[TRANSFER_HOOK_EXECUTE_DISCRIMINATOR.len()..];
+        let amount = u64::deserialize(&mut args)?;
+
+        if accounts.len() < EXECUTE_ACCOUNT_COUNT {
+            return Err(FankorErrorCode::NotEnoughAccountKeys.into());
+        }
+
+        Ok(Some(TransferHookExecute {
+            source: &accounts[0],
+            mint: &accounts[1],
+            destination: &accounts[2],
+            authority: &accounts[3],
+            extra_account_metas: &accounts[4],
+            extra_accounts: &accounts[EXECUTE_ACCOUNT_COUNT..],
+            amount,
+        }))
+    }
+
+    /// The data of an `Execute` instruction transferring `amount`.
+    pub fn instruction_data(amount: u64) -> Vec<u8> {
+        let mut data = Vec::with_capacity(TRANSFER_HOOK_EXECUTE_DISCRIMINATOR.len() + 8);
+        data.extend_from_slice(&TRANSFER_HOOK_EXECUTE_DISCRIMINATOR);
+        data.extend_from_slice(&amount.to_le_bytes());
+        data
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Reads the metas of an [ExtraAccountMetaList] regardless of its program.
+pub(crate) fn unpack_extra_account_metas(
+    buf: &mut &[u8],
+) -> std::io::Result<Vec<ExtraAccountMeta>> {
+    if buf.len() < EXTRA_ACCOUNT_META_LIST_HEADER_SIZE
+        || buf[..8] != TRANSFER_HOOK_EXECUTE_DISCRIMINATOR
+    {
+        return Err(std::io::Error::new(
+            ErrorKind::InvalidData,
+            "Invalid extra account meta list",
+        ));
+    }
+
+    *buf = &buf[8..];
+    let length = u32::deserialize(buf)? as usize;
+    let count = u32::deserialize(buf)? as usize;
+
+    if length != size_of::<u32>() + count * EXTRA_ACCOUNT_META_SIZE
+        || buf.len() < count * EXTRA_ACCOUNT_META_SIZE
+    {
+        return Err(std::io::Error::new(
+            ErrorKind::InvalidData,
+            "Invalid extra account meta list",
+        ));
+    }
+
+    let mut metas = Vec::with_capacity(count);
+    for _ in 0..count {
+        metas.push(ExtraAccountMeta::deserialize(buf)?);
+    }
+
+    Ok(metas)
+}
+
+/// Resolves `metas` in order, so each one can refer to the previous ones.
+pub(crate) fn resolve_extra_account_metas(
+    metas: &[ExtraAccountMeta],
+    instruction_data: &[u8],
+    hook_program_id: &Pubkey,
+    execute_accounts: &[AccountMeta],
+) -> FankorResult<Vec<AccountMeta>> {
+    let mut accounts = execute_accounts.to_vec();
+    accounts.reserve(metas.len());
+
+    for meta in metas {
+        let account = meta.resolve(instruction_data, hook_program_id, &accounts)?;
+        accounts.push(account);
+    }
+
+    Ok(accounts.split_off(execute_accounts.len()))
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::models::System;
+
+    use super::*;
+
+    #[test]
+    fn test_seeds() {
+        let seeds = vec![
+            ExtraAccountMetaSeed::Literal {
+                bytes: b"hook".to_vec(),
+            },
+            ExtraAccountMetaSeed::InstructionData {
+                index: 8,
+                length: 8,
+            },
+            ExtraAccountMetaSeed::AccountKey { index: 1 },
+        ];
+        let meta = ExtraAccountMeta::new_with_seeds(&seeds, false, true).unwrap();
+
+        assert_eq!(meta.seeds().unwrap(), seeds);
+        assert!(ExtraAccountMeta::new_with_seeds(
+            &[ExtraAccountMetaSeed::Literal { bytes: vec![0; 31] }],
+            false,
+            false
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let fixed = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let list = ExtraAccountMetaList::<System>::new(vec![
+            ExtraAccountMeta::new_with_pubkey(&fixed, false, false),
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    ExtraAccountMetaSeed::Literal {
+                        bytes: b"counter".to_vec(),
+                    },
+                    ExtraAccountMetaSeed::AccountKey { index: 1 },
+                    ExtraAccountMetaSeed::AccountKey { index: 5 },
+                ],
+                false,
+                true,
+            )
+            .unwrap(),
+        ]);
+
+        let execute_accounts = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(ExtraAccountMetaList::<System>::find_address(&mint).0, false),
+        ];
+        let data = TransferHookExecute::instruction_data(100);
+        let resolved = list.resolve(&data, &execute_accounts).unwrap();
+
+        let (counter, _) = Pubkey::find_program_address(
+            &[b"counter", mint.as_ref(), fixed.as_ref()],
+            System::address(),
+        );
+        assert_eq!(
+            resolved,
+            vec![
+                AccountMeta::new_readonly(fixed, false),
+                AccountMeta::new(counter, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let list = ExtraAccountMetaList::<System>::new(vec![
+            ExtraAccountMeta::new_with_pubkey(&Pubkey::new_unique(), false, true),
+            ExtraAccountMeta::new_with_pubkey(&Pubkey::new_unique(), true, false),
+        ]);
+        let bytes = list.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), ExtraAccountMetaList::<System>::space(2));
+
+        let deserialized = ExtraAccountMetaList::<System>::try_from_slice(&bytes).unwrap();
+        assert_eq!(deserialized, list);
+
+        let mut bytes = bytes;
+        bytes[0] = 0;
+        assert!(ExtraAccountMetaList::<System>::try_from_slice(&bytes).is_err());
+    }
+}
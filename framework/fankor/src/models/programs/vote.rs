@@ -0,0 +1,313 @@
+use std::io::{ErrorKind, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::clock::Clock;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::traits::{AccountType, ProgramType};
+
+/// The address of the vote program.
+pub const VOTE_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("Vote111111111111111111111111111111111111111");
+
+const VOTE_STATE_VERSION_1_14_11: u32 = 1;
+const VOTE_STATE_VERSION_CURRENT: u32 = 2;
+const VOTE_PRIOR_VOTERS_SIZE: usize = 32 * (32 + 8 + 8) + 8 + 1;
+
+#[derive(Debug, Copy, Clone)]
+pub struct VoteProgram;
+
+impl ProgramType for VoteProgram {
+    fn name() -> &'static str {
+        "VoteProgram"
+    }
+
+    fn address() -> &'static Pubkey {
+        &VOTE_PROGRAM_ID
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ACCOUNTS -------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The credits earned by a vote account in an epoch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct VoteEpochCredits {
+    pub epoch: u64,
+
+    /// The total credits of the account at the end of the epoch.
+    pub credits: u64,
+
+    /// The total credits of the account at the end of the previous epoch.
+    pub prev_credits: u64,
+}
+
+/// A vote account of a validator. Only the state of the versions
+/// `1.14.11` and the current one can be read, the votes and the prior voters
+/// are skipped.
+///
+/// The owner of the account is checked when it is loaded into an
+/// [Account](crate::models::Account).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VoteAccount {
+    /// The identity of the validator.
+    pub node_pubkey: Pubkey,
+
+    /// The authority that can withdraw the lamports of the account.
+    pub authorized_withdrawer: Pubkey,
+
+    /// The percentage (0-100) of the rewards the validator keeps.
+    pub commission: u8,
+
+    /// The slot of the most recent vote, if any.
+    pub last_vote_slot: Option<u64>,
+
+    /// The most recent rooted slot, if any.
+    pub root_slot: Option<u64>,
+
+    /// The authorized voters by the epoch since they are valid.
+    pub authorized_voters: Vec<(u64, Pubkey)>,
+
+    /// The credits of the most recent epochs, the last one is the newest.
+    pub epoch_credits: Vec<VoteEpochCredits>,
+
+    /// The slot of the most recent timestamp submitted with a vote.
+    pub last_timestamp_slot: u64,
+
+    /// The most recent timestamp submitted with a vote.
+    pub last_timestamp: i64,
+}
+
+impl VoteAccount {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The credits earned in `epoch`, or zero if the account did not vote.
+    pub fn credits_in_epoch(&self, epoch: u64) -> u64 {
+        self.epoch_credits
+            .iter()
+            .find(|v| v.epoch == epoch)
+            .map(|v| v.credits.saturating_sub(v.prev_credits))
+            .unwrap_or(0)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Fails if the last vote of the account is older than `max_age` slots.
+    pub fn check_recent_vote(&self, max_age: u64) -> FankorResult<()> {
+        let clock = Clock::get()?;
+        self.check_recent_vote_at(clock.slot, max_age)
+    }
+
+    /// Same as [VoteAccount::check_recent_vote] but using `slot` as the
+    /// current slot.
+    pub fn check_recent_vote_at(&self, slot: u64, max_age: u64) -> FankorResult<()> {
+        let age = match self.last_vote_slot {
+            Some(last_vote_slot) => slot.saturating_sub(last_vote_slot),
+            None => u64::MAX,
+        };
+
+        if age > max_age {
+            return Err(FankorErrorCode::VoteAccountStale { age, max_age }.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl AccountType for VoteAccount {
+    fn discriminant() -> u8 {
+        0
+    }
+
+    fn owner() -> &'static Pubkey {
+        VoteProgram::address()
+    }
+}
+
+impl BorshSerialize for VoteAccount {
+    fn serialize<W: Write>(&self, _writer: &mut W) -> std::io::Result<()> {
+        unreachable!("Cannot write accounts that does not belong to the current program")
+    }
+}
+
+impl BorshDeserialize for VoteAccount {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let mut data = *buf;
+        let version = u32::deserialize(&mut data)?;
+
+        // Size of each vote: the latency in the current version plus the slot
+        // and the confirmation count.
+        let vote_size = match version {
+            VOTE_STATE_VERSION_1_14_11 => 8 + 4,
+            VOTE_STATE_VERSION_CURRENT => 1 + 8 + 4,
+            _ => {
+                return Err(std::io::Error::new(
+                    ErrorKind::InvalidData,
+                    "Unsupported vote account version",
+                ));
+            }
+        };
+
+        let node_pubkey = Pubkey::deserialize(&mut data)?;
+        let authorized_withdrawer = Pubkey::deserialize(&mut data)?;
+        let commission = u8::deserialize(&mut data)?;
+
+        // Votes, only the slot of the last one is read.
+        let votes = u64::deserialize(&mut data)? as usize;
+        let votes_size = votes.saturating_mul(vote_size);
+        let votes_data = skip_bytes(&mut data, votes_size)?;
+        let last_vote_slot = if votes == 0 {
+            None
+        } else {
+            // The slot and the confirmation count are at the end of each vote.
+            let mut last_vote = &votes_data[votes_size - 12..];
+            Some(u64::deserialize(&mut last_vote)?)
+        };
+
+        let root_slot = Option::<u64>::deserialize(&mut data)?;
+
+        let authorized_voters_len = u64::deserialize(&mut data)? as usize;
+        let mut authorized_voters = Vec::with_capacity(authorized_voters_len.min(64));
+        for _ in 0..authorized_voters_len {
+            let epoch = u64::deserialize(&mut data)?;
+            let voter = Pubkey::deserialize(&mut data)?;
+            authorized_voters.push((epoch, voter));
+        }
+
+        skip_bytes(&mut data, VOTE_PRIOR_VOTERS_SIZE)?;
+
+        let epoch_credits_len = u64::deserialize(&mut data)? as usize;
+        let mut epoch_credits = Vec::with_capacity(epoch_credits_len.min(64));
+        for _ in 0..epoch_credits_len {
+            epoch_credits.push(VoteEpochCredits {
+                epoch: u64::deserialize(&mut data)?,
+                credits: u64::deserialize(&mut data)?,
+                prev_credits: u64::deserialize(&mut data)?,
+            });
+        }
+
+        let last_timestamp_slot = u64::deserialize(&mut data)?;
+        let last_timestamp = i64::deserialize(&mut data)?;
+
+        // The rest of the account is padding.
+        *buf = &buf[buf.len()..];
+
+        Ok(VoteAccount {
+            node_pubkey,
+            authorized_withdrawer,
+            commission,
+            last_vote_slot,
+            root_slot,
+            authorized_voters,
+            epoch_credits,
+            last_timestamp_slot,
+            last_timestamp,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn skip_bytes<'a>(buf: &mut &'a [u8], size: usize) -> std::io::Result<&'a [u8]> {
+    if buf.len() < size {
+        return Err(std::io::Error::new(
+            ErrorKind::InvalidData,
+            "Vote account too small",
+        ));
+    }
+
+    let (skipped, rest) = buf.split_at(size);
+    *buf = rest;
+
+    Ok(skipped)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn vote_account_bytes(node_pubkey: &Pubkey, withdrawer: &Pubkey) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        VOTE_STATE_VERSION_CURRENT.serialize(&mut bytes).unwrap();
+        node_pubkey.serialize(&mut bytes).unwrap();
+        withdrawer.serialize(&mut bytes).unwrap();
+        bytes.push(5);
+
+        // Votes.
+        2u64.serialize(&mut bytes).unwrap();
+        for slot in [100u64, 101] {
+            bytes.push(1);
+            slot.serialize(&mut bytes).unwrap();
+            1u32.serialize(&mut bytes).unwrap();
+        }
+
+        Some(90u64).serialize(&mut bytes).unwrap();
+
+        // Authorized voters.
+        1u64.serialize(&mut bytes).unwrap();
+        3u64.serialize(&mut bytes).unwrap();
+        node_pubkey.serialize(&mut bytes).unwrap();
+
+        bytes.extend_from_slice(&[0; VOTE_PRIOR_VOTERS_SIZE]);
+
+        // Epoch credits.
+        1u64.serialize(&mut bytes).unwrap();
+        3u64.serialize(&mut bytes).unwrap();
+        150u64.serialize(&mut bytes).unwrap();
+        100u64.serialize(&mut bytes).unwrap();
+
+        101u64.serialize(&mut bytes).unwrap();
+        1_000i64.serialize(&mut bytes).unwrap();
+
+        bytes.extend_from_slice(&[0; 64]);
+        bytes
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let node_pubkey = Pubkey::new_unique();
+        let withdrawer = Pubkey::new_unique();
+        let bytes = vote_account_bytes(&node_pubkey, &withdrawer);
+
+        let mut buf = bytes.as_slice();
+        let account = VoteAccount::deserialize(&mut buf).unwrap();
+
+        assert!(buf.is_empty());
+        assert_eq!(account.node_pubkey, node_pubkey);
+        assert_eq!(account.authorized_withdrawer, withdrawer);
+        assert_eq!(account.commission, 5);
+        assert_eq!(account.last_vote_slot, Some(101));
+        assert_eq!(account.root_slot, Some(90));
+        assert_eq!(account.authorized_voters, vec![(3, node_pubkey)]);
+        assert_eq!(account.credits_in_epoch(3), 50);
+        assert_eq!(account.credits_in_epoch(4), 0);
+        assert_eq!(account.last_timestamp_slot, 101);
+        assert_eq!(account.last_timestamp, 1_000);
+
+        let mut bytes = bytes;
+        bytes[0] = 0;
+        assert!(VoteAccount::deserialize(&mut bytes.as_slice()).is_err());
+        assert!(VoteAccount::deserialize(&mut &bytes[..100]).is_err());
+    }
+
+    #[test]
+    fn test_check_recent_vote() {
+        let account = VoteAccount {
+            last_vote_slot: Some(100),
+            ..Default::default()
+        };
+
+        assert!(account.check_recent_vote_at(150, 50).is_ok());
+        assert!(account.check_recent_vote_at(151, 50).is_err());
+        assert!(VoteAccount::default().check_recent_vote_at(0, 50).is_err());
+    }
+}
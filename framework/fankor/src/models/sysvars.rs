@@ -0,0 +1,272 @@
+#![allow(deprecated)]
+
+use std::io::Write;
+use std::mem::size_of;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::hash::{Hash, HASH_BYTES};
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::recent_blockhashes::RecentBlockhashes;
+use solana_program::sysvar::slot_hashes::SlotHashes;
+use solana_program::sysvar::SysvarId;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::SysvarAccount;
+
+/// The address of the EpochRewards sysvar.
+pub const EPOCH_REWARDS_SYSVAR_ID: Pubkey =
+    solana_program::pubkey!("SysvarEpochRewards1111111111111111111111111");
+
+/// The size of each entry of the SlotHashes sysvar: `slot: u64 + hash: Hash`.
+const SLOT_HASH_ENTRY_SIZE: usize = size_of::<u64>() + HASH_BYTES;
+
+/// The size of each entry of the RecentBlockhashes sysvar:
+/// `blockhash: Hash + lamports_per_signature: u64`.
+const RECENT_BLOCKHASH_ENTRY_SIZE: usize = HASH_BYTES + size_of::<u64>();
+
+/// The EpochRewards sysvar, that tracks the partitioned distribution of the
+/// staking rewards at the beginning of each epoch.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct EpochRewards {
+    /// The block height at which the distribution of the rewards started.
+    pub distribution_starting_block_height: u64,
+
+    /// The number of partitions the rewards are divided into.
+    pub num_partitions: u64,
+
+    /// The blockhash of the parent block of the first block of the epoch,
+    /// used to seed the partitions.
+    pub parent_blockhash: Hash,
+
+    /// The total points of the rewards calculation.
+    pub total_points: u128,
+
+    /// The total rewards to distribute in the current epoch, in lamports.
+    pub total_rewards: u64,
+
+    /// The rewards distributed so far, in lamports.
+    pub distributed_rewards: u64,
+
+    /// Whether the rewards are currently being distributed.
+    pub active: bool,
+}
+
+impl EpochRewards {
+    // METHODS ----------------------------------------------------------------
+
+    /// The rewards pending to be distributed, in lamports.
+    pub fn remaining_rewards(&self) -> u64 {
+        self.total_rewards.saturating_sub(self.distributed_rewards)
+    }
+}
+
+// Newer versions of `solana-program` do not implement Borsh 0.9 for `Hash`, so
+// it is serialized through its bytes.
+impl BorshSerialize for EpochRewards {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.distribution_starting_block_height.serialize(writer)?;
+        self.num_partitions.serialize(writer)?;
+        self.parent_blockhash.to_bytes().serialize(writer)?;
+        self.total_points.serialize(writer)?;
+        self.total_rewards.serialize(writer)?;
+        self.distributed_rewards.serialize(writer)?;
+        self.active.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for EpochRewards {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        Ok(EpochRewards {
+            distribution_starting_block_height: u64::deserialize(buf)?,
+            num_partitions: u64::deserialize(buf)?,
+            parent_blockhash: Hash::new_from_array(<[u8; HASH_BYTES]>::deserialize(buf)?),
+            total_points: u128::deserialize(buf)?,
+            total_rewards: u64::deserialize(buf)?,
+            distributed_rewards: u64::deserialize(buf)?,
+            active: bool::deserialize(buf)?,
+        })
+    }
+}
+
+impl SysvarId for EpochRewards {
+    fn id() -> Pubkey {
+        EPOCH_REWARDS_SYSVAR_ID
+    }
+
+    fn check_id(pubkey: &Pubkey) -> bool {
+        pubkey == &EPOCH_REWARDS_SYSVAR_ID
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<'info> SysvarAccount<'info, SlotHashes> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Gets the hash of `slot` if it is still in the sysvar. The sysvar is
+    /// not deserialized, the entry is searched directly in the account data.
+    pub fn get_slot_hash(&self, slot: u64) -> FankorResult<Option<Hash>> {
+        let data = self.info().try_borrow_data()?;
+        Ok(find_slot_hash(&data, slot))
+    }
+
+    /// The most recent slot and its hash.
+    pub fn most_recent_slot_hash(&self) -> FankorResult<Option<(u64, Hash)>> {
+        let data = self.info().try_borrow_data()?;
+        Ok(read_slot_hash_entry(&data, 0))
+    }
+
+    /// Fails if `slot` is no longer in the sysvar or its hash is not `hash`.
+    pub fn verify_slot_hash(&self, slot: u64, hash: &Hash) -> FankorResult<()> {
+        match self.get_slot_hash(slot)? {
+            Some(actual) if &actual == hash => Ok(()),
+            Some(_) => Err(FankorErrorCode::SlotHashMismatch { slot }.into()),
+            None => Err(FankorErrorCode::SlotHashNotFound { slot }.into()),
+        }
+    }
+}
+
+impl<'info> SysvarAccount<'info, RecentBlockhashes> {
+    // METHODS ----------------------------------------------------------------
+
+    /// The most recent blockhash.
+    pub fn latest_blockhash(&self) -> FankorResult<Option<Hash>> {
+        let data = self.info().try_borrow_data()?;
+        Ok(read_recent_blockhash_entry(&data, 0))
+    }
+
+    /// Whether `blockhash` is one of the recent blockhashes.
+    pub fn contains_blockhash(&self, blockhash: &Hash) -> FankorResult<bool> {
+        let data = self.info().try_borrow_data()?;
+        let len = read_entry_count(&data, RECENT_BLOCKHASH_ENTRY_SIZE);
+
+        Ok((0..len).any(|i| read_recent_blockhash_entry(&data, i).as_ref() == Some(blockhash)))
+    }
+}
+
+impl<'info> SysvarAccount<'info, EpochRewards> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Deserializes the sysvar from the account data.
+    pub fn data(&self) -> FankorResult<EpochRewards> {
+        let data = self.info().try_borrow_data()?;
+        let mut bytes: &[u8] = &data;
+
+        Ok(EpochRewards::deserialize(&mut bytes)?)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Reads the number of entries of a sysvar serialized as a vector, capped
+/// to the entries actually present in `data`.
+fn read_entry_count(data: &[u8], entry_size: usize) -> usize {
+    let len = match data.get(..size_of::<u64>()) {
+        Some(bytes) => u64::from_le_bytes(bytes.try_into().unwrap()) as usize,
+        None => return 0,
+    };
+
+    len.min((data.len() - size_of::<u64>()) / entry_size)
+}
+
+fn read_slot_hash_entry(data: &[u8], index: usize) -> Option<(u64, Hash)> {
+    if index >= read_entry_count(data, SLOT_HASH_ENTRY_SIZE) {
+        return None;
+    }
+
+    let start = size_of::<u64>() + index * SLOT_HASH_ENTRY_SIZE;
+    let slot = u64::from_le_bytes(data[start..start + 8].try_into().unwrap());
+    let hash = Hash::new(&data[start + 8..start + SLOT_HASH_ENTRY_SIZE]);
+
+    Some((slot, hash))
+}
+
+fn read_recent_blockhash_entry(data: &[u8], index: usize) -> Option<Hash> {
+    if index >= read_entry_count(data, RECENT_BLOCKHASH_ENTRY_SIZE) {
+        return None;
+    }
+
+    let start = size_of::<u64>() + index * RECENT_BLOCKHASH_ENTRY_SIZE;
+    Some(Hash::new(&data[start..start + HASH_BYTES]))
+}
+
+/// Binary searches `slot` in the data of the SlotHashes sysvar, whose
+/// entries are sorted by slot in descending order.
+pub(crate) fn find_slot_hash(data: &[u8], slot: u64) -> Option<Hash> {
+    let mut low = 0;
+    let mut high = read_entry_count(data, SLOT_HASH_ENTRY_SIZE);
+
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let (entry_slot, hash) = read_slot_hash_entry(data, middle)?;
+
+        if entry_slot == slot {
+            return Some(hash);
+        }
+
+        if entry_slot > slot {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    None
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn slot_hashes_data(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+
+        for slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[*slot as u8; HASH_BYTES]);
+        }
+
+        data
+    }
+
+    #[test]
+    fn test_find_slot_hash() {
+        let data = slot_hashes_data(&[20, 18, 15, 10, 5]);
+
+        for slot in [20, 18, 15, 10, 5] {
+            assert_eq!(
+                find_slot_hash(&data, slot),
+                Some(Hash::new(&[slot as u8; HASH_BYTES]))
+            );
+        }
+
+        assert_eq!(find_slot_hash(&data, 21), None);
+        assert_eq!(find_slot_hash(&data, 11), None);
+        assert_eq!(find_slot_hash(&data, 0), None);
+        assert_eq!(read_slot_hash_entry(&data, 0).unwrap().0, 20);
+
+        // Truncated data.
+        assert_eq!(find_slot_hash(&data[..60], 10), None);
+        assert_eq!(find_slot_hash(&[], 10), None);
+    }
+
+    #[test]
+    fn test_epoch_rewards_size() {
+        let rewards = EpochRewards {
+            total_rewards: 100,
+            distributed_rewards: 40,
+            ..Default::default()
+        };
+
+        assert_eq!(rewards.try_to_vec().unwrap().len(), 81);
+        assert_eq!(rewards.remaining_rewards(), 60);
+    }
+}
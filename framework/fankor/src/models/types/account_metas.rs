@@ -0,0 +1,135 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::AccountMeta;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::FankorContext;
+
+/// The address and privileges of an account stored inside another account,
+/// e.g. the extra accounts an interface requires, like the transfer hook one.
+/// It is serialized as the address followed by the signer and writable flags.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, BorshSerialize, BorshDeserialize)]
+pub struct AccountMetaEntry {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl AccountMetaEntry {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates an entry for a writable account.
+    pub fn new(pubkey: Pubkey, is_signer: bool) -> Self {
+        Self {
+            pubkey,
+            is_signer,
+            is_writable: true,
+        }
+    }
+
+    /// Creates an entry for a readonly account.
+    pub fn new_readonly(pubkey: Pubkey, is_signer: bool) -> Self {
+        Self {
+            pubkey,
+            is_signer,
+            is_writable: false,
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Finds the account of the entry among the accounts of the instruction,
+    /// checking it has at least the privileges of the entry.
+    pub fn resolve<'info>(
+        &self,
+        context: &'info FankorContext<'info>,
+    ) -> FankorResult<&'info AccountInfo<'info>> {
+        let info = context.get_account_from_address(&self.pubkey).ok_or(
+            FankorErrorCode::AccountMetaEntryNotFound {
+                address: self.pubkey,
+            },
+        )?;
+
+        if (self.is_signer && !info.is_signer) || (self.is_writable && !info.is_writable) {
+            return Err(FankorErrorCode::AccountMetaEntryMissingPrivileges {
+                address: self.pubkey,
+            }
+            .into());
+        }
+
+        Ok(info)
+    }
+
+    pub fn to_account_meta(&self) -> AccountMeta {
+        AccountMeta {
+            pubkey: self.pubkey,
+            is_signer: self.is_signer,
+            is_writable: self.is_writable,
+        }
+    }
+}
+
+impl From<AccountMeta> for AccountMetaEntry {
+    fn from(v: AccountMeta) -> Self {
+        Self {
+            pubkey: v.pubkey,
+            is_signer: v.is_signer,
+            is_writable: v.is_writable,
+        }
+    }
+}
+
+impl From<AccountMetaEntry> for AccountMeta {
+    fn from(v: AccountMetaEntry) -> Self {
+        v.to_account_meta()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let entry = AccountMetaEntry::new_readonly(Pubkey::new_unique(), true);
+        let bytes = entry.try_to_vec().unwrap();
+
+        assert_eq!(bytes.len(), 34);
+        assert_eq!(AccountMetaEntry::try_from_slice(&bytes).unwrap(), entry);
+        assert_eq!(AccountMetaEntry::from(entry.to_account_meta()), entry);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let mut info = create_account_info_for_tests(lamports, data);
+        info.key = Box::leak(Box::new(Pubkey::new_unique()));
+        info.is_signer = false;
+        info.is_writable = true;
+
+        let key = *info.key;
+        let infos = Box::leak(Box::new([info]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(&PROGRAM_ID, infos)));
+
+        let resolved = AccountMetaEntry::new(key, false).resolve(context).unwrap();
+        assert_eq!(resolved.key, &key);
+
+        assert!(AccountMetaEntry::new_readonly(key, false)
+            .resolve(context)
+            .is_ok());
+        assert!(AccountMetaEntry::new(key, true).resolve(context).is_err());
+        assert!(AccountMetaEntry::new(Pubkey::new_unique(), false)
+            .resolve(context)
+            .is_err());
+    }
+}
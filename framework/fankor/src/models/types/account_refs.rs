@@ -0,0 +1,171 @@
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::marker::PhantomData;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::{Account, FankorContext};
+use crate::traits::{AccountType, Instruction};
+
+/// The address of an account of type `T` stored inside another account.
+/// It is serialized as a plain `Pubkey` but keeps the type of the referenced
+/// account so it can be loaded without repeating it.
+pub struct FnkAccountRef<T> {
+    key: Pubkey,
+    _data: PhantomData<T>,
+}
+
+impl<T> FnkAccountRef<T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(key: Pubkey) -> Self {
+        Self {
+            key,
+            _data: PhantomData,
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The address of the referenced account.
+    pub fn key(&self) -> &Pubkey {
+        &self.key
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Whether the reference points to `address` or not.
+    pub fn points_to(&self, address: &Pubkey) -> bool {
+        &self.key == address
+    }
+
+    pub fn into_inner(self) -> Pubkey {
+        self.key
+    }
+}
+
+impl<T: AccountType> FnkAccountRef<T> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Loads the referenced account from the accounts of the instruction,
+    /// checking its owner and discriminant like any other `Account<T>`.
+    pub fn load<'info>(
+        &self,
+        context: &'info FankorContext<'info>,
+    ) -> FankorResult<Account<'info, T>> {
+        let info = context.get_account_from_address(&self.key).ok_or_else(|| {
+            FankorErrorCode::ReferencedAccountNotFound {
+                address: self.key,
+                account_type: std::any::type_name::<T>(),
+            }
+        })?;
+
+        let mut accounts = std::slice::from_ref(info);
+        <Account<T> as Instruction>::try_from(context, &mut &[][..], &mut accounts)
+    }
+}
+
+impl<T> Clone for FnkAccountRef<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for FnkAccountRef<T> {}
+
+impl<T> PartialEq for FnkAccountRef<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<T> Eq for FnkAccountRef<T> {}
+
+impl<T> PartialOrd for FnkAccountRef<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for FnkAccountRef<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl<T> Hash for FnkAccountRef<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state)
+    }
+}
+
+impl<T> Debug for FnkAccountRef<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FnkAccountRef").field(&self.key).finish()
+    }
+}
+
+impl<T> Default for FnkAccountRef<T> {
+    fn default() -> Self {
+        Self::new(Pubkey::default())
+    }
+}
+
+impl<T> AsRef<Pubkey> for FnkAccountRef<T> {
+    fn as_ref(&self) -> &Pubkey {
+        &self.key
+    }
+}
+
+impl<T> From<Pubkey> for FnkAccountRef<T> {
+    fn from(key: Pubkey) -> Self {
+        Self::new(key)
+    }
+}
+
+impl<T> From<FnkAccountRef<T>> for Pubkey {
+    fn from(v: FnkAccountRef<T>) -> Self {
+        v.key
+    }
+}
+
+impl<T> BorshSerialize for FnkAccountRef<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.key.serialize(writer)
+    }
+}
+
+impl<T> BorshDeserialize for FnkAccountRef<T> {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        Ok(Self::new(Pubkey::deserialize(buf)?))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Data;
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let key = Pubkey::new_unique();
+        let reference = FnkAccountRef::<Data>::new(key);
+        let bytes = reference.try_to_vec().unwrap();
+
+        assert_eq!(bytes, key.try_to_vec().unwrap());
+
+        let deserialized = FnkAccountRef::<Data>::try_from_slice(&bytes).unwrap();
+        assert_eq!(deserialized, reference);
+        assert!(deserialized.points_to(&key));
+        assert_eq!(Pubkey::from(deserialized), key);
+    }
+}
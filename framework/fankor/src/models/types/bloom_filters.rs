@@ -0,0 +1,175 @@
+use std::f64::consts::LN_2;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::hash::hash;
+
+/// A probabilistic set that can tell whether a key has been inserted using a
+/// fixed amount of space, e.g. to track claimed airdrops without storing all
+/// the claimers.
+///
+/// It never returns false negatives but it can return false positives, i.e.
+/// `contains` can return true for a key that was never inserted. The rate
+/// depends on the number of bits, the number of hashes and the number of
+/// inserted keys, see [FnkBloomFilter::with_false_positive_rate].
+///
+/// It is serialized as `hash_count: u8 + bits: Vec<u8>`. Its size never
+/// changes after its creation, so inserting keys never reallocates the account.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FnkBloomFilter {
+    hash_count: u8,
+    bits: Vec<u8>,
+}
+
+impl FnkBloomFilter {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates an empty filter with `byte_size * 8` bits that sets `hash_count`
+    /// bits per key. Both values are at least 1.
+    pub fn new(byte_size: usize, hash_count: u8) -> Self {
+        Self {
+            hash_count: hash_count.max(1),
+            bits: vec![0; byte_size.max(1)],
+        }
+    }
+
+    /// Creates an empty filter sized to hold `expected_items` keys with a
+    /// probability of false positives of `false_positive_rate`.
+    pub fn with_false_positive_rate(expected_items: u64, false_positive_rate: f64) -> Self {
+        let bit_count = Self::optimal_bit_count(expected_items, false_positive_rate);
+        let byte_size = (bit_count + 7) / 8;
+        let hash_count = Self::optimal_hash_count(byte_size * 8, expected_items);
+
+        Self::new(byte_size, hash_count)
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The number of bits set per key.
+    pub fn hash_count(&self) -> u8 {
+        self.hash_count
+    }
+
+    /// The number of bits of the filter.
+    pub fn bit_count(&self) -> usize {
+        self.bits.len() * 8
+    }
+
+    pub fn bits(&self) -> &[u8] {
+        &self.bits
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Whether `key` could have been inserted in the filter.
+    pub fn contains(&self, key: &[u8]) -> bool {
+        bloom_filter_bit_indexes(key, self.hash_count, self.bit_count())
+            .all(|bit| self.bits[bit / 8] & (1 << (bit % 8)) != 0)
+    }
+
+    /// Inserts `key` in the filter. Returns false if it could have been already
+    /// inserted, i.e. if all its bits were already set.
+    pub fn insert(&mut self, key: &[u8]) -> bool {
+        let mut inserted = false;
+
+        for bit in bloom_filter_bit_indexes(key, self.hash_count, self.bit_count()) {
+            let mask = 1 << (bit % 8);
+            inserted |= self.bits[bit / 8] & mask == 0;
+            self.bits[bit / 8] |= mask;
+        }
+
+        inserted
+    }
+
+    /// Removes all the keys.
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// The number of bits required to hold `expected_items` keys with a
+    /// probability of false positives of `false_positive_rate`.
+    pub fn optimal_bit_count(expected_items: u64, false_positive_rate: f64) -> usize {
+        let rate = false_positive_rate.clamp(f64::MIN_POSITIVE, 1.0);
+        let bits = -(expected_items.max(1) as f64) * rate.ln() / (LN_2 * LN_2);
+
+        (bits.ceil() as usize).max(8)
+    }
+
+    /// The number of hashes that minimizes the probability of false positives
+    /// of a filter of `bit_count` bits holding `expected_items` keys.
+    pub fn optimal_hash_count(bit_count: usize, expected_items: u64) -> u8 {
+        let hashes = bit_count as f64 / expected_items.max(1) as f64 * LN_2;
+
+        hashes.round().clamp(1.0, u8::MAX as f64) as u8
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Computes the bits of `key` in a filter of `bit_count` bits using double
+/// hashing over the SHA-256 digest of the key.
+pub(crate) fn bloom_filter_bit_indexes(
+    key: &[u8],
+    hash_count: u8,
+    bit_count: usize,
+) -> impl Iterator<Item = usize> {
+    let digest = hash(key).to_bytes();
+    let h1 = u64::from_le_bytes(digest[0..8].try_into().unwrap());
+    let h2 = u64::from_le_bytes(digest[8..16].try_into().unwrap()) | 1;
+    let bit_count = bit_count as u64;
+    let hash_count = if bit_count == 0 { 0 } else { hash_count };
+
+    (0..hash_count as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % bit_count) as usize)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_contains() {
+        let mut filter = FnkBloomFilter::new(32, 3);
+        assert!(!filter.contains(b"alice"));
+
+        assert!(filter.insert(b"alice"));
+        assert!(filter.contains(b"alice"));
+        assert!(!filter.insert(b"alice"));
+
+        let set_bits = filter.bits().iter().map(|v| v.count_ones()).sum::<u32>();
+        assert!((1..=3).contains(&set_bits));
+
+        filter.clear();
+        assert!(!filter.contains(b"alice"));
+    }
+
+    #[test]
+    fn test_sizing() {
+        assert_eq!(FnkBloomFilter::optimal_bit_count(1000, 0.01), 9586);
+        assert_eq!(FnkBloomFilter::optimal_hash_count(9586, 1000), 7);
+
+        let filter = FnkBloomFilter::with_false_positive_rate(1000, 0.01);
+        assert_eq!(filter.bit_count(), 9592);
+        assert_eq!(filter.hash_count(), 7);
+
+        let mut filter = FnkBloomFilter::with_false_positive_rate(100, 0.01);
+        for i in 0u32..100 {
+            filter.insert(&i.to_le_bytes());
+        }
+
+        for i in 0u32..100 {
+            assert!(filter.contains(&i.to_le_bytes()));
+        }
+
+        let false_positives = (100u32..10_100)
+            .filter(|i| filter.contains(&i.to_le_bytes()))
+            .count();
+        assert!(false_positives < 300, "Too many false positives");
+    }
+}
@@ -0,0 +1,217 @@
+use std::io::{ErrorKind, Write};
+use std::mem::size_of;
+use std::ops::{Deref, DerefMut};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Wrapper over `Vec` that reserves space for new elements in its serialized
+/// form, so they can be pushed in a zero-copy way without shifting the rest of
+/// the account nor reallocating it.
+///
+/// It is serialized as `len: u32 + capacity: u32 + elements + padding`, where
+/// `capacity` is the number of bytes reserved for the elements. Both fields
+/// have a fixed size so that pushing an element never moves the previous ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FnkCapVec<T> {
+    values: Vec<T>,
+    byte_capacity: usize,
+}
+
+impl<T> FnkCapVec<T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(values: Vec<T>) -> Self {
+        Self {
+            values,
+            byte_capacity: 0,
+        }
+    }
+
+    /// Creates a new vector that reserves at least `byte_capacity` bytes for
+    /// its elements.
+    pub fn with_byte_capacity(values: Vec<T>, byte_capacity: usize) -> Self {
+        Self {
+            values,
+            byte_capacity,
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The minimum number of bytes reserved for the elements. The actual
+    /// capacity is increased when the elements do not fit in it.
+    pub fn byte_capacity(&self) -> usize {
+        self.byte_capacity
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn set_byte_capacity(&mut self, byte_capacity: usize) {
+        self.byte_capacity = byte_capacity;
+    }
+
+    pub fn into_inner(self) -> Vec<T> {
+        self.values
+    }
+}
+
+impl<T> Default for FnkCapVec<T> {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<T> AsRef<Vec<T>> for FnkCapVec<T> {
+    fn as_ref(&self) -> &Vec<T> {
+        &self.values
+    }
+}
+
+impl<T> Deref for FnkCapVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.values
+    }
+}
+
+impl<T> DerefMut for FnkCapVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.values
+    }
+}
+
+impl<T> From<Vec<T>> for FnkCapVec<T> {
+    fn from(v: Vec<T>) -> Self {
+        Self::new(v)
+    }
+}
+
+impl<T> From<FnkCapVec<T>> for Vec<T> {
+    fn from(v: FnkCapVec<T>) -> Self {
+        v.values
+    }
+}
+
+impl<T: BorshSerialize> BorshSerialize for FnkCapVec<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let length: u32 = self
+            .values
+            .len()
+            .try_into()
+            .map_err(|_| ErrorKind::InvalidInput)?;
+
+        let mut elements = Vec::new();
+        if let Some(u8_slice) = T::u8_slice(&self.values) {
+            elements.extend_from_slice(u8_slice);
+        } else {
+            for item in &self.values {
+                item.serialize(&mut elements)?;
+            }
+        }
+
+        let byte_capacity = self.byte_capacity.max(elements.len());
+        let capacity: u32 = byte_capacity
+            .try_into()
+            .map_err(|_| ErrorKind::InvalidInput)?;
+
+        length.serialize(writer)?;
+        capacity.serialize(writer)?;
+        writer.write_all(&elements)?;
+        writer.write_all(&vec![0; byte_capacity - elements.len()])?;
+
+        Ok(())
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for FnkCapVec<T> {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let len = u32::deserialize(buf)?;
+        let byte_capacity = u32::deserialize(buf)? as usize;
+
+        if buf.len() < byte_capacity {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "Unexpected length of input",
+            ));
+        }
+
+        let mut elements = &buf[..byte_capacity];
+        let mut values = Vec::with_capacity({
+            let el_size = size_of::<T>().max(1) as u32;
+            core::cmp::max(core::cmp::min(len, 4096 / el_size), 1) as usize
+        });
+        for _ in 0..len {
+            values.push(T::deserialize(&mut elements)?);
+        }
+
+        *buf = &buf[byte_capacity..];
+
+        Ok(FnkCapVec {
+            values,
+            byte_capacity,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_serialize_deserialize_empty() {
+        let data = FnkCapVec::<u16>::with_byte_capacity(vec![], 4);
+        let buffer = data.try_to_vec().expect("Failed to serialize");
+
+        assert_eq!(buffer, vec![0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+
+        let mut de_buf = buffer.as_slice();
+        let deserialized =
+            FnkCapVec::<u16>::deserialize(&mut de_buf).expect("Failed to deserialize");
+
+        assert_eq!(deserialized, data, "Incorrect result");
+        assert!(de_buf.is_empty(), "Buffer not empty");
+    }
+
+    #[test]
+    fn test_serialize_deserialize_data() {
+        let data = FnkCapVec::<u16>::with_byte_capacity(vec![1, 2], 6);
+        let buffer = data.try_to_vec().expect("Failed to serialize");
+
+        assert_eq!(buffer, vec![2, 0, 0, 0, 6, 0, 0, 0, 1, 0, 2, 0, 0, 0]);
+
+        let mut de_buf = buffer.as_slice();
+        let deserialized =
+            FnkCapVec::<u16>::deserialize(&mut de_buf).expect("Failed to deserialize");
+
+        assert_eq!(deserialized, data, "Incorrect result");
+        assert!(de_buf.is_empty(), "Buffer not empty");
+    }
+
+    #[test]
+    fn test_serialize_over_capacity() {
+        let data = FnkCapVec::<u8>::with_byte_capacity(vec![1, 2, 3], 1);
+        let buffer = data.try_to_vec().expect("Failed to serialize");
+
+        assert_eq!(buffer, vec![3, 0, 0, 0, 3, 0, 0, 0, 1, 2, 3]);
+
+        let mut de_buf = buffer.as_slice();
+        let deserialized =
+            FnkCapVec::<u8>::deserialize(&mut de_buf).expect("Failed to deserialize");
+
+        assert_eq!(deserialized.byte_capacity(), 3);
+        assert_eq!(*deserialized, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_deserialize_out_of_bounds() {
+        let buffer = vec![2u8, 0, 0, 0, 1, 0, 0, 0, 1, 2];
+        let mut de_buf = buffer.as_slice();
+
+        assert!(FnkCapVec::<u8>::deserialize(&mut de_buf).is_err());
+    }
+}
@@ -1,10 +1,16 @@
 use std::cmp::Ordering;
 use std::fmt::Display;
 use std::io::{ErrorKind, Write};
+use std::iter::{Product, Sum};
+use std::num::ParseIntError;
 use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::unsigned::FnkUInt;
+
 const FLAG_ENCODING_LIMIT: u64 = 1 << 13;
 // 2^13
 const MIN_I64_ABS: u64 = i64::MIN.unsigned_abs();
@@ -158,6 +164,85 @@ impl FnkInt {
     pub fn into_inner(self) -> i64 {
         self.0
     }
+
+    /// Adds `rhs` failing if the result overflows.
+    pub fn checked_add(self, rhs: FnkInt) -> FankorResult<FnkInt> {
+        self.0
+            .checked_add(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "add" }.into())
+    }
+
+    /// Subtracts `rhs` failing if the result overflows.
+    pub fn checked_sub(self, rhs: FnkInt) -> FankorResult<FnkInt> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "sub" }.into())
+    }
+
+    /// Multiplies by `rhs` failing if the result overflows.
+    pub fn checked_mul(self, rhs: FnkInt) -> FankorResult<FnkInt> {
+        self.0
+            .checked_mul(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "mul" }.into())
+    }
+
+    /// Divides by `rhs` failing if it is zero or the result overflows.
+    pub fn checked_div(self, rhs: FnkInt) -> FankorResult<FnkInt> {
+        self.0
+            .checked_div(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "div" }.into())
+    }
+
+    /// Computes the remainder of dividing by `rhs` failing if it is zero or the result
+    /// overflows.
+    pub fn checked_rem(self, rhs: FnkInt) -> FankorResult<FnkInt> {
+        self.0
+            .checked_rem(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "rem" }.into())
+    }
+
+    /// Negates the number failing if the result overflows.
+    pub fn checked_neg(self) -> FankorResult<FnkInt> {
+        self.0
+            .checked_neg()
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "neg" }.into())
+    }
+
+    /// Adds `rhs` saturating at the numeric bounds.
+    pub fn saturating_add(self, rhs: FnkInt) -> FnkInt {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts `rhs` saturating at the numeric bounds.
+    pub fn saturating_sub(self, rhs: FnkInt) -> FnkInt {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Multiplies by `rhs` saturating at the numeric bounds.
+    pub fn saturating_mul(self, rhs: FnkInt) -> FnkInt {
+        Self(self.0.saturating_mul(rhs.0))
+    }
+
+    /// Adds `rhs` wrapping around at the boundary of the type.
+    pub fn wrapping_add(self, rhs: FnkInt) -> FnkInt {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Subtracts `rhs` wrapping around at the boundary of the type.
+    pub fn wrapping_sub(self, rhs: FnkInt) -> FnkInt {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+
+    /// Multiplies by `rhs` wrapping around at the boundary of the type.
+    pub fn wrapping_mul(self, rhs: FnkInt) -> FnkInt {
+        Self(self.0.wrapping_mul(rhs.0))
+    }
 }
 
 impl AsRef<i64> for FnkInt {
@@ -268,6 +353,92 @@ impl TryFrom<usize> for FnkInt {
     }
 }
 
+macro_rules! impl_try_from_primitive {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFrom<$ty> for FnkInt {
+                type Error = ();
+
+                fn try_from(v: $ty) -> Result<Self, Self::Error> {
+                    i64::try_from(v).map(Self).map_err(|_| ())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_primitive!(u128, i128);
+
+impl TryFrom<FnkUInt> for FnkInt {
+    type Error = ();
+
+    fn try_from(v: FnkUInt) -> Result<Self, Self::Error> {
+        Self::try_from(v.0)
+    }
+}
+
+macro_rules! impl_from_fnk {
+    ($($ty:ty),*) => {
+        $(
+            impl From<FnkInt> for $ty {
+                fn from(v: FnkInt) -> Self {
+                    v.0 as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_from_fnk!(i64, i128);
+
+macro_rules! impl_try_into_primitive {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFrom<FnkInt> for $ty {
+                type Error = ();
+
+                fn try_from(v: FnkInt) -> Result<Self, Self::Error> {
+                    <$ty>::try_from(v.0).map_err(|_| ())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_into_primitive!(i8, i16, i32, isize, u8, u16, u32, u64, usize, u128);
+
+impl FromStr for FnkInt {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        i64::from_str(s).map(Self)
+    }
+}
+
+impl Sum for FnkInt {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|v| v.0).sum())
+    }
+}
+
+impl<'a> Sum<&'a FnkInt> for FnkInt {
+    fn sum<I: Iterator<Item = &'a FnkInt>>(iter: I) -> Self {
+        Self(iter.map(|v| v.0).sum())
+    }
+}
+
+impl Product for FnkInt {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|v| v.0).product())
+    }
+}
+
+impl<'a> Product<&'a FnkInt> for FnkInt {
+    fn product<I: Iterator<Item = &'a FnkInt>>(iter: I) -> Self {
+        Self(iter.map(|v| v.0).product())
+    }
+}
+
 impl BorshSerialize for FnkInt {
     fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
         let number = self.0.unsigned_abs();
@@ -731,4 +902,63 @@ mod test {
             assert!(de_buf.is_empty(), "Buffer not empty for {}", number);
         }
     }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = FnkInt::new(10);
+        let b = FnkInt::new(-3);
+        let max = FnkInt::new(i64::MAX);
+        let min = FnkInt::new(i64::MIN);
+
+        assert_eq!(a.checked_add(b).unwrap(), FnkInt::new(7));
+        assert_eq!(a.checked_sub(b).unwrap(), FnkInt::new(13));
+        assert_eq!(a.checked_mul(b).unwrap(), FnkInt::new(-30));
+        assert_eq!(a.checked_div(b).unwrap(), FnkInt::new(-3));
+        assert_eq!(a.checked_rem(b).unwrap(), FnkInt::new(1));
+        assert_eq!(a.checked_neg().unwrap(), FnkInt::new(-10));
+
+        assert!(max.checked_add(a).is_err());
+        assert!(min.checked_sub(a).is_err());
+        assert!(max.checked_mul(b).is_err());
+        assert!(a.checked_div(FnkInt::new(0)).is_err());
+        assert!(min.checked_div(FnkInt::new(-1)).is_err());
+        assert!(a.checked_rem(FnkInt::new(0)).is_err());
+        assert!(min.checked_neg().is_err());
+
+        assert_eq!(max.saturating_add(a), max);
+        assert_eq!(min.saturating_sub(a), min);
+        assert_eq!(max.saturating_mul(b), min);
+
+        assert_eq!(max.wrapping_add(FnkInt::new(1)), min);
+        assert_eq!(min.wrapping_sub(FnkInt::new(1)), max);
+        assert_eq!(max.wrapping_mul(FnkInt::new(2)), FnkInt::new(-2));
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(FnkInt::try_from(-5i128), Ok(FnkInt::new(-5)));
+        assert_eq!(FnkInt::try_from(i64::MAX as u128 + 1), Err(()));
+        assert_eq!(FnkInt::try_from(FnkUInt::new(5)), Ok(FnkInt::new(5)));
+        assert_eq!(FnkInt::try_from(FnkUInt::new(u64::MAX)), Err(()));
+
+        let number = FnkInt::new(-300);
+        assert_eq!(i64::from(number), -300);
+        assert_eq!(i128::from(number), -300);
+        assert_eq!(i16::try_from(number), Ok(-300));
+        assert_eq!(i8::try_from(number), Err(()));
+        assert_eq!(u64::try_from(number), Err(()));
+        assert_eq!(u128::try_from(FnkInt::new(300)), Ok(300));
+        assert_eq!(u8::try_from(FnkInt::new(300)), Err(()));
+    }
+
+    #[test]
+    fn test_from_str_sum_product() {
+        assert_eq!("-1234".parse::<FnkInt>(), Ok(FnkInt::new(-1234)));
+        assert!("a".parse::<FnkInt>().is_err());
+        assert_eq!(FnkInt::new(-1234).to_string(), "-1234");
+
+        let numbers = [FnkInt::new(2), FnkInt::new(-3), FnkInt::new(4)];
+        assert_eq!(numbers.iter().sum::<FnkInt>(), FnkInt::new(3));
+        assert_eq!(numbers.into_iter().product::<FnkInt>(), FnkInt::new(-24));
+    }
 }
@@ -24,6 +24,27 @@ impl<K, T> FnkMap<K, T> {
     }
 }
 
+impl<K: Ord, T> FnkMap<K, T> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Inserts all entries of `other` in the map. When a key is present in
+    /// both maps, `resolve` receives the current and the new values and
+    /// returns the value to keep.
+    pub fn merge<F>(&mut self, other: FnkMap<K, T>, mut resolve: F)
+    where
+        F: FnMut(&K, T, T) -> T,
+    {
+        for (key, value) in other.0 {
+            let value = match self.0.remove(&key) {
+                Some(current) => resolve(&key, current, value),
+                None => value,
+            };
+
+            self.0.insert(key, value);
+        }
+    }
+}
+
 impl<K, T> Default for FnkMap<K, T> {
     fn default() -> Self {
         Self(BTreeMap::new())
@@ -62,6 +83,18 @@ impl<K, T> From<FnkMap<K, T>> for BTreeMap<K, T> {
     }
 }
 
+impl<K: Ord, T> Extend<(K, T)> for FnkMap<K, T> {
+    fn extend<I: IntoIterator<Item = (K, T)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<K: Ord, T> FromIterator<(K, T)> for FnkMap<K, T> {
+    fn from_iter<I: IntoIterator<Item = (K, T)>>(iter: I) -> Self {
+        Self(BTreeMap::from_iter(iter))
+    }
+}
+
 impl<K: BorshSerialize, T: BorshSerialize> BorshSerialize for FnkMap<K, T> {
     fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Note: this method does not sort the map to save compute cycles.
@@ -189,4 +222,18 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_bulk_operations() {
+        let mut map: FnkMap<u8, u32> = [(1, 10), (2, 20)].into_iter().collect();
+
+        map.extend([(3, 30), (4, 40)]);
+        map.retain(|k, _| k % 2 == 0);
+        map.merge(FnkMap::from_iter([(2, 2), (5, 50)]), |_, a, b| a + b);
+
+        assert_eq!(
+            map.into_inner().into_iter().collect::<Vec<_>>(),
+            vec![(2, 22), (4, 40), (5, 50)]
+        );
+    }
 }
@@ -1,22 +1,34 @@
+pub use account_metas::*;
+pub use account_refs::*;
 pub use arrays::*;
 pub use binary_map::*;
 pub use binary_set::*;
+pub use bloom_filters::*;
+pub use cap_vectors::*;
 pub use extensions::*;
 pub use integers::*;
 pub use maps::*;
+pub use percentages::*;
 pub use ranges::*;
+pub use ring_buffers::*;
 pub use sets::*;
 pub use strings::*;
 pub use unsigned::*;
 pub use vectors::*;
 
+mod account_metas;
+mod account_refs;
 mod arrays;
 mod binary_map;
 mod binary_set;
+mod bloom_filters;
+mod cap_vectors;
 mod extensions;
 mod integers;
 mod maps;
+mod percentages;
 mod ranges;
+mod ring_buffers;
 mod sets;
 mod strings;
 mod unsigned;
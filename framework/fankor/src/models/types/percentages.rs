@@ -0,0 +1,308 @@
+use std::fmt::Display;
+use std::io::{ErrorKind, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::errors::{FankorErrorCode, FankorResult};
+
+/// The number of basis points that represent 100%.
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+/// The number that represents 100% in [Percent].
+pub const PERCENT_DENOMINATOR: u8 = 100;
+
+/// A ratio expressed in basis points, i.e. hundredths of a percent, in range [0, 10000].
+///
+/// The range is validated on creation and deserialization so applying it to an amount
+/// can never return more than the amount itself.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bps(u16);
+
+impl Bps {
+    /// 0%.
+    pub const ZERO: Bps = Bps(0);
+
+    /// 100%.
+    pub const MAX: Bps = Bps(BPS_DENOMINATOR);
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new value failing if it is greater than [BPS_DENOMINATOR].
+    pub fn new(value: u16) -> FankorResult<Bps> {
+        if value > BPS_DENOMINATOR {
+            return Err(FankorErrorCode::ValueOutOfRange {
+                type_name: "Bps",
+                value: value as u64,
+                max: BPS_DENOMINATOR as u64,
+            }
+            .into());
+        }
+
+        Ok(Self(value))
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn get(&self) -> u16 {
+        self.0
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Computes the portion of `amount` represented by this value rounding down.
+    pub fn apply_to(&self, amount: u64) -> u64 {
+        (amount as u128 * self.0 as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+
+    /// Computes the portion of `amount` represented by this value rounding up.
+    /// Useful to compute fees that must never be undercharged.
+    pub fn apply_to_ceil(&self, amount: u64) -> u64 {
+        let denominator = BPS_DENOMINATOR as u128;
+        ((amount as u128 * self.0 as u128 + denominator - 1) / denominator) as u64
+    }
+
+    /// Returns the remaining ratio up to 100%, i.e. `10000 - self`.
+    pub fn complement(&self) -> Bps {
+        Self(BPS_DENOMINATOR - self.0)
+    }
+
+    /// Adds `rhs` failing if the result is greater than 100%.
+    pub fn checked_add(self, rhs: Bps) -> FankorResult<Bps> {
+        Self::new(self.0 + rhs.0)
+    }
+
+    /// Subtracts `rhs` failing if the result is negative.
+    pub fn checked_sub(self, rhs: Bps) -> FankorResult<Bps> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "sub" }.into())
+    }
+
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Bps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}bps", self.0)
+    }
+}
+
+impl From<Percent> for Bps {
+    fn from(v: Percent) -> Self {
+        Self(v.0 as u16 * (BPS_DENOMINATOR / PERCENT_DENOMINATOR as u16))
+    }
+}
+
+impl TryFrom<u16> for Bps {
+    type Error = ();
+
+    fn try_from(v: u16) -> Result<Self, Self::Error> {
+        Self::new(v).map_err(|_| ())
+    }
+}
+
+impl From<Bps> for u16 {
+    fn from(v: Bps) -> Self {
+        v.0
+    }
+}
+
+impl BorshSerialize for Bps {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Bps {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let value = u16::deserialize(buf)?;
+
+        if value > BPS_DENOMINATOR {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "Bps value out of range",
+            ));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A ratio expressed as a percentage in range [0, 100].
+///
+/// The range is validated on creation and deserialization so applying it to an amount
+/// can never return more than the amount itself.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Percent(u8);
+
+impl Percent {
+    /// 0%.
+    pub const ZERO: Percent = Percent(0);
+
+    /// 100%.
+    pub const MAX: Percent = Percent(PERCENT_DENOMINATOR);
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new value failing if it is greater than [PERCENT_DENOMINATOR].
+    pub fn new(value: u8) -> FankorResult<Percent> {
+        if value > PERCENT_DENOMINATOR {
+            return Err(FankorErrorCode::ValueOutOfRange {
+                type_name: "Percent",
+                value: value as u64,
+                max: PERCENT_DENOMINATOR as u64,
+            }
+            .into());
+        }
+
+        Ok(Self(value))
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Computes the portion of `amount` represented by this value rounding down.
+    pub fn apply_to(&self, amount: u64) -> u64 {
+        (amount as u128 * self.0 as u128 / PERCENT_DENOMINATOR as u128) as u64
+    }
+
+    /// Computes the portion of `amount` represented by this value rounding up.
+    /// Useful to compute fees that must never be undercharged.
+    pub fn apply_to_ceil(&self, amount: u64) -> u64 {
+        let denominator = PERCENT_DENOMINATOR as u128;
+        ((amount as u128 * self.0 as u128 + denominator - 1) / denominator) as u64
+    }
+
+    /// Returns the remaining ratio up to 100%, i.e. `100 - self`.
+    pub fn complement(&self) -> Percent {
+        Self(PERCENT_DENOMINATOR - self.0)
+    }
+
+    /// Adds `rhs` failing if the result is greater than 100%.
+    pub fn checked_add(self, rhs: Percent) -> FankorResult<Percent> {
+        Self::new(self.0 + rhs.0)
+    }
+
+    /// Subtracts `rhs` failing if the result is negative.
+    pub fn checked_sub(self, rhs: Percent) -> FankorResult<Percent> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "sub" }.into())
+    }
+
+    pub fn into_inner(self) -> u8 {
+        self.0
+    }
+}
+
+impl Display for Percent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+impl TryFrom<u8> for Percent {
+    type Error = ();
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        Self::new(v).map_err(|_| ())
+    }
+}
+
+impl From<Percent> for u8 {
+    fn from(v: Percent) -> Self {
+        v.0
+    }
+}
+
+impl BorshSerialize for Percent {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Percent {
+    #[inline]
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let value = u8::deserialize(buf)?;
+
+        if value > PERCENT_DENOMINATOR {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "Percent value out of range",
+            ));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bps() {
+        assert!(Bps::new(10_000).is_ok());
+        assert!(Bps::new(10_001).is_err());
+
+        let bps = Bps::new(30).unwrap();
+        assert_eq!(bps.apply_to(1_000_000), 3_000);
+        assert_eq!(bps.apply_to(999), 2);
+        assert_eq!(bps.apply_to_ceil(999), 3);
+        assert_eq!(bps.apply_to_ceil(1_000_000), 3_000);
+        assert_eq!(Bps::MAX.apply_to(u64::MAX), u64::MAX);
+        assert_eq!(Bps::MAX.apply_to_ceil(u64::MAX), u64::MAX);
+        assert_eq!(bps.complement().get(), 9_970);
+
+        assert!(Bps::MAX.checked_add(bps).is_err());
+        assert!(Bps::ZERO.checked_sub(bps).is_err());
+        assert_eq!(Bps::from(Percent::new(15).unwrap()).get(), 1_500);
+    }
+
+    #[test]
+    fn test_percent() {
+        assert!(Percent::new(100).is_ok());
+        assert!(Percent::new(101).is_err());
+
+        let percent = Percent::new(15).unwrap();
+        assert_eq!(percent.apply_to(1_000), 150);
+        assert_eq!(percent.apply_to(99), 14);
+        assert_eq!(percent.apply_to_ceil(99), 15);
+        assert_eq!(Percent::MAX.apply_to(u64::MAX), u64::MAX);
+        assert_eq!(percent.complement().get(), 85);
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let bps = Bps::new(2_500).unwrap();
+        let bytes = bps.try_to_vec().unwrap();
+        assert_eq!(bytes, 2_500u16.to_le_bytes());
+        assert_eq!(Bps::try_from_slice(&bytes).unwrap(), bps);
+        assert!(Bps::try_from_slice(&10_001u16.to_le_bytes()).is_err());
+
+        let percent = Percent::new(50).unwrap();
+        let bytes = percent.try_to_vec().unwrap();
+        assert_eq!(bytes, [50]);
+        assert_eq!(Percent::try_from_slice(&bytes).unwrap(), percent);
+        assert!(Percent::try_from_slice(&[101]).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use std::io::{ErrorKind, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// A circular buffer of `N` elements that overwrites the oldest element when
+/// it is full, e.g. for event queues or price histories.
+///
+/// Every pushed element gets a sequence number that is never reused, so
+/// consumers can resume reading from the last sequence they processed.
+///
+/// It is serialized as `sequence: u64 + N slots`, where `sequence` is the
+/// number of elements pushed so far and the next one is written in the slot
+/// `sequence % N`. The slots must have a fixed size to be used in a zero-copy
+/// way, and `N` must be greater than 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FnkRingBuffer<T, const N: usize> {
+    sequence: u64,
+    slots: Vec<T>,
+}
+
+impl<T: Default, const N: usize> FnkRingBuffer<T, N> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new() -> Self {
+        Self {
+            sequence: 0,
+            slots: (0..N).map(|_| T::default()).collect(),
+        }
+    }
+}
+
+impl<T, const N: usize> FnkRingBuffer<T, N> {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The number of elements pushed into the buffer since its creation.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// The slot in which the next element will be written.
+    pub fn head(&self) -> usize {
+        (self.sequence % N as u64) as usize
+    }
+
+    /// The number of elements currently stored.
+    pub fn len(&self) -> usize {
+        self.sequence.min(N as u64) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sequence == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.sequence >= N as u64
+    }
+
+    /// The sequence number of the oldest stored element.
+    pub fn first_sequence(&self) -> u64 {
+        self.sequence.saturating_sub(N as u64)
+    }
+
+    /// The physical slots of the buffer, ignoring the order of the elements.
+    pub fn slots(&self) -> &[T] {
+        &self.slots
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Gets the element with the `sequence` number if it is still stored.
+    pub fn get(&self, sequence: u64) -> Option<&T> {
+        if sequence < self.first_sequence() || sequence >= self.sequence {
+            return None;
+        }
+
+        self.slots.get((sequence % N as u64) as usize)
+    }
+
+    /// Pushes an element overwriting the oldest one if the buffer is full.
+    /// Returns the sequence number of the new element.
+    pub fn push_overwrite(&mut self, value: T) -> u64 {
+        let sequence = self.sequence;
+        let head = self.head();
+        self.slots[head] = value;
+        self.sequence += 1;
+
+        sequence
+    }
+
+    /// Iterates over the stored elements from the oldest to the newest one
+    /// along with their sequence number.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &T)> {
+        self.iter_from(0)
+    }
+
+    /// Iterates over the stored elements with a sequence number equal or
+    /// greater than `sequence`.
+    pub fn iter_from(&self, sequence: u64) -> impl Iterator<Item = (u64, &T)> {
+        (sequence.max(self.first_sequence())..self.sequence)
+            .map(move |v| (v, &self.slots[(v % N as u64) as usize]))
+    }
+}
+
+impl<T: Default, const N: usize> Default for FnkRingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: BorshSerialize, const N: usize> BorshSerialize for FnkRingBuffer<T, N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.sequence.serialize(writer)?;
+
+        if let Some(u8_slice) = T::u8_slice(&self.slots) {
+            writer.write_all(u8_slice)?;
+        } else {
+            for item in &self.slots {
+                item.serialize(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: BorshDeserialize, const N: usize> BorshDeserialize for FnkRingBuffer<T, N> {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        if N == 0 {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "Ring buffers cannot have zero slots",
+            ));
+        }
+
+        let sequence = u64::deserialize(buf)?;
+        let mut slots = Vec::with_capacity(N);
+
+        for _ in 0..N {
+            slots.push(T::deserialize(buf)?);
+        }
+
+        Ok(FnkRingBuffer { sequence, slots })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_push_overwrite_and_iter() {
+        let mut buffer = FnkRingBuffer::<u8, 3>::new();
+        assert!(buffer.is_empty());
+
+        assert_eq!(buffer.push_overwrite(1), 0);
+        assert_eq!(buffer.push_overwrite(2), 1);
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![(0, &1), (1, &2)]);
+
+        buffer.push_overwrite(3);
+        buffer.push_overwrite(4);
+        assert!(buffer.is_full());
+        assert_eq!(buffer.head(), 1);
+        assert_eq!(buffer.slots(), &[4, 2, 3]);
+        assert_eq!(
+            buffer.iter().collect::<Vec<_>>(),
+            vec![(1, &2), (2, &3), (3, &4)]
+        );
+        assert_eq!(buffer.iter_from(3).collect::<Vec<_>>(), vec![(3, &4)]);
+        assert_eq!(buffer.iter_from(4).count(), 0);
+        assert_eq!(buffer.get(0), None);
+        assert_eq!(buffer.get(2), Some(&3));
+        assert_eq!(buffer.get(4), None);
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let mut buffer = FnkRingBuffer::<u16, 2>::new();
+        buffer.push_overwrite(1);
+        buffer.push_overwrite(2);
+        buffer.push_overwrite(3);
+
+        let bytes = buffer.try_to_vec().expect("Failed to serialize");
+        assert_eq!(bytes, vec![3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0]);
+
+        let mut de_buf = bytes.as_slice();
+        let deserialized =
+            FnkRingBuffer::<u16, 2>::deserialize(&mut de_buf).expect("Failed to deserialize");
+
+        assert_eq!(deserialized, buffer, "Incorrect result");
+        assert!(de_buf.is_empty(), "Buffer not empty");
+    }
+}
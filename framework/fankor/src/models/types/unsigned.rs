@@ -1,9 +1,15 @@
 use std::fmt::Display;
 use std::io::{ErrorKind, Write};
+use std::iter::{Product, Sum};
+use std::num::ParseIntError;
 use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::integers::FnkInt;
+
 const FLAG_ENCODING_LIMIT: u64 = 1 << 14; // 2^14
 
 /// Wrapper over an unsigned number that serializes to a variable-length form.
@@ -95,6 +101,76 @@ impl FnkUInt {
     pub fn into_inner(self) -> u64 {
         self.0
     }
+
+    /// Adds `rhs` failing if the result overflows.
+    pub fn checked_add(self, rhs: FnkUInt) -> FankorResult<FnkUInt> {
+        self.0
+            .checked_add(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "add" }.into())
+    }
+
+    /// Subtracts `rhs` failing if the result underflows.
+    pub fn checked_sub(self, rhs: FnkUInt) -> FankorResult<FnkUInt> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "sub" }.into())
+    }
+
+    /// Multiplies by `rhs` failing if the result overflows.
+    pub fn checked_mul(self, rhs: FnkUInt) -> FankorResult<FnkUInt> {
+        self.0
+            .checked_mul(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "mul" }.into())
+    }
+
+    /// Divides by `rhs` failing if it is zero.
+    pub fn checked_div(self, rhs: FnkUInt) -> FankorResult<FnkUInt> {
+        self.0
+            .checked_div(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "div" }.into())
+    }
+
+    /// Computes the remainder of dividing by `rhs` failing if it is zero.
+    pub fn checked_rem(self, rhs: FnkUInt) -> FankorResult<FnkUInt> {
+        self.0
+            .checked_rem(rhs.0)
+            .map(Self)
+            .ok_or_else(|| FankorErrorCode::ArithmeticOverflow { operation: "rem" }.into())
+    }
+
+    /// Adds `rhs` saturating at the numeric bounds.
+    pub fn saturating_add(self, rhs: FnkUInt) -> FnkUInt {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts `rhs` saturating at the numeric bounds.
+    pub fn saturating_sub(self, rhs: FnkUInt) -> FnkUInt {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Multiplies by `rhs` saturating at the numeric bounds.
+    pub fn saturating_mul(self, rhs: FnkUInt) -> FnkUInt {
+        Self(self.0.saturating_mul(rhs.0))
+    }
+
+    /// Adds `rhs` wrapping around at the boundary of the type.
+    pub fn wrapping_add(self, rhs: FnkUInt) -> FnkUInt {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Subtracts `rhs` wrapping around at the boundary of the type.
+    pub fn wrapping_sub(self, rhs: FnkUInt) -> FnkUInt {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+
+    /// Multiplies by `rhs` wrapping around at the boundary of the type.
+    pub fn wrapping_mul(self, rhs: FnkUInt) -> FnkUInt {
+        Self(self.0.wrapping_mul(rhs.0))
+    }
 }
 
 impl AsRef<u64> for FnkUInt {
@@ -153,6 +229,92 @@ impl From<usize> for FnkUInt {
     }
 }
 
+macro_rules! impl_try_from_primitive {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFrom<$ty> for FnkUInt {
+                type Error = ();
+
+                fn try_from(v: $ty) -> Result<Self, Self::Error> {
+                    u64::try_from(v).map(Self).map_err(|_| ())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_primitive!(i8, i16, i32, i64, isize, u128, i128);
+
+impl TryFrom<FnkInt> for FnkUInt {
+    type Error = ();
+
+    fn try_from(v: FnkInt) -> Result<Self, Self::Error> {
+        Self::try_from(v.0)
+    }
+}
+
+macro_rules! impl_from_fnk {
+    ($($ty:ty),*) => {
+        $(
+            impl From<FnkUInt> for $ty {
+                fn from(v: FnkUInt) -> Self {
+                    v.0 as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_from_fnk!(u64, u128, i128);
+
+macro_rules! impl_try_into_primitive {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFrom<FnkUInt> for $ty {
+                type Error = ();
+
+                fn try_from(v: FnkUInt) -> Result<Self, Self::Error> {
+                    <$ty>::try_from(v.0).map_err(|_| ())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_into_primitive!(u8, u16, u32, usize, i8, i16, i32, i64, isize);
+
+impl FromStr for FnkUInt {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        u64::from_str(s).map(Self)
+    }
+}
+
+impl Sum for FnkUInt {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|v| v.0).sum())
+    }
+}
+
+impl<'a> Sum<&'a FnkUInt> for FnkUInt {
+    fn sum<I: Iterator<Item = &'a FnkUInt>>(iter: I) -> Self {
+        Self(iter.map(|v| v.0).sum())
+    }
+}
+
+impl Product for FnkUInt {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|v| v.0).product())
+    }
+}
+
+impl<'a> Product<&'a FnkUInt> for FnkUInt {
+    fn product<I: Iterator<Item = &'a FnkUInt>>(iter: I) -> Self {
+        Self(iter.map(|v| v.0).product())
+    }
+}
+
 impl BorshSerialize for FnkUInt {
     fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
         if self.0 < FLAG_ENCODING_LIMIT {
@@ -433,4 +595,62 @@ mod test {
             assert!(de_buf.is_empty(), "Buffer not empty for {}", number);
         }
     }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = FnkUInt::new(10);
+        let b = FnkUInt::new(3);
+        let max = FnkUInt::new(u64::MAX);
+
+        assert_eq!(a.checked_add(b).unwrap(), FnkUInt::new(13));
+        assert_eq!(a.checked_sub(b).unwrap(), FnkUInt::new(7));
+        assert_eq!(a.checked_mul(b).unwrap(), FnkUInt::new(30));
+        assert_eq!(a.checked_div(b).unwrap(), FnkUInt::new(3));
+        assert_eq!(a.checked_rem(b).unwrap(), FnkUInt::new(1));
+
+        assert!(max.checked_add(b).is_err());
+        assert!(b.checked_sub(a).is_err());
+        assert!(max.checked_mul(b).is_err());
+        assert!(a.checked_div(FnkUInt::new(0)).is_err());
+        assert!(a.checked_rem(FnkUInt::new(0)).is_err());
+
+        assert_eq!(max.saturating_add(b), max);
+        assert_eq!(b.saturating_sub(a), FnkUInt::new(0));
+        assert_eq!(max.saturating_mul(b), max);
+
+        assert_eq!(max.wrapping_add(b), FnkUInt::new(2));
+        assert_eq!(b.wrapping_sub(a), FnkUInt::new(u64::MAX - 6));
+        assert_eq!(max.wrapping_mul(FnkUInt::new(2)), FnkUInt::new(u64::MAX - 1));
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(FnkUInt::try_from(5i8), Ok(FnkUInt::new(5)));
+        assert_eq!(FnkUInt::try_from(-5i64), Err(()));
+        assert_eq!(FnkUInt::try_from(u64::MAX as u128), Ok(FnkUInt::new(u64::MAX)));
+        assert_eq!(FnkUInt::try_from(u64::MAX as u128 + 1), Err(()));
+        assert_eq!(FnkUInt::try_from(FnkInt::new(5)), Ok(FnkUInt::new(5)));
+        assert_eq!(FnkUInt::try_from(FnkInt::new(-5)), Err(()));
+
+        let number = FnkUInt::new(300);
+        assert_eq!(u64::from(number), 300);
+        assert_eq!(u128::from(number), 300);
+        assert_eq!(i128::from(number), 300);
+        assert_eq!(u16::try_from(number), Ok(300));
+        assert_eq!(u8::try_from(number), Err(()));
+        assert_eq!(i16::try_from(number), Ok(300));
+        assert_eq!(i8::try_from(number), Err(()));
+        assert_eq!(i64::try_from(FnkUInt::new(u64::MAX)), Err(()));
+    }
+
+    #[test]
+    fn test_from_str_sum_product() {
+        assert_eq!("1234".parse::<FnkUInt>(), Ok(FnkUInt::new(1234)));
+        assert!("-1".parse::<FnkUInt>().is_err());
+        assert_eq!(FnkUInt::new(1234).to_string(), "1234");
+
+        let numbers = [FnkUInt::new(2), FnkUInt::new(3), FnkUInt::new(4)];
+        assert_eq!(numbers.iter().sum::<FnkUInt>(), FnkUInt::new(9));
+        assert_eq!(numbers.into_iter().product::<FnkUInt>(), FnkUInt::new(24));
+    }
 }
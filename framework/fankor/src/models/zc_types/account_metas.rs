@@ -0,0 +1,171 @@
+use borsh::BorshDeserialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::AccountMeta;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::{AccountMetaEntry, FnkVec};
+use crate::models::zc_types::try_borrow_zc_data;
+use crate::models::zc_types::vec::ZcFnkVec;
+use crate::models::{FankorContext, Zc};
+use crate::traits::{CopyType, ZeroCopyType};
+
+/// The size of an [AccountMetaEntry]: the address plus both flags.
+const ACCOUNT_META_ENTRY_SIZE: usize = 32 + 1 + 1;
+
+impl<'info> ZeroCopyType<'info> for AccountMetaEntry {
+    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let bytes = try_borrow_zc_data(info, "AccountMetaEntry", "new")?;
+        let mut bytes = &bytes[offset..];
+
+        if bytes.len() < ACCOUNT_META_ENTRY_SIZE {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "AccountMetaEntry",
+            }
+            .into());
+        }
+
+        let value = AccountMetaEntry::deserialize(&mut bytes).map_err(|_| {
+            FankorErrorCode::ZeroCopyCannotDeserialize {
+                type_name: "AccountMetaEntry",
+            }
+        })?;
+
+        Ok((value, Some(ACCOUNT_META_ENTRY_SIZE)))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        if bytes.len() < ACCOUNT_META_ENTRY_SIZE {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "AccountMetaEntry",
+            }
+            .into());
+        }
+
+        Ok(ACCOUNT_META_ENTRY_SIZE)
+    }
+}
+
+impl<'info> CopyType<'info> for AccountMetaEntry {
+    type ZeroCopyType = AccountMetaEntry;
+
+    fn min_byte_size() -> usize {
+        ACCOUNT_META_ENTRY_SIZE
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<'info> ZcFnkVec<'info, AccountMetaEntry> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Reads the entries as the metas of an instruction, e.g. to append them
+    /// to a CPI.
+    pub fn to_account_metas(&self) -> FankorResult<Vec<AccountMeta>> {
+        let mut result = Vec::with_capacity(self.len()?);
+
+        for entry in self.iter()? {
+            result.push(entry?.try_value()?.to_account_meta());
+        }
+
+        Ok(result)
+    }
+
+    /// Finds the account of each entry among the accounts of the instruction,
+    /// in the same order, checking they have at least the privileges of their
+    /// entries.
+    pub fn resolve_account_infos(
+        &self,
+        context: &'info FankorContext<'info>,
+    ) -> FankorResult<Vec<&'info AccountInfo<'info>>> {
+        let mut result = Vec::with_capacity(self.len()?);
+
+        for entry in self.iter()? {
+            result.push(entry?.try_value()?.resolve(context)?);
+        }
+
+        Ok(result)
+    }
+}
+
+impl<'info> Zc<'info, FnkVec<AccountMetaEntry>> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Same as [ZcFnkVec::to_account_metas].
+    pub fn to_account_metas(&self) -> FankorResult<Vec<AccountMeta>> {
+        self.zc_value()?.to_account_metas()
+    }
+
+    /// Same as [ZcFnkVec::resolve_account_infos].
+    pub fn resolve_account_infos(
+        &self,
+        context: &'info FankorContext<'info>,
+    ) -> FankorResult<Vec<&'info AccountInfo<'info>>> {
+        self.zc_value()?.resolve_account_infos(context)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use borsh::BorshSerialize;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[test]
+    fn test_resolve_account_infos() {
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let mut info_a = create_account_info_for_tests(lamports, data);
+        info_a.key = Box::leak(Box::new(Pubkey::new_unique()));
+        info_a.is_writable = true;
+
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let mut info_b = create_account_info_for_tests(lamports, data);
+        info_b.key = Box::leak(Box::new(Pubkey::new_unique()));
+        info_b.is_writable = false;
+
+        let entries = FnkVec::new(vec![
+            AccountMetaEntry::new_readonly(*info_b.key, false),
+            AccountMetaEntry::new(*info_a.key, false),
+        ]);
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(entries.try_to_vec().unwrap().into_boxed_slice());
+        let list = create_account_info_for_tests(lamports, data);
+
+        let infos = Box::leak(Box::new([info_a, info_b, list]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(&PROGRAM_ID, infos)));
+        let zc = Zc::<FnkVec<AccountMetaEntry>>::new_unchecked(&infos[2], 0);
+
+        assert_eq!(
+            zc.to_account_metas().unwrap(),
+            vec![
+                AccountMeta::new_readonly(*infos[1].key, false),
+                AccountMeta::new(*infos[0].key, false),
+            ]
+        );
+
+        let resolved = zc.resolve_account_infos(context).unwrap();
+        assert_eq!(resolved.len(), 2);
+        assert_eq!(resolved[0].key, infos[1].key);
+        assert_eq!(resolved[1].key, infos[0].key);
+
+        let entries = FnkVec::new(vec![AccountMetaEntry::new(*infos[1].key, false)]);
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(entries.try_to_vec().unwrap().into_boxed_slice());
+        let list = Box::leak(Box::new(create_account_info_for_tests(lamports, data)));
+        let zc = Zc::<FnkVec<AccountMetaEntry>>::new_unchecked(list, 0);
+
+        assert!(zc.resolve_account_infos(context).is_err());
+    }
+}
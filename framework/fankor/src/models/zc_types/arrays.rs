@@ -5,6 +5,7 @@ use solana_program::account_info::AccountInfo;
 
 use crate::errors::FankorResult;
 use crate::models::Zc;
+use crate::models::zc_types::try_borrow_zc_data;
 use crate::models::zc_types::vec::Iter;
 use crate::prelude::FnkArray;
 use crate::traits::{CopyType, ZeroCopyType};
@@ -54,6 +55,10 @@ impl<'info, T: CopyType<'info>, const N: usize> CopyType<'info> for FnkArray<T,
     fn min_byte_size() -> usize {
         N * T::min_byte_size()
     }
+
+    fn min_account_size() -> usize {
+        N * T::min_account_size()
+    }
 }
 
 impl<'info, T: CopyType<'info>, const N: usize> ZcFnkArray<'info, T, N> {
@@ -77,7 +82,7 @@ impl<'info, T: CopyType<'info>, const N: usize> ZcFnkArray<'info, T, N> {
             return Ok(None);
         }
 
-        let bytes = (*self.info.data).borrow();
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "get_zc_index")?;
         let mut bytes = &bytes[self.offset..];
         let initial_size = bytes.len();
 
@@ -109,7 +114,7 @@ impl<'info, T: CopyType<'info>, const N: usize> ZcFnkArray<'info, T, N> {
 }
 
 impl<'info, T: CopyType<'info>, const N: usize> IntoIterator for ZcFnkArray<'info, T, N> {
-    type Item = Zc<'info, T>;
+    type Item = FankorResult<Zc<'info, T>>;
     type IntoIter = Iter<'info, T>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -5,8 +5,9 @@ use std::mem::size_of;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::{FankorErrorCode, FankorResult};
+use crate::errors::FankorResult;
 use crate::models::Zc;
+use crate::models::zc_types::{try_borrow_mut_zc_data, try_borrow_zc_data};
 use crate::prelude::{CopyType, FnkBMap, Node, FNK_BINARY_TREE_MAX_HEIGHT};
 use crate::traits::ZeroCopyType;
 use crate::utils::writers::ArrayWriter;
@@ -22,13 +23,7 @@ impl<'info, K: CopyType<'info>, V: CopyType<'info>> ZcFnkBMap<'info, K, V> {
 
     /// Returns the number of elements in the map.
     pub fn len(&self) -> FankorResult<u16> {
-        let bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "len")?;
 
         let mut bytes = &bytes[self.offset..];
         Ok(u16::deserialize(&mut bytes)?)
@@ -41,13 +36,7 @@ impl<'info, K: CopyType<'info>, V: CopyType<'info>> ZcFnkBMap<'info, K, V> {
 
     /// Returns the root position.
     fn root_position(&self) -> FankorResult<u16> {
-        let bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "root_position")?;
 
         let mut bytes = &bytes[self.offset..];
         bytes = &bytes[size_of::<u16>()..];
@@ -63,11 +52,8 @@ impl<'info, K: CopyType<'info>, V: CopyType<'info>> ZcFnkBMap<'info, K, V> {
 
     /// Writes the number of elements in the map.
     fn write_len(&self, len: u16) -> FankorResult<()> {
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let mut bytes =
+            try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "write_len")?;
 
         let bytes = &mut bytes[self.offset..];
         let mut writer = ArrayWriter::new(bytes);
@@ -78,11 +64,11 @@ impl<'info, K: CopyType<'info>, V: CopyType<'info>> ZcFnkBMap<'info, K, V> {
 
     /// Writes the root position in the map.
     fn write_root_position(&self, root_position: u16) -> FankorResult<()> {
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let mut bytes = try_borrow_mut_zc_data(
+            self.info,
+            std::any::type_name::<Self>(),
+            "write_root_position",
+        )?;
 
         let bytes = &mut bytes[self.offset + size_of::<u16>()..];
         let mut writer = ArrayWriter::new(bytes);
@@ -202,13 +188,7 @@ impl<
         let mut offset = self.content_offset();
         offset += index as usize * Node::<K, V>::byte_size();
 
-        let bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "read_node")?;
 
         Ok(<Node<K, V>>::deserialize(&mut &bytes[offset..])?)
     }
@@ -221,13 +201,7 @@ impl<
         let mut offset = self.content_offset();
         offset += index as usize * Node::<K, V>::byte_size();
 
-        let bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "read_node_key")?;
 
         Ok(K::deserialize(&mut &bytes[offset..])?)
     }
@@ -242,12 +216,7 @@ impl<
         offset += K::min_byte_size();
 
         let bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+            try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "read_node_value")?;
 
         Ok(V::deserialize(&mut &bytes[offset..])?)
     }
@@ -261,13 +230,11 @@ impl<
         offset += index as usize * Node::<K, V>::byte_size();
         offset += K::min_byte_size() + V::min_byte_size();
 
-        let bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(
+            self.info,
+            std::any::type_name::<Self>(),
+            "read_node_left_child_at",
+        )?;
 
         Ok(u16::deserialize(&mut &bytes[offset..])?)
     }
@@ -281,13 +248,11 @@ impl<
         offset += index as usize * Node::<K, V>::byte_size();
         offset += K::min_byte_size() + V::min_byte_size() + size_of::<u16>();
 
-        let bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(
+            self.info,
+            std::any::type_name::<Self>(),
+            "read_node_right_child_at",
+        )?;
 
         Ok(u16::deserialize(&mut &bytes[offset..])?)
     }
@@ -302,12 +267,7 @@ impl<
         offset += K::min_byte_size() + V::min_byte_size() + size_of::<u16>() * 2;
 
         let bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+            try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "read_node_height")?;
 
         Ok(u8::deserialize(&mut &bytes[offset..])?)
     }
@@ -330,11 +290,8 @@ impl<
         let mut offset = self.content_offset();
         offset += index as usize * Node::<K, V>::byte_size();
 
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let mut bytes =
+            try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "write_node")?;
 
         let bytes = &mut bytes[offset..];
         let mut writer = ArrayWriter::new(bytes);
@@ -380,11 +337,8 @@ impl<
         offset += index as usize * Node::<K, V>::byte_size();
         offset += K::min_byte_size();
 
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let mut bytes =
+            try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "write_node_value")?;
 
         let bytes = &mut bytes[offset..];
         let mut writer = ArrayWriter::new(bytes);
@@ -402,11 +356,11 @@ impl<
         offset += index as usize * Node::<K, V>::byte_size();
         offset += K::min_byte_size() + V::min_byte_size();
 
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let mut bytes = try_borrow_mut_zc_data(
+            self.info,
+            std::any::type_name::<Self>(),
+            "write_node_left_child_at",
+        )?;
 
         let bytes = &mut bytes[offset..];
         let mut writer = ArrayWriter::new(bytes);
@@ -424,11 +378,11 @@ impl<
         offset += index as usize * Node::<K, V>::byte_size();
         offset += K::min_byte_size() + V::min_byte_size() + size_of::<u16>();
 
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let mut bytes = try_borrow_mut_zc_data(
+            self.info,
+            std::any::type_name::<Self>(),
+            "write_node_right_child_at",
+        )?;
 
         let bytes = &mut bytes[offset..];
         let mut writer = ArrayWriter::new(bytes);
@@ -446,11 +400,8 @@ impl<
         offset += index as usize * Node::<K, V>::byte_size();
         offset += K::min_byte_size() + V::min_byte_size() + size_of::<u16>() * 2;
 
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let mut bytes =
+            try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "write_node_height")?;
 
         let bytes = &mut bytes[offset..];
         let mut writer = ArrayWriter::new(bytes);
@@ -0,0 +1,159 @@
+use std::mem::size_of;
+
+use borsh::BorshDeserialize;
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::bloom_filter_bit_indexes;
+use crate::models::zc_types::{try_borrow_mut_zc_data, try_borrow_zc_data};
+use crate::prelude::FnkBloomFilter;
+use crate::traits::{CopyType, ZeroCopyType};
+
+/// The size of the hash count and length fields.
+const HEADER_SIZE: usize = size_of::<u8>() + size_of::<u32>();
+
+pub struct ZcFnkBloomFilter<'info> {
+    info: &'info AccountInfo<'info>,
+    offset: usize,
+}
+
+impl<'info> ZeroCopyType<'info> for ZcFnkBloomFilter<'info> {
+    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        Ok((ZcFnkBloomFilter { info, offset }, None))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        let mut bytes2 = bytes.get(size_of::<u8>()..).unwrap_or_default();
+        let len = u32::deserialize(&mut bytes2)? as usize;
+        let size = HEADER_SIZE + len;
+
+        if bytes.len() < size {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: std::any::type_name::<Self>(),
+            }
+            .into());
+        }
+
+        Ok(size)
+    }
+}
+
+impl<'info> CopyType<'info> for FnkBloomFilter {
+    type ZeroCopyType = ZcFnkBloomFilter<'info>;
+
+    fn byte_size(&self) -> usize {
+        HEADER_SIZE + self.bits().len()
+    }
+
+    fn min_byte_size() -> usize {
+        HEADER_SIZE
+    }
+}
+
+impl<'info> ZcFnkBloomFilter<'info> {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The number of bits set per key.
+    pub fn hash_count(&self) -> FankorResult<u8> {
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "hash_count")?;
+        let mut bytes = &bytes[self.offset..];
+
+        Ok(u8::deserialize(&mut bytes)?)
+    }
+
+    /// The number of bits of the filter.
+    pub fn bit_count(&self) -> FankorResult<usize> {
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "bit_count")?;
+        let mut bytes = &bytes[self.offset + size_of::<u8>()..];
+        let len = u32::deserialize(&mut bytes)?;
+
+        Ok(len as usize * 8)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Whether `key` could have been inserted in the filter.
+    pub fn contains(&self, key: &[u8]) -> FankorResult<bool> {
+        let hash_count = self.hash_count()?;
+        let bit_count = self.bit_count()?;
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "contains")?;
+        let bits = &bytes[self.offset + HEADER_SIZE..];
+
+        Ok(bloom_filter_bit_indexes(key, hash_count, bit_count)
+            .all(|bit| bits[bit / 8] & (1 << (bit % 8)) != 0))
+    }
+
+    /// Inserts `key` in the filter. Returns false if it could have been already
+    /// inserted, i.e. if all its bits were already set.
+    pub fn insert(&self, key: &[u8]) -> FankorResult<bool> {
+        let hash_count = self.hash_count()?;
+        let bit_count = self.bit_count()?;
+        let mut bytes = try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "insert")?;
+        let bits = &mut bytes[self.offset + HEADER_SIZE..];
+        let mut inserted = false;
+
+        for bit in bloom_filter_bit_indexes(key, hash_count, bit_count) {
+            let mask = 1 << (bit % 8);
+            inserted |= bits[bit / 8] & mask == 0;
+            bits[bit / 8] |= mask;
+        }
+
+        Ok(inserted)
+    }
+
+    /// Removes all the keys.
+    pub fn clear(&self) -> FankorResult<()> {
+        let bit_count = self.bit_count()?;
+        let mut bytes = try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "clear")?;
+        let start = self.offset + HEADER_SIZE;
+        bytes[start..start + bit_count / 8].fill(0);
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use borsh::BorshSerialize;
+
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_insert_and_contains() {
+        let mut lamports = 0;
+        let mut vector = FnkBloomFilter::new(16, 4).try_to_vec().unwrap();
+        vector.push(99);
+
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkBloomFilter::new(&info, 0).unwrap();
+
+        assert_eq!(
+            ZcFnkBloomFilter::read_byte_size(&info.try_borrow_data().unwrap()).unwrap(),
+            21
+        );
+        assert_eq!(zc.hash_count().unwrap(), 4);
+        assert_eq!(zc.bit_count().unwrap(), 128);
+        assert!(!zc.contains(b"alice").unwrap());
+
+        assert!(zc.insert(b"alice").unwrap());
+        assert!(!zc.insert(b"alice").unwrap());
+        assert!(zc.contains(b"alice").unwrap());
+
+        // Matches the non zero-copy version.
+        let mut filter = FnkBloomFilter::new(16, 4);
+        filter.insert(b"alice");
+        let data = info.try_borrow_data().unwrap();
+        assert_eq!(&data[HEADER_SIZE..21], filter.bits());
+        assert_eq!(data[21], 99);
+        drop(data);
+
+        zc.clear().unwrap();
+        assert!(!zc.contains(b"alice").unwrap());
+    }
+}
@@ -1,13 +1,12 @@
 use solana_program::account_info::AccountInfo;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::zc_types::try_borrow_zc_data;
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info> ZeroCopyType<'info> for bool {
     fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
-        let bytes = info
-            .try_borrow_data()
-            .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock { type_name: "bool" })?;
+        let bytes = try_borrow_zc_data(info, "bool", "new")?;
         let bytes = &bytes[offset..];
 
         if bytes.is_empty() {
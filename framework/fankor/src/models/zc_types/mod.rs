@@ -1,4 +1,7 @@
+use std::cell::{Ref, RefMut};
 use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
 use std::io::{Cursor, Write};
 
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -7,21 +10,35 @@ use solana_program::account_info::AccountInfo;
 use crate::errors::{FankorErrorCode, FankorResult};
 use crate::traits::{CopyType, ZeroCopyType};
 
+pub mod account_metas;
 pub mod arrays;
 pub mod binary_map;
 pub mod binary_set;
+pub mod bloom_filters;
 pub mod bool;
 pub mod boxed;
 pub mod extensions;
 pub mod numbers;
 pub mod options;
+pub mod percentages;
 pub mod pubkeys;
 pub mod ranges;
+pub mod ring_buffers;
+pub mod smart_pointers;
 pub mod strings;
 pub mod tuples;
 pub mod vec;
+pub mod write_planner;
 
 /// A wrapper around a `T` that implements `ZeroCopyType`.
+///
+/// All the zero-copy types share the data of the account, so a value cannot be
+/// modified while any other reference is reading it, e.g. while iterating over a
+/// vector that contains it. In that case the operation fails with
+/// [`FankorErrorCode::AlreadyBorrowed`] instead of panicking. The safe pattern is
+/// to collect the positions or values first and modify them once the iteration
+/// has finished, keeping the guards returned by [`Zc::try_borrow`] and
+/// [`Zc::try_borrow_mut`] as short-lived as possible.
 pub struct Zc<'info, T: CopyType<'info>> {
     pub(crate) info: &'info AccountInfo<'info>,
     pub(crate) offset: usize,
@@ -56,13 +73,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     /// Returns the size of the type in bytes.
     /// Note: validates the type without deserializing it.
     pub fn byte_size(&self) -> FankorResult<usize> {
-        let bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "byte_size")?;
         let bytes = &bytes[self.offset..];
         T::ZeroCopyType::read_byte_size(bytes)
     }
@@ -84,11 +95,8 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
         // Reallocate the buffer
         #[cfg(any(feature = "test-utils", test))]
         if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
-            let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-                FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                }
-            })?;
+            let mut bytes =
+                try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "make_space")?;
             let bytes = &mut bytes[self.offset..];
             bytes.rotate_right(length);
             return Ok(());
@@ -98,11 +106,8 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
         self.info.realloc(original_len + length, false)?;
 
         // Shift bytes
-        let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let mut bytes =
+            try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "make_space")?;
         let bytes = &mut bytes[self.offset..];
         bytes.copy_within(0..original_len - self.offset, length);
 
@@ -117,11 +122,8 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
     pub fn remove_unchecked(self) -> FankorResult<()> {
-        let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let mut original_bytes =
+            try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "remove_unchecked")?;
 
         let bytes = &mut original_bytes[self.offset..];
         let value_size = T::ZeroCopyType::read_byte_size(bytes)?;
@@ -146,11 +148,11 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
             return Ok(());
         }
 
-        let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let mut original_bytes = try_borrow_mut_zc_data(
+            self.info,
+            std::any::type_name::<Self>(),
+            "remove_bytes_unchecked",
+        )?;
 
         // Shift bytes
         let bytes = &mut original_bytes[self.offset..];
@@ -161,7 +163,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
         drop(original_bytes);
 
         #[cfg(any(feature = "test-utils", test))]
-        if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+        if self.info.rent_epoch != crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
             self.info.realloc(original_length - length, false)?;
         }
 
@@ -205,11 +207,11 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
         match new_size.cmp(&previous_size) {
             Ordering::Less => {
                 // Serialize
-                let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
-                        type_name: std::any::type_name::<Self>(),
-                    }
-                })?;
+                let mut original_bytes = try_borrow_mut_zc_data(
+                    self.info,
+                    std::any::type_name::<Self>(),
+                    "try_write_bytes_with_sizes_unchecked",
+                )?;
                 let original_bytes_slice = &mut original_bytes[self.offset..];
                 let mut cursor = Cursor::new(original_bytes_slice);
                 cursor.write_all(bytes)?;
@@ -223,7 +225,7 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
                 drop(original_bytes);
 
                 #[cfg(any(feature = "test-utils", test))]
-                if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+                if self.info.rent_epoch != crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
                     self.info.realloc(original_len - diff, false)?;
                 }
 
@@ -234,11 +236,11 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
             }
             Ordering::Equal => {
                 // Serialize
-                let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
-                        type_name: std::any::type_name::<Self>(),
-                    }
-                })?;
+                let mut original_bytes = try_borrow_mut_zc_data(
+                    self.info,
+                    std::any::type_name::<Self>(),
+                    "try_write_bytes_with_sizes_unchecked",
+                )?;
                 let original_bytes_slice = &mut original_bytes[self.offset..];
                 let mut cursor = Cursor::new(original_bytes_slice);
                 cursor.write_all(bytes)?;
@@ -250,11 +252,11 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
                 #[cfg(any(feature = "test-utils", test))]
                 if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
                     // Shift bytes
-                    let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
-                        FankorErrorCode::ZeroCopyPossibleDeadlock {
-                            type_name: std::any::type_name::<Self>(),
-                        }
-                    })?;
+                    let mut original_bytes = try_borrow_mut_zc_data(
+                        self.info,
+                        std::any::type_name::<Self>(),
+                        "try_write_bytes_with_sizes_unchecked",
+                    )?;
                     let original_bytes_slice = &mut original_bytes[self.offset..];
                     original_bytes_slice.rotate_right(diff);
 
@@ -268,11 +270,11 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
                 self.info.realloc(original_len + diff, false)?;
 
                 // Shift bytes
-                let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
-                        type_name: std::any::type_name::<Self>(),
-                    }
-                })?;
+                let mut original_bytes = try_borrow_mut_zc_data(
+                    self.info,
+                    std::any::type_name::<Self>(),
+                    "try_write_bytes_with_sizes_unchecked",
+                )?;
                 let original_bytes_slice = &mut original_bytes[self.offset..];
                 original_bytes_slice
                     .copy_within(previous_size..original_len - self.offset, new_size);
@@ -302,11 +304,11 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
                     return Err(FankorErrorCode::ZeroCopyInvalidMove.into());
                 }
 
-                let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
-                        type_name: std::any::type_name::<Self>(),
-                    }
-                })?;
+                let mut bytes = try_borrow_mut_zc_data(
+                    self.info,
+                    std::any::type_name::<Self>(),
+                    "move_byte_slice",
+                )?;
                 let bytes = &mut bytes[self.offset..];
                 bytes[from..to].rotate_left(size);
             }
@@ -315,11 +317,11 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
                 return Ok(());
             }
             Ordering::Greater => {
-                let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
-                        type_name: std::any::type_name::<Self>(),
-                    }
-                })?;
+                let mut bytes = try_borrow_mut_zc_data(
+                    self.info,
+                    std::any::type_name::<Self>(),
+                    "move_byte_slice",
+                )?;
                 let bytes = &mut bytes[self.offset..];
                 let end = from + size;
                 bytes[to..end].rotate_right(size);
@@ -336,11 +338,8 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     /// This method can fail if the element does not fit in `buffer`.
     pub fn append_to_vec(&self, buffer: &mut Vec<u8>) -> FankorResult<()> {
         let size = {
-            let original_bytes = self.info.data.try_borrow().map_err(|_| {
-                FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                }
-            })?;
+            let original_bytes =
+                try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "append_to_vec")?;
             let original_bytes_slice = &original_bytes[self.offset..];
             T::ZeroCopyType::read_byte_size(original_bytes_slice)?
         };
@@ -354,13 +353,11 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     /// # Safety
     /// This method can fail if the element does not fit in `buffer`.
     pub fn append_to_vec_with_size(&self, buffer: &mut Vec<u8>, size: usize) -> FankorResult<()> {
-        let original_bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let original_bytes = try_borrow_zc_data(
+            self.info,
+            std::any::type_name::<Self>(),
+            "append_to_vec_with_size",
+        )?;
         let mut bytes = &original_bytes[self.offset..];
         bytes = &bytes[..size];
 
@@ -379,13 +376,7 @@ impl<'info, T: CopyType<'info> + BorshDeserialize> Zc<'info, T> {
     ///
     /// This method can fail if `bytes` cannot be deserialized into the type.
     pub fn try_value(&self) -> FankorResult<T> {
-        let bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "try_value")?;
         let mut bytes = &bytes[self.offset..];
         Ok(T::deserialize(&mut bytes)?)
     }
@@ -411,13 +402,11 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
     pub fn try_write_value_unchecked(&self, value: &T) -> FankorResult<()> {
-        let original_bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let original_bytes = try_borrow_zc_data(
+            self.info,
+            std::any::type_name::<Self>(),
+            "try_write_value_unchecked",
+        )?;
         let bytes = &original_bytes[self.offset..];
         let previous_size = T::ZeroCopyType::read_byte_size(bytes)?;
         let new_size = value.byte_size();
@@ -435,23 +424,21 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
     /// MAKE SURE THAT THIS IS THE ONLY REFERENCE TO THE SAME ACCOUNT, OTHERWISE
     /// YOU WILL OVERWRITE DATA.
     pub fn try_write_zc_value_unchecked(&self, value: &Zc<'info, T>) -> FankorResult<()> {
-        let original_bytes =
-            self.info
-                .data
-                .try_borrow()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let original_bytes = try_borrow_zc_data(
+            self.info,
+            std::any::type_name::<Self>(),
+            "try_write_zc_value_unchecked",
+        )?;
         let bytes = &original_bytes[self.offset..];
         let previous_size = T::ZeroCopyType::read_byte_size(bytes)?;
 
         drop(original_bytes);
 
-        let original_value_bytes = value.info.data.try_borrow().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let original_value_bytes = try_borrow_zc_data(
+            value.info,
+            std::any::type_name::<Self>(),
+            "try_write_zc_value_unchecked",
+        )?;
         let value_bytes = &original_value_bytes[self.offset..];
         let value_size = T::ZeroCopyType::read_byte_size(value_bytes)?;
         let value_bytes = &value_bytes[..value_size];
@@ -478,11 +465,11 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
         match new_size.cmp(&previous_size) {
             Ordering::Less => {
                 // Serialize
-                let mut original_bytes = self.info.data.try_borrow_mut().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
-                        type_name: std::any::type_name::<Self>(),
-                    }
-                })?;
+                let mut original_bytes = try_borrow_mut_zc_data(
+                    self.info,
+                    std::any::type_name::<Self>(),
+                    "try_write_value_with_sizes_unchecked",
+                )?;
                 let bytes = &mut original_bytes[self.offset..];
                 let mut cursor = Cursor::new(bytes);
                 value.serialize(&mut cursor)?;
@@ -496,7 +483,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
                 drop(original_bytes);
 
                 #[cfg(any(feature = "test-utils", test))]
-                if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+                if self.info.rent_epoch != crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
                     self.info.realloc(original_len - diff, false)?;
                 }
 
@@ -507,11 +494,11 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
             }
             Ordering::Equal => {
                 // Serialize
-                let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
-                        type_name: std::any::type_name::<Self>(),
-                    }
-                })?;
+                let mut bytes = try_borrow_mut_zc_data(
+                    self.info,
+                    std::any::type_name::<Self>(),
+                    "try_write_value_with_sizes_unchecked",
+                )?;
                 let bytes = &mut bytes[self.offset..];
                 let mut cursor = Cursor::new(bytes);
                 value.serialize(&mut cursor)?;
@@ -523,11 +510,11 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
                 #[cfg(any(feature = "test-utils", test))]
                 if self.info.rent_epoch == crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
                     // Shift bytes
-                    let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-                        FankorErrorCode::ZeroCopyPossibleDeadlock {
-                            type_name: std::any::type_name::<Self>(),
-                        }
-                    })?;
+                    let mut bytes = try_borrow_mut_zc_data(
+                        self.info,
+                        std::any::type_name::<Self>(),
+                        "try_write_value_with_sizes_unchecked",
+                    )?;
                     let bytes = &mut bytes[self.offset..];
                     bytes.rotate_right(diff);
 
@@ -541,11 +528,11 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
                 self.info.realloc(original_len + diff, false)?;
 
                 // Shift bytes
-                let mut bytes = self.info.data.try_borrow_mut().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
-                        type_name: std::any::type_name::<Self>(),
-                    }
-                })?;
+                let mut bytes = try_borrow_mut_zc_data(
+                    self.info,
+                    std::any::type_name::<Self>(),
+                    "try_write_value_with_sizes_unchecked",
+                )?;
                 let bytes = &mut bytes[self.offset..];
                 bytes.copy_within(previous_size..original_len - self.offset, new_size);
 
@@ -570,11 +557,11 @@ impl<'info, T: CopyType<'info> + BorshSerialize> Zc<'info, T> {
         value: &Zc<'info, T>,
         previous_size: usize,
     ) -> FankorResult<()> {
-        let original_value_bytes = value.info.data.try_borrow().map_err(|_| {
-            FankorErrorCode::ZeroCopyPossibleDeadlock {
-                type_name: std::any::type_name::<Self>(),
-            }
-        })?;
+        let original_value_bytes = try_borrow_zc_data(
+            value.info,
+            std::any::type_name::<Self>(),
+            "try_write_zc_value_with_size_unchecked",
+        )?;
         let value_bytes = &original_value_bytes[self.offset..];
         let value_size = T::ZeroCopyType::read_byte_size(value_bytes)?;
         let value_bytes = &value_bytes[..value_size];
@@ -598,6 +585,29 @@ impl<'info, T: CopyType<'info>> Zc<'info, T> {
     }
 }
 
+impl<'info, T: CopyType<'info>> Zc<'info, T> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Immutably borrows the bytes of the value.
+    pub fn try_borrow(&self) -> FankorResult<Ref<'info, [u8]>> {
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "try_borrow")?;
+        let offset = self.offset;
+        let size = T::ZeroCopyType::read_byte_size(&bytes[offset..])?;
+
+        Ok(Ref::map(bytes, |v| &v[offset..offset + size]))
+    }
+
+    /// Mutably borrows the bytes of the value.
+    pub fn try_borrow_mut(&self) -> FankorResult<RefMut<'info, [u8]>> {
+        let bytes =
+            try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "try_borrow_mut")?;
+        let offset = self.offset;
+        let size = T::ZeroCopyType::read_byte_size(&bytes[offset..])?;
+
+        Ok(RefMut::map(bytes, |v| &mut v[offset..offset + size]))
+    }
+}
+
 impl<'info, T: CopyType<'info>> Clone for Zc<'info, T> {
     fn clone(&self) -> Self {
         Zc {
@@ -608,12 +618,75 @@ impl<'info, T: CopyType<'info>> Clone for Zc<'info, T> {
     }
 }
 
+/// Two projections are equal when they point to the same position of the same
+/// account, regardless of its content.
+impl<'info, T: CopyType<'info>> PartialEq for Zc<'info, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.info.key == other.info.key && self.offset == other.offset
+    }
+}
+
+impl<'info, T: CopyType<'info>> Eq for Zc<'info, T> {}
+
+impl<'info, T: CopyType<'info>> Hash for Zc<'info, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.info.key.hash(state);
+        self.offset.hash(state);
+    }
+}
+
+impl<'info, T: CopyType<'info>> Debug for Zc<'info, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Zc")
+            .field("address", self.info.key)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Immutably borrows the data of `info` to execute `operation` on the zero-copy type `type_name`.
+/// Fails with [`FankorErrorCode::AlreadyBorrowed`] if it is mutably borrowed.
+pub fn try_borrow_zc_data<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    type_name: &'static str,
+    operation: &'static str,
+) -> FankorResult<Ref<'a, &'info mut [u8]>> {
+    info.data.try_borrow().map_err(|_| {
+        FankorErrorCode::AlreadyBorrowed {
+            type_name,
+            operation,
+        }
+        .into()
+    })
+}
+
+/// Mutably borrows the data of `info` to execute `operation` on the zero-copy type `type_name`.
+/// Fails with [`FankorErrorCode::AlreadyBorrowed`] if it is already borrowed.
+pub fn try_borrow_mut_zc_data<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    type_name: &'static str,
+    operation: &'static str,
+) -> FankorResult<RefMut<'a, &'info mut [u8]>> {
+    info.data.try_borrow_mut().map_err(|_| {
+        FankorErrorCode::AlreadyBorrowed {
+            type_name,
+            operation,
+        }
+        .into()
+    })
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
 #[cfg(test)]
 mod test {
+    use crate::errors::Error;
     use crate::tests::create_account_info_for_tests;
 
     use super::*;
@@ -658,4 +731,32 @@ mod test {
             );
         }
     }
+
+    #[test]
+    pub fn test_already_borrowed() {
+        let mut lamports = 0;
+        let mut data = vec![0u8, 1, 2, 3];
+        let info = create_account_info_for_tests(&mut lamports, &mut data);
+        let zc = Zc::<u8>::new_unchecked(&info, 1);
+
+        {
+            let bytes = zc.try_borrow().expect("Cannot borrow");
+            assert_eq!(&*bytes, &[1]);
+            assert!(zc.try_borrow().is_ok(), "Immutable borrows must be shared");
+
+            let error = zc.try_borrow_mut().expect_err("Mutable borrow must fail");
+            match error {
+                Error::FankorError(e) => assert_eq!(e.error_name, "AlreadyBorrowed"),
+                Error::ProgramError(e) => panic!("Unexpected error: {}", e),
+            }
+        }
+
+        {
+            let mut bytes = zc.try_borrow_mut().expect("Cannot borrow mutably");
+            bytes[0] = 5;
+            assert!(zc.try_value().is_err(), "Read must fail while borrowed");
+        }
+
+        assert_eq!(zc.try_value().unwrap(), 5);
+    }
 }
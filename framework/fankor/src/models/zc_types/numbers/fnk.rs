@@ -2,16 +2,13 @@ use borsh::BorshDeserialize;
 use solana_program::account_info::AccountInfo;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::zc_types::try_borrow_zc_data;
 use crate::prelude::{FnkInt, FnkUInt};
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info> ZeroCopyType<'info> for FnkInt {
     fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
-        let bytes =
-            info.try_borrow_data()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(info, std::any::type_name::<Self>(), "new")?;
         let mut bytes = &bytes[offset..];
         let initial_size = bytes.len();
         let value = FnkInt::deserialize(&mut bytes)?;
@@ -113,11 +110,7 @@ impl<'info> CopyType<'info> for FnkInt {
 
 impl<'info> ZeroCopyType<'info> for FnkUInt {
     fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
-        let bytes =
-            info.try_borrow_data()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(info, std::any::type_name::<Self>(), "new")?;
         let mut bytes = &bytes[offset..];
         let initial_size = bytes.len();
         let value = FnkUInt::deserialize(&mut bytes)?;
@@ -5,6 +5,7 @@ use solana_program::account_info::AccountInfo;
 pub use fnk::*;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::zc_types::try_borrow_zc_data;
 use crate::traits::{CopyType, ZeroCopyType};
 
 mod fnk;
@@ -16,11 +17,7 @@ macro_rules! impl_type {
                 info: &'info AccountInfo<'info>,
                 offset: usize,
             ) -> FankorResult<(Self, Option<usize>)> {
-                let bytes = info.try_borrow_data().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
-                        type_name: std::any::type_name::<$ty>(),
-                    }
-                })?;
+                let bytes = try_borrow_zc_data(info, std::any::type_name::<$ty>(), "new")?;
                 let bytes = &bytes[offset..];
                 let size = size_of::<$ty>();
 
@@ -74,3 +71,40 @@ impl_type!(i128);
 // impl_type!(isize);
 impl_type!(f32);
 impl_type!(f64);
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::models::Zc;
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_128_bit_numbers() {
+        let mut lamports = 0;
+        let mut data = Vec::new();
+        data.extend_from_slice(&u128::MAX.to_le_bytes());
+        data.extend_from_slice(&i128::MIN.to_le_bytes());
+
+        let info = create_account_info_for_tests(&mut lamports, &mut data);
+
+        assert_eq!(u128::min_byte_size(), 16);
+        assert_eq!(i128::min_byte_size(), 16);
+
+        let zc = Zc::<u128>::new_unchecked(&info, 0);
+        assert_eq!(zc.byte_size().unwrap(), 16);
+        assert_eq!(zc.try_value().unwrap(), u128::MAX);
+
+        let zc = Zc::<i128>::new_unchecked(&info, 16);
+        assert_eq!(zc.byte_size().unwrap(), 16);
+        assert_eq!(zc.try_value().unwrap(), i128::MIN);
+
+        let (value, size) = <i128 as ZeroCopyType>::new(&info, 16).unwrap();
+        assert_eq!(value, i128::MIN);
+        assert_eq!(size, Some(16));
+    }
+}
@@ -5,16 +5,13 @@ use solana_program::account_info::AccountInfo;
 use solana_program::program_option::COption;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::zc_types::try_borrow_zc_data;
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info, T: ZeroCopyType<'info>> ZeroCopyType<'info> for Option<T> {
     fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let flag = {
-            let bytes =
-                info.try_borrow_data()
-                    .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                        type_name: type_name::<Self>(),
-                    })?;
+            let bytes = try_borrow_zc_data(info, type_name::<Self>(), "new")?;
             let bytes = &bytes[offset..];
 
             if bytes.is_empty() {
@@ -70,6 +67,10 @@ impl<'info, T: CopyType<'info>> CopyType<'info> for Option<T> {
     fn min_byte_size() -> usize {
         size_of::<u8>() // Discriminant
     }
+
+    fn min_account_size() -> usize {
+        size_of::<u8>() + T::min_account_size()
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -80,11 +81,7 @@ impl<'info, T: ZeroCopyType<'info>> ZeroCopyType<'info> for COption<T> {
     fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
         let size = size_of::<u32>();
         let flag = {
-            let bytes =
-                info.try_borrow_data()
-                    .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                        type_name: type_name::<Self>(),
-                    })?;
+            let bytes = try_borrow_zc_data(info, type_name::<Self>(), "new")?;
             let bytes = &bytes[offset..];
 
             if bytes.len() < size {
@@ -141,4 +138,8 @@ impl<'info, T: CopyType<'info>> CopyType<'info> for COption<T> {
     fn min_byte_size() -> usize {
         size_of::<u32>() // Discriminant
     }
+
+    fn min_account_size() -> usize {
+        size_of::<u32>() + T::min_account_size()
+    }
 }
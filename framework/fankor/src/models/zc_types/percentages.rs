@@ -0,0 +1,109 @@
+use borsh::BorshDeserialize;
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::zc_types::try_borrow_zc_data;
+use crate::prelude::{Bps, Percent};
+use crate::traits::{CopyType, ZeroCopyType};
+
+impl<'info> ZeroCopyType<'info> for Bps {
+    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let bytes = try_borrow_zc_data(info, "Bps", "new")?;
+        let mut bytes = &bytes[offset..];
+
+        if bytes.len() < 2 {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength { type_name: "Bps" }.into());
+        }
+
+        let value = Bps::deserialize(&mut bytes)
+            .map_err(|_| FankorErrorCode::ZeroCopyCannotDeserialize { type_name: "Bps" })?;
+
+        Ok((value, Some(2)))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        if bytes.len() < 2 {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength { type_name: "Bps" }.into());
+        }
+
+        Ok(2)
+    }
+}
+
+impl<'info> CopyType<'info> for Bps {
+    type ZeroCopyType = Bps;
+
+    fn min_byte_size() -> usize {
+        2
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<'info> ZeroCopyType<'info> for Percent {
+    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let bytes = try_borrow_zc_data(info, "Percent", "new")?;
+        let mut bytes = &bytes[offset..];
+
+        if bytes.is_empty() {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "Percent",
+            }
+            .into());
+        }
+
+        let value = Percent::deserialize(&mut bytes)
+            .map_err(|_| FankorErrorCode::ZeroCopyCannotDeserialize { type_name: "Percent" })?;
+
+        Ok((value, Some(1)))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        if bytes.is_empty() {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: "Percent",
+            }
+            .into());
+        }
+
+        Ok(1)
+    }
+}
+
+impl<'info> CopyType<'info> for Percent {
+    type ZeroCopyType = Percent;
+
+    fn min_byte_size() -> usize {
+        1
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::models::Zc;
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_zc_percentages() {
+        let mut lamports = 0;
+        let mut data = vec![0x10, 0x27, 50, 0x11, 0x27];
+        let info = create_account_info_for_tests(&mut lamports, &mut data);
+
+        let zc = Zc::<Bps>::new_unchecked(&info, 0);
+        assert_eq!(zc.try_value().unwrap(), Bps::MAX);
+
+        let zc = Zc::<Percent>::new_unchecked(&info, 2);
+        assert_eq!(zc.try_value().unwrap().get(), 50);
+
+        // Out of range.
+        assert!(<Bps as ZeroCopyType>::new(&info, 3).is_err());
+    }
+}
@@ -4,15 +4,13 @@ use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::types::FnkAccountRef;
+use crate::models::zc_types::try_borrow_zc_data;
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info> ZeroCopyType<'info> for Pubkey {
     fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
-        let bytes =
-            info.try_borrow_data()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: "Pubkey",
-                })?;
+        let bytes = try_borrow_zc_data(info, "Pubkey", "new")?;
         let bytes = &bytes[offset..];
         let size = size_of::<Pubkey>();
 
@@ -48,3 +46,22 @@ impl<'info> CopyType<'info> for Pubkey {
         size_of::<[u8; 32]>()
     }
 }
+
+impl<'info, T> ZeroCopyType<'info> for FnkAccountRef<T> {
+    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let (key, size) = <Pubkey as ZeroCopyType>::new(info, offset)?;
+        Ok((FnkAccountRef::new(key), size))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        <Pubkey as ZeroCopyType>::read_byte_size(bytes)
+    }
+}
+
+impl<'info, T> CopyType<'info> for FnkAccountRef<T> {
+    type ZeroCopyType = FnkAccountRef<T>;
+
+    fn min_byte_size() -> usize {
+        <Pubkey as CopyType>::min_byte_size()
+    }
+}
@@ -2,20 +2,16 @@ use borsh::BorshDeserialize;
 use solana_program::account_info::AccountInfo;
 
 use crate::{
-    errors::FankorErrorCode,
     errors::FankorResult,
     prelude::{FnkInt, FnkUInt},
 };
+use crate::models::zc_types::try_borrow_zc_data;
 use crate::prelude::{FnkRange, FnkURange};
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info> ZeroCopyType<'info> for FnkURange {
     fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
-        let bytes =
-            info.try_borrow_data()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(info, std::any::type_name::<Self>(), "new")?;
         let mut bytes = &bytes[offset..];
         let initial_size = bytes.len();
         let value = FnkURange::deserialize(&mut bytes)?;
@@ -51,11 +47,7 @@ impl<'info> CopyType<'info> for FnkURange {
 
 impl<'info> ZeroCopyType<'info> for FnkRange {
     fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
-        let bytes =
-            info.try_borrow_data()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                })?;
+        let bytes = try_borrow_zc_data(info, std::any::type_name::<Self>(), "new")?;
         let mut bytes = &bytes[offset..];
         let initial_size = bytes.len();
         let value = FnkRange::deserialize(&mut bytes)?;
@@ -0,0 +1,285 @@
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::zc_types::try_borrow_zc_data;
+use crate::models::Zc;
+use crate::prelude::FnkRingBuffer;
+use crate::traits::{CopyType, ZeroCopyType};
+
+pub struct ZcFnkRingBuffer<'info, T: CopyType<'info>, const N: usize> {
+    info: &'info AccountInfo<'info>,
+    offset: usize,
+    _data: PhantomData<T>,
+}
+
+impl<'info, T: CopyType<'info>, const N: usize> ZeroCopyType<'info>
+    for ZcFnkRingBuffer<'info, T, N>
+{
+    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        Ok((
+            ZcFnkRingBuffer {
+                info,
+                offset,
+                _data: PhantomData,
+            },
+            None,
+        ))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        let size = FnkRingBuffer::<T, N>::min_byte_size();
+
+        if bytes.len() < size {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: std::any::type_name::<Self>(),
+            }
+            .into());
+        }
+
+        Ok(size)
+    }
+}
+
+impl<'info, T: CopyType<'info>, const N: usize> CopyType<'info> for FnkRingBuffer<T, N> {
+    type ZeroCopyType = ZcFnkRingBuffer<'info, T, N>;
+
+    fn byte_size(&self) -> usize {
+        let mut size = size_of::<u64>();
+
+        for i in self.slots() {
+            size += i.byte_size();
+        }
+
+        size
+    }
+
+    fn min_byte_size() -> usize {
+        size_of::<u64>() + N * T::min_byte_size()
+    }
+}
+
+impl<'info, T: CopyType<'info>, const N: usize> ZcFnkRingBuffer<'info, T, N> {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The number of elements pushed into the buffer since its creation.
+    pub fn sequence(&self) -> FankorResult<u64> {
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "sequence")?;
+        let mut bytes = &bytes[self.offset..];
+
+        Ok(u64::deserialize(&mut bytes)?)
+    }
+
+    /// The slot in which the next element will be written.
+    pub fn head(&self) -> FankorResult<usize> {
+        Ok((self.sequence()? % N as u64) as usize)
+    }
+
+    /// The number of elements currently stored.
+    pub fn len(&self) -> FankorResult<usize> {
+        Ok(self.sequence()?.min(N as u64) as usize)
+    }
+
+    pub fn is_empty(&self) -> FankorResult<bool> {
+        Ok(self.sequence()? == 0)
+    }
+
+    /// The sequence number of the oldest stored element.
+    pub fn first_sequence(&self) -> FankorResult<u64> {
+        Ok(self.sequence()?.saturating_sub(N as u64))
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Gets the element with the `sequence` number if it is still stored.
+    pub fn get_zc(&self, sequence: u64) -> FankorResult<Option<Zc<'info, T>>> {
+        let current_sequence = self.sequence()?;
+
+        if sequence < current_sequence.saturating_sub(N as u64) || sequence >= current_sequence {
+            return Ok(None);
+        }
+
+        Ok(Some(self.slot_unchecked(sequence)))
+    }
+
+    /// Iterates over the stored elements from the oldest to the newest one
+    /// along with their sequence number.
+    pub fn iter(&self) -> FankorResult<RingBufferIter<'info, T, N>> {
+        self.iter_from(0)
+    }
+
+    /// Iterates over the stored elements with a sequence number equal or
+    /// greater than `sequence`, e.g. the next one a consumer has to process.
+    pub fn iter_from(&self, sequence: u64) -> FankorResult<RingBufferIter<'info, T, N>> {
+        let current_sequence = self.sequence()?;
+
+        Ok(RingBufferIter {
+            info: self.info,
+            offset: self.offset,
+            next: sequence
+                .max(current_sequence.saturating_sub(N as u64))
+                .min(current_sequence),
+            end: current_sequence,
+            _data: PhantomData,
+        })
+    }
+
+    fn slot_unchecked(&self, sequence: u64) -> Zc<'info, T> {
+        let slot = (sequence % N as u64) as usize;
+
+        Zc {
+            info: self.info,
+            offset: self.offset + size_of::<u64>() + slot * T::min_byte_size(),
+            _data: PhantomData,
+        }
+    }
+}
+
+impl<'info, T: CopyType<'info> + BorshSerialize, const N: usize> ZcFnkRingBuffer<'info, T, N> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Pushes an element overwriting the oldest one if the buffer is full.
+    /// Returns the sequence number of the new element.
+    ///
+    /// It never shifts nor reallocates the account so `value` must have the
+    /// fixed size of the slots.
+    pub fn push_overwrite(&self, value: &T) -> FankorResult<u64> {
+        let slot_size = T::min_byte_size();
+        let value_size = value.byte_size();
+
+        if value_size != slot_size {
+            return Err(FankorErrorCode::ZeroCopyInvalidElementSize {
+                type_name: std::any::type_name::<Self>(),
+                expected: slot_size,
+                actual: value_size,
+            }
+            .into());
+        }
+
+        let sequence = self.sequence()?;
+        let new_sequence = sequence
+            .checked_add(1)
+            .ok_or(FankorErrorCode::ZeroCopyLengthFieldOverflow)?;
+
+        let zc = self.slot_unchecked(sequence);
+        zc.try_write_value_with_sizes_unchecked(value, slot_size, slot_size)?;
+
+        let zc = Zc::<u64>::new_unchecked(self.info, self.offset);
+        zc.try_write_value_unchecked(&new_sequence)?;
+
+        Ok(sequence)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+pub struct RingBufferIter<'info, T: CopyType<'info>, const N: usize> {
+    info: &'info AccountInfo<'info>,
+    offset: usize,
+    next: u64,
+    end: u64,
+    _data: PhantomData<T>,
+}
+
+impl<'info, T: CopyType<'info>, const N: usize> Iterator for RingBufferIter<'info, T, N> {
+    type Item = (u64, Zc<'info, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+
+        let sequence = self.next;
+        let slot = (sequence % N as u64) as usize;
+        self.next += 1;
+
+        Some((
+            sequence,
+            Zc {
+                info: self.info,
+                offset: self.offset + size_of::<u64>() + slot * T::min_byte_size(),
+                _data: PhantomData,
+            },
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = (self.end - self.next) as usize;
+
+        (size, Some(size))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_push_overwrite_and_iter() {
+        let mut lamports = 0;
+        let mut vector = vec![0; 8 + 3 * 2 + 1];
+        vector[14] = 99;
+
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkRingBuffer::<u16, 3>::new(&info, 0).unwrap();
+
+        assert!(zc.is_empty().unwrap());
+
+        for i in 0..4 {
+            assert_eq!(zc.push_overwrite(&(i + 1)).unwrap(), i as u64);
+        }
+
+        assert_eq!(zc.sequence().unwrap(), 4);
+        assert_eq!(zc.head().unwrap(), 1);
+        assert_eq!(zc.len().unwrap(), 3);
+        assert_eq!(
+            *info.try_borrow_data().unwrap(),
+            vec![4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 3, 0, 99]
+        );
+
+        let values = zc
+            .iter()
+            .unwrap()
+            .map(|(seq, v)| (seq, v.try_value().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![(1, 2), (2, 3), (3, 4)]);
+
+        let values = zc
+            .iter_from(3)
+            .unwrap()
+            .map(|(seq, v)| (seq, v.try_value().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![(3, 4)]);
+
+        let iter = zc.iter_from(10).unwrap();
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.count(), 0);
+
+        assert!(zc.get_zc(0).unwrap().is_none());
+        assert_eq!(zc.get_zc(2).unwrap().unwrap().try_value().unwrap(), 3);
+        assert!(zc.get_zc(4).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_push_overwrite_invalid_size() {
+        let mut lamports = 0;
+        let mut vector = vec![0; 8 + 2 * 4];
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkRingBuffer::<Vec<u8>, 2>::new(&info, 0).unwrap();
+
+        assert!(zc.push_overwrite(&vec![]).is_ok());
+        assert!(zc.push_overwrite(&vec![1]).is_err());
+        assert_eq!(zc.sequence().unwrap(), 1);
+    }
+}
@@ -0,0 +1,107 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::FankorResult;
+use crate::traits::{CopyType, ZeroCopyType};
+
+macro_rules! impl_pointer {
+    ($($pointer:ident),* $(,)?) => {
+        $(
+            impl<'info, T: ZeroCopyType<'info>> ZeroCopyType<'info> for $pointer<T> {
+                fn new(
+                    info: &'info AccountInfo<'info>,
+                    offset: usize,
+                ) -> FankorResult<(Self, Option<usize>)> {
+                    let (result, size) = T::new(info, offset)?;
+                    Ok(($pointer::new(result), size))
+                }
+
+                fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+                    T::read_byte_size(bytes)
+                }
+            }
+
+            impl<'info, T: CopyType<'info>> CopyType<'info> for $pointer<T> {
+                type ZeroCopyType = T::ZeroCopyType;
+
+                fn byte_size(&self) -> usize {
+                    let aux: &T = self;
+                    aux.byte_size()
+                }
+
+                fn min_byte_size() -> usize {
+                    // Prevents infinite recursion.
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_pointer!(Rc, Arc);
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<'a, 'info, T> ZeroCopyType<'info> for Cow<'a, T>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: ZeroCopyType<'info>,
+{
+    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        let (result, size) = T::Owned::new(info, offset)?;
+        Ok((Cow::Owned(result), size))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        T::Owned::read_byte_size(bytes)
+    }
+}
+
+impl<'a, 'info, T> CopyType<'info> for Cow<'a, T>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: CopyType<'info>,
+{
+    type ZeroCopyType = <T::Owned as CopyType<'info>>::ZeroCopyType;
+
+    fn byte_size(&self) -> usize {
+        match self {
+            Cow::Borrowed(v) => T::to_owned(v).byte_size(),
+            Cow::Owned(v) => v.byte_size(),
+        }
+    }
+
+    fn min_byte_size() -> usize {
+        T::Owned::min_byte_size()
+    }
+
+    fn min_account_size() -> usize {
+        T::Owned::min_account_size()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_byte_size() {
+        let value = "fankor".to_string();
+        let size = value.byte_size();
+
+        assert_eq!(Rc::new(value.clone()).byte_size(), size);
+        assert_eq!(Arc::new(value.clone()).byte_size(), size);
+        assert_eq!(Cow::Borrowed(value.as_str()).byte_size(), size);
+        assert_eq!(Cow::<str>::Owned(value.clone()).byte_size(), size);
+        assert_eq!(<Cow<str> as CopyType>::min_byte_size(), String::min_byte_size());
+    }
+}
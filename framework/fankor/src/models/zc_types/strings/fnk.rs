@@ -2,6 +2,7 @@ use borsh::BorshDeserialize;
 use solana_program::account_info::AccountInfo;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::zc_types::try_borrow_zc_data;
 use crate::prelude::{FnkString, FnkUInt};
 use crate::traits::{CopyType, ZeroCopyType};
 
@@ -45,12 +46,7 @@ impl<'info> ZcFnkString<'info> {
     // GETTERS ----------------------------------------------------------------
 
     pub fn len(&self) -> FankorResult<usize> {
-        let bytes =
-            self.info
-                .try_borrow_data()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: "ZcFnkString",
-                })?;
+        let bytes = try_borrow_zc_data(self.info, "ZcFnkString", "len")?;
         let mut bytes = &bytes[self.offset..];
         let length = FnkUInt::deserialize(&mut bytes)?;
 
@@ -67,12 +63,7 @@ impl<'info> ZcFnkString<'info> {
 
     /// Reads the string as `&str` without copying it.
     pub fn borrow_as_str<R, F: FnOnce(&str) -> R>(&self, f: F) -> FankorResult<R> {
-        let bytes =
-            self.info
-                .try_borrow_data()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: "ZcFnkString",
-                })?;
+        let bytes = try_borrow_zc_data(self.info, "ZcFnkString", "borrow_as_str")?;
         let mut bytes = &bytes[self.offset..];
         let length = FnkUInt::deserialize(&mut bytes)?;
         let size = length
@@ -101,12 +92,7 @@ impl<'info> ZcFnkString<'info> {
     /// Differs from `borrow_as_str` in that this method returns does not check
     /// the string is a valid UTF-8 string.
     pub fn borrow_as_str_unchecked<R, F: FnOnce(&str) -> R>(&self, f: F) -> FankorResult<R> {
-        let bytes =
-            self.info
-                .try_borrow_data()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: "ZcFnkString",
-                })?;
+        let bytes = try_borrow_zc_data(self.info, "ZcFnkString", "borrow_as_str_unchecked")?;
         let mut bytes = &bytes[self.offset..];
         let length = FnkUInt::deserialize(&mut bytes)?;
         let size = length
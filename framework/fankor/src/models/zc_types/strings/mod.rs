@@ -6,6 +6,7 @@ use solana_program::account_info::AccountInfo;
 pub use fnk::*;
 
 use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::zc_types::try_borrow_zc_data;
 use crate::traits::{CopyType, ZeroCopyType};
 
 mod fnk;
@@ -43,12 +44,7 @@ impl<'info> ZcString<'info> {
     // GETTERS ----------------------------------------------------------------
 
     pub fn len(&self) -> FankorResult<usize> {
-        let bytes =
-            self.info
-                .try_borrow_data()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: "ZcString",
-                })?;
+        let bytes = try_borrow_zc_data(self.info, "ZcString", "len")?;
         let mut bytes = &bytes[self.offset..];
         let length = u32::deserialize(&mut bytes)?;
 
@@ -63,12 +59,7 @@ impl<'info> ZcString<'info> {
 
     /// Reads the string as `&str` without copying it.
     pub fn borrow_as_str<R, F: FnOnce(&str) -> R>(&self, f: F) -> FankorResult<R> {
-        let bytes =
-            self.info
-                .try_borrow_data()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: "ZcString",
-                })?;
+        let bytes = try_borrow_zc_data(self.info, "ZcString", "borrow_as_str")?;
         let mut bytes = &bytes[self.offset..];
         let length = u32::deserialize(&mut bytes)?;
         let size = length as usize;
@@ -95,12 +86,7 @@ impl<'info> ZcString<'info> {
     /// Differs from `borrow_as_str` in that this method returns does not check
     /// the string is a valid UTF-8 string.
     pub fn borrow_as_str_unchecked<R, F: FnOnce(&str) -> R>(&self, f: F) -> FankorResult<R> {
-        let bytes =
-            self.info
-                .try_borrow_data()
-                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: "ZcString",
-                })?;
+        let bytes = try_borrow_zc_data(self.info, "ZcString", "borrow_as_str_unchecked")?;
         let mut bytes = &bytes[self.offset..];
         let length = u32::deserialize(&mut bytes)?;
         let size = length as usize;
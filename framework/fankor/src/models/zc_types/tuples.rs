@@ -2,7 +2,8 @@ use std::any::type_name;
 
 use solana_program::account_info::AccountInfo;
 
-use crate::errors::{FankorErrorCode, FankorResult};
+use crate::errors::FankorResult;
+use crate::models::zc_types::try_borrow_zc_data;
 use crate::traits::{CopyType, ZeroCopyType};
 
 impl<'info> ZeroCopyType<'info> for () {
@@ -46,11 +47,7 @@ macro_rules! impl_tuple {
                     if let Some(size) = size {
                         offset += size
                     } else {
-                        let bytes =
-                            info.try_borrow_data()
-                                .map_err(|_| FankorErrorCode::ZeroCopyPossibleDeadlock {
-                                    type_name: type_name::<Self>(),
-                                })?;
+                        let bytes = try_borrow_zc_data(info, type_name::<Self>(), "new")?;
                         let bytes = &bytes[offset..];
                         offset += $types::read_byte_size(bytes)?
                     }
@@ -89,6 +86,14 @@ macro_rules! impl_tuple {
 
                 size
             }
+
+            fn min_account_size() -> usize {
+                let mut size = 0;
+
+                $(size += <$types>::min_account_size();)*
+
+                size
+            }
         }
     };
 }
@@ -0,0 +1,344 @@
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::zc_types::vec::Iter;
+use crate::models::zc_types::{try_borrow_mut_zc_data, try_borrow_zc_data};
+use crate::models::Zc;
+use crate::prelude::FnkCapVec;
+use crate::traits::{CopyType, ZeroCopyType};
+
+/// The size of the length and capacity fields.
+const HEADER_SIZE: usize = 2 * size_of::<u32>();
+
+pub struct ZcFnkCapVec<'info, T: CopyType<'info>> {
+    info: &'info AccountInfo<'info>,
+    offset: usize,
+    _data: PhantomData<T>,
+}
+
+impl<'info, T: CopyType<'info>> ZeroCopyType<'info> for ZcFnkCapVec<'info, T> {
+    fn new(info: &'info AccountInfo<'info>, offset: usize) -> FankorResult<(Self, Option<usize>)> {
+        Ok((
+            ZcFnkCapVec {
+                info,
+                offset,
+                _data: PhantomData,
+            },
+            None,
+        ))
+    }
+
+    fn read_byte_size(bytes: &[u8]) -> FankorResult<usize> {
+        let mut bytes2 = bytes;
+        let _len = u32::deserialize(&mut bytes2)?;
+        let byte_capacity = u32::deserialize(&mut bytes2)? as usize;
+        let size = HEADER_SIZE + byte_capacity;
+
+        if bytes.len() < size {
+            return Err(FankorErrorCode::ZeroCopyNotEnoughLength {
+                type_name: std::any::type_name::<Self>(),
+            }
+            .into());
+        }
+
+        Ok(size)
+    }
+}
+
+impl<'info, T: CopyType<'info>> CopyType<'info> for FnkCapVec<T> {
+    type ZeroCopyType = ZcFnkCapVec<'info, T>;
+
+    fn byte_size(&self) -> usize {
+        let mut size = 0;
+
+        for i in self.iter() {
+            size += i.byte_size();
+        }
+
+        HEADER_SIZE + size.max(self.byte_capacity())
+    }
+
+    fn min_byte_size() -> usize {
+        HEADER_SIZE
+    }
+}
+
+impl<'info, T: CopyType<'info>> ZcFnkCapVec<'info, T> {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The length of the vector.
+    pub fn len(&self) -> FankorResult<usize> {
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "len")?;
+        let mut bytes = &bytes[self.offset..];
+        let len = u32::deserialize(&mut bytes)?;
+
+        Ok(len as usize)
+    }
+
+    /// Whether the vector is empty or not
+    pub fn is_empty(&self) -> FankorResult<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// The number of bytes reserved for the elements, including the used ones.
+    pub fn byte_capacity(&self) -> FankorResult<usize> {
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "byte_capacity")?;
+        let mut bytes = &bytes[self.offset + size_of::<u32>()..];
+        let byte_capacity = u32::deserialize(&mut bytes)?;
+
+        Ok(byte_capacity as usize)
+    }
+
+    /// The number of bytes used by the elements.
+    pub fn used_bytes(&self) -> FankorResult<usize> {
+        let len = self.len()?;
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "used_bytes")?;
+        let bytes = &bytes[self.offset + HEADER_SIZE..];
+        let mut size = 0;
+
+        for _ in 0..len {
+            size += T::ZeroCopyType::read_byte_size(&bytes[size..])?;
+        }
+
+        Ok(size)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Gets the element at the specified position.
+    pub fn get_zc_index(&self, index: usize) -> FankorResult<Option<Zc<'info, T>>> {
+        let len = self.len()?;
+        if index >= len {
+            return Ok(None);
+        }
+
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "get_zc_index")?;
+        let bytes = &bytes[self.offset + HEADER_SIZE..];
+        let mut size = 0;
+
+        for _ in 0..index {
+            size += T::ZeroCopyType::read_byte_size(&bytes[size..])?;
+        }
+
+        Ok(Some(Zc {
+            info: self.info,
+            offset: self.offset + HEADER_SIZE + size,
+            _data: PhantomData,
+        }))
+    }
+
+    pub fn iter(&self) -> FankorResult<Iter<'info, T>> {
+        Ok(Iter {
+            info: self.info,
+            offset: self.offset + HEADER_SIZE,
+            len: self.len()?,
+            index: 0,
+            _data: PhantomData,
+        })
+    }
+
+    /// Removes all the elements keeping the reserved capacity.
+    pub fn clear(&self) -> FankorResult<()> {
+        let used_bytes = self.used_bytes()?;
+
+        {
+            let mut bytes =
+                try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "clear")?;
+            let start = self.offset + HEADER_SIZE;
+            bytes[start..start + used_bytes].fill(0);
+        }
+
+        self.write_len_unchecked(0)
+    }
+
+    /// Reserves space for at least `additional` more bytes of elements, shifting
+    /// the rest of the account only if the current capacity is not enough.
+    pub fn reserve(&self, additional: usize) -> FankorResult<()> {
+        let used_bytes = self.used_bytes()?;
+        let byte_capacity = self.byte_capacity()?;
+        let free_bytes = byte_capacity.saturating_sub(used_bytes);
+
+        if free_bytes >= additional {
+            return Ok(());
+        }
+
+        let diff = additional - free_bytes;
+        let new_byte_capacity: u32 = (byte_capacity + diff)
+            .try_into()
+            .map_err(|_| FankorErrorCode::ZeroCopyLengthFieldOverflow)?;
+
+        let start = self.offset + HEADER_SIZE + byte_capacity;
+        let zc = Zc::<u8>::new_unchecked(self.info, start);
+        zc.make_space(diff)?;
+
+        {
+            let mut bytes =
+                try_borrow_mut_zc_data(self.info, std::any::type_name::<Self>(), "reserve")?;
+            bytes[start..start + diff].fill(0);
+        }
+
+        self.write_byte_capacity_unchecked(new_byte_capacity)
+    }
+
+    /// Removes the unused capacity of the vector, shrinking the account.
+    pub fn shrink_to_fit(&self) -> FankorResult<()> {
+        let used_bytes = self.used_bytes()?;
+        let byte_capacity = self.byte_capacity()?;
+
+        if byte_capacity == used_bytes {
+            return Ok(());
+        }
+
+        let zc = Zc::<u8>::new_unchecked(self.info, self.offset + HEADER_SIZE + used_bytes);
+        zc.remove_bytes_unchecked(byte_capacity - used_bytes)?;
+
+        self.write_byte_capacity_unchecked(used_bytes as u32)
+    }
+
+    /// Writes the length of the vector.
+    pub fn write_len_unchecked(&self, new_length: u32) -> FankorResult<()> {
+        let zc = Zc::<u32>::new_unchecked(self.info, self.offset);
+        zc.try_write_value_unchecked(&new_length)
+    }
+
+    /// Writes the capacity of the vector.
+    fn write_byte_capacity_unchecked(&self, new_byte_capacity: u32) -> FankorResult<()> {
+        let zc = Zc::<u32>::new_unchecked(self.info, self.offset + size_of::<u32>());
+        zc.try_write_value_unchecked(&new_byte_capacity)
+    }
+}
+
+impl<'info, T: CopyType<'info> + BorshSerialize> ZcFnkCapVec<'info, T> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Appends an element to the end of the vector. The account is only shifted
+    /// and reallocated when the element does not fit in the reserved capacity.
+    pub fn push(&self, value: &T) -> FankorResult<()> {
+        let length: u32 = (self.len()? + 1)
+            .try_into()
+            .map_err(|_| FankorErrorCode::ZeroCopyLengthFieldOverflow)?;
+        let value_size = value.byte_size();
+
+        self.reserve(value_size)?;
+
+        let used_bytes = self.used_bytes()?;
+        let zc = Zc::new_unchecked(self.info, self.offset + HEADER_SIZE + used_bytes);
+        zc.try_write_value_with_sizes_unchecked(value, value_size, value_size)?;
+
+        self.write_len_unchecked(length)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_read_byte_size() {
+        let vector = vec![1, 0, 0, 0, 4, 0, 0, 0, 5, 0, 0, 0, 99];
+        let size = ZcFnkCapVec::<u8>::read_byte_size(&vector).unwrap();
+
+        assert_eq!(size, HEADER_SIZE + 4);
+
+        let vector = vec![1, 0, 0, 0, 4, 0, 0, 0, 5];
+        assert!(ZcFnkCapVec::<u8>::read_byte_size(&vector).is_err());
+    }
+
+    #[test]
+    fn test_push_within_capacity() {
+        let mut lamports = 0;
+        let mut vector = vec![1, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 99];
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkCapVec::<u16>::new(&info, 0).unwrap();
+
+        zc.push(&2).unwrap();
+
+        assert_eq!(zc.len().unwrap(), 2);
+        assert_eq!(zc.byte_capacity().unwrap(), 4);
+        assert_eq!(zc.used_bytes().unwrap(), 4);
+        assert_eq!(
+            *info.try_borrow_data().unwrap(),
+            vec![2, 0, 0, 0, 4, 0, 0, 0, 1, 0, 2, 0, 99]
+        );
+
+        let values = zc
+            .iter()
+            .unwrap()
+            .map(|v| v.unwrap().try_value().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_push_over_capacity() {
+        let mut lamports = 0;
+        let mut vector = vec![1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 99, 0, 0];
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkCapVec::<u16>::new(&info, 0).unwrap();
+
+        zc.push(&2).unwrap();
+
+        assert_eq!(zc.len().unwrap(), 2);
+        assert_eq!(zc.byte_capacity().unwrap(), 4);
+        assert_eq!(
+            *info.try_borrow_data().unwrap(),
+            vec![2, 0, 0, 0, 4, 0, 0, 0, 1, 0, 2, 0, 99]
+        );
+    }
+
+    #[test]
+    fn test_reserve_and_shrink_to_fit() {
+        let mut lamports = 0;
+        let mut vector = vec![1, 0, 0, 0, 1, 0, 0, 0, 5, 99, 0, 0, 0];
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkCapVec::<u8>::new(&info, 0).unwrap();
+
+        zc.reserve(3).unwrap();
+
+        assert_eq!(zc.byte_capacity().unwrap(), 4);
+        assert_eq!(
+            *info.try_borrow_data().unwrap(),
+            vec![1, 0, 0, 0, 4, 0, 0, 0, 5, 0, 0, 0, 99]
+        );
+
+        // Enough capacity.
+        zc.reserve(2).unwrap();
+        assert_eq!(zc.byte_capacity().unwrap(), 4);
+
+        zc.shrink_to_fit().unwrap();
+
+        assert_eq!(zc.byte_capacity().unwrap(), 1);
+
+        // Test accounts are not reallocated, so the last bytes are kept.
+        let data = info.try_borrow_data().unwrap();
+        assert_eq!(data.len(), 13);
+        assert_eq!(&data[..10], &[1, 0, 0, 0, 1, 0, 0, 0, 5, 99]);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut lamports = 0;
+        let mut vector = vec![2, 0, 0, 0, 3, 0, 0, 0, 5, 6, 0, 99];
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkCapVec::<u8>::new(&info, 0).unwrap();
+
+        zc.clear().unwrap();
+
+        assert!(zc.is_empty().unwrap());
+        assert_eq!(zc.byte_capacity().unwrap(), 3);
+        assert_eq!(
+            *info.try_borrow_data().unwrap(),
+            vec![0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 99]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::marker::PhantomData;
 use std::mem::size_of;
 
@@ -6,6 +7,7 @@ use solana_program::account_info::AccountInfo;
 
 use crate::errors::{FankorErrorCode, FankorResult};
 use crate::models::Zc;
+use crate::models::zc_types::try_borrow_zc_data;
 use crate::models::zc_types::vec::Iter;
 use crate::prelude::{FnkMap, FnkSet, FnkUInt, FnkVec};
 use crate::traits::{CopyType, ZeroCopyType};
@@ -116,7 +118,7 @@ impl<'info, T: CopyType<'info>> ZcFnkVec<'info, T> {
 
     /// The length of the vector.
     pub fn len(&self) -> FankorResult<usize> {
-        let bytes = (*self.info.data).borrow();
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "len")?;
         let mut bytes = &bytes[self.offset..];
         let len = FnkUInt::deserialize(&mut bytes)?;
 
@@ -133,7 +135,7 @@ impl<'info, T: CopyType<'info>> ZcFnkVec<'info, T> {
 
     /// Gets the element at the specified position.
     pub fn get_zc_index(&self, index: usize) -> FankorResult<Option<Zc<'info, T>>> {
-        let bytes = (*self.info.data).borrow();
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "get_zc_index")?;
         let mut bytes = &bytes[self.offset..];
         let initial_size = bytes.len();
 
@@ -171,7 +173,8 @@ impl<'info, T: CopyType<'info>> ZcFnkVec<'info, T> {
     {
         let mut offset = self.offset;
         let mut length = {
-            let original_bytes = (*self.info.data).borrow();
+            let original_bytes =
+                try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "retain")?;
             let bytes = &original_bytes[self.offset..];
             let mut bytes2 = bytes;
             let len = FnkUInt::deserialize(&mut bytes2)?;
@@ -199,22 +202,21 @@ impl<'info, T: CopyType<'info>> ZcFnkVec<'info, T> {
         Ok(())
     }
 
-    pub fn iter(&self) -> Iter<'info, T> {
-        let bytes = (*self.info.data).borrow();
+    pub fn iter(&self) -> FankorResult<Iter<'info, T>> {
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "iter")?;
         let mut bytes = &bytes[self.offset..];
         let original_len = bytes.len();
-        let len =
-            FnkUInt::deserialize(&mut bytes).expect("Failed to get length of ZcFnkVec in iterator");
+        let len = FnkUInt::deserialize(&mut bytes)?;
 
-        Iter {
+        Ok(Iter {
             info: self.info,
             offset: self.offset + (original_len - bytes.len()),
             len: len
                 .get_usize()
-                .expect("Failed to get usize from FnkUInt in iterator"),
+                .ok_or(FankorErrorCode::ZeroCopyLengthFieldOverflow)?,
             index: 0,
             _data: PhantomData,
-        }
+        })
     }
 
     /// Writes the length of the vector.
@@ -232,7 +234,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcFnkVec<'info, T> {
     pub fn append(&self, values: &[T]) -> FankorResult<usize> {
         // Get current size.
         let mut size = {
-            let bytes = (*self.info.data).borrow();
+            let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "append")?;
             let bytes = &bytes[self.offset..];
             Self::read_byte_size(bytes)?
         };
@@ -265,7 +267,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcFnkVec<'info, T> {
     pub fn append_zc(&self, values: &[Zc<'info, T>]) -> FankorResult<usize> {
         // Get current size.
         let mut size = {
-            let bytes = (*self.info.data).borrow();
+            let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "append_zc")?;
             let bytes = &bytes[self.offset..];
             Self::read_byte_size(bytes)?
         };
@@ -280,11 +282,8 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcFnkVec<'info, T> {
 
         // Append values.
         for value in values {
-            let original_value_bytes = value.info.data.try_borrow().map_err(|_| {
-                FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                }
-            })?;
+            let original_value_bytes =
+                try_borrow_zc_data(value.info, std::any::type_name::<Self>(), "append_zc")?;
             let value_bytes = &original_value_bytes[self.offset..];
             let value_size = T::ZeroCopyType::read_byte_size(value_bytes)?;
             let value_bytes = &value_bytes[..value_size];
@@ -301,12 +300,310 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcFnkVec<'info, T> {
     }
 }
 
-impl<'info, T: CopyType<'info>> IntoIterator for ZcFnkVec<'info, T> {
-    type Item = Zc<'info, T>;
-    type IntoIter = Iter<'info, T>;
+impl<'info, K, V> ZcFnkVec<'info, (K, V)>
+where
+    K: CopyType<'info> + Ord + BorshSerialize + BorshDeserialize,
+    V: CopyType<'info> + BorshSerialize + BorshDeserialize,
+{
+    // METHODS ----------------------------------------------------------------
+
+    /// Gets the entry of `key` in the map for in-place manipulation, finding
+    /// it with a single scan of the map.
+    pub fn entry(&self, key: K) -> FankorResult<ZcFnkMapEntry<'info, K, V>> {
+        let original_bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "entry")?;
+        let bytes = &original_bytes[self.offset..];
+        let initial_size = bytes.len();
+        let mut bytes2 = bytes;
+
+        let len = FnkUInt::deserialize(&mut bytes2)?;
+        let len = len
+            .get_usize()
+            .ok_or(FankorErrorCode::ZeroCopyLengthFieldOverflow)?;
+
+        for _ in 0..len {
+            let entry_offset = self.offset + initial_size - bytes2.len();
+            let entry_key = K::deserialize(&mut bytes2)?;
+
+            match entry_key.cmp(&key) {
+                Ordering::Less => {
+                    let size = V::ZeroCopyType::read_byte_size(bytes2)?;
+                    bytes2 = &bytes2[size..];
+                }
+                Ordering::Equal => {
+                    return Ok(ZcFnkMapEntry::Occupied(ZcFnkMapOccupiedEntry {
+                        info: self.info,
+                        offset: self.offset,
+                        entry_offset,
+                        value_offset: self.offset + initial_size - bytes2.len(),
+                        key,
+                        _data: PhantomData,
+                    }));
+                }
+                Ordering::Greater => {
+                    // Entries are sorted by key so it goes before this one.
+                    return Ok(ZcFnkMapEntry::Vacant(ZcFnkMapVacantEntry {
+                        info: self.info,
+                        offset: self.offset,
+                        entry_offset,
+                        key,
+                        _data: PhantomData,
+                    }));
+                }
+            }
+        }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+        Ok(ZcFnkMapEntry::Vacant(ZcFnkMapVacantEntry {
+            info: self.info,
+            offset: self.offset,
+            entry_offset: self.offset + initial_size - bytes2.len(),
+            key,
+            _data: PhantomData,
+        }))
+    }
+
+    /// Inserts all `entries` in the map rewriting it only once.
+    /// Returns the size of the map in bytes.
+    pub fn extend_entries<I>(&self, entries: I) -> FankorResult<usize>
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        self.update_map(|map| map.extend(entries))
+    }
+
+    /// Retains only the entries specified by the predicate rewriting the map
+    /// only once. Returns the size of the map in bytes.
+    pub fn retain_entries<F>(&self, mut f: F) -> FankorResult<usize>
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        self.update_map(|map| map.retain(|k, v| f(k, v)))
+    }
+
+    /// Merges `other` into the map rewriting it only once. See [FnkMap::merge].
+    /// Returns the size of the map in bytes.
+    pub fn merge_entries<F>(&self, other: FnkMap<K, V>, resolve: F) -> FankorResult<usize>
+    where
+        F: FnMut(&K, V, V) -> V,
+    {
+        self.update_map(|map| map.merge(other, resolve))
+    }
+
+    /// Deserializes the map, applies `f` to it and writes it back at once,
+    /// which is cheaper than modifying each entry in place for large maps.
+    /// Returns the size of the map in bytes.
+    pub fn update_map<F>(&self, f: F) -> FankorResult<usize>
+    where
+        F: FnOnce(&mut FnkMap<K, V>),
+    {
+        let (mut map, previous_size) = {
+            let original_bytes =
+                try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "update_map")?;
+            let bytes = &original_bytes[self.offset..];
+            let mut bytes2 = bytes;
+            let len = FnkUInt::deserialize(&mut bytes2)?;
+            let len = len
+                .get_usize()
+                .ok_or(FankorErrorCode::ZeroCopyLengthFieldOverflow)?;
+
+            let mut map = FnkMap::default();
+            for _ in 0..len {
+                let key = K::deserialize(&mut bytes2)?;
+                let value = V::deserialize(&mut bytes2)?;
+                map.insert(key, value);
+            }
+
+            (map, bytes.len() - bytes2.len())
+        };
+
+        f(&mut map);
+
+        let new_bytes = map.try_to_vec()?;
+        let zc = Zc::<FnkMap<K, V>>::new_unchecked(self.info, self.offset);
+        zc.try_write_bytes_with_sizes_unchecked(&new_bytes, previous_size)?;
+
+        Ok(new_bytes.len())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An entry of a zero-copy [FnkMap], obtained with [ZcFnkVec::entry].
+pub enum ZcFnkMapEntry<'info, K, V> {
+    Occupied(ZcFnkMapOccupiedEntry<'info, K, V>),
+    Vacant(ZcFnkMapVacantEntry<'info, K, V>),
+}
+
+impl<'info, K, V> ZcFnkMapEntry<'info, K, V>
+where
+    K: CopyType<'info> + Ord + BorshSerialize + BorshDeserialize,
+    V: CopyType<'info> + BorshSerialize + BorshDeserialize,
+{
+    // GETTERS ----------------------------------------------------------------
+
+    /// The key of the entry.
+    pub fn key(&self) -> &K {
+        match self {
+            ZcFnkMapEntry::Occupied(v) => v.key(),
+            ZcFnkMapEntry::Vacant(v) => v.key(),
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Inserts `default` if the entry is vacant and returns its value.
+    pub fn or_insert(self, default: V) -> FankorResult<Zc<'info, V>> {
+        match self {
+            ZcFnkMapEntry::Occupied(v) => Ok(v.get()),
+            ZcFnkMapEntry::Vacant(v) => v.insert(default),
+        }
+    }
+
+    /// Inserts the result of `default` if the entry is vacant and returns its
+    /// value.
+    pub fn or_insert_with<F>(self, default: F) -> FankorResult<Zc<'info, V>>
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            ZcFnkMapEntry::Occupied(v) => Ok(v.get()),
+            ZcFnkMapEntry::Vacant(v) => v.insert(default()),
+        }
+    }
+
+    /// Inserts the default value if the entry is vacant and returns its value.
+    pub fn or_default(self) -> FankorResult<Zc<'info, V>>
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Calls `f` with the value of the entry if it is occupied.
+    pub fn and_modify<F>(self, f: F) -> FankorResult<Self>
+    where
+        F: FnOnce(&Zc<'info, V>) -> FankorResult<()>,
+    {
+        if let ZcFnkMapEntry::Occupied(v) = &self {
+            f(&v.get())?;
+        }
+
+        Ok(self)
+    }
+}
+
+/// An occupied entry of a zero-copy [FnkMap].
+pub struct ZcFnkMapOccupiedEntry<'info, K, V> {
+    info: &'info AccountInfo<'info>,
+    offset: usize,
+    entry_offset: usize,
+    value_offset: usize,
+    key: K,
+    _data: PhantomData<V>,
+}
+
+impl<'info, K, V> ZcFnkMapOccupiedEntry<'info, K, V>
+where
+    K: CopyType<'info> + Ord + BorshSerialize + BorshDeserialize,
+    V: CopyType<'info> + BorshSerialize + BorshDeserialize,
+{
+    // GETTERS ----------------------------------------------------------------
+
+    /// The key of the entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// The value of the entry.
+    pub fn get(&self) -> Zc<'info, V> {
+        Zc::new_unchecked(self.info, self.value_offset)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Replaces the value of the entry, returning the previous one.
+    pub fn insert(&self, value: V) -> FankorResult<V> {
+        let zc = self.get();
+        let previous = zc.try_value()?;
+        zc.try_write_value_unchecked(&value)?;
+
+        Ok(previous)
+    }
+
+    /// Removes the entry from the map, returning its value.
+    pub fn remove(self) -> FankorResult<V> {
+        let map = ZcFnkVec::<(K, V)> {
+            info: self.info,
+            offset: self.offset,
+            _data: PhantomData,
+        };
+        let length = map.len()?;
+        let value = self.get().try_value()?;
+
+        Zc::<(K, V)>::new_unchecked(self.info, self.entry_offset).remove_unchecked()?;
+        map.write_len_unchecked(FnkUInt::from(length - 1))?;
+
+        Ok(value)
+    }
+}
+
+/// A vacant entry of a zero-copy [FnkMap].
+pub struct ZcFnkMapVacantEntry<'info, K, V> {
+    info: &'info AccountInfo<'info>,
+    offset: usize,
+    entry_offset: usize,
+    key: K,
+    _data: PhantomData<V>,
+}
+
+impl<'info, K, V> ZcFnkMapVacantEntry<'info, K, V>
+where
+    K: CopyType<'info> + Ord + BorshSerialize + BorshDeserialize,
+    V: CopyType<'info> + BorshSerialize + BorshDeserialize,
+{
+    // GETTERS ----------------------------------------------------------------
+
+    /// The key of the entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Takes the key of the entry.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Inserts `value` in the map keeping the entries sorted and returns it.
+    pub fn insert(self, value: V) -> FankorResult<Zc<'info, V>> {
+        let map = ZcFnkVec::<(K, V)> {
+            info: self.info,
+            offset: self.offset,
+            _data: PhantomData,
+        };
+        let length = map.len()?;
+        let fnk_length = FnkUInt::from(length);
+        let fnk_new_length = FnkUInt::from(
+            length
+                .checked_add(1)
+                .ok_or(FankorErrorCode::ZeroCopyLengthFieldOverflow)?,
+        );
+
+        let key_size = self.key.byte_size();
+        let entry = (self.key, value);
+        let zc = Zc::<(K, V)>::new_unchecked(self.info, self.entry_offset);
+        zc.try_write_value_with_sizes_unchecked(&entry, 0, entry.byte_size())?;
+
+        // Writing the length can shift the entries.
+        map.write_len_unchecked(fnk_new_length)?;
+        let diff = fnk_new_length.byte_size() - fnk_length.byte_size();
+
+        Ok(Zc::new_unchecked(
+            self.info,
+            self.entry_offset + diff + key_size,
+        ))
     }
 }
 
@@ -317,6 +614,7 @@ impl<'info, T: CopyType<'info>> IntoIterator for ZcFnkVec<'info, T> {
 #[cfg(test)]
 mod test {
     use std::cell::RefCell;
+    use std::collections::BTreeMap;
     use std::mem::size_of;
     use std::rc::Rc;
 
@@ -344,10 +642,10 @@ mod test {
         assert_eq!(zc.len().unwrap(), 5);
 
         let mut count = 0;
-        for zc_el in zc {
+        for zc_el in zc.iter().unwrap() {
             count += 1;
 
-            let value = zc_el.try_value().unwrap();
+            let value = zc_el.unwrap().try_value().unwrap();
             assert_eq!(value, 3);
         }
 
@@ -365,10 +663,10 @@ mod test {
         assert_eq!(zc.len().unwrap(), 5);
 
         let mut count = 0;
-        for zc_el in zc {
+        for zc_el in zc.iter().unwrap() {
             count += 1;
 
-            let value = zc_el.try_value().unwrap();
+            let value = zc_el.unwrap().try_value().unwrap();
             assert_eq!(value, 3);
         }
 
@@ -391,10 +689,10 @@ mod test {
         assert_eq!(new_offset, 504);
 
         let mut count = 0;
-        for zc_el in zc {
+        for zc_el in zc.iter().unwrap() {
             count += 1;
 
-            let value = zc_el.try_value().unwrap();
+            let value = zc_el.unwrap().try_value().unwrap();
             assert_eq!(value, 3);
         }
 
@@ -420,10 +718,10 @@ mod test {
         assert_eq!(new_offset, 1006);
 
         let mut count = 0;
-        for zc_el in zc {
+        for zc_el in zc.iter().unwrap() {
             count += 1;
 
-            let value = zc_el.try_value().unwrap();
+            let value = zc_el.unwrap().try_value().unwrap();
             assert_eq!(value, (3, 4));
         }
 
@@ -460,10 +758,10 @@ mod test {
         assert_eq!(new_offset, 5);
 
         let mut count = 0;
-        for zc_el in zc {
+        for zc_el in zc.iter().unwrap() {
             count += 1;
 
-            let value = zc_el.try_value().unwrap();
+            let value = zc_el.unwrap().try_value().unwrap();
             assert_eq!(value, 3);
         }
 
@@ -495,13 +793,97 @@ mod test {
         assert_eq!(zc.len().unwrap(), 3);
 
         let mut count = 0;
-        for zc_el in zc {
+        for zc_el in zc.iter().unwrap() {
             count += 1;
 
-            let value = zc_el.try_value().unwrap();
+            let value = zc_el.unwrap().try_value().unwrap();
             assert_eq!(value, 3);
         }
 
         assert_eq!(count, 3);
     }
+
+    #[test]
+    fn test_map_bulk_operations() {
+        let mut lamports = 0;
+        let mut vector = vec![0; 100];
+        let map = FnkMap::from(BTreeMap::from([(1u8, 10u16), (3, 30), (5, 50)]));
+        let bytes = map.try_to_vec().unwrap();
+        vector[..bytes.len()].copy_from_slice(&bytes);
+
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkVec::<(u8, u16)>::new(&info, 0).unwrap();
+
+        let size = zc.extend_entries([(2, 20), (4, 40)]).unwrap();
+        assert_eq!(size, 1 + 5 * 3);
+        assert_eq!(zc.len().unwrap(), 5);
+
+        let size = zc.retain_entries(|k, _| *k != 3).unwrap();
+        assert_eq!(size, 1 + 4 * 3);
+
+        let other = FnkMap::from(BTreeMap::from([(1u8, 1u16), (6, 60)]));
+        let size = zc.merge_entries(other, |_, a, b| a + b).unwrap();
+        assert_eq!(size, 1 + 5 * 3);
+
+        let values = zc
+            .iter()
+            .unwrap()
+            .map(|v| v.unwrap().try_value().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![(1, 11), (2, 20), (4, 40), (5, 50), (6, 60)]);
+    }
+
+    #[test]
+    fn test_map_entry() {
+        let mut lamports = 0;
+        let mut vector = vec![0; 100];
+        let map = FnkMap::from(BTreeMap::from([(1u8, 10u16), (5, 50)]));
+        let bytes = map.try_to_vec().unwrap();
+        vector[..bytes.len()].copy_from_slice(&bytes);
+
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcFnkVec::<(u8, u16)>::new(&info, 0).unwrap();
+
+        // Occupied.
+        let value = zc
+            .entry(5)
+            .unwrap()
+            .and_modify(|v| v.try_write_value_unchecked(&(v.try_value()? + 1)))
+            .unwrap()
+            .or_insert(0)
+            .unwrap();
+        assert_eq!(value.try_value().unwrap(), 51);
+
+        match zc.entry(1).unwrap() {
+            ZcFnkMapEntry::Occupied(v) => {
+                assert_eq!(*v.key(), 1);
+                assert_eq!(v.insert(11).unwrap(), 10);
+            }
+            ZcFnkMapEntry::Vacant(_) => unreachable!(),
+        }
+
+        // Vacant.
+        let value = zc.entry(3).unwrap().or_insert(30).unwrap();
+        assert_eq!(value.try_value().unwrap(), 30);
+
+        let value = zc.entry(7).unwrap().or_default().unwrap();
+        assert_eq!(value.try_value().unwrap(), 0);
+        assert_eq!(zc.len().unwrap(), 4);
+
+        // Remove.
+        match zc.entry(3).unwrap() {
+            ZcFnkMapEntry::Occupied(v) => assert_eq!(v.remove().unwrap(), 30),
+            ZcFnkMapEntry::Vacant(_) => unreachable!(),
+        }
+
+        // Test accounts are not reallocated.
+        assert_eq!(info.data_len(), 100);
+
+        let values = zc
+            .iter()
+            .unwrap()
+            .map(|v| v.unwrap().try_value().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![(1, 11), (5, 51), (7, 0)]);
+    }
 }
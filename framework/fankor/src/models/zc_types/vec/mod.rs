@@ -4,13 +4,16 @@ use std::mem::size_of;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::account_info::AccountInfo;
 
+pub use cap::*;
 pub use fnk::*;
 
 use crate::errors::{FankorErrorCode, FankorResult};
 use crate::models::Zc;
+use crate::models::zc_types::{try_borrow_mut_zc_data, try_borrow_zc_data};
 use crate::traits::{CopyType, ZeroCopyType};
 use crate::utils::writers::ArrayWriter;
 
+mod cap;
 mod fnk;
 
 pub struct ZcVec<'info, T: CopyType<'info>> {
@@ -68,7 +71,7 @@ impl<'info, T: CopyType<'info>> ZcVec<'info, T> {
 
     /// The length of the vector.
     pub fn len(&self) -> FankorResult<usize> {
-        let bytes = (*self.info.data).borrow();
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "len")?;
         let mut bytes = &bytes[self.offset..];
         let len = u32::deserialize(&mut bytes)?;
 
@@ -84,7 +87,7 @@ impl<'info, T: CopyType<'info>> ZcVec<'info, T> {
 
     /// Gets the element at the specified position.
     pub fn get_zc_index(&self, index: usize) -> FankorResult<Option<Zc<'info, T>>> {
-        let bytes = (*self.info.data).borrow();
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "get_zc_index")?;
         let mut bytes = &bytes[self.offset..];
         let initial_size = bytes.len();
 
@@ -140,24 +143,28 @@ impl<'info, T: CopyType<'info>> ZcVec<'info, T> {
         Ok(())
     }
 
-    pub fn iter(&self) -> Iter<'info, T> {
-        let bytes = (*self.info.data).borrow();
+    pub fn iter(&self) -> FankorResult<Iter<'info, T>> {
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "iter")?;
         let mut bytes = &bytes[self.offset..];
         let original_len = bytes.len();
-        let len = u32::deserialize(&mut bytes).expect("Failed to get length of ZcVec in iterator");
+        let len = u32::deserialize(&mut bytes)?;
 
-        Iter {
+        Ok(Iter {
             info: self.info,
             offset: self.offset + (original_len - bytes.len()),
             len: len as usize,
             index: 0,
             _data: PhantomData,
-        }
+        })
     }
 
     /// Writes the length of the vector.
     pub fn write_len_unchecked(&self, new_length: u32) -> FankorResult<()> {
-        let mut bytes = (*self.info.data).borrow_mut();
+        let mut bytes = try_borrow_mut_zc_data(
+            self.info,
+            std::any::type_name::<Self>(),
+            "write_len_unchecked",
+        )?;
         let bytes = &mut bytes[self.offset..];
         let mut writer = ArrayWriter::new(bytes);
         u32::serialize(&new_length, &mut writer)?;
@@ -174,7 +181,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcVec<'info, T> {
     pub fn append(&self, values: &[T]) -> FankorResult<usize> {
         // Get current size.
         let mut size = {
-            let bytes = (*self.info.data).borrow();
+            let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "append")?;
             let bytes = &bytes[self.offset..];
             Self::read_byte_size(bytes)?
         };
@@ -204,7 +211,7 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcVec<'info, T> {
     pub fn append_zc(&self, values: &[Zc<'info, T>]) -> FankorResult<usize> {
         // Get current size.
         let mut size = {
-            let bytes = (*self.info.data).borrow();
+            let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "append_zc")?;
             let bytes = &bytes[self.offset..];
             Self::read_byte_size(bytes)?
         };
@@ -220,11 +227,8 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcVec<'info, T> {
 
         // Append values.
         for value in values {
-            let original_value_bytes = value.info.data.try_borrow().map_err(|_| {
-                FankorErrorCode::ZeroCopyPossibleDeadlock {
-                    type_name: std::any::type_name::<Self>(),
-                }
-            })?;
+            let original_value_bytes =
+                try_borrow_zc_data(value.info, std::any::type_name::<Self>(), "append_zc")?;
             let value_bytes = &original_value_bytes[self.offset..];
             let value_size = T::ZeroCopyType::read_byte_size(value_bytes)?;
             let value_bytes = &value_bytes[..value_size];
@@ -238,15 +242,6 @@ impl<'info, T: CopyType<'info> + BorshSerialize> ZcVec<'info, T> {
     }
 }
 
-impl<'info, T: CopyType<'info>> IntoIterator for ZcVec<'info, T> {
-    type Item = Zc<'info, T>;
-    type IntoIter = Iter<'info, T>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
-    }
-}
-
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
@@ -259,8 +254,20 @@ pub struct Iter<'info, T: CopyType<'info>> {
     pub(crate) _data: PhantomData<T>,
 }
 
+impl<'info, T: CopyType<'info>> Iter<'info, T> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Moves the offset to the next element.
+    fn advance(&mut self) -> FankorResult<()> {
+        let bytes = try_borrow_zc_data(self.info, std::any::type_name::<Self>(), "next")?;
+        self.offset += T::ZeroCopyType::read_byte_size(&bytes[self.offset..])?;
+
+        Ok(())
+    }
+}
+
 impl<'info, T: CopyType<'info>> Iterator for Iter<'info, T> {
-    type Item = Zc<'info, T>;
+    type Item = FankorResult<Zc<'info, T>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.index >= self.len {
@@ -268,11 +275,11 @@ impl<'info, T: CopyType<'info>> Iterator for Iter<'info, T> {
         }
 
         if self.index != 0 {
-            let bytes = (*self.info.data).borrow();
-            let bytes = &bytes[self.offset..];
-
-            self.offset += T::ZeroCopyType::read_byte_size(bytes)
-                .expect("Deserialization failed in vector iterator");
+            if let Err(e) = self.advance() {
+                // The following elements cannot be located.
+                self.index = self.len;
+                return Some(Err(e));
+            }
         }
 
         let result = Zc {
@@ -283,7 +290,7 @@ impl<'info, T: CopyType<'info>> Iterator for Iter<'info, T> {
 
         self.index += 1;
 
-        Some(result)
+        Some(Ok(result))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -328,10 +335,10 @@ mod test {
         assert_eq!(zc.len().unwrap(), 5);
 
         let mut count = 0;
-        for zc_el in zc {
+        for zc_el in zc.iter().unwrap() {
             count += 1;
 
-            let value = zc_el.try_value().unwrap();
+            let value = zc_el.unwrap().try_value().unwrap();
             assert_eq!(value, 3);
         }
 
@@ -345,7 +352,8 @@ mod test {
         let info = create_account_info_for_tests(&mut lamports, &mut vector);
         let (zc, _) = ZcVec::<Option<u8>>::new(&info, 0).unwrap();
 
-        zc.iter().for_each(|zc_el| {
+        zc.iter().unwrap().for_each(|zc_el| {
+            let zc_el = zc_el.unwrap();
             let value = zc_el.try_value().unwrap().unwrap();
 
             if value == 2 {
@@ -355,8 +363,8 @@ mod test {
 
         let mut some_count = 0;
         let mut none_count = 0;
-        for zc_el in zc {
-            match zc_el.try_value().unwrap() {
+        for zc_el in zc.iter().unwrap() {
+            match zc_el.unwrap().try_value().unwrap() {
                 Some(v) => {
                     assert_eq!(v, 3);
                     some_count += 1;
@@ -371,6 +379,22 @@ mod test {
         assert_eq!(none_count, 2);
     }
 
+    #[test]
+    fn test_iter_already_borrowed() {
+        let mut lamports = 0;
+        let mut vector = vec![2, 0, 0, 0, 3, 3];
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let (zc, _) = ZcVec::<u8>::new(&info, 0).unwrap();
+
+        let mut iter = zc.iter().unwrap();
+        assert!(iter.next().unwrap().is_ok());
+
+        let _bytes = info.try_borrow_mut_data().unwrap();
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+        assert!(zc.iter().is_err());
+    }
+
     #[test]
     fn test_write_len() {
         let mut lamports = 0;
@@ -382,10 +406,10 @@ mod test {
         assert_eq!(zc.len().unwrap(), 5);
 
         let mut count = 0;
-        for zc_el in zc {
+        for zc_el in zc.iter().unwrap() {
             count += 1;
 
-            let value = zc_el.try_value().unwrap();
+            let value = zc_el.unwrap().try_value().unwrap();
             assert_eq!(value, 3);
         }
 
@@ -404,10 +428,10 @@ mod test {
         assert_eq!(new_offset, 9);
 
         let mut count = 0;
-        for zc_el in zc {
+        for zc_el in zc.iter().unwrap() {
             count += 1;
 
-            let value = zc_el.try_value().unwrap();
+            let value = zc_el.unwrap().try_value().unwrap();
             assert_eq!(value, 3);
         }
 
@@ -440,10 +464,10 @@ mod test {
         assert_eq!(new_offset, 9);
 
         let mut count = 0;
-        for zc_el in zc {
+        for zc_el in zc.iter().unwrap() {
             count += 1;
 
-            let value = zc_el.try_value().unwrap();
+            let value = zc_el.unwrap().try_value().unwrap();
             assert_eq!(value, 3);
         }
 
@@ -0,0 +1,322 @@
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::zc_types::{try_borrow_mut_zc_data, try_borrow_zc_data};
+use crate::models::Zc;
+use crate::traits::{CopyType, ZeroCopyType};
+
+/// Records several writes of zero-copy values of the same account and applies
+/// them at once.
+///
+/// Writing a value with a different size shifts all the trailing bytes of the
+/// account, so modifying `n` fields one by one moves the tail of the account `n`
+/// times. The planner moves every byte at most once and reallocates the account
+/// only once, which is much cheaper for big accounts.
+///
+/// The offsets of the writes are the ones of the account before applying any
+/// of them, so all [Zc]s must be obtained before calling [ZcWritePlanner::apply]
+/// and none of them is valid afterwards.
+pub struct ZcWritePlanner<'info> {
+    info: &'info AccountInfo<'info>,
+    writes: Vec<PlannedWrite>,
+}
+
+struct PlannedWrite {
+    offset: usize,
+    previous_size: usize,
+    bytes: Vec<u8>,
+}
+
+impl<'info> ZcWritePlanner<'info> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(info: &'info AccountInfo<'info>) -> ZcWritePlanner<'info> {
+        ZcWritePlanner {
+            info,
+            writes: Vec::new(),
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn info(&self) -> &'info AccountInfo<'info> {
+        self.info
+    }
+
+    /// The number of pending writes.
+    pub fn len(&self) -> usize {
+        self.writes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.writes.is_empty()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Plans writing `value` in the position of `zc`.
+    pub fn write_value<T: CopyType<'info> + BorshSerialize>(
+        &mut self,
+        zc: &Zc<'info, T>,
+        value: &T,
+    ) -> FankorResult<()> {
+        let mut bytes = Vec::with_capacity(value.byte_size());
+        value.serialize(&mut bytes)?;
+
+        self.write_bytes(zc, bytes)
+    }
+
+    /// Plans writing the serialized `bytes` of a value in the position of `zc`.
+    pub fn write_bytes<T: CopyType<'info>>(
+        &mut self,
+        zc: &Zc<'info, T>,
+        bytes: Vec<u8>,
+    ) -> FankorResult<()> {
+        if zc.info.key != self.info.key {
+            return Err(FankorErrorCode::ZeroCopyWritePlannerAccountMismatch {
+                address: *zc.info.key,
+            }
+            .into());
+        }
+
+        let data = try_borrow_zc_data(self.info, "ZcWritePlanner", "write_bytes")?;
+        let previous_size = T::ZeroCopyType::read_byte_size(&data[zc.offset..])?;
+
+        self.writes.push(PlannedWrite {
+            offset: zc.offset,
+            previous_size,
+            bytes,
+        });
+
+        Ok(())
+    }
+
+    /// Applies all the pending writes in a single pass. Fails without modifying
+    /// the account if any pair of writes overlaps.
+    pub fn apply(mut self) -> FankorResult<()> {
+        if self.writes.is_empty() {
+            return Ok(());
+        }
+
+        self.writes.sort_by_key(|v| v.offset);
+
+        for pair in self.writes.windows(2) {
+            if pair[0].offset + pair[0].previous_size > pair[1].offset {
+                return Err(FankorErrorCode::ZeroCopyOverlappingWrites {
+                    offset: pair[1].offset,
+                }
+                .into());
+            }
+        }
+
+        // The bytes between a write and the next one, and how much they must be shifted.
+        let original_len = self.info.data_len();
+        let mut shift = 0isize;
+        let segments = self
+            .writes
+            .iter()
+            .enumerate()
+            .map(|(i, write)| {
+                shift += write.bytes.len() as isize - write.previous_size as isize;
+
+                let start = write.offset + write.previous_size;
+                let end = self
+                    .writes
+                    .get(i + 1)
+                    .map_or(original_len, |next| next.offset);
+
+                (start, end, shift)
+            })
+            .collect::<Vec<_>>();
+        let new_len = (original_len as isize + shift) as usize;
+
+        // Reallocate the buffer
+        if new_len > original_len {
+            #[cfg(any(feature = "test-utils", test))]
+            if self.info.rent_epoch != crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+                self.info.realloc(new_len, false)?;
+            }
+
+            #[cfg(not(any(feature = "test-utils", test)))]
+            {
+                self.info.realloc(new_len, false)?;
+            }
+        }
+
+        {
+            let mut data = try_borrow_mut_zc_data(self.info, "ZcWritePlanner", "apply")?;
+            let buffer_len = data.len();
+
+            // Shift bytes backwards from front to back and forwards from back to front
+            // so no segment overwrites another one before it has been moved.
+            for &(start, end, shift) in segments.iter().filter(|(_, _, shift)| *shift < 0) {
+                data.copy_within(start..end, start - shift.unsigned_abs());
+            }
+
+            for &(start, end, shift) in segments.iter().rev().filter(|(_, _, shift)| *shift > 0) {
+                let shift = shift as usize;
+                let end = end.min(buffer_len.saturating_sub(shift));
+
+                if start < end {
+                    data.copy_within(start..end, start + shift);
+                }
+            }
+
+            // Serialize
+            let mut shift = 0isize;
+            for write in &self.writes {
+                let offset = (write.offset as isize + shift) as usize;
+                let bytes = data.get_mut(offset..offset + write.bytes.len()).ok_or(
+                    FankorErrorCode::ZeroCopyNotEnoughLength {
+                        type_name: "ZcWritePlanner",
+                    },
+                )?;
+                bytes.copy_from_slice(&write.bytes);
+
+                shift += write.bytes.len() as isize - write.previous_size as isize;
+            }
+        }
+
+        if new_len < original_len {
+            #[cfg(any(feature = "test-utils", test))]
+            if self.info.rent_epoch != crate::tests::ACCOUNT_INFO_TEST_MAGIC_NUMBER {
+                self.info.realloc(new_len, false)?;
+            }
+
+            #[cfg(not(any(feature = "test-utils", test)))]
+            {
+                self.info.realloc(new_len, false)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_apply_same_size() {
+        let mut lamports = 0;
+        let mut data = vec![1u8, 2, 3, 4, 5, 6];
+        let info = create_account_info_for_tests(&mut lamports, &mut data);
+
+        let mut planner = ZcWritePlanner::new(&info);
+        planner
+            .write_value(&Zc::<u8>::new_unchecked(&info, 4), &50)
+            .unwrap();
+        planner
+            .write_value(&Zc::<u16>::new_unchecked(&info, 1), &0x0A0B)
+            .unwrap();
+        assert_eq!(planner.len(), 2);
+
+        planner.apply().unwrap();
+        assert_eq!(
+            *info.try_borrow_data().unwrap(),
+            vec![1u8, 0x0B, 0x0A, 4, 50, 6]
+        );
+    }
+
+    #[test]
+    fn test_apply_growing_and_shrinking() {
+        let mut lamports = 0;
+
+        // [u8: 9] [Vec<u8>: 3 items] [u8: 8] [Vec<u8>: 1 item] [u8: 7] + padding
+        let mut data = vec![9u8, 3, 0, 0, 0, 1, 2, 3, 8, 1, 0, 0, 0, 4, 7, 0, 0, 0, 0];
+        let info = create_account_info_for_tests(&mut lamports, &mut data);
+
+        let mut planner = ZcWritePlanner::new(&info);
+        planner
+            .write_value(&Zc::<Vec<u8>>::new_unchecked(&info, 1), &vec![5])
+            .unwrap();
+        planner
+            .write_value(&Zc::<Vec<u8>>::new_unchecked(&info, 9), &vec![6, 6, 6])
+            .unwrap();
+        planner.apply().unwrap();
+
+        assert_eq!(
+            *info.try_borrow_data().unwrap(),
+            vec![9u8, 1, 0, 0, 0, 5, 8, 3, 0, 0, 0, 6, 6, 6, 7, 0, 0, 0, 0]
+        );
+
+        // Growing the first vector and shrinking the second one.
+        let mut lamports = 0;
+        let mut data = vec![9u8, 3, 0, 0, 0, 1, 2, 3, 8, 1, 0, 0, 0, 4, 7, 0, 0, 0, 0];
+        let info = create_account_info_for_tests(&mut lamports, &mut data);
+
+        let mut planner = ZcWritePlanner::new(&info);
+        planner
+            .write_value(&Zc::<Vec<u8>>::new_unchecked(&info, 1), &vec![5; 5])
+            .unwrap();
+        planner
+            .write_value(&Zc::<Vec<u8>>::new_unchecked(&info, 9), &vec![])
+            .unwrap();
+        planner.apply().unwrap();
+
+        assert_eq!(
+            *info.try_borrow_data().unwrap(),
+            vec![9u8, 5, 0, 0, 0, 5, 5, 5, 5, 5, 8, 0, 0, 0, 0, 7, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_apply_net_shrinking() {
+        let mut lamports = 0;
+
+        // [u8: 9] [Vec<u8>: 3 items] [u8: 7]
+        let mut data = vec![9u8, 3, 0, 0, 0, 1, 2, 3, 7];
+        let info = create_account_info_for_tests(&mut lamports, &mut data);
+
+        let mut planner = ZcWritePlanner::new(&info);
+        planner
+            .write_value(&Zc::<Vec<u8>>::new_unchecked(&info, 1), &vec![5])
+            .unwrap();
+        planner.apply().unwrap();
+
+        // Test accounts are not reallocated, so the last bytes are kept.
+        let data = info.try_borrow_data().unwrap();
+        assert_eq!(data.len(), 9);
+        assert_eq!(&data[..7], &[9u8, 1, 0, 0, 0, 5, 7]);
+    }
+
+    #[test]
+    fn test_apply_errors() {
+        let key = solana_program::pubkey::Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![3u8, 0, 0, 0, 1, 2, 3];
+        let mut other_lamports = 0;
+        let mut other_data = vec![0u8];
+        let info = create_account_info_for_tests(&mut lamports, &mut data);
+        let mut other_info = create_account_info_for_tests(&mut other_lamports, &mut other_data);
+        other_info.key = &key;
+
+        // Overlapping writes.
+        let mut planner = ZcWritePlanner::new(&info);
+        planner
+            .write_value(&Zc::<Vec<u8>>::new_unchecked(&info, 0), &vec![])
+            .unwrap();
+        planner
+            .write_value(&Zc::<u8>::new_unchecked(&info, 5), &0)
+            .unwrap();
+        assert!(planner.apply().is_err());
+        assert_eq!(
+            *info.try_borrow_data().unwrap(),
+            vec![3u8, 0, 0, 0, 1, 2, 3]
+        );
+
+        // Other account.
+        let mut planner = ZcWritePlanner::new(&info);
+        assert!(planner
+            .write_value(&Zc::<u8>::new_unchecked(&other_info, 0), &0)
+            .is_err());
+    }
+}
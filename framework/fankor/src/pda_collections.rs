@@ -0,0 +1,153 @@
+use std::ops::Range;
+
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+
+/// A family of PDAs derived from the same seeds followed by their index as a
+/// little-endian `u64`, e.g. the items of a list: `[b"item", list, index]`.
+///
+/// The number of items is usually kept in a `next_index: u64` field of a head
+/// account, advanced with [take_next_index] when an item is created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdaCollection {
+    program_id: Pubkey,
+    seeds: Vec<Vec<u8>>,
+}
+
+impl PdaCollection {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(program_id: Pubkey, seeds: &[&[u8]]) -> PdaCollection {
+        PdaCollection {
+            program_id,
+            seeds: seeds.iter().map(|v| v.to_vec()).collect(),
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn program_id(&self) -> &Pubkey {
+        &self.program_id
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// The seeds of the item at `index`, without the bump.
+    pub fn item_seeds(&self, index: u64) -> Vec<Vec<u8>> {
+        let mut seeds = self.seeds.clone();
+        seeds.push(index.to_le_bytes().to_vec());
+        seeds
+    }
+
+    /// Gets the address and bump of the item at `index`.
+    pub fn find_item_address(&self, index: u64) -> (Pubkey, u8) {
+        let index = index.to_le_bytes();
+        let mut seeds = self.seeds.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
+        seeds.push(&index);
+
+        Pubkey::find_program_address(&seeds, &self.program_id)
+    }
+
+    /// Checks that `info` is the item at `index` of a collection with
+    /// `next_index` items. Returns the bump of the item.
+    pub fn verify_item(
+        &self,
+        info: &AccountInfo,
+        index: u64,
+        next_index: u64,
+    ) -> FankorResult<u8> {
+        if index >= next_index {
+            return Err(FankorErrorCode::CollectionIndexOutOfBounds { index, next_index }.into());
+        }
+
+        let (address, bump) = self.find_item_address(index);
+        if info.key != &address {
+            return Err(FankorErrorCode::InvalidPda {
+                expected: address,
+                actual: *info.key,
+            }
+            .into());
+        }
+
+        Ok(bump)
+    }
+
+    /// Iterates over the items in `range` along with their index. Addresses
+    /// are derived lazily, so large ranges can be consumed partially.
+    pub fn iter(&self, range: Range<u64>) -> impl Iterator<Item = (u64, Pubkey)> + '_ {
+        range.map(move |index| (index, self.find_item_address(index).0))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Returns the index of the next item of a collection advancing `next_index`,
+/// the counter stored in its head account.
+pub fn take_next_index(next_index: &mut u64) -> FankorResult<u64> {
+    let index = *next_index;
+    *next_index = index
+        .checked_add(1)
+        .ok_or(FankorErrorCode::CollectionIndexOutOfBounds {
+            index,
+            next_index: index,
+        })?;
+
+    Ok(index)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_collection() {
+        let list = Pubkey::new_unique();
+        let collection = PdaCollection::new(Pubkey::new_unique(), &[b"item", list.as_ref()]);
+
+        let (address, bump) = collection.find_item_address(3);
+        let seeds = collection.item_seeds(3);
+        assert_eq!(seeds[2], 3u64.to_le_bytes().to_vec());
+        assert_eq!(
+            Pubkey::find_program_address(
+                &[b"item", list.as_ref(), &3u64.to_le_bytes()],
+                collection.program_id()
+            ),
+            (address, bump)
+        );
+
+        let items = collection.iter(2..5).collect::<Vec<_>>();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[1], (3, address));
+
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(vec![0u8; 0].into_boxed_slice());
+        let mut info = create_account_info_for_tests(lamports, data);
+        info.key = Box::leak(Box::new(address));
+
+        assert_eq!(collection.verify_item(&info, 3, 4).unwrap(), bump);
+        assert!(collection.verify_item(&info, 3, 3).is_err());
+        assert!(collection.verify_item(&info, 2, 4).is_err());
+    }
+
+    #[test]
+    fn test_take_next_index() {
+        let mut next_index = 0;
+        assert_eq!(take_next_index(&mut next_index).unwrap(), 0);
+        assert_eq!(take_next_index(&mut next_index).unwrap(), 1);
+        assert_eq!(next_index, 2);
+
+        let mut next_index = u64::MAX;
+        assert!(take_next_index(&mut next_index).is_err());
+        assert_eq!(next_index, u64::MAX);
+    }
+}
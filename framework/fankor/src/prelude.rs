@@ -2,10 +2,14 @@ pub use borsh;
 pub use borsh::BorshDeserialize;
 pub use borsh::BorshSerialize;
 pub use bs58;
+#[cfg(feature = "pod")]
+pub use bytemuck;
 #[cfg(feature = "ts-gen")]
 pub use lazy_static::lazy_static;
 #[cfg(feature = "metadata-program")]
 pub use mpl_token_metadata;
+#[cfg(feature = "client")]
+pub use solana_client;
 pub use solana_program;
 pub use solana_program::account_info::{AccountInfo, next_account_info};
 pub use solana_program::instruction::AccountMeta;
@@ -26,8 +30,8 @@ pub use solana_program_runtime;
 pub use solana_program_test;
 #[cfg(feature = "test-utils")]
 pub use solana_sdk;
-#[cfg(not(feature = "no-entrypoint"))]
-pub use solana_security_txt::security_txt;
+#[doc(hidden)]
+pub use solana_security_txt;
 #[cfg(feature = "token-program")]
 pub use spl_associated_token_account;
 #[cfg(feature = "token-program")]
@@ -38,11 +42,19 @@ pub use static_assertions::const_assert;
 
 pub use fankor_macros::*;
 
+pub use crate::account_registry::*;
+pub use crate::fees::*;
+#[cfg(feature = "client")]
+pub use crate::client::*;
 pub use crate::cpi;
 pub use crate::errors::*;
+pub use crate::idempotency::*;
+pub use crate::growable_program::*;
+pub use crate::logs::*;
 pub use crate::macros::*;
 pub use crate::models::*;
 pub use crate::models::types::*;
+pub use crate::pda_collections::*;
 #[cfg(feature = "testable-program")]
 pub use crate::testable_program::*;
 #[cfg(feature = "test-utils")]
@@ -50,10 +62,14 @@ pub use crate::tests::*;
 pub use crate::traits::*;
 #[cfg(feature = "ts-gen")]
 pub use crate::ts_gen;
+pub use crate::utils::lamports::transfer_lamports;
 pub use crate::utils::seeds::byte_seeds_to_slices;
+pub use crate::utils::signers::verify_threshold_signers;
+pub use crate::utils::upgradeable::{verify_program_data, verify_upgrade_authority};
 pub use crate::utils::type_id_of;
 pub use crate::utils::writers::ArrayWriter;
 pub use crate::utils::writers::VecWriter;
+pub use crate::versioned_program::*;
 
 pub mod sysvar {
     pub use solana_program::sysvar::*;
@@ -78,8 +78,9 @@ impl<'info> TestInstruction<'info> {
 
                 // Write bytes.
                 let mut data_bytes = self.account.info().try_borrow_mut_data().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
+                    FankorErrorCode::AlreadyBorrowed {
                         type_name: std::any::type_name::<Self>(),
+                        operation: "init",
                     }
                 })?;
                 let data_bytes = &mut data_bytes[..];
@@ -107,8 +108,9 @@ impl<'info> TestInstruction<'info> {
 
                 // Write bytes.
                 let mut data_bytes = self.account.info().try_borrow_mut_data().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
+                    FankorErrorCode::AlreadyBorrowed {
                         type_name: std::any::type_name::<Self>(),
+                        operation: "init_pda",
                     }
                 })?;
                 let data_bytes = &mut data_bytes[..];
@@ -127,8 +129,9 @@ impl<'info> TestInstruction<'info> {
 
                 // Append bytes.
                 let mut data_bytes = self.account.info().try_borrow_mut_data().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
+                    FankorErrorCode::AlreadyBorrowed {
                         type_name: std::any::type_name::<Self>(),
+                        operation: "replace",
                     }
                 })?;
                 let data_bytes = &mut data_bytes[..];
@@ -148,8 +151,9 @@ impl<'info> TestInstruction<'info> {
 
                 // Append bytes.
                 let mut data_bytes = self.account.info().try_borrow_mut_data().map_err(|_| {
-                    FankorErrorCode::ZeroCopyPossibleDeadlock {
+                    FankorErrorCode::AlreadyBorrowed {
                         type_name: std::any::type_name::<Self>(),
+                        operation: "append",
                     }
                 })?;
                 let data_bytes = &mut data_bytes[length..];
@@ -0,0 +1,85 @@
+use std::fmt::Debug;
+
+/// Renders the differences between two snapshots of the same value.
+pub trait FankorDebug: Debug {
+    /// Returns the lines of the pretty-printed values that differ, prefixed
+    /// by `-` for `self` and by `+` for `other`. Each field is printed in its
+    /// own line, so the result is a field-level diff.
+    fn debug_diff(&self, other: &Self) -> String {
+        diff_lines(&format!("{:#?}", self), &format!("{:#?}", other))
+    }
+}
+
+impl<T: Debug> FankorDebug for T {}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn diff_lines(old: &str, new: &str) -> String {
+    let old = old.lines().collect::<Vec<_>>();
+    let new = new.lines().collect::<Vec<_>>();
+
+    // Longest common subsequence table.
+    let mut table = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            table[i][j] = if old[i] == new[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if j == new.len() || (i < old.len() && table[i + 1][j] >= table[i][j + 1]) {
+            result.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            result.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+
+    result.join("\n")
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Data {
+        a: u8,
+        b: &'static str,
+        c: bool,
+    }
+
+    #[test]
+    fn test_debug_diff() {
+        let old = Data {
+            a: 1,
+            b: "x",
+            c: true,
+        };
+        let new = Data {
+            a: 2,
+            b: "x",
+            c: true,
+        };
+
+        assert_eq!(old.debug_diff(&new), "-     a: 1,\n+     a: 2,");
+        assert_eq!(old.debug_diff(&old), "");
+    }
+}
@@ -1,9 +1,13 @@
 pub use account_info::*;
 pub use banks_client::*;
+pub use debug::*;
 pub use program_test::*;
 pub use program_test_context::*;
+pub use snapshot::*;
 
 mod account_info;
 mod banks_client;
+mod debug;
 mod program_test;
 mod program_test_context;
+mod snapshot;
@@ -1,13 +1,31 @@
 //! Code based on https://github.com/halbornteam/solana-test-framework
 
 use async_trait::async_trait;
-use solana_program_test::{ProgramTestContext, ProgramTestError};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{BanksClientError, ProgramTestContext, ProgramTestError};
 use solana_sdk::sysvar::clock::Clock;
 
+use crate::tests::AccountsSnapshot;
+
 #[async_trait]
 pub trait ProgramTestContextExtension {
     /// Calculate slot number from the provided timestamp
     async fn warp_to_timestamp(&mut self, timestamp: i64) -> Result<(), ProgramTestError>;
+
+    /// Takes a snapshot of the current state of the accounts at `addresses`,
+    /// including those that do not exist yet, so the changes made to them
+    /// can be rolled back with [restore_snapshot](Self::restore_snapshot).
+    ///
+    /// The banks client cannot list the accounts of a program, so all the
+    /// accounts a scenario may create or modify must be included.
+    async fn snapshot_accounts(
+        &mut self,
+        addresses: &[Pubkey],
+    ) -> Result<AccountsSnapshot, BanksClientError>;
+
+    /// Restores the accounts of a snapshot to the state they had when it was
+    /// taken, removing those that did not exist.
+    fn restore_snapshot(&mut self, snapshot: &AccountsSnapshot);
 }
 
 // ----------------------------------------------------------------------------
@@ -39,4 +57,24 @@ impl ProgramTestContextExtension for ProgramTestContext {
 
         Ok(())
     }
+
+    async fn snapshot_accounts(
+        &mut self,
+        addresses: &[Pubkey],
+    ) -> Result<AccountsSnapshot, BanksClientError> {
+        let mut snapshot = AccountsSnapshot::new();
+
+        for address in addresses {
+            let account = self.banks_client.get_account(*address).await?;
+            snapshot.insert(*address, account);
+        }
+
+        Ok(snapshot)
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &AccountsSnapshot) {
+        for (address, account) in snapshot.restore_data() {
+            self.set_account(address, &account);
+        }
+    }
 }
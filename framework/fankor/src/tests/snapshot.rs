@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::{Account, AccountSharedData};
+
+/// The state of a set of accounts of a test environment taken with
+/// [ProgramTestContextExtension::snapshot_accounts](crate::tests::ProgramTestContextExtension::snapshot_accounts)
+/// that can be restored later to roll back the changes of a scenario.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AccountsSnapshot {
+    /// The accounts by address. `None` means the account did not exist when
+    /// the snapshot was taken, so it is removed when restoring.
+    accounts: BTreeMap<Pubkey, Option<Account>>,
+}
+
+impl AccountsSnapshot {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new() -> AccountsSnapshot {
+        Self::default()
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The addresses included in the snapshot.
+    pub fn addresses(&self) -> impl Iterator<Item = &Pubkey> {
+        self.accounts.keys()
+    }
+
+    /// Gets the state of an account in the snapshot. Returns `None` if the
+    /// account is not included in the snapshot or did not exist.
+    pub fn get(&self, address: &Pubkey) -> Option<&Account> {
+        self.accounts.get(address).and_then(|v| v.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Sets the state of an account in the snapshot.
+    pub fn insert(&mut self, address: Pubkey, account: Option<Account>) {
+        self.accounts.insert(address, account);
+    }
+
+    /// Removes an account from the snapshot so it is not restored.
+    pub fn remove(&mut self, address: &Pubkey) {
+        self.accounts.remove(address);
+    }
+
+    /// Gets the data to write into the test environment for every account to
+    /// restore the snapshot. Missing accounts are written without lamports nor
+    /// data so the runtime removes them.
+    pub(crate) fn restore_data(&self) -> impl Iterator<Item = (&Pubkey, AccountSharedData)> {
+        self.accounts.iter().map(|(address, account)| {
+            let account = match account {
+                Some(account) => AccountSharedData::from(account.clone()),
+                None => AccountSharedData::default(),
+            };
+
+            (address, account)
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_restore_data() {
+        let address1 = Pubkey::new_unique();
+        let address2 = Pubkey::new_unique();
+        let account = Account::new(10, 2, &Pubkey::new_unique());
+
+        let mut snapshot = AccountsSnapshot::new();
+        snapshot.insert(address1, Some(account.clone()));
+        snapshot.insert(address2, None);
+
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot.get(&address1), Some(&account));
+        assert_eq!(snapshot.get(&address2), None);
+
+        let data = snapshot.restore_data().collect::<BTreeMap<_, _>>();
+        assert_eq!(data[&address1], AccountSharedData::from(account));
+        assert_eq!(data[&address2], AccountSharedData::default());
+    }
+}
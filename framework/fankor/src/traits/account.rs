@@ -1,5 +1,13 @@
+use std::any::type_name;
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 
+use crate::errors::{FankorErrorCode, FankorResult};
+
 pub trait AccountType: borsh::BorshSerialize + borsh::BorshDeserialize {
     /// The discriminant of the account.
     fn discriminant() -> u8;
@@ -7,10 +15,80 @@ pub trait AccountType: borsh::BorshSerialize + borsh::BorshDeserialize {
     /// Defines an address expected to own an account.
     fn owner() -> &'static Pubkey;
 
+    /// The previous discriminants of the account that are still accepted
+    /// when reading it, e.g. during the migration window of a change of the
+    /// discriminants. Writing the account always uses [discriminant](Self::discriminant).
+    fn legacy_discriminants() -> &'static [u8] {
+        &[]
+    }
+
+    /// A hash of the serialized layout of the account, i.e. the names and
+    /// types of its fields, stored in the account registry of the program.
+    /// Zero if unknown.
+    fn layout_hash() -> u64 {
+        0
+    }
+
     /// Checks whether the discriminant matches this account type.
     /// This is mainly used when there's more than one discriminant
     /// for this account.
     fn check_discriminant(discriminant: u8) -> bool {
-        discriminant == Self::discriminant()
+        discriminant == Self::discriminant() || Self::legacy_discriminants().contains(&discriminant)
     }
 }
+
+macro_rules! impl_account_type_wrapper {
+    ($($wrapper:ty),* $(,)?) => {
+        $(impl<'a, T: AccountType + Clone> AccountType for $wrapper {
+            fn discriminant() -> u8 {
+                T::discriminant()
+            }
+
+            fn owner() -> &'static Pubkey {
+                T::owner()
+            }
+
+            fn legacy_discriminants() -> &'static [u8] {
+                T::legacy_discriminants()
+            }
+
+            fn layout_hash() -> u64 {
+                T::layout_hash()
+            }
+
+            fn check_discriminant(discriminant: u8) -> bool {
+                T::check_discriminant(discriminant)
+            }
+        })*
+    };
+}
+
+impl_account_type_wrapper!(Box<T>, Rc<T>, Arc<T>, Cow<'a, T>);
+
+/// Deserializes the field at `offset` of an account of type `T` without
+/// deserializing the rest of the account, failing if `info` does not hold an
+/// account of type `T`.
+pub fn read_account_field<T: AccountType, F: borsh::BorshDeserialize>(
+    info: &AccountInfo,
+    offset: usize,
+) -> FankorResult<F> {
+    if info.owner != T::owner() {
+        return Err(FankorErrorCode::AccountOwnedByWrongProgram {
+            address: *info.key,
+            expected: *T::owner(),
+            actual: *info.owner,
+        }
+        .into());
+    }
+
+    let data = info.try_borrow_data()?;
+    if !data.first().map_or(false, |v| T::check_discriminant(*v)) {
+        return Err(FankorErrorCode::AccountDiscriminantMismatch {
+            account: type_name::<T>().to_string(),
+        }
+        .into());
+    }
+
+    let mut buf = data.get(offset..).unwrap_or_default();
+    Ok(F::deserialize(&mut buf)?)
+}
@@ -0,0 +1,142 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::FankorResult;
+use crate::models::types::{
+    Bps, FnkAccountRef, FnkArray, FnkExtension, FnkInt, FnkMap, FnkRange, FnkSet, FnkString,
+    FnkUInt, FnkURange, FnkVec, Percent,
+};
+
+/// Types whose serialization is deterministic, i.e. the same value always
+/// produces the same bytes, so they can be reproduced off-chain for hashing
+/// or signing. Maps and sets are serialized in sorted key order, while floats
+/// and types whose layout depends on the insertion order are not allowed.
+///
+/// Use `#[account(canonical)]` to implement it for an account.
+pub trait CanonicalSerialize: BorshSerialize {
+    /// Serializes the value into its canonical bytes.
+    fn try_to_canonical_vec(&self) -> FankorResult<Vec<u8>> {
+        Ok(self.try_to_vec()?)
+    }
+}
+
+macro_rules! impl_canonical {
+    ($($ty:ty),* $(,)?) => {
+        $(impl CanonicalSerialize for $ty {})*
+    };
+}
+
+impl_canonical!(
+    (),
+    bool,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    String,
+    Pubkey,
+    FnkUInt,
+    FnkInt,
+    FnkURange,
+    FnkRange,
+    FnkExtension,
+    Bps,
+    Percent,
+);
+
+impl<'a> CanonicalSerialize for FnkString<'a> {}
+
+impl<T> CanonicalSerialize for FnkAccountRef<T> {}
+
+impl<T: CanonicalSerialize> CanonicalSerialize for Box<T> {}
+
+impl<T: CanonicalSerialize> CanonicalSerialize for Rc<T> {}
+
+impl<T: CanonicalSerialize> CanonicalSerialize for Arc<T> {}
+
+impl<'a, T: CanonicalSerialize + Clone> CanonicalSerialize for Cow<'a, T> {}
+
+impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {}
+
+impl<T: CanonicalSerialize> CanonicalSerialize for Vec<T> {}
+
+impl<T: CanonicalSerialize> CanonicalSerialize for FnkVec<T> {}
+
+
+impl<T: CanonicalSerialize, const N: usize> CanonicalSerialize for FnkArray<T, N> {}
+
+impl<K: CanonicalSerialize, V: CanonicalSerialize> CanonicalSerialize for BTreeMap<K, V> {}
+
+impl<K: CanonicalSerialize, V: CanonicalSerialize> CanonicalSerialize for FnkMap<K, V> {}
+
+impl<T: CanonicalSerialize> CanonicalSerialize for BTreeSet<T> {}
+
+impl<T: CanonicalSerialize> CanonicalSerialize for FnkSet<T> {}
+
+// Borsh only implements arrays of these sizes.
+macro_rules! impl_canonical_arrays {
+    ($($len:expr),*) => {
+        $(impl<T: CanonicalSerialize> CanonicalSerialize for [T; $len] {})*
+    };
+}
+
+impl_canonical_arrays!(
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31, 32, 64, 65, 128, 256, 512, 1024, 2048
+);
+
+macro_rules! impl_canonical_tuple {
+    ($($types:ident),*) => {
+        impl<$($types: CanonicalSerialize),*> CanonicalSerialize for ($($types),*) {}
+    };
+}
+
+impl_canonical_tuple!(T0, T1);
+impl_canonical_tuple!(T0, T1, T2);
+impl_canonical_tuple!(T0, T1, T2, T3);
+impl_canonical_tuple!(T0, T1, T2, T3, T4);
+impl_canonical_tuple!(T0, T1, T2, T3, T4, T5);
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_maps_are_sorted() {
+        let mut map1 = FnkMap::default();
+        map1.insert(3u8, "c".to_string());
+        map1.insert(1u8, "a".to_string());
+        map1.insert(2u8, "b".to_string());
+
+        let map2: FnkMap<u8, String> = [(2, "b"), (1, "a"), (3, "c")]
+            .into_iter()
+            .map(|(k, v)| (k, v.to_string()))
+            .collect();
+
+        let bytes = map1.try_to_canonical_vec().unwrap();
+        assert_eq!(bytes, map2.try_to_canonical_vec().unwrap());
+        assert_eq!(&bytes[..3], &[3, 1, 1]);
+
+        let set1 = FnkSet::new(BTreeSet::from([5u16, 1, 3]));
+        let set2 = FnkSet::new(BTreeSet::from([3u16, 5, 1]));
+        assert_eq!(
+            set1.try_to_canonical_vec().unwrap(),
+            set2.try_to_canonical_vec().unwrap()
+        );
+    }
+}
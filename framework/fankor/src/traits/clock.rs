@@ -0,0 +1,62 @@
+use solana_program::clock::Clock;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+
+/// Helpers over the [Clock] sysvar. Use [FankorContext::clock](crate::models::FankorContext::clock)
+/// to get a clock that can be mocked in tests.
+pub trait ClockExt {
+    /// The current unix timestamp.
+    fn unix_now(&self) -> i64;
+
+    /// The number of slots elapsed since `slot`, zero if it is in the future.
+    fn slots_since(&self, slot: u64) -> u64;
+
+    /// Fails if the current unix timestamp is not after `timestamp`.
+    fn assert_after(&self, timestamp: i64) -> FankorResult<()>;
+}
+
+impl ClockExt for Clock {
+    fn unix_now(&self) -> i64 {
+        self.unix_timestamp
+    }
+
+    fn slots_since(&self, slot: u64) -> u64 {
+        self.slot.saturating_sub(slot)
+    }
+
+    fn assert_after(&self, timestamp: i64) -> FankorResult<()> {
+        if self.unix_timestamp <= timestamp {
+            return Err(FankorErrorCode::TimestampNotReached {
+                timestamp,
+                now: self.unix_timestamp,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clock_ext() {
+        let clock = Clock {
+            slot: 100,
+            unix_timestamp: 1_000,
+            ..Default::default()
+        };
+
+        assert_eq!(clock.unix_now(), 1_000);
+        assert_eq!(clock.slots_since(40), 60);
+        assert_eq!(clock.slots_since(200), 0);
+        assert!(clock.assert_after(999).is_ok());
+        assert!(clock.assert_after(1_000).is_err());
+    }
+}
@@ -0,0 +1,26 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::FankorResult;
+use crate::models::FankorContext;
+
+/// An instruction handler whose accounts type is erased, so that handlers of
+/// different instructions can be stored together in an
+/// [InstructionRegistry](crate::models::InstructionRegistry) and selected at
+/// runtime. Use [erased_instruction](crate::erased_instruction) to build one
+/// from an instruction struct.
+pub trait ErasedInstruction {
+    /// The name of the instruction.
+    fn name(&self) -> &'static str;
+
+    /// The discriminant of the instruction, i.e. the first byte of its data.
+    fn discriminant(&self) -> u8;
+
+    /// Deserializes the accounts and arguments of the instruction from
+    /// `ix_data` and `ix_accounts` and executes it.
+    fn process<'info>(
+        &self,
+        context: &'info FankorContext<'info>,
+        ix_data: &mut &[u8],
+        ix_accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<()>;
+}
@@ -0,0 +1,25 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::FankorResult;
+use crate::models::FankorContext;
+use crate::traits::ProgramType;
+
+/// Trait for groups of instructions defined with `#[instruction_group]` that are
+/// included into a program as a single variant, i.e. `Admin(AdminInstruction)`.
+///
+/// The data of the grouped instructions starts with the discriminant of the group
+/// followed by the discriminant of the instruction inside the group.
+pub trait InstructionGroup {
+    /// Dispatches the instruction selected by the first byte of `ix_data`.
+    fn dispatch<'info>(
+        context: &'info FankorContext<'info>,
+        ix_data: &mut &[u8],
+        ix_accounts: &mut &'info [AccountInfo<'info>],
+    ) -> FankorResult<()>;
+}
+
+/// Links an instruction group to the program `P` that includes it.
+pub trait InstructionGroupOf<P: ProgramType>: InstructionGroup {
+    /// The discriminant of the group inside the program.
+    const DISCRIMINANT: u8;
+}
@@ -0,0 +1,14 @@
+use crate::errors::FankorResult;
+use crate::traits::AccountType;
+
+/// An account type that replaces a previous version of it. Implement it with
+/// `#[derive(Migratable)]` to also generate the migration instruction.
+pub trait Migratable: AccountType {
+    /// The previous version of the account.
+    type Old: AccountType;
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Converts the previous version of the account into the new one.
+    fn migrate(old: &Self::Old) -> FankorResult<Self>;
+}
@@ -1,15 +1,35 @@
 pub use account::*;
+pub use canonical::*;
+pub use clock::*;
+#[cfg(feature = "dynamic-dispatch")]
+pub use erased_instruction::*;
 pub use instruction::*;
+pub use instruction_group::*;
+pub use migratable::*;
 pub use pda_checker::*;
 pub use pda_generator::*;
+#[cfg(feature = "pod")]
+pub use pod_account::*;
 pub use program::*;
+pub use singleton_account::*;
 pub use ts_gen::*;
+pub use validate::*;
 pub use zero_copy::*;
 
 mod account;
+mod canonical;
+mod clock;
+#[cfg(feature = "dynamic-dispatch")]
+mod erased_instruction;
 mod instruction;
+mod instruction_group;
+mod migratable;
 mod pda_checker;
 mod pda_generator;
+#[cfg(feature = "pod")]
+mod pod_account;
 mod program;
+mod singleton_account;
 mod ts_gen;
+mod validate;
 mod zero_copy;
@@ -0,0 +1,132 @@
+use std::any::type_name;
+use std::io::{ErrorKind, Write};
+use std::mem::size_of;
+
+use crate::traits::AccountType;
+
+/// The offset of the value of a pod account: the discriminant plus the padding
+/// that keeps the value aligned to 8 bytes.
+pub const POD_ACCOUNT_DATA_OFFSET: usize = 8;
+
+/// An account whose data is a plain-old-data value, i.e. `#[repr(C)]` without
+/// padding, stored after the discriminant and its padding. The value is cast
+/// from the bytes of the account instead of being deserialized, so it can be
+/// read and written in place with a [PodAccount](crate::models::PodAccount).
+///
+/// Use `#[account(base = <accounts>, pod)]` to implement it for an account.
+pub trait PodAccountType: AccountType + bytemuck::Pod {
+    /// The size of the account: the discriminant, its padding and the value.
+    const ACCOUNT_SIZE: usize = POD_ACCOUNT_DATA_OFFSET + size_of::<Self>();
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Writes a pod account as it is stored: the discriminant, the padding and the
+/// bytes of the value.
+pub fn serialize_pod_account<T: PodAccountType, W: Write>(
+    value: &T,
+    writer: &mut W,
+) -> std::io::Result<()> {
+    let mut header = [0u8; POD_ACCOUNT_DATA_OFFSET];
+    header[0] = T::discriminant();
+
+    writer.write_all(&header)?;
+    writer.write_all(bytemuck::bytes_of(value))
+}
+
+/// Reads a pod account written by [serialize_pod_account].
+pub fn deserialize_pod_account<T: PodAccountType>(buf: &mut &[u8]) -> std::io::Result<T> {
+    if buf.len() < T::ACCOUNT_SIZE {
+        return Err(std::io::Error::new(
+            ErrorKind::UnexpectedEof,
+            format!("Not enough bytes to read the pod account {}", type_name::<T>()),
+        ));
+    }
+
+    if !T::check_discriminant(buf[0]) {
+        return Err(std::io::Error::new(
+            ErrorKind::Other,
+            format!("Invalid discriminant for the pod account {}", type_name::<T>()),
+        ));
+    }
+
+    let value = bytemuck::pod_read_unaligned(&buf[POD_ACCOUNT_DATA_OFFSET..T::ACCOUNT_SIZE]);
+    *buf = &buf[T::ACCOUNT_SIZE..];
+
+    Ok(value)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    const OWNER: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[derive(Debug, Copy, Clone, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+    #[repr(C)]
+    struct Counter {
+        authority: Pubkey,
+        count: u64,
+        limit: u32,
+        bump: [u8; 4],
+    }
+
+    impl AccountType for Counter {
+        fn discriminant() -> u8 {
+            5
+        }
+
+        fn owner() -> &'static Pubkey {
+            &OWNER
+        }
+    }
+
+    impl PodAccountType for Counter {}
+
+    impl BorshSerialize for Counter {
+        fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+            serialize_pod_account(self, writer)
+        }
+    }
+
+    impl BorshDeserialize for Counter {
+        fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+            deserialize_pod_account(buf)
+        }
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let counter = Counter {
+            authority: Pubkey::new_unique(),
+            count: 3,
+            limit: 10,
+            bump: [255, 0, 0, 0],
+        };
+
+        let bytes = counter.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), Counter::ACCOUNT_SIZE);
+        assert_eq!(bytes.len(), 8 + 32 + 8 + 4 + 4);
+        assert_eq!(&bytes[..POD_ACCOUNT_DATA_OFFSET], &[5, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(&bytes[8..40], counter.authority.as_ref());
+        assert_eq!(&bytes[40..48], &3u64.to_le_bytes());
+
+        let mut buf = bytes.as_slice();
+        assert_eq!(Counter::deserialize(&mut buf).unwrap(), counter);
+        assert!(buf.is_empty());
+
+        let mut bytes = bytes;
+        bytes[0] = 4;
+        assert!(Counter::deserialize(&mut bytes.as_slice()).is_err());
+        assert!(Counter::deserialize(&mut &bytes[..20]).is_err());
+    }
+}
@@ -0,0 +1,18 @@
+use solana_program::pubkey::Pubkey;
+
+use crate::traits::AccountType;
+
+/// An account of which there is only one per program, e.g. the global config,
+/// stored in the PDA derived from its type name.
+///
+/// Use `#[account(base = <accounts>, singleton)]` to implement it for an account
+/// and load it with a [Singleton](crate::models::Singleton).
+pub trait SingletonAccountType: AccountType {
+    /// The only seed of the PDA of the account.
+    const SINGLETON_SEED: &'static [u8];
+
+    /// The address and bump of the PDA of the account.
+    fn singleton_address() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SINGLETON_SEED], Self::owner())
+    }
+}
@@ -1,5 +1,9 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::models::AccountLayout;
 
 pub type TsTypesCache = HashMap<Cow<'static, str>, Cow<'static, str>>;
 
@@ -49,6 +53,14 @@ pub trait TsTypeGen {
     fn generate_schema_use_method(registered_use_methods: &mut TsTypesCache) {
         unreachable!("generate_schema_use_method")
     }
+
+    /// Generates the codec of the type for the standalone TypeScript file and
+    /// returns the expression to access it. By default the codec fails at
+    /// runtime because the type is not supported.
+    #[allow(unused_variables)]
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Owned(format!("fnkUnsupported({:?})", Self::value_type()))
+    }
 }
 
 impl<T: TsTypeGen> TsTypeGen for Box<T> {
@@ -75,6 +87,77 @@ impl<T: TsTypeGen> TsTypeGen for Box<T> {
     ) -> Cow<'static, str> {
         T::generate_schema(registered_schemas)
     }
+
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        T::generate_standalone_codec(registered_codecs)
+    }
+}
+
+macro_rules! impl_ts_type_gen_pointer {
+    ($($pointer:ident),* $(,)?) => {
+        $(impl<T: TsTypeGen> TsTypeGen for $pointer<T> {
+            fn value(&self) -> Cow<'static, str> {
+                T::value(self)
+            }
+
+            fn unit_value() -> Option<Cow<'static, str>> {
+                T::unit_value()
+            }
+
+            fn value_type() -> Cow<'static, str> {
+                T::value_type()
+            }
+
+            fn schema_name() -> Cow<'static, str> {
+                T::schema_name()
+            }
+
+            fn generate_type(registered_types: &mut TsTypesCache) -> Cow<'static, str> {
+                T::generate_type(registered_types)
+            }
+
+            fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
+                T::generate_schema(registered_schemas)
+            }
+
+            fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+                T::generate_standalone_codec(registered_codecs)
+            }
+        })*
+    };
+}
+
+impl_ts_type_gen_pointer!(Rc, Arc);
+
+/// `Cow<str>` has its own implementation, so only sized types are covered.
+impl<'a, T: TsTypeGen + Clone> TsTypeGen for Cow<'a, T> {
+    fn value(&self) -> Cow<'static, str> {
+        T::value(self)
+    }
+
+    fn unit_value() -> Option<Cow<'static, str>> {
+        T::unit_value()
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        T::value_type()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn generate_type(registered_types: &mut TsTypesCache) -> Cow<'static, str> {
+        T::generate_type(registered_types)
+    }
+
+    fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
+        T::generate_schema(registered_schemas)
+    }
+
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        T::generate_standalone_codec(registered_codecs)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -118,4 +201,23 @@ pub trait TsInstructionGen {
     ) -> Cow<'static, str> {
         Self::get_account_metas(value, signer, writable)
     }
+
+    /// Generates the code to decode the value of the type from the `reader`
+    /// of the instruction data and the `accountMetas` reader of its accounts,
+    /// assigning it to `value`. It is the inverse of `get_account_metas`.
+    fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+        Cow::Owned(format!("{} = accountMetas.next();", value))
+    }
+
+    /// Generates the code to decode the value of the type inside another
+    /// account's decodeMetasOf method.
+    fn decode_external_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+        Self::decode_account_metas(value)
+    }
+
+    /// Gets the static layout of the accounts of the type, used to document
+    /// the instruction. Empty if the type is not an instruction struct.
+    fn account_layout() -> &'static [AccountLayout] {
+        &[]
+    }
 }
@@ -0,0 +1,41 @@
+use crate::errors::FankorResult;
+use crate::models::FankorContext;
+
+/// Custom validation of an instruction struct or enum. If implemented, it is
+/// called automatically after all the account checks of the instruction.
+///
+/// It is not named `validate` to not clash with the method of the same name
+/// generated by `#[instruction]`.
+pub trait Validate<'info> {
+    fn validate_accounts(&self, context: &'info FankorContext<'info>) -> FankorResult<()>;
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+// Helpers to call `Validate::validate_accounts` only when it is implemented. They rely on
+// the method resolution preferring the by-value receiver over the autoref one.
+
+#[doc(hidden)]
+pub struct ValidateWrapper<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub trait ValidateImplemented<'info> {
+    fn __fankor_validate(&self, context: &'info FankorContext<'info>) -> FankorResult<()>;
+}
+
+impl<'a, 'info, T: Validate<'info>> ValidateImplemented<'info> for ValidateWrapper<'a, T> {
+    fn __fankor_validate(&self, context: &'info FankorContext<'info>) -> FankorResult<()> {
+        Validate::validate_accounts(self.0, context)
+    }
+}
+
+#[doc(hidden)]
+pub trait ValidateNotImplemented<'info> {
+    fn __fankor_validate(&self, _context: &'info FankorContext<'info>) -> FankorResult<()> {
+        Ok(())
+    }
+}
+
+impl<'a, 'info, T> ValidateNotImplemented<'info> for &ValidateWrapper<'a, T> {}
@@ -31,4 +31,11 @@ pub trait CopyType<'info>: Sized {
 
     /// Returns the minimum byte size of the type in bytes.
     fn min_byte_size() -> usize;
+
+    /// Returns the minimum byte size required for an account to hold any
+    /// value of the type whose dynamic parts are empty, i.e. the maximum over
+    /// the minimum byte size of every enum variant.
+    fn min_account_size() -> usize {
+        Self::min_byte_size()
+    }
 }
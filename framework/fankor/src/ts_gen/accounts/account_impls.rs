@@ -1,14 +1,21 @@
+use std::any::{Any, TypeId};
 use std::borrow::Cow;
 
 use solana_program::pubkey::Pubkey;
 use solana_program::sysvar::SysvarId;
 
 use crate::models::{
-    Account, Argument, Either, MaybeUninitialized, Program, Rest, RestArguments, SingleEither,
+    Account, Argument, Batch, DeferredClose, Either, GrowableAccount, MaybeUninitialized,
+    PreFundedUninitializedAccount, Program, Rest, RestArguments, SingleEither, Singleton,
     SysvarAccount, UncheckedAccount, UninitializedAccount, ZcAccount,
 };
 use crate::prelude::ProgramType;
-use crate::traits::{AccountType, CopyType, TsInstructionGen, TsTypeGen, TsTypesCache};
+use crate::traits::{
+    AccountType, CopyType, SingletonAccountType, TsInstructionGen, TsTypeGen, TsTypesCache,
+};
+
+/// The TypeScript type of the accounts that are a single public key.
+const PUBLIC_KEY_TYPE: &str = "solana.PublicKey";
 
 impl<'info, T: AccountType> TsInstructionGen for Account<'info, T> {
     fn value_type() -> Cow<'static, str> {
@@ -16,6 +23,12 @@ impl<'info, T: AccountType> TsInstructionGen for Account<'info, T> {
     }
 }
 
+impl<'info, T: SingletonAccountType> TsInstructionGen for Singleton<'info, T> {
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("solana.PublicKey")
+    }
+}
+
 impl<T: TsTypeGen> TsInstructionGen for Argument<T> {
     fn value_type() -> Cow<'static, str> {
         T::value_type()
@@ -36,11 +49,53 @@ impl<T: TsTypeGen> TsInstructionGen for Argument<T> {
             value
         ))
     }
+
+    fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "{} = {}.deserialize(reader);",
+            value,
+            T::schema_name()
+        ))
+    }
+}
+
+impl<T: TsTypeGen + Any, const N: usize> TsInstructionGen for Batch<T, N> {
+    fn value_type() -> Cow<'static, str> {
+        Vec::<T>::value_type()
+    }
+
+    fn generate_type(registered_types: &mut TsTypesCache) -> Cow<'static, str> {
+        Vec::<T>::generate_type(registered_types)
+    }
+
+    fn get_account_metas(
+        value: Cow<'static, str>,
+        _signer: bool,
+        _writable: bool,
+    ) -> Cow<'static, str> {
+        let schema = if TypeId::of::<u8>() == TypeId::of::<T>() {
+            Cow::Borrowed("fnk.ByteVec")
+        } else {
+            Cow::Owned(format!("fnk.Vec({})", T::schema_name()))
+        };
+
+        Cow::Owned(format!("{}.serialize(writer, {});", schema, value))
+    }
+
+    fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+        let schema = if TypeId::of::<u8>() == TypeId::of::<T>() {
+            Cow::Borrowed("fnk.ByteVec")
+        } else {
+            Cow::Owned(format!("fnk.Vec({})", T::schema_name()))
+        };
+
+        Cow::Owned(format!("{} = {}.deserialize(reader);", value, schema))
+    }
 }
 
 impl<T: TsInstructionGen> TsInstructionGen for Box<T> {
     fn value_type() -> Cow<'static, str> {
-        Cow::Borrowed("solana.PublicKey")
+        T::value_type()
     }
 
     fn generate_type(registered_types: &mut TsTypesCache) -> Cow<'static, str> {
@@ -54,6 +109,10 @@ impl<T: TsInstructionGen> TsInstructionGen for Box<T> {
     ) -> Cow<'static, str> {
         T::get_external_account_metas(value, signer, writable)
     }
+
+    fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+        T::decode_external_account_metas(value)
+    }
 }
 
 impl<L: TsInstructionGen, R: TsInstructionGen> TsInstructionGen for Either<L, R> {
@@ -92,11 +151,40 @@ impl<L: TsInstructionGen, R: TsInstructionGen> TsInstructionGen for Either<L, R>
             R::get_external_account_metas(Cow::Owned(format!("{}.value", value)), signer, writable),
         ))
     }
+
+    fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "if (reader.readByte() === 0) {{
+                {} = {{ type: 'Left' }} as any;
+                {}
+            }} else {{
+                {} = {{ type: 'Right' }} as any;
+                {}
+            }}",
+            value,
+            L::decode_external_account_metas(Cow::Owned(format!("{}.value", value))),
+            value,
+            R::decode_external_account_metas(Cow::Owned(format!("{}.value", value))),
+        ))
+    }
 }
 
-impl<'info, T> TsInstructionGen for MaybeUninitialized<'info, T> {
+impl<'info, T: TsInstructionGen> TsInstructionGen for MaybeUninitialized<'info, T> {
     fn value_type() -> Cow<'static, str> {
-        Cow::Borrowed("solana.PublicKey")
+        let init_type = T::value_type();
+
+        if init_type == PUBLIC_KEY_TYPE {
+            init_type
+        } else {
+            // Uninitialized accounts are always a single account.
+            Cow::Owned(format!("{} | {}", init_type, PUBLIC_KEY_TYPE))
+        }
+    }
+
+    fn generate_type(registered_types: &mut TsTypesCache) -> Cow<'static, str> {
+        T::generate_type(registered_types);
+
+        Self::value_type()
     }
 
     fn get_account_metas(
@@ -104,11 +192,29 @@ impl<'info, T> TsInstructionGen for MaybeUninitialized<'info, T> {
         signer: bool,
         writable: bool,
     ) -> Cow<'static, str> {
+        if T::value_type() == PUBLIC_KEY_TYPE {
+            return T::get_external_account_metas(value, signer, writable);
+        }
+
         Cow::Owned(format!(
-            "accountMetas.push({{ pubkey: {}, isSigner: {}, isWritable: {} }});",
-            value, signer, writable
+            "if ({} instanceof solana.PublicKey) {{
+                accountMetas.push({{ pubkey: {}, isSigner: {}, isWritable: {} }});
+            }} else {{
+                {}
+            }}",
+            value,
+            value,
+            signer,
+            writable,
+            T::get_external_account_metas(value.clone(), signer, writable),
         ))
     }
+
+    fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+        // The uninitialized case cannot be distinguished from the accounts, so
+        // they are always decoded as the initialized type.
+        T::decode_external_account_metas(value)
+    }
 }
 
 impl<T: TsInstructionGen> TsInstructionGen for Option<T> {
@@ -129,8 +235,9 @@ impl<T: TsInstructionGen> TsInstructionGen for Option<T> {
         signer: bool,
         writable: bool,
     ) -> Cow<'static, str> {
+        // Compare against null to keep falsy values like `0` or `false`.
         Cow::Owned(format!(
-            "if ({}) {{
+            "if ({} != null) {{
                 writer.writeByte(1);
                 {}
             }} else {{
@@ -140,6 +247,18 @@ impl<T: TsInstructionGen> TsInstructionGen for Option<T> {
             T::get_external_account_metas(value, signer, writable),
         ))
     }
+
+    fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "if (reader.readByte() === 1) {{
+                {}
+            }} else {{
+                {} = null;
+            }}",
+            T::decode_external_account_metas(value.clone()),
+            value,
+        ))
+    }
 }
 
 impl<'info, T: ProgramType> TsInstructionGen for Program<'info, T> {
@@ -185,6 +304,10 @@ impl<'info> TsInstructionGen for Rest<'info> {
             value, signer, writable
         ))
     }
+
+    fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+        Cow::Owned(format!("{} = accountMetas.remaining();", value))
+    }
 }
 
 impl TsInstructionGen for RestArguments {
@@ -207,6 +330,14 @@ impl TsInstructionGen for RestArguments {
             value
         ))
     }
+
+    fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "{} = {}.deserialize(reader);",
+            value,
+            <Vec<u8>>::schema_name()
+        ))
+    }
 }
 
 impl<L, R> TsInstructionGen for SingleEither<L, R> {
@@ -256,9 +387,34 @@ impl<'info> TsInstructionGen for UninitializedAccount<'info> {
     }
 }
 
+impl<'info, T: AccountType> TsInstructionGen for GrowableAccount<'info, T> {
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("solana.PublicKey")
+    }
+}
+
+impl<'info> TsInstructionGen for PreFundedUninitializedAccount<'info> {
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("solana.PublicKey")
+    }
+}
+
+impl<'info> TsInstructionGen for DeferredClose<'info> {
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("solana.PublicKey")
+    }
+}
+
 impl<T: TsInstructionGen> TsInstructionGen for Vec<T> {
     fn value_type() -> Cow<'static, str> {
-        Cow::Owned(format!("{}[]", T::value_type()))
+        let inner_type = T::value_type();
+
+        // Unions must be wrapped to apply the array to all their members.
+        if inner_type.contains('|') {
+            Cow::Owned(format!("({})[]", inner_type))
+        } else {
+            Cow::Owned(format!("{}[]", inner_type))
+        }
     }
 
     fn generate_type(registered_types: &mut TsTypesCache) -> Cow<'static, str> {
@@ -281,6 +437,14 @@ impl<T: TsInstructionGen> TsInstructionGen for Vec<T> {
             T::get_external_account_metas(Cow::Borrowed("v"), signer, writable)
         ))
     }
+
+    fn decode_account_metas(value: Cow<'static, str>) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "{} = Array.from({{ length: reader.readByte() }}, () => {{ let v: any; {} return v; }});",
+            value,
+            T::decode_external_account_metas(Cow::Borrowed("v"))
+        ))
+    }
 }
 
 impl<'info, T: AccountType + CopyType<'info>> TsInstructionGen for ZcAccount<'info, T> {
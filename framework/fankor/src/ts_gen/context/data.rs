@@ -1,9 +1,14 @@
 use std::borrow::Cow;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use convert_case::{Case, Converter};
 
+use crate::models::{AccountFieldLayout, AccountLayout, ProgramInfo};
 use crate::traits::{TsInstructionGen, TsTypeGen, TsTypesCache};
+use crate::ts_gen::{
+    DocsError, DocsInstruction, Idl, IdlConstant, IdlField, IdlInstruction, IdlProgramInfo,
+    IdlSchema, ProgramDocs, STANDALONE_RUNTIME,
+};
 
 /// Contains the info for building the IDL.
 pub struct DataContext {
@@ -15,10 +20,43 @@ pub struct DataContext {
     pub account_schemas_use_methods: TsTypesCache,
     pub account_schemas_constants: TsTypesCache,
     pub get_meta_methods: TsTypesCache,
+    pub decode_meta_methods: TsTypesCache,
     pub program_methods: TsTypesCache,
+    pub program_decoders: TsTypesCache,
+    pub standalone_codecs: TsTypesCache,
+
+    // Name -> find PDA function.
+    pub pda_functions: BTreeMap<&'static str, &'static str>,
 
     // Type-value pairs.
     pub constants: HashMap<&'static str, (Cow<'static, str>, Cow<'static, str>)>,
+
+    // IDL data.
+    // Name -> discriminant.
+    pub program_accounts: BTreeMap<&'static str, u8>,
+    // Name -> accounts type.
+    pub program_instructions: BTreeMap<&'static str, Cow<'static, str>>,
+    // Name -> discriminants.
+    pub instruction_discriminants: BTreeMap<&'static str, Vec<u8>>,
+
+    // Docs data.
+    // Accounts type -> account layout.
+    pub instruction_layouts: HashMap<Cow<'static, str>, &'static [AccountLayout]>,
+    // Code -> name and message.
+    pub program_errors: BTreeMap<u32, (&'static str, &'static str)>,
+    // Account name -> field layout.
+    pub account_layouts: BTreeMap<&'static str, &'static [AccountFieldLayout]>,
+
+    // Type name -> serialized sample values.
+    pub schema_samples: BTreeMap<Cow<'static, str>, Vec<Vec<u8>>>,
+
+    pub program_info: Option<ProgramInfo>,
+
+    // Whether the program includes the `__version` instruction.
+    pub version_instruction: bool,
+
+    // Whether the program includes the `__account_registry` instruction.
+    pub account_registry_instruction: bool,
 }
 
 impl DataContext {
@@ -35,8 +73,22 @@ impl DataContext {
             account_schemas_use_methods: TsTypesCache::new(),
             account_schemas_constants: TsTypesCache::new(),
             get_meta_methods: HashMap::new(),
+            decode_meta_methods: HashMap::new(),
             program_methods: HashMap::new(),
+            program_decoders: HashMap::new(),
+            standalone_codecs: TsTypesCache::new(),
+            pda_functions: BTreeMap::new(),
             constants: HashMap::new(),
+            program_accounts: BTreeMap::new(),
+            program_instructions: BTreeMap::new(),
+            instruction_discriminants: BTreeMap::new(),
+            instruction_layouts: HashMap::new(),
+            program_errors: BTreeMap::new(),
+            account_layouts: BTreeMap::new(),
+            schema_samples: BTreeMap::new(),
+            program_info: None,
+            version_instruction: false,
+            account_registry_instruction: false,
         }
     }
 
@@ -76,6 +128,7 @@ impl DataContext {
         T::generate_schema(&mut self.account_schemas);
         T::generate_schema_constant(&mut self.account_schemas_constants);
         T::generate_schema_use_method(&mut self.account_schemas_use_methods);
+        T::generate_standalone_codec(&mut self.standalone_codecs);
 
         Ok(())
     }
@@ -96,6 +149,54 @@ impl DataContext {
         Ok(())
     }
 
+    /// Adds the function that finds a PDA declared in a namespace.
+    pub fn add_pda_function(
+        &mut self,
+        name: &'static str,
+        function: &'static str,
+    ) -> Result<(), String> {
+        if self.pda_functions.contains_key(name) {
+            return Err(format!("Duplicated PDA name: '{}'", name));
+        }
+
+        self.pda_functions.insert(name, function);
+
+        Ok(())
+    }
+
+    /// Sets the metadata of the program.
+    pub fn set_program_info(&mut self, info: ProgramInfo) -> Result<(), String> {
+        if self.program_info.is_some() {
+            return Err("Duplicated program info".to_string());
+        }
+
+        self.program_info = Some(info);
+
+        Ok(())
+    }
+
+    /// Adds the helpers of the `__version` instruction.
+    pub fn add_version_instruction(&mut self) -> Result<(), String> {
+        if self.version_instruction {
+            return Err("Duplicated version instruction".to_string());
+        }
+
+        self.version_instruction = true;
+
+        Ok(())
+    }
+
+    /// Adds the helpers of the `__account_registry` instruction.
+    pub fn add_account_registry_instruction(&mut self) -> Result<(), String> {
+        if self.account_registry_instruction {
+            return Err("Duplicated account registry instruction".to_string());
+        }
+
+        self.account_registry_instruction = true;
+
+        Ok(())
+    }
+
     /// Adds an instruction account.
     pub fn add_instruction_account<T: TsInstructionGen>(&mut self) -> Result<(), String> {
         let name = T::value_type();
@@ -115,8 +216,21 @@ impl DataContext {
             T::get_account_metas(Cow::Borrowed("accounts"), false, false),
         );
 
+        let decode_metas_method = format!(
+            "function decodeMetasOf{}(reader: fnk.FnkBorshReader, accountMetas: fnk.AccountMetasReader): {} {{
+                let accounts: any = {{}};
+                {}
+                return accounts;
+            }}",
+            name,
+            name,
+            T::decode_account_metas(Cow::Borrowed("accounts")),
+        );
+
         self.get_meta_methods
-            .insert(name, Cow::Owned(get_metas_method));
+            .insert(name.clone(), Cow::Owned(get_metas_method));
+        self.decode_meta_methods
+            .insert(name, Cow::Owned(decode_metas_method));
 
         Ok(())
     }
@@ -126,6 +240,34 @@ impl DataContext {
         &mut self,
         discriminant_name: &'static str,
         variant_name: &'static str,
+        discriminant: u8,
+    ) -> Result<(), String> {
+        let discriminants = [(format!("{}.{}", discriminant_name, variant_name), discriminant)];
+        self.add_program_method_with_discriminants::<T>(&discriminants, variant_name)
+    }
+
+    /// Adds a program method that belongs to an instruction group, so its data
+    /// starts with the discriminant of the group, e.g. `ProgramDiscriminant.Admin`.
+    pub fn add_program_group_method<T: TsInstructionGen>(
+        &mut self,
+        group_discriminant: (&'static str, u8),
+        discriminant_name: &'static str,
+        variant_name: &'static str,
+        discriminant: u8,
+    ) -> Result<(), String> {
+        let discriminants = [
+            (group_discriminant.0.to_string(), group_discriminant.1),
+            (format!("{}.{}", discriminant_name, variant_name), discriminant),
+        ];
+        self.add_program_method_with_discriminants::<T>(&discriminants, variant_name)
+    }
+
+    /// Adds a program method whose data starts with `discriminants`, given as
+    /// their TypeScript expression and value.
+    fn add_program_method_with_discriminants<T: TsInstructionGen>(
+        &mut self,
+        discriminants: &[(String, u8)],
+        variant_name: &'static str,
     ) -> Result<(), String> {
         let case_converter = Converter::new()
             .from_case(Case::Pascal)
@@ -137,10 +279,22 @@ impl DataContext {
         }
 
         let accounts_type = T::value_type();
+        self.program_instructions
+            .insert(variant_name, accounts_type.clone());
+        self.instruction_layouts
+            .insert(accounts_type.clone(), T::account_layout());
+        self.instruction_discriminants
+            .insert(variant_name, discriminants.iter().map(|(_, v)| *v).collect());
+
+        let write_discriminants = discriminants
+            .iter()
+            .map(|(v, _)| format!("writer.writeByte({});", v))
+            .collect::<String>();
+
         let method = format!(
             "{}(accounts: {}) {{
                 const writer = new fnk.FnkBorshWriter();
-                writer.writeByte({}.{});
+                {}
                 const accountMetas: solana.AccountMeta[] = [];
 
                 getMetasOf{}(accounts, accountMetas, writer);
@@ -151,14 +305,96 @@ impl DataContext {
                     data: writer.toBuffer()
                 }});
             }}",
-            name, accounts_type, discriminant_name, variant_name, accounts_type,
+            name, accounts_type, write_discriminants, accounts_type,
         );
 
+        let match_discriminants = discriminants
+            .iter()
+            .enumerate()
+            .map(|(i, (v, _))| format!(" && data[{}] === {}", i, v))
+            .collect::<String>();
+
+        let decoder = format!(
+            "if (data.length >= {}{}) {{
+                const reader = new fnk.FnkBorshReader(data, {});
+                return {{ name: '{}', accounts: decodeMetasOf{}(reader, accountMetas) }};
+            }}",
+            discriminants.len(),
+            match_discriminants,
+            discriminants.len(),
+            name,
+            accounts_type,
+        );
+
+        self.program_decoders
+            .insert(name.clone(), Cow::Owned(decoder));
         self.program_methods.insert(name, Cow::Owned(method));
 
         Ok(())
     }
 
+    /// Adds an account of the program.
+    pub fn add_program_account(
+        &mut self,
+        name: &'static str,
+        discriminant: u8,
+    ) -> Result<(), String> {
+        if self.program_accounts.insert(name, discriminant).is_some() {
+            return Err(format!("Duplicated program account: '{}'", name));
+        }
+
+        Ok(())
+    }
+
+    /// Adds an error of the program.
+    pub fn add_program_error(
+        &mut self,
+        name: &'static str,
+        code: u32,
+        message: &'static str,
+    ) -> Result<(), String> {
+        if let Some((other, _)) = self.program_errors.get(&code) {
+            return Err(format!(
+                "Duplicated error code {}: '{}' and '{}'",
+                code, other, name
+            ));
+        }
+
+        self.program_errors.insert(code, (name, message));
+
+        Ok(())
+    }
+
+    /// Adds the byte layout of an account.
+    pub fn add_account_layout(
+        &mut self,
+        name: &'static str,
+        layout: &'static [AccountFieldLayout],
+    ) -> Result<(), String> {
+        if self.account_layouts.insert(name, layout).is_some() {
+            return Err(format!("Duplicated account layout: '{}'", name));
+        }
+
+        Ok(())
+    }
+
+    /// Adds the serialized sample values of a type to test its schema, e.g.
+    /// one per variant of an enum.
+    pub fn add_schema_samples<T: TsTypeGen>(
+        &mut self,
+        samples: Vec<Vec<u8>>,
+    ) -> Result<(), String> {
+        let name = T::value_type();
+
+        if self.schema_samples.contains_key(&name) {
+            return Err(format!("Duplicated schema sample: '{}'", name));
+        }
+
+        self.schema_samples.insert(name, samples);
+
+        Ok(())
+    }
+
     /// Adds an account type extension.
     pub fn add_account_type_extensions(
         &mut self,
@@ -192,6 +428,39 @@ impl DataContext {
             buffer.push_str(format!("export const {}: {} = {};\n", name, ty, value).as_str());
         }
 
+        // Build program info.
+        if let Some(info) = &self.program_info {
+            let optional_string = |v: Option<&str>| match v {
+                Some(v) => format!("'{}'", v),
+                None => "undefined".to_string(),
+            };
+            let deployments = info
+                .deployments
+                .iter()
+                .map(|(cluster, address)| {
+                    format!("'{}': new solana.PublicKey('{}'),", cluster, address)
+                })
+                .collect::<String>();
+
+            buffer.push_str(&format!(
+                "export const PROGRAM_INFO: fnk.ProgramInfo = {{
+                    version: '{}',
+                    docs: {},
+                    repository: {},
+                    deployments: {{{}}},
+                }};",
+                info.version,
+                optional_string(info.docs),
+                optional_string(info.repository),
+                deployments,
+            ));
+        }
+
+        // Build PDA functions.
+        for function in self.pda_functions.values() {
+            buffer.push_str(function);
+        }
+
         // Build types.
         let mut account_types = self.account_types.iter().collect::<Vec<_>>();
         account_types.sort_by(|a, b| a.0.cmp(b.0));
@@ -242,7 +511,18 @@ impl DataContext {
             buffer.push_str(method);
         }
 
+        // Build decode meta methods.
+        let mut decode_meta_methods = self.decode_meta_methods.iter().collect::<Vec<_>>();
+        decode_meta_methods.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (_name, method) in decode_meta_methods {
+            buffer.push_str(method);
+        }
+
         // Build program methods.
+        let case_converter = Converter::new()
+            .from_case(Case::Pascal)
+            .to_case(Case::Camel);
         let mut program_methods = self.program_methods.iter().collect::<Vec<_>>();
         program_methods.sort_by(|a, b| a.0.cmp(b.0));
 
@@ -253,8 +533,379 @@ impl DataContext {
         }
         buffer.push_str("};");
 
+        // Build instruction decoder.
+        let mut program_decoders = self.program_decoders.iter().collect::<Vec<_>>();
+        program_decoders.sort_by(|a, b| a.0.cmp(b.0));
+
+        let decoded_instructions = self
+            .program_instructions
+            .iter()
+            .map(|(name, accounts_type)| {
+                format!(
+                    "{{ name: '{}', accounts: {} }}",
+                    case_converter.convert(name),
+                    accounts_type
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if decoded_instructions.is_empty() {
+            buffer.push_str("export type DecodedInstruction = never;");
+        } else {
+            buffer.push_str(&format!(
+                "export type DecodedInstruction = {};",
+                decoded_instructions.join("|")
+            ));
+        }
+
+        buffer.push_str("export function decodeInstruction(data: Buffer, accounts: solana.AccountMeta[]): DecodedInstruction {
+            const accountMetas = new fnk.AccountMetasReader(accounts);");
+        for (_name, decoder) in program_decoders {
+            buffer.push_str(decoder);
+        }
+        buffer.push_str("throw new Error('Unknown instruction'); }");
+
+        // Build write locks.
+        buffer.push_str("export const instructionWriteLocks: Record<string, string[]> = {");
+        for (name, accounts_type) in &self.program_instructions {
+            let write_locks = self
+                .write_locks(accounts_type)
+                .iter()
+                .map(|v| format!("'{}'", v))
+                .collect::<Vec<_>>();
+
+            buffer.push_str(&format!(
+                "{}: [{}],",
+                case_converter.convert(name),
+                write_locks.join(",")
+            ));
+        }
+        buffer.push_str("};");
+
+        // Build transaction helpers.
+        buffer.push_str(
+            "export function buildTransaction(connection: solana.Connection, payer: solana.PublicKey, instructions: solana.TransactionInstruction[], options?: fnk.BuildTransactionOptions): Promise<solana.VersionedTransaction> {
+                return fnk.buildVersionedTransaction(connection, payer, instructions, options);
+            }
+            export function signAndSend(connection: solana.Connection, wallet: fnk.WalletAdapter, instructions: solana.TransactionInstruction[] | solana.VersionedTransaction, options?: fnk.SignAndSendOptions): Promise<string> {
+                return fnk.signAndSend(connection, wallet, instructions, options);
+            }",
+        );
+
+        // Build version helpers.
+        if self.version_instruction {
+            buffer.push_str(
+                "export function getProgramVersion(connection: solana.Connection, payer: solana.PublicKey): Promise<string> {
+                    return fnk.getProgramVersion(connection, ID, payer);
+                }
+                export function assertProgramVersion(connection: solana.Connection, range: string, payer: solana.PublicKey): Promise<string> {
+                    return fnk.assertProgramVersion(connection, ID, range, payer);
+                }",
+            );
+        }
+
+        // Build account registry helpers.
+        if self.account_registry_instruction {
+            buffer.push_str(
+                "export function getAccountRegistry(connection: solana.Connection) {
+                    return fnk.getAccountRegistry(connection, ID);
+                }
+                export function createAccountRegistryInstruction(payer: solana.PublicKey): solana.TransactionInstruction {
+                    return new solana.TransactionInstruction({
+                        keys: [
+                            { pubkey: fnk.findAccountRegistryAddress(ID), isSigner: false, isWritable: true },
+                            { pubkey: payer, isSigner: true, isWritable: true },
+                            { pubkey: solana.SystemProgram.programId, isSigner: false, isWritable: false },
+                        ],
+                        programId: ID,
+                        data: Buffer.from([fnk.ACCOUNT_REGISTRY_INSTRUCTION_DISCRIMINANT]),
+                    });
+                }",
+            );
+        }
+
         buffer
     }
+
+    /// Builds the TypeScript test file that checks the schemas deserialize and
+    /// serialize again the samples produced by Rust without changes.
+    pub fn build_ts_tests_file(&self) -> String {
+        let mut buffer = String::new();
+
+        // Imports.
+        buffer.push_str("import assert from 'assert';");
+        buffer.push_str("import * as fnk from '@suri-project/fankor/dist/esm';");
+        buffer.push_str(&format!(
+            "import * as program from '../{}';",
+            self.program_name
+        ));
+
+        buffer.push_str(&format!("describe('{} schemas', () => {{", self.program_name));
+
+        for (name, samples) in &self.schema_samples {
+            for (i, sample) in samples.iter().enumerate() {
+                let hex = sample
+                    .iter()
+                    .map(|v| format!("{:02x}", v))
+                    .collect::<String>();
+
+                buffer.push_str(&format!(
+                    "it('{} #{}', () => {{
+                        const bytes = Buffer.from('{}', 'hex');
+                        const reader = new fnk.FnkBorshReader(bytes);
+                        const value = program.T{}.deserialize(reader);
+
+                        const writer = new fnk.FnkBorshWriter();
+                        program.T{}.serialize(writer, value);
+
+                        assert(writer.toBuffer().equals(bytes), 'The serialized value differs from the Rust one');
+                    }});",
+                    name, i, hex, name, name,
+                ));
+            }
+        }
+
+        buffer.push_str("});");
+
+        buffer
+    }
+
+    /// Builds the standalone TypeScript file, which contains the codecs of the
+    /// types without depending on any package.
+    pub fn build_standalone_ts_file(&self) -> String {
+        let mut buffer = String::from(STANDALONE_RUNTIME);
+
+        let mut codecs = self.standalone_codecs.iter().collect::<Vec<_>>();
+        codecs.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (_name, codec) in codecs {
+            buffer.push_str(codec);
+        }
+
+        buffer
+    }
+
+    /// Builds the IDL from the data stored in the context.
+    pub fn build_idl(&self) -> Idl {
+        let mut constants = self
+            .constants
+            .iter()
+            .map(|(name, (ty, value))| IdlConstant {
+                name: name.to_string(),
+                ty: ty.to_string(),
+                value: value.to_string(),
+            })
+            .collect::<Vec<_>>();
+        constants.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut types = self
+            .account_types
+            .keys()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        types.sort();
+
+        let mut schemas = self
+            .account_schemas
+            .iter()
+            .map(|(name, definition)| IdlSchema {
+                name: name.to_string(),
+                definition: definition.to_string(),
+            })
+            .collect::<Vec<_>>();
+        schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let accounts = self
+            .program_accounts
+            .keys()
+            .map(|v| v.to_string())
+            .collect();
+
+        let account_discriminants = self
+            .program_accounts
+            .iter()
+            .map(|(name, discriminant)| (name.to_string(), *discriminant))
+            .collect();
+
+        let account_fields = self
+            .account_layouts
+            .iter()
+            .map(|(name, layout)| {
+                let fields = layout
+                    .iter()
+                    .map(|v| IdlField {
+                        name: v.name.to_string(),
+                        ty: v.type_name.to_string(),
+                        offset: v.offset,
+                        size: v.size,
+                    })
+                    .collect();
+
+                (name.to_string(), fields)
+            })
+            .collect();
+
+        let instructions = self
+            .program_instructions
+            .iter()
+            .map(|(name, accounts)| IdlInstruction {
+                name: name.to_string(),
+                accounts: accounts.to_string(),
+                metas: self
+                    .get_meta_methods
+                    .get(accounts)
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+                discriminants: self
+                    .instruction_discriminants
+                    .get(name)
+                    .cloned()
+                    .unwrap_or_default(),
+                account_names: self.account_names(accounts),
+                write_locks: self.write_locks(accounts),
+                unchecked_reasons: self.unchecked_reasons(accounts),
+            })
+            .collect();
+
+        let info = self.program_info.map(|info| IdlProgramInfo {
+            version: info.version.to_string(),
+            docs: info.docs.map(|v| v.to_string()),
+            repository: info.repository.map(|v| v.to_string()),
+            deployments: info
+                .deployments
+                .iter()
+                .map(|(cluster, address)| (cluster.to_string(), address.to_string()))
+                .collect(),
+        });
+
+        Idl {
+            name: self.program_name.to_string(),
+            constants,
+            types,
+            schemas,
+            accounts,
+            account_discriminants,
+            account_fields,
+            instructions,
+            info,
+        }
+    }
+
+    /// Builds the JSON IDL file from the data stored in the context.
+    pub fn build_idl_file(&self) -> String {
+        self.build_idl().to_json()
+    }
+
+    /// Builds the documentation data from the data stored in the context.
+    pub fn build_docs(&self) -> ProgramDocs {
+        let idl = self.build_idl();
+
+        let instructions = self
+            .program_instructions
+            .iter()
+            .map(|(name, accounts_type)| DocsInstruction {
+                name: name.to_string(),
+                accounts_type: accounts_type.to_string(),
+                accounts: self
+                    .instruction_layouts
+                    .get(accounts_type)
+                    .map(|layout| layout.iter().map(|v| v.into()).collect())
+                    .unwrap_or_default(),
+            })
+            .collect();
+
+        let errors = self
+            .program_errors
+            .iter()
+            .map(|(code, (name, message))| DocsError {
+                name: name.to_string(),
+                code: *code,
+                message: message.to_string(),
+            })
+            .collect();
+
+        ProgramDocs {
+            name: idl.name,
+            instructions,
+            accounts: idl.accounts,
+            errors,
+            info: idl.info,
+        }
+    }
+
+    /// Builds the JSON documentation file from the data stored in the context.
+    pub fn build_docs_file(&self) -> String {
+        self.build_docs().to_json()
+    }
+
+    /// Builds the Markdown file with the byte layout of the accounts.
+    pub fn build_layout_file(&self) -> String {
+        let mut buffer = format!("# {} accounts layout\n", self.program_name);
+        let dynamic = |v: Option<usize>| v.map_or("dynamic".to_string(), |v| v.to_string());
+
+        for (name, layout) in &self.account_layouts {
+            buffer.push_str(&format!("\n## {}\n\n", name));
+            buffer.push_str("| Field | Offset | Size | Type |\n| --- | --- | --- | --- |\n");
+            buffer.push_str("| discriminant | 0 | 1 | u8 |\n");
+
+            for field in layout.iter() {
+                buffer.push_str(&format!(
+                    "| {} | {} | {} | `{}` |\n",
+                    field.name,
+                    dynamic(field.offset),
+                    dynamic(field.size),
+                    field.type_name
+                ));
+            }
+        }
+
+        buffer
+    }
+
+    /// The fields of an accounts type in order, in camel case like in its
+    /// TypeScript interface.
+    fn account_names(&self, accounts_type: &str) -> Vec<String> {
+        let case_converter = Converter::new().from_case(Case::Snake).to_case(Case::Camel);
+
+        self.instruction_layouts
+            .get(accounts_type)
+            .map(|layout| layout.iter().map(|v| case_converter.convert(v.name)).collect())
+            .unwrap_or_default()
+    }
+
+    /// The fields of an accounts type that are always writable, in camel case
+    /// like in its TypeScript interface.
+    fn write_locks(&self, accounts_type: &str) -> Vec<String> {
+        let case_converter = Converter::new().from_case(Case::Snake).to_case(Case::Camel);
+
+        self.instruction_layouts
+            .get(accounts_type)
+            .map(|layout| {
+                layout
+                    .iter()
+                    .filter(|v| v.writable == Some(true))
+                    .map(|v| case_converter.convert(v.name))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn unchecked_reasons(&self, accounts_type: &str) -> BTreeMap<String, String> {
+        let case_converter = Converter::new().from_case(Case::Snake).to_case(Case::Camel);
+
+        self.instruction_layouts
+            .get(accounts_type)
+            .map(|layout| {
+                layout
+                    .iter()
+                    .filter_map(|v| {
+                        v.unchecked_reason
+                            .map(|reason| (case_converter.convert(v.name), reason.to_string()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 }
 
 impl Default for DataContext {
@@ -262,3 +913,368 @@ impl Default for DataContext {
         Self::new()
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::models::RestArguments;
+    use crate::ts_gen::DocsAccount;
+
+    use super::*;
+
+    #[test]
+    fn test_build_idl_file() {
+        const LAYOUT: &[AccountLayout] = &[
+            AccountLayout {
+                name: "user_vault",
+                type_name: "Account<'info,Vault>",
+                writable: Some(true),
+                signer: None,
+                optional: false,
+                min_count: 1,
+                max_count: Some(1),
+                constraints: &[],
+                pda: None,
+                unchecked_reason: None,
+            },
+            AccountLayout {
+                name: "config",
+                type_name: "UncheckedAccount<'info>",
+                writable: None,
+                signer: None,
+                optional: false,
+                min_count: 1,
+                max_count: Some(1),
+                constraints: &[],
+                pda: None,
+                unchecked_reason: Some("Only forwarded to the oracle"),
+            },
+        ];
+
+        let mut context = DataContext::new();
+        context.set_context_name("test").unwrap();
+        context.add_constant("A", 5u8).unwrap();
+        context.add_program_account("Account", 2).unwrap();
+        context
+            .add_account_layout(
+                "Account",
+                &[AccountFieldLayout {
+                    name: "value",
+                    type_name: "u32",
+                    offset: Some(1),
+                    size: Some(4),
+                }],
+            )
+            .unwrap();
+        context
+            .program_instructions
+            .insert("Instruction", Cow::Borrowed("InstructionAccounts"));
+        context
+            .instruction_discriminants
+            .insert("Instruction", vec![4]);
+        context
+            .instruction_layouts
+            .insert(Cow::Borrowed("InstructionAccounts"), LAYOUT);
+
+        let idl = Idl::from_json(&context.build_idl_file()).unwrap();
+
+        assert_eq!(idl, context.build_idl());
+        assert_eq!(idl.name, "test");
+        assert_eq!(
+            idl.constants,
+            vec![IdlConstant {
+                name: "A".to_string(),
+                ty: "number".to_string(),
+                value: "5".to_string(),
+            }]
+        );
+        assert_eq!(idl.accounts, vec!["Account".to_string()]);
+        assert_eq!(
+            idl.account_discriminants,
+            BTreeMap::from([("Account".to_string(), 2)])
+        );
+        assert_eq!(
+            idl.account_fields,
+            BTreeMap::from([(
+                "Account".to_string(),
+                vec![IdlField {
+                    name: "value".to_string(),
+                    ty: "u32".to_string(),
+                    offset: Some(1),
+                    size: Some(4),
+                }]
+            )])
+        );
+        assert_eq!(
+            idl.instructions,
+            vec![IdlInstruction {
+                name: "Instruction".to_string(),
+                accounts: "InstructionAccounts".to_string(),
+                metas: "".to_string(),
+                discriminants: vec![4],
+                account_names: vec!["userVault".to_string(), "config".to_string()],
+                write_locks: vec!["userVault".to_string()],
+                unchecked_reasons: BTreeMap::from([(
+                    "config".to_string(),
+                    "Only forwarded to the oracle".to_string(),
+                )]),
+            }]
+        );
+        assert!(context
+            .build_ts_file()
+            .contains("export const instructionWriteLocks: Record<string, string[]> = {instruction: ['userVault'],};"));
+    }
+
+    #[test]
+    fn test_build_docs_file() {
+        const LAYOUT: &[AccountLayout] = &[AccountLayout {
+            name: "vault",
+            type_name: "Account<'info,Vault>",
+            writable: Some(true),
+            signer: None,
+            optional: false,
+            min_count: 1,
+            max_count: Some(1),
+            constraints: &["owner = &crate::ID"],
+            pda: Some("[b\"vault\"]"),
+            unchecked_reason: None,
+        }];
+
+        let mut context = DataContext::new();
+        context.set_context_name("test").unwrap();
+        context
+            .program_instructions
+            .insert("Instruction", Cow::Borrowed("InstructionAccounts"));
+        context
+            .instruction_layouts
+            .insert(Cow::Borrowed("InstructionAccounts"), LAYOUT);
+        context
+            .add_program_error("Unauthorized", 6001, "Unauthorized: {}")
+            .unwrap();
+        context.add_program_error("Other", 6000, "").unwrap();
+        assert!(context.add_program_error("Duplicated", 6000, "").is_err());
+
+        let docs = ProgramDocs::from_json(&context.build_docs_file()).unwrap();
+
+        assert_eq!(docs, context.build_docs());
+        assert_eq!(docs.name, "test");
+        assert_eq!(docs.instructions.len(), 1);
+        assert_eq!(docs.instructions[0].accounts_type, "InstructionAccounts");
+        assert_eq!(docs.instructions[0].accounts, vec![DocsAccount::from(&LAYOUT[0])]);
+        assert_eq!(
+            docs.instructions[0].accounts[0].pda.as_deref(),
+            Some("[b\"vault\"]")
+        );
+        assert_eq!(
+            docs.errors,
+            vec![
+                DocsError {
+                    name: "Other".to_string(),
+                    code: 6000,
+                    message: "".to_string(),
+                },
+                DocsError {
+                    name: "Unauthorized".to_string(),
+                    code: 6001,
+                    message: "Unauthorized: {}".to_string(),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_layout_file() {
+        const LAYOUT: &[AccountFieldLayout] = &[
+            AccountFieldLayout {
+                name: "value1",
+                type_name: "u32",
+                offset: Some(1),
+                size: Some(4),
+            },
+            AccountFieldLayout {
+                name: "value2",
+                type_name: "String",
+                offset: Some(5),
+                size: None,
+            },
+            AccountFieldLayout {
+                name: "value3",
+                type_name: "u8",
+                offset: None,
+                size: Some(1),
+            },
+        ];
+
+        let mut context = DataContext::new();
+        context.set_context_name("test").unwrap();
+        context.add_account_layout("Data", LAYOUT).unwrap();
+        assert!(context.add_account_layout("Data", LAYOUT).is_err());
+
+        let file = context.build_layout_file();
+
+        assert!(file.starts_with("# test accounts layout\n"));
+        assert!(file.contains("## Data\n"));
+        assert!(file.contains("| discriminant | 0 | 1 | u8 |\n"));
+        assert!(file.contains("| value1 | 1 | 4 | `u32` |\n"));
+        assert!(file.contains("| value2 | 5 | dynamic | `String` |\n"));
+        assert!(file.contains("| value3 | dynamic | 1 | `u8` |\n"));
+    }
+
+    #[test]
+    fn test_program_info() {
+        const DEPLOYMENTS: &[(&str, Pubkey)] = &[("devnet", Pubkey::new_from_array([0; 32]))];
+
+        let mut context = DataContext::new();
+        let info = ProgramInfo {
+            version: "1.2.0",
+            docs: Some("https://docs.example.com"),
+            repository: None,
+            deployments: DEPLOYMENTS,
+        };
+
+        context.set_program_info(info).unwrap();
+        assert!(context.set_program_info(info).is_err());
+
+        let idl = Idl::from_json(&context.build_idl_file()).unwrap();
+        let idl_info = idl.info.unwrap();
+        assert_eq!(idl_info.version, "1.2.0");
+        assert_eq!(idl_info.docs.as_deref(), Some("https://docs.example.com"));
+        assert_eq!(idl_info.repository, None);
+        assert_eq!(
+            idl_info.deployments.get("devnet").map(|v| v.as_str()),
+            Some("11111111111111111111111111111111")
+        );
+
+        let file = context.build_ts_file();
+        assert!(file.contains("export const PROGRAM_INFO: fnk.ProgramInfo"));
+        assert!(file.contains("version: '1.2.0'"));
+        assert!(file.contains("repository: undefined"));
+        assert!(file.contains(
+            "'devnet': new solana.PublicKey('11111111111111111111111111111111')"
+        ));
+    }
+
+    #[test]
+    fn test_add_version_instruction() {
+        let mut context = DataContext::new();
+        assert!(!context
+            .build_ts_file()
+            .contains("export function assertProgramVersion"));
+
+        context.add_version_instruction().unwrap();
+        assert!(context.add_version_instruction().is_err());
+
+        let file = context.build_ts_file();
+        assert!(file.contains("return fnk.getProgramVersion(connection, ID, payer);"));
+        assert!(file.contains("return fnk.assertProgramVersion(connection, ID, range, payer);"));
+    }
+
+    #[test]
+    fn test_add_account_registry_instruction() {
+        let mut context = DataContext::new();
+        assert!(!context
+            .build_ts_file()
+            .contains("export function getAccountRegistry"));
+
+        context.add_account_registry_instruction().unwrap();
+        assert!(context.add_account_registry_instruction().is_err());
+
+        let file = context.build_ts_file();
+        assert!(file.contains("return fnk.getAccountRegistry(connection, ID);"));
+        assert!(file.contains("data: Buffer.from([fnk.ACCOUNT_REGISTRY_INSTRUCTION_DISCRIMINANT]),"));
+    }
+
+    #[test]
+    fn test_transaction_helpers() {
+        let mut context = DataContext::new();
+        let file = context.build_ts_file();
+
+        assert!(file.contains(
+            "return fnk.buildVersionedTransaction(connection, payer, instructions, options);"
+        ));
+        assert!(file.contains("return fnk.signAndSend(connection, wallet, instructions, options);"));
+        assert!(file.contains("export type DecodedInstruction = never;"));
+    }
+
+    #[test]
+    fn test_build_ts_tests_file() {
+        let mut context = DataContext::new();
+        context.set_context_name("test").unwrap();
+        context
+            .schema_samples
+            .insert(Cow::Borrowed("Data"), vec![vec![1, 0, 255], vec![2]]);
+
+        let file = context.build_ts_tests_file();
+
+        assert!(file.contains("import * as program from '../test';"));
+        assert!(file.contains("it('Data #0', () => {"));
+        assert!(file.contains("Buffer.from('0100ff', 'hex')"));
+        assert!(file.contains("it('Data #1', () => {"));
+        assert!(file.contains("Buffer.from('02', 'hex')"));
+        assert!(file.contains("program.TData.deserialize(reader)"));
+    }
+
+    #[test]
+    fn test_build_standalone_ts_file() {
+        let mut context = DataContext::new();
+        let codec = <Option<Vec<u64>> as TsTypeGen>::generate_standalone_codec(
+            &mut context.standalone_codecs,
+        );
+        assert_eq!(codec, "fnkOption(fnkVec(fnkU64))");
+
+        let codec =
+            <[u8; 4] as TsTypeGen>::generate_standalone_codec(&mut context.standalone_codecs);
+        assert_eq!(codec, "fnkBytes(4)");
+
+        context.standalone_codecs.insert(
+            Cow::Borrowed("DataCodec"),
+            Cow::Borrowed("export const DataCodec: FnkCodec<any> = fnkStruct(() => []);"),
+        );
+
+        let file = context.build_standalone_ts_file();
+
+        assert!(file.starts_with(STANDALONE_RUNTIME));
+        assert!(file.contains("export const DataCodec"));
+        assert!(!file.contains("import "));
+    }
+
+    #[test]
+    fn test_add_program_group_method() {
+        let mut context = DataContext::new();
+        context
+            .add_program_group_method::<RestArguments>(
+                ("ProgramDiscriminant.Admin", 3),
+                "AdminDiscriminant",
+                "SetFee",
+                1,
+            )
+            .unwrap();
+
+        let method = context.program_methods.get("setFee").unwrap();
+        let group_position = method.find("writer.writeByte(ProgramDiscriminant.Admin);");
+        let method_position = method.find("writer.writeByte(AdminDiscriminant.SetFee);");
+        assert!(group_position.is_some());
+        assert!(group_position < method_position);
+        assert_eq!(context.program_instructions.len(), 1);
+        assert_eq!(context.instruction_discriminants.get("SetFee"), Some(&vec![3, 1]));
+
+        let decoder = context.program_decoders.get("setFee").unwrap();
+        assert!(decoder.contains(
+            "data.length >= 2 && data[0] === ProgramDiscriminant.Admin && data[1] === AdminDiscriminant.SetFee"
+        ));
+        assert!(decoder.contains("new fnk.FnkBorshReader(data, 2)"));
+
+        let file = context.build_ts_file();
+        assert!(file.contains("export type DecodedInstruction = { name: 'setFee', accounts: "));
+        assert!(file.contains("export function decodeInstruction(data: Buffer, accounts: solana.AccountMeta[]): DecodedInstruction {"));
+
+        assert!(context
+            .add_program_method::<RestArguments>("ProgramDiscriminant", "SetFee", 3)
+            .is_err());
+    }
+}
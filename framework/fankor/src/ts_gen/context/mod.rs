@@ -1,4 +1,4 @@
-use std::{fs, thread};
+use std::thread;
 use std::panic::UnwindSafe;
 use std::sync::{Arc, Mutex, MutexGuard};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
@@ -6,7 +6,7 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 pub use data::*;
 
-use crate::ts_gen::context::data::DataContext;
+use crate::build::{generate, TsGenConfig};
 
 mod data;
 
@@ -95,8 +95,23 @@ impl BuildContext {
         actions.pop()
     }
 
-    /// Performs the build process waiting 4 seconds till all tests register their actions.
+    /// Performs the build process waiting till all tests register their actions
+    /// and writes the TypeScript file following the config of the environment.
+    /// See [TsGenConfig::from_env].
     pub fn build(&self) {
+        let config = TsGenConfig::from_env();
+        let output = generate(self, &config)
+            .unwrap_or_else(|e| panic!("Cannot generate the TypeScript file: {}", e));
+
+        if output.changed {
+            println!("IDL generation done: {}", output.file_path.display());
+        } else {
+            println!("IDL unchanged: {}", output.file_path.display());
+        }
+    }
+
+    /// Waits till all tests register their actions and executes them.
+    pub(crate) fn execute_all_actions(&self) -> MutexGuard<DataContext> {
         // Wait till start_at.
         loop {
             let now = SystemTime::now()
@@ -123,12 +138,10 @@ impl BuildContext {
         // another one was registered.
         total_actions = 0;
 
-        let idl_build_context = self.execute_actions(data_context, &mut total_actions);
+        let data_context = self.execute_actions(data_context, &mut total_actions);
         println!("{} actions done [second round]", total_actions);
 
-        // Generate the IDL files.
-        self.generate(idl_build_context);
-        println!("IDL generation done.");
+        data_context
     }
 
     fn execute_actions<'a>(
@@ -145,23 +158,6 @@ impl BuildContext {
 
         data_context
     }
-
-    fn generate(&self, mut data_context: MutexGuard<DataContext>) {
-        let folder_path = "target/fnk_ts";
-        let file_path = format!("{}/{}.ts", folder_path, data_context.program_name);
-
-        // Remove file.
-        let _ = fs::remove_file(file_path.as_str());
-
-        // Create folder.
-        fs::create_dir_all(folder_path)
-            .unwrap_or_else(|e| panic!("Cannot create folder '{}': {}", folder_path, e));
-
-        // Generate the TypeScript file.
-        let file_content = data_context.build_ts_file();
-        fs::write(file_path.as_str(), file_content.as_str())
-            .unwrap_or_else(|e| panic!("Cannot write file '{}': {}", file_path, e));
-    }
 }
 
 impl Default for BuildContext {
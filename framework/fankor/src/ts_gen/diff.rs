@@ -0,0 +1,368 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use crate::ts_gen::Idl;
+
+/// A change between two versions of an IDL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdlChange {
+    InstructionAdded { name: String },
+    InstructionRemoved { name: String },
+    InstructionDiscriminantChanged { name: String },
+    /// The accounts of the instruction have been added, removed or reordered.
+    InstructionAccountsChanged { name: String },
+    AccountAdded { name: String },
+    AccountRemoved { name: String },
+    AccountDiscriminantChanged { name: String },
+    /// The fields of the account have been added, removed, reordered or
+    /// changed their type, offset or size.
+    AccountFieldsChanged { name: String },
+    SchemaAdded { name: String },
+    SchemaRemoved { name: String },
+    /// The layout of the type has changed.
+    SchemaChanged { name: String },
+    ConstantAdded { name: String },
+    ConstantRemoved { name: String },
+    ConstantChanged { name: String },
+}
+
+impl IdlChange {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Whether the change breaks the clients of the previous version or not.
+    pub fn is_breaking(&self) -> bool {
+        !matches!(
+            self,
+            IdlChange::InstructionAdded { .. }
+                | IdlChange::AccountAdded { .. }
+                | IdlChange::SchemaAdded { .. }
+                | IdlChange::ConstantAdded { .. }
+                | IdlChange::ConstantChanged { .. }
+        )
+    }
+}
+
+impl Display for IdlChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdlChange::InstructionAdded { name } => write!(f, "Instruction '{}' added", name),
+            IdlChange::InstructionRemoved { name } => write!(f, "Instruction '{}' removed", name),
+            IdlChange::InstructionDiscriminantChanged { name } => {
+                write!(f, "Discriminant of instruction '{}' changed", name)
+            }
+            IdlChange::InstructionAccountsChanged { name } => {
+                write!(f, "Accounts of instruction '{}' changed", name)
+            }
+            IdlChange::AccountAdded { name } => write!(f, "Account '{}' added", name),
+            IdlChange::AccountRemoved { name } => write!(f, "Account '{}' removed", name),
+            IdlChange::AccountDiscriminantChanged { name } => {
+                write!(f, "Discriminant of account '{}' changed", name)
+            }
+            IdlChange::AccountFieldsChanged { name } => {
+                write!(f, "Fields of account '{}' changed", name)
+            }
+            IdlChange::SchemaAdded { name } => write!(f, "Type '{}' added", name),
+            IdlChange::SchemaRemoved { name } => write!(f, "Type '{}' removed", name),
+            IdlChange::SchemaChanged { name } => write!(f, "Layout of type '{}' changed", name),
+            IdlChange::ConstantAdded { name } => write!(f, "Constant '{}' added", name),
+            IdlChange::ConstantRemoved { name } => write!(f, "Constant '{}' removed", name),
+            IdlChange::ConstantChanged { name } => write!(f, "Constant '{}' changed", name),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The list of changes between two versions of an IDL.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdlDiff {
+    pub changes: Vec<IdlChange>,
+}
+
+impl IdlDiff {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Whether any of the changes is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|v| v.is_breaking())
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &IdlChange> {
+        self.changes.iter().filter(|v| v.is_breaking())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Compares two versions of the IDL of a program.
+pub fn diff(old: &Idl, new: &Idl) -> IdlDiff {
+    let mut changes = Vec::new();
+
+    // Instructions.
+    let old_instructions = old
+        .instructions
+        .iter()
+        .map(|v| (v.name.as_str(), v))
+        .collect::<BTreeMap<_, _>>();
+    let new_instructions = new
+        .instructions
+        .iter()
+        .map(|v| (v.name.as_str(), v))
+        .collect::<BTreeMap<_, _>>();
+
+    for (name, old_instruction) in &old_instructions {
+        match new_instructions.get(name) {
+            Some(new_instruction) => {
+                if old_instruction.discriminants != new_instruction.discriminants {
+                    changes.push(IdlChange::InstructionDiscriminantChanged {
+                        name: name.to_string(),
+                    });
+                }
+
+                if old_instruction.account_names != new_instruction.account_names {
+                    changes.push(IdlChange::InstructionAccountsChanged {
+                        name: name.to_string(),
+                    });
+                }
+            }
+            None => changes.push(IdlChange::InstructionRemoved {
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    for name in new_instructions.keys() {
+        if !old_instructions.contains_key(name) {
+            changes.push(IdlChange::InstructionAdded {
+                name: name.to_string(),
+            });
+        }
+    }
+
+    // Accounts.
+    let old_accounts = old.accounts.iter().collect::<BTreeSet<_>>();
+    let new_accounts = new.accounts.iter().collect::<BTreeSet<_>>();
+
+    for name in old_accounts.difference(&new_accounts) {
+        changes.push(IdlChange::AccountRemoved {
+            name: name.to_string(),
+        });
+    }
+
+    for name in old_accounts.intersection(&new_accounts) {
+        if old.account_discriminants.get(*name) != new.account_discriminants.get(*name) {
+            changes.push(IdlChange::AccountDiscriminantChanged {
+                name: name.to_string(),
+            });
+        }
+
+        if old.account_fields.get(*name) != new.account_fields.get(*name) {
+            changes.push(IdlChange::AccountFieldsChanged {
+                name: name.to_string(),
+            });
+        }
+    }
+
+    for name in new_accounts.difference(&old_accounts) {
+        changes.push(IdlChange::AccountAdded {
+            name: name.to_string(),
+        });
+    }
+
+    // Schemas.
+    let old_schemas = old
+        .schemas
+        .iter()
+        .map(|v| (v.name.as_str(), v.definition.as_str()))
+        .collect::<BTreeMap<_, _>>();
+    let new_schemas = new
+        .schemas
+        .iter()
+        .map(|v| (v.name.as_str(), v.definition.as_str()))
+        .collect::<BTreeMap<_, _>>();
+
+    for (name, old_definition) in &old_schemas {
+        match new_schemas.get(name) {
+            Some(new_definition) => {
+                if old_definition != new_definition {
+                    changes.push(IdlChange::SchemaChanged {
+                        name: name.to_string(),
+                    });
+                }
+            }
+            None => changes.push(IdlChange::SchemaRemoved {
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    for name in new_schemas.keys() {
+        if !old_schemas.contains_key(name) {
+            changes.push(IdlChange::SchemaAdded {
+                name: name.to_string(),
+            });
+        }
+    }
+
+    // Constants.
+    let old_constants = old
+        .constants
+        .iter()
+        .map(|v| (v.name.as_str(), v))
+        .collect::<BTreeMap<_, _>>();
+    let new_constants = new
+        .constants
+        .iter()
+        .map(|v| (v.name.as_str(), v))
+        .collect::<BTreeMap<_, _>>();
+
+    for (name, old_constant) in &old_constants {
+        match new_constants.get(name) {
+            Some(new_constant) => {
+                if old_constant != new_constant {
+                    changes.push(IdlChange::ConstantChanged {
+                        name: name.to_string(),
+                    });
+                }
+            }
+            None => changes.push(IdlChange::ConstantRemoved {
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    for name in new_constants.keys() {
+        if !old_constants.contains_key(name) {
+            changes.push(IdlChange::ConstantAdded {
+                name: name.to_string(),
+            });
+        }
+    }
+
+    IdlDiff { changes }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::ts_gen::{IdlField, IdlInstruction, IdlSchema};
+
+    use super::*;
+
+    fn instruction(name: &str, discriminant: u8, account_names: &[&str]) -> IdlInstruction {
+        IdlInstruction {
+            name: name.to_string(),
+            accounts: format!("{}Accounts", name),
+            metas: format!("getMetasOf{}Accounts", name),
+            discriminants: vec![discriminant],
+            account_names: account_names.iter().map(|v| v.to_string()).collect(),
+            write_locks: vec![],
+            unchecked_reasons: BTreeMap::new(),
+        }
+    }
+
+    fn field(name: &str, ty: &str, size: usize) -> IdlField {
+        IdlField {
+            name: name.to_string(),
+            ty: ty.to_string(),
+            offset: Some(1),
+            size: Some(size),
+        }
+    }
+
+    fn schema(name: &str, definition: &str) -> IdlSchema {
+        IdlSchema {
+            name: name.to_string(),
+            definition: definition.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = Idl {
+            name: "program".to_string(),
+            accounts: vec!["A".to_string(), "B".to_string()],
+            account_discriminants: BTreeMap::from([("A".to_string(), 0), ("B".to_string(), 1)]),
+            account_fields: BTreeMap::from([("A".to_string(), vec![field("value", "u8", 1)])]),
+            instructions: vec![
+                instruction("Init", 0, &["a", "b"]),
+                instruction("Close", 1, &["a"]),
+            ],
+            schemas: vec![schema("ASchema", "u8"), schema("BSchema", "u16")],
+            ..Default::default()
+        };
+
+        let unchanged = diff(&old, &old);
+        assert!(unchanged.changes.is_empty());
+        assert!(!unchanged.is_breaking());
+
+        // The generated code is not compared.
+        let mut regenerated = old.clone();
+        regenerated.instructions[0].metas = "getMetasOfInitAccountsV2".to_string();
+        assert!(diff(&old, &regenerated).changes.is_empty());
+
+        let new = Idl {
+            name: "program".to_string(),
+            accounts: vec!["A".to_string(), "C".to_string()],
+            account_discriminants: BTreeMap::from([("A".to_string(), 0), ("C".to_string(), 1)]),
+            account_fields: BTreeMap::from([("A".to_string(), vec![field("value", "u16", 2)])]),
+            instructions: vec![
+                instruction("Init", 2, &["b", "a"]),
+                instruction("Open", 1, &["a"]),
+            ],
+            schemas: vec![schema("ASchema", "u16"), schema("BSchema", "u16")],
+            ..Default::default()
+        };
+
+        let result = diff(&old, &new);
+        assert!(result.is_breaking());
+        assert_eq!(
+            result.changes,
+            vec![
+                IdlChange::InstructionRemoved {
+                    name: "Close".to_string()
+                },
+                IdlChange::InstructionDiscriminantChanged {
+                    name: "Init".to_string()
+                },
+                IdlChange::InstructionAccountsChanged {
+                    name: "Init".to_string()
+                },
+                IdlChange::InstructionAdded {
+                    name: "Open".to_string()
+                },
+                IdlChange::AccountRemoved {
+                    name: "B".to_string()
+                },
+                IdlChange::AccountFieldsChanged {
+                    name: "A".to_string()
+                },
+                IdlChange::AccountAdded {
+                    name: "C".to_string()
+                },
+                IdlChange::SchemaChanged {
+                    name: "ASchema".to_string()
+                },
+            ]
+        );
+        assert_eq!(result.breaking_changes().count(), 6);
+
+        let mut moved = old.clone();
+        moved.account_discriminants.insert("B".to_string(), 2);
+        assert_eq!(
+            diff(&old, &moved).changes,
+            vec![IdlChange::AccountDiscriminantChanged {
+                name: "B".to_string()
+            }]
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::AccountLayout;
+use crate::ts_gen::IdlProgramInfo;
+
+/// A machine-readable description of a program intended for documentation
+/// generators.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramDocs {
+    pub name: String,
+    pub instructions: Vec<DocsInstruction>,
+    pub accounts: Vec<String>,
+    pub errors: Vec<DocsError>,
+
+    /// The metadata declared with `program_info!`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<IdlProgramInfo>,
+}
+
+impl ProgramDocs {
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses the docs from their JSON representation.
+    pub fn from_json(json: &str) -> Result<ProgramDocs, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Serializes the docs into JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("The docs are always serializable")
+    }
+}
+
+/// An instruction of the program.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocsInstruction {
+    pub name: String,
+
+    /// The name of the accounts type of the instruction.
+    #[serde(rename = "accountsType")]
+    pub accounts_type: String,
+
+    /// The accounts of the instruction in order.
+    pub accounts: Vec<DocsAccount>,
+}
+
+/// An account of an instruction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocsAccount {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub writable: Option<bool>,
+    pub signer: Option<bool>,
+    pub optional: bool,
+    #[serde(rename = "minCount")]
+    pub min_count: usize,
+    #[serde(rename = "maxCount")]
+    pub max_count: Option<usize>,
+    pub constraints: Vec<String>,
+
+    /// The seeds of the account if it is a PDA.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pda: Option<String>,
+
+    /// Why the account is not checked if it is an unchecked account.
+    #[serde(
+        rename = "uncheckedReason",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub unchecked_reason: Option<String>,
+}
+
+impl From<&AccountLayout> for DocsAccount {
+    fn from(layout: &AccountLayout) -> Self {
+        DocsAccount {
+            name: layout.name.to_string(),
+            ty: layout.type_name.to_string(),
+            writable: layout.writable,
+            signer: layout.signer,
+            optional: layout.optional,
+            min_count: layout.min_count,
+            max_count: layout.max_count,
+            constraints: layout.constraints.iter().map(|v| v.to_string()).collect(),
+            pda: layout.pda.map(|v| v.to_string()),
+            unchecked_reason: layout.unchecked_reason.map(|v| v.to_string()),
+        }
+    }
+}
+
+/// An error of the program.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocsError {
+    pub name: String,
+    pub code: u32,
+
+    /// The message of the error before formatting its fields.
+    pub message: String,
+}
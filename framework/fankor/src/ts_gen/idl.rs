@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The JSON IDL of a program generated alongside the TypeScript file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Idl {
+    pub name: String,
+    pub constants: Vec<IdlConstant>,
+    pub types: Vec<String>,
+    pub schemas: Vec<IdlSchema>,
+    pub accounts: Vec<String>,
+
+    /// Account name -> discriminant.
+    #[serde(default)]
+    pub account_discriminants: BTreeMap<String, u8>,
+
+    /// Account name -> fields in serialization order. Only for struct accounts.
+    #[serde(default)]
+    pub account_fields: BTreeMap<String, Vec<IdlField>>,
+
+    pub instructions: Vec<IdlInstruction>,
+
+    /// The metadata declared with `program_info!`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<IdlProgramInfo>,
+}
+
+impl Idl {
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses an IDL from its JSON representation.
+    pub fn from_json(json: &str) -> Result<Idl, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Serializes the IDL into JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("The IDL is always serializable")
+    }
+}
+
+/// A constant of the program.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdlConstant {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub value: String,
+}
+
+/// The serialization schema of a type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdlSchema {
+    pub name: String,
+    pub definition: String,
+}
+
+/// A field of an account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+
+    /// The byte offset of the field in the account data. `None` if it
+    /// depends on the size of a previous field.
+    pub offset: Option<usize>,
+
+    /// The serialized size of the field. `None` if it is not fixed.
+    pub size: Option<usize>,
+}
+
+/// An instruction of the program.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+
+    /// The name of the accounts type of the instruction.
+    pub accounts: String,
+
+    /// The code that builds the account metas, which contains the order of the accounts.
+    pub metas: String,
+
+    /// The discriminants the data of the instruction starts with, i.e. the
+    /// one of its group, if any, followed by its own.
+    #[serde(default)]
+    pub discriminants: Vec<u8>,
+
+    /// The fields of the accounts type in order.
+    #[serde(default)]
+    pub account_names: Vec<String>,
+
+    /// The fields of the accounts type that are always write-locked, so that
+    /// clients can schedule in parallel the transactions that do not conflict.
+    /// Fields whose writability depends on runtime values are not included.
+    #[serde(default)]
+    pub write_locks: Vec<String>,
+
+    /// Field -> why the unchecked accounts of the accounts type are not
+    /// checked, declared with `#[account(unchecked_reason = "...")]`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub unchecked_reasons: BTreeMap<String, String>,
+}
+
+/// The metadata of the program.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdlProgramInfo {
+    pub version: String,
+    pub docs: Option<String>,
+    pub repository: Option<String>,
+
+    /// Cluster -> program address.
+    pub deployments: BTreeMap<String, String>,
+}
@@ -1,5 +1,15 @@
 pub use context::*;
+pub use diff::*;
+pub use docs::*;
+pub use idl::*;
+pub use samples::*;
+pub use standalone::*;
 
 pub mod accounts;
 mod context;
+mod diff;
+mod docs;
+mod idl;
+mod samples;
+mod standalone;
 pub mod types;
@@ -0,0 +1,103 @@
+use std::marker::PhantomData;
+
+use borsh::BorshSerialize;
+
+// Helpers to get the default value of a type only when it implements `Default`,
+// and to serialize it only when it implements `BorshSerialize`. They rely on the
+// method resolution preferring the by-value receiver over the autoref one.
+
+#[doc(hidden)]
+pub struct SchemaSampleWrapper<T>(PhantomData<T>);
+
+impl<T> SchemaSampleWrapper<T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> SchemaSampleWrapper<T> {
+        SchemaSampleWrapper(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait SchemaDefaultImplemented {
+    type Value;
+
+    fn __fankor_schema_default(&self) -> Option<Self::Value>;
+}
+
+impl<T: Default> SchemaDefaultImplemented for SchemaSampleWrapper<T> {
+    type Value = T;
+
+    fn __fankor_schema_default(&self) -> Option<T> {
+        Some(T::default())
+    }
+}
+
+#[doc(hidden)]
+pub trait SchemaDefaultNotImplemented {
+    type Value;
+
+    fn __fankor_schema_default(&self) -> Option<Self::Value> {
+        None
+    }
+}
+
+impl<T> SchemaDefaultNotImplemented for &SchemaSampleWrapper<T> {
+    type Value = T;
+}
+
+#[doc(hidden)]
+pub trait SchemaSerializeImplemented {
+    type Value;
+
+    fn __fankor_schema_serialize(&self, value: &Self::Value) -> Option<Vec<u8>>;
+}
+
+impl<T: BorshSerialize> SchemaSerializeImplemented for SchemaSampleWrapper<T> {
+    type Value = T;
+
+    fn __fankor_schema_serialize(&self, value: &T) -> Option<Vec<u8>> {
+        value.try_to_vec().ok()
+    }
+}
+
+#[doc(hidden)]
+pub trait SchemaSerializeNotImplemented {
+    type Value;
+
+    #[allow(unused_variables)]
+    fn __fankor_schema_serialize(&self, value: &Self::Value) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+impl<T> SchemaSerializeNotImplemented for &SchemaSampleWrapper<T> {
+    type Value = T;
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct NoDefault;
+
+    #[test]
+    #[allow(clippy::needless_borrow)]
+    fn test_schema_sample() {
+        let wrapper = SchemaSampleWrapper::<(u8, String)>::new();
+        let value = (&wrapper).__fankor_schema_default().unwrap();
+        assert_eq!(value, (0, String::new()));
+        assert_eq!(
+            (&wrapper).__fankor_schema_serialize(&value),
+            Some(vec![0, 0, 0, 0, 0])
+        );
+
+        let wrapper = SchemaSampleWrapper::<NoDefault>::new();
+        assert!((&wrapper).__fankor_schema_default().is_none());
+        assert_eq!((&wrapper).__fankor_schema_serialize(&NoDefault), None);
+    }
+}
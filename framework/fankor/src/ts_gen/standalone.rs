@@ -0,0 +1,575 @@
+/// The runtime of the standalone TypeScript file: the reader, the writer and
+/// the codecs of the builtin types, implemented with `DataView` and `bigint`
+/// so that the file does not depend on any package.
+///
+/// The values differ from the ones of the regular file: 64 and 128 bit
+/// integers are `bigint`s, public keys are base58 strings, options are
+/// `T | null` and maps are arrays of `[key, value]` pairs.
+pub const STANDALONE_RUNTIME: &str = r#"
+export interface FnkCodec<T> {
+    encode(writer: FnkWriter, value: T): void;
+    decode(reader: FnkReader): T;
+}
+
+export class FnkWriter {
+    bytes = new Uint8Array(256);
+    view = new DataView(this.bytes.buffer);
+    length = 0;
+
+    // METHODS ----------------------------------------------------------------
+
+    reserve(size: number) {
+        if (this.length + size <= this.bytes.length) {
+            return;
+        }
+
+        let capacity = this.bytes.length * 2;
+        while (capacity < this.length + size) {
+            capacity *= 2;
+        }
+
+        const bytes = new Uint8Array(capacity);
+        bytes.set(this.bytes.subarray(0, this.length));
+        this.bytes = bytes;
+        this.view = new DataView(bytes.buffer);
+    }
+
+    writeBytes(bytes: Uint8Array) {
+        this.reserve(bytes.length);
+        this.bytes.set(bytes, this.length);
+        this.length += bytes.length;
+    }
+
+    toBytes() {
+        return this.bytes.slice(0, this.length);
+    }
+}
+
+export class FnkReader {
+    view: DataView;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    constructor(public bytes: Uint8Array, public offset = 0) {
+        this.view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    take(size: number) {
+        const offset = this.offset;
+        if (offset + size > this.bytes.length) {
+            throw new RangeError(`Cannot read ${size} bytes at offset ${offset}`);
+        }
+
+        this.offset += size;
+        return offset;
+    }
+
+    peekByte() {
+        if (this.offset >= this.bytes.length) {
+            throw new RangeError(`Cannot read 1 byte at offset ${this.offset}`);
+        }
+
+        return this.bytes[this.offset];
+    }
+
+    readBytes(size: number) {
+        const offset = this.take(size);
+        return this.bytes.slice(offset, offset + size);
+    }
+}
+
+export function fnkEncode<T>(codec: FnkCodec<T>, value: T): Uint8Array {
+    const writer = new FnkWriter();
+    codec.encode(writer, value);
+    return writer.toBytes();
+}
+
+export function fnkDecode<T>(codec: FnkCodec<T>, bytes: Uint8Array, offset?: number): T {
+    return codec.decode(new FnkReader(bytes, offset));
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+function fnkPrimitive<T>(
+    size: number,
+    get: (view: DataView, offset: number) => T,
+    set: (view: DataView, offset: number, value: T) => void
+): FnkCodec<T> {
+    return {
+        encode(writer, value) {
+            writer.reserve(size);
+            set(writer.view, writer.length, value);
+            writer.length += size;
+        },
+        decode(reader) {
+            return get(reader.view, reader.take(size));
+        },
+    };
+}
+
+const U64_MASK = (BigInt(1) << BigInt(64)) - BigInt(1);
+
+export const fnkUnit: FnkCodec<null> = {
+    encode() {},
+    decode() {
+        return null;
+    },
+};
+export const fnkBool = fnkPrimitive<boolean>(1, (v, o) => v.getUint8(o) !== 0, (v, o, x) => v.setUint8(o, x ? 1 : 0));
+export const fnkU8 = fnkPrimitive<number>(1, (v, o) => v.getUint8(o), (v, o, x) => v.setUint8(o, x));
+export const fnkU16 = fnkPrimitive<number>(2, (v, o) => v.getUint16(o, true), (v, o, x) => v.setUint16(o, x, true));
+export const fnkU32 = fnkPrimitive<number>(4, (v, o) => v.getUint32(o, true), (v, o, x) => v.setUint32(o, x, true));
+export const fnkU64 = fnkPrimitive<bigint>(8, (v, o) => v.getBigUint64(o, true), (v, o, x) => v.setBigUint64(o, x, true));
+export const fnkU128 = fnkPrimitive<bigint>(
+    16,
+    (v, o) => v.getBigUint64(o, true) | (v.getBigUint64(o + 8, true) << BigInt(64)),
+    (v, o, x) => {
+        v.setBigUint64(o, x & U64_MASK, true);
+        v.setBigUint64(o + 8, x >> BigInt(64), true);
+    }
+);
+export const fnkI8 = fnkPrimitive<number>(1, (v, o) => v.getInt8(o), (v, o, x) => v.setInt8(o, x));
+export const fnkI16 = fnkPrimitive<number>(2, (v, o) => v.getInt16(o, true), (v, o, x) => v.setInt16(o, x, true));
+export const fnkI32 = fnkPrimitive<number>(4, (v, o) => v.getInt32(o, true), (v, o, x) => v.setInt32(o, x, true));
+export const fnkI64 = fnkPrimitive<bigint>(8, (v, o) => v.getBigInt64(o, true), (v, o, x) => v.setBigInt64(o, x, true));
+export const fnkI128 = fnkPrimitive<bigint>(
+    16,
+    (v, o) => BigInt.asIntN(128, v.getBigUint64(o, true) | (v.getBigUint64(o + 8, true) << BigInt(64))),
+    (v, o, x) => {
+        const value = BigInt.asUintN(128, x);
+        v.setBigUint64(o, value & U64_MASK, true);
+        v.setBigUint64(o + 8, value >> BigInt(64), true);
+    }
+);
+export const fnkF32 = fnkPrimitive<number>(4, (v, o) => v.getFloat32(o, true), (v, o, x) => v.setFloat32(o, x, true));
+export const fnkF64 = fnkPrimitive<number>(8, (v, o) => v.getFloat64(o, true), (v, o, x) => v.setFloat64(o, x, true));
+
+export function fnkBytes(size: number): FnkCodec<Uint8Array> {
+    return {
+        encode(writer, value) {
+            if (value.length !== size) {
+                throw new RangeError(`Expected ${size} bytes but got ${value.length}`);
+            }
+
+            writer.writeBytes(value);
+        },
+        decode(reader) {
+            return reader.readBytes(size);
+        },
+    };
+}
+
+export const fnkByteVec: FnkCodec<Uint8Array> = {
+    encode(writer, value) {
+        fnkU32.encode(writer, value.length);
+        writer.writeBytes(value);
+    },
+    decode(reader) {
+        return reader.readBytes(fnkU32.decode(reader));
+    },
+};
+
+export const fnkString: FnkCodec<string> = {
+    encode(writer, value) {
+        fnkByteVec.encode(writer, new TextEncoder().encode(value));
+    },
+    decode(reader) {
+        return new TextDecoder().decode(fnkByteVec.decode(reader));
+    },
+};
+
+const BASE58_ALPHABET = '123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz';
+
+export const fnkPublicKey: FnkCodec<string> = {
+    encode(writer, value) {
+        let number = BigInt(0);
+        for (const char of value) {
+            const digit = BASE58_ALPHABET.indexOf(char);
+            if (digit < 0) {
+                throw new Error(`Invalid public key: ${value}`);
+            }
+
+            number = number * BigInt(58) + BigInt(digit);
+        }
+
+        const bytes = new Uint8Array(32);
+        for (let i = 31; i >= 0; i -= 1) {
+            bytes[i] = Number(number & BigInt(0xff));
+            number >>= BigInt(8);
+        }
+
+        if (number !== BigInt(0)) {
+            throw new Error(`Invalid public key: ${value}`);
+        }
+
+        writer.writeBytes(bytes);
+    },
+    decode(reader) {
+        const bytes = reader.readBytes(32);
+        let zeros = 0;
+        while (zeros < bytes.length && bytes[zeros] === 0) {
+            zeros += 1;
+        }
+
+        let number = BigInt(0);
+        for (const byte of bytes) {
+            number = (number << BigInt(8)) | BigInt(byte);
+        }
+
+        let result = '';
+        while (number > BigInt(0)) {
+            result = BASE58_ALPHABET[Number(number % BigInt(58))] + result;
+            number /= BigInt(58);
+        }
+
+        return '1'.repeat(zeros) + result;
+    },
+};
+
+export function fnkOption<T>(codec: FnkCodec<T>): FnkCodec<T | null> {
+    return {
+        encode(writer, value) {
+            if (value === null) {
+                fnkU8.encode(writer, 0);
+            } else {
+                fnkU8.encode(writer, 1);
+                codec.encode(writer, value);
+            }
+        },
+        decode(reader) {
+            return fnkU8.decode(reader) === 0 ? null : codec.decode(reader);
+        },
+    };
+}
+
+export function fnkArray<T>(codec: FnkCodec<T>, size: number): FnkCodec<T[]> {
+    return {
+        encode(writer, value) {
+            if (value.length !== size) {
+                throw new RangeError(`Expected ${size} items but got ${value.length}`);
+            }
+
+            for (const item of value) {
+                codec.encode(writer, item);
+            }
+        },
+        decode(reader) {
+            const result: T[] = [];
+            for (let i = 0; i < size; i += 1) {
+                result.push(codec.decode(reader));
+            }
+
+            return result;
+        },
+    };
+}
+
+function fnkSequence<T>(length: FnkCodec<any>, toLength: (v: number) => any, fromLength: (v: any) => number, codec: FnkCodec<T>): FnkCodec<T[]> {
+    return {
+        encode(writer, value) {
+            length.encode(writer, toLength(value.length));
+            for (const item of value) {
+                codec.encode(writer, item);
+            }
+        },
+        decode(reader) {
+            const size = fromLength(length.decode(reader));
+            const result: T[] = [];
+            for (let i = 0; i < size; i += 1) {
+                result.push(codec.decode(reader));
+            }
+
+            return result;
+        },
+    };
+}
+
+function fnkPair<K, V>(keyCodec: FnkCodec<K>, valueCodec: FnkCodec<V>): FnkCodec<[K, V]> {
+    return {
+        encode(writer, value) {
+            keyCodec.encode(writer, value[0]);
+            valueCodec.encode(writer, value[1]);
+        },
+        decode(reader) {
+            return [keyCodec.decode(reader), valueCodec.decode(reader)];
+        },
+    };
+}
+
+export function fnkVec<T>(codec: FnkCodec<T>): FnkCodec<T[]> {
+    return fnkSequence(fnkU32, (v) => v, (v) => v, codec);
+}
+
+export function fnkMap<K, V>(keyCodec: FnkCodec<K>, valueCodec: FnkCodec<V>): FnkCodec<[K, V][]> {
+    return fnkVec(fnkPair(keyCodec, valueCodec));
+}
+
+export function fnkStruct(fields: () => [string, FnkCodec<any>][]): FnkCodec<any> {
+    let resolved: [string, FnkCodec<any>][] | null = null;
+    const get = () => {
+        if (resolved === null) {
+            resolved = fields();
+        }
+
+        return resolved;
+    };
+
+    return {
+        encode(writer, value) {
+            for (const [name, codec] of get()) {
+                codec.encode(writer, value[name]);
+            }
+        },
+        decode(reader) {
+            const result: any = {};
+            for (const [name, codec] of get()) {
+                result[name] = codec.decode(reader);
+            }
+
+            return result;
+        },
+    };
+}
+
+// The variants are tuples of the discriminant, the name and the codec of the
+// value, if any. When `peek` is set the discriminant is not consumed because
+// it is part of the value, like in the enums of accounts.
+export function fnkEnum(variants: () => [number, string, FnkCodec<any> | null][], peek = false): FnkCodec<any> {
+    let resolved: [number, string, FnkCodec<any> | null][] | null = null;
+    const get = () => {
+        if (resolved === null) {
+            resolved = variants();
+        }
+
+        return resolved;
+    };
+
+    return {
+        encode(writer, value) {
+            const variant = get().find((v) => v[1] === value.type);
+            if (variant === undefined) {
+                throw new Error(`Enum variant not found: ${value.type}`);
+            }
+
+            if (!peek) {
+                fnkU8.encode(writer, variant[0]);
+            }
+
+            variant[2]?.encode(writer, value.value);
+        },
+        decode(reader) {
+            const discriminant = peek ? reader.peekByte() : fnkU8.decode(reader);
+            const variant = get().find((v) => v[0] === discriminant);
+            if (variant === undefined) {
+                throw new Error(`Enum variant not found for discriminant: ${discriminant}`);
+            }
+
+            if (variant[2] === null) {
+                return { type: variant[1] };
+            }
+
+            return { type: variant[1], value: variant[2].decode(reader) };
+        },
+    };
+}
+
+// Prefixes the value with the discriminant of the account and its padding.
+export function fnkAccount(discriminant: number, padding: number, codec: FnkCodec<any>): FnkCodec<any> {
+    return {
+        encode(writer, value) {
+            fnkU8.encode(writer, discriminant);
+            writer.writeBytes(new Uint8Array(padding));
+            codec.encode(writer, value);
+        },
+        decode(reader) {
+            if (fnkU8.decode(reader) !== discriminant) {
+                throw new Error('Invalid discriminant');
+            }
+
+            reader.take(padding);
+            return codec.decode(reader);
+        },
+    };
+}
+
+// Exposes the value as its raw bytes.
+export function fnkOpaque(codec: FnkCodec<any>): FnkCodec<Uint8Array> {
+    return {
+        encode(writer, value) {
+            writer.writeBytes(value);
+        },
+        decode(reader) {
+            const start = reader.offset;
+            codec.decode(reader);
+            return reader.bytes.slice(start, reader.offset);
+        },
+    };
+}
+
+// Discards the value, writing `defaultBytes` instead.
+export function fnkSkip(codec: FnkCodec<any>, defaultBytes: Uint8Array): FnkCodec<undefined> {
+    return {
+        encode(writer) {
+            writer.writeBytes(defaultBytes);
+        },
+        decode(reader) {
+            codec.decode(reader);
+            return undefined;
+        },
+    };
+}
+
+export function fnkUnsupported(type: string): FnkCodec<any> {
+    const fail = () => {
+        throw new Error(`The type ${type} is not supported by the standalone codecs`);
+    };
+
+    return { encode: fail, decode: fail };
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+const U64_MAX = U64_MASK;
+
+function fnkWriteLengthEncoded(writer: FnkWriter, value: bigint, flags: number) {
+    const bytes: number[] = [];
+    while (value > BigInt(0)) {
+        bytes.push(Number(value & BigInt(0xff)));
+        value >>= BigInt(8);
+    }
+
+    fnkU8.encode(writer, (bytes.length - 2) | flags);
+    writer.writeBytes(Uint8Array.from(bytes));
+}
+
+function fnkReadLengthEncoded(reader: FnkReader, length: number) {
+    if (length >= 7) {
+        throw new RangeError('Incorrect length of the variable-length integer');
+    }
+
+    const bytes = reader.readBytes(length + 2);
+    let value = BigInt(0);
+    for (let i = bytes.length - 1; i >= 0; i -= 1) {
+        value = (value << BigInt(8)) | BigInt(bytes[i]);
+    }
+
+    return value;
+}
+
+export const fnkFnkUInt: FnkCodec<bigint> = {
+    encode(writer, value) {
+        if (value < BigInt(0) || value > U64_MAX) {
+            throw new RangeError('FnkUInt must be in range [0, 2^64 - 1]');
+        }
+
+        if (value < BigInt(1) << BigInt(14)) {
+            const low = Number(value & BigInt(0x3f));
+            const high = Number(value >> BigInt(6));
+
+            fnkU8.encode(writer, high !== 0 ? low | 0x40 : low);
+            if (high !== 0) {
+                fnkU8.encode(writer, high);
+            }
+        } else {
+            fnkWriteLengthEncoded(writer, value, 0x80);
+        }
+    },
+    decode(reader) {
+        const first = fnkU8.decode(reader);
+        if ((first & 0x80) !== 0) {
+            return fnkReadLengthEncoded(reader, first & 0x7f);
+        }
+
+        let value = BigInt(first & 0x3f);
+        if ((first & 0x40) !== 0) {
+            value |= BigInt(fnkU8.decode(reader)) << BigInt(6);
+        }
+
+        return value;
+    },
+};
+
+export const fnkFnkInt: FnkCodec<bigint> = {
+    encode(writer, value) {
+        if (value < -(BigInt(1) << BigInt(63)) || value >= BigInt(1) << BigInt(63)) {
+            throw new RangeError('FnkInt must be in range [-2^63, 2^63 - 1]');
+        }
+
+        const isNegative = value < BigInt(0);
+        const abs = isNegative ? -value : value;
+
+        if (abs < BigInt(1) << BigInt(13)) {
+            const low = Number(abs & BigInt(0x1f));
+            const high = Number(abs >> BigInt(5));
+
+            fnkU8.encode(writer, low | (high !== 0 ? 0x40 : 0) | (isNegative ? 0x20 : 0));
+            if (high !== 0) {
+                fnkU8.encode(writer, high);
+            }
+        } else {
+            fnkWriteLengthEncoded(writer, abs, isNegative ? 0xc0 : 0x80);
+        }
+    },
+    decode(reader) {
+        const first = fnkU8.decode(reader);
+        if ((first & 0x80) !== 0) {
+            const abs = fnkReadLengthEncoded(reader, first & 0x3f);
+            return (first & 0x40) !== 0 ? -abs : abs;
+        }
+
+        let abs = BigInt(first & 0x1f);
+        if ((first & 0x40) !== 0) {
+            abs |= BigInt(fnkU8.decode(reader)) << BigInt(5);
+        }
+
+        return (first & 0x20) !== 0 ? -abs : abs;
+    },
+};
+
+const fnkLength = (v: number) => BigInt(v);
+const fromFnkLength = (v: bigint) => Number(v);
+
+export const fnkFnkByteVec: FnkCodec<Uint8Array> = {
+    encode(writer, value) {
+        fnkFnkUInt.encode(writer, BigInt(value.length));
+        writer.writeBytes(value);
+    },
+    decode(reader) {
+        return reader.readBytes(Number(fnkFnkUInt.decode(reader)));
+    },
+};
+
+export const fnkFnkString: FnkCodec<string> = {
+    encode(writer, value) {
+        fnkFnkByteVec.encode(writer, new TextEncoder().encode(value));
+    },
+    decode(reader) {
+        return new TextDecoder().decode(fnkFnkByteVec.decode(reader));
+    },
+};
+
+export function fnkFnkVec<T>(codec: FnkCodec<T>): FnkCodec<T[]> {
+    return fnkSequence(fnkFnkUInt, fnkLength, fromFnkLength, codec);
+}
+
+export function fnkFnkMap<K, V>(keyCodec: FnkCodec<K>, valueCodec: FnkCodec<V>): FnkCodec<[K, V][]> {
+    return fnkFnkVec(fnkPair(keyCodec, valueCodec));
+}
+
+export const fnkFnkRange = fnkStruct(() => [
+    ['from', fnkFnkInt],
+    ['to', fnkFnkInt],
+]);
+
+export const fnkAccountMetaEntry = fnkStruct(() => [
+    ['pubkey', fnkPublicKey],
+    ['isSigner', fnkBool],
+    ['isWritable', fnkBool],
+]);
+"#;
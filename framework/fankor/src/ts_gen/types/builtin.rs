@@ -24,6 +24,10 @@ impl TsTypeGen for () {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.Unit")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkUnit")
+    }
 }
 
 impl TsTypeGen for bool {
@@ -42,6 +46,10 @@ impl TsTypeGen for bool {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.Bool")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkBool")
+    }
 }
 
 impl TsTypeGen for i8 {
@@ -56,6 +64,10 @@ impl TsTypeGen for i8 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.I8")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkI8")
+    }
 }
 
 impl TsTypeGen for i16 {
@@ -70,6 +82,10 @@ impl TsTypeGen for i16 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.I16")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkI16")
+    }
 }
 
 impl TsTypeGen for i32 {
@@ -84,6 +100,10 @@ impl TsTypeGen for i32 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.I32")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkI32")
+    }
 }
 
 impl TsTypeGen for i64 {
@@ -98,6 +118,10 @@ impl TsTypeGen for i64 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.I64")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkI64")
+    }
 }
 
 impl TsTypeGen for i128 {
@@ -112,6 +136,10 @@ impl TsTypeGen for i128 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.I128")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkI128")
+    }
 }
 
 impl TsTypeGen for isize {
@@ -150,6 +178,18 @@ impl TsTypeGen for isize {
             panic!("Unsupported pointer width");
         }
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        let size = size_of::<usize>();
+
+        if size == 8 {
+            Cow::Borrowed("fnkI64")
+        } else if size == 4 {
+            Cow::Borrowed("fnkI32")
+        } else {
+            panic!("Unsupported pointer width");
+        }
+    }
 }
 
 impl TsTypeGen for u8 {
@@ -164,6 +204,10 @@ impl TsTypeGen for u8 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.U8")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkU8")
+    }
 }
 
 impl TsTypeGen for u16 {
@@ -178,6 +222,10 @@ impl TsTypeGen for u16 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.U16")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkU16")
+    }
 }
 
 impl TsTypeGen for u32 {
@@ -192,6 +240,10 @@ impl TsTypeGen for u32 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.U32")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkU32")
+    }
 }
 
 impl TsTypeGen for u64 {
@@ -206,6 +258,10 @@ impl TsTypeGen for u64 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.U64")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkU64")
+    }
 }
 
 impl TsTypeGen for u128 {
@@ -220,6 +276,10 @@ impl TsTypeGen for u128 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.U128")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkU128")
+    }
 }
 
 impl TsTypeGen for usize {
@@ -258,6 +318,18 @@ impl TsTypeGen for usize {
             panic!("Unsupported pointer width");
         }
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        let size = size_of::<usize>();
+
+        if size == 8 {
+            Cow::Borrowed("fnkU64")
+        } else if size == 4 {
+            Cow::Borrowed("fnkU32")
+        } else {
+            panic!("Unsupported pointer width");
+        }
+    }
 }
 
 impl TsTypeGen for f32 {
@@ -272,6 +344,10 @@ impl TsTypeGen for f32 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.F32")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkF32")
+    }
 }
 
 impl TsTypeGen for f64 {
@@ -286,6 +362,10 @@ impl TsTypeGen for f64 {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.F64")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkF64")
+    }
 }
 
 impl TsTypeGen for String {
@@ -300,6 +380,10 @@ impl TsTypeGen for String {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.TString")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkString")
+    }
 }
 
 impl TsTypeGen for Keypair {
@@ -335,6 +419,10 @@ impl TsTypeGen for Pubkey {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.TPublicKey")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkPublicKey")
+    }
 }
 
 impl<'a> TsTypeGen for &'a str {
@@ -349,6 +437,10 @@ impl<'a> TsTypeGen for &'a str {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.TString")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkString")
+    }
 }
 
 impl<'a> TsTypeGen for Cow<'a, str> {
@@ -363,6 +455,10 @@ impl<'a> TsTypeGen for Cow<'a, str> {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.TString")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkString")
+    }
 }
 
 impl TsTypeGen for char {
@@ -377,6 +473,10 @@ impl TsTypeGen for char {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.TString")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkString")
+    }
 }
 
 impl<T: TsTypeGen> TsTypeGen for Option<T> {
@@ -396,6 +496,11 @@ impl<T: TsTypeGen> TsTypeGen for Option<T> {
         Cow::Owned(format!("fnk.OptionSchema<{}>", T::schema_name()))
     }
 
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        let inner_codec = T::generate_standalone_codec(registered_codecs);
+        Cow::Owned(format!("fnkOption({})", inner_codec))
+    }
+
     fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
         let inner_schema = T::generate_schema(registered_schemas);
         Cow::Owned(format!("fnk.Option({})", inner_schema))
@@ -433,6 +538,15 @@ impl<T: TsTypeGen + Any, const S: usize> TsTypeGen for [T; S] {
         }
     }
 
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        let inner_codec = T::generate_standalone_codec(registered_codecs);
+        if TypeId::of::<u8>() == TypeId::of::<T>() {
+            Cow::Owned(format!("fnkBytes({})", S))
+        } else {
+            Cow::Owned(format!("fnkArray({}, {})", inner_codec, S))
+        }
+    }
+
     fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
         let inner_schema = T::generate_schema(registered_schemas);
         if TypeId::of::<u8>() == TypeId::of::<T>() {
@@ -473,6 +587,15 @@ impl<T: TsTypeGen + Any> TsTypeGen for Vec<T> {
         }
     }
 
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        let inner_codec = T::generate_standalone_codec(registered_codecs);
+        if TypeId::of::<u8>() == TypeId::of::<T>() {
+            Cow::Borrowed("fnkByteVec")
+        } else {
+            Cow::Owned(format!("fnkVec({})", inner_codec))
+        }
+    }
+
     fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
         let inner_schema = T::generate_schema(registered_schemas);
         if TypeId::of::<u8>() == TypeId::of::<T>() {
@@ -497,6 +620,11 @@ impl<T: TsTypeGen> TsTypeGen for BTreeSet<T> {
         Cow::Owned(format!("fnk.VecSchema<{}>", T::schema_name()))
     }
 
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        let inner_codec = T::generate_standalone_codec(registered_codecs);
+        Cow::Owned(format!("fnkVec({})", inner_codec))
+    }
+
     fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
         let inner_schema = T::generate_schema(registered_schemas);
         Cow::Owned(format!("fnk.Vec({})", inner_schema))
@@ -529,6 +657,12 @@ impl<K: TsTypeGen, V: TsTypeGen> TsTypeGen for BTreeMap<K, V> {
         ))
     }
 
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        let key_codec = K::generate_standalone_codec(registered_codecs);
+        let value_codec = V::generate_standalone_codec(registered_codecs);
+        Cow::Owned(format!("fnkMap({}, {})", key_codec, value_codec))
+    }
+
     fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
         let inner_key_schema = K::generate_schema(registered_schemas);
         let inner_value_schema = V::generate_schema(registered_schemas);
@@ -2,8 +2,9 @@ use std::any::{Any, TypeId};
 use std::borrow::Cow;
 
 use crate::prelude::{
-    FnkArray, FnkBMap, FnkExtension, FnkInt, FnkMap, FnkRange, FnkSet, FnkString, FnkUInt,
-    FnkURange, FnkVec,
+    AccountMetaEntry, Bps, FnkAccountRef, FnkArray, FnkBMap, FnkBloomFilter, FnkCapVec,
+    FnkExtension, FnkInt, FnkMap, FnkRange, FnkRingBuffer, FnkSet, FnkString, FnkUInt, FnkURange,
+    FnkVec, Percent,
 };
 use crate::traits::{TsTypeGen, TsTypesCache};
 
@@ -19,6 +20,10 @@ impl TsTypeGen for FnkInt {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.FnkInt")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkFnkInt")
+    }
 }
 
 impl TsTypeGen for FnkUInt {
@@ -33,6 +38,46 @@ impl TsTypeGen for FnkUInt {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.FnkUInt")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkFnkUInt")
+    }
+}
+
+impl TsTypeGen for Bps {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("{}", self.get()))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("number")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.TBps")
+    }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkU16")
+    }
+}
+
+impl TsTypeGen for Percent {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("{}", self.get()))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("number")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.TPercent")
+    }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkU8")
+    }
 }
 
 impl TsTypeGen for FnkRange {
@@ -51,6 +96,10 @@ impl TsTypeGen for FnkRange {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.TFnkRange")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkFnkRange")
+    }
 }
 
 impl TsTypeGen for FnkURange {
@@ -83,6 +132,55 @@ impl<'a> TsTypeGen for FnkString<'a> {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.FnkString")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkFnkString")
+    }
+}
+
+impl<T: TsTypeGen> TsTypeGen for FnkAccountRef<T> {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "{} as fnk.AccountRef<{}>",
+            self.key().value(),
+            T::value_type()
+        ))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Owned(format!("fnk.AccountRef<{}>", T::value_type()))
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.TPublicKey")
+    }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkPublicKey")
+    }
+}
+
+impl TsTypeGen for AccountMetaEntry {
+    fn value(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "{{ pubkey: {}, isSigner: {}, isWritable: {} }}",
+            self.pubkey.value(),
+            self.is_signer.value(),
+            self.is_writable.value()
+        ))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("solana.AccountMeta")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.TAccountMetaEntry")
+    }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkAccountMetaEntry")
+    }
 }
 
 impl<T: TsTypeGen + Any, const S: usize> TsTypeGen for FnkArray<T, S> {
@@ -116,6 +214,15 @@ impl<T: TsTypeGen + Any, const S: usize> TsTypeGen for FnkArray<T, S> {
         }
     }
 
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        let inner_codec = T::generate_standalone_codec(registered_codecs);
+        if TypeId::of::<u8>() == TypeId::of::<T>() {
+            Cow::Owned(format!("fnkBytes({})", S))
+        } else {
+            Cow::Owned(format!("fnkArray({}, {})", inner_codec, S))
+        }
+    }
+
     fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
         let inner_schema = T::generate_schema(registered_schemas);
         if TypeId::of::<u8>() == TypeId::of::<T>() {
@@ -156,6 +263,15 @@ impl<T: TsTypeGen + Any> TsTypeGen for FnkVec<T> {
         }
     }
 
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        let inner_codec = T::generate_standalone_codec(registered_codecs);
+        if TypeId::of::<u8>() == TypeId::of::<T>() {
+            Cow::Borrowed("fnkFnkByteVec")
+        } else {
+            Cow::Owned(format!("fnkFnkVec({})", inner_codec))
+        }
+    }
+
     fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
         let inner_schema = T::generate_schema(registered_schemas);
         if TypeId::of::<u8>() == TypeId::of::<T>() {
@@ -166,6 +282,80 @@ impl<T: TsTypeGen + Any> TsTypeGen for FnkVec<T> {
     }
 }
 
+impl<T: TsTypeGen> TsTypeGen for FnkCapVec<T> {
+    fn value(&self) -> Cow<'static, str> {
+        let values = self.iter().map(|v| v.value()).collect::<Vec<_>>();
+        Cow::Owned(format!(
+            "{{ values: [{}], byteCapacity: {} }}",
+            values.join(","),
+            self.byte_capacity()
+        ))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Owned(format!("fnk.FnkCapVecValue<{}>", T::value_type()))
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Owned(format!("fnk.FnkCapVecSchema<{}>", T::schema_name()))
+    }
+
+    fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
+        let inner_schema = T::generate_schema(registered_schemas);
+        Cow::Owned(format!("fnk.FnkCapVec({})", inner_schema))
+    }
+}
+
+impl<T: TsTypeGen, const N: usize> TsTypeGen for FnkRingBuffer<T, N> {
+    fn value(&self) -> Cow<'static, str> {
+        let slots = self.slots().iter().map(|v| v.value()).collect::<Vec<_>>();
+        Cow::Owned(format!(
+            "{{ sequence: new BN(\"{}\"), slots: [{}] }}",
+            self.sequence(),
+            slots.join(",")
+        ))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Owned(format!("fnk.FnkRingBufferValue<{}>", T::value_type()))
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Owned(format!("fnk.FnkRingBufferSchema<{}>", T::schema_name()))
+    }
+
+    fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
+        let inner_schema = T::generate_schema(registered_schemas);
+        Cow::Owned(format!(
+            "fnk.FnkRingBuffer({{ schema: {}, size: {} }})",
+            inner_schema, N
+        ))
+    }
+}
+
+impl TsTypeGen for FnkBloomFilter {
+    fn value(&self) -> Cow<'static, str> {
+        let bits = self
+            .bits()
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        Cow::Owned(format!(
+            "{{ hashCount: {}, bits: new Uint8Array([{}]) }}",
+            self.hash_count(),
+            bits.join(",")
+        ))
+    }
+
+    fn value_type() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkBloomFilterValue")
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("fnk.FnkBloomFilter")
+    }
+}
+
 impl<T: TsTypeGen> TsTypeGen for FnkSet<T> {
     fn value(&self) -> Cow<'static, str> {
         let values = self.iter().map(|v| v.value()).collect::<Vec<_>>();
@@ -180,6 +370,11 @@ impl<T: TsTypeGen> TsTypeGen for FnkSet<T> {
         Cow::Owned(format!("fnk.FnkVecSchema<{}>", T::schema_name()))
     }
 
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        let inner_codec = T::generate_standalone_codec(registered_codecs);
+        Cow::Owned(format!("fnkFnkVec({})", inner_codec))
+    }
+
     fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
         let inner_schema = T::generate_schema(registered_schemas);
         Cow::Owned(format!("fnk.FnkVec({})", inner_schema))
@@ -212,6 +407,12 @@ impl<K: TsTypeGen, V: TsTypeGen> TsTypeGen for FnkMap<K, V> {
         ))
     }
 
+    fn generate_standalone_codec(registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        let key_codec = K::generate_standalone_codec(registered_codecs);
+        let value_codec = V::generate_standalone_codec(registered_codecs);
+        Cow::Owned(format!("fnkFnkMap({}, {})", key_codec, value_codec))
+    }
+
     fn generate_schema(registered_schemas: &mut TsTypesCache) -> Cow<'static, str> {
         let inner_key_schema = K::generate_schema(registered_schemas);
         let inner_value_schema = V::generate_schema(registered_schemas);
@@ -274,4 +475,8 @@ impl TsTypeGen for FnkExtension {
     fn schema_name() -> Cow<'static, str> {
         Cow::Borrowed("fnk.U8")
     }
+
+    fn generate_standalone_codec(_registered_codecs: &mut TsTypesCache) -> Cow<'static, str> {
+        Cow::Borrowed("fnkU8")
+    }
 }
@@ -0,0 +1,128 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+use crate::models::FankorContext;
+
+/// Transfers `amount` lamports from `from`, which must be owned by the program,
+/// to `to` by modifying their balances directly instead of invoking the system
+/// program. `from` must remain rent-exempt after the transfer.
+///
+/// Note: the runtime only checks the balances of the accounts at the end of the
+/// instruction or in a CPI that includes both accounts, so a CPI that includes
+/// only one of them after this transfer will fail.
+pub fn transfer_lamports<'info>(
+    context: &FankorContext<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> FankorResult<()> {
+    transfer_lamports_with_rent(context.program_id(), from, to, amount, &Rent::get()?)
+}
+
+fn transfer_lamports_with_rent<'a, 'info>(
+    program_id: &Pubkey,
+    from: &'a AccountInfo<'info>,
+    to: &'a AccountInfo<'info>,
+    amount: u64,
+    rent: &Rent,
+) -> FankorResult<()> {
+    if from.owner != program_id {
+        return Err(FankorErrorCode::AccountNotOwnedByProgram {
+            address: *from.key,
+            action: "transfer lamports",
+        }
+        .into());
+    }
+
+    for info in [from, to] {
+        if !info.is_writable {
+            return Err(FankorErrorCode::ReadonlyAccountModification {
+                address: *info.key,
+                action: "transfer lamports",
+            }
+            .into());
+        }
+    }
+
+    let from_lamports = from.lamports();
+    let remaining =
+        from_lamports
+            .checked_sub(amount)
+            .ok_or(FankorErrorCode::InsufficientLamports {
+                address: *from.key,
+                balance: from_lamports,
+                amount,
+            })?;
+
+    if remaining < rent.minimum_balance(from.data_len()) {
+        return Err(FankorErrorCode::AccountNotRentExempt { account: *from.key }.into());
+    }
+
+    if amount == 0 || from.key == to.key {
+        return Ok(());
+    }
+
+    let to_lamports = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(FankorErrorCode::ArithmeticOverflow { operation: "add" })?;
+
+    **from.lamports.borrow_mut() = remaining;
+    **to.lamports.borrow_mut() = to_lamports;
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_transfer_lamports() {
+        let program_id = Pubkey::new_unique();
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let rent = Rent::default();
+        let minimum_balance = rent.minimum_balance(10);
+        let mut from_lamports = minimum_balance + 100;
+        let mut to_lamports = 5;
+        let mut from_data = vec![0; 10];
+        let mut to_data = vec![];
+        let mut from = create_account_info_for_tests(&mut from_lamports, &mut from_data);
+        let mut to = create_account_info_for_tests(&mut to_lamports, &mut to_data);
+        from.key = &keys[0];
+        from.owner = &program_id;
+        to.key = &keys[1];
+
+        // Readonly accounts.
+        assert!(transfer_lamports_with_rent(&program_id, &from, &to, 10, &rent).is_err());
+
+        from.is_writable = true;
+        to.is_writable = true;
+
+        // Not owned by the program.
+        assert!(transfer_lamports_with_rent(&program_id, &to, &from, 1, &rent).is_err());
+
+        transfer_lamports_with_rent(&program_id, &from, &to, 60, &rent).unwrap();
+        assert_eq!(from.lamports(), minimum_balance + 40);
+        assert_eq!(to.lamports(), 65);
+
+        // Breaks the rent exemption.
+        assert!(transfer_lamports_with_rent(&program_id, &from, &to, 41, &rent).is_err());
+
+        // Underflow.
+        assert!(transfer_lamports_with_rent(&program_id, &from, &to, u64::MAX, &rent).is_err());
+
+        transfer_lamports_with_rent(&program_id, &from, &to, 40, &rent).unwrap();
+        assert_eq!(from.lamports(), minimum_balance);
+        assert_eq!(to.lamports(), 105);
+    }
+}
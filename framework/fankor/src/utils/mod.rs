@@ -1,9 +1,12 @@
 use std::any::TypeId;
 
 pub mod close;
+pub mod lamports;
 pub mod realloc;
 pub mod rent;
 pub mod seeds;
+pub mod signers;
+pub mod upgradeable;
 pub mod writers;
 
 /// Gets the type identifier of a given value.
@@ -1,11 +1,12 @@
 use std::cmp::Ordering;
 
 use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
 use solana_program::rent::Rent;
 use solana_program::sysvar::Sysvar;
 
 use crate::cpi;
-use crate::cpi::system_program::CpiTransfer;
+use crate::cpi::system_program::{CpiAllocate, CpiAssign, CpiTransfer};
 use crate::errors::{FankorErrorCode, FankorResult};
 use crate::models::{Program, System};
 
@@ -92,3 +93,50 @@ pub(crate) fn make_rent_exempt<'info>(
         }
     }
 }
+
+/// Creates `info` with `space` bytes owned by `owner`. Unlike `create_account`,
+/// it does not fail if the account already holds lamports, e.g. because anyone
+/// transferred them to its address beforehand: only the missing lamports to
+/// cover the rent are transferred from `payer`, then it is allocated and assigned.
+pub(crate) fn create_rent_exempt_account<'info>(
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+    payer: &AccountInfo<'info>,
+    info: &AccountInfo<'info>,
+    system_program: &Program<System>,
+) -> FankorResult<()> {
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(info.lamports());
+
+    if lamports > 0 {
+        cpi::system_program::transfer(
+            system_program,
+            CpiTransfer {
+                from: payer.clone(),
+                to: info.clone(),
+            },
+            lamports,
+            &[],
+        )?;
+    }
+
+    cpi::system_program::allocate(
+        system_program,
+        CpiAllocate {
+            account_to_allocate: info.clone(),
+        },
+        space as u64,
+        signer_seeds,
+    )?;
+
+    cpi::system_program::assign(
+        system_program,
+        CpiAssign {
+            account_to_assign: info.clone(),
+        },
+        owner,
+        signer_seeds,
+    )
+}
@@ -0,0 +1,71 @@
+use solana_program::account_info::AccountInfo;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+
+/// Checks that at least `required` of the `candidates` have signed the
+/// transaction, e.g. to implement M-of-N authorities. Accounts that appear
+/// more than once are counted only once.
+pub fn verify_threshold_signers(required: u8, candidates: &[&AccountInfo]) -> FankorResult<()> {
+    let mut signers = Vec::with_capacity(candidates.len());
+
+    for candidate in candidates {
+        if candidate.is_signer && !signers.contains(&candidate.key) {
+            signers.push(candidate.key);
+        }
+    }
+
+    if signers.len() < required as usize {
+        return Err(FankorErrorCode::NotEnoughSigners {
+            required,
+            actual: signers.len(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::tests::create_account_info_for_tests;
+
+    use super::*;
+
+    #[test]
+    fn test_verify_threshold_signers() {
+        let keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut lamports = [0; 3];
+        let mut data: [Vec<u8>; 3] = Default::default();
+        let mut infos = lamports
+            .iter_mut()
+            .zip(data.iter_mut())
+            .zip(keys.iter())
+            .map(|((lamports, data), key)| {
+                let mut info = create_account_info_for_tests(lamports, data);
+                info.key = key;
+                info
+            })
+            .collect::<Vec<_>>();
+        infos[0].is_signer = true;
+        infos[1].is_signer = true;
+
+        let candidates = infos.iter().collect::<Vec<_>>();
+        assert!(verify_threshold_signers(0, &candidates).is_ok());
+        assert!(verify_threshold_signers(2, &candidates).is_ok());
+        assert!(verify_threshold_signers(3, &candidates).is_err());
+
+        // Duplicated signers only count once.
+        let candidates = [&infos[0], &infos[0], &infos[2]];
+        assert!(verify_threshold_signers(2, &candidates).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::bpf_loader_upgradeable;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{FankorErrorCode, FankorResult};
+
+/// The discriminant of the `Program` variant of the upgradeable loader state.
+const PROGRAM_DISCRIMINANT: u32 = 2;
+
+/// The discriminant of the `ProgramData` variant of the upgradeable loader state.
+const PROGRAM_DATA_DISCRIMINANT: u32 = 3;
+
+/// Checks that `program_data` is the ProgramData account of `program`, i.e.
+/// both are owned by the upgradeable loader and the program points to it.
+pub fn verify_program_data(program: &AccountInfo, program_data: &AccountInfo) -> FankorResult<()> {
+    let error = || FankorErrorCode::InvalidProgramDataAccount {
+        program: *program.key,
+        program_data: *program_data.key,
+    };
+
+    if program.owner != &bpf_loader_upgradeable::ID
+        || program_data.owner != &bpf_loader_upgradeable::ID
+    {
+        return Err(error().into());
+    }
+
+    let data = program.try_borrow_data()?;
+    match read_program_data_address(&data) {
+        Some(address) if &address == program_data.key => Ok(()),
+        _ => Err(error().into()),
+    }
+}
+
+/// Checks that the upgrade authority stored in the `program_data` account is
+/// `authority`, or that the program is immutable if it is `None`.
+pub fn verify_upgrade_authority<'a>(
+    program_data: &AccountInfo,
+    authority: impl Into<Option<&'a Pubkey>>,
+) -> FankorResult<()> {
+    let authority = authority.into();
+
+    if program_data.owner != &bpf_loader_upgradeable::ID {
+        return Err(FankorErrorCode::UpgradeAuthorityMismatch {
+            program_data: *program_data.key,
+        }
+        .into());
+    }
+
+    let data = program_data.try_borrow_data()?;
+    match read_upgrade_authority(&data) {
+        Some(actual) if actual.as_ref() == authority => Ok(()),
+        _ => Err(FankorErrorCode::UpgradeAuthorityMismatch {
+            program_data: *program_data.key,
+        }
+        .into()),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Reads the ProgramData address of the data of an upgradeable program account:
+/// `discriminant: u32 + programdata_address: Pubkey`.
+fn read_program_data_address(data: &[u8]) -> Option<Pubkey> {
+    if u32::from_le_bytes(data.get(0..4)?.try_into().unwrap()) != PROGRAM_DISCRIMINANT {
+        return None;
+    }
+
+    Some(Pubkey::new_from_array(data.get(4..36)?.try_into().unwrap()))
+}
+
+/// Reads the upgrade authority of the data of a ProgramData account:
+/// `discriminant: u32 + slot: u64 + upgrade_authority_address: Option<Pubkey>`.
+fn read_upgrade_authority(data: &[u8]) -> Option<Option<Pubkey>> {
+    if u32::from_le_bytes(data.get(0..4)?.try_into().unwrap()) != PROGRAM_DATA_DISCRIMINANT {
+        return None;
+    }
+
+    match data.get(12)? {
+        0 => Some(None),
+        1 => Some(Some(Pubkey::new_from_array(
+            data.get(13..45)?.try_into().unwrap(),
+        ))),
+        _ => None,
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_read_program_data_address() {
+        let address = Pubkey::new_unique();
+        let mut data = PROGRAM_DISCRIMINANT.to_le_bytes().to_vec();
+        data.extend_from_slice(address.as_ref());
+
+        assert_eq!(read_program_data_address(&data), Some(address));
+        assert_eq!(read_program_data_address(&data[..20]), None);
+
+        data[0] = PROGRAM_DATA_DISCRIMINANT as u8;
+        assert_eq!(read_program_data_address(&data), None);
+    }
+
+    #[test]
+    fn test_read_upgrade_authority() {
+        let authority = Pubkey::new_unique();
+        let mut data = PROGRAM_DATA_DISCRIMINANT.to_le_bytes().to_vec();
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(&[9; 10]);
+
+        assert_eq!(read_upgrade_authority(&data), Some(Some(authority)));
+
+        data[12] = 0;
+        assert_eq!(read_upgrade_authority(&data), Some(None));
+
+        data[12] = 2;
+        assert_eq!(read_upgrade_authority(&data), None);
+        assert_eq!(read_upgrade_authority(&data[..10]), None);
+    }
+}
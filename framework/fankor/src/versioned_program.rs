@@ -0,0 +1,26 @@
+use borsh::BorshSerialize;
+use solana_program::program::set_return_data;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::FankorResult;
+
+/// The discriminant of the `__version` instruction in the programs that enable it
+/// with `#[program(versioned)]`.
+pub const VERSION_INSTRUCTION_DISCRIMINANT: u8 = u8::MAX - 1;
+
+/// Processes the `__version` instruction writing `version` into the return data
+/// as a borsh string.
+pub fn process_version_instruction(version: &str) -> FankorResult<()> {
+    set_return_data(&version.try_to_vec().unwrap());
+
+    Ok(())
+}
+
+/// Creates a new `__version` instruction. It does not require any account.
+pub fn create_version_instruction(program_id: &Pubkey) -> solana_program::instruction::Instruction {
+    solana_program::instruction::Instruction {
+        program_id: *program_id,
+        accounts: Vec::new(),
+        data: vec![VERSION_INSTRUCTION_DISCRIMINANT],
+    }
+}
@@ -3,6 +3,7 @@ use fankor::prelude::*;
 use crate::accounts::ProgramAccountDiscriminant;
 
 #[account(base = ProgramAccount)]
+#[zc_derive(Clone, Debug, PartialEq, Eq)]
 pub enum EnumAccountData {
     A,
     B(u32),
@@ -34,11 +35,13 @@ mod test {
 
         assert_eq!(value.byte_size(), 1 + 1 + 4 + 4 + 4);
         assert_eq!(EnumAccountData::min_byte_size(), 1 + 1);
+        assert_eq!(EnumAccountData::min_account_size(), 1 + 1 + 4 + 4);
 
         let value = ZeroCopyEnumWithoutValues::A;
 
         assert_eq!(value.byte_size(), 1);
         assert_eq!(ZeroCopyEnumWithoutValues::min_byte_size(), 1);
+        assert_eq!(ZeroCopyEnumWithoutValues::min_account_size(), 1);
     }
 
     #[test]
@@ -0,0 +1,12 @@
+use fankor::prelude::*;
+
+use crate::accounts::ProgramAccountDiscriminant;
+
+/// An account that can be grown by the grow instruction of the program.
+#[account(base = ProgramAccount)]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct GrowableAccountData {
+    // Must be the first field, see `GrowableAccount`.
+    pub target_size: u64,
+    pub data: Vec<u8>,
+}
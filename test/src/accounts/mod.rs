@@ -1,8 +1,14 @@
 pub use enum_account::*;
 use fankor::prelude::*;
+pub use growable_account::*;
+pub use pod_account::*;
+pub use singleton_account::*;
 pub use struct_account::*;
 
 mod enum_account;
+mod growable_account;
+mod pod_account;
+mod singleton_account;
 mod struct_account;
 
 #[accounts]
@@ -12,6 +18,9 @@ pub enum ProgramAccount {
     StructAccountData2,
     ZeroCopyStructAccountData,
     EnumAccountData,
+    PodAccountData,
+    GlobalConfig,
+    GrowableAccountData,
 }
 
 #[accounts(base = ProgramAccount)]
@@ -109,4 +118,50 @@ mod test {
         let data = info.try_borrow_data().unwrap();
         assert_eq!(*data, &vector_save);
     }
+
+    #[test]
+    #[cfg(feature = "client")]
+    fn test_decode() {
+        let mut vector = vec![5u8, 1u8, 0, 0, 0, 4u8, 0, 0, 0];
+        vector.extend_from_slice(b"test");
+
+        let value = ProgramAccount::decode(&vector).unwrap();
+        assert_eq!(
+            value.unwrap_struct_account_data().unwrap(),
+            StructAccountData {
+                value1: 1,
+                value2: "test".to_string(),
+            }
+        );
+
+        let value = ProgramAccountSubSet::decode(&vector).unwrap();
+        assert!(value.struct_account_data_as_ref().is_some());
+
+        // Wrong discriminants.
+        vector[0] = 0;
+        assert!(ProgramAccount::decode(&vector).is_err());
+        assert!(ProgramAccount::decode(&[]).is_err());
+
+        vector[0] = 6;
+        assert!(ProgramAccountSubSet::decode(&vector).is_err());
+    }
+
+    #[test]
+    fn test_account_registry() {
+        let registry = ProgramAccount::account_registry();
+
+        assert_eq!(registry.entries.len(), 7);
+        assert_eq!(registry.entries[0].name, "StructAccountData");
+        assert_eq!(registry.entries[0].discriminant, 5);
+        assert_eq!(
+            registry.entries[0].layout_hash,
+            <StructAccountData as AccountType>::layout_hash()
+        );
+        assert_ne!(registry.entries[0].layout_hash, 0);
+        assert_ne!(registry.entries[0].layout_hash, registry.entries[1].layout_hash);
+        assert_eq!(
+            registry.get_by_discriminant(6).map(|v| v.name.as_str()),
+            Some("StructAccountData2")
+        );
+    }
 }
@@ -0,0 +1,48 @@
+use bytemuck::{Pod, Zeroable};
+use fankor::prelude::*;
+
+use crate::accounts::ProgramAccountDiscriminant;
+
+#[account(base = ProgramAccount, pod)]
+#[derive(Debug, Copy, Clone, PartialEq, Pod, Zeroable)]
+pub struct PodAccountData {
+    pub authority: Pubkey,
+    pub counter: u64,
+    pub limit: u32,
+    pub flags: u32,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_layout() {
+        let value = PodAccountData {
+            authority: Pubkey::new_unique(),
+            counter: 7,
+            limit: 10,
+            flags: 1,
+        };
+
+        let bytes = value.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), PodAccountData::ACCOUNT_SIZE);
+        assert_eq!(PodAccountData::min_byte_size(), PodAccountData::ACCOUNT_SIZE);
+        assert_eq!(bytes[0], PodAccountData::discriminant());
+        assert_eq!(PodAccountData::OFFSET_COUNTER, 8 + 32);
+        assert_eq!(&bytes[40..48], &7u64.to_le_bytes());
+
+        let mut lamports = 0;
+        let mut vector = bytes.clone();
+        let info = create_account_info_for_tests(&mut lamports, &mut vector);
+        let zc = Zc::<PodAccountData>::new_unchecked(&info, 0);
+
+        assert_eq!(zc.try_value().unwrap(), value);
+        assert_eq!(zc.zc_value().unwrap().counter().unwrap().offset(), 40);
+        assert_eq!(PodAccountData::deserialize(&mut bytes.as_slice()).unwrap(), value);
+    }
+}
@@ -0,0 +1,36 @@
+use fankor::prelude::*;
+
+use crate::accounts::ProgramAccountDiscriminant;
+
+#[account(base = ProgramAccount, singleton)]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct GlobalConfig {
+    pub authority: Pubkey,
+    pub fee: u64,
+
+    // Internal bit flags, only exposed as raw bytes in TypeScript.
+    #[fankor(ts_opaque)]
+    pub flags: u32,
+
+    // Space reserved for future fields, hidden in TypeScript.
+    #[fankor(ts_skip)]
+    pub reserved: FnkArray<u8, 32>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_singleton_address() {
+        assert_eq!(GlobalConfig::SINGLETON_SEED, b"GlobalConfig");
+        assert_eq!(
+            GlobalConfig::singleton_address(),
+            Pubkey::find_program_address(&[b"GlobalConfig"], &crate::ID)
+        );
+    }
+}
@@ -2,20 +2,30 @@ use fankor::prelude::*;
 
 use crate::accounts::ProgramAccountDiscriminant;
 
-#[account(base = ProgramAccount)]
+#[account(base = ProgramAccount, canonical)]
 #[derive(Debug, PartialEq)]
 pub struct StructAccountData {
     pub value1: u32,
     pub value2: String,
 }
 
-#[account(base = ProgramAccount)]
+#[account(base = ProgramAccount, legacy_discriminants = [200])]
+#[derive(Migratable)]
+#[migratable(from = StructAccountData, with = migrate_struct_account_data)]
 pub struct StructAccountData2 {
     pub value: String,
 }
 
+fn migrate_struct_account_data(old: &StructAccountData) -> FankorResult<StructAccountData2> {
+    Ok(StructAccountData2 {
+        value: format!("{}:{}", old.value1, old.value2),
+    })
+}
+
 #[account(base = ProgramAccount)]
 #[derive(FieldOffsets)]
+#[zc_derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[filter_derive(Clone, Debug, PartialEq)]
 pub struct ZeroCopyStructAccountData {
     pub value1: u32,
     pub value2: String,
@@ -24,12 +34,23 @@ pub struct ZeroCopyStructAccountData {
     pub value5: FnkExtension,
 }
 
+#[derive(FankorZeroCopy)]
+pub struct ZeroCopyGenericStruct<T> {
+    pub value1: T,
+    pub value2: FnkArray<Option<u32>, 2>,
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "client")]
+    use fankor::prelude::solana_client::rpc_filter::RpcFilterType;
+
+    use crate::accounts::EnumAccountData;
+
     use super::*;
 
     #[test]
@@ -41,6 +62,118 @@ mod test {
 
         assert_eq!(value.byte_size(), 1 + 4 + 4 + 4);
         assert_eq!(StructAccountData::min_byte_size(), 1 + 4 + 4);
+        assert_eq!(StructAccountData::min_account_size(), 1 + 4 + 4);
+    }
+
+    #[test]
+    fn test_builder() {
+        let value = StructAccountData::builder()
+            .try_value1(5u64)
+            .value2("test".to_string())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            value,
+            StructAccountData {
+                value1: 5,
+                value2: "test".to_string(),
+            }
+        );
+
+        let value = StructAccountDataBuilder::from(value)
+            .value1(7)
+            .build()
+            .unwrap();
+        assert_eq!(value.value1, 7);
+
+        assert!(StructAccountData::builder().value1(5).build().is_err());
+        assert!(StructAccountData::builder()
+            .try_value1(u64::MAX)
+            .value2("test".to_string())
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_canonical() {
+        let value = StructAccountData {
+            value1: 5,
+            value2: "test".to_string(),
+        };
+        let bytes = value.try_to_canonical_vec().unwrap();
+
+        assert_eq!(bytes, value.try_to_vec().unwrap());
+        assert_eq!(bytes[0], StructAccountData::discriminant());
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(StructAccountData::OFFSET_VALUE1, 1);
+        assert_eq!(StructAccountData::OFFSET_VALUE2, 1 + 4);
+        assert_eq!(StructAccountData2::OFFSET_VALUE, 1);
+        assert_eq!(ZeroCopyStructAccountData::OFFSET_VALUE2, 1 + 4);
+    }
+
+    #[test]
+    fn test_field_readers() {
+        let value = StructAccountData {
+            value1: 7,
+            value2: "test".to_string(),
+        };
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = value.try_to_vec().unwrap();
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+
+        assert_eq!(StructAccountData::read_field_value1(&info).unwrap(), 7);
+        assert_eq!(StructAccountData::read_field_value2(&info).unwrap(), "test");
+        assert!(StructAccountData2::read_field_value(&info).is_err());
+    }
+
+    #[test]
+    fn test_field_layout() {
+        let layout = ZeroCopyStructAccountData::field_layout();
+
+        assert_eq!(layout.len(), 5);
+        assert_eq!(layout[0].name, "value1");
+        assert_eq!((layout[0].offset, layout[0].size), (Some(1), Some(4)));
+        assert_eq!(layout[1].type_name, "String");
+        assert_eq!((layout[1].offset, layout[1].size), (Some(5), None));
+        assert_eq!((layout[2].offset, layout[2].size), (None, None));
+    }
+
+    #[test]
+    fn test_legacy_discriminants() {
+        let discriminant = StructAccountData2::discriminant();
+        assert!(StructAccountData2::check_discriminant(discriminant));
+        assert!(StructAccountData2::check_discriminant(200));
+        assert!(!StructAccountData2::check_discriminant(201));
+
+        let mut data = vec![200u8, 1, 0, 0, 0, b'a'];
+        let value = StructAccountData2::deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(value.value, "a");
+
+        data[0] = 201;
+        assert!(StructAccountData2::deserialize(&mut data.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_generic_size() {
+        assert_eq!(ZeroCopyGenericStruct::<u64>::min_byte_size(), 8 + 2);
+        assert_eq!(
+            ZeroCopyGenericStruct::<EnumAccountData>::min_account_size(),
+            1 + 1 + 4 + 4 + 2 * (1 + 4)
+        );
     }
 
     #[test]
@@ -94,4 +227,23 @@ mod test {
         let data = info.try_borrow_data().unwrap();
         assert_eq!(*data, &vector_save);
     }
+
+    #[test]
+    #[cfg(feature = "client")]
+    fn test_filter() {
+        let filters = StructAccountData::filter()
+            .field_value2(&"test".to_string())
+            .unwrap()
+            .build();
+
+        assert_eq!(filters.len(), 2);
+
+        match &filters[1] {
+            RpcFilterType::Memcmp(v) => {
+                assert_eq!(v.offset, 1 + 4);
+                assert_eq!(*v.bytes().unwrap(), vec![4u8, 0, 0, 0, b't', b'e', b's', b't']);
+            }
+            _ => panic!("Incorrect filter"),
+        }
+    }
 }
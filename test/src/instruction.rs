@@ -9,6 +9,8 @@ use crate::errors::Errors;
 #[instruction(initial_validation, final_validation)]
 #[allow(dead_code)]
 pub struct StructAccounts<'info> {
+    #[argument(validate = args.arg2 > 0)]
+    #[argument(validate = args.arg3 <= 1_000 @ Errors::A)]
     pub args: Argument<InstructionArgs>,
 
     pub args_rest: RestArguments,
@@ -25,6 +27,7 @@ pub struct StructAccounts<'info> {
     #[account(executable = false)]
     #[account(rent_exempt = false)]
     #[account(signer = false)]
+    #[account(tombstoned = false)]
     #[account(pda = [crate::ID.as_ref(), & self.account2.data().value1.to_le_bytes(), & self.args.arg2.to_le_bytes()])]
     #[account(pda_program_id = & Pubkey::default())]
     pub account2: Account<'info, StructAccountData>,
@@ -32,8 +35,11 @@ pub struct StructAccounts<'info> {
     #[account(pda_bytes = vec![1, 2, 3])]
     pub account3: Option<Account<'info, StructAccountData>>,
 
+    #[account(tombstoned = false)]
+    #[account(unchecked_reason = "Only receives the rent of zero_copy_account")]
     pub unchecked_account: UncheckedAccount<'info>,
 
+    #[account(rent_to = unchecked_account)]
     pub zero_copy_account: ZcAccount<'info, ZeroCopyStructAccountData>,
 
     pub single_either: SingleEither<
@@ -44,13 +50,22 @@ pub struct StructAccounts<'info> {
     #[account(address = & crate::ID)]
     pub program: Program<'info, System>,
 
+    #[account(program_data = self.external_program_data)]
+    #[account(upgrade_authority = & crate::ID)]
+    pub external_program: Program<'info, crate::ExternalProgram>,
+
+    #[account(unchecked_reason = "Checked by the program_data argument of external_program")]
+    pub external_program_data: UncheckedAccount<'info>,
+
+    #[account(each(owner = & crate::ID, writable))]
+    #[account(unique)]
     pub list: Vec<Account<'info, StructAccountData>>,
 
     pub either: Either<Account<'info, StructAccountData>, Account<'info, StructAccountData2>>,
 
     pub uninitialized: UninitializedAccount<'info>,
 
-    pub maybe_uninitialized: MaybeUninitialized<'info, Account<'info, StructAccountData>>,
+    pub maybe_uninitialized: MaybeUninitializedAccount<'info, StructAccountData>,
 
     pub other_struct: Box<StructAccountsWithoutAssociatedType<'info>>,
 
@@ -84,7 +99,7 @@ impl<'info> StructAccounts<'info> {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
-#[instruction(initial_validation, final_validation)]
+#[instruction(initial_validation, final_validation, max_data_len = 256, max_accounts = 32)]
 #[allow(dead_code)]
 pub struct StructAccountsWithoutAssociatedType<'info> {
     pub args: Argument<InstructionArgs>,
@@ -107,6 +122,7 @@ pub struct StructAccountsWithoutAssociatedType<'info> {
 
     pub maybe_uninitialized: MaybeUninitialized<'info, ZcAccount<'info, ZeroCopyStructAccountData>>,
 
+    #[account(resolve = solana_program::sysvar::instructions::ID)]
     pub instructions_sysvar: SysvarAccount<'info, Instructions>,
 }
 
@@ -184,3 +200,251 @@ pub struct AuxiliarInstruction<'info> {
     pub args: Argument<InstructionArgs>,
     pub account: Account<'info, StructAccountData>,
 }
+
+impl<'info> Validate<'info> for AuxiliarInstruction<'info> {
+    fn validate_accounts(&self, _context: &'info FankorContext<'info>) -> FankorResult<()> {
+        if self.args.arg2 == 0 {
+            return Err(Errors::A.into());
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[instruction]
+#[allow(dead_code)]
+pub struct GroupedInstruction<'info> {
+    pub account: Account<'info, StructAccountData>,
+}
+
+#[allow(dead_code)]
+impl<'info> GroupedInstruction<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn processor(self, _context: FankorContext<'info>) -> FankorResult<()> {
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[instruction]
+#[allow(dead_code)]
+pub struct LimitedListInstruction<'info> {
+    #[account(min = 1, max = 4)]
+    pub strict_list: Vec<Account<'info, StructAccountData>>,
+
+    #[account(max = 2, max_mode = truncate)]
+    pub truncated_list: Vec<Account<'info, StructAccountData>>,
+
+    #[account(max = 2, max_mode = rest)]
+    #[account(unchecked_reason = "Forwarded as is")]
+    pub list: Vec<UncheckedAccount<'info>>,
+
+    #[account(min = 3)]
+    pub rest: Rest<'info>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[instruction]
+#[allow(dead_code)]
+pub struct CountedListInstruction<'info> {
+    #[account(sentinel = &crate::ID)]
+    pub sentinel_list: Vec<Account<'info, StructAccountData>>,
+
+    #[account(remaining, max = 3)]
+    #[account(unchecked_reason = "Forwarded as is")]
+    pub remaining_list: Vec<UncheckedAccount<'info>>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[instruction(collect_fee = lamports, fee_base = self.args.arg3)]
+#[allow(dead_code)]
+pub struct FeeInstruction<'info> {
+    pub args: Argument<InstructionArgs>,
+
+    #[account(unchecked_reason = "Checked by collect_fee")]
+    pub fee_config: UncheckedAccount<'info>,
+
+    #[account(writable, signer)]
+    #[account(unchecked_reason = "Only pays the fee")]
+    pub fee_payer: UncheckedAccount<'info>,
+
+    #[account(writable)]
+    #[account(unchecked_reason = "Checked by collect_fee")]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[instruction]
+#[allow(dead_code)]
+pub struct TokenGatedInstruction<'info> {
+    #[account(token_gate = (&crate::PUBKEY_FROM_CONSTANT, 1))]
+    #[account(unchecked_reason = "Checked by token_gate")]
+    pub member: UncheckedAccount<'info>,
+
+    pub member_token_account: Account<'info, TokenAccount>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[instruction(idempotent_by = args.arg3, idempotency_scope = authority)]
+#[allow(dead_code)]
+pub struct IdempotentInstruction<'info> {
+    pub args: Argument<InstructionArgs>,
+
+    #[account(signer)]
+    #[account(unchecked_reason = "Only scopes the idempotency record")]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(writable)]
+    #[account(unchecked_reason = "Checked by idempotent_by")]
+    pub idempotency_record: UncheckedAccount<'info>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[instruction]
+pub struct InitGlobalConfig<'info> {
+    #[account(writable)]
+    #[account(unchecked_reason = "Checked by Singleton::get_or_init")]
+    pub global_config: UncheckedAccount<'info>,
+
+    #[account(writable)]
+    #[account(signer)]
+    #[account(unchecked_reason = "Only pays the rent of the global config")]
+    pub payer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitGlobalConfig<'info> {
+    // METHODS ----------------------------------------------------------------
+
+    pub fn processor(self, _context: FankorContext<'info>) -> FankorResult<()> {
+        let space = GlobalConfig::default().try_to_vec()?.len();
+
+        Singleton::<GlobalConfig>::get_or_init(
+            self.global_config.context(),
+            space,
+            self.payer.info(),
+            &self.system_program,
+        )?;
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_hook() {
+        let program_id: &'static Pubkey = Box::leak(Box::new(crate::ID));
+        let data = StructAccountData {
+            value1: 1,
+            value2: "test".to_string(),
+        }
+        .try_to_vec()
+        .unwrap();
+
+        let lamports = Box::leak(Box::new(0));
+        let data = Box::leak(data.into_boxed_slice());
+        let mut info = create_account_info_for_tests(lamports, data);
+        info.owner = program_id;
+
+        let infos: &'static [AccountInfo] = Box::leak(Box::new([info]));
+        let context = Box::leak(Box::new(FankorContext::new_unchecked(program_id, infos)));
+
+        let try_from = |arg2: u32| {
+            let args = InstructionArgs {
+                arg1: true,
+                arg2,
+                arg3: 0,
+            }
+            .try_to_vec()
+            .unwrap();
+            let mut accounts = infos;
+
+            <AuxiliarInstruction as Instruction>::try_from(context, &mut &args[..], &mut accounts)
+        };
+
+        assert!(try_from(1).is_ok());
+        assert!(try_from(0).is_err());
+    }
+
+    #[test]
+    fn test_account_layout() {
+        let layout = StructAccounts::account_layout();
+
+        assert_eq!(layout[0].name, "account1");
+        assert_eq!(layout[0].writable, Some(true));
+        assert_eq!(layout[0].signer, Some(true));
+        assert!(!layout[0].optional);
+        assert!(!layout[0].is_variable());
+
+        assert_eq!(layout[1].name, "account2");
+        assert_eq!(layout[1].writable, Some(false));
+        assert_eq!(layout[1].signer, Some(false));
+
+        assert_eq!(layout[2].name, "account3");
+        assert!(layout[2].optional);
+        assert_eq!(layout[2].writable, None);
+        assert_eq!((layout[2].min_count, layout[2].max_count), (0, Some(1)));
+
+        let list = layout.iter().find(|v| v.name == "list").unwrap();
+        assert_eq!((list.min_count, list.max_count), (0, None));
+        assert_eq!(list.unchecked_reason, None);
+
+        let unchecked = layout.iter().find(|v| v.name == "unchecked_account").unwrap();
+        assert_eq!(
+            unchecked.unchecked_reason,
+            Some("Only receives the rent of zero_copy_account")
+        );
+
+        let layout = LimitedListInstruction::account_layout();
+        assert_eq!((layout[0].min_count, layout[0].max_count), (1, Some(4)));
+        assert_eq!(layout[0].constraints, &["min = 1", "max = 4"]);
+        assert_eq!((layout[1].min_count, layout[1].max_count), (0, None));
+        assert_eq!(layout[2].constraints, &["max = 2 (extra accounts in rest)"]);
+        assert_eq!((layout[3].min_count, layout[3].max_count), (3, None));
+
+        let layout = CountedListInstruction::account_layout();
+        assert_eq!(layout[0].constraints, &["sentinel = & crate :: ID"]);
+        assert_eq!((layout[0].min_count, layout[0].max_count), (0, None));
+        assert_eq!(layout[1].constraints, &["max = 3", "remaining"]);
+        assert_eq!((layout[1].min_count, layout[1].max_count), (0, Some(3)));
+
+        let layout = TokenGatedInstruction::account_layout();
+        assert_eq!(
+            layout[0].constraints,
+            &["token_gate = (& crate :: PUBKEY_FROM_CONSTANT, 1)"]
+        );
+    }
+}
@@ -14,10 +14,13 @@ mod serialization;
 mod tests;
 
 setup!("7JKciYMdWKBo1yPhjVe5eDDjoxYfB8YhkAL7DRpJj3xE");
+strict_mode!();
 
 pub static PUBKEY_FROM_CONSTANT: Pubkey =
     const_pubkey!("7JKciYMdWKBo1yPhjVe5eDDjoxYfB8YhkAL7DRpJj3xE");
 
+declare_external_program!(ExternalProgram, "9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin");
+
 security_txt! {
     // Required fields
     name: "Example",
@@ -25,3 +28,16 @@ security_txt! {
     contacts: "email:example@example.com,link:https://example.com/security,discord:example#1234",
     policy: "https://github.com/solana-labs/solana/blob/master/SECURITY.md"
 }
+
+program_info! {
+    docs: "https://example.com/docs",
+    repository: "https://github.com/example/example",
+    deployments: {
+        devnet: "7JKciYMdWKBo1yPhjVe5eDDjoxYfB8YhkAL7DRpJj3xE",
+    },
+}
+
+namespace! {
+    config => [b"config"],
+    user(wallet: Pubkey) => [b"user", wallet],
+}
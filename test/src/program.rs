@@ -1,8 +1,15 @@
 use fankor::prelude::*;
 
+use crate::accounts::{GrowableAccountData, MigrateStructAccountData2, ProgramAccount};
 use crate::instruction::*;
 
-#[program(testable, fallback)]
+#[program(
+    testable,
+    growable = GrowableAccountData,
+    versioned,
+    fallback,
+    account_registry = ProgramAccount
+)]
 enum TestProgram {
     #[discriminant = 3]
     StructAccounts,
@@ -17,6 +24,28 @@ enum TestProgram {
     #[return_type = u8]
     #[boxed]
     EnumAccountsWithoutArgs,
+
+    Admin(AdminInstruction),
+
+    #[return_type = MigrationProgress]
+    MigrateStructAccountData2,
+
+    InitGlobalConfig,
+}
+
+#[instruction_group(middleware = admin_middleware)]
+enum AdminInstruction {
+    GroupedInstruction,
+}
+
+#[allow(dead_code)]
+fn admin_middleware<'info>(
+    _context: &'info FankorContext<'info>,
+    instruction: AdminInstructionDiscriminant,
+) -> FankorResult<()> {
+    msg!("admin instruction: {:?}", instruction);
+
+    Ok(())
 }
 
 #[allow(dead_code)]
@@ -24,6 +24,17 @@ enum X {
     },
 }
 
+#[derive(Debug, Eq, PartialEq, FankorSerialize, FankorDeserialize)]
+struct Y {
+    a: u8,
+    #[fankor(default)]
+    b: u16,
+    // The arguments of all the attributes are merged.
+    #[fankor(ts_opaque)]
+    #[fankor(default)]
+    c: Option<u8>,
+}
+
 #[cfg(test)]
 mod test {
     use fankor::prelude::borsh::{BorshDeserialize, BorshSerialize};
@@ -86,4 +97,25 @@ mod test {
         assert_eq!(X::try_from_slice(&f).unwrap(), X::F);
         assert_eq!(X::try_from_slice(&g).unwrap(), X::G { a: 100, b: 200 });
     }
+
+    #[test]
+    fn test_deserialize_default_fields() {
+        let value = Y {
+            a: 1,
+            b: 2,
+            c: Some(3),
+        };
+        let bytes = value.try_to_vec().unwrap();
+
+        assert_eq!(Y::try_from_slice(&bytes).unwrap(), value);
+        assert_eq!(
+            Y::try_from_slice(&bytes[..3]).unwrap(),
+            Y { a: 1, b: 2, c: None }
+        );
+        assert_eq!(
+            Y::try_from_slice(&bytes[..1]).unwrap(),
+            Y { a: 1, b: 0, c: None }
+        );
+        assert!(Y::try_from_slice(&[]).is_err());
+    }
 }
@@ -1,7 +1,8 @@
 use fankor::prelude::*;
 use fankor::tests::ClientExtensions;
 
-use crate::instruction::LpiInstructionStructAccountsWithoutAssociatedType;
+use crate::accounts::GlobalConfig;
+use crate::instruction::{LpiInitGlobalConfig, LpiInstructionStructAccountsWithoutAssociatedType};
 use crate::program::TestProgram;
 
 #[tokio::test]
@@ -41,6 +42,51 @@ async fn test_program_instruction() {
     // assert_eq!(account_data.owner, Pubkey::default());
 }
 
+#[tokio::test]
+async fn test_init_prefunded_global_config() {
+    let mut program = TestProgram::new_program_test();
+    let payer = add_payer(&mut program);
+
+    // Anyone can transfer lamports to the PDA before it is created.
+    let (global_config, _) = GlobalConfig::singleton_address();
+    program.add_account(
+        global_config,
+        solana_sdk::account::Account {
+            lamports: 1_000,
+            ..Default::default()
+        },
+    );
+
+    let (mut banks_client, _payer_keypair, _recent_blockhash) = program.start().await;
+    let instruction = crate::program::lpi::init_global_config(LpiInitGlobalConfig {
+        global_config,
+        payer: payer.pubkey(),
+        system_program: solana_program::system_program::ID,
+    })
+    .expect("Cannot build instruction");
+
+    let transaction = banks_client
+        .create_transaction_from_instructions(&[instruction], &payer, vec![&payer])
+        .await
+        .expect("Cannot build transaction");
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("Cannot process transaction");
+
+    let account = banks_client
+        .get_account(global_config)
+        .await
+        .unwrap()
+        .expect("The global config does not exist");
+    assert_eq!(account.owner, crate::ID);
+    assert_eq!(
+        GlobalConfig::try_from_slice(&account.data).unwrap(),
+        GlobalConfig::default()
+    );
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
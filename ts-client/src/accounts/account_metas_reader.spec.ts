@@ -0,0 +1,26 @@
+import assert from 'assert';
+import { PublicKey } from '@solana/web3.js';
+import { AccountMetasReader } from './account_metas_reader';
+
+describe('AccountMetasReader Tests', () => {
+    it('test_read', () => {
+        const keys = [
+            new PublicKey('11111111111111111111111111111112'),
+            new PublicKey('11111111111111111111111111111113'),
+            new PublicKey('11111111111111111111111111111114'),
+        ];
+        const reader = new AccountMetasReader(
+            keys.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+        );
+
+        assert(reader.peek().equals(keys[0]), 'Incorrect peek');
+        assert(reader.next().equals(keys[0]), 'Incorrect first');
+        assert(reader.remainingLength === 2, 'Incorrect remaining length');
+
+        const remaining = reader.remaining();
+        assert(remaining.length === 2, 'Incorrect remaining');
+        assert(remaining[1].equals(keys[2]), 'Incorrect last');
+        assert(reader.remainingLength === 0, 'Incorrect final length');
+        assert.throws(() => reader.next());
+    });
+});
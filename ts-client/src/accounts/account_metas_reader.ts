@@ -0,0 +1,47 @@
+import { AccountMeta, PublicKey } from '@solana/web3.js';
+
+/**
+ * Reads in order the accounts of an instruction to decode them back into
+ * the named accounts of the instruction.
+ */
+export class AccountMetasReader {
+    metas: AccountMeta[];
+    offset: number;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    constructor(metas: AccountMeta[], offset?: number) {
+        this.metas = metas;
+        this.offset = offset ?? 0;
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    get remainingLength(): number {
+        return Math.max(this.metas.length - this.offset, 0);
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    peek(): PublicKey {
+        if (this.offset >= this.metas.length) {
+            throw new Error(
+                `Expected account at position ${this.offset} but the instruction only has ${this.metas.length}`
+            );
+        }
+
+        return this.metas[this.offset].pubkey;
+    }
+
+    next(): PublicKey {
+        const value = this.peek();
+        this.offset += 1;
+        return value;
+    }
+
+    remaining(): PublicKey[] {
+        const values = this.metas.slice(this.offset).map((v) => v.pubkey);
+        this.offset = this.metas.length;
+        return values;
+    }
+}
@@ -0,0 +1,15 @@
+import { PublicKey } from '@solana/web3.js';
+
+/**
+ * The address of an account of type `T` stored inside another account, i.e.
+ * a `FnkAccountRef<T>`. It is a plain `PublicKey` at runtime, the referenced
+ * type is only kept for type-checking.
+ */
+export type AccountRef<T> = PublicKey & { readonly __account?: T };
+
+/**
+ * Marks `address` as a reference to an account of type `T`.
+ */
+export function accountRef<T>(address: PublicKey): AccountRef<T> {
+    return address as AccountRef<T>;
+}
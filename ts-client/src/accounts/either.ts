@@ -9,3 +9,17 @@ export interface EitherRight<T> {
     type: 'Right';
     value: T;
 }
+
+/**
+ * Builds the left variant of an Either account.
+ */
+export function left<L, R = never>(value: L): Either<L, R> {
+    return { type: 'Left', value };
+}
+
+/**
+ * Builds the right variant of an Either account.
+ */
+export function right<R, L = never>(value: R): Either<L, R> {
+    return { type: 'Right', value };
+}
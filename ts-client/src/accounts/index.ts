@@ -1 +1,3 @@
+export * from './account_metas_reader';
+export * from './account_ref';
 export * from './either';
@@ -2,11 +2,13 @@ import {
     FnkBorshReader,
     FnkBorshSchema,
     FnkBorshWriter,
+    I64,
     TEnum,
     TPublicKey,
     TString,
     TStruct,
     U64,
+    U8,
 } from './serde';
 import { clone, equals } from './utils';
 import { PublicKey } from '@solana/web3.js';
@@ -60,6 +62,15 @@ export type FankorErrorCodeTypes =
     | FankorErrorCode_InvalidPda
     | FankorErrorCode_MissingSeedsAccount
     | FankorErrorCode_MissingPdaSeeds
+    | FankorErrorCode_ArithmeticOverflow
+    | FankorErrorCode_ValueOutOfRange
+    | FankorErrorCode_TimestampNotReached
+    | FankorErrorCode_SlotHashNotFound
+    | FankorErrorCode_SlotHashMismatch
+    | FankorErrorCode_ArgumentValidationFailed
+    | FankorErrorCode_InstructionDataTooLarge
+    | FankorErrorCode_InstructionTooManyAccounts
+    | FankorErrorCode_BatchTooLarge
     | FankorErrorCode_DuplicatedWritableAccounts
     | FankorErrorCode_AccountDiscriminantMismatch
     | FankorErrorCode_InstructionDidNotDeserialize
@@ -96,16 +107,55 @@ export type FankorErrorCodeTypes =
     | FankorErrorCode_AccountConstraintFailed
     | FankorErrorCode_DuplicatedAccountWithDifferentType
     | FankorErrorCode_AccountNotDefault
+    | FankorErrorCode_GrowableAccountWithoutHeader
+    | FankorErrorCode_AccountTombstoned
+    | FankorErrorCode_AccountTombstoneLocked
+    | FankorErrorCode_AccountConstraintNotTombstoned
+    | FankorErrorCode_AccountConstraintTombstoned
+    | FankorErrorCode_NotEnoughSigners
+    | FankorErrorCode_AccountModifiedAfterCheckpoint
+    | FankorErrorCode_DuplicatedAccountInVec
+    | FankorErrorCode_InvalidProgramDataAccount
+    | FankorErrorCode_UpgradeAuthorityMismatch
+    | FankorErrorCode_InvalidRentDestination
+    | FankorErrorCode_ReferencedAccountNotFound
+    | FankorErrorCode_VecAccountsTooLarge
+    | FankorErrorCode_InsufficientLamports
+    | FankorErrorCode_AccountMetaEntryNotFound
+    | FankorErrorCode_AccountMetaEntryMissingPrivileges
+    | FankorErrorCode_AccountBuilderMissingField
+    | FankorErrorCode_AccountBuilderInvalidField
+    | FankorErrorCode_RestAccountsNotDivisible
+    | FankorErrorCode_TokenGateAccountMissing
+    | FankorErrorCode_TokenGateNotMet
+    | FankorErrorCode_InsufficientRentFunding
+    | FankorErrorCode_CollectionIndexOutOfBounds
     | FankorErrorCode_EmptyIntermediateBuffer
     | FankorErrorCode_IntermediateBufferIncorrectProgramId
     | FankorErrorCode_TooManyAccounts
+    | FankorErrorCode_TransferHookInvalidExtraAccountMeta
+    | FankorErrorCode_TransferHookMissingExtraAccount
+    | FankorErrorCode_CpiProgramNotAllowed
     | FankorErrorCode_ZeroCopyCannotDeserialize
     | FankorErrorCode_ZeroCopyNotEnoughLength
     | FankorErrorCode_ZeroCopyInvalidEnumDiscriminant
     | FankorErrorCode_ZeroCopyPossibleDeadlock
     | FankorErrorCode_ZeroCopyLengthFieldOverflow
     | FankorErrorCode_ZeroCopyIncorrectPrecedingField
-    | FankorErrorCode_ZeroCopyInvalidMove;
+    | FankorErrorCode_ZeroCopyInvalidMove
+    | FankorErrorCode_AlreadyBorrowed
+    | FankorErrorCode_ZeroCopyOverlappingWrites
+    | FankorErrorCode_ZeroCopyWritePlannerAccountMismatch
+    | FankorErrorCode_ZeroCopyInvalidElementSize
+    | FankorErrorCode_TransactionDidNotDecode
+    | FankorErrorCode_OraclePriceUnavailable
+    | FankorErrorCode_OraclePriceStale
+    | FankorErrorCode_OraclePriceConfidenceTooWide
+    | FankorErrorCode_StakePoolNotUpdated
+    | FankorErrorCode_VoteAccountStale
+    | FankorErrorCode_FeeRecipientMismatch
+    | FankorErrorCode_FeeMintMismatch
+    | FankorErrorCode_InstructionReplayed;
 
 export interface FankorErrorCode_DeclaredProgramIdMismatch {
     type: 'DeclaredProgramIdMismatch';
@@ -147,6 +197,51 @@ export interface FankorErrorCode_MissingPdaSeeds {
     value: { account: PublicKey };
 }
 
+export interface FankorErrorCode_ArithmeticOverflow {
+    type: 'ArithmeticOverflow';
+    value: { operation: string };
+}
+
+export interface FankorErrorCode_ValueOutOfRange {
+    type: 'ValueOutOfRange';
+    value: { typeName: string; value: BN; max: BN };
+}
+
+export interface FankorErrorCode_TimestampNotReached {
+    type: 'TimestampNotReached';
+    value: { timestamp: BN; now: BN };
+}
+
+export interface FankorErrorCode_SlotHashNotFound {
+    type: 'SlotHashNotFound';
+    value: { slot: BN };
+}
+
+export interface FankorErrorCode_SlotHashMismatch {
+    type: 'SlotHashMismatch';
+    value: { slot: BN };
+}
+
+export interface FankorErrorCode_ArgumentValidationFailed {
+    type: 'ArgumentValidationFailed';
+    value: { argument: string; validation: string };
+}
+
+export interface FankorErrorCode_InstructionDataTooLarge {
+    type: 'InstructionDataTooLarge';
+    value: { max: BN; actual: BN };
+}
+
+export interface FankorErrorCode_InstructionTooManyAccounts {
+    type: 'InstructionTooManyAccounts';
+    value: { max: BN; actual: BN };
+}
+
+export interface FankorErrorCode_BatchTooLarge {
+    type: 'BatchTooLarge';
+    value: { max: BN; actual: BN };
+}
+
 export interface FankorErrorCode_DuplicatedWritableAccounts {
     type: 'DuplicatedWritableAccounts';
     value: { address: PublicKey };
@@ -322,6 +417,121 @@ export interface FankorErrorCode_AccountNotDefault {
     type: 'AccountNotDefault';
 }
 
+export interface FankorErrorCode_GrowableAccountWithoutHeader {
+    type: 'GrowableAccountWithoutHeader';
+    value: { address: PublicKey };
+}
+
+export interface FankorErrorCode_AccountTombstoned {
+    type: 'AccountTombstoned';
+    value: { address: PublicKey };
+}
+
+export interface FankorErrorCode_AccountTombstoneLocked {
+    type: 'AccountTombstoneLocked';
+    value: { address: PublicKey; unlockTimestamp: BN };
+}
+
+export interface FankorErrorCode_AccountConstraintNotTombstoned {
+    type: 'AccountConstraintNotTombstoned';
+    value: { account: string };
+}
+
+export interface FankorErrorCode_AccountConstraintTombstoned {
+    type: 'AccountConstraintTombstoned';
+    value: { account: string };
+}
+
+export interface FankorErrorCode_NotEnoughSigners {
+    type: 'NotEnoughSigners';
+    value: { required: number; actual: BN };
+}
+
+export interface FankorErrorCode_AccountModifiedAfterCheckpoint {
+    type: 'AccountModifiedAfterCheckpoint';
+    value: { address: PublicKey };
+}
+
+export interface FankorErrorCode_DuplicatedAccountInVec {
+    type: 'DuplicatedAccountInVec';
+    value: { account: string; address: PublicKey };
+}
+
+export interface FankorErrorCode_InvalidProgramDataAccount {
+    type: 'InvalidProgramDataAccount';
+    value: { program: PublicKey; programData: PublicKey };
+}
+
+export interface FankorErrorCode_UpgradeAuthorityMismatch {
+    type: 'UpgradeAuthorityMismatch';
+    value: { programData: PublicKey };
+}
+
+export interface FankorErrorCode_InvalidRentDestination {
+    type: 'InvalidRentDestination';
+    value: { account: string; destination: PublicKey };
+}
+
+export interface FankorErrorCode_ReferencedAccountNotFound {
+    type: 'ReferencedAccountNotFound';
+    value: { address: PublicKey; accountType: string };
+}
+
+export interface FankorErrorCode_VecAccountsTooLarge {
+    type: 'VecAccountsTooLarge';
+    value: { field: string; max: BN; actual: BN };
+}
+
+export interface FankorErrorCode_InsufficientLamports {
+    type: 'InsufficientLamports';
+    value: { address: PublicKey; balance: BN; amount: BN };
+}
+
+export interface FankorErrorCode_AccountMetaEntryNotFound {
+    type: 'AccountMetaEntryNotFound';
+    value: { address: PublicKey };
+}
+
+export interface FankorErrorCode_AccountMetaEntryMissingPrivileges {
+    type: 'AccountMetaEntryMissingPrivileges';
+    value: { address: PublicKey };
+}
+
+export interface FankorErrorCode_AccountBuilderMissingField {
+    type: 'AccountBuilderMissingField';
+    value: { account: string; field: string };
+}
+
+export interface FankorErrorCode_AccountBuilderInvalidField {
+    type: 'AccountBuilderInvalidField';
+    value: { account: string; field: string };
+}
+
+export interface FankorErrorCode_RestAccountsNotDivisible {
+    type: 'RestAccountsNotDivisible';
+    value: { chunkSize: BN; actual: BN };
+}
+
+export interface FankorErrorCode_TokenGateAccountMissing {
+    type: 'TokenGateAccountMissing';
+    value: { account: string; tokenAccount: PublicKey };
+}
+
+export interface FankorErrorCode_TokenGateNotMet {
+    type: 'TokenGateNotMet';
+    value: { account: string; amount: BN; minAmount: BN };
+}
+
+export interface FankorErrorCode_InsufficientRentFunding {
+    type: 'InsufficientRentFunding';
+    value: { address: PublicKey; required: BN; funded: BN };
+}
+
+export interface FankorErrorCode_CollectionIndexOutOfBounds {
+    type: 'CollectionIndexOutOfBounds';
+    value: { index: BN; nextIndex: BN };
+}
+
 export interface FankorErrorCode_EmptyIntermediateBuffer {
     type: 'EmptyIntermediateBuffer';
 }
@@ -336,6 +546,20 @@ export interface FankorErrorCode_TooManyAccounts {
     value: { size: BN };
 }
 
+export interface FankorErrorCode_TransferHookInvalidExtraAccountMeta {
+    type: 'TransferHookInvalidExtraAccountMeta';
+}
+
+export interface FankorErrorCode_TransferHookMissingExtraAccount {
+    type: 'TransferHookMissingExtraAccount';
+    value: { address: PublicKey };
+}
+
+export interface FankorErrorCode_CpiProgramNotAllowed {
+    type: 'CpiProgramNotAllowed';
+    value: { program: PublicKey };
+}
+
 export interface FankorErrorCode_ZeroCopyCannotDeserialize {
     type: 'ZeroCopyCannotDeserialize';
     value: { typeName: string };
@@ -368,6 +592,69 @@ export interface FankorErrorCode_ZeroCopyInvalidMove {
     type: 'ZeroCopyInvalidMove';
 }
 
+export interface FankorErrorCode_AlreadyBorrowed {
+    type: 'AlreadyBorrowed';
+    value: { typeName: string; operation: string };
+}
+
+export interface FankorErrorCode_ZeroCopyOverlappingWrites {
+    type: 'ZeroCopyOverlappingWrites';
+    value: { offset: BN };
+}
+
+export interface FankorErrorCode_ZeroCopyWritePlannerAccountMismatch {
+    type: 'ZeroCopyWritePlannerAccountMismatch';
+    value: { address: PublicKey };
+}
+
+export interface FankorErrorCode_ZeroCopyInvalidElementSize {
+    type: 'ZeroCopyInvalidElementSize';
+    value: { typeName: string; expected: BN; actual: BN };
+}
+
+export interface FankorErrorCode_TransactionDidNotDecode {
+    type: 'TransactionDidNotDecode';
+}
+
+export interface FankorErrorCode_OraclePriceUnavailable {
+    type: 'OraclePriceUnavailable';
+}
+
+export interface FankorErrorCode_OraclePriceStale {
+    type: 'OraclePriceStale';
+    value: { age: BN; maxAge: BN };
+}
+
+export interface FankorErrorCode_OraclePriceConfidenceTooWide {
+    type: 'OraclePriceConfidenceTooWide';
+    value: { confidence: BN; maxConfidence: BN };
+}
+
+export interface FankorErrorCode_StakePoolNotUpdated {
+    type: 'StakePoolNotUpdated';
+    value: { lastUpdateEpoch: BN; epoch: BN };
+}
+
+export interface FankorErrorCode_VoteAccountStale {
+    type: 'VoteAccountStale';
+    value: { age: BN; maxAge: BN };
+}
+
+export interface FankorErrorCode_FeeRecipientMismatch {
+    type: 'FeeRecipientMismatch';
+    value: { expected: PublicKey; actual: PublicKey };
+}
+
+export interface FankorErrorCode_FeeMintMismatch {
+    type: 'FeeMintMismatch';
+    value: { expected: PublicKey; actual: PublicKey };
+}
+
+export interface FankorErrorCode_InstructionReplayed {
+    type: 'InstructionReplayed';
+    value: { nonce: BN };
+}
+
 export class FankorErrorCodeSchema implements FnkBorshSchema<FankorErrorCode> {
     innerSchema = null as any as ReturnType<
         FankorErrorCodeSchema['initSchema']
@@ -408,6 +695,70 @@ export class FankorErrorCodeSchema implements FnkBorshSchema<FankorErrorCode> {
                 'MissingPdaSeeds',
                 TStruct([['account', TPublicKey]] as const),
             ],
+            [
+                1009,
+                'ArithmeticOverflow',
+                TStruct([['operation', TString]] as const),
+            ],
+            [
+                1010,
+                'ValueOutOfRange',
+                TStruct([
+                    ['typeName', TString],
+                    ['value', U64],
+                    ['max', U64],
+                ] as const),
+            ],
+            [
+                1011,
+                'TimestampNotReached',
+                TStruct([
+                    ['timestamp', I64],
+                    ['now', I64],
+                ] as const),
+            ],
+            [
+                1012,
+                'SlotHashNotFound',
+                TStruct([['slot', U64]] as const),
+            ],
+            [
+                1013,
+                'SlotHashMismatch',
+                TStruct([['slot', U64]] as const),
+            ],
+            [
+                1014,
+                'ArgumentValidationFailed',
+                TStruct([
+                    ['argument', TString],
+                    ['validation', TString],
+                ] as const),
+            ],
+            [
+                1015,
+                'InstructionDataTooLarge',
+                TStruct([
+                    ['max', U64],
+                    ['actual', U64],
+                ] as const),
+            ],
+            [
+                1016,
+                'InstructionTooManyAccounts',
+                TStruct([
+                    ['max', U64],
+                    ['actual', U64],
+                ] as const),
+            ],
+            [
+                1017,
+                'BatchTooLarge',
+                TStruct([
+                    ['max', U64],
+                    ['actual', U64],
+                ] as const),
+            ],
             [
                 1500,
                 'DuplicatedWritableAccounts',
@@ -606,6 +957,170 @@ export class FankorErrorCodeSchema implements FnkBorshSchema<FankorErrorCode> {
                 TStruct([['address', TPublicKey]] as const),
             ],
             [1535, 'AccountNotDefault'],
+            [
+                1536,
+                'GrowableAccountWithoutHeader',
+                TStruct([['address', TPublicKey]] as const),
+            ],
+            [
+                1537,
+                'AccountTombstoned',
+                TStruct([['address', TPublicKey]] as const),
+            ],
+            [
+                1538,
+                'AccountTombstoneLocked',
+                TStruct([
+                    ['address', TPublicKey],
+                    ['unlockTimestamp', I64],
+                ] as const),
+            ],
+            [
+                1539,
+                'AccountConstraintNotTombstoned',
+                TStruct([['account', TString]] as const),
+            ],
+            [
+                1540,
+                'AccountConstraintTombstoned',
+                TStruct([['account', TString]] as const),
+            ],
+            [
+                1541,
+                'NotEnoughSigners',
+                TStruct([
+                    ['required', U8],
+                    ['actual', U64],
+                ] as const),
+            ],
+            [
+                1542,
+                'AccountModifiedAfterCheckpoint',
+                TStruct([['address', TPublicKey]] as const),
+            ],
+            [
+                1543,
+                'DuplicatedAccountInVec',
+                TStruct([
+                    ['account', TString],
+                    ['address', TPublicKey],
+                ] as const),
+            ],
+            [
+                1544,
+                'InvalidProgramDataAccount',
+                TStruct([
+                    ['program', TPublicKey],
+                    ['programData', TPublicKey],
+                ] as const),
+            ],
+            [
+                1545,
+                'UpgradeAuthorityMismatch',
+                TStruct([['programData', TPublicKey]] as const),
+            ],
+            [
+                1546,
+                'InvalidRentDestination',
+                TStruct([
+                    ['account', TString],
+                    ['destination', TPublicKey],
+                ] as const),
+            ],
+            [
+                1547,
+                'ReferencedAccountNotFound',
+                TStruct([
+                    ['address', TPublicKey],
+                    ['accountType', TString],
+                ] as const),
+            ],
+            [
+                1548,
+                'VecAccountsTooLarge',
+                TStruct([
+                    ['field', TString],
+                    ['max', U64],
+                    ['actual', U64],
+                ] as const),
+            ],
+            [
+                1549,
+                'InsufficientLamports',
+                TStruct([
+                    ['address', TPublicKey],
+                    ['balance', U64],
+                    ['amount', U64],
+                ] as const),
+            ],
+            [
+                1550,
+                'AccountMetaEntryNotFound',
+                TStruct([['address', TPublicKey]] as const),
+            ],
+            [
+                1551,
+                'AccountMetaEntryMissingPrivileges',
+                TStruct([['address', TPublicKey]] as const),
+            ],
+            [
+                1552,
+                'AccountBuilderMissingField',
+                TStruct([
+                    ['account', TString],
+                    ['field', TString],
+                ] as const),
+            ],
+            [
+                1553,
+                'AccountBuilderInvalidField',
+                TStruct([
+                    ['account', TString],
+                    ['field', TString],
+                ] as const),
+            ],
+            [
+                1554,
+                'RestAccountsNotDivisible',
+                TStruct([
+                    ['chunkSize', U64],
+                    ['actual', U64],
+                ] as const),
+            ],
+            [
+                1555,
+                'TokenGateAccountMissing',
+                TStruct([
+                    ['account', TString],
+                    ['tokenAccount', TPublicKey],
+                ] as const),
+            ],
+            [
+                1556,
+                'TokenGateNotMet',
+                TStruct([
+                    ['account', TString],
+                    ['amount', U64],
+                    ['minAmount', U64],
+                ] as const),
+            ],
+            [
+                1557,
+                'InsufficientRentFunding',
+                TStruct([
+                    ['address', TPublicKey],
+                    ['required', U64],
+                    ['funded', U64],
+                ] as const),
+            ],
+            [
+                1558,
+                'CollectionIndexOutOfBounds',
+                TStruct([
+                    ['index', U64],
+                    ['nextIndex', U64],
+                ] as const),
+            ],
             [2000, 'EmptyIntermediateBuffer'],
             [
                 2001,
@@ -616,6 +1131,17 @@ export class FankorErrorCodeSchema implements FnkBorshSchema<FankorErrorCode> {
                 ] as const),
             ],
             [2002, 'TooManyAccounts', TStruct([['size', U64]] as const)],
+            [2003, 'TransferHookInvalidExtraAccountMeta'],
+            [
+                2004,
+                'TransferHookMissingExtraAccount',
+                TStruct([['address', TPublicKey]] as const),
+            ],
+            [
+                2005,
+                'CpiProgramNotAllowed',
+                TStruct([['program', TPublicKey]] as const),
+            ],
             [
                 2500,
                 'ZeroCopyCannotDeserialize',
@@ -639,6 +1165,84 @@ export class FankorErrorCodeSchema implements FnkBorshSchema<FankorErrorCode> {
             [2504, 'ZeroCopyLengthFieldOverflow'],
             [2505, 'ZeroCopyIncorrectPrecedingField'],
             [2506, 'ZeroCopyInvalidMove'],
+            [
+                2507,
+                'AlreadyBorrowed',
+                TStruct([
+                    ['typeName', TString],
+                    ['operation', TString],
+                ] as const),
+            ],
+            [
+                2508,
+                'ZeroCopyOverlappingWrites',
+                TStruct([['offset', U64]] as const),
+            ],
+            [
+                2509,
+                'ZeroCopyWritePlannerAccountMismatch',
+                TStruct([['address', TPublicKey]] as const),
+            ],
+            [
+                2510,
+                'ZeroCopyInvalidElementSize',
+                TStruct([
+                    ['typeName', TString],
+                    ['expected', U64],
+                    ['actual', U64],
+                ] as const),
+            ],
+            [3000, 'TransactionDidNotDecode'],
+            [3500, 'OraclePriceUnavailable'],
+            [
+                3501,
+                'OraclePriceStale',
+                TStruct([
+                    ['age', U64],
+                    ['maxAge', U64],
+                ] as const),
+            ],
+            [
+                3502,
+                'OraclePriceConfidenceTooWide',
+                TStruct([
+                    ['confidence', U64],
+                    ['maxConfidence', U64],
+                ] as const),
+            ],
+            [
+                4000,
+                'StakePoolNotUpdated',
+                TStruct([
+                    ['lastUpdateEpoch', U64],
+                    ['epoch', U64],
+                ] as const),
+            ],
+            [
+                4001,
+                'VoteAccountStale',
+                TStruct([
+                    ['age', U64],
+                    ['maxAge', U64],
+                ] as const),
+            ],
+            [
+                4500,
+                'FeeRecipientMismatch',
+                TStruct([
+                    ['expected', TPublicKey],
+                    ['actual', TPublicKey],
+                ] as const),
+            ],
+            [
+                4501,
+                'FeeMintMismatch',
+                TStruct([
+                    ['expected', TPublicKey],
+                    ['actual', TPublicKey],
+                ] as const),
+            ],
+            [5000, 'InstructionReplayed', TStruct([['nonce', U64]] as const)],
         ] as const);
         this.innerSchema = innerSchema;
         return innerSchema;
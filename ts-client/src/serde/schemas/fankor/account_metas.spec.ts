@@ -0,0 +1,29 @@
+import assert from 'assert';
+import { AccountMeta, PublicKey } from '@solana/web3.js';
+import { TAccountMetaEntry } from './account_metas';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshReader } from '../../deserializer';
+
+describe('AccountMetaEntry Tests', () => {
+    it('test_serialize_deserialize', () => {
+        const data: AccountMeta = {
+            pubkey: new PublicKey('11111111111111111111111111111112'),
+            isSigner: true,
+            isWritable: false,
+        };
+
+        const writer = new FnkBorshWriter();
+        TAccountMetaEntry.serialize(writer, data);
+
+        const buffer = writer.buffer.slice(0, writer.length);
+        assert(buffer.length === 34, 'Incorrect length');
+        assert(buffer[32] === 1, 'Incorrect isSigner');
+        assert(buffer[33] === 0, 'Incorrect isWritable');
+
+        const reader = new FnkBorshReader(buffer);
+        const actual = TAccountMetaEntry.deserialize(reader);
+        assert(actual.pubkey.equals(data.pubkey), 'Incorrect pubkey');
+        assert(actual.isSigner === data.isSigner, 'Incorrect isSigner');
+        assert(actual.isWritable === data.isWritable, 'Incorrect isWritable');
+    });
+});
@@ -0,0 +1,13 @@
+import { Bool } from '../bools';
+import { TPublicKey } from '../public_keys';
+import { TStruct } from '../structs';
+
+/**
+ * The address and privileges of an account stored inside another account.
+ * Its values are compatible with `AccountMeta` of `@solana/web3.js`.
+ */
+export const TAccountMetaEntry = TStruct([
+    ['pubkey', TPublicKey],
+    ['isSigner', Bool],
+    ['isWritable', Bool],
+] as const);
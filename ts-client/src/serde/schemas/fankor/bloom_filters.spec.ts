@@ -0,0 +1,21 @@
+import assert from 'assert';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshReader } from '../../deserializer';
+import { FnkBloomFilter } from './bloom_filters';
+
+describe('FnkBloomFilter Tests', () => {
+    it('test_serialize_deserialize', () => {
+        const data = { hashCount: 3, bits: new Uint8Array([1, 2]) };
+        const writer = new FnkBorshWriter();
+        FnkBloomFilter.serialize(writer, data);
+
+        let buffer = writer.buffer.slice(0, writer.length);
+        assert.deepStrictEqual([...buffer], [3, 2, 0, 0, 0, 1, 2]);
+
+        const reader = new FnkBorshReader(buffer);
+        let actual = FnkBloomFilter.deserialize(reader);
+        assert(actual.hashCount === 3, `HashCount: ${actual.hashCount} != 3`);
+        assert.deepStrictEqual([...actual.bits], [1, 2]);
+        assert(reader.offset === buffer.length, 'Buffer not consumed');
+    });
+});
@@ -0,0 +1,31 @@
+import { FnkBorshReader } from '../../deserializer';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshSchema } from '../../borsh';
+import { U32, U8 } from '../unsigned';
+
+export interface FnkBloomFilterValue {
+    hashCount: number;
+    bits: Uint8Array;
+}
+
+export class FnkBloomFilterSchema
+    implements FnkBorshSchema<FnkBloomFilterValue>
+{
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: FnkBloomFilterValue) {
+        U8.serialize(writer, value.hashCount);
+        U32.serialize(writer, value.bits.length);
+        writer.writeBuffer(Buffer.from(value.bits));
+    }
+
+    deserialize(reader: FnkBorshReader): FnkBloomFilterValue {
+        const hashCount = U8.deserialize(reader);
+        const length = U32.deserialize(reader);
+        const bits = reader.readBuffer(length).subarray();
+
+        return { hashCount, bits };
+    }
+}
+
+export const FnkBloomFilter = new FnkBloomFilterSchema();
@@ -1,7 +1,11 @@
+export * from './account_metas';
+export * from './bloom_filters';
 export * from './bmap';
 export * from './integers';
 export * from './maps';
+export * from './percentages';
 export * from './ranges';
+export * from './ring_buffers';
 export * from './strings';
 export * from './unsigned';
 export * from './vectors';
@@ -0,0 +1,44 @@
+import assert from 'assert';
+import { TBps, TPercent } from './percentages';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshReader } from '../../deserializer';
+
+describe('Percentages Tests', () => {
+    it('test_serialize_deserialize_bps', () => {
+        for (const data of [0, 30, 10000]) {
+            const writer = new FnkBorshWriter();
+            TBps.serialize(writer, data);
+
+            const buffer = writer.buffer.slice(0, writer.length);
+            assert(buffer.length === 2, `Incorrect length for ${data}`);
+
+            const reader = new FnkBorshReader(buffer);
+            const actual = TBps.deserialize(reader);
+            assert(actual === data, `${actual} != ${data}`);
+        }
+
+        assert.throws(() => TBps.serialize(new FnkBorshWriter(), 10001));
+        assert.throws(() =>
+            TBps.deserialize(new FnkBorshReader(Buffer.from([0x11, 0x27])))
+        );
+    });
+
+    it('test_serialize_deserialize_percent', () => {
+        for (const data of [0, 15, 100]) {
+            const writer = new FnkBorshWriter();
+            TPercent.serialize(writer, data);
+
+            const buffer = writer.buffer.slice(0, writer.length);
+            assert(buffer.length === 1, `Incorrect length for ${data}`);
+
+            const reader = new FnkBorshReader(buffer);
+            const actual = TPercent.deserialize(reader);
+            assert(actual === data, `${actual} != ${data}`);
+        }
+
+        assert.throws(() => TPercent.serialize(new FnkBorshWriter(), 101));
+        assert.throws(() =>
+            TPercent.deserialize(new FnkBorshReader(Buffer.from([101])))
+        );
+    });
+});
@@ -0,0 +1,70 @@
+import { FnkBorshReader } from '../../deserializer';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshError } from '../../errors';
+import { FnkBorshSchema } from '../../borsh';
+
+export const BPS_DENOMINATOR = 10000;
+export const PERCENT_DENOMINATOR = 100;
+
+export class BpsSchema implements FnkBorshSchema<number> {
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: number) {
+        if (!Number.isInteger(value) || value < 0 || value > BPS_DENOMINATOR) {
+            throw new RangeError(
+                `Bps must be an integer in range [0, ${BPS_DENOMINATOR}]`
+            );
+        }
+
+        writer.maybeResize();
+        writer.buffer.writeUInt16LE(value, writer.length);
+        writer.length += 2;
+    }
+
+    deserialize(reader: FnkBorshReader): number {
+        const value = reader.buffer.readUInt16LE(reader.offset);
+
+        if (value > BPS_DENOMINATOR) {
+            throw new FnkBorshError(`Bps value out of range: ${value}`);
+        }
+
+        reader.offset += 2;
+        return value;
+    }
+}
+
+export const TBps = new BpsSchema();
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export class PercentSchema implements FnkBorshSchema<number> {
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: number) {
+        if (
+            !Number.isInteger(value) ||
+            value < 0 ||
+            value > PERCENT_DENOMINATOR
+        ) {
+            throw new RangeError(
+                `Percent must be an integer in range [0, ${PERCENT_DENOMINATOR}]`
+            );
+        }
+
+        writer.writeByte(value);
+    }
+
+    deserialize(reader: FnkBorshReader): number {
+        const value = reader.readByte();
+
+        if (value > PERCENT_DENOMINATOR) {
+            throw new FnkBorshError(`Percent value out of range: ${value}`);
+        }
+
+        return value;
+    }
+}
+
+export const TPercent = new PercentSchema();
@@ -0,0 +1,47 @@
+import assert from 'assert';
+import BN from 'bn.js';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshReader } from '../../deserializer';
+import { FnkRingBuffer, ringBufferEntries } from './ring_buffers';
+import { U16 } from '../unsigned';
+
+describe('FnkRingBuffer Tests', () => {
+    it('test_serialize_deserialize', () => {
+        const schema = FnkRingBuffer({ schema: U16, size: 2 });
+        const data = { sequence: new BN(3), slots: [3, 2] };
+        const writer = new FnkBorshWriter();
+        schema.serialize(writer, data);
+
+        let buffer = writer.buffer.slice(0, writer.length);
+        assert.deepStrictEqual(
+            [...buffer],
+            [3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0]
+        );
+
+        const reader = new FnkBorshReader(buffer);
+        let actual = schema.deserialize(reader);
+        assert(actual.sequence.eqn(3), `Sequence: ${actual.sequence} != 3`);
+        assert.deepStrictEqual(actual.slots, data.slots);
+        assert(reader.offset === buffer.length, 'Buffer not consumed');
+    });
+
+    it('test_entries', () => {
+        const data = { sequence: new BN(4), slots: [4, 2, 3] };
+
+        let entries = ringBufferEntries(data).map((v) => [
+            v.sequence.toNumber(),
+            v.value,
+        ]);
+        assert.deepStrictEqual(entries, [
+            [1, 2],
+            [2, 3],
+            [3, 4],
+        ]);
+
+        entries = ringBufferEntries(data, new BN(3)).map((v) => [
+            v.sequence.toNumber(),
+            v.value,
+        ]);
+        assert.deepStrictEqual(entries, [[3, 4]]);
+    });
+});
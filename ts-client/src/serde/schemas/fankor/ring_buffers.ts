@@ -0,0 +1,100 @@
+import BN from 'bn.js';
+import { FnkBorshReader } from '../../deserializer';
+import { FnkBorshWriter } from '../../serializer';
+import { FnkBorshError } from '../../errors';
+import { FnkBorshSchema } from '../../borsh';
+import { InferFnkBorshSchemaInner } from '../maps';
+import { U64 } from '../unsigned';
+
+export interface FnkRingBufferValue<T> {
+    sequence: BN;
+    slots: T[];
+}
+
+export interface FnkRingBufferEntry<T> {
+    sequence: BN;
+    value: T;
+}
+
+export function FnkRingBuffer<S extends FnkBorshSchema<any>>({
+    schema,
+    size,
+}: {
+    schema: S;
+    size: number;
+}) {
+    return new FnkRingBufferSchema(schema, size);
+}
+
+export class FnkRingBufferSchema<S extends FnkBorshSchema<any>>
+    implements FnkBorshSchema<FnkRingBufferValue<InferFnkBorshSchemaInner<S>>>
+{
+    readonly schema: S;
+    readonly size: number;
+
+    // CONSTRUCTOR ------------------------------------------------------------
+
+    constructor(schema: S, size: number) {
+        this.schema = schema;
+        this.size = size;
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    serialize(
+        writer: FnkBorshWriter,
+        value: FnkRingBufferValue<InferFnkBorshSchemaInner<S>>
+    ) {
+        if (value.slots.length !== this.size) {
+            throw new FnkBorshError(
+                `FnkRingBufferSchema: expected ${this.size} slots, got ${value.slots.length}`
+            );
+        }
+
+        U64.serialize(writer, value.sequence);
+
+        for (const item of value.slots) {
+            this.schema.serialize(writer, item);
+        }
+    }
+
+    deserialize(
+        reader: FnkBorshReader
+    ): FnkRingBufferValue<InferFnkBorshSchemaInner<S>> {
+        const sequence = U64.deserialize(reader);
+        const slots: InferFnkBorshSchemaInner<S>[] = [];
+
+        for (let i = 0; i < this.size; i++) {
+            slots.push(this.schema.deserialize(reader));
+        }
+
+        return { sequence, slots };
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/**
+ * Returns the stored elements of a ring buffer with a sequence number equal or
+ * greater than `fromSequence`, from the oldest to the newest one.
+ */
+export function ringBufferEntries<T>(
+    value: FnkRingBufferValue<T>,
+    fromSequence: BN = new BN(0)
+): FnkRingBufferEntry<T>[] {
+    const size = new BN(value.slots.length);
+    let first = BN.max(value.sequence.sub(size), new BN(0));
+    first = BN.max(first, fromSequence);
+
+    const result: FnkRingBufferEntry<T>[] = [];
+    for (let i = first; i.lt(value.sequence); i = i.addn(1)) {
+        result.push({
+            sequence: i,
+            value: value.slots[i.mod(size).toNumber()],
+        });
+    }
+
+    return result;
+}
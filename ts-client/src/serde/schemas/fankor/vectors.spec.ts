@@ -1,9 +1,9 @@
 import assert from 'assert';
 import { FnkBorshWriter } from '../../serializer';
 import { FnkBorshReader } from '../../deserializer';
-import { FnkByteVec, FnkVec } from './vectors';
+import { FnkByteVec, FnkCapVec, FnkVec } from './vectors';
 import { TString } from '../strings';
-import { U8 } from '../unsigned';
+import { U16, U8 } from '../unsigned';
 
 describe('FnkVec Tests', () => {
     it('test_serialize_deserialize_empty', () => {
@@ -133,3 +133,40 @@ describe('FnkVec Tests', () => {
         }
     });
 });
+
+describe('FnkCapVec Tests', () => {
+    it('test_serialize_deserialize_data', () => {
+        const schema = FnkCapVec(U16);
+        const data = { values: [1, 2], byteCapacity: 6 };
+        const writer = new FnkBorshWriter();
+        schema.serialize(writer, data);
+
+        let buffer = writer.buffer.slice(0, writer.length);
+        assert.deepStrictEqual(
+            [...buffer],
+            [2, 0, 0, 0, 6, 0, 0, 0, 1, 0, 2, 0, 0, 0]
+        );
+
+        const reader = new FnkBorshReader(buffer);
+        let actual = schema.deserialize(reader);
+        assert.deepStrictEqual(actual, data);
+        assert(reader.offset === buffer.length, 'Buffer not consumed');
+    });
+
+    it('test_serialize_over_capacity', () => {
+        const schema = FnkCapVec(U16);
+        const data = { values: [1, 2], byteCapacity: 0 };
+        const writer = new FnkBorshWriter();
+        schema.serialize(writer, data);
+
+        let buffer = writer.buffer.slice(0, writer.length);
+        assert.deepStrictEqual(
+            [...buffer],
+            [2, 0, 0, 0, 4, 0, 0, 0, 1, 0, 2, 0]
+        );
+
+        const reader = new FnkBorshReader(buffer);
+        let actual = schema.deserialize(reader);
+        assert.deepStrictEqual(actual, { values: [1, 2], byteCapacity: 4 });
+    });
+});
@@ -5,6 +5,7 @@ import { FnkBorshSchema } from '../../borsh';
 import { InferFnkBorshSchemaInner } from '../maps';
 import { FnkBorshError } from '../../errors';
 import { numberToBN } from '../../../utils';
+import { U32 } from '../unsigned';
 
 export class FnkByteVecSchema implements FnkBorshSchema<Uint8Array> {
     // METHODS ----------------------------------------------------------------
@@ -75,3 +76,65 @@ export class FnkVecSchema<S extends FnkBorshSchema<any>>
         return result;
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export interface FnkCapVecValue<T> {
+    values: T[];
+    byteCapacity: number;
+}
+
+export function FnkCapVec<S extends FnkBorshSchema<any>>(schema: S) {
+    return new FnkCapVecSchema(schema);
+}
+
+export class FnkCapVecSchema<S extends FnkBorshSchema<any>>
+    implements FnkBorshSchema<FnkCapVecValue<InferFnkBorshSchemaInner<S>>>
+{
+    readonly schema: S;
+
+    // CONSTRUCTOR ------------------------------------------------------------
+
+    constructor(schema: S) {
+        this.schema = schema;
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    serialize(
+        writer: FnkBorshWriter,
+        value: FnkCapVecValue<InferFnkBorshSchemaInner<S>>
+    ) {
+        const elementsWriter = new FnkBorshWriter();
+        for (const item of value.values) {
+            this.schema.serialize(elementsWriter, item);
+        }
+
+        const elements = elementsWriter.toBuffer();
+        const byteCapacity = Math.max(value.byteCapacity, elements.length);
+
+        U32.serialize(writer, value.values.length);
+        U32.serialize(writer, byteCapacity);
+        writer.writeBuffer(Buffer.from(elements));
+        writer.writeBuffer(Buffer.alloc(byteCapacity - elements.length));
+    }
+
+    deserialize(
+        reader: FnkBorshReader
+    ): FnkCapVecValue<InferFnkBorshSchemaInner<S>> {
+        const size = U32.deserialize(reader);
+        const byteCapacity = U32.deserialize(reader);
+        const elementsReader = new FnkBorshReader(
+            reader.readBuffer(byteCapacity)
+        );
+        const values: InferFnkBorshSchemaInner<S>[] = [];
+
+        for (let i = 0; i < size; i++) {
+            values.push(this.schema.deserialize(elementsReader));
+        }
+
+        return { values, byteCapacity };
+    }
+}
@@ -7,6 +7,7 @@ export * from './floats';
 export * from './integers';
 export * from './keypairs';
 export * from './maps';
+export * from './opaque';
 export * from './options';
 export * from './public_keys';
 export * from './strings';
@@ -0,0 +1,66 @@
+import assert from 'assert';
+import BN from 'bn.js';
+import { FnkBorshWriter } from '../serializer';
+import { FnkBorshReader } from '../deserializer';
+import { I128, I64 } from './integers';
+import { U128 } from './unsigned';
+
+describe('Integers Tests', () => {
+    it('test_serialize_deserialize_i64', () => {
+        for (const data of [
+            new BN(0),
+            new BN(-1),
+            new BN('9223372036854775807'),
+            new BN('-9223372036854775808'),
+        ]) {
+            const writer = new FnkBorshWriter();
+            I64.serialize(writer, data);
+
+            const buffer = writer.buffer.slice(0, writer.length);
+            assert(buffer.length === 8, `Incorrect length for ${data}`);
+
+            const reader = new FnkBorshReader(buffer);
+            const actual = I64.deserialize(reader);
+            assert(actual.eq(data), `${actual} != ${data}`);
+        }
+    });
+
+    it('test_serialize_deserialize_i128', () => {
+        for (const data of [
+            new BN(0),
+            new BN(-1),
+            new BN('170141183460469231731687303715884105727'),
+            new BN('-170141183460469231731687303715884105728'),
+        ]) {
+            const writer = new FnkBorshWriter();
+            I128.serialize(writer, data);
+
+            const buffer = writer.buffer.slice(0, writer.length);
+            assert(buffer.length === 16, `Incorrect length for ${data}`);
+
+            const reader = new FnkBorshReader(buffer);
+            const actual = I128.deserialize(reader);
+            assert(actual.eq(data), `${actual} != ${data}`);
+        }
+
+        const writer = new FnkBorshWriter();
+        I128.serialize(writer, new BN(-2));
+        const buffer = writer.buffer.slice(0, writer.length);
+        assert(buffer[0] === 0xfe && buffer[15] === 0xff, 'Not two complement');
+    });
+
+    it('test_serialize_deserialize_u128', () => {
+        for (const data of [
+            new BN(0),
+            new BN('340282366920938463463374607431768211455'),
+        ]) {
+            const writer = new FnkBorshWriter();
+            U128.serialize(writer, data);
+
+            const buffer = writer.buffer.slice(0, writer.length);
+            const reader = new FnkBorshReader(buffer);
+            const actual = U128.deserialize(reader);
+            assert(actual.eq(data), `${actual} != ${data}`);
+        }
+    });
+});
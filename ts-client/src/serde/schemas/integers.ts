@@ -75,12 +75,12 @@ export class I64Schema
     // METHODS ----------------------------------------------------------------
 
     serialize(writer: FnkBorshWriter, value: BN) {
-        writer.writeBuffer(Buffer.from(value.toArray('le', 8)));
+        writer.writeBuffer(Buffer.from(value.toTwos(64).toArray('le', 8)));
     }
 
     deserialize(reader: FnkBorshReader): BN {
         const buffer = reader.readBuffer(8);
-        return new BN(buffer, 'le');
+        return new BN(buffer, 'le').fromTwos(64);
     }
 }
 
@@ -96,12 +96,12 @@ export class I128Schema
     // METHODS ----------------------------------------------------------------
 
     serialize(writer: FnkBorshWriter, value: BN) {
-        writer.writeBuffer(Buffer.from(value.toArray('le', 16)));
+        writer.writeBuffer(Buffer.from(value.toTwos(128).toArray('le', 16)));
     }
 
     deserialize(reader: FnkBorshReader): BN {
         const buffer = reader.readBuffer(16);
-        return new BN(buffer, 'le');
+        return new BN(buffer, 'le').fromTwos(128);
     }
 }
 
@@ -0,0 +1,38 @@
+import assert from 'assert';
+import { FnkBorshWriter } from '../serializer';
+import { FnkBorshReader } from '../deserializer';
+import { TOpaque, TSkip } from './opaque';
+import { TString } from './strings';
+import { U16 } from './unsigned';
+
+describe('Opaque Tests', () => {
+    it('test_opaque', () => {
+        const writer = new FnkBorshWriter();
+        TString.serialize(writer, 'ab');
+        const buffer = writer.toBuffer();
+
+        const schema = TOpaque(TString);
+        const reader = new FnkBorshReader(buffer);
+        const bytes = schema.deserialize(reader);
+        assert(reader.offset === buffer.length, 'Incorrect offset');
+        assert(Buffer.from(bytes).equals(buffer), 'Incorrect bytes');
+
+        const writer2 = new FnkBorshWriter();
+        schema.serialize(writer2, bytes);
+        assert(writer2.toBuffer().equals(buffer), 'Incorrect serialization');
+    });
+
+    it('test_skip', () => {
+        const schema = TSkip(U16, new Uint8Array([0, 0]));
+        const reader = new FnkBorshReader(Buffer.from([5, 1, 7]));
+        assert(schema.deserialize(reader) === undefined, 'Incorrect value');
+        assert(reader.offset === 2, 'Incorrect offset');
+
+        const writer = new FnkBorshWriter();
+        schema.serialize(writer, undefined);
+        assert(
+            writer.toBuffer().equals(Buffer.from([0, 0])),
+            'Incorrect serialization'
+        );
+    });
+});
@@ -0,0 +1,77 @@
+import { FnkBorshReader } from '../deserializer';
+import { FnkBorshWriter } from '../serializer';
+import { FnkBorshSchema } from '../borsh';
+
+export function TOpaque<S extends FnkBorshSchema<any>>(schema: S) {
+    return new OpaqueSchema(schema);
+}
+
+/**
+ * Exposes the value of a schema as its raw bytes, e.g. for fields marked with
+ * `#[fankor(ts_opaque)]`. The inner schema is only used to know how many
+ * bytes the value takes.
+ */
+export class OpaqueSchema<S extends FnkBorshSchema<any>>
+    implements FnkBorshSchema<Uint8Array>
+{
+    readonly schema: S;
+
+    // CONSTRUCTOR ------------------------------------------------------------
+
+    constructor(schema: S) {
+        this.schema = schema;
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: Uint8Array) {
+        writer.writeBuffer(Buffer.from(value));
+    }
+
+    deserialize(reader: FnkBorshReader): Uint8Array {
+        const start = reader.offset;
+        this.schema.deserialize(reader);
+
+        return Uint8Array.from(reader.buffer.subarray(start, reader.offset));
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+export function TSkip<S extends FnkBorshSchema<any>>(
+    schema: S,
+    defaultBytes: Uint8Array
+) {
+    return new SkipSchema(schema, defaultBytes);
+}
+
+/**
+ * Hides a value, e.g. for fields marked with `#[fankor(ts_skip)]`. It is read
+ * and discarded, and written as the serialized default value of the field.
+ */
+export class SkipSchema<S extends FnkBorshSchema<any>>
+    implements FnkBorshSchema<undefined>
+{
+    readonly schema: S;
+    readonly defaultBytes: Uint8Array;
+
+    // CONSTRUCTOR ------------------------------------------------------------
+
+    constructor(schema: S, defaultBytes: Uint8Array) {
+        this.schema = schema;
+        this.defaultBytes = defaultBytes;
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    serialize(writer: FnkBorshWriter, value: undefined) {
+        writer.writeBuffer(Buffer.from(this.defaultBytes));
+    }
+
+    deserialize(reader: FnkBorshReader): undefined {
+        this.schema.deserialize(reader);
+        return undefined;
+    }
+}
@@ -165,4 +165,32 @@ describe('Structs Tests', () => {
             `d[0].value: ${actual0.value} != ${expected0.value}`
         );
     });
+
+    it('test_serialize_omitted_trailing_fields', () => {
+        const schema = TStruct([
+            ['a', U8],
+            ['b', U8, true],
+            ['c', U8, true],
+        ] as const);
+        const writer = new FnkBorshWriter();
+        schema.serialize(writer, { a: 1 });
+
+        const buffer = writer.buffer.slice(0, writer.length);
+        assert(buffer.length === 1, `Length: ${buffer.length} != 1`);
+
+        assert.throws(() =>
+            schema.serialize(new FnkBorshWriter(), { a: 1, c: 2 })
+        );
+    });
+
+    it('test_serialize_missing_fields_without_default', () => {
+        const schema = TStruct([
+            ['a', U8],
+            ['b', U8],
+        ] as const);
+
+        assert.throws(() =>
+            schema.serialize(new FnkBorshWriter(), { a: 1 } as any)
+        );
+    });
 });
@@ -1,6 +1,7 @@
 import { FnkBorshReader } from '../deserializer';
 import { FnkBorshWriter } from '../serializer';
 import { FnkBorshSchema } from '../borsh';
+import { SkipSchema } from './opaque';
 
 export function TStruct<S extends ReadonlyArray<StructField>>(
     schema: S
@@ -22,8 +23,30 @@ export class StructSchema<S extends ReadonlyArray<StructField>>
     // METHODS ----------------------------------------------------------------
 
     serialize(writer: FnkBorshWriter, value: FromStructSchema<S>) {
+        let omitted: string | null = null;
+
         for (const field of this.schema) {
             const fieldValue = (value as any)[field[0]];
+
+            // Trailing default fields can be omitted to use their default
+            // value. Skipped fields are always undefined.
+            if (fieldValue === undefined && !(field[1] instanceof SkipSchema)) {
+                if (field[2] !== true) {
+                    throw new Error(
+                        `Field '${field[0]}' is missing and it has no default value`
+                    );
+                }
+
+                omitted = omitted ?? field[0];
+                continue;
+            }
+
+            if (omitted !== null) {
+                throw new Error(
+                    `Only trailing fields can be omitted but '${omitted}' is followed by '${field[0]}'`
+                );
+            }
+
             field[1].serialize(writer, fieldValue);
         }
     }
@@ -43,7 +66,13 @@ export class StructSchema<S extends ReadonlyArray<StructField>>
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
-export type StructField = readonly [string, FnkBorshSchema<any>];
+/**
+ * The name and schema of a field, and whether it takes its default value
+ * when omitted.
+ */
+export type StructField =
+    | readonly [string, FnkBorshSchema<any>]
+    | readonly [string, FnkBorshSchema<any>, boolean];
 
 export type FromStructSchema<S extends ReadonlyArray<StructField>> = IsZero<
     S['length']
@@ -51,7 +80,11 @@ export type FromStructSchema<S extends ReadonlyArray<StructField>> = IsZero<
     ? {}
     : UnionToIntersection<ToObjectsArray<S>[number]>;
 
-type ToObject<T> = T extends readonly [infer K, infer Ty]
+type ToObject<T> = T extends readonly [infer K, infer Ty, true]
+    ? K extends PropertyKey
+        ? { [P in K]?: UnwrapSchemaType<Ty> }
+        : never
+    : T extends readonly [infer K, infer Ty, ...any[]]
     ? K extends PropertyKey
         ? { [P in K]: UnwrapSchemaType<Ty> }
         : never
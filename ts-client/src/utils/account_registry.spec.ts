@@ -0,0 +1,27 @@
+import assert from 'assert';
+import BN from 'bn.js';
+import { FnkBorshReader, FnkBorshWriter } from '../serde';
+import { TAccountRegistry } from './account_registry';
+
+describe('Account registry Tests', () => {
+    it('test_serialize_deserialize', () => {
+        const data = {
+            entries: [
+                { name: 'Config', discriminant: 1, layoutHash: new BN(10) },
+                { name: 'User', discriminant: 2, layoutHash: new BN(20) },
+            ],
+        };
+
+        const writer = new FnkBorshWriter();
+        TAccountRegistry.serialize(writer, data);
+
+        const buffer = writer.buffer.slice(0, writer.length);
+        assert(buffer.length === 40, 'Incorrect length');
+
+        const actual = TAccountRegistry.deserialize(new FnkBorshReader(buffer));
+        assert(actual.entries.length === 2, 'Incorrect entries');
+        assert(actual.entries[1].name === 'User', 'Incorrect name');
+        assert(actual.entries[1].discriminant === 2, 'Incorrect discriminant');
+        assert(actual.entries[1].layoutHash.eqn(20), 'Incorrect layoutHash');
+    });
+});
@@ -0,0 +1,57 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import { FnkBorshReader, TString, TStruct, U64, U8, Vec } from '../serde';
+
+/**
+ * The discriminant of the `__account_registry` instruction in the programs
+ * that enable it with `#[program(account_registry = <accounts enum>)]`.
+ */
+export const ACCOUNT_REGISTRY_INSTRUCTION_DISCRIMINANT = 253;
+
+/**
+ * The seed of the PDA that stores the account registry of a program.
+ */
+export const ACCOUNT_REGISTRY_SEED = Buffer.from('__account_registry');
+
+/**
+ * An account type of the registry along with the hash of its layout, which
+ * changes whenever the serialized layout of the account changes.
+ */
+export const TAccountRegistryEntry = TStruct([
+    ['name', TString],
+    ['discriminant', U8],
+    ['layoutHash', U64],
+] as const);
+
+export const TAccountRegistry = TStruct([
+    ['entries', Vec(TAccountRegistryEntry)],
+] as const);
+
+/**
+ * Gets the address of the account that stores the account registry of
+ * `programId`.
+ */
+export function findAccountRegistryAddress(programId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [ACCOUNT_REGISTRY_SEED],
+        programId
+    )[0];
+}
+
+/**
+ * Fetches the account registry of a program, or `null` if the program has
+ * not stored it yet.
+ */
+export async function getAccountRegistry(
+    connection: Connection,
+    programId: PublicKey
+) {
+    const account = await connection.getAccountInfo(
+        findAccountRegistryAddress(programId)
+    );
+
+    if (!account) {
+        return null;
+    }
+
+    return TAccountRegistry.deserialize(new FnkBorshReader(account.data));
+}
@@ -0,0 +1,31 @@
+import assert from 'assert';
+import BN from 'bn.js';
+import { PublicKey } from '@solana/web3.js';
+import { FnkBorshReader, FnkBorshWriter } from '../serde';
+import { computeFee, TFeeConfig } from './fees';
+
+describe('Fees Tests', () => {
+    it('test_compute_fee', () => {
+        assert(computeFee(30, 0).eqn(0), 'Incorrect fee of 0');
+        assert(computeFee(30, 10000).eqn(30), 'Incorrect fee of 10000');
+        assert(computeFee(30, 10001).eqn(31), 'Incorrect fee of 10001');
+        assert(computeFee(30, new BN(1)).eqn(1), 'Incorrect fee of 1');
+    });
+
+    it('test_serialize_deserialize', () => {
+        const data = { bps: 30, recipient: PublicKey.default };
+
+        const writer = new FnkBorshWriter();
+        TFeeConfig.serialize(writer, data);
+
+        const buffer = writer.buffer.slice(0, writer.length);
+        assert(buffer.length === 34, 'Incorrect length');
+
+        const actual = TFeeConfig.deserialize(new FnkBorshReader(buffer));
+        assert(actual.bps === 30, 'Incorrect bps');
+        assert(
+            actual.recipient.equals(PublicKey.default),
+            'Incorrect recipient'
+        );
+    });
+});
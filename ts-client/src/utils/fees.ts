@@ -0,0 +1,64 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import BN from 'bn.js';
+import {
+    BPS_DENOMINATOR,
+    FnkBorshReader,
+    TBps,
+    TPublicKey,
+    TStruct,
+} from '../serde';
+import { numberToBN } from './numbers';
+
+/**
+ * The seed of the PDA that stores the fee config of a program.
+ */
+export const FEE_CONFIG_SEED = Buffer.from('__fee_config');
+
+/**
+ * The fee charged by the instructions declared with
+ * `#[instruction(collect_fee = ...)]`.
+ */
+export const TFeeConfig = TStruct([
+    ['bps', TBps],
+    ['recipient', TPublicKey],
+] as const);
+
+/**
+ * Gets the address of the account that stores the fee config of `programId`.
+ */
+export function findFeeConfigAddress(programId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([FEE_CONFIG_SEED], programId)[0];
+}
+
+/**
+ * Fetches the fee config of a program, or `null` if the program has not
+ * stored it yet.
+ */
+export async function getFeeConfig(
+    connection: Connection,
+    programId: PublicKey
+) {
+    const account = await connection.getAccountInfo(
+        findFeeConfigAddress(programId)
+    );
+
+    if (!account) {
+        return null;
+    }
+
+    return TFeeConfig.deserialize(new FnkBorshReader(account.data));
+}
+
+/**
+ * Computes the fee that a program charges for `amount` with a fee of `bps`,
+ * rounding up like the program does.
+ */
+export function computeFee(
+    bps: number,
+    amount: BN | bigint | number | string
+): BN {
+    const product = numberToBN(amount).muln(bps);
+    const fee = product.divn(BPS_DENOMINATOR);
+
+    return product.modn(BPS_DENOMINATOR) === 0 ? fee : fee.addn(1);
+}
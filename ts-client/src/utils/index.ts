@@ -1,4 +1,10 @@
+export * from './account_registry';
 export * from './clone';
 export * from './equality';
+export * from './fees';
+export * from './logs';
 export * from './numbers';
+export * from './pda_collections';
+export * from './program_info';
+export * from './transactions';
 export * from './types';
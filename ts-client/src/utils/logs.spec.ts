@@ -0,0 +1,48 @@
+import assert from 'assert';
+import { parseFnkLog, parseFnkLogs } from './logs';
+
+describe('Logs Tests', () => {
+    it('parseFnkLog', () => {
+        assert.deepStrictEqual(
+            parseFnkLog('Program log: FnkLog warn my_program Deposit: Low: 5'),
+            {
+                level: 'warn',
+                program: 'my_program',
+                instruction: 'Deposit',
+                message: 'Low: 5',
+            },
+            '1'
+        );
+        assert.strictEqual(
+            parseFnkLog('Program log: FnkLog info my_program -: Message')
+                ?.instruction,
+            null,
+            '2'
+        );
+        assert.strictEqual(parseFnkLog('Program log: Message'), null, '3');
+        assert.strictEqual(
+            parseFnkLog('Program log: FnkLog unknown my_program -: Message'),
+            null,
+            '4'
+        );
+        assert.strictEqual(
+            parseFnkLog('Program log: FnkLog info my_program: Message'),
+            null,
+            '5'
+        );
+    });
+
+    it('parseFnkLogs', () => {
+        const logs = [
+            'Program log: FnkLog error my_program -: A',
+            'Program log: FnkLog debug my_program -: B',
+            'Program log: other',
+            'Program log: FnkLog info my_program -: C',
+        ];
+
+        assert.deepStrictEqual(
+            parseFnkLogs(logs, 'info').map((v) => v.message),
+            ['A', 'C']
+        );
+    });
+});
@@ -0,0 +1,84 @@
+/**
+ * The tag that starts the structured logs emitted by `fnk_log!`.
+ */
+export const FNK_LOG_PREFIX = 'FnkLog';
+
+/**
+ * The levels of the structured logs from the least to the most verbose.
+ */
+export const LOG_LEVELS = [
+    'off',
+    'error',
+    'warn',
+    'info',
+    'debug',
+    'trace',
+] as const;
+
+export type LogLevel = (typeof LOG_LEVELS)[number];
+
+/**
+ * A structured log emitted by `fnk_log!`.
+ */
+export interface FnkLog {
+    level: LogLevel;
+    program: string;
+    instruction: string | null;
+    message: string;
+}
+
+/**
+ * Decodes a structured log from a log line of a transaction.
+ */
+export function parseFnkLog(log: string): FnkLog | null {
+    const prefix = `Program log: ${FNK_LOG_PREFIX} `;
+    if (!log.startsWith(prefix)) {
+        return null;
+    }
+
+    log = log.slice(prefix.length);
+
+    const position = log.indexOf(': ');
+    if (position === -1) {
+        return null;
+    }
+
+    const header = log.slice(0, position).split(' ');
+    if (header.length !== 3) {
+        return null;
+    }
+
+    const [level, program, instruction] = header;
+    if (!LOG_LEVELS.includes(level as LogLevel)) {
+        return null;
+    }
+
+    return {
+        level: level as LogLevel,
+        program,
+        instruction: instruction === '-' ? null : instruction,
+        message: log.slice(position + 2),
+    };
+}
+
+/**
+ * Decodes all the structured logs of a transaction whose level is at most
+ * `maxLevel`.
+ */
+export function parseFnkLogs(
+    logs: string[],
+    maxLevel: LogLevel = 'trace'
+): FnkLog[] {
+    const max = LOG_LEVELS.indexOf(maxLevel);
+    const result: FnkLog[] = [];
+
+    for (const log of logs) {
+        const entry = parseFnkLog(log);
+
+        if (entry !== null && LOG_LEVELS.indexOf(entry.level) <= max) {
+            result.push(entry);
+        }
+    }
+
+    return result;
+}
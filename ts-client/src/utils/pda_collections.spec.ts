@@ -0,0 +1,41 @@
+import assert from 'assert';
+import BN from 'bn.js';
+import { PublicKey } from '@solana/web3.js';
+import {
+    findCollectionItemAddress,
+    iterCollectionItemAddresses,
+} from './pda_collections';
+
+describe('PDA Collections Tests', () => {
+    const programId = PublicKey.unique();
+    const seeds = [Buffer.from('item'), PublicKey.unique().toBuffer()];
+
+    it('test_find_collection_item_address', () => {
+        const expected = PublicKey.findProgramAddressSync(
+            [...seeds, Buffer.from([3, 0, 0, 0, 0, 0, 0, 0])],
+            programId
+        )[0];
+
+        assert(
+            findCollectionItemAddress(programId, seeds, 3).equals(expected),
+            'Incorrect address of 3'
+        );
+        assert(
+            findCollectionItemAddress(programId, seeds, new BN(3)).equals(
+                expected
+            ),
+            'Incorrect address of BN 3'
+        );
+    });
+
+    it('test_iter_collection_item_addresses', () => {
+        const items = [...iterCollectionItemAddresses(programId, seeds, 2, 5)];
+
+        assert(items.length === 3, 'Incorrect length');
+        assert(items[1][0].eqn(3), 'Incorrect index');
+        assert(
+            items[1][1].equals(findCollectionItemAddress(programId, seeds, 3)),
+            'Incorrect address'
+        );
+    });
+});
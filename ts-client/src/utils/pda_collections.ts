@@ -0,0 +1,41 @@
+import { PublicKey } from '@solana/web3.js';
+import BN from 'bn.js';
+import { numberToBN } from './numbers';
+
+type Index = BN | bigint | number | string;
+
+/**
+ * Gets the address of the item at `index` of a family of PDAs derived from
+ * `seeds` followed by the index as a little-endian `u64`, like
+ * `PdaCollection` does in the program.
+ */
+export function findCollectionItemAddress(
+    programId: PublicKey,
+    seeds: (Buffer | Uint8Array)[],
+    index: Index
+): PublicKey {
+    const indexSeed = numberToBN(index).toArrayLike(Buffer, 'le', 8);
+
+    return PublicKey.findProgramAddressSync(
+        [...seeds, indexSeed],
+        programId
+    )[0];
+}
+
+/**
+ * Iterates over the items of a collection from `start` to `end`, excluded,
+ * along with their index. Addresses are derived lazily, so large ranges can
+ * be consumed partially.
+ */
+export function* iterCollectionItemAddresses(
+    programId: PublicKey,
+    seeds: (Buffer | Uint8Array)[],
+    start: Index,
+    end: Index
+): Generator<[BN, PublicKey]> {
+    const last = numberToBN(end);
+
+    for (let i = numberToBN(start); i.lt(last); i = i.addn(1)) {
+        yield [i, findCollectionItemAddress(programId, seeds, i)];
+    }
+}
@@ -0,0 +1,30 @@
+import assert from 'assert';
+import {
+    isCompatibleVersion,
+    satisfiesVersionRange,
+} from './program_info';
+
+describe('Program info Tests', () => {
+    it('isCompatibleVersion', () => {
+        assert(isCompatibleVersion('1.4.2', '1.4.2'), '1');
+        assert(isCompatibleVersion('1.4.2', '1.0.0'), '2');
+        assert(isCompatibleVersion('1.4.2', '1.4.0-beta.1'), '3');
+        assert(!isCompatibleVersion('1.4.2', '1.5.0'), '4');
+        assert(!isCompatibleVersion('1.4.2', '2.0.0'), '5');
+        assert(!isCompatibleVersion('1.4.2', '1.4'), '6');
+        assert(isCompatibleVersion('0.3.1', '0.3.0'), '7');
+        assert(!isCompatibleVersion('0.3.1', '0.2.0'), '8');
+    });
+
+    it('satisfiesVersionRange', () => {
+        assert(satisfiesVersionRange('1.4.2', '1.2.0'), '1');
+        assert(satisfiesVersionRange('1.4.2', '^1.2.0'), '2');
+        assert(!satisfiesVersionRange('1.4.2', '^1.5.0'), '3');
+        assert(satisfiesVersionRange('1.4.2', '~1.4.0'), '4');
+        assert(!satisfiesVersionRange('1.4.2', '~1.3.0'), '5');
+        assert(satisfiesVersionRange('1.4.2', '=1.4.2'), '6');
+        assert(!satisfiesVersionRange('1.4.2', '=1.4.1'), '7');
+        assert(satisfiesVersionRange('2.0.0', '>=1.4.2'), '8');
+        assert(!satisfiesVersionRange('1.4.1', '>=1.4.2'), '9');
+    });
+});
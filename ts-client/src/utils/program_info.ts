@@ -0,0 +1,201 @@
+import {
+    Connection,
+    PublicKey,
+    TransactionInstruction,
+    TransactionMessage,
+    VersionedTransaction,
+} from '@solana/web3.js';
+import { deserialize, FnkBorshReader, TString } from '../serde';
+
+/**
+ * The discriminant of the `__version` instruction in the programs that enable it
+ * with `#[program(versioned)]`.
+ */
+export const VERSION_INSTRUCTION_DISCRIMINANT = 254;
+
+/**
+ * The metadata of a program declared with `program_info!`.
+ */
+export interface ProgramInfo {
+    version: string;
+    docs: string | undefined;
+    repository: string | undefined;
+    deployments: Record<string, PublicKey>;
+}
+
+/**
+ * Whether a client built for `version` can talk to a program with version
+ * `programVersion` following semver rules, i.e. same major version (or minor
+ * for `0.x` versions) and not newer than the program's one.
+ */
+export function isCompatibleVersion(
+    programVersion: string,
+    version: string
+): boolean {
+    const actual = parseVersion(programVersion);
+    const required = parseVersion(version);
+
+    if (actual === null || required === null) {
+        return false;
+    }
+
+    const sameSeries =
+        actual[0] === 0
+            ? actual[0] === required[0] && actual[1] === required[1]
+            : actual[0] === required[0];
+
+    return sameSeries && compareVersions(required, actual) <= 0;
+}
+
+/**
+ * Whether `version` satisfies `range`. Supported ranges are: `x.y.z` and `^x.y.z`
+ * (compatible following semver), `~x.y.z` (same minor), `=x.y.z` (exact) and
+ * `>=x.y.z` (any newer version).
+ */
+export function satisfiesVersionRange(
+    version: string,
+    range: string
+): boolean {
+    range = range.trim();
+
+    if (range.startsWith('>=')) {
+        const actual = parseVersion(version);
+        const required = parseVersion(range.slice(2));
+
+        return (
+            actual !== null &&
+            required !== null &&
+            compareVersions(required, actual) <= 0
+        );
+    }
+
+    if (range.startsWith('=')) {
+        const actual = parseVersion(version);
+        const required = parseVersion(range.slice(1));
+
+        return (
+            actual !== null &&
+            required !== null &&
+            compareVersions(required, actual) === 0
+        );
+    }
+
+    if (range.startsWith('~')) {
+        const actual = parseVersion(version);
+        const required = parseVersion(range.slice(1));
+
+        return (
+            actual !== null &&
+            required !== null &&
+            actual[0] === required[0] &&
+            actual[1] === required[1] &&
+            compareVersions(required, actual) <= 0
+        );
+    }
+
+    if (range.startsWith('^')) {
+        range = range.slice(1);
+    }
+
+    return isCompatibleVersion(version, range);
+}
+
+/**
+ * Throws if the program described by `info` is not compatible with `version`.
+ */
+export function assertCompatibleVersion(info: ProgramInfo, version: string) {
+    if (!isCompatibleVersion(info.version, version)) {
+        throw new Error(
+            `Incompatible program version: expected ${version} but got ${info.version}`
+        );
+    }
+}
+
+/**
+ * Gets the version of a program that includes the `__version` instruction by
+ * simulating it. `payer` must be an existing account, it does not sign anything.
+ */
+export async function getProgramVersion(
+    connection: Connection,
+    programId: PublicKey,
+    payer: PublicKey
+): Promise<string> {
+    const instruction = new TransactionInstruction({
+        programId,
+        keys: [],
+        data: Buffer.from([VERSION_INSTRUCTION_DISCRIMINANT]),
+    });
+    const message = new TransactionMessage({
+        payerKey: payer,
+        recentBlockhash: PublicKey.default.toBase58(),
+        instructions: [instruction],
+    }).compileToV0Message();
+
+    const result = await connection.simulateTransaction(
+        new VersionedTransaction(message),
+        {
+            sigVerify: false,
+            replaceRecentBlockhash: true,
+        }
+    );
+
+    if (result.value.err) {
+        throw new Error(
+            `Cannot get the version of the program: ${JSON.stringify(
+                result.value.err
+            )}`
+        );
+    }
+
+    const returnData = result.value.returnData;
+    if (!returnData || returnData.programId !== programId.toBase58()) {
+        throw new Error('The program did not return its version');
+    }
+
+    const data = Buffer.from(returnData.data[0], 'base64');
+    return deserialize(TString, new FnkBorshReader(data));
+}
+
+/**
+ * Throws if the version of the deployed program does not satisfy `range`,
+ * see `satisfiesVersionRange`. Returns the version of the program otherwise.
+ */
+export async function assertProgramVersion(
+    connection: Connection,
+    programId: PublicKey,
+    range: string,
+    payer: PublicKey
+): Promise<string> {
+    const version = await getProgramVersion(connection, programId, payer);
+
+    if (!satisfiesVersionRange(version, range)) {
+        throw new Error(
+            `Incompatible program version: expected ${range} but got ${version}`
+        );
+    }
+
+    return version;
+}
+
+function compareVersions(
+    a: [number, number, number],
+    b: [number, number, number]
+): number {
+    for (let i = 0; i < 3; i++) {
+        if (a[i] !== b[i]) {
+            return a[i] - b[i];
+        }
+    }
+
+    return 0;
+}
+
+function parseVersion(version: string): [number, number, number] | null {
+    const parts = version.split(/[-+]/)[0].split('.');
+
+    if (parts.length !== 3 || parts.some((v) => !/^\d+$/.test(v))) {
+        return null;
+    }
+
+    return [Number(parts[0]), Number(parts[1]), Number(parts[2])];
+}
@@ -0,0 +1,66 @@
+import assert from 'assert';
+import {
+    AddressLookupTableAccount,
+    Keypair,
+    PublicKey,
+    TransactionInstruction,
+} from '@solana/web3.js';
+import { compileVersionedTransaction } from './transactions';
+
+describe('Transactions Tests', () => {
+    const payer = Keypair.generate().publicKey;
+    const programId = Keypair.generate().publicKey;
+    const account = Keypair.generate().publicKey;
+    const instruction = new TransactionInstruction({
+        programId,
+        keys: [{ pubkey: account, isSigner: false, isWritable: true }],
+        data: Buffer.from([1, 2, 3]),
+    });
+
+    it('compileVersionedTransaction', () => {
+        const transaction = compileVersionedTransaction(
+            payer,
+            [instruction],
+            PublicKey.default.toBase58()
+        );
+
+        assert.strictEqual(transaction.version, 0, '1');
+        assert(transaction.message.staticAccountKeys[0].equals(payer), '2');
+        assert.strictEqual(transaction.message.staticAccountKeys.length, 3, '3');
+        assert.strictEqual(
+            transaction.message.addressTableLookups.length,
+            0,
+            '4'
+        );
+    });
+
+    it('compileVersionedTransaction with lookup tables', () => {
+        const lookupTable = new AddressLookupTableAccount({
+            key: Keypair.generate().publicKey,
+            state: {
+                deactivationSlot: BigInt('18446744073709551615'),
+                lastExtendedSlot: 0,
+                lastExtendedSlotStartIndex: 0,
+                addresses: [account],
+            },
+        });
+        const transaction = compileVersionedTransaction(
+            payer,
+            [instruction],
+            PublicKey.default.toBase58(),
+            [lookupTable]
+        );
+
+        assert.strictEqual(transaction.message.staticAccountKeys.length, 2, '1');
+        assert.strictEqual(
+            transaction.message.addressTableLookups.length,
+            1,
+            '2'
+        );
+        assert.deepStrictEqual(
+            transaction.message.addressTableLookups[0].writableIndexes,
+            [0],
+            '3'
+        );
+    });
+});
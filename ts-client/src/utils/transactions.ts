@@ -0,0 +1,188 @@
+import {
+    AddressLookupTableAccount,
+    Blockhash,
+    Commitment,
+    Connection,
+    PublicKey,
+    SendOptions,
+    Transaction,
+    TransactionInstruction,
+    TransactionMessage,
+    VersionedTransaction,
+} from '@solana/web3.js';
+
+/**
+ * The minimal interface of a wallet adapter required to sign transactions,
+ * compatible with `@solana/wallet-adapter-base`.
+ */
+export interface WalletAdapter {
+    publicKey: PublicKey | null;
+
+    signTransaction<T extends Transaction | VersionedTransaction>(
+        transaction: T
+    ): Promise<T>;
+}
+
+/**
+ * The options to build a `VersionedTransaction`.
+ */
+export interface BuildTransactionOptions {
+    /**
+     * The address lookup tables used to compress the accounts of the
+     * transaction. Either the accounts or their addresses, that are fetched.
+     */
+    lookupTables?: (AddressLookupTableAccount | PublicKey)[];
+
+    /**
+     * The blockhash of the transaction. The latest one is fetched if missing.
+     */
+    recentBlockhash?: Blockhash;
+
+    /**
+     * The commitment used to fetch the blockhash and the lookup tables.
+     */
+    commitment?: Commitment;
+}
+
+/**
+ * The options to sign and send a transaction.
+ */
+export interface SignAndSendOptions extends BuildTransactionOptions {
+    /**
+     * The options used to send the transaction.
+     */
+    sendOptions?: SendOptions;
+
+    /**
+     * Whether to wait for the confirmation of the transaction or not.
+     */
+    confirm?: boolean;
+}
+
+/**
+ * Compiles `instructions` into a v0 `VersionedTransaction` paid by `payer`.
+ */
+export function compileVersionedTransaction(
+    payer: PublicKey,
+    instructions: TransactionInstruction[],
+    recentBlockhash: Blockhash,
+    lookupTables: AddressLookupTableAccount[] = []
+): VersionedTransaction {
+    const message = new TransactionMessage({
+        payerKey: payer,
+        recentBlockhash,
+        instructions,
+    }).compileToV0Message(lookupTables);
+
+    return new VersionedTransaction(message);
+}
+
+/**
+ * Builds a v0 `VersionedTransaction` with `instructions` paid by `payer`,
+ * fetching the blockhash and the lookup tables that are not provided.
+ */
+export async function buildVersionedTransaction(
+    connection: Connection,
+    payer: PublicKey,
+    instructions: TransactionInstruction[],
+    options: BuildTransactionOptions = {}
+): Promise<VersionedTransaction> {
+    const recentBlockhash =
+        options.recentBlockhash ??
+        (await connection.getLatestBlockhash(options.commitment)).blockhash;
+    const lookupTables = await resolveLookupTables(
+        connection,
+        options.lookupTables ?? [],
+        options.commitment
+    );
+
+    return compileVersionedTransaction(
+        payer,
+        instructions,
+        recentBlockhash,
+        lookupTables
+    );
+}
+
+/**
+ * Builds a transaction with `instructions` paid by the wallet, signs it with
+ * the wallet and sends it. Returns the signature of the transaction.
+ */
+export async function signAndSend(
+    connection: Connection,
+    wallet: WalletAdapter,
+    instructions: TransactionInstruction[] | VersionedTransaction,
+    options: SignAndSendOptions = {}
+): Promise<string> {
+    if (!wallet.publicKey) {
+        throw new Error('The wallet is not connected');
+    }
+
+    const latestBlockhash = await connection.getLatestBlockhash(
+        options.commitment
+    );
+    const transaction =
+        instructions instanceof VersionedTransaction
+            ? instructions
+            : await buildVersionedTransaction(
+                  connection,
+                  wallet.publicKey,
+                  instructions,
+                  {
+                      ...options,
+                      recentBlockhash:
+                          options.recentBlockhash ?? latestBlockhash.blockhash,
+                  }
+              );
+
+    const signedTransaction = await wallet.signTransaction(transaction);
+    const signature = await connection.sendRawTransaction(
+        signedTransaction.serialize(),
+        options.sendOptions
+    );
+
+    if (options.confirm ?? true) {
+        const result = await connection.confirmTransaction(
+            {
+                signature,
+                blockhash: transaction.message.recentBlockhash,
+                lastValidBlockHeight: latestBlockhash.lastValidBlockHeight,
+            },
+            options.commitment
+        );
+
+        if (result.value.err) {
+            throw new Error(
+                `Transaction ${signature} failed: ${JSON.stringify(
+                    result.value.err
+                )}`
+            );
+        }
+    }
+
+    return signature;
+}
+
+async function resolveLookupTables(
+    connection: Connection,
+    lookupTables: (AddressLookupTableAccount | PublicKey)[],
+    commitment?: Commitment
+): Promise<AddressLookupTableAccount[]> {
+    return Promise.all(
+        lookupTables.map(async (v) => {
+            if (v instanceof AddressLookupTableAccount) {
+                return v;
+            }
+
+            const result = await connection.getAddressLookupTable(v, {
+                commitment,
+            });
+
+            if (!result.value) {
+                throw new Error(`Lookup table not found: ${v.toBase58()}`);
+            }
+
+            return result.value;
+        })
+    );
+}